@@ -19,6 +19,8 @@ mon1.name = "Foo"
 mon1.name = None
 # set enabled status
 mon1.enabled = False
+# ephemeral monitors aren't saved, so they don't come back after a reboot
+mon1.ephemeral = True
 
 print(mon1.modes)
 # node, this can be set with a dict
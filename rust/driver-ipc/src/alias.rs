@@ -0,0 +1,187 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Id, Monitor};
+
+// Longest alias a monitor can have, see [`Monitor::alias`]
+pub const MAX_ALIAS_LEN: usize = 32;
+
+/// A monitor in a [`crate::Command`], by its id or its alias
+///
+/// Serialized as just the id or the alias, so commands from clients that only know ids look the
+/// same as before.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum MonitorRef {
+    Id(Id),
+    Alias(String),
+}
+
+impl MonitorRef {
+    /// Whether this refers to `monitor`
+    #[must_use]
+    pub fn matches(&self, monitor: &Monitor) -> bool {
+        match self {
+            Self::Id(id) => monitor.id == *id,
+            Self::Alias(alias) => monitor.alias.as_ref() == Some(alias),
+        }
+    }
+
+    /// The id of the monitor this refers to among `monitors`
+    ///
+    /// Ids are returned as they are, even if none of the monitors has them, so only aliases can
+    /// fail to resolve.
+    pub fn resolve<'a>(&self, monitors: impl IntoIterator<Item = &'a Monitor>) -> Option<Id> {
+        match self {
+            Self::Id(id) => Some(*id),
+            Self::Alias(_) => monitors
+                .into_iter()
+                .find(|monitor| self.matches(monitor))
+                .map(|monitor| monitor.id),
+        }
+    }
+}
+
+impl From<Id> for MonitorRef {
+    fn from(id: Id) -> Self {
+        Self::Id(id)
+    }
+}
+
+impl fmt::Display for MonitorRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "{id}"),
+            Self::Alias(alias) => f.write_str(alias),
+        }
+    }
+}
+
+/// Whether `alias` can be a monitor's alias: a slug of lowercase ascii letters, digits, `-` and
+/// `_`, like `living-room-tv`, at most [`MAX_ALIAS_LEN`] long
+///
+/// It has to start with a letter, so it can't be mistaken for an id.
+#[must_use]
+pub fn is_valid_alias(alias: &str) -> bool {
+    alias.len() <= MAX_ALIAS_LEN
+        && alias.starts_with(|c: char| c.is_ascii_lowercase())
+        && alias
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// Why the aliases of `notified` monitors can't be applied on top of the `current` ones, if they
+/// can't: an alias isn't valid, see [`is_valid_alias`], or another monitor would have it too
+///
+/// Monitors in `notified` replace the ones in `current` with the same id, so an alias can move
+/// from one monitor to another in a single notify.
+pub fn alias_conflict<'a>(
+    current: impl IntoIterator<Item = &'a Monitor>,
+    notified: &'a [Monitor],
+) -> Option<String> {
+    let mut aliased = current
+        .into_iter()
+        .filter(|monitor| notified.iter().all(|new| new.id != monitor.id))
+        .chain(notified)
+        .filter_map(|monitor| Some((monitor.alias.as_deref()?, monitor.id)))
+        .collect::<Vec<_>>();
+
+    if let Some((alias, _)) = aliased.iter().find(|(alias, _)| !is_valid_alias(alias)) {
+        return Some(format!(
+            "invalid alias {alias:?}, it needs to be a lowercase slug starting with a letter"
+        ));
+    }
+
+    aliased.sort_unstable();
+    aliased.windows(2).find_map(|pair| {
+        let [(alias, a), (other, b)] = pair else {
+            return None;
+        };
+        (alias == other).then(|| format!("alias {alias:?} is used by monitors {a} and {b}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mode;
+
+    fn monitor(id: Id, alias: Option<&str>) -> Monitor {
+        Monitor {
+            alias: alias.map(str::to_owned),
+            ..Monitor::new(
+                id,
+                vec![Mode {
+                    width: 1920,
+                    height: 1080,
+                    refresh_rates: vec![60],
+                }],
+            )
+        }
+    }
+
+    #[test]
+    fn serialized_like_ids_and_aliases() {
+        let refs = [MonitorRef::Id(3), MonitorRef::Alias("desk".to_owned())];
+        let json = serde_json::to_string(&refs).unwrap();
+        assert_eq!(json, r#"[3,"desk"]"#);
+        assert_eq!(
+            serde_json::from_str::<Vec<MonitorRef>>(&json).unwrap(),
+            refs
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let monitors = [monitor(0, None), monitor(1, Some("desk"))];
+
+        assert_eq!(MonitorRef::Id(7).resolve(&monitors), Some(7));
+        assert_eq!(
+            MonitorRef::Alias("desk".to_owned()).resolve(&monitors),
+            Some(1)
+        );
+        assert_eq!(MonitorRef::Alias("tv".to_owned()).resolve(&monitors), None);
+    }
+
+    #[test]
+    fn valid_aliases() {
+        for alias in ["desk", "living-room-tv", "stream_2", "a"] {
+            assert!(is_valid_alias(alias), "{alias}");
+        }
+        for alias in [
+            "",
+            "2",
+            "2nd",
+            "Desk",
+            "-desk",
+            "living room",
+            "déjà",
+            &"a".repeat(33),
+        ] {
+            assert!(!is_valid_alias(alias), "{alias}");
+        }
+    }
+
+    #[test]
+    fn conflicts() {
+        let current = [monitor(0, Some("desk")), monitor(1, Some("tv"))];
+
+        assert_eq!(
+            alias_conflict(&current, &[monitor(2, Some("stream"))]),
+            None
+        );
+        // the alias moves from 0 to 2
+        assert_eq!(
+            alias_conflict(&current, &[monitor(0, None), monitor(2, Some("desk"))]),
+            None
+        );
+
+        let conflict = alias_conflict(&current, &[monitor(2, Some("desk"))]).unwrap();
+        assert!(conflict.contains("monitors 0 and 2"), "{conflict}");
+        let conflict = alias_conflict([], &[monitor(2, Some("tv")), monitor(3, Some("tv"))]);
+        assert!(conflict.is_some());
+        assert!(alias_conflict([], &[monitor(2, Some("TV"))]).is_some());
+    }
+}
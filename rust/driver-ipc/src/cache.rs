@@ -0,0 +1,338 @@
+use std::collections::HashMap;
+
+use crate::{Event, EventKind, Id, Monitor, MonitorControl};
+
+/// The last known monitors of a driver, kept up to date from its events instead of requesting
+/// the whole list again
+///
+/// The driver only keeps its latest events. If the cache falls behind further than that, it
+/// requests the list again, and reports all monitors as changed.
+#[derive(Debug, Default)]
+pub struct MonitorCache {
+    monitors: Vec<Monitor>,
+    // sequence number of the last applied event
+    seq: u64,
+    // sequence number of the last change of each monitor, including removed ones
+    changes: HashMap<Id, u64>,
+}
+
+/// Monitors that changed since an earlier [`MonitorCache::seq`], see [`MonitorCache::diff_since`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CacheDiff {
+    /// Pass this to the next [`MonitorCache::diff_since`]
+    pub seq: u64,
+    /// Monitors that were added or changed, with their current state
+    pub changed: Vec<Monitor>,
+    pub removed: Vec<Id>,
+}
+
+impl CacheDiff {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl MonitorCache {
+    /// Request the monitors and the latest event
+    pub fn load<C: MonitorControl>(control: &mut C) -> Result<Self, C::Error> {
+        // the list is requested after the events, so changes in between are applied again on
+        // the next update, instead of being missed
+        let seq = latest(control, 0)?.last().map_or(0, |event| event.seq);
+        let monitors = control.list()?;
+
+        Ok(Self {
+            monitors,
+            seq,
+            changes: HashMap::new(),
+        })
+    }
+
+    #[must_use]
+    pub fn monitors(&self) -> &[Monitor] {
+        &self.monitors
+    }
+
+    /// Sequence number of the last applied event
+    #[must_use]
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// Apply the events since the last update, returns whether any monitor changed
+    pub fn update<C: MonitorControl>(&mut self, control: &mut C) -> Result<bool, C::Error> {
+        let events = latest(control, self.seq)?;
+        let Some(last) = events.last().map(|event| event.seq) else {
+            return Ok(false);
+        };
+
+        // older events were dropped by the driver before they were seen
+        if events[0].seq > self.seq + 1 {
+            let monitors = control.list()?;
+            self.reset(monitors, last);
+            return Ok(true);
+        }
+
+        let mut changed = false;
+        for event in events {
+            changed |= self.apply(event);
+        }
+
+        Ok(changed)
+    }
+
+    /// Apply a single event, returns whether a monitor changed
+    ///
+    /// Events have to be applied in order, ones that were applied already are ignored.
+    pub fn apply(&mut self, event: Event) -> bool {
+        if event.seq <= self.seq {
+            return false;
+        }
+        self.seq = event.seq;
+
+        match event.kind {
+            EventKind::MonitorAdded { monitor } | EventKind::MonitorChanged { monitor, .. } => {
+                self.changes.insert(monitor.id, event.seq);
+
+                match self.monitors.iter_mut().find(|m| m.id == monitor.id) {
+                    Some(existing) => *existing = monitor,
+                    None => self.monitors.push(monitor),
+                }
+            }
+
+            EventKind::MonitorRemoved { id } => {
+                self.changes.insert(id, event.seq);
+                self.monitors.retain(|monitor| monitor.id != id);
+            }
+
+            EventKind::ActiveModeChanged { id, mode } => {
+                let Some(monitor) = self.monitors.iter_mut().find(|m| m.id == id) else {
+                    return false;
+                };
+
+                self.changes.insert(id, event.seq);
+                monitor.active_mode = mode;
+            }
+
+            EventKind::SwapChainStalled { .. }
+            | EventKind::ConsumerAbandoned { .. }
+            | EventKind::ModeChangeRequested { .. } => return false,
+        }
+
+        true
+    }
+
+    /// Monitors that changed after `last_seen`, an earlier [`Self::seq`] or the `seq` of the last
+    /// diff. Use 0 to get all monitors
+    #[must_use]
+    pub fn diff_since(&self, last_seen: u64) -> CacheDiff {
+        let changed_after = |id| self.changes.get(&id).is_some_and(|&seq| seq > last_seen);
+
+        let changed = self
+            .monitors
+            .iter()
+            .filter(|monitor| last_seen == 0 || changed_after(monitor.id))
+            .cloned()
+            .collect();
+
+        let mut removed = self
+            .changes
+            .iter()
+            .filter(|&(&id, &seq)| {
+                seq > last_seen && !self.monitors.iter().any(|monitor| monitor.id == id)
+            })
+            .map(|(&id, _)| id)
+            .collect::<Vec<_>>();
+        removed.sort_unstable();
+
+        CacheDiff {
+            seq: self.seq,
+            changed,
+            removed,
+        }
+    }
+
+    // replace the monitors after events were missed, everything counts as changed
+    fn reset(&mut self, monitors: Vec<Monitor>, seq: u64) {
+        for monitor in &self.monitors {
+            self.changes.insert(monitor.id, seq);
+        }
+        for monitor in &monitors {
+            self.changes.insert(monitor.id, seq);
+        }
+
+        self.monitors = monitors;
+        self.seq = seq;
+    }
+}
+
+// all events after `since`, the driver limits how many it sends at once
+fn latest<C: MonitorControl>(control: &mut C, since: u64) -> Result<Vec<Event>, C::Error> {
+    let mut events = Vec::new();
+
+    loop {
+        let seq = events.last().map_or(since, |event: &Event| event.seq);
+        let new = control.events(seq)?;
+        if new.is_empty() {
+            return Ok(events);
+        }
+
+        events.extend(new);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ActiveMode, MockMonitorControl, Mode};
+
+    fn monitor(id: Id, name: &str) -> Monitor {
+        Monitor {
+            name: Some(name.to_owned()),
+            ..Monitor::new(
+                id,
+                vec![Mode {
+                    width: 1920,
+                    height: 1080,
+                    refresh_rates: vec![60],
+                }],
+            )
+        }
+    }
+
+    fn event(seq: u64, kind: EventKind) -> Event {
+        Event {
+            seq,
+            timestamp: 0,
+            kind,
+        }
+    }
+
+    #[test]
+    fn load_and_update() {
+        let mut control = MockMonitorControl::new();
+        control.notify(vec![monitor(0, "a")]).unwrap();
+
+        let mut cache = MonitorCache::load(&mut control).unwrap();
+        assert_eq!(cache.seq(), 1);
+        assert_eq!(cache.monitors(), [monitor(0, "a")]);
+        assert!(!cache.update(&mut control).unwrap());
+
+        control
+            .notify(vec![monitor(0, "b"), monitor(1, "c")])
+            .unwrap();
+        assert!(cache.update(&mut control).unwrap());
+        assert_eq!(cache.monitors(), [monitor(0, "b"), monitor(1, "c")]);
+        assert_eq!(cache.seq(), 3);
+    }
+
+    #[test]
+    fn diff_since() {
+        let mut cache = MonitorCache::default();
+        cache.apply(event(
+            1,
+            EventKind::MonitorAdded {
+                monitor: monitor(0, "a"),
+            },
+        ));
+        cache.apply(event(
+            2,
+            EventKind::MonitorAdded {
+                monitor: monitor(1, "b"),
+            },
+        ));
+        let seen = cache.seq();
+
+        assert!(cache.diff_since(seen).is_empty());
+
+        cache.apply(event(3, EventKind::MonitorRemoved { id: 0 }));
+        cache.apply(event(
+            4,
+            EventKind::MonitorChanged {
+                monitor: monitor(1, "c"),
+                changed: vec!["name".to_owned()],
+            },
+        ));
+
+        let diff = cache.diff_since(seen);
+        assert_eq!(diff.seq, 4);
+        assert_eq!(diff.changed, [monitor(1, "c")]);
+        assert_eq!(diff.removed, [0]);
+
+        assert!(cache.diff_since(diff.seq).is_empty());
+        assert_eq!(cache.diff_since(0).changed, [monitor(1, "c")]);
+    }
+
+    #[test]
+    fn ignores_old_events() {
+        let mut cache = MonitorCache::default();
+        assert!(cache.apply(event(
+            2,
+            EventKind::MonitorAdded {
+                monitor: monitor(0, "a")
+            }
+        )));
+        assert!(!cache.apply(event(1, EventKind::MonitorRemoved { id: 0 })));
+        assert!(!cache.apply(event(
+            3,
+            EventKind::SwapChainStalled {
+                id: 0,
+                stalled_ms: 0
+            }
+        )));
+
+        assert_eq!(cache.monitors(), [monitor(0, "a")]);
+        assert_eq!(cache.seq(), 3);
+    }
+
+    #[test]
+    fn active_mode_changes() {
+        let mut cache = MonitorCache::default();
+        cache.apply(event(
+            1,
+            EventKind::MonitorAdded {
+                monitor: monitor(0, "a"),
+            },
+        ));
+
+        let mode = ActiveMode {
+            width: 1280,
+            height: 720,
+            refresh_rate: 60,
+        };
+        assert!(cache.apply(event(
+            2,
+            EventKind::ActiveModeChanged {
+                id: 0,
+                mode: Some(mode)
+            }
+        )));
+        assert!(!cache.apply(event(3, EventKind::ActiveModeChanged { id: 1, mode: None })));
+
+        assert_eq!(cache.monitors()[0].active_mode, Some(mode));
+        assert_eq!(cache.diff_since(1).changed.len(), 1);
+    }
+
+    #[test]
+    fn reloads_after_missed_events() {
+        let mut control = MockMonitorControl::with_monitors(vec![monitor(0, "a")]);
+        let mut cache = MonitorCache::load(&mut control).unwrap();
+        let seen = cache.seq();
+
+        control.remove(vec![0]).unwrap();
+        control.notify(vec![monitor(1, "b")]).unwrap();
+        control.drop_events();
+        control.push_event(EventKind::SwapChainStalled {
+            id: 1,
+            stalled_ms: 0,
+        });
+
+        assert!(cache.update(&mut control).unwrap());
+        assert_eq!(cache.monitors(), [monitor(1, "b")]);
+        assert_eq!(cache.seq(), 3);
+
+        let diff = cache.diff_since(seen);
+        assert_eq!(diff.changed, [monitor(1, "b")]);
+        assert_eq!(diff.removed, [0]);
+    }
+}
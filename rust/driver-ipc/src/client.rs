@@ -0,0 +1,220 @@
+use std::{
+    io::{self, BufRead as _, BufReader, Write as _},
+    net::{Shutdown, TcpStream, ToSocketAddrs},
+};
+
+#[cfg(windows)]
+use win_pipes::{NamedPipeClientOptions, NamedPipeClientReader, NamedPipeClientWriter};
+
+#[cfg(feature = "remote")]
+use crate::remote::{RemoteReader, RemoteWriter};
+#[cfg(windows)]
+use crate::IoctlClient;
+use crate::{Command, Event, Id, Monitor, MonitorControl, MonitorDiff};
+
+/// Connection to a driver instance, over any of the ways it takes json [`Command`]s
+///
+/// The driver pipe only accepts a single client at a time, so clients that stay around, like
+/// services, should only connect while they need to, to not lock out others.
+pub struct Client {
+    transport: Transport,
+}
+
+enum Transport {
+    #[cfg(windows)]
+    Pipe {
+        reader: NamedPipeClientReader,
+        writer: NamedPipeClientWriter,
+    },
+    #[cfg(windows)]
+    Ioctl(IoctlClient),
+    // newline delimited json, as served by `vdd-emulator --listen`
+    Tcp {
+        reader: BufReader<TcpStream>,
+        writer: TcpStream,
+    },
+    // through `vdd-server --agent` on another machine, boxed as its halves carry the session keys
+    #[cfg(feature = "remote")]
+    Remote {
+        reader: Box<RemoteReader>,
+        writer: Box<RemoteWriter>,
+    },
+}
+
+impl Client {
+    /// Connect to the pipe `pipe_name`, waiting for it if another client is connected
+    #[cfg(windows)]
+    pub fn connect(pipe_name: &str) -> io::Result<Self> {
+        let (reader, writer) = NamedPipeClientOptions::new(pipe_name)
+            .wait()
+            .access_duplex()
+            .mode_message()
+            .create()
+            .map_err(io::Error::other)?;
+
+        Ok(Self {
+            transport: Transport::Pipe { reader, writer },
+        })
+    }
+
+    /// Connect over device control requests on the adapter's device interface at
+    /// `interface_path` instead of the pipe, see [`IoctlClient`]
+    #[cfg(windows)]
+    pub fn connect_ioctl(interface_path: &str) -> io::Result<Self> {
+        Ok(Self {
+            transport: Transport::Ioctl(IoctlClient::open(interface_path)?),
+        })
+    }
+
+    /// Connect to `vdd-emulator --listen` at `addr`, which also serves on other platforms than
+    /// Windows
+    pub fn connect_tcp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let writer = TcpStream::connect(addr)?;
+        let reader = BufReader::new(writer.try_clone()?);
+
+        Ok(Self {
+            transport: Transport::Tcp { reader, writer },
+        })
+    }
+
+    /// Use a connection to `vdd-server --agent`, made with [`crate::remote::connect`]
+    #[cfg(feature = "remote")]
+    #[must_use]
+    pub fn remote(reader: RemoteReader, writer: RemoteWriter) -> Self {
+        Self {
+            transport: Transport::Remote {
+                reader: Box::new(reader),
+                writer: Box::new(writer),
+            },
+        }
+    }
+
+    /// Send a command without a reply
+    pub fn send(&mut self, command: &Command) -> io::Result<()> {
+        let message = serde_json::to_vec(command)?;
+
+        match &mut self.transport {
+            #[cfg(windows)]
+            Transport::Pipe { writer, .. } => {
+                // the pipe is in message mode, so the whole command must go out in a single write
+                writer.write_all(&message)?;
+                writer.flush()
+            }
+            #[cfg(windows)]
+            Transport::Ioctl(ioctl) => ioctl.request(&message).map(drop),
+            Transport::Tcp { writer, .. } => write_line(writer, message),
+            #[cfg(feature = "remote")]
+            Transport::Remote { writer, .. } => writer.write_message(&message),
+        }
+    }
+
+    /// Send a command and wait for its reply
+    pub fn request(&mut self, command: &Command) -> io::Result<Command> {
+        let message = serde_json::to_vec(command)?;
+
+        let reply = match &mut self.transport {
+            #[cfg(windows)]
+            Transport::Pipe { reader, writer } => {
+                writer.write_all(&message)?;
+                writer.flush()?;
+                reader.read_full().map_err(io::Error::other)?
+            }
+            #[cfg(windows)]
+            Transport::Ioctl(ioctl) => ioctl.request(&message)?,
+            Transport::Tcp { reader, writer } => {
+                write_line(writer, message)?;
+
+                let mut reply = Vec::new();
+                if reader.read_until(b'\n', &mut reply)? == 0 {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "the server closed the connection",
+                    ));
+                }
+
+                reply
+            }
+            #[cfg(feature = "remote")]
+            Transport::Remote { reader, writer } => {
+                writer.write_message(&message)?;
+                reader.read_message()?.ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "the agent closed the connection",
+                    )
+                })?
+            }
+        };
+
+        Ok(serde_json::from_slice(&reply)?)
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        // wait for the server to close its end too, so commands without a reply took effect once
+        // the client is gone. Tcp is the only transport off Windows without `remote`
+        #[allow(irrefutable_let_patterns)]
+        if let Transport::Tcp { reader, writer } = &mut self.transport {
+            if writer.shutdown(Shutdown::Write).is_ok() {
+                _ = io::copy(reader, &mut io::sink());
+            }
+        }
+    }
+}
+
+// json never contains raw newlines, so they delimit messages
+fn write_line(writer: &mut TcpStream, mut message: Vec<u8>) -> io::Result<()> {
+    message.push(b'\n');
+    writer.write_all(&message)
+}
+
+fn unexpected_reply() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "received unexpected reply from driver",
+    )
+}
+
+impl MonitorControl for Client {
+    type Error = io::Error;
+
+    fn list(&mut self) -> io::Result<Vec<Monitor>> {
+        let Command::ReplyState(monitors) = self.request(&Command::RequestState)? else {
+            return Err(unexpected_reply());
+        };
+
+        Ok(monitors)
+    }
+
+    fn get(&mut self, id: Id) -> io::Result<Option<Monitor>> {
+        let Command::ReplyMonitor(monitor) = self.request(&Command::RequestMonitor(id.into()))?
+        else {
+            return Err(unexpected_reply());
+        };
+
+        Ok(monitor)
+    }
+
+    fn notify(&mut self, monitors: Vec<Monitor>) -> io::Result<Vec<MonitorDiff>> {
+        let Command::ReplyNotify(diffs) = self.request(&Command::RequestNotify(monitors))? else {
+            return Err(unexpected_reply());
+        };
+
+        Ok(diffs)
+    }
+
+    fn remove(&mut self, ids: Vec<Id>) -> io::Result<()> {
+        self.send(&Command::DriverRemove(
+            ids.into_iter().map(Into::into).collect(),
+        ))
+    }
+
+    fn events(&mut self, since: u64) -> io::Result<Vec<Event>> {
+        let Command::ReplyEvents(events) = self.request(&Command::RequestEvents(since))? else {
+            return Err(unexpected_reply());
+        };
+
+        Ok(events)
+    }
+}
@@ -0,0 +1,299 @@
+use std::{
+    error::Error,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Event, EventKind, Id, Monitor, MonitorDiff};
+
+/// Control over the monitors of a driver instance
+///
+/// Implemented by clients talking to the driver, and by [`MockMonitorControl`], so code written
+/// against it can be tested without a driver.
+pub trait MonitorControl {
+    type Error;
+
+    /// Get the current monitors
+    fn list(&mut self) -> Result<Vec<Monitor>, Self::Error>;
+
+    /// Get a single monitor, without transferring the others
+    fn get(&mut self, id: Id) -> Result<Option<Monitor>, Self::Error>;
+
+    /// Add or update monitors, returning what was done to apply each of them
+    fn notify(&mut self, monitors: Vec<Monitor>) -> Result<Vec<MonitorDiff>, Self::Error>;
+
+    /// Remove monitors, ids that don't exist are ignored
+    fn remove(&mut self, ids: Vec<Id>) -> Result<(), Self::Error>;
+
+    /// Get the events with a sequence number greater than `since`
+    fn events(&mut self, since: u64) -> Result<Vec<Event>, Self::Error>;
+}
+
+/// A call made to a [`MockMonitorControl`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockCall {
+    List,
+    Get(Id),
+    Notify(Vec<Monitor>),
+    Remove(Vec<Id>),
+    Events(u64),
+}
+
+/// Failure of a [`MockMonitorControl`] call, see [`MockMonitorControl::fail_next`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockError(pub String);
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Error for MockError {}
+
+/// In-memory [`MonitorControl`], which records the calls made to it
+///
+/// Monitors are only stored, so the diffs it returns never have os operations. For the driver's
+/// rules of plugging monitors in, use `vdd-emulator` instead. Added, changed and removed monitors
+/// are recorded as events, like the driver does.
+#[derive(Debug, Default)]
+pub struct MockMonitorControl {
+    monitors: Vec<Monitor>,
+    events: Vec<Event>,
+    event_seq: u64,
+    calls: Vec<MockCall>,
+    failure: Option<MockError>,
+}
+
+impl MockMonitorControl {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start with `monitors`, like a driver that restored them
+    #[must_use]
+    pub fn with_monitors(monitors: Vec<Monitor>) -> Self {
+        Self {
+            monitors,
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn monitors(&self) -> &[Monitor] {
+        &self.monitors
+    }
+
+    /// Calls made so far, oldest first
+    #[must_use]
+    pub fn calls(&self) -> &[MockCall] {
+        &self.calls
+    }
+
+    /// Record an event, returning its sequence number
+    pub fn push_event(&mut self, kind: EventKind) -> u64 {
+        self.event_seq += 1;
+        let seq = self.event_seq;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_millis() as u64);
+
+        self.events.push(Event {
+            seq,
+            timestamp,
+            kind,
+        });
+
+        seq
+    }
+
+    /// Forget the events recorded so far, like a driver that dropped them before a client saw
+    /// them. Sequence numbers aren't reused
+    pub fn drop_events(&mut self) {
+        self.events.clear();
+    }
+
+    /// Make the next call fail with `message`, without changing anything
+    pub fn fail_next(&mut self, message: impl Into<String>) {
+        self.failure = Some(MockError(message.into()));
+    }
+
+    fn call(&mut self, call: MockCall) -> Result<(), MockError> {
+        self.calls.push(call);
+
+        match self.failure.take() {
+            Some(failure) => Err(failure),
+            None => Ok(()),
+        }
+    }
+}
+
+impl MonitorControl for MockMonitorControl {
+    type Error = MockError;
+
+    fn list(&mut self) -> Result<Vec<Monitor>, Self::Error> {
+        self.call(MockCall::List)?;
+
+        Ok(self.monitors.clone())
+    }
+
+    fn get(&mut self, id: Id) -> Result<Option<Monitor>, Self::Error> {
+        self.call(MockCall::Get(id))?;
+
+        Ok(self
+            .monitors
+            .iter()
+            .find(|monitor| monitor.id == id)
+            .cloned())
+    }
+
+    fn notify(&mut self, monitors: Vec<Monitor>) -> Result<Vec<MonitorDiff>, Self::Error> {
+        self.call(MockCall::Notify(monitors.clone()))?;
+
+        let diffs = monitors
+            .into_iter()
+            .map(|monitor| {
+                let id = monitor.id;
+
+                if let Some(existing) = self.monitors.iter_mut().find(|m| m.id == id) {
+                    let changed = existing
+                        .changed_fields(&monitor)
+                        .into_iter()
+                        .map(str::to_owned)
+                        .collect::<Vec<_>>();
+                    if !changed.is_empty() {
+                        *existing = monitor.clone();
+                        self.push_event(EventKind::MonitorChanged {
+                            monitor,
+                            changed: changed.clone(),
+                        });
+                    }
+
+                    MonitorDiff {
+                        id,
+                        created: false,
+                        changed,
+                        operations: Vec::new(),
+                    }
+                } else {
+                    self.monitors.push(monitor.clone());
+                    self.push_event(EventKind::MonitorAdded { monitor });
+
+                    MonitorDiff {
+                        id,
+                        created: true,
+                        changed: Vec::new(),
+                        operations: Vec::new(),
+                    }
+                }
+            })
+            .collect();
+
+        Ok(diffs)
+    }
+
+    fn remove(&mut self, ids: Vec<Id>) -> Result<(), Self::Error> {
+        self.call(MockCall::Remove(ids.clone()))?;
+
+        for id in ids {
+            if let Some(index) = self.monitors.iter().position(|monitor| monitor.id == id) {
+                self.monitors.remove(index);
+                self.push_event(EventKind::MonitorRemoved { id });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn events(&mut self, since: u64) -> Result<Vec<Event>, Self::Error> {
+        self.call(MockCall::Events(since))?;
+
+        Ok(self
+            .events
+            .iter()
+            .filter(|event| event.seq > since)
+            .cloned()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Mode;
+
+    fn monitor(id: Id) -> Monitor {
+        Monitor::new(
+            id,
+            vec![Mode {
+                width: 1920,
+                height: 1080,
+                refresh_rates: vec![60],
+            }],
+        )
+    }
+
+    #[test]
+    fn notify_adds_and_updates() {
+        let mut control = MockMonitorControl::with_monitors(vec![monitor(0)]);
+
+        let renamed = Monitor {
+            name: Some("desk".to_owned()),
+            ..monitor(0)
+        };
+        let diffs = control.notify(vec![renamed, monitor(1)]).unwrap();
+
+        assert!(!diffs[0].created);
+        assert_eq!(diffs[0].changed, ["name"]);
+        assert!(diffs[1].created);
+        assert_eq!(control.list().unwrap().len(), 2);
+        assert_eq!(control.monitors()[0].name.as_deref(), Some("desk"));
+    }
+
+    #[test]
+    fn remove_and_record_calls() {
+        let mut control = MockMonitorControl::with_monitors(vec![monitor(0), monitor(1)]);
+
+        control.remove(vec![0, 5]).unwrap();
+        assert_eq!(control.get(0).unwrap(), None);
+        assert_eq!(control.get(1).unwrap(), Some(monitor(1)));
+
+        assert_eq!(control.monitors(), [monitor(1)]);
+        assert_eq!(
+            control.calls(),
+            [
+                MockCall::Remove(vec![0, 5]),
+                MockCall::Get(0),
+                MockCall::Get(1)
+            ]
+        );
+    }
+
+    #[test]
+    fn events_since() {
+        let mut control = MockMonitorControl::new();
+
+        for id in 0..3 {
+            control.push_event(EventKind::SwapChainStalled { id, stalled_ms: 0 });
+        }
+
+        let seqs = |events: Vec<Event>| events.iter().map(|e| e.seq).collect::<Vec<_>>();
+        assert_eq!(seqs(control.events(0).unwrap()), [1, 2, 3]);
+        assert_eq!(seqs(control.events(2).unwrap()), [3]);
+    }
+
+    #[test]
+    fn fail_next() {
+        let mut control = MockMonitorControl::new();
+
+        control.fail_next("pipe closed");
+        let error = control.notify(vec![monitor(0)]).unwrap_err();
+
+        assert_eq!(error.to_string(), "pipe closed");
+        assert!(control.monitors().is_empty());
+        assert!(control.list().is_ok());
+    }
+}
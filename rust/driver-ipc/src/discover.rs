@@ -0,0 +1,97 @@
+use std::io;
+
+use serde::{Deserialize, Serialize};
+
+use windows::{
+    core::{GUID, PCWSTR},
+    Win32::Devices::DeviceAndDriverInstallation::{
+        CM_Get_Device_Interface_ListW, CM_Get_Device_Interface_List_SizeW,
+        CM_GET_DEVICE_INTERFACE_LIST_PRESENT, CR_BUFFER_SMALL, CR_SUCCESS,
+    },
+};
+
+use crate::{DEFAULT_PIPE_NAME, DEVICE_INTERFACE_GUID};
+
+/// An installed and running driver instance
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Instance {
+    /// Symbolic link of the adapter's device interface
+    pub interface_path: String,
+    /// Name of the pipe to connect to, without the `\\.\pipe\` prefix
+    pub pipe_name: String,
+}
+
+/// Enumerate the running driver instances, by their device interface
+///
+/// Every virtual adapter (including embedded or branded builds of the driver) registers the
+/// [`DEVICE_INTERFACE_GUID`] interface, so this finds all of them instead of assuming a single
+/// well-known pipe. Instances are returned in the order the os lists them.
+pub fn discover() -> io::Result<Vec<Instance>> {
+    let guid = GUID::from_u128(DEVICE_INTERFACE_GUID);
+
+    // the list can grow between querying its size and reading it, so retry until it fits
+    let list = loop {
+        let mut len = 0u32;
+        // SAFETY: all pointers are valid for the duration of the call
+        let cr = unsafe {
+            CM_Get_Device_Interface_List_SizeW(
+                &mut len,
+                &guid,
+                PCWSTR::null(),
+                CM_GET_DEVICE_INTERFACE_LIST_PRESENT,
+            )
+        };
+        if cr != CR_SUCCESS {
+            return Err(io::Error::other(format!(
+                "failed to get device interface list size: {cr:?}"
+            )));
+        }
+
+        let mut buffer = vec![0u16; len as usize];
+        // SAFETY: as above, and the buffer length is passed along with it
+        let cr = unsafe {
+            CM_Get_Device_Interface_ListW(
+                &guid,
+                PCWSTR::null(),
+                &mut buffer,
+                CM_GET_DEVICE_INTERFACE_LIST_PRESENT,
+            )
+        };
+
+        match cr {
+            CR_SUCCESS => break buffer,
+            CR_BUFFER_SMALL => continue,
+            _ => {
+                return Err(io::Error::other(format!(
+                    "failed to get device interface list: {cr:?}"
+                )))
+            }
+        }
+    };
+
+    // the list is a sequence of nul terminated strings, ending with an empty one
+    let instances = list
+        .split(|&c| c == 0)
+        .take_while(|path| !path.is_empty())
+        .map(|path| {
+            let interface_path = String::from_utf16_lossy(path);
+
+            Instance {
+                pipe_name: pipe_name(&interface_path),
+                interface_path,
+            }
+        })
+        .collect();
+
+    Ok(instances)
+}
+
+/// The pipe name is the interface's reference string, the part after the interface guid
+fn pipe_name(interface_path: &str) -> String {
+    interface_path
+        .rsplit_once("}\\")
+        .map(|(_, reference)| reference)
+        .filter(|reference| !reference.is_empty())
+        .unwrap_or(DEFAULT_PIPE_NAME)
+        .to_owned()
+}
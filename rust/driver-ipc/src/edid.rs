@@ -0,0 +1,1182 @@
+//! Building and parsing EDIDs, which describe a monitor to the os when it's plugged in
+//!
+//! An [`Edid`] is the base block of EDID 1.3 with its extensions, in fields instead of bytes.
+//! [`EdidBuilder`] starts from the EDID the driver generates and changes parts of it, and
+//! [`Edid::to_bytes`] checks that everything fits before writing the bytes. [`Edid::parse`] reads
+//! them back, as well as the EDIDs of other displays: CTA-861 extensions are read into their data
+//! blocks, other extensions and unknown descriptors are kept as bytes.
+
+use std::{error::Error, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::EDID_NAME_LEN;
+
+/// Length of the base block and of every extension block
+pub const BLOCK_LEN: usize = 128;
+/// First 8 bytes of every EDID
+pub const HEADER: [u8; 8] = [0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+/// Descriptors in the base block
+pub const DESCRIPTORS: usize = 4;
+
+const DESCRIPTORS_START: usize = 54;
+const DESCRIPTOR_LEN: usize = 18;
+// bytes of text or data in a display descriptor, after its 5 byte header
+const DESCRIPTOR_DATA_LEN: usize = 13;
+const CTA_EXTENSION_TAG: u8 = 0x02;
+// data blocks and timings of a CTA-861 extension, between its header and checksum
+const CTA_PAYLOAD_LEN: usize = BLOCK_LEN - 5;
+const DATA_BLOCK_MAX_LEN: usize = 0x1F;
+// sample rates of short audio descriptors, by bit
+const SAMPLE_RATES: [u32; 7] = [32000, 44100, 48000, 88200, 96000, 176_400, 192_000];
+
+const NAME_TAG: u8 = 0xFC;
+const SERIAL_TAG: u8 = 0xFF;
+const TEXT_TAG: u8 = 0xFE;
+const RANGE_LIMITS_TAG: u8 = 0xFD;
+// descriptor filling the slots of the base block nothing else uses
+const DUMMY_TAG: u8 = 0x10;
+
+// IEEE OUIs of vendor specific data blocks, most significant byte first
+const HDMI_OUI: [u8; 3] = [0x00, 0x0C, 0x03];
+const MICROSOFT_OUI: [u8; 3] = [0xCA, 0x12, 0x5C];
+
+/// A parsed or built EDID
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Edid {
+    /// Three letter PNP id of the manufacturer, e.g. `DEL`
+    pub vendor: String,
+    pub product: u16,
+    pub serial: u32,
+    /// Week of manufacture, 0 if not given, 0xFF if `year` is the model year
+    pub week: u8,
+    pub year: u16,
+    pub version: (u8, u8),
+    /// Video input definition, bit 7 set for digital inputs
+    pub input: u8,
+    /// Width and height of the screen in centimeters, 0 if not given
+    pub size_cm: (u8, u8),
+    /// Gamma times 100, less 100, e.g. 120 for 2.2
+    pub gamma: u8,
+    /// Supported features, such as power management and the color type
+    pub features: u8,
+    pub chromaticity: Chromaticity,
+    /// Bitmap of the supported VESA modes of old, such as 640x480@60
+    pub established_timings: [u8; 3],
+    /// Two bytes per mode, `[1, 1]` for unused ones
+    pub standard_timings: [[u8; 2]; 8],
+    /// At most [`DESCRIPTORS`], the first one being the preferred timing. Missing ones are
+    /// written as dummy descriptors
+    pub descriptors: Vec<Descriptor>,
+    pub extensions: Vec<Extension>,
+}
+
+/// Coordinates of the primaries and the white point in the CIE 1931 color space, in 1024ths
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct Chromaticity {
+    pub red: (u16, u16),
+    pub green: (u16, u16),
+    pub blue: (u16, u16),
+    pub white: (u16, u16),
+}
+
+impl Chromaticity {
+    /// BT.709 primaries with a D65 white point, like most monitors
+    pub const SRGB: Self = Self {
+        red: (655, 338),
+        green: (307, 614),
+        blue: (154, 61),
+        white: (321, 337),
+    };
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Descriptor {
+    Timing(DetailedTiming),
+    /// Display product name, at most [`EDID_NAME_LEN`] printable ascii characters
+    Name(String),
+    /// Serial number as text, like the name
+    Serial(String),
+    /// Unspecified text, like the name
+    Text(String),
+    RangeLimits(RangeLimits),
+    /// A display descriptor of another type, by its tag, with its 13 bytes of data
+    Other {
+        tag: u8,
+        data: Vec<u8>,
+    },
+}
+
+/// A detailed timing descriptor
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DetailedTiming {
+    /// Written in steps of 10 kHz
+    pub pixel_clock_khz: u32,
+    pub width: u16,
+    pub height: u16,
+    pub h_blank: u16,
+    pub v_blank: u16,
+    pub h_sync_offset: u16,
+    pub h_sync_width: u16,
+    pub v_sync_offset: u8,
+    pub v_sync_width: u8,
+    /// Size of the picture in millimeters, 0 if not given
+    pub size_mm: (u16, u16),
+    pub border: (u8, u8),
+    /// Interlacing, stereo and sync, e.g. 0x1E for digital separate sync with positive polarities
+    pub flags: u8,
+}
+
+/// Range of refresh rates and pixel clocks a monitor supports
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RangeLimits {
+    pub vertical_hz: (u8, u8),
+    pub horizontal_khz: (u8, u8),
+    /// Written in steps of 10 MHz
+    pub max_pixel_clock_mhz: u16,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum Extension {
+    Cta(CtaExtension),
+    /// An extension of another type, as its 128 bytes, the first being its tag
+    Other(Vec<u8>),
+}
+
+/// A CTA-861 extension block, which holds what HDMI monitors support beyond the base block
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct CtaExtension {
+    pub revision: u8,
+    /// Underscan, audio and YCbCr support in the upper 4 bits, see [`Self::BASIC_AUDIO`], the
+    /// number of native timings in the lower ones
+    pub flags: u8,
+    pub data_blocks: Vec<DataBlock>,
+    pub timings: Vec<DetailedTiming>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DataBlock {
+    Audio(Vec<AudioFormat>),
+    /// Video identification codes of the supported modes, bit 7 marking native ones
+    Video(Vec<u8>),
+    /// `oui` identifies the vendor, most significant byte first, e.g. `00-0C-03` for HDMI
+    VendorSpecific {
+        oui: [u8; 3],
+        payload: Vec<u8>,
+    },
+    /// Bits of the present speakers
+    SpeakerAllocation([u8; 3]),
+    /// A data block of another type, by its tag. Extended data blocks (tag 7) carry their
+    /// extended tag first
+    Other {
+        tag: u8,
+        payload: Vec<u8>,
+    },
+}
+
+/// A short audio descriptor
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct AudioFormat {
+    /// Audio format code, 1 for LPCM
+    pub format: u8,
+    pub channels: u8,
+    /// In Hz, 32 to 192 kHz
+    pub sample_rates: Vec<u32>,
+    /// Bit depths of LPCM (bit 0 for 16 bits, 1 for 20, 2 for 24), specific to the format
+    /// otherwise
+    pub details: u8,
+}
+
+/// What doesn't fit into an EDID, or what isn't one
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EdidError {
+    /// Not a whole number of blocks, by the length
+    Length(usize),
+    /// The first bytes aren't [`HEADER`]
+    Header,
+    /// Not three letters from A to Z
+    Vendor(String),
+    /// More than [`DESCRIPTORS`], by their number
+    TooManyDescriptors(usize),
+    /// More than [`EDID_NAME_LEN`] characters, or not printable ascii
+    Text(String),
+    /// A value of a detailed timing is too large for its field, e.g. a pixel clock above
+    /// 655.35 MHz, by the timing's size
+    Timing(u16, u16),
+    /// More than 2550 MHz, by its value
+    PixelClock(u16),
+    /// The data blocks and timings of a CTA-861 extension are larger than its block, or a data
+    /// block larger than 31 bytes, by the bytes they need
+    ExtensionFull(usize),
+    /// An extension of another type isn't a whole block, by its length
+    ExtensionLength(usize),
+    /// More than 255 extensions
+    TooManyExtensions,
+}
+
+impl fmt::Display for EdidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Length(len) => {
+                write!(
+                    f,
+                    "an EDID is made of {BLOCK_LEN} byte blocks, got {len} bytes"
+                )
+            }
+            Self::Header => f.write_str("the EDID doesn't start with the EDID header"),
+            Self::Vendor(vendor) => write!(f, "vendor {vendor:?} isn't three letters from A to Z"),
+            Self::TooManyDescriptors(count) => write!(
+                f,
+                "an EDID holds {DESCRIPTORS} descriptors, {count} were given"
+            ),
+            Self::Text(text) => write!(
+                f,
+                "{text:?} isn't at most {EDID_NAME_LEN} printable ASCII characters"
+            ),
+            Self::Timing(width, height) => write!(
+                f,
+                "timing {width}x{height} doesn't fit into a detailed timing descriptor"
+            ),
+            Self::PixelClock(mhz) => write!(f, "pixel clock limit {mhz} MHz is above 2550 MHz"),
+            Self::ExtensionFull(len) => write!(
+                f,
+                "the data blocks and timings of a CTA-861 extension need {len} bytes, which is \
+                 more than it holds"
+            ),
+            Self::ExtensionLength(len) => {
+                write!(
+                    f,
+                    "an extension block has {len} bytes instead of {BLOCK_LEN}"
+                )
+            }
+            Self::TooManyExtensions => f.write_str("an EDID has at most 255 extensions"),
+        }
+    }
+}
+
+impl Error for EdidError {}
+
+impl Edid {
+    /// Read an EDID, which wrong checksums don't keep from, see [`bad_checksums`]
+    ///
+    /// # Errors
+    ///
+    /// If it isn't made of whole blocks or doesn't start with [`HEADER`]
+    pub fn parse(bytes: &[u8]) -> Result<Self, EdidError> {
+        if bytes.is_empty() || bytes.len() % BLOCK_LEN != 0 {
+            return Err(EdidError::Length(bytes.len()));
+        }
+        if bytes[..HEADER.len()] != HEADER {
+            return Err(EdidError::Header);
+        }
+
+        let mut blocks = bytes.chunks_exact(BLOCK_LEN);
+        let base = blocks.next().unwrap_or_default();
+
+        // 5 bits per letter, 1 is 'A'
+        let id = u16::from_be_bytes([base[8], base[9]]);
+        #[allow(clippy::cast_possible_truncation)]
+        let vendor = [10, 5, 0]
+            .into_iter()
+            .map(|shift| char::from(b'A' - 1 + ((id >> shift) & 0x1F) as u8))
+            .collect();
+
+        let lows = [base[25], base[26]];
+        // the lower 2 bits of each coordinate come first, in the order of the upper 8 bits
+        let coordinate = |index: usize| {
+            let low = (lows[index / 4] >> (6 - 2 * (index % 4))) & 0x3;
+            (u16::from(base[27 + index]) << 2) | u16::from(low)
+        };
+        let point = |index: usize| (coordinate(index), coordinate(index + 1));
+
+        let mut standard_timings = [[0; 2]; 8];
+        for (timing, bytes) in standard_timings
+            .iter_mut()
+            .zip(base[38..54].chunks_exact(2))
+        {
+            timing.copy_from_slice(bytes);
+        }
+
+        let descriptors = base[DESCRIPTORS_START..DESCRIPTORS_START + DESCRIPTORS * DESCRIPTOR_LEN]
+            .chunks_exact(DESCRIPTOR_LEN)
+            .filter_map(Descriptor::parse)
+            .collect();
+
+        Ok(Self {
+            vendor,
+            product: u16::from_le_bytes([base[10], base[11]]),
+            serial: u32::from_le_bytes([base[12], base[13], base[14], base[15]]),
+            week: base[16],
+            year: 1990 + u16::from(base[17]),
+            version: (base[18], base[19]),
+            input: base[20],
+            size_cm: (base[21], base[22]),
+            gamma: base[23],
+            features: base[24],
+            chromaticity: Chromaticity {
+                red: point(0),
+                green: point(2),
+                blue: point(4),
+                white: point(6),
+            },
+            established_timings: [base[35], base[36], base[37]],
+            standard_timings,
+            descriptors,
+            extensions: blocks.map(Extension::parse).collect(),
+        })
+    }
+
+    /// Write the EDID, with the checksums of all blocks
+    ///
+    /// # Errors
+    ///
+    /// If something doesn't fit, see [`EdidError`]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EdidError> {
+        let mut base = [0; BLOCK_LEN];
+        base[..HEADER.len()].copy_from_slice(&HEADER);
+
+        let letters = self.vendor.as_bytes();
+        if letters.len() != 3 || !letters.iter().all(u8::is_ascii_uppercase) {
+            return Err(EdidError::Vendor(self.vendor.clone()));
+        }
+        let id = letters
+            .iter()
+            .fold(0, |id, letter| id << 5 | u16::from(letter - b'A' + 1));
+        base[8..10].copy_from_slice(&id.to_be_bytes());
+
+        base[10..12].copy_from_slice(&self.product.to_le_bytes());
+        base[12..16].copy_from_slice(&self.serial.to_le_bytes());
+        base[16] = self.week;
+        base[17] = u8::try_from(self.year.saturating_sub(1990)).unwrap_or(u8::MAX);
+        (base[18], base[19]) = self.version;
+        base[20] = self.input;
+        (base[21], base[22]) = self.size_cm;
+        base[23] = self.gamma;
+        base[24] = self.features;
+
+        let Chromaticity {
+            red,
+            green,
+            blue,
+            white,
+        } = self.chromaticity;
+        let coordinates = [
+            red.0, red.1, green.0, green.1, blue.0, blue.1, white.0, white.1,
+        ];
+        for (index, coordinate) in coordinates.into_iter().enumerate() {
+            // 10 bits each
+            #[allow(clippy::cast_possible_truncation)]
+            let (high, low) = ((coordinate >> 2) as u8, (coordinate & 0x3) as u8);
+            base[27 + index] = high;
+            base[25 + index / 4] |= low << (6 - 2 * (index % 4));
+        }
+
+        base[35..38].copy_from_slice(&self.established_timings);
+        for (bytes, timing) in base[38..54].chunks_exact_mut(2).zip(self.standard_timings) {
+            bytes.copy_from_slice(&timing);
+        }
+
+        if self.descriptors.len() > DESCRIPTORS {
+            return Err(EdidError::TooManyDescriptors(self.descriptors.len()));
+        }
+        let dummy = Descriptor::Other {
+            tag: DUMMY_TAG,
+            data: Vec::new(),
+        };
+        let descriptors = self
+            .descriptors
+            .iter()
+            .chain(std::iter::repeat(&dummy))
+            .zip(base[DESCRIPTORS_START..].chunks_exact_mut(DESCRIPTOR_LEN));
+        for (descriptor, bytes) in descriptors {
+            bytes.copy_from_slice(&descriptor.to_bytes()?);
+        }
+
+        base[126] =
+            u8::try_from(self.extensions.len()).map_err(|_| EdidError::TooManyExtensions)?;
+        set_checksum(&mut base);
+
+        let mut edid = base.to_vec();
+        for extension in &self.extensions {
+            edid.extend_from_slice(&extension.to_bytes()?);
+        }
+
+        Ok(edid)
+    }
+}
+
+/// Blocks of `edid` with a wrong checksum, the base block being 0
+#[must_use]
+pub fn bad_checksums(edid: &[u8]) -> Vec<usize> {
+    edid.chunks_exact(BLOCK_LEN)
+        .enumerate()
+        .filter(|(_, block)| block.iter().fold(0u8, |sum, &byte| sum.wrapping_add(byte)) != 0)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Set the last byte of `block` so that its bytes add up to 0
+pub fn set_checksum(block: &mut [u8; BLOCK_LEN]) {
+    let sum = block[..BLOCK_LEN - 1]
+        .iter()
+        .fold(0u8, |sum, &byte| sum.wrapping_add(byte));
+    block[BLOCK_LEN - 1] = sum.wrapping_neg();
+}
+
+impl Descriptor {
+    // `None` for dummy descriptors, which only fill unused slots
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        // display descriptors have no pixel clock
+        if bytes[..2] != [0, 0] {
+            return Some(Self::Timing(DetailedTiming::parse(bytes)));
+        }
+
+        let data = &bytes[5..];
+        let descriptor = match bytes[3] {
+            NAME_TAG => Self::Name(text(data)),
+            SERIAL_TAG => Self::Serial(text(data)),
+            TEXT_TAG => Self::Text(text(data)),
+            RANGE_LIMITS_TAG => Self::RangeLimits(RangeLimits {
+                vertical_hz: (data[0], data[1]),
+                horizontal_khz: (data[2], data[3]),
+                max_pixel_clock_mhz: u16::from(data[4]) * 10,
+            }),
+            DUMMY_TAG => return None,
+            tag => Self::Other {
+                tag,
+                data: data.to_vec(),
+            },
+        };
+
+        Some(descriptor)
+    }
+
+    fn to_bytes(&self) -> Result<[u8; DESCRIPTOR_LEN], EdidError> {
+        let (tag, data) = match self {
+            Self::Timing(timing) => return timing.to_bytes(),
+            Self::Name(name) => (NAME_TAG, text_bytes(name)?),
+            Self::Serial(serial) => (SERIAL_TAG, text_bytes(serial)?),
+            Self::Text(text) => (TEXT_TAG, text_bytes(text)?),
+            Self::RangeLimits(limits) => (RANGE_LIMITS_TAG, limits.to_bytes()?),
+            Self::Other { tag, data } => {
+                let mut bytes = [0; DESCRIPTOR_DATA_LEN];
+                let len = data.len().min(DESCRIPTOR_DATA_LEN);
+                bytes[..len].copy_from_slice(&data[..len]);
+                (*tag, bytes)
+            }
+        };
+
+        let mut bytes = [0; DESCRIPTOR_LEN];
+        bytes[3] = tag;
+        bytes[5..].copy_from_slice(&data);
+        Ok(bytes)
+    }
+}
+
+// text of a display descriptor, ended by a line feed and padded with spaces
+fn text(bytes: &[u8]) -> String {
+    let end = bytes.iter().position(|&byte| byte == b'\n');
+    let text = &bytes[..end.unwrap_or(bytes.len())];
+
+    String::from_utf8_lossy(text).trim_end().to_owned()
+}
+
+fn text_bytes(text: &str) -> Result<[u8; DESCRIPTOR_DATA_LEN], EdidError> {
+    let valid =
+        text.len() <= EDID_NAME_LEN && text.chars().all(|c| c == ' ' || c.is_ascii_graphic());
+    if !valid {
+        return Err(EdidError::Text(text.to_owned()));
+    }
+
+    let mut bytes = [b' '; DESCRIPTOR_DATA_LEN];
+    bytes[..text.len()].copy_from_slice(text.as_bytes());
+    if let Some(end) = bytes.get_mut(text.len()) {
+        *end = b'\n';
+    }
+
+    Ok(bytes)
+}
+
+impl RangeLimits {
+    fn to_bytes(self) -> Result<[u8; DESCRIPTOR_DATA_LEN], EdidError> {
+        let clock = u8::try_from(self.max_pixel_clock_mhz.div_ceil(10))
+            .map_err(|_| EdidError::PixelClock(self.max_pixel_clock_mhz))?;
+
+        // no secondary timing formula, and padding
+        let mut bytes = [b' '; DESCRIPTOR_DATA_LEN];
+        bytes[..7].copy_from_slice(&[
+            self.vertical_hz.0,
+            self.vertical_hz.1,
+            self.horizontal_khz.0,
+            self.horizontal_khz.1,
+            clock,
+            0x00,
+            b'\n',
+        ]);
+
+        Ok(bytes)
+    }
+}
+
+impl DetailedTiming {
+    /// Timing of `width`x`height` at `refresh_rate` Hz with CVT reduced blanking, which digital
+    /// monitors use to keep the pixel clock low
+    #[must_use]
+    pub fn reduced_blanking(width: u16, height: u16, refresh_rate: u32) -> Self {
+        const H_BLANK: u16 = 160;
+        const V_FRONT_PORCH: u8 = 3;
+        const MIN_V_BACK_PORCH: u32 = 6;
+        const MIN_V_BLANK_US: f64 = 460.0;
+        const CLOCK_STEP_KHZ: u32 = 250;
+
+        // the sync width tells the aspect ratio
+        let (w, h) = (u32::from(width), u32::from(height));
+        let v_sync_width = if w * 3 == h * 4 {
+            4
+        } else if w * 9 == h * 16 {
+            5
+        } else if w * 10 == h * 16 {
+            6
+        } else if w * 4 == h * 5 {
+            7
+        } else {
+            10
+        };
+
+        let refresh_rate = refresh_rate.max(1);
+        let line_us =
+            (1_000_000.0 / f64::from(refresh_rate) - MIN_V_BLANK_US) / f64::from(h.max(1));
+        // whole lines
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let v_blank_lines = (MIN_V_BLANK_US / line_us.max(1.0)) as u32 + 1;
+        let v_blank = v_blank_lines
+            .max(u32::from(V_FRONT_PORCH) + u32::from(v_sync_width) + MIN_V_BACK_PORCH);
+
+        let total = u64::from(w + u32::from(H_BLANK)) * u64::from(h + v_blank);
+        let pixel_clock_khz = total * u64::from(refresh_rate) / 1000;
+        let pixel_clock_khz =
+            u32::try_from(pixel_clock_khz).unwrap_or(u32::MAX) / CLOCK_STEP_KHZ * CLOCK_STEP_KHZ;
+
+        Self {
+            pixel_clock_khz,
+            width,
+            height,
+            h_blank: H_BLANK,
+            v_blank: u16::try_from(v_blank).unwrap_or(u16::MAX),
+            h_sync_offset: 48,
+            h_sync_width: 32,
+            v_sync_offset: V_FRONT_PORCH,
+            v_sync_width,
+            size_mm: (0, 0),
+            border: (0, 0),
+            // digital separate sync, positive horizontal and negative vertical polarity
+            flags: 0x1A,
+        }
+    }
+
+    /// Frames per second
+    #[must_use]
+    pub fn refresh_rate(&self) -> f64 {
+        let total = (u32::from(self.width) + u32::from(self.h_blank))
+            * (u32::from(self.height) + u32::from(self.v_blank));
+        if total == 0 {
+            return 0.0;
+        }
+
+        f64::from(self.pixel_clock_khz) * 1000.0 / f64::from(total)
+    }
+
+    /// Whether the timing fits into a descriptor, which holds pixel clocks up to 655.35 MHz and
+    /// sizes below 4096
+    #[must_use]
+    pub fn fits(&self) -> bool {
+        self.pixel_clock_khz / 10 <= u32::from(u16::MAX)
+            && [self.width, self.height, self.h_blank, self.v_blank]
+                .iter()
+                .chain([&self.size_mm.0, &self.size_mm.1])
+                .all(|&value| value < 1 << 12)
+            && self.h_sync_offset < 1 << 10
+            && self.h_sync_width < 1 << 10
+            && self.v_sync_offset < 1 << 6
+            && self.v_sync_width < 1 << 6
+    }
+
+    fn parse(bytes: &[u8]) -> Self {
+        // the upper bits of each value are in a shared byte
+        let value = |low: usize, high: usize, shift: u8, mask: u8| {
+            u16::from(bytes[low]) | (u16::from((bytes[high] >> shift) & mask) << 8)
+        };
+
+        Self {
+            pixel_clock_khz: u32::from(u16::from_le_bytes([bytes[0], bytes[1]])) * 10,
+            width: value(2, 4, 4, 0xF),
+            h_blank: value(3, 4, 0, 0xF),
+            height: value(5, 7, 4, 0xF),
+            v_blank: value(6, 7, 0, 0xF),
+            h_sync_offset: value(8, 11, 6, 0x3),
+            h_sync_width: value(9, 11, 4, 0x3),
+            v_sync_offset: bytes[10] >> 4 | ((bytes[11] >> 2) & 0x3) << 4,
+            v_sync_width: bytes[10] & 0xF | (bytes[11] & 0x3) << 4,
+            size_mm: (value(12, 14, 4, 0xF), value(13, 14, 0, 0xF)),
+            border: (bytes[15], bytes[16]),
+            flags: bytes[17],
+        }
+    }
+
+    fn to_bytes(self) -> Result<[u8; DESCRIPTOR_LEN], EdidError> {
+        if !self.fits() {
+            return Err(EdidError::Timing(self.width, self.height));
+        }
+
+        // checked above
+        #[allow(clippy::cast_possible_truncation)]
+        let (low, high) = (|value: u16| value as u8, |value: u16| (value >> 8) as u8);
+        let clock = u16::try_from(self.pixel_clock_khz / 10).unwrap_or(u16::MAX);
+
+        let mut bytes = [0; DESCRIPTOR_LEN];
+        bytes[..2].copy_from_slice(&clock.to_le_bytes());
+        bytes[2] = low(self.width);
+        bytes[3] = low(self.h_blank);
+        bytes[4] = high(self.width) << 4 | high(self.h_blank);
+        bytes[5] = low(self.height);
+        bytes[6] = low(self.v_blank);
+        bytes[7] = high(self.height) << 4 | high(self.v_blank);
+        bytes[8] = low(self.h_sync_offset);
+        bytes[9] = low(self.h_sync_width);
+        bytes[10] = (self.v_sync_offset & 0xF) << 4 | self.v_sync_width & 0xF;
+        bytes[11] = high(self.h_sync_offset) << 6
+            | high(self.h_sync_width) << 4
+            | (self.v_sync_offset >> 4) << 2
+            | self.v_sync_width >> 4;
+        bytes[12] = low(self.size_mm.0);
+        bytes[13] = low(self.size_mm.1);
+        bytes[14] = high(self.size_mm.0) << 4 | high(self.size_mm.1);
+        (bytes[15], bytes[16]) = self.border;
+        bytes[17] = self.flags;
+
+        Ok(bytes)
+    }
+}
+
+impl Extension {
+    fn parse(block: &[u8]) -> Self {
+        if block[0] == CTA_EXTENSION_TAG {
+            Self::Cta(CtaExtension::parse(block))
+        } else {
+            Self::Other(block.to_vec())
+        }
+    }
+
+    fn to_bytes(&self) -> Result<[u8; BLOCK_LEN], EdidError> {
+        match self {
+            Self::Cta(extension) => extension.to_block(),
+            Self::Other(bytes) => {
+                let mut block = <[u8; BLOCK_LEN]>::try_from(&bytes[..])
+                    .map_err(|_| EdidError::ExtensionLength(bytes.len()))?;
+                set_checksum(&mut block);
+                Ok(block)
+            }
+        }
+    }
+}
+
+impl CtaExtension {
+    /// Flag of sinks that support basic audio
+    pub const BASIC_AUDIO: u8 = 0x40;
+
+    /// HDMI audio, 2 channel and 7.1 LPCM, which the driver adds to the EDIDs of monitors with
+    /// `Monitor::audio`
+    #[must_use]
+    pub fn hdmi_audio() -> Self {
+        let lpcm = |channels, sample_rates: &[u32]| AudioFormat {
+            format: 1,
+            channels,
+            sample_rates: sample_rates.to_vec(),
+            // 16, 20 and 24 bit
+            details: 0x07,
+        };
+
+        Self {
+            revision: 3,
+            flags: Self::BASIC_AUDIO,
+            data_blocks: vec![
+                DataBlock::Audio(vec![lpcm(2, &SAMPLE_RATES[..3]), lpcm(8, &SAMPLE_RATES)]),
+                // FL/FR, LFE, FC, RL/RR, RLC/RRC
+                DataBlock::SpeakerAllocation([0x4F, 0x00, 0x00]),
+                // physical address 1.0.0.0
+                DataBlock::VendorSpecific {
+                    oui: HDMI_OUI,
+                    payload: vec![0x10, 0x00],
+                },
+            ],
+            timings: Vec::new(),
+        }
+    }
+
+    /// Microsoft's data block for head-mounted and specialized displays, which windows keeps out
+    /// of the desktop, see `Monitor::exclusive_capture`
+    #[must_use]
+    pub fn specialized() -> Self {
+        // version 3, not used by the desktop, available to third party renderers, generic
+        // display, and a zero container id, which lets windows pick one
+        let mut payload = vec![0x03, 0x22];
+        payload.resize(2 + 16, 0);
+
+        Self {
+            revision: 3,
+            flags: 0,
+            data_blocks: vec![DataBlock::VendorSpecific {
+                oui: MICROSOFT_OUI,
+                payload,
+            }],
+            timings: Vec::new(),
+        }
+    }
+
+    fn parse(block: &[u8]) -> Self {
+        // detailed timing descriptors follow the data blocks, up to the checksum
+        let timings_start = usize::from(block[2]).clamp(4, BLOCK_LEN - 1);
+        let timings = block[timings_start..BLOCK_LEN - 1]
+            .chunks_exact(DESCRIPTOR_LEN)
+            .take_while(|bytes| bytes[..2] != [0, 0])
+            .map(DetailedTiming::parse)
+            .collect();
+
+        let mut data_blocks = Vec::new();
+        let mut rest = &block[4..timings_start];
+        while let Some((&header, after)) = rest.split_first() {
+            let len = usize::from(header & 0x1F).min(after.len());
+            let (payload, after) = after.split_at(len);
+            data_blocks.push(DataBlock::parse(header >> 5, payload));
+            rest = after;
+        }
+
+        Self {
+            revision: block[1],
+            flags: block[3],
+            data_blocks,
+            timings,
+        }
+    }
+
+    /// Write the extension block, with its checksum
+    ///
+    /// # Errors
+    ///
+    /// If the data blocks and timings don't fit into the block
+    pub fn to_block(&self) -> Result<[u8; BLOCK_LEN], EdidError> {
+        let mut data = Vec::new();
+        for block in &self.data_blocks {
+            let (tag, payload) = block.to_bytes();
+            let len = u8::try_from(payload.len())
+                .ok()
+                .filter(|&len| usize::from(len) <= DATA_BLOCK_MAX_LEN)
+                .ok_or(EdidError::ExtensionFull(payload.len()))?;
+            data.push(tag << 5 | len);
+            data.extend_from_slice(&payload);
+        }
+        for timing in &self.timings {
+            data.extend_from_slice(&timing.to_bytes()?);
+        }
+
+        let timings_len = self.timings.len() * DESCRIPTOR_LEN;
+        if data.len() > CTA_PAYLOAD_LEN {
+            return Err(EdidError::ExtensionFull(data.len()));
+        }
+
+        let mut block = [0; BLOCK_LEN];
+        block[0] = CTA_EXTENSION_TAG;
+        block[1] = self.revision;
+        // where the timings start, right after the data blocks, fits as checked above
+        #[allow(clippy::cast_possible_truncation)]
+        let timings_start = (4 + data.len() - timings_len) as u8;
+        block[2] = timings_start;
+        block[3] = self.flags;
+        block[4..4 + data.len()].copy_from_slice(&data);
+        set_checksum(&mut block);
+
+        Ok(block)
+    }
+}
+
+impl DataBlock {
+    fn parse(tag: u8, payload: &[u8]) -> Self {
+        match (tag, payload) {
+            (1, _) => Self::Audio(
+                payload
+                    .chunks_exact(3)
+                    .map(|sad| AudioFormat {
+                        format: (sad[0] >> 3) & 0xF,
+                        channels: (sad[0] & 0x7) + 1,
+                        sample_rates: SAMPLE_RATES
+                            .iter()
+                            .enumerate()
+                            .filter(|(bit, _)| sad[1] & (1 << bit) != 0)
+                            .map(|(_, &rate)| rate)
+                            .collect(),
+                        details: sad[2],
+                    })
+                    .collect(),
+            ),
+            (2, _) => Self::Video(payload.to_vec()),
+            // the oui is written least significant byte first
+            (3, [low, middle, high, payload @ ..]) => Self::VendorSpecific {
+                oui: [*high, *middle, *low],
+                payload: payload.to_vec(),
+            },
+            (4, &[a, b, c]) => Self::SpeakerAllocation([a, b, c]),
+            _ => Self::Other {
+                tag,
+                payload: payload.to_vec(),
+            },
+        }
+    }
+
+    fn to_bytes(&self) -> (u8, Vec<u8>) {
+        match self {
+            Self::Audio(formats) => {
+                let payload = formats
+                    .iter()
+                    .flat_map(|format| {
+                        let rates = SAMPLE_RATES
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, rate)| format.sample_rates.contains(rate))
+                            .fold(0, |rates, (bit, _)| rates | 1 << bit);
+                        let code =
+                            (format.format & 0xF) << 3 | format.channels.saturating_sub(1) & 0x7;
+                        [code, rates, format.details]
+                    })
+                    .collect();
+                (1, payload)
+            }
+            Self::Video(codes) => (2, codes.clone()),
+            Self::VendorSpecific { oui, payload } => {
+                let [high, middle, low] = *oui;
+                (
+                    3,
+                    [low, middle, high]
+                        .into_iter()
+                        .chain(payload.iter().copied())
+                        .collect(),
+                )
+            }
+            Self::SpeakerAllocation(speakers) => (4, speakers.to_vec()),
+            Self::Other { tag, payload } => (*tag, payload.clone()),
+        }
+    }
+}
+
+/// Builds an [`Edid`], starting from the one the driver generates for monitors without a custom
+/// one: a 1920x1080@60 monitor named `VirtuDisplay+`
+#[derive(Debug, Clone)]
+pub struct EdidBuilder {
+    edid: Edid,
+}
+
+impl Default for EdidBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EdidBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            edid: Edid {
+                vendor: "CHY".to_owned(),
+                product: 0,
+                serial: 0,
+                week: 0xFF,
+                year: 2023,
+                version: (1, 3),
+                input: 0x80,
+                size_cm: (50, 31),
+                // 2.2
+                gamma: 120,
+                // rgb color, preferred timing first, sRGB
+                features: 0x07,
+                chromaticity: Chromaticity::SRGB,
+                established_timings: [0; 3],
+                standard_timings: [[1, 1]; 8],
+                descriptors: vec![
+                    // CTA-861 1080p60
+                    Descriptor::Timing(DetailedTiming {
+                        pixel_clock_khz: 148_500,
+                        width: 1920,
+                        height: 1080,
+                        h_blank: 280,
+                        v_blank: 45,
+                        h_sync_offset: 88,
+                        h_sync_width: 44,
+                        v_sync_offset: 4,
+                        v_sync_width: 5,
+                        size_mm: (0, 0),
+                        border: (0, 0),
+                        flags: 0x1E,
+                    }),
+                    Descriptor::RangeLimits(RangeLimits {
+                        vertical_hz: (23, 240),
+                        horizontal_khz: (15, 255),
+                        max_pixel_clock_mhz: 150,
+                    }),
+                    Descriptor::Name("VirtuDisplay+".to_owned()),
+                    // empty manufacturer specified descriptor
+                    Descriptor::Other {
+                        tag: 0,
+                        data: vec![0; DESCRIPTOR_DATA_LEN],
+                    },
+                ],
+                extensions: Vec::new(),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn vendor(mut self, vendor: &str) -> Self {
+        vendor.clone_into(&mut self.edid.vendor);
+        self
+    }
+
+    #[must_use]
+    pub fn product(mut self, product: u16) -> Self {
+        self.edid.product = product;
+        self
+    }
+
+    #[must_use]
+    pub fn serial(mut self, serial: u32) -> Self {
+        self.edid.serial = serial;
+        self
+    }
+
+    #[must_use]
+    pub fn size_cm(mut self, width: u8, height: u8) -> Self {
+        self.edid.size_cm = (width, height);
+        self
+    }
+
+    #[must_use]
+    pub fn chromaticity(mut self, chromaticity: Chromaticity) -> Self {
+        self.edid.chromaticity = chromaticity;
+        self
+    }
+
+    /// Make `timing` the preferred one, in place of the current one
+    #[must_use]
+    pub fn preferred_timing(mut self, timing: DetailedTiming) -> Self {
+        match self.edid.descriptors.first_mut() {
+            Some(first @ Descriptor::Timing(_)) => *first = Descriptor::Timing(timing),
+            _ => self.edid.descriptors.insert(0, Descriptor::Timing(timing)),
+        }
+        self
+    }
+
+    /// Add another timing after the others, in place of a descriptor that isn't needed if all
+    /// four are used
+    #[must_use]
+    pub fn timing(mut self, timing: DetailedTiming) -> Self {
+        let descriptors = &mut self.edid.descriptors;
+        let after = descriptors
+            .iter()
+            .take_while(|descriptor| matches!(descriptor, Descriptor::Timing(_)))
+            .count();
+        descriptors.insert(after, Descriptor::Timing(timing));
+
+        // the base block holds four
+        if descriptors.len() > DESCRIPTORS {
+            if let Some(unused) = descriptors
+                .iter()
+                .rposition(|descriptor| matches!(descriptor, Descriptor::Other { .. }))
+            {
+                descriptors.remove(unused);
+            }
+        }
+        self
+    }
+
+    /// Set the display product name, at most [`EDID_NAME_LEN`] printable ascii characters
+    #[must_use]
+    pub fn name(self, name: &str) -> Self {
+        self.replace(Descriptor::Name(name.to_owned()))
+    }
+
+    #[must_use]
+    pub fn range_limits(self, limits: RangeLimits) -> Self {
+        self.replace(Descriptor::RangeLimits(limits))
+    }
+
+    #[must_use]
+    pub fn extension(mut self, extension: CtaExtension) -> Self {
+        self.edid.extensions.push(Extension::Cta(extension));
+        self
+    }
+
+    #[must_use]
+    pub fn edid(self) -> Edid {
+        self.edid
+    }
+
+    /// # Errors
+    ///
+    /// If something doesn't fit, see [`EdidError`]
+    pub fn build(&self) -> Result<Vec<u8>, EdidError> {
+        self.edid.to_bytes()
+    }
+
+    // replace the descriptor of the same type, or add it
+    fn replace(mut self, descriptor: Descriptor) -> Self {
+        let descriptors = &mut self.edid.descriptors;
+        let same = descriptors
+            .iter()
+            .position(|other| std::mem::discriminant(other) == std::mem::discriminant(&descriptor));
+
+        match same {
+            Some(index) => descriptors[index] = descriptor,
+            None => descriptors.push(descriptor),
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // what the driver generated before it used the builder, which must not change, or windows
+    // forgets the settings of every monitor
+    const DRIVER_EDID: [u8; BLOCK_LEN] = [
+        0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x0D, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0xFF, 0x21, 0x01, 0x03, 0x80, 0x32, 0x1F, 0x78, 0x07, 0xEE, 0x95, 0xA3, 0x54, 0x4C,
+        0x99, 0x26, 0x0F, 0x50, 0x54, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x3A, 0x80, 0x18, 0x71, 0x38,
+        0x2D, 0x40, 0x58, 0x2C, 0x45, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x00, 0x00,
+        0xFD, 0x00, 0x17, 0xF0, 0x0F, 0xFF, 0x0F, 0x00, 0x0A, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20,
+        0x00, 0x00, 0x00, 0xFC, 0x00, 0x56, 0x69, 0x72, 0x74, 0x75, 0x44, 0x69, 0x73, 0x70, 0x6C,
+        0x61, 0x79, 0x2B, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+
+    // the driver's audio extension, as it was written by hand
+    #[rustfmt::skip]
+    const AUDIO_DATA_BLOCKS: [u8; 17] = [
+        0x26, 0x09, 0x07, 0x07, 0x0F, 0x7F, 0x07,
+        0x83, 0x4F, 0x00, 0x00,
+        0x65, 0x03, 0x0C, 0x00, 0x10, 0x00,
+    ];
+
+    #[test]
+    fn builds_the_drivers_edid() {
+        let mut expected = DRIVER_EDID;
+        set_checksum(&mut expected);
+
+        assert_eq!(EdidBuilder::new().build().unwrap(), expected);
+    }
+
+    #[test]
+    fn builds_the_drivers_audio_extension() {
+        let edid = EdidBuilder::new()
+            .extension(CtaExtension::hdmi_audio())
+            .build()
+            .unwrap();
+
+        assert_eq!(edid.len(), 2 * BLOCK_LEN);
+        assert_eq!(edid[126], 1);
+        let extension = &edid[BLOCK_LEN..];
+        assert_eq!(extension[..4], [0x02, 0x03, 4 + 17, 0x40]);
+        assert_eq!(extension[4..21], AUDIO_DATA_BLOCKS);
+        assert!(bad_checksums(&edid).is_empty());
+    }
+
+    #[test]
+    fn round_trip() {
+        let builder = EdidBuilder::new()
+            .vendor("DEL")
+            .product(0xA0B1)
+            .serial(7)
+            .size_cm(60, 34)
+            .chromaticity(Chromaticity {
+                red: (700, 300),
+                ..Chromaticity::SRGB
+            })
+            .preferred_timing(DetailedTiming::reduced_blanking(2560, 1440, 144))
+            .timing(DetailedTiming::reduced_blanking(1920, 1080, 60))
+            .name("desk")
+            .extension(CtaExtension::hdmi_audio())
+            .extension(CtaExtension::specialized());
+
+        let bytes = builder.build().unwrap();
+        let edid = Edid::parse(&bytes).unwrap();
+        assert!(bad_checksums(&bytes).is_empty());
+
+        assert_eq!(edid, builder.edid());
+        assert_eq!(edid.to_bytes().unwrap(), bytes);
+    }
+
+    #[test]
+    fn parses_what_it_builds() {
+        let edid = Edid::parse(&EdidBuilder::new().name("desk").build().unwrap()).unwrap();
+
+        assert_eq!(edid.vendor, "CHY");
+        assert_eq!(edid.year, 2023);
+        assert_eq!(edid.descriptors[2], Descriptor::Name("desk".to_owned()));
+        let Descriptor::Timing(timing) = &edid.descriptors[0] else {
+            panic!("expected the preferred timing first");
+        };
+        assert!((timing.refresh_rate() - 60.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn reduced_blanking() {
+        // from the VESA CVT spreadsheet
+        let timing = DetailedTiming::reduced_blanking(1920, 1080, 60);
+
+        assert_eq!(timing.pixel_clock_khz, 138_500);
+        assert_eq!((timing.h_blank, timing.v_blank), (160, 31));
+        assert_eq!(timing.v_sync_width, 5);
+    }
+
+    #[test]
+    fn what_doesnt_fit() {
+        let error = |builder: EdidBuilder| builder.build().unwrap_err();
+
+        assert_eq!(
+            error(EdidBuilder::new().vendor("dell")),
+            EdidError::Vendor("dell".to_owned())
+        );
+        assert!(matches!(
+            error(EdidBuilder::new().name("a name that is too long")),
+            EdidError::Text(_)
+        ));
+        // a pixel clock of more than 655.35 MHz
+        assert_eq!(
+            error(
+                EdidBuilder::new()
+                    .preferred_timing(DetailedTiming::reduced_blanking(3840, 2160, 144))
+            ),
+            EdidError::Timing(3840, 2160)
+        );
+        let full = CtaExtension {
+            data_blocks: vec![DataBlock::Video(vec![16; 31]); 4],
+            ..CtaExtension::hdmi_audio()
+        };
+        assert!(matches!(
+            error(EdidBuilder::new().extension(full)),
+            EdidError::ExtensionFull(_)
+        ));
+    }
+
+    #[test]
+    fn malformed() {
+        assert_eq!(Edid::parse(&[0, 255, 255, 0]), Err(EdidError::Length(4)));
+        assert_eq!(Edid::parse(&[0; BLOCK_LEN]), Err(EdidError::Header));
+
+        let mut edid = EdidBuilder::new().build().unwrap();
+        edid[20] = 0x90;
+        assert_eq!(bad_checksums(&edid), [0]);
+    }
+}
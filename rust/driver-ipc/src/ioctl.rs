@@ -0,0 +1,119 @@
+use std::{ffi::c_void, io};
+
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Foundation::{
+            CloseHandle, ERROR_INVALID_PARAMETER, ERROR_MORE_DATA, GENERIC_READ, GENERIC_WRITE,
+            HANDLE,
+        },
+        Storage::FileSystem::{
+            CreateFileW, FILE_FLAGS_AND_ATTRIBUTES, FILE_SHARE_READ, FILE_SHARE_WRITE,
+            OPEN_EXISTING,
+        },
+        System::IO::DeviceIoControl,
+    },
+};
+
+use crate::{IOCTL_COMMAND, IOCTL_REPLY, PENDING_REPLY_LEN};
+
+// replies larger than this are treated as an error instead of allocating a buffer for them
+const MAX_REPLY_SIZE: usize = 16 * 1024 * 1024;
+
+/// Control channel over device control requests on an adapter's device interface
+///
+/// An alternative to the pipe, for systems where security products block drivers from creating
+/// named pipes. Messages are the same json [`Command`](crate::Command)s as over the pipe.
+#[derive(Debug)]
+pub struct IoctlClient(HANDLE);
+
+// SAFETY: The handle isn't tied to a thread, and `DeviceIoControl` can be called concurrently
+unsafe impl Send for IoctlClient {}
+unsafe impl Sync for IoctlClient {}
+
+impl IoctlClient {
+    /// Open the device interface at `interface_path`, see [`Instance`](crate::Instance)
+    pub fn open(interface_path: &str) -> io::Result<Self> {
+        let handle = unsafe {
+            CreateFileW(
+                &HSTRING::from(interface_path),
+                (GENERIC_READ | GENERIC_WRITE).0,
+                FILE_SHARE_READ | FILE_SHARE_WRITE,
+                None,
+                OPEN_EXISTING,
+                FILE_FLAGS_AND_ATTRIBUTES(0),
+                None,
+            )
+        }?;
+
+        Ok(Self(handle))
+    }
+
+    /// Send `message`, and return the reply, which is empty for commands without one
+    ///
+    /// The command runs once, also if its reply doesn't fit the first buffer. The driver keeps it
+    /// then, and it's fetched with [`IOCTL_REPLY`](crate::IOCTL_REPLY) into a buffer of its size.
+    pub fn request(&self, message: &[u8]) -> io::Result<Vec<u8>> {
+        let mut reply = vec![0u8; 64 * 1024];
+
+        match self.control(IOCTL_COMMAND, message, &mut reply) {
+            Ok(written) => {
+                reply.truncate(written);
+                Ok(reply)
+            }
+
+            Err(e) if e.code() == ERROR_MORE_DATA.to_hresult() => self.pending_reply(&reply),
+
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Fetch a reply which didn't fit, with the id and length the driver wrote instead of it
+    fn pending_reply(&self, pending: &[u8]) -> io::Result<Vec<u8>> {
+        let invalid = || io::Error::new(io::ErrorKind::InvalidData, "invalid pending reply");
+
+        let id = pending.get(..8).ok_or_else(invalid)?;
+        let len = pending.get(8..PENDING_REPLY_LEN).ok_or_else(invalid)?;
+        let len = u64::from_le_bytes(len.try_into().map_err(|_| invalid())?);
+
+        let len = usize::try_from(len)
+            .ok()
+            .filter(|&len| len <= MAX_REPLY_SIZE)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "reply too large"))?;
+
+        let mut reply = vec![0u8; len];
+        let written = self.control(IOCTL_REPLY, id, &mut reply)?;
+        reply.truncate(written);
+
+        Ok(reply)
+    }
+
+    /// Send a device control request, returns how much of `output` was written
+    fn control(&self, code: u32, input: &[u8], output: &mut [u8]) -> windows::core::Result<usize> {
+        let too_large = || windows::core::Error::from(ERROR_INVALID_PARAMETER.to_hresult());
+        let input_len = u32::try_from(input.len()).map_err(|_| too_large())?;
+        let output_len = u32::try_from(output.len()).map_err(|_| too_large())?;
+        let mut written = 0u32;
+
+        unsafe {
+            DeviceIoControl(
+                self.0,
+                code,
+                Some(input.as_ptr().cast::<c_void>()),
+                input_len,
+                Some(output.as_mut_ptr().cast::<c_void>()),
+                output_len,
+                Some(&mut written),
+                None,
+            )
+        }?;
+
+        Ok(written as usize)
+    }
+}
+
+impl Drop for IoctlClient {
+    fn drop(&mut self) {
+        _ = unsafe { CloseHandle(self.0) };
+    }
+}
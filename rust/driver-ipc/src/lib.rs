@@ -1,42 +1,1152 @@
 use serde::{Deserialize, Serialize};
 
+mod alias;
+pub use alias::{alias_conflict, is_valid_alias, MonitorRef, MAX_ALIAS_LEN};
+mod cache;
+pub use cache::{CacheDiff, MonitorCache};
+#[cfg(feature = "client")]
+mod client;
+#[cfg(feature = "client")]
+pub use client::Client;
+mod control;
+pub use control::{MockCall, MockError, MockMonitorControl, MonitorControl};
+#[cfg(windows)]
+mod discover;
+pub mod edid;
+#[cfg(windows)]
+pub use discover::{discover, Instance};
+#[cfg(windows)]
+mod ioctl;
+#[cfg(windows)]
+pub use ioctl::IoctlClient;
+mod lut;
+pub use lut::{Lut, MAX_LUT_1D_SIZE, MAX_LUT_3D_SIZE};
+#[cfg(feature = "remote")]
+pub mod remote;
+mod ring;
+pub use ring::{RingConsumer, RingHeader, RING_CONSUMERS, RING_MAGIC, RING_SLOTS};
+#[cfg(feature = "schema")]
+pub mod schema;
+
 pub type Id = u32;
 pub type Dimen = u32;
 pub type RefreshRate = u32;
 
+// Pipe of the first driver instance, other instances append `-{n}`, see [`pipe_name`]
+pub const DEFAULT_PIPE_NAME: &str = "virtualdisplaydriver";
+// Device interface registered by every adapter, its reference string is the instance's pipe name
+pub const DEVICE_INTERFACE_GUID: u128 = 0xec54_1cbb_7968_4621_891c_d67d_f3c9_2c9a;
+// Device control code taking a json [`Command`] on the device interface, the alternative to the
+// pipe. CTL_CODE(FILE_DEVICE_UNKNOWN, 0x800, METHOD_BUFFERED, FILE_READ_DATA | FILE_WRITE_DATA)
+//
+// If the reply doesn't fit the output buffer, the command still runs only once: the request
+// completes with STATUS_BUFFER_OVERFLOW, and the output holds the id and length of the reply as
+// little endian u64s, to fetch it with [`IOCTL_REPLY`].
+pub const IOCTL_COMMAND: u32 = 0x0022_E000;
+// Device control code taking the id of a reply which didn't fit, as little endian u64, and
+// returning it. The driver only keeps the latest few of them, so it has to be fetched right away.
+// CTL_CODE(FILE_DEVICE_UNKNOWN, 0x801, METHOD_BUFFERED, FILE_READ_DATA | FILE_WRITE_DATA)
+pub const IOCTL_REPLY: u32 = 0x0022_E004;
+// Length of the id and length of a reply that didn't fit, the least output an [`IOCTL_COMMAND`]
+// needs room for
+pub const PENDING_REPLY_LEN: usize = 16;
+
+// ETW TraceLogging provider the driver writes events to, for use with WPR/WPA or xperf
+pub const TRACE_PROVIDER_NAME: &str = "VirtualDisplayDriver";
+pub const TRACE_PROVIDER_GUID: &str = "e473de08-5950-547b-e905-a9d89c310666";
+// Keywords to filter the provider's events by
+pub const TRACE_KEYWORD_DEVICE: u64 = 0x1;
+pub const TRACE_KEYWORD_MONITOR: u64 = 0x2;
+pub const TRACE_KEYWORD_SWAP_CHAIN: u64 = 0x4;
+// only written by drivers built with the `trace-calls` feature
+pub const TRACE_KEYWORD_CALLS: u64 = 0x8;
+
+// Characters the display product name descriptor of an edid holds, see [`Monitor::edid_name`]
+pub const EDID_NAME_LEN: usize = 13;
+
+// Shortest time between two `RequestModeChange`s of a monitor, so a consumer reacting to its
+// bandwidth doesn't keep the desktop flickering
+pub const MODE_CHANGE_INTERVAL_MS: u64 = 2000;
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Monitor {
     // identifier
     pub id: Id,
     pub name: Option<String>,
+    // unique slug that commands take instead of the id, see [`MonitorRef`]. unlike the id it's
+    // picked by the user, so it stays the same when the monitor is removed and added again
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
     pub enabled: bool,
     pub modes: Vec<Mode>,
+    // custom edid presented to the os instead of the generated one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edid: Option<Vec<u8>>,
+    // advertise hdmi audio support in the generated edid
+    #[serde(default)]
+    pub audio: bool,
+    // not persisted, so it isn't restored after a reboot
+    #[serde(default)]
+    pub ephemeral: bool,
+    // how the cursor is handed to the driver
+    #[serde(default)]
+    pub cursor: CursorPolicy,
+    // whether the monitor follows remote desktop sessions, applied by `vdd-server --sessions`
+    #[serde(default)]
+    pub session: SessionPolicy,
+    // highest frame rate the driver processes, unlimited if none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fps_limit: Option<u32>,
+    // share frames with other processes as a gpu texture, see [`SharedFrame`]
+    #[serde(default)]
+    pub gpu_export: bool,
+    // keep the monitor out of the desktop, as a render target that vr and kiosk renderers take
+    // over. the driver marks it as a specialized display in its edid, its frames are still
+    // processed, e.g. for `gpu_export`
+    #[serde(default)]
+    pub exclusive_capture: bool,
+    // size frames are shared in with `gpu_export`, instead of the mode's size
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_size: Option<ExportSize>,
+    // plug the monitor in as soon as it's added or enabled. if false, it's only plugged in on
+    // `DriverPlug`, or when it's enabled after being disabled
+    #[serde(default = "auto_plug_default")]
+    pub auto_plug: bool,
+    // labels to manage related monitors together, e.g. `disable --tag test` in the cli
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    // what the driver supports for the monitor, reported in its state so clients can hide options
+    // that wouldn't work. clients don't set it, the driver ignores it in `DriverNotify`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub capabilities: Option<MonitorCapabilities>,
+    // the mode windows currently drives the monitor with, as picked in display settings. `None`
+    // while the monitor isn't active. reported by the driver like `capabilities`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_mode: Option<ActiveMode>,
+    // the os display of the monitor while it's plugged in, to find it with the display config
+    // api. reported by the driver like `capabilities`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<DisplayTarget>,
+}
+
+fn auto_plug_default() -> bool {
+    true
+}
+
+impl Monitor {
+    /// An enabled monitor with `modes` and the default for everything else, to fill in the other
+    /// fields with struct update syntax
+    #[must_use]
+    pub fn new(id: Id, modes: Vec<Mode>) -> Self {
+        Self {
+            id,
+            name: None,
+            alias: None,
+            enabled: true,
+            modes,
+            edid: None,
+            audio: false,
+            ephemeral: false,
+            cursor: CursorPolicy::default(),
+            session: SessionPolicy::default(),
+            fps_limit: None,
+            gpu_export: false,
+            exclusive_capture: false,
+            export_size: None,
+            auto_plug: auto_plug_default(),
+            tags: Vec::new(),
+            capabilities: None,
+            active_mode: None,
+            target: None,
+        }
+    }
+
+    /// Names of the fields that differ from `other`, besides the id and the capabilities and
+    /// active mode the driver reports
+    #[must_use]
+    pub fn changed_fields(&self, other: &Self) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+
+        if self.name != other.name {
+            changed.push("name");
+        }
+        if self.alias != other.alias {
+            changed.push("alias");
+        }
+        if self.enabled != other.enabled {
+            changed.push("enabled");
+        }
+        if self.modes != other.modes {
+            changed.push("modes");
+        }
+        if self.edid != other.edid {
+            changed.push("edid");
+        }
+        if self.audio != other.audio {
+            changed.push("audio");
+        }
+        if self.ephemeral != other.ephemeral {
+            changed.push("ephemeral");
+        }
+        if self.cursor != other.cursor {
+            changed.push("cursor");
+        }
+        if self.session != other.session {
+            changed.push("session");
+        }
+        if self.fps_limit != other.fps_limit {
+            changed.push("fps_limit");
+        }
+        if self.gpu_export != other.gpu_export {
+            changed.push("gpu_export");
+        }
+        if self.exclusive_capture != other.exclusive_capture {
+            changed.push("exclusive_capture");
+        }
+        if self.export_size != other.export_size {
+            changed.push("export_size");
+        }
+        if self.auto_plug != other.auto_plug {
+            changed.push("auto_plug");
+        }
+        if self.tags != other.tags {
+            changed.push("tags");
+        }
+
+        changed
+    }
+
+    /// The name as the driver writes it into the monitor's generated edid, where windows and other
+    /// apps show it: printable ascii, others are replaced with `?`, cut to [`EDID_NAME_LEN`]
+    /// characters
+    ///
+    /// `None` without a name, or with a custom [`Self::edid`], which keeps its own
+    #[must_use]
+    pub fn edid_name(&self) -> Option<String> {
+        if self.edid.is_some() {
+            return None;
+        }
+
+        let name = self
+            .name
+            .as_deref()?
+            .trim()
+            .chars()
+            .map(|c| {
+                if c == ' ' || c.is_ascii_graphic() {
+                    c
+                } else {
+                    '?'
+                }
+            })
+            .take(EDID_NAME_LEN)
+            .collect::<String>();
+        let name = name.trim_end();
+
+        (!name.is_empty()).then(|| name.to_owned())
+    }
+
+    /// Make `mode` the preferred one, the first of [`Self::modes`], keeping the others in order
+    ///
+    /// Returns `false` if the monitor doesn't have the mode
+    pub fn prefer_mode(&mut self, mode: ActiveMode) -> bool {
+        let Some(index) = self
+            .modes
+            .iter()
+            .position(|m| (m.width, m.height) == (mode.width, mode.height))
+        else {
+            return false;
+        };
+        let Some(rate) = self.modes[index]
+            .refresh_rates
+            .iter()
+            .position(|&rr| rr == mode.refresh_rate)
+        else {
+            return false;
+        };
+
+        self.modes[index].refresh_rates[..=rate].rotate_right(1);
+        self.modes[..=index].rotate_right(1);
+
+        true
+    }
+}
+
+// What the driver did to apply a monitor's new state
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MonitorDiff {
+    pub id: Id,
+    // whether the monitor didn't exist before
+    pub created: bool,
+    // fields that differ from the previous state, see [`Monitor::changed_fields`]
+    pub changed: Vec<String>,
+    // os operations, in the order they were done. empty if only the stored state changed,
+    // e.g. for a new name
+    pub operations: Vec<MonitorOperation>,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum MonitorOperation {
+    // the modes were updated without re-plugging the monitor
+    UpdateModes,
+    // the os was told to apply the new preferred mode, without re-plugging the monitor
+    DisplayConfigUpdate,
+    // the monitor was unplugged
+    Departure,
+    // the monitor was plugged in
+    Arrival,
+}
+
+// Outcome of a `RequestModeChange`
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ModeChange {
+    // the mode is the preferred one now, and the diff says how the os was told to switch to it.
+    // without a `DisplayConfigUpdate` or `Arrival` (before IddCx 1.10) it's only picked the next
+    // time the monitor is plugged in. `EventKind::ActiveModeChanged` follows once it switched
+    Requested(MonitorDiff),
+    // the monitor doesn't exist, isn't active, or doesn't have the mode
+    Unavailable,
+    // the monitor's mode was changed less than `MODE_CHANGE_INTERVAL_MS` ago
+    RateLimited { retry_after_ms: u64 },
+}
+
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum SessionPolicy {
+    // only enabled or disabled by clients
+    #[default]
+    Manual,
+    // enabled while a remote desktop session is connected, disabled when the console is used
+    Remote,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CursorPolicy {
+    // get the cursor separately from the frames (hardware cursor), instead of the os
+    // compositing it into them
+    pub hardware: bool,
+    // largest cursor width and height the os hands over as a hardware cursor
+    pub max_size: u32,
+    pub format: CursorFormat,
+}
+
+impl Default for CursorPolicy {
+    fn default() -> Self {
+        Self {
+            hardware: false,
+            max_size: 64,
+            format: CursorFormat::Alpha,
+        }
+    }
+}
+
+// Hardware cursor of a monitor as the os last reported it, see `CursorPolicy::hardware`
+//
+// Consumers of shared frames draw it themselves, like with desktop duplication, unless they
+// asked for it to be drawn into the frames with `DriverSetCursorComposite`
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CursorState {
+    pub visible: bool,
+    // position of the shape's top left corner on the monitor, can be partly off it
+    pub x: i32,
+    pub y: i32,
+    // changes with every new shape, 0 before the first one
+    pub shape_id: u32,
+    // only sent if the requested shape id is another one
+    pub shape: Option<CursorShape>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CursorShape {
+    pub kind: CursorShapeKind,
+    pub width: u32,
+    pub height: u32,
+    // hot spot, where the cursor points, relative to the top left corner
+    pub hot_x: u32,
+    pub hot_y: u32,
+    // bgra rows of width * 4 bytes
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CursorShapeKind {
+    // blended by alpha
+    Alpha,
+    // pixels with an alpha of 0 replace the screen, ones with 255 are xored with it
+    MaskedColor,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum CursorFormat {
+    // monochrome cursors only, others are composited into the frames
+    Monochrome,
+    // monochrome and 32bpp cursors with alpha
+    Alpha,
+    // also masked color cursors, which xor with the desktop
+    AlphaXor,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct MonitorCapabilities {
+    // hdr modes and color space transforms
+    pub hdr_supported: bool,
+    // variable refresh rate
+    pub vrr_supported: bool,
+    // getting the cursor separately from the frames, see [`CursorPolicy::hardware`]
+    pub cursor_supported: bool,
+    // largest width and height of a mode
+    pub max_resolution: (Dimen, Dimen),
+}
+
+// Size the frames of a monitor are scaled to for consumers, e.g. thin clients that can only
+// decode certain sizes, while windows keeps rendering the monitor's mode
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ExportSize {
+    pub width: Dimen,
+    pub height: Dimen,
+    // how frames of another aspect ratio are fit into the size
+    #[serde(default)]
+    pub fit: ScaleFit,
+}
+
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ScaleFit {
+    // keep the aspect ratio, and fill the rest with black bars
+    #[default]
+    Letterbox,
+    // fill the whole size, distorting frames of another aspect ratio
+    Stretch,
+}
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ActiveMode {
+    pub width: Dimen,
+    pub height: Dimen,
+    pub refresh_rate: RefreshRate,
+}
+
+/// A display target of the os, `adapterId` and `id` of a `DISPLAYCONFIG_PATH_TARGET_INFO`
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DisplayTarget {
+    // luid of the adapter, `HighPart << 32 | LowPart`
+    pub adapter: i64,
+    pub id: u32,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct Mode {
     pub width: Dimen,
     pub height: Dimen,
     pub refresh_rates: Vec<RefreshRate>,
 }
 
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct LogRecord {
+    // increasing sequence number, used to request only newer records
+    pub seq: u64,
+    // milliseconds since the unix epoch
+    pub timestamp: u64,
+    pub level: LogLevel,
+    pub target: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Stats {
+    pub id: Id,
+    // frames acquired from the os since the monitor was added
+    pub frames_presented: u64,
+    // frames the os presented that were never acquired
+    pub frames_dropped: u64,
+    // average time between acquiring a frame and releasing it, in microseconds
+    pub avg_latency_us: f64,
+    // refresh rate measured from the time between acquired frames, 0 if idle
+    pub refresh_rate: f64,
+    // average time between the os presenting a frame and the driver acquiring it, in microseconds
+    #[serde(default)]
+    pub avg_present_latency_us: f64,
+    // average deviation of the time between frames from its average, in microseconds
+    #[serde(default)]
+    pub jitter_us: f64,
+    // devices and textures created for processing and sharing frames
+    #[serde(default)]
+    pub pool_allocations: u64,
+    // devices and textures reused from a previous swap chain or mode instead
+    #[serde(default)]
+    pub pool_reuses: u64,
+    // frames the consumer of the shared texture didn't get because it fell behind, see
+    // `Backpressure`
+    #[serde(default)]
+    pub shared_frames_dropped: u64,
+    // consumers reading from the monitor's frame ring, see `SharedRing`
+    #[serde(default)]
+    pub consumers: Vec<ConsumerStats>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ConsumerStats {
+    // process id of the consumer
+    pub pid: u32,
+    // frames the consumer read from the ring
+    pub frames_read: u64,
+    // frames the consumer read per second, measured over the last second
+    pub fps: f64,
+    // frames the consumer skipped because it read slower than frames came
+    #[serde(default)]
+    pub frames_dropped: u64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CallbackStats {
+    // name of the IddCx callback or call, e.g. `AssignSwapChain`
+    pub callback: String,
+    pub succeeded: u64,
+    pub failed: u64,
+}
+
+// Texture a monitor's frames are shared in, open it by name with
+// `ID3D11Device1::OpenSharedResourceByName` (or `ID3D12Device::OpenSharedHandleByName`)
+//
+// Frames are handed over with the texture's keyed mutex or a shared fence, see `FrameSync`. The
+// name changes when the texture is re-created, e.g. after a mode change, so request it again when
+// frames stop coming
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SharedFrame {
+    pub name: String,
+    pub width: u32,
+    pub height: u32,
+    // DXGI_FORMAT of the texture, see `FrameFormat`
+    pub format: u32,
+    #[serde(default)]
+    pub sync: FrameSync,
+    // name of the shared fence with `FrameSync::Fence`, open it with
+    // `ID3D12Device::OpenSharedHandleByName` (or `ID3D11Device5::OpenSharedFence`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fence: Option<String>,
+    // what happens to new frames while the consumer falls behind
+    #[serde(default)]
+    pub backpressure: Backpressure,
+}
+
+// What the driver does with a new frame while the consumer of a monitor's shared frames is
+// behind, i.e. still holds the texture or hasn't read the previous frame, set with
+// `DriverSetBackpressure`
+//
+// The frame ring always replaces its oldest frames and every consumer reads the newest one, so
+// the drop policies are the same there: a frame is only dropped while every texture of the ring
+// is being read
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Backpressure {
+    // keep the frame the consumer hasn't read yet, and drop the new one
+    #[default]
+    DropNewest,
+    // replace the frame the consumer hasn't read yet with the new one, so it always reads the
+    // latest. Frames the consumer is reading are kept, and so are frames handed over with a fence,
+    // which can't be taken back
+    DropOldest,
+    // wait up to `timeout_ms` for the consumer, then drop the new one. This holds up the monitor's
+    // swap chain, so the os drops desktop frames instead, and the latency the consumer measures
+    // includes the wait
+    Block {
+        timeout_ms: u32,
+    },
+}
+
+// How the driver and the consumer of a monitor's shared frames take turns with the texture,
+// requested by the consumer with `RequestSharedFrameSync`
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FrameSync {
+    // the texture's keyed mutex: acquire key 1 to read a new frame, then release key 0 so the
+    // driver can write the next one
+    #[default]
+    KeyedMutex,
+    // a shared fence, for D3D12 and Vulkan consumers: the driver signals it to an odd value once a
+    // frame is written, the consumer waits for that, reads the frame, then signals the next (even)
+    // value so the driver can write the next one
+    Fence,
+}
+
+// Synchronization the consumer of a monitor's shared frames asked for, it applies from the next
+// frame on, and textures shared with another `sync` have another name
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SyncOptions {
+    pub sync: FrameSync,
+    // how long the consumer may keep a frame before the driver considers it gone, and shares
+    // frames in a new texture. Counts from when the frame was handed over, so a consumer with a
+    // timeout has to take every frame. 0 waits forever
+    pub timeout_ms: u32,
+}
+
+// Ring of textures a monitor's frames are shared in, for several consumers at once, each reading
+// at its own pace. Requested with `RequestSharedRing`, after which the driver fills the ring
+// besides the single texture of `SharedFrame`, in the same size and format
+//
+// Open the file mapping `header`, a `RingHeader`, and claim an entry in it with
+// `RingHeader::claim`. The driver sets the entry's event in `events` after every frame. Then
+// find the newest frame with `RingHeader::newest`, acquire key 0 of its texture's keyed mutex,
+// copy the frame, release key 0 and move the read cursor with `RingHeader::read`. The driver
+// skips textures that are held, so copy frames out rather than keeping them. Entries of
+// consumers that exit are freed by the driver. The names change when the ring is re-created, e.g.
+// after a mode change
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct SharedRing {
+    pub header: String,
+    // names of the ring's textures, by slot
+    pub textures: Vec<String>,
+    // names of the auto-reset events of the header's consumer entries, by entry
+    pub events: Vec<String>,
+    pub width: u32,
+    pub height: u32,
+    // DXGI_FORMAT of the textures
+    pub format: u32,
+}
+
+// Format frames are shared in, requested by the consumer with `RequestSharedFrameAs`
+//
+// Frames are converted on the gpu, to BT.709 limited range for the YUV formats. Those need an
+// even size, so frames of an odd width or height lose the last column or row. Frames stop
+// coming if the gpu can't convert to the format
+#[derive(Debug, Copy, Clone, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum FrameFormat {
+    // the desktop's format, usually DXGI_FORMAT_B8G8R8A8_UNORM
+    #[default]
+    Bgra,
+    // 8 bit 4:2:0, what most hardware encoders take
+    Nv12,
+    // 10 bit 4:2:0
+    P010,
+}
+
+impl FrameFormat {
+    /// The `DXGI_FORMAT` shared textures of this format have, `None` for [`Self::Bgra`], which
+    /// keeps the desktop's format
+    #[must_use]
+    pub fn dxgi_format(self) -> Option<u32> {
+        match self {
+            Self::Bgra => None,
+            // DXGI_FORMAT_NV12
+            Self::Nv12 => Some(103),
+            // DXGI_FORMAT_P010
+            Self::P010 => Some(104),
+        }
+    }
+}
+
+// Generated frames, to validate capture and encode pipelines independent of the desktop
+//
+// Every pattern shows a frame counter, both as digits and as a row of 32 black/white cells
+// (most significant bit first) along the top edge, so latency can be measured end to end
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum TestPattern {
+    // SMPTE color bars
+    Bars,
+    // grayscale and color ramps
+    Gradient,
+    // a box moving across the screen, to spot dropped or repeated frames
+    MovingBox,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DriverInfo {
+    // version of the driver
+    pub version: String,
+    // IddCx version the driver was built against
+    pub iddcx_version: String,
+    // optional features that aren't active, e.g. because the os is too old
+    pub degraded: Vec<DegradedFeature>,
+    // whether the adapter's frame processing gets realtime gpu priority
+    #[serde(default)]
+    pub realtime_gpu_priority: bool,
+    // optional IddCx functions, and whether the driver can use them
+    #[serde(default)]
+    pub capabilities: Vec<Capability>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Capability {
+    // name of the IddCx function, e.g. `IddCxMonitorUpdateModes`
+    pub function: String,
+    // IddCx version the driver needs to be built against to use it, 1.4 for functions that are
+    // at least as old as that
+    pub iddcx_version: String,
+    // whether the driver was built against headers that have it
+    pub built: bool,
+    // whether this version of Windows has it, always false if it isn't built
+    pub available: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DegradedFeature {
+    pub feature: String,
+    // why the feature isn't active
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Event {
+    // increasing sequence number, used to request only newer events
+    pub seq: u64,
+    // milliseconds since the unix epoch
+    pub timestamp: u64,
+    pub kind: EventKind,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum EventKind {
+    // the monitor's swap chain made no progress for `stalled_ms`, so the driver re-created it
+    SwapChainStalled {
+        id: Id,
+        stalled_ms: u64,
+    },
+    // a monitor was added, carrying its state
+    MonitorAdded {
+        monitor: Monitor,
+    },
+    // a monitor's stored state changed, carrying the new state and the names of the changed
+    // fields, see [`Monitor::changed_fields`]
+    MonitorChanged {
+        monitor: Monitor,
+        changed: Vec<String>,
+    },
+    MonitorRemoved {
+        id: Id,
+    },
+    // windows switched the monitor to another mode, or (de)activated it, see
+    // [`Monitor::active_mode`]
+    ActiveModeChanged {
+        id: Id,
+        mode: Option<ActiveMode>,
+    },
+    // a client asked for the monitor to be switched to `mode` with `RequestModeChange`, e.g. a
+    // streaming server whose bandwidth dropped. `ActiveModeChanged` follows once windows did
+    ModeChangeRequested {
+        id: Id,
+        mode: ActiveMode,
+    },
+    // the consumer of the monitor's shared frames went away while holding a frame: its device was
+    // lost (`held_ms` is `None`), or it kept the frame longer than its timeout, see
+    // [`SyncOptions::timeout_ms`], so the driver shares frames in a new texture
+    ConsumerAbandoned {
+        id: Id,
+        held_ms: Option<u64>,
+    },
+}
+
+// Picture settings of a monitor, set by windows or by tools talking DDC/CI to it (e.g. Monitorian
+// or the brightness slider). The driver doesn't apply them to frames, so consumers showing them
+// elsewhere should
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct Picture {
+    // 0 to 100, from DDC/CI
+    pub brightness: u16,
+    pub contrast: u16,
+    // from color calibration or night light, `None` if it's the default (identity) ramp
+    pub gamma: Option<GammaRamp>,
+    // set by clients with `DriverSetLut`, to correct colors for the device frames are shown on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lut: Option<Lut>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct GammaRamp {
+    // 256 entries per channel, mapping input levels to output levels from 0 to 65535
+    pub red: Vec<u16>,
+    pub green: Vec<u16>,
+    pub blue: Vec<u16>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub enum Command {
     // Single line of communication client->server
+    // Monitors are given by their id or alias, see [`MonitorRef`]
     // Driver commands
     //
     // Notify of monitor changes (whether adding or updating)
     DriverNotify(Vec<Monitor>),
     // Remove a monitor from system
-    DriverRemove(Vec<Id>),
+    DriverRemove(Vec<MonitorRef>),
     // Remove all monitors from system
     DriverRemoveAll,
+    // Change the driver log level at runtime
+    DriverSetLogLevel(LogLevel),
+    // Share a generated test pattern instead of the desktop, or the desktop again with `None`.
+    // Only affects monitors with gpu export enabled, and isn't persisted
+    DriverSetTestPattern(MonitorRef, Option<TestPattern>),
+    // Unplug a monitor and plug it in again, keeping its configuration. Does nothing for disabled
+    // monitors
+    DriverReplug(MonitorRef),
+    // Plug in an enabled monitor that isn't plugged in yet, see `Monitor::auto_plug`
+    DriverPlug(MonitorRef),
+    // Let the os schedule the adapter's frame processing ahead of other gpu work. Persisted per
+    // adapter; turning it off applies to monitors once they get a new swap chain, e.g. on replug
+    DriverSetRealtimeGpuPriority(bool),
+    // Set the color lookup table of a monitor's picture settings, or remove it with `None`.
+    // Invalid tables are ignored, see [`Lut::is_valid`]. Isn't persisted
+    DriverSetLut(MonitorRef, Option<Lut>),
+    // Draw the hardware cursor of a monitor into its shared frames, for consumers that don't draw
+    // it themselves. Isn't persisted
+    DriverSetCursorComposite(MonitorRef, bool),
+    // Set what happens to new frames while the consumers of a monitor's shared frames fall
+    // behind. Isn't persisted
+    DriverSetBackpressure(MonitorRef, Backpressure),
     // Requests
     // client->server
     //
     // Request information on the current system monitor state
     RequestState,
+    // Request the server to echo the payload back, used to benchmark the pipe
+    RequestEcho(Vec<u8>),
+    // Request the edid of a connected physical display, e.g. `DISPLAY2`
+    RequestDisplayEdid(String),
+    // Request buffered log records with a sequence number greater than the given one
+    RequestLogs(u64),
+    // Request frame statistics of a single monitor, or all of them
+    RequestStats(Option<MonitorRef>),
+    // Request success/failure counts of the driver's IddCx callbacks
+    RequestCallbackStats,
+    // Request buffered events with a sequence number greater than the given one
+    RequestEvents(u64),
+    // Request the driver version and which optional features aren't available
+    RequestDriverInfo,
+    // Like `DriverNotify`, but replies with what was done to apply the changes
+    RequestNotify(Vec<Monitor>),
+    // Request the texture a monitor's frames are currently shared in
+    RequestSharedFrame(MonitorRef),
+    // Like `RequestSharedFrame`, but first ask for frames to be shared in the given format. The
+    // format applies from the next frame on, so the reply can still have the previous one;
+    // request the shared frame again until its format matches
+    RequestSharedFrameAs(MonitorRef, FrameFormat),
+    // Like `RequestSharedFrame`, but first ask for frames to be handed over with the given
+    // synchronization. Applies from the next frame on like `RequestSharedFrameAs`
+    RequestSharedFrameSync(MonitorRef, SyncOptions),
+    // Request the frame ring of a monitor, for consumers reading alongside others. The first
+    // request starts filling it from the next frame on, so request it again until it's there
+    RequestSharedRing(MonitorRef),
+    // Request the picture settings of a monitor
+    RequestPicture(MonitorRef),
+    // Request the state of a single monitor, instead of all of them like `RequestState`
+    RequestMonitor(MonitorRef),
+    // Request the hardware cursor of a monitor, with its shape unless it's the one with the given
+    // shape id
+    RequestCursor(MonitorRef, u32),
+    // Request that an active monitor is switched to another of its modes, by making it the
+    // preferred one, see [`ModeChange`]. Limited to one per `MODE_CHANGE_INTERVAL_MS` per monitor
+    RequestModeChange(MonitorRef, ActiveMode),
+    // Request the edid a monitor was plugged in with, as the os reads it
+    RequestEdid(MonitorRef),
     // Replies to request
     // server->client
     ReplyState(Vec<Monitor>),
+    // Reply to an echo request, carrying the same payload back
+    ReplyEcho(Vec<u8>),
+    // Reply with the edid of the requested display, if it could be read
+    ReplyDisplayEdid(Option<Vec<u8>>),
+    // Reply with buffered log records, oldest first
+    ReplyLogs(Vec<LogRecord>),
+    // Reply with frame statistics, one entry per requested monitor
+    ReplyStats(Vec<Stats>),
+    // Reply with callback outcome counts, one entry per callback
+    ReplyCallbackStats(Vec<CallbackStats>),
+    // Reply with buffered events, oldest first
+    ReplyEvents(Vec<Event>),
+    // Reply with the driver info
+    ReplyDriverInfo(DriverInfo),
+    // Reply with one diff per notified monitor
+    ReplyNotify(Vec<MonitorDiff>),
+    // Reply with the shared texture, if the monitor has gpu export enabled and a swap chain
+    ReplySharedFrame(Option<SharedFrame>),
+    // Reply with the frame ring, if the monitor has gpu export enabled and a swap chain
+    ReplySharedRing(Option<SharedRing>),
+    // Reply with the monitor's picture settings
+    ReplyPicture(Picture),
+    // Reply with the requested monitor, if it exists
+    ReplyMonitor(Option<Monitor>),
+    // Reply with the hardware cursor, if the monitor has one and is plugged in
+    ReplyCursor(Option<CursorState>),
+    // Reply with what came of the mode change
+    ReplyModeChange(ModeChange),
+    // Reply with the edid, if the monitor is plugged in
+    ReplyEdid(Option<Vec<u8>>),
+}
+
+impl Command {
+    /// Whether this is a request, which the driver replies to. Driver commands have no reply
+    #[must_use]
+    pub fn is_request(&self) -> bool {
+        matches!(
+            self,
+            Self::RequestState
+                | Self::RequestEcho(_)
+                | Self::RequestDisplayEdid(_)
+                | Self::RequestLogs(_)
+                | Self::RequestStats(_)
+                | Self::RequestCallbackStats
+                | Self::RequestEvents(_)
+                | Self::RequestDriverInfo
+                | Self::RequestNotify(_)
+                | Self::RequestSharedFrame(_)
+                | Self::RequestSharedFrameAs(..)
+                | Self::RequestSharedFrameSync(..)
+                | Self::RequestSharedRing(_)
+                | Self::RequestPicture(_)
+                | Self::RequestMonitor(_)
+                | Self::RequestCursor(..)
+                | Self::RequestModeChange(..)
+                | Self::RequestEdid(_)
+        )
+    }
+
+    /// Whether this is a reply, which only the driver sends
+    #[must_use]
+    pub fn is_reply(&self) -> bool {
+        matches!(
+            self,
+            Self::ReplyState(_)
+                | Self::ReplyEcho(_)
+                | Self::ReplyDisplayEdid(_)
+                | Self::ReplyLogs(_)
+                | Self::ReplyStats(_)
+                | Self::ReplyCallbackStats(_)
+                | Self::ReplyEvents(_)
+                | Self::ReplyDriverInfo(_)
+                | Self::ReplyNotify(_)
+                | Self::ReplySharedFrame(_)
+                | Self::ReplySharedRing(_)
+                | Self::ReplyPicture(_)
+                | Self::ReplyMonitor(_)
+                | Self::ReplyCursor(_)
+                | Self::ReplyModeChange(_)
+                | Self::ReplyEdid(_)
+        )
+    }
+}
+
+/// Name of the pipe of the driver instance with the given index
+#[must_use]
+pub fn pipe_name(index: u32) -> String {
+    if index == 0 {
+        DEFAULT_PIPE_NAME.to_owned()
+    } else {
+        format!("{DEFAULT_PIPE_NAME}-{index}")
+    }
+}
+
+// Pipe of the encoder in `vdd-server --encoder`, it speaks [`EncoderCommand`] instead of
+// [`Command`]
+pub const ENCODER_PIPE_NAME: &str = "virtualdisplaydriver-encoder";
+
+#[derive(Debug, Copy, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub enum Codec {
+    H264,
+    Hevc,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EncodeSettings {
+    pub codec: Codec,
+    // target bitrate in kbit/s
+    pub bitrate: u32,
+    // file the stream is written to, as fragmented mp4, so it can be read while it's written
+    pub output: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Encoding {
+    pub id: Id,
+    pub settings: EncodeSettings,
+    // frames encoded so far
+    pub frames: u64,
+    // why encoding stopped, if it stopped on its own
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum EncoderCommand {
+    // Requests
+    // client->server
+    //
+    // Start encoding the frames of a monitor with gpu export enabled, restarting it if it's
+    // already encoded
+    RequestStart(Id, EncodeSettings),
+    // Stop encoding a monitor, finishing its output file
+    RequestStop(Id),
+    // Request the state of all encoded monitors
+    RequestStatus,
+    // Replies to request
+    // server->client
+    ReplyOk,
+    ReplyError(String),
+    ReplyStatus(Vec<Encoding>),
+}
+
+// Pipe of `vdd-stream`, it speaks [`StreamCommand`] instead of [`Command`]
+pub const STREAM_PIPE_NAME: &str = "virtualdisplaydriver-stream";
+
+// Shortest [`StreamSettings::token`] `vdd-stream` accepts, it's all that keeps others on the
+// network from watching
+pub const MIN_STREAM_TOKEN_LEN: usize = 16;
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct StreamSettings {
+    pub id: Id,
+    // tcp port the stream is served on, on all interfaces
+    pub port: u16,
+    // clients have to pass this as `?token=` to watch
+    pub token: String,
+    // jpeg quality, 1-100
+    pub quality: u8,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct StreamInfo {
+    pub settings: StreamSettings,
+    // clients currently watching
+    pub clients: u32,
+    // why streaming stopped, if it stopped on its own
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum StreamCommand {
+    // Requests
+    // client->server
+    //
+    // Start serving a monitor with gpu export enabled, restarting it if it's already served
+    RequestStart(StreamSettings),
+    // Stop serving a monitor, disconnecting its clients
+    RequestStop(Id),
+    // Request all served monitors
+    RequestStatus,
+    // Replies to request
+    // server->client
+    ReplyOk,
+    ReplyError(String),
+    ReplyStatus(Vec<StreamInfo>),
+}
+
+// Pipe of `vdd-server --leases`, it speaks [`LeaseCommand`] instead of [`Command`]
+pub const LEASE_PIPE_NAME: &str = "virtualdisplaydriver-lease";
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Lease {
+    pub id: Id,
+    // gdi name of the monitor's display, e.g. `\\.\DISPLAY3`
+    pub display: String,
+    pub target: DisplayTarget,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub enum LeaseCommand {
+    // Requests
+    // client->server
+    //
+    // Get a monitor windows drives in exactly this mode: one that already is, a disabled one
+    // preferring it, or a new one. Replies once windows shows it
+    RequestLease(ActiveMode),
+    // Undo what a lease changed: remove the monitor if it was added for it, or disable it again.
+    // Leases are also released when the client disconnects
+    RequestRelease(Id),
+    // Replies to request
+    // server->client
+    ReplyOk,
+    ReplyError(String),
+    ReplyLease(Lease),
+}
+
+// Registry key under HKEY_CURRENT_USER with the persisted settings. Its `data` value holds the
+// json `Vec<Monitor>` the driver restores when it starts
+pub const SETTINGS_KEY: &str = r"SOFTWARE\VirtualDisplayDriver";
+// Value of [`SETTINGS_KEY`] with the json `Vec<ScheduleEntry>` of `vdd-server --schedule`
+pub const SCHEDULE_VALUE: &str = "schedule";
+// Value of [`SETTINGS_KEY`] with the json `Vec<Rule>`, also applied by `vdd-server --schedule`
+pub const RULES_VALUE: &str = "rules";
+// Value of [`SETTINGS_KEY`] with the json [`HookSession`] of `virtual-display-driver-cli hook`
+pub const HOOK_VALUE: &str = "hook";
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub enum Trigger {
+    // When `vdd-server` starts, which is meant to run at login. Replaces all monitors
+    Login,
+    // Every day at this local time. Replaces all monitors
+    At { hour: u8, minute: u8 },
+    // When a process with this executable name (e.g. `sunshine.exe`) starts. Adds the profile's
+    // monitors, and removes them again when the process exits
+    Process(String),
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct ScheduleEntry {
+    pub trigger: Trigger,
+    // json file with the monitors to load, as printed by `virtual-display-driver-cli list --json`
+    pub profile: String,
+}
+
+// Keeps a monitor enabled (or disabled) while a process runs
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct Rule {
+    // executable name of the process, e.g. `parsecd.exe`
+    pub process: String,
+    // ID or name of the monitor
+    pub monitor: String,
+    // state of the monitor while the process runs, it's flipped when the process exits
+    pub enabled: bool,
+}
+
+// A profile loaded by `virtual-display-driver-cli hook pre-launch` before a game. It's undone by
+// `hook post-launch`, or by `vdd-server --schedule` once the launcher exits
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct HookSession {
+    pub profile: String,
+    // the profile's monitors that already existed, as they were before
+    pub previous: Vec<Monitor>,
+    // the profile's monitors that didn't exist
+    pub added: Vec<Id>,
+    // PID of the launcher, and its creation time as a FILETIME, so a later process with the same
+    // PID isn't mistaken for it
+    pub launcher: u32,
+    pub launcher_started: u64,
 }
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+
+/// Largest number of entries of a 1D [`Lut`]
+pub const MAX_LUT_1D_SIZE: usize = 65536;
+/// Largest number of entries per axis of a 3D [`Lut`]
+pub const MAX_LUT_3D_SIZE: u32 = 65;
+
+/// Color lookup table of a monitor, mapping the colors of its frames to those of the device they
+/// are shown on
+///
+/// Entries are red, green and blue from 0 to 65535, like [`GammaRamp`](crate::GammaRamp)s.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Lut {
+    /// Entries spread evenly from black to white, applied to each channel on its own
+    OneD(Vec<[u16; 3]>),
+    /// `size` entries per axis, red changing fastest, then green, then blue, as in .cube files
+    ThreeD { size: u32, table: Vec<[u16; 3]> },
+}
+
+impl Lut {
+    /// Whether the table has as many entries as its size says, and a supported size
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::OneD(table) => (2..=MAX_LUT_1D_SIZE).contains(&table.len()),
+            Self::ThreeD { size, table } => {
+                (2..=MAX_LUT_3D_SIZE).contains(size) && table.len() == (*size as usize).pow(3)
+            }
+        }
+    }
+
+    /// Map a color, interpolating between the nearest entries
+    ///
+    /// # Panics
+    ///
+    /// If the table isn't [valid](Self::is_valid)
+    #[must_use]
+    pub fn apply(&self, rgb: [u8; 3]) -> [u8; 3] {
+        match self {
+            Self::OneD(table) => {
+                let mut out = [0; 3];
+                for (channel, out) in out.iter_mut().enumerate() {
+                    let (index, t) = position(rgb[channel], table.len());
+                    let low = f32::from(table[index][channel]);
+                    let high = f32::from(table[index + 1][channel]);
+                    *out = to_u8(low + (high - low) * t);
+                }
+                out
+            }
+
+            Self::ThreeD { size, table } => {
+                let size = *size as usize;
+                let [(r, tr), (g, tg), (b, tb)] = rgb.map(|value| position(value, size));
+                let entry = |r: usize, g: usize, b: usize| table[r + g * size + b * size * size];
+
+                let mut out = [0; 3];
+                for (channel, out) in out.iter_mut().enumerate() {
+                    let at = |r, g, b| f32::from(entry(r, g, b)[channel]);
+                    let lerp = |low: f32, high: f32, t: f32| low + (high - low) * t;
+
+                    // trilinear, first along red, then green, then blue
+                    let g0 = lerp(at(r, g, b), at(r + 1, g, b), tr);
+                    let g1 = lerp(at(r, g + 1, b), at(r + 1, g + 1, b), tr);
+                    let b0 = lerp(g0, g1, tg);
+                    let g0 = lerp(at(r, g, b + 1), at(r + 1, g, b + 1), tr);
+                    let g1 = lerp(at(r, g + 1, b + 1), at(r + 1, g + 1, b + 1), tr);
+                    let b1 = lerp(g0, g1, tg);
+
+                    *out = to_u8(lerp(b0, b1, tb));
+                }
+                out
+            }
+        }
+    }
+}
+
+// the entry below `value` in a table of `len` entries, and how far `value` is towards the next one
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn position(value: u8, len: usize) -> (usize, f32) {
+    #[allow(clippy::cast_precision_loss)]
+    let scaled = f32::from(value) / 255.0 * (len - 1) as f32;
+    // the last entry has no next one, so white interpolates from the one before fully
+    let index = (scaled as usize).min(len - 2);
+
+    #[allow(clippy::cast_precision_loss)]
+    (index, scaled - index as f32)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_u8(value: f32) -> u8 {
+    (value / 257.0).round().clamp(0.0, 255.0) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn identity_3d(size: u32) -> Lut {
+        let max = size - 1;
+        let level = |i: u32| u16::try_from(i * 65535 / max).unwrap();
+
+        let table = (0..size.pow(3))
+            .map(|i| {
+                [
+                    level(i % size),
+                    level(i / size % size),
+                    level(i / size / size),
+                ]
+            })
+            .collect();
+        Lut::ThreeD { size, table }
+    }
+
+    #[test]
+    fn identity_keeps_colors() {
+        let lut = identity_3d(17);
+        assert!(lut.is_valid());
+
+        for rgb in [[0, 0, 0], [255, 255, 255], [12, 128, 250], [77, 3, 199]] {
+            assert_eq!(lut.apply(rgb), rgb);
+        }
+    }
+
+    #[test]
+    fn one_d_interpolates() {
+        // inverts the colors
+        let lut = Lut::OneD(vec![[65535; 3], [0; 3]]);
+        assert!(lut.is_valid());
+
+        assert_eq!(lut.apply([0, 255, 100]), [255, 0, 155]);
+    }
+
+    #[test]
+    fn wrong_sizes_are_invalid() {
+        assert!(!Lut::OneD(vec![[0; 3]]).is_valid());
+        assert!(!Lut::ThreeD {
+            size: 2,
+            table: vec![[0; 3]; 7]
+        }
+        .is_valid());
+        assert!(!Lut::ThreeD {
+            size: MAX_LUT_3D_SIZE + 1,
+            table: Vec::new()
+        }
+        .is_valid());
+    }
+}
@@ -0,0 +1,293 @@
+use std::{
+    io::{self, Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+};
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Port `vdd-server --agent` listens on unless another is given
+pub const DEFAULT_AGENT_PORT: u16 = 9900;
+
+// sent first by clients, so other protocols on the port fail fast
+const MAGIC: &[u8; 4] = b"VDD1";
+const NONCE_LEN: usize = 32;
+const TAG_LEN: usize = 32;
+// shorter keys are too easy to guess
+const MIN_KEY_LEN: usize = 16;
+// messages larger than this are treated as an error instead of allocating them
+const MAX_MESSAGE_SIZE: usize = 64 * 1024 * 1024;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Receiving half of an authenticated connection to a remote agent
+///
+/// Connections carry the same json [`Command`](crate::Command)s as the pipe. Both ends prove they
+/// know a shared key, and every message is tagged with it, so messages can't be forged, replayed
+/// or reordered. Messages aren't encrypted.
+pub struct RemoteReader {
+    stream: TcpStream,
+    keys: Keys,
+    direction: u8,
+    sequence: u64,
+}
+
+/// Sending half of an authenticated connection, see [`RemoteReader`]
+pub struct RemoteWriter {
+    stream: TcpStream,
+    keys: Keys,
+    direction: u8,
+    sequence: u64,
+}
+
+#[derive(Clone)]
+struct Keys {
+    key: Vec<u8>,
+    // both nonces, binding messages to the connection
+    session: [u8; NONCE_LEN * 2],
+}
+
+// message directions, so a message can't be reflected back to its sender
+const TO_AGENT: u8 = 0;
+const TO_CLIENT: u8 = 1;
+
+/// Connect to an agent at `addr`, authenticating each other with `key`
+pub fn connect(addr: impl ToSocketAddrs, key: &[u8]) -> io::Result<(RemoteReader, RemoteWriter)> {
+    check_key(key)?;
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_nodelay(true)?;
+
+    let client_nonce = nonce()?;
+    let mut hello = MAGIC.to_vec();
+    hello.extend_from_slice(&client_nonce);
+    stream.write_all(&hello)?;
+
+    let mut server_nonce = [0; NONCE_LEN];
+    stream.read_exact(&mut server_nonce)?;
+    let mut server_proof = [0; TAG_LEN];
+    stream.read_exact(&mut server_proof)?;
+
+    let keys = Keys::new(key, &client_nonce, &server_nonce);
+    keys.mac(b"agent")
+        .verify_slice(&server_proof)
+        .map_err(|_| denied("the agent doesn't know the key"))?;
+
+    stream.write_all(&keys.mac(b"client").finalize().into_bytes())?;
+
+    halves(stream, keys, TO_CLIENT, TO_AGENT)
+}
+
+/// Authenticate a client that connected to an agent with `key`
+pub fn accept(mut stream: TcpStream, key: &[u8]) -> io::Result<(RemoteReader, RemoteWriter)> {
+    check_key(key)?;
+    stream.set_nodelay(true)?;
+
+    let mut magic = [0; MAGIC.len()];
+    stream.read_exact(&mut magic)?;
+    if magic != *MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not a virtual display driver client",
+        ));
+    }
+    let mut client_nonce = [0; NONCE_LEN];
+    stream.read_exact(&mut client_nonce)?;
+
+    let server_nonce = nonce()?;
+    let keys = Keys::new(key, &client_nonce, &server_nonce);
+    let mut challenge = server_nonce.to_vec();
+    challenge.extend_from_slice(&keys.mac(b"agent").finalize().into_bytes());
+    stream.write_all(&challenge)?;
+
+    let mut client_proof = [0; TAG_LEN];
+    stream.read_exact(&mut client_proof)?;
+    keys.mac(b"client")
+        .verify_slice(&client_proof)
+        .map_err(|_| denied("the client doesn't know the key"))?;
+
+    halves(stream, keys, TO_AGENT, TO_CLIENT)
+}
+
+impl RemoteReader {
+    /// Read the next message, or `None` once the other end closed the connection
+    pub fn read_message(&mut self) -> io::Result<Option<Vec<u8>>> {
+        let mut len = [0; 4];
+        match self.stream.read_exact(&mut len) {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e),
+        }
+
+        let len = u32::from_le_bytes(len) as usize;
+        if len > MAX_MESSAGE_SIZE {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "message too large",
+            ));
+        }
+
+        let mut message = vec![0; len];
+        self.stream.read_exact(&mut message)?;
+        let mut tag = [0; TAG_LEN];
+        self.stream.read_exact(&mut tag)?;
+
+        self.keys
+            .message_mac(self.direction, self.sequence, &message)
+            .verify_slice(&tag)
+            .map_err(|_| denied("message failed authentication"))?;
+        self.sequence += 1;
+
+        Ok(Some(message))
+    }
+}
+
+impl RemoteWriter {
+    /// Send a message as a whole
+    pub fn write_message(&mut self, message: &[u8]) -> io::Result<()> {
+        let len = u32::try_from(message.len())
+            .ok()
+            .filter(|&len| len as usize <= MAX_MESSAGE_SIZE)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "message too large"))?;
+
+        let tag = self
+            .keys
+            .message_mac(self.direction, self.sequence, message)
+            .finalize()
+            .into_bytes();
+        self.sequence += 1;
+
+        let mut frame = Vec::with_capacity(4 + message.len() + TAG_LEN);
+        frame.extend_from_slice(&len.to_le_bytes());
+        frame.extend_from_slice(message);
+        frame.extend_from_slice(&tag);
+        self.stream.write_all(&frame)
+    }
+}
+
+impl Keys {
+    fn new(key: &[u8], client_nonce: &[u8; NONCE_LEN], server_nonce: &[u8; NONCE_LEN]) -> Self {
+        let mut session = [0; NONCE_LEN * 2];
+        session[..NONCE_LEN].copy_from_slice(client_nonce);
+        session[NONCE_LEN..].copy_from_slice(server_nonce);
+
+        Self {
+            key: key.to_vec(),
+            session,
+        }
+    }
+
+    // proof that `role` knows the key, over the nonces of both ends
+    fn mac(&self, role: &[u8]) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.key).expect("hmac takes keys of any size");
+        mac.update(role);
+        mac.update(&self.session);
+        mac
+    }
+
+    fn message_mac(&self, direction: u8, sequence: u64, message: &[u8]) -> HmacSha256 {
+        let mut mac = self.mac(b"message");
+        mac.update(&[direction]);
+        mac.update(&sequence.to_le_bytes());
+        mac.update(message);
+        mac
+    }
+}
+
+fn halves(
+    stream: TcpStream,
+    keys: Keys,
+    incoming: u8,
+    outgoing: u8,
+) -> io::Result<(RemoteReader, RemoteWriter)> {
+    let reader = RemoteReader {
+        stream: stream.try_clone()?,
+        keys: keys.clone(),
+        direction: incoming,
+        sequence: 0,
+    };
+    let writer = RemoteWriter {
+        stream,
+        keys,
+        direction: outgoing,
+        sequence: 0,
+    };
+
+    Ok((reader, writer))
+}
+
+fn check_key(key: &[u8]) -> io::Result<()> {
+    if key.len() < MIN_KEY_LEN {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("the key must be at least {MIN_KEY_LEN} bytes long"),
+        ));
+    }
+
+    Ok(())
+}
+
+fn nonce() -> io::Result<[u8; NONCE_LEN]> {
+    let mut nonce = [0; NONCE_LEN];
+    getrandom::getrandom(&mut nonce).map_err(io::Error::from)?;
+
+    Ok(nonce)
+}
+
+fn denied(reason: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, reason)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::TcpListener, thread};
+
+    use super::*;
+
+    const KEY: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    fn agent(key: &'static [u8]) -> (u16, thread::JoinHandle<io::Result<Vec<u8>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let agent = thread::spawn(move || {
+            let (stream, _) = listener.accept()?;
+            let (mut reader, mut writer) = accept(stream, key)?;
+
+            let message = reader.read_message()?.unwrap_or_default();
+            writer.write_message(&message)?;
+            Ok(message)
+        });
+
+        (port, agent)
+    }
+
+    #[test]
+    fn messages_round_trip() {
+        let (port, agent) = agent(KEY);
+
+        let (mut reader, mut writer) = connect(("127.0.0.1", port), KEY).unwrap();
+        writer.write_message(b"\"RequestState\"").unwrap();
+
+        assert_eq!(
+            reader.read_message().unwrap().as_deref(),
+            Some(&b"\"RequestState\""[..])
+        );
+        assert_eq!(agent.join().unwrap().unwrap(), b"\"RequestState\"");
+        assert!(reader.read_message().unwrap().is_none());
+    }
+
+    #[test]
+    fn wrong_key_is_rejected() {
+        let (port, agent) = agent(b"fedcba9876543210fedcba9876543210");
+
+        let error = connect(("127.0.0.1", port), KEY).err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+        assert!(agent.join().unwrap().is_err());
+    }
+
+    #[test]
+    fn short_key_is_rejected() {
+        let error = connect("127.0.0.1:1", b"short").err().unwrap();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -0,0 +1,217 @@
+//! Layout of the header of a monitor's frame ring, see [`SharedRing`](crate::SharedRing)
+//!
+//! The driver writes frames round robin into the ring's textures, skipping ones a consumer is
+//! still reading, and records which frame each texture holds. Every consumer claims an entry in
+//! the header and keeps its read cursor there, the frame it read last, so each one takes frames at
+//! its own pace, and the driver knows how many consumers there are and how fast they read.
+
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+/// First field of every [`RingHeader`], "VDDR"
+pub const RING_MAGIC: u32 = u32::from_le_bytes(*b"VDDR");
+/// Textures in a ring
+pub const RING_SLOTS: usize = 4;
+/// Consumers that can read from a ring at once
+pub const RING_CONSUMERS: usize = 8;
+
+/// Shared memory of a frame ring, mapped by the driver and every consumer
+///
+/// Frames are numbered from 1 in the order they're written, 0 means none.
+#[repr(C)]
+#[derive(Debug)]
+pub struct RingHeader {
+    pub magic: u32,
+    /// Textures in the ring, at most [`RING_SLOTS`]
+    pub slots: u32,
+    /// The newest frame
+    pub latest: AtomicU64,
+    /// The frame each texture holds, by slot
+    pub frames: [AtomicU64; RING_SLOTS],
+    pub consumers: [RingConsumer; RING_CONSUMERS],
+}
+
+/// A consumer's entry in a [`RingHeader`]
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct RingConsumer {
+    /// Process id of the consumer, 0 if the entry is free
+    pub pid: AtomicU32,
+    reserved: u32,
+    /// The frame the consumer read last, its read cursor
+    pub cursor: AtomicU64,
+    /// How many frames the consumer read
+    pub reads: AtomicU64,
+    /// How many frames the consumer skipped, because newer ones came before it read them
+    pub dropped: AtomicU64,
+}
+
+impl RingHeader {
+    /// Header of a ring of `slots` textures, without frames or consumers
+    #[must_use]
+    pub fn new(slots: usize) -> Self {
+        Self {
+            magic: RING_MAGIC,
+            // at most `RING_SLOTS`
+            #[allow(clippy::cast_possible_truncation)]
+            slots: slots.min(RING_SLOTS) as u32,
+            latest: AtomicU64::new(0),
+            frames: Default::default(),
+            consumers: Default::default(),
+        }
+    }
+
+    /// Claim a free entry for the consumer with process id `pid`, `None` if all are taken
+    pub fn claim(&self, pid: u32) -> Option<usize> {
+        self.consumers.iter().position(|consumer| {
+            let claimed = consumer
+                .pid
+                .compare_exchange(0, pid, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok();
+
+            if claimed {
+                // frames written before the consumer came don't count as skipped
+                consumer
+                    .cursor
+                    .store(self.latest.load(Ordering::Acquire), Ordering::Relaxed);
+                consumer.reads.store(0, Ordering::Relaxed);
+                consumer.dropped.store(0, Ordering::Relaxed);
+            }
+
+            claimed
+        })
+    }
+
+    /// Free a consumer's entry again
+    pub fn leave(&self, consumer: usize) {
+        if let Some(consumer) = self.consumers.get(consumer) {
+            consumer.pid.store(0, Ordering::Release);
+        }
+    }
+
+    /// The slot of the newest frame, if `consumer` hasn't read it yet
+    #[must_use]
+    pub fn newest(&self, consumer: usize) -> Option<usize> {
+        let cursor = self.consumers.get(consumer)?.cursor.load(Ordering::Relaxed);
+        let latest = self.latest.load(Ordering::Acquire);
+        if latest <= cursor {
+            return None;
+        }
+
+        self.slots()
+            .find(|&slot| self.frames[slot].load(Ordering::Acquire) == latest)
+    }
+
+    /// Move `consumer`'s read cursor to the frame in `slot`, once it read it while holding the
+    /// slot's texture
+    ///
+    /// Returns how many frames the consumer skipped since the one it read before, which are
+    /// counted as dropped.
+    pub fn read(&self, consumer: usize, slot: usize) -> u64 {
+        let (Some(consumer), Some(frame)) = (self.consumers.get(consumer), self.frames.get(slot))
+        else {
+            return 0;
+        };
+
+        let frame = frame.load(Ordering::Acquire);
+        let cursor = consumer.cursor.swap(frame, Ordering::Relaxed);
+        consumer.reads.fetch_add(1, Ordering::Relaxed);
+
+        let skipped = frame.saturating_sub(cursor).saturating_sub(1);
+        consumer.dropped.fetch_add(skipped, Ordering::Relaxed);
+
+        skipped
+    }
+
+    /// Slots from the one with the oldest frame to the one with the newest, the order the driver
+    /// tries to write them in
+    #[must_use]
+    pub fn oldest(&self) -> Vec<usize> {
+        let mut slots = self.slots().collect::<Vec<_>>();
+        slots.sort_by_key(|&slot| self.frames[slot].load(Ordering::Relaxed));
+        slots
+    }
+
+    /// Record that the driver wrote the next frame into `slot`, and return its number
+    pub fn written(&self, slot: usize) -> u64 {
+        let frame = self.latest.load(Ordering::Relaxed) + 1;
+
+        if let Some(slot) = self.frames.get(slot) {
+            slot.store(frame, Ordering::Release);
+        }
+        self.latest.store(frame, Ordering::Release);
+
+        frame
+    }
+
+    fn slots(&self) -> impl Iterator<Item = usize> {
+        0..(self.slots as usize).min(RING_SLOTS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consumers_claim_free_entries() {
+        let header = RingHeader::new(3);
+
+        assert_eq!(header.claim(10), Some(0));
+        assert_eq!(header.claim(11), Some(1));
+        header.leave(0);
+        assert_eq!(header.claim(12), Some(0));
+
+        for pid in 0..RING_CONSUMERS {
+            header.claim(100 + u32::try_from(pid).unwrap());
+        }
+        assert_eq!(header.claim(200), None);
+    }
+
+    #[test]
+    fn consumers_read_at_their_own_pace() {
+        let header = RingHeader::new(3);
+        let fast = header.claim(1).unwrap();
+        let slow = header.claim(2).unwrap();
+        assert_eq!(header.newest(fast), None);
+
+        for expected in 1..=4 {
+            let slot = header.oldest()[0];
+            assert_eq!(header.written(slot), expected);
+
+            let newest = header.newest(fast).unwrap();
+            assert_eq!(header.read(fast, newest), 0);
+            assert_eq!(header.newest(fast), None);
+        }
+
+        // the slow consumer only gets the newest frame, and learns what it missed
+        let newest = header.newest(slow).unwrap();
+        assert_eq!(header.frames[newest].load(Ordering::Relaxed), 4);
+        assert_eq!(header.read(slow, newest), 3);
+        assert_eq!(header.consumers[fast].reads.load(Ordering::Relaxed), 4);
+        assert_eq!(header.consumers[slow].reads.load(Ordering::Relaxed), 1);
+        assert_eq!(header.consumers[slow].dropped.load(Ordering::Relaxed), 3);
+        assert_eq!(header.consumers[fast].dropped.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn late_consumers_skip_nothing() {
+        let header = RingHeader::new(2);
+        header.written(0);
+        header.written(1);
+
+        let consumer = header.claim(1).unwrap();
+        assert_eq!(header.newest(consumer), None);
+
+        header.written(0);
+        assert_eq!(header.read(consumer, 0), 0);
+    }
+
+    #[test]
+    fn oldest_slot_first() {
+        let header = RingHeader::new(3);
+        header.written(2);
+        header.written(0);
+
+        assert_eq!(header.oldest(), [1, 2, 0]);
+    }
+}
@@ -0,0 +1,65 @@
+//! JSON Schema of the json the driver speaks, so other tools can validate what they send, e.g.
+//! with PowerShell's `Test-Json -Schema`
+
+use schemars::{schema::RootSchema, schema_for};
+
+use crate::{Command, Mode, Monitor};
+
+/// Schema of a single [`Monitor`], as in [`Command::DriverNotify`] and [`Command::ReplyState`]
+#[must_use]
+pub fn monitor() -> RootSchema {
+    schema_for!(Monitor)
+}
+
+/// Schema of a single [`Mode`] of a monitor
+#[must_use]
+pub fn mode() -> RootSchema {
+    schema_for!(Mode)
+}
+
+/// Schema of every [`Command`] sent to and received from the driver
+#[must_use]
+pub fn command() -> RootSchema {
+    schema_for!(Command)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::Value;
+
+    use super::*;
+
+    fn to_value(schema: &RootSchema) -> Value {
+        serde_json::to_value(schema).unwrap()
+    }
+
+    #[test]
+    fn monitor_schema() {
+        let schema = to_value(&monitor());
+
+        assert_eq!(schema["title"], "Monitor");
+        for field in ["id", "enabled", "modes"] {
+            assert!(schema["required"]
+                .as_array()
+                .unwrap()
+                .contains(&field.into()));
+        }
+        // fields with defaults can be left out
+        assert!(!schema["required"]
+            .as_array()
+            .unwrap()
+            .contains(&"auto_plug".into()));
+        assert!(schema["definitions"]["Mode"].is_object());
+    }
+
+    #[test]
+    fn command_schema() {
+        let schema = to_value(&command());
+
+        assert_eq!(schema["title"], "Command");
+        let variants = serde_json::to_string(&schema["oneOf"]).unwrap();
+        for command in ["DriverNotify", "RequestState", "ReplyState"] {
+            assert!(variants.contains(command), "{command} missing");
+        }
+    }
+}
@@ -1,12 +1,15 @@
 #![allow(clippy::missing_errors_doc)]
 
+mod ring;
 mod win_debug;
 mod win_logger;
 
 use std::error::Error;
 
-use log::{Level, Log};
+use log::{Level, LevelFilter, Log};
 
+use crate::ring::RingLogger;
+pub use crate::ring::{records_since, RingRecord};
 use crate::win_debug::WinDebugLogger;
 use crate::win_logger::WinLogger;
 
@@ -15,6 +18,7 @@ pub struct DriverLogger {
     pub level: Level,
     win_debug: Option<WinDebugLogger>,
     win_logger: Option<WinLogger>,
+    ring: Option<&'static RingLogger>,
 }
 
 impl DriverLogger {
@@ -24,11 +28,21 @@ impl DriverLogger {
             level,
             win_logger: None,
             win_debug: None,
+            ring: None,
         }
     }
 
     pub fn debug(&mut self) -> &mut Self {
-        self.win_debug = Some(WinDebugLogger { level: self.level });
+        // filtering is done by `DriverLogger`, since the level can change at runtime
+        self.win_debug = Some(WinDebugLogger {
+            level: Level::Trace,
+        });
+        self
+    }
+
+    /// Keep the last `capacity` records in memory, see [`records_since`]
+    pub fn ring(&mut self, capacity: usize) -> &mut Self {
+        self.ring = Some(RingLogger::global(capacity));
         self
     }
 
@@ -50,9 +64,14 @@ impl DriverLogger {
     }
 }
 
+/// Change the log level at runtime
+pub fn set_level(level: LevelFilter) {
+    log::set_max_level(level);
+}
+
 impl Log for DriverLogger {
     fn enabled(&self, metadata: &log::Metadata) -> bool {
-        metadata.level() <= self.level
+        metadata.level() <= log::max_level()
     }
 
     fn log(&self, record: &log::Record) {
@@ -67,6 +86,10 @@ impl Log for DriverLogger {
         if let Some(logger) = self.win_logger.as_ref() {
             logger.log(record);
         }
+
+        if let Some(ring) = self.ring {
+            ring.log(record);
+        }
     }
 
     fn flush(&self) {
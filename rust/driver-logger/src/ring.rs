@@ -0,0 +1,96 @@
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, OnceLock},
+    time::SystemTime,
+};
+
+use log::{Level, Log, Metadata, Record};
+
+static RING: OnceLock<RingLogger> = OnceLock::new();
+
+/// A log record kept in the ring buffer
+#[derive(Debug, Clone)]
+pub struct RingRecord {
+    /// Monotonically increasing sequence number, starting at 1
+    pub seq: u64,
+    pub timestamp: SystemTime,
+    pub level: Level,
+    pub target: String,
+    pub message: String,
+}
+
+/// Keeps the most recent log records in memory, so clients can fetch them
+#[derive(Debug)]
+pub struct RingLogger {
+    capacity: usize,
+    inner: Mutex<Ring>,
+}
+
+#[derive(Debug, Default)]
+struct Ring {
+    next_seq: u64,
+    records: VecDeque<RingRecord>,
+}
+
+impl RingLogger {
+    /// Get the global ring logger, creating it with `capacity` if it doesn't exist yet
+    pub(crate) fn global(capacity: usize) -> &'static Self {
+        RING.get_or_init(|| Self {
+            capacity,
+            inner: Mutex::new(Ring {
+                next_seq: 1,
+                records: VecDeque::with_capacity(capacity),
+            }),
+        })
+    }
+}
+
+impl Log for RingLogger {
+    fn enabled(&self, _metadata: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let Ok(mut ring) = self.inner.lock() else {
+            return;
+        };
+
+        if ring.records.len() == self.capacity {
+            ring.records.pop_front();
+        }
+
+        let seq = ring.next_seq;
+        ring.next_seq += 1;
+
+        ring.records.push_back(RingRecord {
+            seq,
+            timestamp: SystemTime::now(),
+            level: record.level(),
+            target: record.target().to_owned(),
+            message: record.args().to_string(),
+        });
+    }
+
+    fn flush(&self) {}
+}
+
+/// Get up to `limit` buffered records with a sequence number greater than `since`
+///
+/// Returns nothing if the ring buffer was never enabled, see [`crate::DriverLogger::ring`]
+#[must_use]
+pub fn records_since(since: u64, limit: usize) -> Vec<RingRecord> {
+    let Some(ring) = RING.get() else {
+        return Vec::new();
+    };
+
+    let Ok(ring) = ring.inner.lock() else {
+        return Vec::new();
+    };
+
+    ring.records
+        .iter()
+        .skip_while(|record| record.seq <= since)
+        .take(limit)
+        .cloned()
+        .collect()
+}
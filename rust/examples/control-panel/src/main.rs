@@ -0,0 +1,140 @@
+//! Small egui control panel: list, add, enable/disable, and remove virtual monitors
+//!
+//! Run with `cargo run -p control-panel`.
+
+use driver_ipc::{Client, Command, Mode, Monitor, MonitorControl as _};
+use eframe::egui;
+use eyre::Context as _;
+
+fn main() -> eyre::Result<()> {
+    let client = Client::connect(driver_ipc::DEFAULT_PIPE_NAME)
+        .context("Failed to connect to the driver, is it installed?")?;
+
+    eframe::run_native(
+        "Virtual Display Control Panel",
+        eframe::NativeOptions::default(),
+        Box::new(|_cc| Box::new(ControlPanel::new(client))),
+    )
+    .map_err(|e| eyre::eyre!("{e}"))
+}
+
+struct ControlPanel {
+    client: Client,
+    monitors: Vec<Monitor>,
+    status: Option<String>,
+}
+
+impl ControlPanel {
+    fn new(client: Client) -> Self {
+        let mut panel = Self {
+            client,
+            monitors: Vec::new(),
+            status: None,
+        };
+        panel.refresh();
+
+        panel
+    }
+
+    fn refresh(&mut self) {
+        match self.client.list() {
+            Ok(monitors) => self.monitors = monitors,
+            Err(e) => self.status = Some(format!("Failed to get state: {e}")),
+        }
+    }
+
+    fn apply(&mut self, command: &Command) {
+        if let Err(e) = self.client.send(command) {
+            self.status = Some(format!("Failed to send command: {e}"));
+        }
+
+        self.refresh();
+    }
+
+    fn add(&mut self) {
+        #[allow(clippy::maybe_infinite_iter)]
+        let id = (0..)
+            .find(|id| self.monitors.iter().all(|monitor| monitor.id != *id))
+            .expect("ran out of ids");
+
+        let monitor = Monitor::new(
+            id,
+            vec![Mode {
+                width: 1920,
+                height: 1080,
+                refresh_rates: vec![60],
+            }],
+        );
+
+        self.apply(&Command::DriverNotify(vec![monitor]));
+    }
+}
+
+impl eframe::App for ControlPanel {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Add 1920x1080").clicked() {
+                    self.add();
+                }
+                if ui.button("Refresh").clicked() {
+                    self.refresh();
+                }
+                if ui.button("Remove all").clicked() {
+                    self.apply(&Command::DriverRemoveAll);
+                }
+            });
+
+            ui.separator();
+
+            // changes are collected first, as applying them refreshes `self.monitors`
+            let mut changed = None;
+            let mut removed = None;
+
+            for monitor in &self.monitors {
+                ui.horizontal(|ui| {
+                    let name = monitor.name.as_deref().unwrap_or("unnamed");
+                    ui.label(format!("{} [{name}]", monitor.id));
+
+                    let mut enabled = monitor.enabled;
+                    if ui.checkbox(&mut enabled, "enabled").changed() {
+                        changed = Some(Monitor {
+                            enabled,
+                            ..monitor.clone()
+                        });
+                    }
+
+                    let modes = monitor
+                        .modes
+                        .iter()
+                        .map(|mode| {
+                            format!("{}x{}@{:?}", mode.width, mode.height, mode.refresh_rates)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    ui.label(modes);
+
+                    if ui.button("Remove").clicked() {
+                        removed = Some(monitor.id);
+                    }
+                });
+            }
+
+            if self.monitors.is_empty() {
+                ui.label("No virtual monitors");
+            }
+
+            if let Some(monitor) = changed {
+                self.apply(&Command::DriverNotify(vec![monitor]));
+            }
+            if let Some(id) = removed {
+                self.apply(&Command::DriverRemove(vec![id.into()]));
+            }
+
+            if let Some(status) = &self.status {
+                ui.separator();
+                ui.colored_label(egui::Color32::RED, status);
+            }
+        });
+    }
+}
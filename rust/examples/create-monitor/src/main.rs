@@ -0,0 +1,45 @@
+//! Minimal example: add a virtual monitor, wait for enter, then remove it again
+//!
+//! Talks to the driver pipe with the `driver-ipc` client. Run with
+//! `cargo run -p create-monitor`.
+
+use std::io;
+
+use driver_ipc::{Client, Mode, Monitor, MonitorControl as _};
+use eyre::Context as _;
+
+fn main() -> eyre::Result<()> {
+    let mut client = Client::connect(driver_ipc::DEFAULT_PIPE_NAME)
+        .context("Failed to connect to the driver, is it installed?")?;
+
+    // the current state is needed to pick an unused id
+    let monitors = client.list()?;
+
+    #[allow(clippy::maybe_infinite_iter)]
+    let id = (0..)
+        .find(|id| monitors.iter().all(|monitor| monitor.id != *id))
+        .expect("ran out of ids");
+
+    let modes = vec![Mode {
+        width: 1920,
+        height: 1080,
+        refresh_rates: vec![60, 120],
+    }];
+    let monitor = Monitor {
+        name: Some("example".to_owned()),
+        // it is removed again below, no need to restore it after a reboot
+        ephemeral: true,
+        ..Monitor::new(id, modes)
+    };
+
+    // notify only sends the given monitors, the others are left as is
+    client.notify(vec![monitor])?;
+    println!("Added virtual monitor {id}, press enter to remove it");
+
+    io::stdin().read_line(&mut String::new())?;
+
+    client.remove(vec![id])?;
+    println!("Removed virtual monitor {id}");
+
+    Ok(())
+}
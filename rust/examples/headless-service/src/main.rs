@@ -0,0 +1,77 @@
+//! Headless service: keeps the driver in sync with a json file of monitors
+//!
+//! The monitors are re-applied whenever the file changes, or when the driver state drifts away
+//! from it (e.g. the driver restarted, or another client changed it). Reconnects if the driver
+//! goes away. Run with `cargo run -p headless-service -- monitors.json`, where the file holds a
+//! list of monitors in the same format as `virtual-display-driver-cli --json list` prints.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use driver_ipc::{Client, Monitor, MonitorControl as _};
+use eyre::{bail, Context as _};
+
+// How often the driver state is compared against the file
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+fn main() -> eyre::Result<()> {
+    let Some(path) = env::args_os().nth(1).map(PathBuf::from) else {
+        bail!("usage: headless-service <monitors.json>");
+    };
+
+    loop {
+        if let Err(e) = run(&path) {
+            eprintln!("Lost connection to the driver: {e:?}");
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn run(path: &Path) -> eyre::Result<()> {
+    let mut client = Client::connect(driver_ipc::DEFAULT_PIPE_NAME)
+        .context("Failed to connect to the driver")?;
+
+    println!("Connected to the driver");
+
+    let mut loaded_at: Option<SystemTime> = None;
+    let mut wanted = Vec::<Monitor>::new();
+
+    loop {
+        let modified = fs::metadata(path)?.modified()?;
+        if loaded_at != Some(modified) {
+            wanted = serde_json::from_slice(&fs::read(path)?)
+                .with_context(|| format!("Failed to parse {}", path.display()))?;
+            loaded_at = Some(modified);
+
+            println!("Loaded {} monitors from {}", wanted.len(), path.display());
+        }
+
+        let current = client.list()?;
+
+        // the driver doesn't keep the order monitors were sent in
+        let in_sync =
+            current.len() == wanted.len() && wanted.iter().all(|monitor| current.contains(monitor));
+        if !in_sync {
+            println!("Driver state differs from the file, applying it");
+
+            // monitors not in the file are removed, the rest are added or updated
+            let stale = current
+                .iter()
+                .map(|monitor| monitor.id)
+                .filter(|id| wanted.iter().all(|monitor| monitor.id != *id))
+                .collect::<Vec<_>>();
+            if !stale.is_empty() {
+                client.remove(stale)?;
+            }
+
+            client.notify(wanted.clone())?;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
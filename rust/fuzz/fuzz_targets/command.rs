@@ -0,0 +1,16 @@
+//! Messages as the driver deserializes them from the pipe and device control requests
+
+#![no_main]
+
+use driver_ipc::Command;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(command) = serde_json::from_slice::<Command>(data) else {
+        return;
+    };
+
+    // whatever the driver accepts, it must be able to send back, e.g. in `DriverNotify`
+    let json = serde_json::to_vec(&command).expect("failed to serialize command");
+    serde_json::from_slice::<Command>(&json).expect("failed to deserialize serialized command");
+});
@@ -0,0 +1,17 @@
+//! Sequences of messages, one per line, run through the emulator, which follows the driver's
+//! rules for them
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vdd_emulator::Emulator;
+
+fuzz_target!(|data: &[u8]| {
+    let mut emulator = Emulator::new();
+
+    for message in data.split(|&b| b == b'\n') {
+        if let Some(reply) = emulator.handle_message(message) {
+            serde_json::from_slice::<driver_ipc::Command>(&reply).expect("invalid reply");
+        }
+    }
+});
@@ -0,0 +1,18 @@
+//! Modes as given to the cli
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+#[path = "../../virtual-display-driver-cli/src/mode.rs"]
+#[allow(dead_code)]
+mod mode;
+
+fuzz_target!(|s: &str| {
+    let Ok(mode) = s.parse::<mode::Mode>() else {
+        return;
+    };
+
+    let parsed = mode.to_string().parse::<mode::Mode>();
+    assert_eq!(parsed.ok(), Some(mode), "mode doesn't survive a round trip");
+});
@@ -0,0 +1,17 @@
+use driver_ipc::{Client, Command, Id, SharedFrame};
+use eyre::Context as _;
+
+/// The texture a monitor's frames are shared in, if it has one
+///
+/// Connects only for this request, since the driver pipe only accepts a single client at a time.
+pub fn shared_frame(pipe_name: &str, id: Id) -> eyre::Result<Option<SharedFrame>> {
+    let mut client = Client::connect(pipe_name).context("Failed to connect to the driver")?;
+
+    let Command::ReplySharedFrame(frame) =
+        client.request(&Command::RequestSharedFrame(id.into()))?
+    else {
+        eyre::bail!("received unexpected reply from driver pipe");
+    };
+
+    Ok(frame)
+}
@@ -0,0 +1,57 @@
+//! OBS plugin adding a "Virtual Display" source, which shows the frames a virtual monitor shares
+//! with `--gpu-export` directly, instead of capturing the display
+
+use std::{
+    ffi::{c_char, CString},
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+
+mod driver;
+mod obs;
+mod source;
+
+static MODULE: AtomicPtr<obs::obs_module_t> = AtomicPtr::new(ptr::null_mut());
+
+// what `OBS_DECLARE_MODULE()` would define
+
+#[no_mangle]
+pub extern "C" fn obs_module_set_pointer(module: *mut obs::obs_module_t) {
+    MODULE.store(module, Ordering::Relaxed);
+}
+
+#[no_mangle]
+pub extern "C" fn obs_current_module() -> *mut obs::obs_module_t {
+    MODULE.load(Ordering::Relaxed)
+}
+
+#[no_mangle]
+pub extern "C" fn obs_module_ver() -> u32 {
+    obs::LIBOBS_API_VER
+}
+
+#[no_mangle]
+pub extern "C" fn obs_module_name() -> *const c_char {
+    b"Virtual Display Driver\0".as_ptr().cast()
+}
+
+#[no_mangle]
+pub extern "C" fn obs_module_description() -> *const c_char {
+    b"Shows the shared frames of virtual monitors\0"
+        .as_ptr()
+        .cast()
+}
+
+#[no_mangle]
+pub extern "C" fn obs_module_load() -> bool {
+    source::register();
+    true
+}
+
+/// Write to the obs log
+pub fn log(level: std::ffi::c_int, message: &str) {
+    let message = CString::new(message.replace('\0', "")).unwrap_or_default();
+
+    // the message is passed as an argument, so `%` in it isn't a format
+    unsafe { obs::blog(level, b"[obs-vdd] %s\0".as_ptr().cast(), message.as_ptr()) };
+}
@@ -0,0 +1,102 @@
+//! The parts of the libobs api the plugin uses, see `obs-module.h`, `obs-source.h` and
+//! `graphics/graphics.h` of obs studio
+//!
+//! `obs.dll` is linked with `raw-dylib`, so building doesn't need obs' import library.
+
+#![allow(non_camel_case_types)]
+
+use std::ffi::{c_char, c_int, c_void};
+
+// opaque obs types
+pub enum obs_module_t {}
+pub enum obs_source_t {}
+pub enum obs_data_t {}
+pub enum obs_properties_t {}
+pub enum obs_property_t {}
+pub enum gs_texture_t {}
+pub enum gs_effect_t {}
+
+// MAKE_SEMANTIC_VERSION of the oldest obs with `gs_texture_open_nt_shared`
+pub const LIBOBS_API_VER: u32 = 28 << 24;
+
+pub const OBS_SOURCE_TYPE_INPUT: c_int = 0;
+pub const OBS_SOURCE_VIDEO: u32 = 1 << 0;
+
+pub const GS_BGRA: c_int = 5;
+
+pub const LOG_WARNING: c_int = 200;
+
+/// The start of `struct obs_source_info`, up to `video_render`. obs copies only the size passed
+/// to `obs_register_source_s`, and zeroes the rest
+#[repr(C)]
+pub struct obs_source_info {
+    pub id: *const c_char,
+    pub type_: c_int,
+    pub output_flags: u32,
+    pub get_name: Option<unsafe extern "C" fn(type_data: *mut c_void) -> *const c_char>,
+    pub create: Option<
+        unsafe extern "C" fn(settings: *mut obs_data_t, source: *mut obs_source_t) -> *mut c_void,
+    >,
+    pub destroy: Option<unsafe extern "C" fn(data: *mut c_void)>,
+    pub get_width: Option<unsafe extern "C" fn(data: *mut c_void) -> u32>,
+    pub get_height: Option<unsafe extern "C" fn(data: *mut c_void) -> u32>,
+    pub get_defaults: Option<unsafe extern "C" fn(settings: *mut obs_data_t)>,
+    pub get_properties: Option<unsafe extern "C" fn(data: *mut c_void) -> *mut obs_properties_t>,
+    pub update: Option<unsafe extern "C" fn(data: *mut c_void, settings: *mut obs_data_t)>,
+    pub activate: Option<unsafe extern "C" fn(data: *mut c_void)>,
+    pub deactivate: Option<unsafe extern "C" fn(data: *mut c_void)>,
+    pub show: Option<unsafe extern "C" fn(data: *mut c_void)>,
+    pub hide: Option<unsafe extern "C" fn(data: *mut c_void)>,
+    pub video_tick: Option<unsafe extern "C" fn(data: *mut c_void, seconds: f32)>,
+    pub video_render: Option<unsafe extern "C" fn(data: *mut c_void, effect: *mut gs_effect_t)>,
+}
+
+// SAFETY: only holds pointers to static strings and functions
+unsafe impl Sync for obs_source_info {}
+
+#[link(name = "obs", kind = "raw-dylib")]
+extern "C" {
+    pub fn blog(log_level: c_int, format: *const c_char, ...);
+
+    pub fn obs_register_source_s(info: *const obs_source_info, size: usize);
+
+    pub fn obs_data_get_int(data: *mut obs_data_t, name: *const c_char) -> i64;
+    pub fn obs_data_set_default_int(data: *mut obs_data_t, name: *const c_char, val: i64);
+
+    pub fn obs_properties_create() -> *mut obs_properties_t;
+    pub fn obs_properties_add_int(
+        props: *mut obs_properties_t,
+        name: *const c_char,
+        description: *const c_char,
+        min: c_int,
+        max: c_int,
+        step: c_int,
+    ) -> *mut obs_property_t;
+
+    pub fn obs_source_draw(
+        image: *mut gs_texture_t,
+        x: c_int,
+        y: c_int,
+        cx: u32,
+        cy: u32,
+        flip: bool,
+    );
+
+    pub fn obs_enter_graphics();
+    pub fn obs_leave_graphics();
+
+    pub fn gs_get_device_obj() -> *mut c_void;
+    pub fn gs_texture_create(
+        width: u32,
+        height: u32,
+        color_format: c_int,
+        levels: u32,
+        data: *const *const u8,
+        flags: u32,
+    ) -> *mut gs_texture_t;
+    pub fn gs_texture_open_nt_shared(handle: u32) -> *mut gs_texture_t;
+    pub fn gs_texture_destroy(tex: *mut gs_texture_t);
+    pub fn gs_texture_acquire_sync(tex: *mut gs_texture_t, key: u64, ms: u32) -> c_int;
+    pub fn gs_texture_release_sync(tex: *mut gs_texture_t, key: u64) -> c_int;
+    pub fn gs_copy_texture(dst: *mut gs_texture_t, src: *mut gs_texture_t);
+}
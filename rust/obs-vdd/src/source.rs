@@ -0,0 +1,289 @@
+use std::{
+    ffi::{c_void, CStr},
+    mem, ptr,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use driver_ipc::{Id, SharedFrame};
+use eyre::Context as _;
+use windows::{
+    core::{Interface as _, HSTRING, PCWSTR},
+    Win32::{
+        Foundation::CloseHandle,
+        Graphics::{
+            Direct3D11::{ID3D11Device, ID3D11Device1, ID3D11Texture2D},
+            Dxgi::{
+                Common::DXGI_FORMAT_B8G8R8A8_UNORM, IDXGIResource1, DXGI_SHARED_RESOURCE_READ,
+                DXGI_SHARED_RESOURCE_WRITE,
+            },
+        },
+    },
+};
+
+use crate::{
+    driver, log,
+    obs::{self, gs_texture_t, obs_data_t, obs_properties_t, obs_source_t},
+};
+
+// Keys of the shared texture's keyed mutex, see `SharedFrame`
+const KEY_DRIVER: u64 = 0;
+const KEY_CONSUMER: u64 = 1;
+
+// How often the driver is asked whether the texture was replaced, e.g. after a mode change
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+const MONITOR_SETTING: &CStr = unsafe { CStr::from_bytes_with_nul_unchecked(b"monitor\0") };
+
+static INFO: obs::obs_source_info = obs::obs_source_info {
+    id: b"vdd_monitor_source\0".as_ptr().cast(),
+    type_: obs::OBS_SOURCE_TYPE_INPUT,
+    output_flags: obs::OBS_SOURCE_VIDEO,
+    get_name: Some(get_name),
+    create: Some(create),
+    destroy: Some(destroy),
+    get_width: Some(get_width),
+    get_height: Some(get_height),
+    get_defaults: Some(get_defaults),
+    get_properties: Some(get_properties),
+    update: Some(update),
+    activate: None,
+    deactivate: None,
+    show: None,
+    hide: None,
+    video_tick: None,
+    video_render: Some(video_render),
+};
+
+pub fn register() {
+    unsafe {
+        obs::obs_register_source_s(ptr::addr_of!(INFO), mem::size_of::<obs::obs_source_info>());
+    }
+}
+
+// shared with the thread asking the driver for the texture
+struct State {
+    // monitor to show, from the source's settings
+    id: AtomicU32,
+    // its texture, as last reported by the driver
+    frame: Mutex<Option<SharedFrame>>,
+    stop: AtomicBool,
+}
+
+struct Source {
+    state: Arc<State>,
+    // the frame `texture` was opened for, also when opening it failed, so it isn't retried
+    // every frame
+    opened: Option<SharedFrame>,
+    texture: Option<Texture>,
+}
+
+/// The shared texture opened on obs' device, and a copy of its last frame
+///
+/// Frames are copied, so the driver gets the texture back right away instead of after obs is
+/// done rendering.
+struct Texture {
+    frame: SharedFrame,
+    shared: *mut gs_texture_t,
+    copy: *mut gs_texture_t,
+}
+
+impl Texture {
+    /// Must be called in the graphics context
+    fn open(frame: SharedFrame) -> eyre::Result<Self> {
+        // desktop frames are bgra, anything else (e.g. hdr) would need a conversion pass first
+        if u32::try_from(DXGI_FORMAT_B8G8R8A8_UNORM.0) != Ok(frame.format) {
+            eyre::bail!("unsupported frame format {}", frame.format);
+        }
+
+        let device = unsafe { obs::gs_get_device_obj() };
+        let device = unsafe { ID3D11Device::from_raw_borrowed(&device) }
+            .ok_or_else(|| eyre::eyre!("obs doesn't render with direct3d 11"))?
+            .cast::<ID3D11Device1>()?;
+
+        // obs can only open nt handles, so one is made for the named texture
+        let access = DXGI_SHARED_RESOURCE_READ | DXGI_SHARED_RESOURCE_WRITE;
+        let texture = unsafe {
+            device
+                .OpenSharedResourceByName::<_, ID3D11Texture2D>(&HSTRING::from(&frame.name), access)
+        }
+        .wrap_err("failed to open the shared frame, is obs using the gpu the driver renders on?")?;
+        let handle = unsafe {
+            texture
+                .cast::<IDXGIResource1>()?
+                .CreateSharedHandle(None, access, PCWSTR::null())?
+        };
+
+        // nt handles of shared resources fit in 32 bits
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let shared = unsafe { obs::gs_texture_open_nt_shared(handle.0 as u32) };
+        // obs holds its own reference to the texture
+        unsafe { CloseHandle(handle)? };
+        if shared.is_null() {
+            eyre::bail!("obs failed to open the shared frame");
+        }
+
+        let copy = unsafe {
+            obs::gs_texture_create(frame.width, frame.height, obs::GS_BGRA, 1, ptr::null(), 0)
+        };
+        if copy.is_null() {
+            unsafe { obs::gs_texture_destroy(shared) };
+            eyre::bail!("failed to create a texture");
+        }
+
+        Ok(Self {
+            frame,
+            shared,
+            copy,
+        })
+    }
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        // entering is counted, so this also works while already in the graphics context
+        unsafe { obs::obs_enter_graphics() };
+        unsafe { obs::gs_texture_destroy(self.shared) };
+        unsafe { obs::gs_texture_destroy(self.copy) };
+        unsafe { obs::obs_leave_graphics() };
+    }
+}
+
+fn poll(state: &State) {
+    while !state.stop.load(Ordering::Relaxed) {
+        let id = state.id.load(Ordering::Relaxed);
+
+        // the monitor may not have gpu export enabled yet, or the driver may not be running, both
+        // are checked again later
+        let frame = driver::shared_frame(driver_ipc::DEFAULT_PIPE_NAME, id)
+            .ok()
+            .flatten();
+        if let Ok(mut current) = state.frame.lock() {
+            *current = frame;
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn monitor_id(settings: *mut obs_data_t) -> Id {
+    let id = unsafe { obs::obs_data_get_int(settings, MONITOR_SETTING.as_ptr()) };
+    Id::try_from(id).unwrap_or_default()
+}
+
+/// # Safety
+///
+/// `data` must be a pointer returned by `create`
+unsafe fn source<'a>(data: *mut c_void) -> &'a mut Source {
+    unsafe { &mut *data.cast::<Source>() }
+}
+
+unsafe extern "C" fn get_name(_type_data: *mut c_void) -> *const std::ffi::c_char {
+    b"Virtual Display\0".as_ptr().cast()
+}
+
+unsafe extern "C" fn create(settings: *mut obs_data_t, _source: *mut obs_source_t) -> *mut c_void {
+    let state = Arc::new(State {
+        id: AtomicU32::new(monitor_id(settings)),
+        frame: Mutex::new(None),
+        stop: AtomicBool::new(false),
+    });
+
+    // not joined when the source is destroyed, connecting to the driver can block for a while
+    let poll_state = state.clone();
+    thread::spawn(move || poll(&poll_state));
+
+    Box::into_raw(Box::new(Source {
+        state,
+        opened: None,
+        texture: None,
+    }))
+    .cast()
+}
+
+unsafe extern "C" fn destroy(data: *mut c_void) {
+    let source = unsafe { Box::from_raw(data.cast::<Source>()) };
+    source.state.stop.store(true, Ordering::Relaxed);
+}
+
+unsafe extern "C" fn get_width(data: *mut c_void) -> u32 {
+    let source = unsafe { source(data) };
+    source
+        .texture
+        .as_ref()
+        .map_or(0, |texture| texture.frame.width)
+}
+
+unsafe extern "C" fn get_height(data: *mut c_void) -> u32 {
+    let source = unsafe { source(data) };
+    source
+        .texture
+        .as_ref()
+        .map_or(0, |texture| texture.frame.height)
+}
+
+unsafe extern "C" fn get_defaults(settings: *mut obs_data_t) {
+    unsafe { obs::obs_data_set_default_int(settings, MONITOR_SETTING.as_ptr(), 0) };
+}
+
+unsafe extern "C" fn get_properties(_data: *mut c_void) -> *mut obs_properties_t {
+    let properties = unsafe { obs::obs_properties_create() };
+    unsafe {
+        obs::obs_properties_add_int(
+            properties,
+            MONITOR_SETTING.as_ptr(),
+            b"Virtual monitor ID (needs --gpu-export)\0".as_ptr().cast(),
+            0,
+            i32::MAX,
+            1,
+        )
+    };
+
+    properties
+}
+
+unsafe extern "C" fn update(data: *mut c_void, settings: *mut obs_data_t) {
+    let source = unsafe { source(data) };
+    source
+        .state
+        .id
+        .store(monitor_id(settings), Ordering::Relaxed);
+}
+
+unsafe extern "C" fn video_render(data: *mut c_void, _effect: *mut obs::gs_effect_t) {
+    let source = unsafe { source(data) };
+
+    let frame = source
+        .state
+        .frame
+        .lock()
+        .ok()
+        .and_then(|frame| frame.clone());
+    if frame != source.opened {
+        source.texture = None;
+        source.opened.clone_from(&frame);
+
+        if let Some(frame) = frame {
+            match Texture::open(frame) {
+                Ok(texture) => source.texture = Some(texture),
+                Err(e) => log(obs::LOG_WARNING, &format!("{e:#}")),
+            }
+        }
+    }
+
+    let Some(texture) = &source.texture else {
+        return;
+    };
+
+    // without a new frame, the last one is shown again
+    if unsafe { obs::gs_texture_acquire_sync(texture.shared, KEY_CONSUMER, 0) } == 0 {
+        unsafe { obs::gs_copy_texture(texture.copy, texture.shared) };
+        unsafe { obs::gs_texture_release_sync(texture.shared, KEY_DRIVER) };
+    }
+
+    unsafe { obs::obs_source_draw(texture.copy, 0, 0, 0, 0, false) };
+}
@@ -119,7 +119,7 @@ impl Monitors {
             return Ok(Self);
         }
 
-        let (reader, mut writer) = NamedPipeClientOptions::new("virtualdisplaydriver")
+        let (reader, mut writer) = NamedPipeClientOptions::new(driver_ipc::DEFAULT_PIPE_NAME)
             .wait()
             .access_duplex()
             .mode_message()
@@ -152,7 +152,7 @@ impl Monitors {
             .lock()
             .map_err(|e| eyre!("{e}"))?;
 
-        let removals = queue.drain(..).collect::<Vec<_>>();
+        let removals = queue.drain(..).map(Into::into).collect::<Vec<_>>();
         if !removals.is_empty() {
             let command = Command::DriverRemove(removals);
             let command = serde_json::to_vec(&command).map_err(|e| eyre!("{e}"))?;
@@ -181,7 +181,7 @@ impl Monitors {
             remove_monitor(id)?;
         }
 
-        let command = Command::DriverRemove(list);
+        let command = Command::DriverRemove(list.into_iter().map(Into::into).collect());
         let command = serde_json::to_vec(&command).map_err(|e| eyre!("{e}"))?;
         with_writer(|writer| writer.write_all(&command))??;
 
@@ -298,6 +298,13 @@ impl Monitors {
             return Err(PyKeyError::new_err("enabled"));
         };
 
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        let ephemeral = dict
+            .get_item("ephemeral")?
+            .map(|o| o.extract::<bool>())
+            .transpose()?
+            .unwrap_or_default();
+
         #[allow(clippy::redundant_closure_for_method_calls)]
         let Some(py_modes) = dict
             .get_item("modes")?
@@ -346,10 +353,10 @@ impl Monitors {
         }
 
         let monitor = Monitor {
-            id,
             name,
             enabled,
-            modes,
+            ephemeral,
+            ..Monitor::new(id, modes)
         };
 
         let mut lock = MONITORS.get().unwrap().lock().map_err(|e| eyre!("{e}"))?;
@@ -374,7 +381,13 @@ impl Drop for Monitors {
             return;
         };
 
-        let Ok(data) = serde_json::to_string(&*monitors) else {
+        // ephemeral monitors are intentionally not restored after a reboot
+        let monitors = monitors
+            .iter()
+            .filter(|monitor| !monitor.ephemeral)
+            .collect::<Vec<_>>();
+
+        let Ok(data) = serde_json::to_string(&monitors) else {
             return;
         };
 
@@ -424,6 +437,18 @@ impl MonitorWrapper {
         Ok(())
     }
 
+    #[getter]
+    fn get_ephemeral(&self) -> PyResult<bool> {
+        let ephemeral = with_monitor(self.0, |monitor| monitor.ephemeral)?;
+        Ok(ephemeral)
+    }
+
+    #[setter]
+    fn set_ephemeral(&self, ephemeral: bool) -> PyResult<()> {
+        with_monitor(self.0, |monitor| monitor.ephemeral = ephemeral)?;
+        Ok(())
+    }
+
     #[getter]
     fn get_modes(&self) -> ModeList {
         ModeList(self.0)
@@ -0,0 +1,1420 @@
+//! In-memory stand-in for the driver, speaking the same [`Command`] protocol
+//!
+//! Monitors are only kept in memory, nothing is shown to the os. Adding, updating, plugging and
+//! removing them follows the driver's rules, including the [`MonitorDiff`]s it replies with, so
+//! clients behave the same against both. Frame statistics are synthetic, counted at the preferred
+//! refresh rate while a monitor is plugged in.
+
+mod serve;
+
+#[cfg(windows)]
+pub use serve::spawn_pipe;
+pub use serve::{spawn_agent, spawn_tcp};
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use driver_ipc::{
+    ActiveMode, Backpressure, CallbackStats, Capability, Command, CursorState, Dimen, DriverInfo,
+    Event, EventKind, FrameFormat, FrameSync, Id, LogLevel, LogRecord, Mode, ModeChange, Monitor,
+    MonitorCapabilities, MonitorDiff, MonitorOperation, MonitorRef, Picture, RefreshRate,
+    SharedFrame, SharedRing, Stats, SyncOptions, TestPattern, MODE_CHANGE_INTERVAL_MS,
+    RING_CONSUMERS, RING_SLOTS,
+};
+
+// Maximum amount of log records and events sent in a single reply, like the driver
+const MAX_LOG_RECORDS: usize = 256;
+const MAX_EVENTS: usize = 256;
+
+// Records and events kept for clients, older ones are dropped
+const LOG_CAPACITY: usize = 1024;
+const EVENT_CAPACITY: usize = 1024;
+
+// DXGI_FORMAT_B8G8R8A8_UNORM, the format the driver shares frames in
+const SHARED_FRAME_FORMAT: u32 = 87;
+
+// Reported for every presented frame, real values depend on the gpu
+const SYNTHETIC_LATENCY_US: f64 = 500.0;
+
+// Settings of a monitor that hasn't changed any yet
+const DEFAULT_PICTURE: Picture = Picture {
+    brightness: 100,
+    contrast: 100,
+    gamma: None,
+    lut: None,
+};
+
+// Reported for every monitor, like the driver does on a system with hardware cursor support
+const MONITOR_CAPABILITIES: MonitorCapabilities = MonitorCapabilities {
+    hdr_supported: false,
+    vrr_supported: false,
+    cursor_supported: true,
+    max_resolution: (16384, 16384),
+};
+
+// IddCx functions the driver reports, all of them available here
+const CAPABILITIES: &[(&str, &str)] = &[
+    ("IddCxMonitorUpdateModes", "1.4"),
+    ("IddCxMonitorSetupHardwareCursor", "1.4"),
+    ("IddCxSetRealtimeGPUPriority", "1.4"),
+    ("IddCxMonitorUpdateModes2", "1.10"),
+    ("IddCxMonitorQueryHardwareCursor3", "1.10"),
+    ("IddCxSwapChainReleaseAndAcquireBuffer2", "1.10"),
+    ("IddCxAdapterDisplayConfigUpdate", "1.10"),
+];
+
+#[derive(Debug)]
+struct EmulatedMonitor {
+    monitor: Monitor,
+    // whether the monitor should be visible to the os, see `Monitor::auto_plug`
+    plugged: bool,
+    // modes the monitor was last arrived with
+    arrived_modes: Vec<Mode>,
+    // when the monitor last arrived, `None` while it isn't visible
+    arrived_at: Option<Instant>,
+    // frames presented before the last arrival
+    presented: u64,
+    // swap chains assigned since the monitor was added, names its shared frame
+    swap_chains: u64,
+    pattern: Option<TestPattern>,
+    // requested by the consumer of its shared frame, converted to right away
+    frame_format: FrameFormat,
+    // requested by the consumer of its shared frame, applied right away too
+    frame_sync: SyncOptions,
+    // whether a consumer asked for the frame ring, which nobody reads from here
+    ring: bool,
+    backpressure: Backpressure,
+    // hardware cursor, set by tests like the os would
+    cursor: CursorState,
+    composite_cursor: bool,
+}
+
+impl EmulatedMonitor {
+    fn refresh_rate(&self) -> RefreshRate {
+        flatten(&self.monitor.modes)
+            .next()
+            .map_or(0, |(_, _, rr)| rr)
+    }
+
+    // frames presented since the last arrival
+    fn presented_since_arrival(&self) -> u64 {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        self.arrived_at.map_or(0, |at| {
+            (at.elapsed().as_secs_f64() * f64::from(self.refresh_rate())) as u64
+        })
+    }
+}
+
+/// The emulated driver state, see the [crate docs](crate)
+#[derive(Debug)]
+pub struct Emulator {
+    monitors: Vec<EmulatedMonitor>,
+    log_level: LogLevel,
+    logs: VecDeque<LogRecord>,
+    log_seq: u64,
+    events: VecDeque<Event>,
+    event_seq: u64,
+    // succeeded and failed calls of each IddCx callback the os would have made
+    callbacks: BTreeMap<&'static str, (u64, u64)>,
+    pictures: HashMap<Id, Picture>,
+    // edids of the emulated physical displays, by name
+    displays: HashMap<String, Vec<u8>>,
+    realtime_gpu_priority: bool,
+    suspended: bool,
+    // when each monitor's mode was last changed with `RequestModeChange`
+    mode_changes: HashMap<Id, Instant>,
+}
+
+impl Default for Emulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Emulator {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            monitors: Vec::new(),
+            log_level: LogLevel::Info,
+            logs: VecDeque::new(),
+            log_seq: 0,
+            events: VecDeque::new(),
+            event_seq: 0,
+            callbacks: BTreeMap::new(),
+            pictures: HashMap::new(),
+            displays: HashMap::new(),
+            realtime_gpu_priority: false,
+            suspended: false,
+            mode_changes: HashMap::new(),
+        }
+    }
+
+    /// Handle a json message like the driver's pipe does, returning the serialized reply if
+    /// there is one
+    ///
+    /// Invalid messages are logged and ignored.
+    pub fn handle_message(&mut self, message: &[u8]) -> Option<Vec<u8>> {
+        let command = match serde_json::from_slice::<Command>(message) {
+            Ok(command) => command,
+            Err(e) => {
+                self.log(
+                    LogLevel::Warn,
+                    format!("Failed to deserialize command: {e}"),
+                );
+                return None;
+            }
+        };
+
+        let reply = self.handle(command)?;
+
+        match serde_json::to_vec(&reply) {
+            Ok(reply) => Some(reply),
+            Err(e) => {
+                self.log(LogLevel::Error, format!("Failed to serialize reply: {e}"));
+                None
+            }
+        }
+    }
+
+    /// Run a command, returning the reply if it has one
+    #[allow(clippy::too_many_lines)]
+    pub fn handle(&mut self, command: Command) -> Option<Command> {
+        let reply = match command {
+            Command::DriverNotify(monitors) => {
+                self.notify(monitors);
+                return None;
+            }
+
+            Command::DriverRemove(monitors) => {
+                let ids = monitors
+                    .iter()
+                    .filter_map(|monitor| self.resolve(monitor))
+                    .collect::<Vec<_>>();
+                self.remove(&ids);
+                return None;
+            }
+
+            Command::DriverRemoveAll => {
+                self.remove_all();
+                return None;
+            }
+
+            Command::DriverSetLogLevel(level) => {
+                self.log_level = level;
+                return None;
+            }
+
+            Command::DriverSetTestPattern(monitor, pattern) => {
+                match self.find_mut(&monitor) {
+                    Some(mon) => mon.pattern = pattern,
+                    None => self.log(
+                        LogLevel::Warn,
+                        format!("set_test_pattern(): Monitor {monitor} doesn't exist"),
+                    ),
+                }
+                return None;
+            }
+
+            Command::DriverSetLut(monitor, lut) => {
+                let id = self.find(&monitor).map(|mon| mon.monitor.id);
+                if lut.as_ref().is_some_and(|lut| !lut.is_valid()) {
+                    self.log(
+                        LogLevel::Warn,
+                        format!("set_lut(): Invalid LUT for monitor {monitor}"),
+                    );
+                } else if let Some(id) = id {
+                    self.pictures.entry(id).or_insert(DEFAULT_PICTURE).lut = lut;
+                } else {
+                    self.log(
+                        LogLevel::Warn,
+                        format!("set_lut(): Monitor {monitor} doesn't exist"),
+                    );
+                }
+                return None;
+            }
+
+            Command::DriverSetCursorComposite(monitor, composite) => {
+                match self.find_mut(&monitor) {
+                    Some(mon) => mon.composite_cursor = composite,
+                    None => self.log(
+                        LogLevel::Warn,
+                        format!("set_cursor_composite(): Monitor {monitor} doesn't exist"),
+                    ),
+                }
+                return None;
+            }
+
+            Command::DriverSetBackpressure(monitor, backpressure) => {
+                match self.find_mut(&monitor) {
+                    Some(mon) => mon.backpressure = backpressure,
+                    None => self.log(
+                        LogLevel::Warn,
+                        format!("set_backpressure(): Monitor {monitor} doesn't exist"),
+                    ),
+                }
+                return None;
+            }
+
+            Command::DriverReplug(monitor) => {
+                self.replug(&monitor);
+                return None;
+            }
+
+            Command::DriverPlug(monitor) => {
+                self.plug(&monitor);
+                return None;
+            }
+
+            Command::DriverSetRealtimeGpuPriority(enabled) => {
+                self.realtime_gpu_priority = enabled;
+                return None;
+            }
+
+            Command::RequestState => Command::ReplyState(self.monitors().cloned().collect()),
+
+            Command::RequestMonitor(monitor) => {
+                Command::ReplyMonitor(self.find(&monitor).map(|mon| mon.monitor.clone()))
+            }
+
+            Command::RequestCursor(monitor, shape_id) => Command::ReplyCursor(
+                self.find(&monitor)
+                    .filter(|mon| mon.monitor.cursor.hardware && mon.arrived_at.is_some())
+                    .map(|mon| {
+                        let mut cursor = mon.cursor.clone();
+                        // the client already has this shape
+                        if cursor.shape_id == shape_id {
+                            cursor.shape = None;
+                        }
+                        cursor
+                    }),
+            ),
+
+            Command::RequestEcho(payload) => Command::ReplyEcho(payload),
+
+            Command::RequestDisplayEdid(display) => {
+                let edid = self.displays.get(&display).cloned();
+                if edid.is_none() {
+                    self.log(
+                        LogLevel::Warn,
+                        format!("Failed to read edid of {display}: no such display"),
+                    );
+                }
+
+                Command::ReplyDisplayEdid(edid)
+            }
+
+            Command::RequestLogs(since) => Command::ReplyLogs(
+                self.logs
+                    .iter()
+                    .filter(|record| record.seq > since)
+                    .take(MAX_LOG_RECORDS)
+                    .cloned()
+                    .collect(),
+            ),
+
+            Command::RequestStats(monitor) => Command::ReplyStats(
+                self.monitors
+                    .iter()
+                    .filter(|mon| {
+                        monitor
+                            .as_ref()
+                            .map_or(true, |monitor| monitor.matches(&mon.monitor))
+                    })
+                    .map(stats)
+                    .collect(),
+            ),
+
+            Command::RequestCallbackStats => Command::ReplyCallbackStats(
+                self.callbacks
+                    .iter()
+                    .map(|(&callback, &(succeeded, failed))| CallbackStats {
+                        callback: callback.to_owned(),
+                        succeeded,
+                        failed,
+                    })
+                    .collect(),
+            ),
+
+            Command::RequestEvents(since) => Command::ReplyEvents(
+                self.events
+                    .iter()
+                    .filter(|event| event.seq > since)
+                    .take(MAX_EVENTS)
+                    .cloned()
+                    .collect(),
+            ),
+
+            Command::RequestDriverInfo => Command::ReplyDriverInfo(self.driver_info()),
+
+            Command::RequestNotify(monitors) => Command::ReplyNotify(self.notify(monitors)),
+
+            Command::RequestSharedFrame(monitor) => {
+                Command::ReplySharedFrame(self.find(&monitor).and_then(shared_frame))
+            }
+
+            Command::RequestSharedFrameAs(monitor, format) => {
+                match self.find_mut(&monitor) {
+                    Some(mon) => mon.frame_format = format,
+                    None => self.log(
+                        LogLevel::Warn,
+                        format!("shared_frame_as(): Monitor {monitor} doesn't exist"),
+                    ),
+                }
+                Command::ReplySharedFrame(self.find(&monitor).and_then(shared_frame))
+            }
+
+            Command::RequestSharedFrameSync(monitor, options) => {
+                match self.find_mut(&monitor) {
+                    Some(mon) => mon.frame_sync = options,
+                    None => self.log(
+                        LogLevel::Warn,
+                        format!("shared_frame_sync(): Monitor {monitor} doesn't exist"),
+                    ),
+                }
+
+                Command::ReplySharedFrame(self.find(&monitor).and_then(shared_frame))
+            }
+
+            Command::RequestSharedRing(monitor) => {
+                match self.find_mut(&monitor) {
+                    Some(mon) => mon.ring = true,
+                    None => self.log(
+                        LogLevel::Warn,
+                        format!("shared_ring(): Monitor {monitor} doesn't exist"),
+                    ),
+                }
+
+                Command::ReplySharedRing(self.find(&monitor).and_then(shared_ring))
+            }
+
+            Command::RequestModeChange(monitor, mode) => {
+                Command::ReplyModeChange(self.change_mode(&monitor, mode))
+            }
+
+            // the driver generates the edids of monitors without a custom one, which the
+            // emulator can't
+            Command::RequestEdid(monitor) => Command::ReplyEdid(
+                self.find(&monitor)
+                    .filter(|mon| mon.arrived_at.is_some())
+                    .and_then(|mon| mon.monitor.edid.clone()),
+            ),
+
+            Command::RequestPicture(monitor) => Command::ReplyPicture(
+                self.resolve(&monitor)
+                    .and_then(|id| self.pictures.get(&id).cloned())
+                    .unwrap_or(DEFAULT_PICTURE),
+            ),
+
+            // replies aren't valid commands, the driver ignores them too
+            Command::ReplyState(_)
+            | Command::ReplyEcho(_)
+            | Command::ReplyDisplayEdid(_)
+            | Command::ReplyLogs(_)
+            | Command::ReplyStats(_)
+            | Command::ReplyCallbackStats(_)
+            | Command::ReplyEvents(_)
+            | Command::ReplyDriverInfo(_)
+            | Command::ReplyNotify(_)
+            | Command::ReplySharedFrame(_)
+            | Command::ReplySharedRing(_)
+            | Command::ReplyPicture(_)
+            | Command::ReplyMonitor(_)
+            | Command::ReplyCursor(_)
+            | Command::ReplyModeChange(_)
+            | Command::ReplyEdid(_) => return None,
+        };
+
+        Some(reply)
+    }
+
+    /// The monitors, in the order they were added
+    pub fn monitors(&self) -> impl Iterator<Item = &Monitor> {
+        self.monitors.iter().map(|mon| &mon.monitor)
+    }
+
+    /// Whether the monitor with `id` is currently visible to the (emulated) os
+    #[must_use]
+    pub fn is_arrived(&self, id: Id) -> bool {
+        self.monitor(id).is_some_and(|mon| mon.arrived_at.is_some())
+    }
+
+    /// Move or reshape the hardware cursor of the monitor with `id`, like the os would
+    pub fn set_cursor(&mut self, id: Id, cursor: CursorState) {
+        if let Some(mon) = self.monitor_mut(id) {
+            mon.cursor = cursor;
+        }
+    }
+
+    /// Whether the hardware cursor is drawn into the shared frames of the monitor with `id`
+    #[must_use]
+    pub fn cursor_composited(&self, id: Id) -> bool {
+        self.monitor(id).is_some_and(|mon| mon.composite_cursor)
+    }
+
+    /// The test pattern shared instead of the desktop of the monitor with `id`
+    #[must_use]
+    pub fn test_pattern(&self, id: Id) -> Option<TestPattern> {
+        self.monitor(id).and_then(|mon| mon.pattern)
+    }
+
+    /// Add a physical display `RequestDisplayEdid` can read, e.g. `DISPLAY2`
+    pub fn add_display(&mut self, name: impl Into<String>, edid: Vec<u8>) {
+        self.displays.insert(name.into(), edid);
+    }
+
+    /// Change picture settings of a monitor, like windows or DDC/CI tools would
+    pub fn set_picture(&mut self, id: Id, picture: Picture) {
+        self.pictures.insert(id, picture);
+    }
+
+    /// Report an event to clients, e.g. a stalled swap chain. Monitor changes are reported by
+    /// the emulator itself
+    pub fn push_event(&mut self, kind: EventKind) {
+        self.event_seq += 1;
+
+        if self.events.len() == EVENT_CAPACITY {
+            self.events.pop_front();
+        }
+        self.events.push_back(Event {
+            seq: self.event_seq,
+            timestamp: timestamp(),
+            kind,
+        });
+    }
+
+    /// Switch an arrived monitor to another of its modes, like a user would in display settings.
+    /// Returns whether the os would have accepted the mode
+    pub fn set_active_mode(&mut self, id: Id, mode: ActiveMode) -> bool {
+        let Some(index) = self.position(id) else {
+            return false;
+        };
+
+        let mon = &self.monitors[index];
+        let known = flatten(&mon.arrived_modes).any(|(width, height, refresh_rate)| {
+            (width, height, refresh_rate) == (mode.width, mode.height, mode.refresh_rate)
+        });
+        if mon.arrived_at.is_none() || !known {
+            return false;
+        }
+
+        self.callback("AdapterCommitModes");
+        self.commit_mode(index, Some(mode));
+
+        true
+    }
+
+    /// Emulate the device going to sleep, which departs all monitors
+    pub fn suspend(&mut self) {
+        if self.suspended {
+            return;
+        }
+
+        self.suspended = true;
+        for index in 0..self.monitors.len() {
+            self.depart(index);
+        }
+    }
+
+    /// Emulate the device waking up again, which arrives the monitors that are plugged in
+    pub fn resume(&mut self) {
+        if !self.suspended {
+            return;
+        }
+
+        self.suspended = false;
+        for index in 0..self.monitors.len() {
+            if self.monitors[index].plugged {
+                self.monitors[index].arrived_modes = self.monitors[index].monitor.modes.clone();
+                self.arrive(index);
+            }
+        }
+    }
+
+    fn monitor(&self, id: Id) -> Option<&EmulatedMonitor> {
+        self.monitors.iter().find(|mon| mon.monitor.id == id)
+    }
+
+    fn monitor_mut(&mut self, id: Id) -> Option<&mut EmulatedMonitor> {
+        self.monitors.iter_mut().find(|mon| mon.monitor.id == id)
+    }
+
+    fn position(&self, id: Id) -> Option<usize> {
+        self.monitors.iter().position(|mon| mon.monitor.id == id)
+    }
+
+    fn position_of(&self, monitor: &MonitorRef) -> Option<usize> {
+        self.monitors
+            .iter()
+            .position(|mon| monitor.matches(&mon.monitor))
+    }
+
+    fn find(&self, monitor: &MonitorRef) -> Option<&EmulatedMonitor> {
+        self.monitors
+            .iter()
+            .find(|mon| monitor.matches(&mon.monitor))
+    }
+
+    fn find_mut(&mut self, monitor: &MonitorRef) -> Option<&mut EmulatedMonitor> {
+        self.monitors
+            .iter_mut()
+            .find(|mon| monitor.matches(&mon.monitor))
+    }
+
+    /// The id of the monitor `monitor` refers to, `None` for an alias no monitor has
+    fn resolve(&self, monitor: &MonitorRef) -> Option<Id> {
+        monitor.resolve(self.monitors())
+    }
+
+    fn log(&mut self, level: LogLevel, message: String) {
+        if level > self.log_level {
+            return;
+        }
+
+        self.log_seq += 1;
+
+        if self.logs.len() == LOG_CAPACITY {
+            self.logs.pop_front();
+        }
+        self.logs.push_back(LogRecord {
+            seq: self.log_seq,
+            timestamp: timestamp(),
+            level,
+            target: env!("CARGO_PKG_NAME").replace('-', "_"),
+            message,
+        });
+    }
+
+    fn callback(&mut self, callback: &'static str) {
+        self.callbacks.entry(callback).or_default().0 += 1;
+    }
+
+    // the os committed a mode, or none while the monitor isn't active
+    fn commit_mode(&mut self, index: usize, mode: Option<ActiveMode>) {
+        let mon = &mut self.monitors[index];
+        if mon.monitor.active_mode == mode {
+            return;
+        }
+
+        mon.monitor.active_mode = mode;
+        let id = mon.monitor.id;
+        self.push_event(EventKind::ActiveModeChanged { id, mode });
+    }
+
+    // the os parses the description, picks a mode and assigns a swap chain
+    fn arrive(&mut self, index: usize) {
+        for callback in [
+            "ParseMonitorDescription",
+            "MonitorQueryModes",
+            "AdapterCommitModes",
+            "AssignSwapChain",
+        ] {
+            self.callback(callback);
+        }
+
+        let mon = &mut self.monitors[index];
+        mon.arrived_at = Some(Instant::now());
+        mon.swap_chains += 1;
+
+        let id = mon.monitor.id;
+        // without a previous choice to restore, the os picks the preferred mode
+        let preferred = preferred_mode(&mon.monitor.modes);
+        self.log(LogLevel::Info, format!("Monitor {id} arrived"));
+        self.commit_mode(index, preferred);
+    }
+
+    // returns whether the monitor was arrived
+    fn depart(&mut self, index: usize) -> bool {
+        let mon = &mut self.monitors[index];
+        let presented = mon.presented_since_arrival();
+        if mon.arrived_at.take().is_none() {
+            return false;
+        }
+        mon.presented += presented;
+
+        let id = mon.monitor.id;
+        self.callback("UnassignSwapChain");
+        self.callback("AdapterCommitModes");
+        self.log(LogLevel::Info, format!("Monitor {id} departed"));
+        self.commit_mode(index, None);
+
+        true
+    }
+
+    /// Adds monitors that don't exist, and applies the changes of the others, like the driver
+    fn notify(&mut self, monitors: Vec<Monitor>) -> Vec<MonitorDiff> {
+        if let Some(problem) = duplicate(&monitors) {
+            self.log(LogLevel::Warn, problem);
+            self.log(
+                LogLevel::Warn,
+                "notify(): Duplicate data was detected; nothing was changed; please fix your program"
+                    .to_owned(),
+            );
+            return Vec::new();
+        }
+        if let Some(problem) = driver_ipc::alias_conflict(self.monitors(), &monitors) {
+            self.log(LogLevel::Warn, problem);
+            self.log(
+                LogLevel::Warn,
+                "notify(): Conflicting aliases were detected; nothing was changed".to_owned(),
+            );
+            return Vec::new();
+        }
+
+        monitors
+            .into_iter()
+            .map(|monitor| self.notify_one(monitor))
+            .collect()
+    }
+
+    fn notify_one(&mut self, mut monitor: Monitor) -> MonitorDiff {
+        let id = monitor.id;
+        monitor.capabilities = Some(MONITOR_CAPABILITIES);
+        // there's no os to show the monitor as a display
+        monitor.target = None;
+        let mut diff = MonitorDiff {
+            id,
+            created: false,
+            changed: Vec::new(),
+            operations: Vec::new(),
+        };
+
+        let Some(index) = self.position(id) else {
+            let plugged = monitor.enabled && monitor.auto_plug;
+            diff.created = true;
+
+            self.push_event(EventKind::MonitorAdded {
+                monitor: monitor.clone(),
+            });
+
+            self.monitors.push(EmulatedMonitor {
+                arrived_modes: monitor.modes.clone(),
+                monitor,
+                plugged,
+                arrived_at: None,
+                presented: 0,
+                swap_chains: 0,
+                pattern: None,
+                frame_format: FrameFormat::default(),
+                frame_sync: SyncOptions::default(),
+                ring: false,
+                backpressure: Backpressure::default(),
+                cursor: CursorState::default(),
+                composite_cursor: false,
+            });
+
+            if plugged && !self.suspended {
+                self.arrive(self.monitors.len() - 1);
+                diff.operations.push(MonitorOperation::Arrival);
+            }
+
+            return diff;
+        };
+
+        let mon = &mut self.monitors[index];
+        // only the os changes it
+        monitor.active_mode = mon.monitor.active_mode;
+        diff.changed = mon
+            .monitor
+            .changed_fields(&monitor)
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+
+        let description_changed = mon.monitor.edid != monitor.edid
+            || mon.monitor.edid_name() != monitor.edid_name()
+            || mon.monitor.audio != monitor.audio
+            || mon.monitor.exclusive_capture != monitor.exclusive_capture
+            || mon.monitor.cursor != monitor.cursor
+            || mon.monitor.gpu_export != monitor.gpu_export
+            || mon.monitor.export_size != monitor.export_size;
+
+        let updated_in_place = mon.monitor.modes != monitor.modes
+            && !description_changed
+            && mon.monitor.enabled
+            && monitor.enabled
+            && is_subset(&monitor.modes, &mon.arrived_modes)
+            && mon.arrived_at.is_some();
+
+        let mut display_config_update = false;
+        if updated_in_place {
+            diff.operations.push(MonitorOperation::UpdateModes);
+
+            display_config_update =
+                flatten(&mon.monitor.modes).next() != flatten(&monitor.modes).next();
+            if display_config_update {
+                diff.operations.push(MonitorOperation::DisplayConfigUpdate);
+            }
+        }
+
+        let modes_changed =
+            description_changed || (mon.monitor.modes != monitor.modes && !updated_in_place);
+
+        let plugged = monitor.enabled && (mon.plugged || monitor.auto_plug || !mon.monitor.enabled);
+        let should_arrive = plugged && (!mon.plugged || modes_changed);
+
+        if !diff.changed.is_empty() {
+            self.push_event(EventKind::MonitorChanged {
+                monitor: monitor.clone(),
+                changed: diff.changed.clone(),
+            });
+        }
+
+        let mon = &mut self.monitors[index];
+        let enabled = monitor.enabled;
+        if should_arrive {
+            mon.arrived_modes.clone_from(&monitor.modes);
+        }
+        mon.monitor = monitor;
+        mon.plugged = plugged;
+
+        // the display config update switches the os to the new preferred mode
+        if display_config_update {
+            let preferred = preferred_mode(&self.monitors[index].monitor.modes);
+            self.commit_mode(index, preferred);
+        }
+
+        // should only detach if modes changed, or if it's disabled
+        if (modes_changed || !enabled) && self.depart(index) {
+            diff.operations.push(MonitorOperation::Departure);
+        }
+
+        if should_arrive && !self.suspended {
+            self.arrive(index);
+            diff.operations.push(MonitorOperation::Arrival);
+        }
+
+        diff
+    }
+
+    /// Makes `mode` the preferred one and switches to it, like the driver
+    fn change_mode(&mut self, monitor: &MonitorRef, mode: ActiveMode) -> ModeChange {
+        let Some(index) = self.position_of(monitor) else {
+            self.log(
+                LogLevel::Warn,
+                format!("change_mode(): Monitor {monitor} doesn't exist"),
+            );
+            return ModeChange::Unavailable;
+        };
+
+        let id = self.monitors[index].monitor.id;
+        let mut monitor = self.monitors[index].monitor.clone();
+        if monitor.active_mode.is_none() || !monitor.prefer_mode(mode) {
+            return ModeChange::Unavailable;
+        }
+
+        let interval = Duration::from_millis(MODE_CHANGE_INTERVAL_MS);
+        if let Some(changed) = self.mode_changes.get(&id) {
+            let wait = interval.saturating_sub(changed.elapsed());
+            if !wait.is_zero() {
+                #[allow(clippy::cast_possible_truncation)]
+                return ModeChange::RateLimited {
+                    retry_after_ms: wait.as_millis() as u64,
+                };
+            }
+        }
+        self.mode_changes.insert(id, Instant::now());
+
+        self.push_event(EventKind::ModeChangeRequested { id, mode });
+        let mut diff = self.notify_one(monitor);
+
+        // the preferred mode didn't change, e.g. because another one was picked in display
+        // settings, so the os only switches back when told to
+        let switched = diff.operations.iter().any(|operation| {
+            matches!(
+                operation,
+                MonitorOperation::DisplayConfigUpdate | MonitorOperation::Arrival
+            )
+        });
+        if !switched && self.monitors[index].monitor.active_mode != Some(mode) {
+            diff.operations.push(MonitorOperation::DisplayConfigUpdate);
+            self.commit_mode(index, Some(mode));
+        }
+
+        ModeChange::Requested(diff)
+    }
+
+    fn replug(&mut self, monitor: &MonitorRef) {
+        let Some(index) = self.position_of(monitor) else {
+            self.log(
+                LogLevel::Warn,
+                format!("replug(): Monitor {monitor} doesn't exist"),
+            );
+            return;
+        };
+
+        if !self.monitors[index].plugged {
+            return;
+        }
+
+        self.depart(index);
+        self.monitors[index].arrived_modes = self.monitors[index].monitor.modes.clone();
+
+        if !self.suspended {
+            self.arrive(index);
+        }
+    }
+
+    fn plug(&mut self, monitor: &MonitorRef) {
+        let Some(index) = self.position_of(monitor) else {
+            self.log(
+                LogLevel::Warn,
+                format!("plug(): Monitor {monitor} doesn't exist"),
+            );
+            return;
+        };
+
+        let mon = &mut self.monitors[index];
+        // disabled monitors are plugged in by enabling them
+        if !mon.monitor.enabled || mon.plugged {
+            return;
+        }
+
+        mon.plugged = true;
+        mon.arrived_modes = mon.monitor.modes.clone();
+
+        if !self.suspended {
+            self.arrive(index);
+        }
+    }
+
+    fn remove(&mut self, ids: &[Id]) {
+        for &id in ids {
+            let Some(index) = self.position(id) else {
+                continue;
+            };
+
+            self.depart(index);
+            self.monitors.remove(index);
+            self.pictures.remove(&id);
+            self.push_event(EventKind::MonitorRemoved { id });
+        }
+    }
+
+    fn remove_all(&mut self) {
+        let ids = self
+            .monitors()
+            .map(|monitor| monitor.id)
+            .collect::<Vec<_>>();
+        self.remove(&ids);
+    }
+
+    fn driver_info(&self) -> DriverInfo {
+        DriverInfo {
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            iddcx_version: "1.10".to_owned(),
+            degraded: Vec::new(),
+            realtime_gpu_priority: self.realtime_gpu_priority,
+            capabilities: CAPABILITIES
+                .iter()
+                .map(|&(function, iddcx_version)| Capability {
+                    function: function.to_owned(),
+                    iddcx_version: iddcx_version.to_owned(),
+                    built: true,
+                    available: true,
+                })
+                .collect(),
+        }
+    }
+}
+
+fn stats(mon: &EmulatedMonitor) -> Stats {
+    let frames_presented = mon.presented + mon.presented_since_arrival();
+    let (refresh_rate, latency_us) = if mon.arrived_at.is_some() {
+        (f64::from(mon.refresh_rate()), SYNTHETIC_LATENCY_US)
+    } else {
+        (0.0, 0.0)
+    };
+
+    Stats {
+        id: mon.monitor.id,
+        frames_presented,
+        frames_dropped: 0,
+        avg_latency_us: latency_us,
+        refresh_rate,
+        avg_present_latency_us: latency_us,
+        jitter_us: 0.0,
+        pool_allocations: mon.swap_chains,
+        pool_reuses: 0,
+        shared_frames_dropped: 0,
+        consumers: Vec::new(),
+    }
+}
+
+fn shared_frame(mon: &EmulatedMonitor) -> Option<SharedFrame> {
+    if !mon.monitor.gpu_export || mon.arrived_at.is_none() {
+        return None;
+    }
+
+    let (mut width, mut height) = match mon.monitor.export_size {
+        Some(size) => (size.width, size.height),
+        None => flatten(&mon.monitor.modes)
+            .next()
+            .map(|(width, height, _)| (width, height))?,
+    };
+
+    let format = mon.frame_format.dxgi_format();
+    // the yuv formats need an even size
+    if format.is_some() {
+        width &= !1;
+        height &= !1;
+    }
+
+    let sync = mon.frame_sync.sync;
+    let fence = (sync == FrameSync::Fence).then(|| {
+        format!(
+            "Global\\VirtualDisplayEmulator-Fence-{}-{}",
+            mon.monitor.id, mon.swap_chains
+        )
+    });
+
+    Some(SharedFrame {
+        name: format!(
+            "Global\\VirtualDisplayEmulator-Frame-{}-{}",
+            mon.monitor.id, mon.swap_chains
+        ),
+        width,
+        height,
+        format: format.unwrap_or(SHARED_FRAME_FORMAT),
+        sync,
+        fence,
+        backpressure: mon.backpressure,
+    })
+}
+
+fn shared_ring(mon: &EmulatedMonitor) -> Option<SharedRing> {
+    let frame = shared_frame(mon).filter(|_| mon.ring)?;
+    let name = |kind: &str| {
+        format!(
+            "Global\\VirtualDisplayEmulator-{kind}-{}-{}",
+            mon.monitor.id, mon.swap_chains
+        )
+    };
+
+    Some(SharedRing {
+        header: name("Ring"),
+        textures: (0..RING_SLOTS)
+            .map(|slot| format!("{}-{slot}", name("RingFrame")))
+            .collect(),
+        events: (0..RING_CONSUMERS)
+            .map(|entry| format!("{}-{entry}", name("RingEvent")))
+            .collect(),
+        width: frame.width,
+        height: frame.height,
+        format: frame.format,
+    })
+}
+
+/// Every mode as (width, height, refresh rate), the first one is the preferred one
+fn flatten(modes: &[Mode]) -> impl Iterator<Item = (Dimen, Dimen, RefreshRate)> + '_ {
+    modes.iter().flat_map(|mode| {
+        mode.refresh_rates
+            .iter()
+            .map(|&rr| (mode.width, mode.height, rr))
+    })
+}
+
+/// Whether every mode in `modes` is also in `known`
+fn preferred_mode(modes: &[Mode]) -> Option<ActiveMode> {
+    flatten(modes)
+        .next()
+        .map(|(width, height, refresh_rate)| ActiveMode {
+            width,
+            height,
+            refresh_rate,
+        })
+}
+
+fn is_subset(modes: &[Mode], known: &[Mode]) -> bool {
+    flatten(modes).all(|mode| flatten(known).any(|known| known == mode))
+}
+
+/// What the driver rejects a notification for: duplicate ids, modes or refresh rates
+fn duplicate(monitors: &[Monitor]) -> Option<String> {
+    for (i, monitor) in monitors.iter().enumerate() {
+        if monitors[i + 1..].iter().any(|b| monitor.id == b.id) {
+            return Some(format!("Found duplicate monitor id {}", monitor.id));
+        }
+
+        for (j, mode) in monitor.modes.iter().enumerate() {
+            let duplicate_mode = monitor.modes[j + 1..]
+                .iter()
+                .any(|m| mode.width == m.width && mode.height == m.height);
+            if duplicate_mode {
+                return Some(format!(
+                    "Found duplicate mode {}x{} on monitor {}",
+                    mode.width, mode.height, monitor.id
+                ));
+            }
+
+            for (k, rr) in mode.refresh_rates.iter().enumerate() {
+                if mode.refresh_rates[k + 1..].contains(rr) {
+                    return Some(format!(
+                        "Found duplicate refresh rate {rr} on mode {}x{} for monitor {}",
+                        mode.width, mode.height, monitor.id
+                    ));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_millis() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(id: Id, modes: &[(Dimen, Dimen, &[RefreshRate])]) -> Monitor {
+        let modes = modes
+            .iter()
+            .map(|&(width, height, refresh_rates)| Mode {
+                width,
+                height,
+                refresh_rates: refresh_rates.to_vec(),
+            })
+            .collect();
+
+        Monitor::new(id, modes)
+    }
+
+    fn active(width: Dimen, height: Dimen, refresh_rate: RefreshRate) -> ActiveMode {
+        ActiveMode {
+            width,
+            height,
+            refresh_rate,
+        }
+    }
+
+    fn notify(emulator: &mut Emulator, monitors: Vec<Monitor>) -> Vec<MonitorDiff> {
+        let Some(Command::ReplyNotify(diffs)) = emulator.handle(Command::RequestNotify(monitors))
+        else {
+            panic!("expected a notify reply");
+        };
+
+        diffs
+    }
+
+    #[test]
+    fn add_update_remove() {
+        let mut emulator = Emulator::new();
+
+        let diffs = notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[60, 120])])]);
+        assert!(diffs[0].created);
+        assert_eq!(diffs[0].operations, [MonitorOperation::Arrival]);
+        assert!(emulator.is_arrived(0));
+
+        // known modes are updated in place
+        let diffs = notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[120])])]);
+        assert_eq!(
+            diffs[0].operations,
+            [
+                MonitorOperation::UpdateModes,
+                MonitorOperation::DisplayConfigUpdate
+            ]
+        );
+
+        // new ones need a re-plug
+        let diffs = notify(&mut emulator, vec![monitor(0, &[(2560, 1440, &[60])])]);
+        assert_eq!(
+            diffs[0].operations,
+            [MonitorOperation::Departure, MonitorOperation::Arrival]
+        );
+
+        emulator.handle(Command::DriverRemove(vec![0.into()]));
+        assert_eq!(emulator.monitors().count(), 0);
+    }
+
+    #[test]
+    fn names_are_in_the_edid() {
+        let mut emulator = Emulator::new();
+        let named = |name: &str| Monitor {
+            name: Some(name.to_owned()),
+            ..monitor(0, &[(1920, 1080, &[60])])
+        };
+        notify(&mut emulator, vec![named("desk")]);
+
+        let diffs = notify(&mut emulator, vec![named("couch")]);
+        assert_eq!(diffs[0].changed, ["name"]);
+        assert_eq!(
+            diffs[0].operations,
+            [MonitorOperation::Departure, MonitorOperation::Arrival]
+        );
+
+        // only the first 13 characters are in the edid
+        notify(&mut emulator, vec![named("living room tv")]);
+        let diffs = notify(&mut emulator, vec![named("living room tv 2")]);
+        assert!(diffs[0].operations.is_empty());
+        assert_eq!(
+            emulator.monitors().next().unwrap().edid_name().as_deref(),
+            Some("living room t")
+        );
+
+        // custom edids keep their own name
+        let custom = |name: &str| Monitor {
+            edid: Some(vec![0; 128]),
+            ..named(name)
+        };
+        notify(&mut emulator, vec![custom("desk")]);
+        let diffs = notify(&mut emulator, vec![custom("couch")]);
+        assert!(diffs[0].operations.is_empty());
+    }
+
+    #[test]
+    fn monitor_events() {
+        let mut emulator = Emulator::new();
+
+        notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[60])])]);
+        // unchanged monitors aren't reported
+        notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[60])])]);
+        notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[120])])]);
+        emulator.handle(Command::DriverRemove(vec![0.into()]));
+
+        let Some(Command::ReplyEvents(events)) = emulator.handle(Command::RequestEvents(0)) else {
+            panic!("expected an events reply");
+        };
+        let kinds = events
+            .into_iter()
+            .map(|event| event.kind)
+            .collect::<Vec<_>>();
+        let reported = |monitor, active_mode| Monitor {
+            capabilities: Some(MONITOR_CAPABILITIES),
+            active_mode,
+            ..monitor
+        };
+        assert_eq!(
+            kinds,
+            [
+                EventKind::MonitorAdded {
+                    monitor: reported(monitor(0, &[(1920, 1080, &[60])]), None)
+                },
+                EventKind::ActiveModeChanged {
+                    id: 0,
+                    mode: Some(active(1920, 1080, 60))
+                },
+                EventKind::MonitorChanged {
+                    monitor: reported(
+                        monitor(0, &[(1920, 1080, &[120])]),
+                        Some(active(1920, 1080, 60))
+                    ),
+                    changed: vec!["modes".to_owned()]
+                },
+                // re-plugged for the new mode
+                EventKind::ActiveModeChanged { id: 0, mode: None },
+                EventKind::ActiveModeChanged {
+                    id: 0,
+                    mode: Some(active(1920, 1080, 120))
+                },
+                EventKind::ActiveModeChanged { id: 0, mode: None },
+                EventKind::MonitorRemoved { id: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn active_mode_follows_the_os() {
+        let mut emulator = Emulator::new();
+
+        notify(
+            &mut emulator,
+            vec![monitor(0, &[(1920, 1080, &[60, 120]), (1280, 720, &[60])])],
+        );
+        let active_mode = |emulator: &Emulator| emulator.monitors().next().unwrap().active_mode;
+        assert_eq!(active_mode(&emulator), Some(active(1920, 1080, 60)));
+
+        // modes the monitor doesn't have can't be picked
+        assert!(!emulator.set_active_mode(0, active(2560, 1440, 60)));
+        assert!(emulator.set_active_mode(0, active(1280, 720, 60)));
+        assert_eq!(active_mode(&emulator), Some(active(1280, 720, 60)));
+
+        // clients can't change it, and it isn't reported as a change
+        let diffs = notify(
+            &mut emulator,
+            vec![monitor(0, &[(1920, 1080, &[60, 120]), (1280, 720, &[60])])],
+        );
+        assert!(diffs[0].changed.is_empty());
+        assert_eq!(active_mode(&emulator), Some(active(1280, 720, 60)));
+
+        // a new preferred mode is applied right away
+        notify(
+            &mut emulator,
+            vec![monitor(0, &[(1920, 1080, &[120]), (1280, 720, &[60])])],
+        );
+        assert_eq!(active_mode(&emulator), Some(active(1920, 1080, 120)));
+
+        emulator.handle(Command::DriverNotify(vec![Monitor {
+            enabled: false,
+            ..monitor(0, &[(1920, 1080, &[120])])
+        }]));
+        assert_eq!(active_mode(&emulator), None);
+        assert!(!emulator.set_active_mode(0, active(1920, 1080, 120)));
+    }
+
+    #[test]
+    fn consumers_change_the_mode() {
+        let mut emulator = Emulator::new();
+        notify(
+            &mut emulator,
+            vec![monitor(0, &[(1920, 1080, &[60, 120]), (1280, 720, &[60])])],
+        );
+        let mut change = |mode| emulator.handle(Command::RequestModeChange(0.into(), mode));
+
+        let Some(Command::ReplyModeChange(ModeChange::Requested(diff))) =
+            change(active(1280, 720, 60))
+        else {
+            panic!("expected the mode change to be requested");
+        };
+        assert_eq!(diff.changed, ["modes"]);
+        assert_eq!(
+            diff.operations,
+            [
+                MonitorOperation::UpdateModes,
+                MonitorOperation::DisplayConfigUpdate
+            ]
+        );
+
+        // too soon after the last one
+        assert!(matches!(
+            change(active(1920, 1080, 120)),
+            Some(Command::ReplyModeChange(ModeChange::RateLimited { .. }))
+        ));
+        assert!(matches!(
+            change(active(2560, 1440, 60)),
+            Some(Command::ReplyModeChange(ModeChange::Unavailable))
+        ));
+
+        let monitor = emulator.monitors().next().unwrap();
+        assert_eq!(monitor.active_mode, Some(active(1280, 720, 60)));
+        assert_eq!(
+            monitor.modes[0],
+            Mode {
+                width: 1280,
+                height: 720,
+                refresh_rates: vec![60]
+            }
+        );
+        assert!(emulator.events.iter().any(|event| event.kind
+            == EventKind::ModeChangeRequested {
+                id: 0,
+                mode: active(1280, 720, 60)
+            }));
+    }
+
+    #[test]
+    fn capabilities_are_reported() {
+        let mut emulator = Emulator::new();
+
+        // whatever clients send is replaced
+        let mut mon = monitor(0, &[(1920, 1080, &[60])]);
+        mon.capabilities = Some(MonitorCapabilities {
+            hdr_supported: true,
+            ..MONITOR_CAPABILITIES
+        });
+        let diffs = notify(&mut emulator, vec![mon]);
+        assert!(diffs[0].created);
+
+        let Some(Command::ReplyMonitor(Some(mon))) =
+            emulator.handle(Command::RequestMonitor(0.into()))
+        else {
+            panic!("expected the monitor");
+        };
+        assert_eq!(mon.capabilities, Some(MONITOR_CAPABILITIES));
+
+        // and not reported as a change
+        let diffs = notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[60])])]);
+        assert!(diffs[0].changed.is_empty());
+    }
+
+    #[test]
+    fn duplicates_are_rejected() {
+        let mut emulator = Emulator::new();
+
+        let diffs = notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[60, 60])])]);
+        assert!(diffs.is_empty());
+        assert_eq!(emulator.monitors().count(), 0);
+
+        let Some(Command::ReplyLogs(records)) = emulator.handle(Command::RequestLogs(0)) else {
+            panic!("expected a logs reply");
+        };
+        assert!(records.iter().all(|record| record.level == LogLevel::Warn));
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn aliases() {
+        let mut emulator = Emulator::new();
+        let desk = MonitorRef::Alias("desk".to_owned());
+
+        let mut mon = monitor(3, &[(1920, 1080, &[60])]);
+        mon.alias = Some("desk".to_owned());
+        notify(&mut emulator, vec![mon]);
+
+        let Some(Command::ReplyMonitor(Some(mon))) =
+            emulator.handle(Command::RequestMonitor(desk.clone()))
+        else {
+            panic!("expected a monitor reply");
+        };
+        assert_eq!(mon.id, 3);
+
+        // taken by monitor 3
+        let mut other = monitor(4, &[(1920, 1080, &[60])]);
+        other.alias = Some("desk".to_owned());
+        assert!(notify(&mut emulator, vec![other]).is_empty());
+        assert_eq!(emulator.monitors().count(), 1);
+
+        emulator.handle(Command::DriverRemove(vec![desk.clone()]));
+        assert_eq!(emulator.monitors().count(), 0);
+        assert!(matches!(
+            emulator.handle(Command::RequestMonitor(desk)),
+            Some(Command::ReplyMonitor(None))
+        ));
+    }
+
+    #[test]
+    fn plug_without_auto_plug() {
+        let mut emulator = Emulator::new();
+
+        let mut mon = monitor(0, &[(1920, 1080, &[60])]);
+        mon.auto_plug = false;
+        let diffs = notify(&mut emulator, vec![mon]);
+        assert!(diffs[0].operations.is_empty());
+        assert!(!emulator.is_arrived(0));
+
+        emulator.handle(Command::DriverPlug(0.into()));
+        assert!(emulator.is_arrived(0));
+    }
+
+    #[test]
+    fn suspend_and_resume() {
+        let mut emulator = Emulator::new();
+        notify(&mut emulator, vec![monitor(0, &[(1920, 1080, &[60])])]);
+
+        emulator.suspend();
+        assert!(!emulator.is_arrived(0));
+
+        emulator.resume();
+        assert!(emulator.is_arrived(0));
+    }
+
+    #[test]
+    fn messages_round_trip() {
+        let mut emulator = Emulator::new();
+
+        let reply = emulator
+            .handle_message(br#"{"RequestEcho":[1,2,3]}"#)
+            .expect("echo has a reply");
+        assert_eq!(reply, br#"{"ReplyEcho":[1,2,3]}"#);
+
+        assert!(emulator.handle_message(b"not json").is_none());
+        assert!(emulator.handle_message(br#""DriverRemoveAll""#).is_none());
+    }
+}
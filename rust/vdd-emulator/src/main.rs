@@ -0,0 +1,99 @@
+use std::{
+    fs,
+    net::SocketAddr,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+use clap::Parser;
+use driver_ipc::{Command, Monitor};
+use eyre::Context as _;
+use vdd_emulator::Emulator;
+
+/// Stand-in for the Virtual Display Driver, for developing and testing
+/// clients without installing it.
+#[derive(Debug, Parser)]
+struct Args {
+    /// Pipe to serve, clients connect to it like to the driver's. Defaults to
+    /// the stock driver instance's pipe, so the driver must not be running.
+    #[cfg(windows)]
+    #[clap(long, default_value = driver_ipc::DEFAULT_PIPE_NAME)]
+    pipe: String,
+
+    /// Serve newline delimited json commands over TCP at `<ADDR>`, e.g.
+    /// `127.0.0.1:9102`, for clients made with `driver_ipc::Client::connect_tcp`.
+    /// The only transport on other platforms than Windows.
+    #[clap(long, value_name = "ADDR")]
+    listen: Option<SocketAddr>,
+
+    /// Start with the monitors in this json file, a list like the driver
+    /// restores from the registry.
+    #[clap(long, value_name = "FILE")]
+    monitors: Option<PathBuf>,
+
+    /// Emulate a physical display whose EDID can be requested, e.g.
+    /// `DISPLAY2=edid.bin`. Can be given multiple times.
+    #[clap(long = "display", value_name = "NAME=FILE")]
+    displays: Vec<String>,
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let mut emulator = Emulator::new();
+
+    if let Some(path) = &args.monitors {
+        let data = fs::read(path).wrap_err_with(|| format!("failed to read {}", path.display()))?;
+        let monitors = serde_json::from_slice::<Vec<Monitor>>(&data)
+            .wrap_err_with(|| format!("failed to parse monitors in {}", path.display()))?;
+
+        emulator.handle(Command::DriverNotify(monitors));
+    }
+
+    for display in &args.displays {
+        let (name, path) = display
+            .split_once('=')
+            .ok_or_else(|| eyre::eyre!("invalid display {display}, expected NAME=FILE"))?;
+        let edid = fs::read(path).wrap_err_with(|| format!("failed to read {path}"))?;
+
+        emulator.add_display(name, edid);
+    }
+
+    let emulator = Arc::new(Mutex::new(emulator));
+
+    #[cfg(windows)]
+    {
+        if let Some(addr) = args.listen {
+            let (addr, _) = vdd_emulator::spawn_tcp(addr, emulator.clone())
+                .wrap_err_with(|| format!("failed to listen on {addr}"))?;
+            println!("Emulating driver at tcp://{addr}");
+        }
+
+        let server = vdd_emulator::spawn_pipe(&args.pipe, emulator).wrap_err_with(|| {
+            format!(
+                "failed to create pipe {}, is the driver or another emulator running?",
+                args.pipe
+            )
+        })?;
+        println!("Emulating driver at \\\\.\\pipe\\{}", args.pipe);
+
+        server
+            .join()
+            .map_err(|_| eyre::eyre!("pipe server panicked"))
+    }
+
+    #[cfg(not(windows))]
+    {
+        let addr = args
+            .listen
+            .ok_or_else(|| eyre::eyre!("nothing to serve, pass --listen <ADDR>"))?;
+
+        let (addr, server) = vdd_emulator::spawn_tcp(addr, emulator)
+            .wrap_err_with(|| format!("failed to listen on {addr}"))?;
+        println!("Emulating driver at tcp://{addr}");
+
+        server
+            .join()
+            .map_err(|_| eyre::eyre!("tcp server panicked"))
+    }
+}
@@ -0,0 +1,164 @@
+//! Transports serving an [`Emulator`] to clients
+//!
+//! All of them create their server before returning, so clients can connect as soon as they do.
+
+use std::{
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread::{self, JoinHandle},
+};
+
+use crate::Emulator;
+
+/// Serve the pipe `pipe_name` like the driver does, one client at a time
+#[cfg(windows)]
+pub fn spawn_pipe(pipe_name: &str, emulator: Arc<Mutex<Emulator>>) -> io::Result<JoinHandle<()>> {
+    let server = win_pipes::NamedPipeServerOptions::new(pipe_name)
+        .reject_remote()
+        .read_message()
+        .write_message()
+        .access_duplex()
+        .first_pipe_instance()
+        .max_instances(1)
+        .wait()
+        .create()
+        .map_err(io::Error::other)?;
+
+    Ok(thread::spawn(move || {
+        for client in server.incoming() {
+            let Ok((reader, mut writer)) = client else {
+                continue;
+            };
+
+            for data in reader.iter_read_full() {
+                let reply = emulator.lock().unwrap().handle_message(&data);
+
+                if let Some(reply) = reply {
+                    _ = writer.write_all(&reply);
+                }
+            }
+        }
+    }))
+}
+
+/// Serve json commands over TCP at `addr`, one per line, every client on its own thread
+///
+/// Returns the address it listens on, which has the actual port if `addr` has port 0.
+pub fn spawn_tcp(
+    addr: SocketAddr,
+    emulator: Arc<Mutex<Emulator>>,
+) -> io::Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind(addr)?;
+    let addr = listener.local_addr()?;
+
+    let handle = thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+
+            let emulator = emulator.clone();
+            thread::spawn(move || serve_tcp_client(stream, &emulator));
+        }
+    });
+
+    Ok((addr, handle))
+}
+
+fn serve_tcp_client(stream: TcpStream, emulator: &Mutex<Emulator>) -> io::Result<()> {
+    let mut writer = stream.try_clone()?;
+
+    for line in BufReader::new(stream).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let reply = emulator.lock().unwrap().handle_message(line.as_bytes());
+
+        if let Some(mut reply) = reply {
+            // json never contains raw newlines, so they delimit messages
+            reply.push(b'\n');
+            writer.write_all(&reply)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Serve clients of `virtual-display-driver-cli --host` at `addr` like `vdd-server --agent`,
+/// authenticated with `key`, every client on its own thread
+///
+/// Returns the address it listens on, like [`spawn_tcp`].
+pub fn spawn_agent(
+    addr: SocketAddr,
+    key: Vec<u8>,
+    emulator: Arc<Mutex<Emulator>>,
+) -> io::Result<(SocketAddr, JoinHandle<()>)> {
+    let listener = TcpListener::bind(addr)?;
+    let addr = listener.local_addr()?;
+
+    let handle = thread::spawn(move || {
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+
+            let key = key.clone();
+            let emulator = emulator.clone();
+            thread::spawn(move || serve_agent_client(stream, &key, &emulator));
+        }
+    });
+
+    Ok((addr, handle))
+}
+
+fn serve_agent_client(stream: TcpStream, key: &[u8], emulator: &Mutex<Emulator>) -> io::Result<()> {
+    let (mut reader, mut writer) = driver_ipc::remote::accept(stream, key)?;
+
+    while let Some(message) = reader.read_message()? {
+        let reply = emulator.lock().unwrap().handle_message(&message);
+
+        if let Some(reply) = reply {
+            writer.write_message(&reply)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use driver_ipc::{Client, Mode, Monitor, MonitorControl as _};
+
+    use super::*;
+
+    #[test]
+    fn clients_connect_over_tcp() {
+        let emulator = Arc::new(Mutex::new(Emulator::new()));
+        let (addr, _) = spawn_tcp((Ipv4Addr::LOCALHOST, 0).into(), emulator).unwrap();
+
+        let mut client = Client::connect_tcp(addr).unwrap();
+        let monitor = Monitor::new(
+            0,
+            vec![Mode {
+                width: 1920,
+                height: 1080,
+                refresh_rates: vec![60],
+            }],
+        );
+
+        let diffs = client.notify(vec![monitor.clone()]).unwrap();
+        assert!(diffs[0].created);
+        // replies have the state the emulator added, e.g. the active mode
+        let added = client.get(0).unwrap().expect("monitor was added");
+        assert_eq!(added.modes, monitor.modes);
+
+        // commands without a reply don't leave anything to read for the next request
+        client.remove(vec![0]).unwrap();
+        assert!(client.list().unwrap().is_empty());
+    }
+}
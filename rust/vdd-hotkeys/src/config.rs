@@ -0,0 +1,261 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use driver_ipc::{Id, Monitor};
+use eyre::Context as _;
+use serde::Deserialize;
+
+/// Hotkeys and the profiles they load, read from a YAML (or JSON) file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    // monitor files by name, relative to the config file
+    #[serde(default)]
+    pub profiles: HashMap<String, PathBuf>,
+    pub hotkeys: Vec<Hotkey>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Hotkey {
+    pub keys: Keys,
+    pub action: Action,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Toggle(MonitorRef),
+    Enable(MonitorRef),
+    Disable(MonitorRef),
+    // replace all monitors with the ones in the profile
+    Profile(String),
+    RemoveAll,
+}
+
+/// A monitor by ID, or by name
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum MonitorRef {
+    Id(Id),
+    Name(String),
+}
+
+impl MonitorRef {
+    pub fn matches(&self, monitor: &Monitor) -> bool {
+        match self {
+            Self::Id(id) => monitor.id == *id,
+            Self::Name(name) => monitor.name.as_deref() == Some(name.as_str()),
+        }
+    }
+}
+
+impl fmt::Display for MonitorRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Id(id) => write!(f, "{id}"),
+            Self::Name(name) => write!(f, "{name:?}"),
+        }
+    }
+}
+
+impl Config {
+    pub fn read(path: &Path) -> eyre::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        let mut config: Self = serde_yaml::from_str(&contents)
+            .with_context(|| format!("invalid config file {}", path.display()))?;
+
+        config.validate()?;
+
+        // profiles are read when they're loaded, so they can be changed without a restart
+        let base = path.parent().unwrap_or(Path::new(""));
+        for file in config.profiles.values_mut() {
+            *file = base.join(&*file);
+        }
+
+        Ok(config)
+    }
+
+    fn validate(&self) -> eyre::Result<()> {
+        let mut keys = HashSet::new();
+
+        for hotkey in &self.hotkeys {
+            eyre::ensure!(
+                keys.insert(hotkey.keys),
+                "{} is used for more than one hotkey",
+                hotkey.keys
+            );
+
+            if let Action::Profile(name) = &hotkey.action {
+                eyre::ensure!(
+                    self.profiles.contains_key(name),
+                    "profile `{name}` of {} isn't in `profiles`",
+                    hotkey.keys
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Monitors of a profile, in the format `virtual-display-driver-cli list --json` prints
+    pub fn profile(&self, name: &str) -> eyre::Result<Vec<Monitor>> {
+        let path = &self.profiles[name];
+        let contents =
+            fs::read(path).with_context(|| format!("failed to read profile {}", path.display()))?;
+
+        serde_json::from_slice(&contents)
+            .with_context(|| format!("invalid profile {}", path.display()))
+    }
+}
+
+/// A key combination, e.g. `Ctrl+Alt+F1`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Keys {
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub win: bool,
+    // virtual-key code
+    pub key: u16,
+    name: &'static str,
+}
+
+// keys besides letters, digits and F1-F24, by their virtual-key code
+const NAMED_KEYS: &[(&str, u16)] = &[
+    ("Space", 0x20),
+    ("PageUp", 0x21),
+    ("PageDown", 0x22),
+    ("End", 0x23),
+    ("Home", 0x24),
+    ("Left", 0x25),
+    ("Up", 0x26),
+    ("Right", 0x27),
+    ("Down", 0x28),
+    ("Insert", 0x2D),
+    ("Delete", 0x2E),
+    ("Pause", 0x13),
+    ("ScrollLock", 0x91),
+];
+
+// names of F1-F24, to keep `Keys` copyable
+const FUNCTION_KEYS: [&str; 24] = [
+    "F1", "F2", "F3", "F4", "F5", "F6", "F7", "F8", "F9", "F10", "F11", "F12", "F13", "F14", "F15",
+    "F16", "F17", "F18", "F19", "F20", "F21", "F22", "F23", "F24",
+];
+
+const LETTERS_AND_DIGITS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+fn key(name: &str) -> Option<(&'static str, u16)> {
+    let upper = name.to_ascii_uppercase();
+
+    if upper.len() == 1 {
+        let index = LETTERS_AND_DIGITS.find(&upper)?;
+        let name = &LETTERS_AND_DIGITS[index..=index];
+        // virtual-key codes of letters and digits are their ascii codes
+        return Some((name, u16::from(name.as_bytes()[0])));
+    }
+
+    if let Some(index) = FUNCTION_KEYS.iter().position(|key| *key == upper) {
+        // VK_F1 is 0x70, the others follow
+        #[allow(clippy::cast_possible_truncation)]
+        return Some((FUNCTION_KEYS[index], 0x70 + index as u16));
+    }
+
+    NAMED_KEYS
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+impl FromStr for Keys {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('+').map(str::trim).collect::<Vec<_>>();
+        let Some(last) = parts.pop() else {
+            return Err("expected keys like `Ctrl+Alt+F1`".to_owned());
+        };
+
+        let (name, key) = key(last).ok_or_else(|| format!("unknown key `{last}` in `{s}`"))?;
+        let mut keys = Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            win: false,
+            key,
+            name,
+        };
+
+        for modifier in parts {
+            let flag = match modifier.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => &mut keys.ctrl,
+                "alt" => &mut keys.alt,
+                "shift" => &mut keys.shift,
+                "win" | "super" => &mut keys.win,
+                _ => return Err(format!("unknown modifier `{modifier}` in `{s}`")),
+            };
+            *flag = true;
+        }
+
+        // windows reserves most combinations without modifiers for typing
+        if !(keys.ctrl || keys.alt || keys.win) {
+            return Err(format!("`{s}` needs Ctrl, Alt or Win"));
+        }
+
+        Ok(keys)
+    }
+}
+
+impl<'de> Deserialize<'de> for Keys {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl fmt::Display for Keys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (set, modifier) in [
+            (self.ctrl, "Ctrl+"),
+            (self.alt, "Alt+"),
+            (self.shift, "Shift+"),
+            (self.win, "Win+"),
+        ] {
+            if set {
+                f.write_str(modifier)?;
+            }
+        }
+
+        f.write_str(self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_keys() {
+        let keys = "ctrl+Alt+f1".parse::<Keys>().unwrap();
+        assert!(keys.ctrl && keys.alt && !keys.shift && !keys.win);
+        assert_eq!(keys.key, 0x70);
+        assert_eq!(keys.to_string(), "Ctrl+Alt+F1");
+
+        assert_eq!("Win+Shift+d".parse::<Keys>().unwrap().key, u16::from(b'D'));
+        assert_eq!("Ctrl+ PageUp".parse::<Keys>().unwrap().key, 0x21);
+    }
+
+    #[test]
+    fn rejects_invalid_keys() {
+        for keys in ["F1", "Shift+A", "Ctrl+F25", "Hyper+A", "Ctrl+", ""] {
+            assert!(keys.parse::<Keys>().is_err(), "{keys}");
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use driver_ipc::Client;
+use eyre::Context as _;
+
+/// Open a short-lived connection to the driver
+///
+/// The driver pipe only accepts a single client at a time, so a connection is only opened when
+/// a hotkey is pressed, which would lock out the cli and other clients otherwise.
+pub fn connect(pipe_name: &str) -> eyre::Result<Client> {
+    Client::connect(pipe_name).context("Failed to connect to the driver")
+}
@@ -0,0 +1,51 @@
+use eyre::Context as _;
+use windows::Win32::{
+    Foundation::HWND,
+    UI::{
+        Input::KeyboardAndMouse::{
+            RegisterHotKey, MOD_ALT, MOD_CONTROL, MOD_NOREPEAT, MOD_SHIFT, MOD_WIN,
+        },
+        WindowsAndMessaging::{GetMessageW, MSG, WM_HOTKEY},
+    },
+};
+
+use crate::config::Keys;
+
+/// Register `hotkeys` for this thread, and call `pressed` with the index of every one that's
+/// pressed. Only returns on errors
+pub fn listen(hotkeys: &[Keys], mut pressed: impl FnMut(usize)) -> eyre::Result<()> {
+    for (index, keys) in hotkeys.iter().enumerate() {
+        let mut modifiers = MOD_NOREPEAT;
+        for (set, modifier) in [
+            (keys.ctrl, MOD_CONTROL),
+            (keys.alt, MOD_ALT),
+            (keys.shift, MOD_SHIFT),
+            (keys.win, MOD_WIN),
+        ] {
+            if set {
+                modifiers |= modifier;
+            }
+        }
+
+        let id = i32::try_from(index).expect("too many hotkeys");
+        unsafe { RegisterHotKey(HWND(0), id, modifiers, u32::from(keys.key)) }.wrap_err_with(
+            || format!("failed to register {keys}, is it used by another program?"),
+        )?;
+    }
+
+    // hotkeys without a window are posted to the thread's message queue
+    let mut message = MSG::default();
+    loop {
+        let result = unsafe { GetMessageW(&mut message, HWND(0), 0, 0) };
+        if result.0 == -1 {
+            return Err(windows::core::Error::from_win32()).wrap_err("failed to get messages");
+        }
+        if result.0 == 0 {
+            eyre::bail!("message loop quit");
+        }
+
+        if message.message == WM_HOTKEY {
+            pressed(message.wParam.0);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::path::PathBuf;
+
+use clap::Parser;
+use driver_ipc::{Command, MonitorControl as _};
+
+use crate::config::{Action, Config};
+
+mod config;
+mod driver;
+mod hotkeys;
+
+/// Runs monitor actions on global hotkeys, e.g. toggling a monitor with
+/// `Ctrl+Alt+F1`.
+///
+/// Hotkeys are read from a YAML config file, see the readme for its format.
+#[derive(Debug, Parser)]
+struct Args {
+    /// Config file with the hotkeys.
+    config: PathBuf,
+
+    /// Driver instance to talk to, by pipe name. Defaults to the stock
+    /// driver instance.
+    #[clap(long, default_value = driver_ipc::DEFAULT_PIPE_NAME)]
+    instance: String,
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+    let config = Config::read(&args.config)?;
+
+    let keys = config
+        .hotkeys
+        .iter()
+        .map(|hotkey| hotkey.keys)
+        .collect::<Vec<_>>();
+
+    hotkeys::listen(&keys, |index| {
+        let hotkey = &config.hotkeys[index];
+
+        // a failed action shouldn't stop the other hotkeys, e.g. when the driver isn't running
+        if let Err(e) = run(&config, &args.instance, &hotkey.action) {
+            eprintln!("{}: {e:?}", hotkey.keys);
+        }
+    })
+}
+
+fn run(config: &Config, pipe_name: &str, action: &Action) -> eyre::Result<()> {
+    let mut connection = driver::connect(pipe_name)?;
+
+    match action {
+        Action::Toggle(monitor) | Action::Enable(monitor) | Action::Disable(monitor) => {
+            let mut monitors = connection.list()?;
+            let Some(found) = monitors.iter_mut().find(|m| monitor.matches(m)) else {
+                eyre::bail!("no monitor {monitor}");
+            };
+
+            found.enabled = match action {
+                Action::Enable(_) => true,
+                Action::Disable(_) => false,
+                _ => !found.enabled,
+            };
+            println!(
+                "{} monitor {monitor}",
+                if found.enabled { "enabled" } else { "disabled" }
+            );
+
+            let found = found.clone();
+            connection.send(&Command::DriverNotify(vec![found]))?;
+        }
+
+        Action::Profile(name) => {
+            let profile = config.profile(name)?;
+            let removed = connection
+                .list()?
+                .iter()
+                .map(|monitor| monitor.id)
+                .filter(|id| profile.iter().all(|monitor| monitor.id != *id))
+                .map(Into::into)
+                .collect::<Vec<_>>();
+
+            if !removed.is_empty() {
+                connection.send(&Command::DriverRemove(removed))?;
+            }
+            connection.send(&Command::DriverNotify(profile))?;
+
+            println!("loaded profile `{name}`");
+        }
+
+        Action::RemoveAll => {
+            connection.send(&Command::DriverRemoveAll)?;
+            println!("removed all monitors");
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+use std::{
+    fs,
+    net::{SocketAddr, TcpListener, TcpStream},
+    path::Path,
+    thread,
+};
+
+use driver_ipc::{remote, Command};
+use eyre::Context as _;
+
+use crate::driver;
+
+/// Proxy driver commands from authenticated remote clients until listening fails
+///
+/// Each client gets its own driver connection while it's connected. The driver pipe takes one
+/// client at a time, so other clients, local ones included, wait until it disconnects.
+pub fn serve(addr: SocketAddr, key_file: &Path, pipe_name: &str) -> eyre::Result<()> {
+    let key = read_key(key_file)?;
+
+    let listener = TcpListener::bind(addr).wrap_err("failed to bind agent address")?;
+    println!("Serving remote clients at {addr}");
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+
+        let key = key.clone();
+        let pipe_name = pipe_name.to_owned();
+        thread::spawn(move || {
+            let peer = stream
+                .peer_addr()
+                .map_or_else(|_| "unknown".to_owned(), |addr| addr.to_string());
+
+            if let Err(e) = proxy(stream, &key, &pipe_name) {
+                eprintln!("Remote client {peer} failed: {e:?}");
+            }
+        });
+    }
+
+    Ok(())
+}
+
+// the key file is text, so it can end with a line break
+fn read_key(key_file: &Path) -> eyre::Result<Vec<u8>> {
+    let key = fs::read_to_string(key_file)
+        .wrap_err_with(|| format!("failed to read key file {}", key_file.display()))?;
+
+    Ok(key.trim().as_bytes().to_vec())
+}
+
+fn proxy(stream: TcpStream, key: &[u8], pipe_name: &str) -> eyre::Result<()> {
+    let (mut reader, mut writer) =
+        remote::accept(stream, key).wrap_err("failed to authenticate")?;
+    let mut connection = driver::connect(pipe_name)?;
+
+    while let Some(message) = reader.read_message()? {
+        let command =
+            serde_json::from_slice::<Command>(&message).wrap_err("invalid command from client")?;
+        if command.is_reply() {
+            eyre::bail!("client sent a reply");
+        }
+
+        if command.is_request() {
+            let reply = connection.request(&command)?;
+            writer.write_message(&serde_json::to_vec(&reply)?)?;
+        } else {
+            connection.send(&command)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::{mem::size_of, ptr};
+
+use driver_ipc::DisplayTarget;
+use windows::Win32::{
+    Devices::Display::{
+        DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes, QueryDisplayConfig,
+        DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_MODE_INFO,
+        DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_SOURCE_DEVICE_NAME, QDC_ONLY_ACTIVE_PATHS,
+    },
+    Foundation::{ERROR_INSUFFICIENT_BUFFER, WIN32_ERROR},
+};
+
+/// GDI name of the display windows shows a target on, such as `\\.\DISPLAY3`, or `None` if the
+/// target isn't active
+pub fn gdi_name(target: DisplayTarget) -> eyre::Result<Option<String>> {
+    let Some(path) = active_paths()?.into_iter().find(|path| {
+        let adapter = path.targetInfo.adapterId;
+        let adapter = (i64::from(adapter.HighPart) << 32) | i64::from(adapter.LowPart);
+        adapter == target.adapter && path.targetInfo.id == target.id
+    }) else {
+        return Ok(None);
+    };
+
+    let mut source = DISPLAYCONFIG_SOURCE_DEVICE_NAME::default();
+    source.header.r#type = DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME;
+    #[allow(clippy::cast_possible_truncation)]
+    let size = size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>() as u32;
+    source.header.size = size;
+    source.header.adapterId = path.sourceInfo.adapterId;
+    source.header.id = path.sourceInfo.id;
+
+    // the header is the first field, windows writes the rest of the struct through it
+    let header = ptr::addr_of_mut!(source).cast();
+    check(unsafe { DisplayConfigGetDeviceInfo(header) })?;
+
+    let name = &source.viewGdiDeviceName;
+    let len = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+    Ok(Some(String::from_utf16_lossy(&name[..len])))
+}
+
+fn active_paths() -> eyre::Result<Vec<DISPLAYCONFIG_PATH_INFO>> {
+    // displays can change between getting the sizes and querying them
+    loop {
+        let (mut path_count, mut mode_count) = (0, 0);
+        unsafe {
+            GetDisplayConfigBufferSizes(QDC_ONLY_ACTIVE_PATHS, &mut path_count, &mut mode_count)
+        }
+        .ok()?;
+
+        let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+        let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+        let status = unsafe {
+            QueryDisplayConfig(
+                QDC_ONLY_ACTIVE_PATHS,
+                &mut path_count,
+                paths.as_mut_ptr(),
+                &mut mode_count,
+                modes.as_mut_ptr(),
+                None,
+            )
+        };
+        if status == ERROR_INSUFFICIENT_BUFFER {
+            continue;
+        }
+        status.ok()?;
+
+        paths.truncate(path_count as usize);
+        return Ok(paths);
+    }
+}
+
+// the display config functions return win32 error codes
+fn check(status: i32) -> eyre::Result<()> {
+    #[allow(clippy::cast_sign_loss)]
+    WIN32_ERROR(status as u32).ok()?;
+
+    Ok(())
+}
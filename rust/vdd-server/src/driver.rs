@@ -0,0 +1,77 @@
+use std::{fs, path::Path};
+
+use driver_ipc::{Client, Command, Id, Monitor, MonitorControl as _, Stats};
+use eyre::Context as _;
+
+/// Snapshot of the driver state
+pub struct Snapshot {
+    pub monitors: Vec<Monitor>,
+    pub stats: Vec<Stats>,
+}
+
+/// Open a short-lived connection to the driver
+///
+/// The driver pipe only accepts a single client at a time, so connections aren't held open
+/// between uses, which would lock out the cli and other clients.
+pub fn connect(pipe_name: &str) -> eyre::Result<Client> {
+    Client::connect(pipe_name).context("Failed to connect to the driver")
+}
+
+/// Connect to the driver, fetch its state, and disconnect again
+pub fn snapshot(pipe_name: &str) -> eyre::Result<Snapshot> {
+    let mut connection = connect(pipe_name)?;
+
+    let monitors = connection.list()?;
+
+    let Command::ReplyStats(stats) = connection.request(&Command::RequestStats(None))? else {
+        eyre::bail!("received unexpected reply from driver pipe");
+    };
+
+    Ok(Snapshot { monitors, stats })
+}
+
+/// Find a monitor by ID, or by name if no monitor has that ID
+pub fn find<'a>(monitors: &'a [Monitor], query: &str) -> Option<&'a Monitor> {
+    let by_id = query
+        .parse::<Id>()
+        .ok()
+        .and_then(|id| monitors.iter().find(|monitor| monitor.id == id));
+
+    by_id.or_else(|| {
+        monitors
+            .iter()
+            .find(|monitor| monitor.name.as_deref() == Some(query))
+    })
+}
+
+/// Add or update the monitors of a profile, a json file as printed by
+/// `virtual-display-driver-cli list --json`, and remove all others if `replace` is set. Returns
+/// the IDs of the profile's monitors
+pub fn load_profile(pipe_name: &str, profile: &Path, replace: bool) -> eyre::Result<Vec<Id>> {
+    let contents = fs::read(profile).wrap_err("failed to read profile")?;
+    let monitors = serde_json::from_slice::<Vec<Monitor>>(&contents).wrap_err("invalid profile")?;
+    let ids = monitors
+        .iter()
+        .map(|monitor| monitor.id)
+        .collect::<Vec<_>>();
+
+    let mut connection = connect(pipe_name)?;
+
+    if replace {
+        let removed = connection
+            .list()?
+            .into_iter()
+            .map(|monitor| monitor.id)
+            .filter(|id| !ids.contains(id))
+            .map(Into::into)
+            .collect::<Vec<_>>();
+
+        if !removed.is_empty() {
+            connection.send(&Command::DriverRemove(removed))?;
+        }
+    }
+
+    connection.send(&Command::DriverNotify(monitors))?;
+
+    Ok(ids)
+}
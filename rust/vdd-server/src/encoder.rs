@@ -0,0 +1,235 @@
+use std::{
+    collections::HashMap,
+    io::Write as _,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{Command, EncodeSettings, EncoderCommand, Encoding, Id, SharedFrame};
+use eyre::Context as _;
+use windows::Win32::{
+    Media::MediaFoundation::{MFShutdown, MFStartup, MFSTARTUP_FULL, MF_VERSION},
+    System::Com::{CoInitializeEx, CoUninitialize, COINIT_MULTITHREADED},
+};
+
+use crate::{driver, media_foundation::Encoder};
+
+// How long to wait for a frame before checking whether to stop. Monitors only produce frames
+// when something on them changes
+const FRAME_TIMEOUT: Duration = Duration::from_millis(100);
+
+// How long a monitor has to be idle before checking whether its shared texture was re-created
+// under a new name
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A monitor being encoded on its own thread
+struct Session {
+    settings: EncodeSettings,
+    state: Arc<SessionState>,
+    thread: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct SessionState {
+    stop: AtomicBool,
+    frames: AtomicU64,
+    error: Mutex<Option<String>>,
+}
+
+impl Session {
+    fn stop(self) -> eyre::Result<()> {
+        self.state.stop.store(true, Ordering::Relaxed);
+        self.thread
+            .join()
+            .map_err(|_| eyre::eyre!("encoder thread panicked"))
+    }
+
+    fn status(&self, id: Id) -> Encoding {
+        Encoding {
+            id,
+            settings: self.settings.clone(),
+            frames: self.state.frames.load(Ordering::Relaxed),
+            error: self.state.error.lock().ok().and_then(|e| e.clone()),
+        }
+    }
+}
+
+/// Serve the encoder pipe until it fails
+///
+/// Encoders keep running between connections, and are only stopped on request.
+pub fn serve(pipe_name: &str) -> eyre::Result<()> {
+    unsafe { MFStartup(MF_VERSION, MFSTARTUP_FULL).wrap_err("failed to start media foundation")? };
+
+    let server = win_pipes::NamedPipeServerOptions::new(driver_ipc::ENCODER_PIPE_NAME)
+        .reject_remote()
+        .read_message()
+        .write_message()
+        .access_duplex()
+        .first_pipe_instance()
+        .max_instances(1)
+        .wait()
+        .create()
+        .wrap_err("failed to create the encoder pipe")?;
+
+    println!(
+        "Serving encoder at \\\\.\\pipe\\{}",
+        driver_ipc::ENCODER_PIPE_NAME
+    );
+
+    let mut sessions = HashMap::<Id, Session>::new();
+
+    for client in server.incoming() {
+        let Ok((reader, mut writer)) = client else {
+            continue;
+        };
+
+        for data in reader.iter_read_full() {
+            let Ok(command) = serde_json::from_slice::<EncoderCommand>(&data) else {
+                _ = server.disconnect();
+                continue;
+            };
+
+            let reply = handle(command, &mut sessions, pipe_name)
+                .unwrap_or_else(|e| EncoderCommand::ReplyError(format!("{e:#}")));
+
+            let message = serde_json::to_vec(&reply)?;
+            _ = writer.write_all(&message);
+        }
+    }
+
+    for (_, session) in sessions.drain() {
+        _ = session.stop();
+    }
+    _ = unsafe { MFShutdown() };
+
+    Ok(())
+}
+
+fn handle(
+    command: EncoderCommand,
+    sessions: &mut HashMap<Id, Session>,
+    pipe_name: &str,
+) -> eyre::Result<EncoderCommand> {
+    let reply = match command {
+        EncoderCommand::RequestStart(id, settings) => {
+            if let Some(session) = sessions.remove(&id) {
+                session.stop()?;
+            }
+
+            let frame = shared_frame(pipe_name, id)?.ok_or_else(|| {
+                eyre::eyre!("monitor {id} has no shared frame, add it with `--gpu-export`")
+            })?;
+
+            sessions.insert(id, start(id, frame, settings, pipe_name));
+            EncoderCommand::ReplyOk
+        }
+
+        EncoderCommand::RequestStop(id) => {
+            let session = sessions
+                .remove(&id)
+                .ok_or_else(|| eyre::eyre!("monitor {id} isn't encoded"))?;
+            session.stop()?;
+            EncoderCommand::ReplyOk
+        }
+
+        EncoderCommand::RequestStatus => {
+            let mut status = sessions
+                .iter()
+                .map(|(&id, session)| session.status(id))
+                .collect::<Vec<_>>();
+            status.sort_by_key(|encoding| encoding.id);
+            EncoderCommand::ReplyStatus(status)
+        }
+
+        EncoderCommand::ReplyOk
+        | EncoderCommand::ReplyError(_)
+        | EncoderCommand::ReplyStatus(_) => {
+            eyre::bail!("unexpected command");
+        }
+    };
+
+    Ok(reply)
+}
+
+fn shared_frame(pipe_name: &str, id: Id) -> eyre::Result<Option<SharedFrame>> {
+    let mut connection = driver::connect(pipe_name)?;
+
+    let Command::ReplySharedFrame(frame) =
+        connection.request(&Command::RequestSharedFrame(id.into()))?
+    else {
+        eyre::bail!("received unexpected reply from driver pipe");
+    };
+
+    Ok(frame)
+}
+
+fn start(id: Id, frame: SharedFrame, settings: EncodeSettings, pipe_name: &str) -> Session {
+    let state = Arc::new(SessionState::default());
+
+    let thread = thread::spawn({
+        let state = state.clone();
+        let settings = settings.clone();
+        let pipe_name = pipe_name.to_owned();
+
+        move || {
+            // media foundation hands work to its own threads, which needs com
+            _ = unsafe { CoInitializeEx(None, COINIT_MULTITHREADED) };
+
+            if let Err(e) = encode(id, frame, &settings, &pipe_name, &state) {
+                eprintln!("Encoding monitor {id} failed: {e:?}");
+                if let Ok(mut error) = state.error.lock() {
+                    *error = Some(format!("{e:#}"));
+                }
+            }
+
+            unsafe { CoUninitialize() };
+        }
+    });
+
+    Session {
+        settings,
+        state,
+        thread,
+    }
+}
+
+fn encode(
+    id: Id,
+    mut frame: SharedFrame,
+    settings: &EncodeSettings,
+    pipe_name: &str,
+    state: &SessionState,
+) -> eyre::Result<()> {
+    let mut encoder = Encoder::new(&frame, settings)?;
+    let mut idle_since = Instant::now();
+
+    while !state.stop.load(Ordering::Relaxed) {
+        if encoder.encode_next(FRAME_TIMEOUT)? {
+            state.frames.fetch_add(1, Ordering::Relaxed);
+            idle_since = Instant::now();
+            continue;
+        }
+
+        // idle monitors are normal, so the driver is only asked now and then whether the texture
+        // was replaced, the pipe only takes one client at a time
+        if idle_since.elapsed() < IDLE_CHECK_INTERVAL {
+            continue;
+        }
+        idle_since = Instant::now();
+
+        // the swap chain may be gone for now, e.g. while the monitor is off, and the driver may be
+        // busy with another client, both are checked again later
+        if let Ok(Some(current)) = shared_frame(pipe_name, id) {
+            if current != frame {
+                encoder.reopen(&current)?;
+                frame = current;
+            }
+        }
+    }
+
+    encoder.finish()
+}
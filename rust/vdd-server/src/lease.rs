@@ -0,0 +1,250 @@
+use std::{
+    io::Write as _,
+    thread,
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{
+    ActiveMode, Command, Id, Lease, LeaseCommand, Mode, Monitor, MonitorControl as _,
+};
+use eyre::Context as _;
+
+use crate::{display, driver};
+
+// How long windows gets to show a monitor once it's plugged in
+const DISPLAY_TIMEOUT: Duration = Duration::from_secs(10);
+const DISPLAY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A lease held by the connected client, and what releasing it undoes
+struct Held {
+    lease: Lease,
+    mode: ActiveMode,
+    teardown: Teardown,
+}
+
+#[derive(Clone, Copy)]
+enum Teardown {
+    // the monitor was added for the lease
+    Remove,
+    // the monitor was enabled for the lease
+    Disable,
+    // the monitor already was in the mode
+    Keep,
+}
+
+/// Serve the lease pipe until it fails
+///
+/// Leases belong to the connection, and are released when the client disconnects.
+pub fn serve(pipe_name: &str) -> eyre::Result<()> {
+    let server = win_pipes::NamedPipeServerOptions::new(driver_ipc::LEASE_PIPE_NAME)
+        .reject_remote()
+        .read_message()
+        .write_message()
+        .access_duplex()
+        .first_pipe_instance()
+        .max_instances(1)
+        .wait()
+        .create()
+        .wrap_err("failed to create the lease pipe")?;
+
+    println!(
+        "Serving leases at \\\\.\\pipe\\{}",
+        driver_ipc::LEASE_PIPE_NAME
+    );
+
+    for client in server.incoming() {
+        let Ok((reader, mut writer)) = client else {
+            continue;
+        };
+
+        let mut leases = Vec::<Held>::new();
+
+        for data in reader.iter_read_full() {
+            let Ok(command) = serde_json::from_slice::<LeaseCommand>(&data) else {
+                _ = server.disconnect();
+                continue;
+            };
+
+            let reply = handle(&command, &mut leases, pipe_name)
+                .unwrap_or_else(|e| LeaseCommand::ReplyError(format!("{e:#}")));
+
+            let message = serde_json::to_vec(&reply)?;
+            _ = writer.write_all(&message);
+        }
+
+        // the session ended with the connection
+        for held in leases {
+            let id = held.lease.id;
+            if let Err(e) = release(id, held.teardown, pipe_name) {
+                eprintln!("Failed to release the lease of monitor {id}: {e:?}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle(
+    command: &LeaseCommand,
+    leases: &mut Vec<Held>,
+    pipe_name: &str,
+) -> eyre::Result<LeaseCommand> {
+    let reply = match *command {
+        LeaseCommand::RequestLease(mode) => {
+            // leasing a mode twice gets the same monitor, released once
+            if let Some(held) = leases.iter().find(|held| held.mode == mode) {
+                return Ok(LeaseCommand::ReplyLease(held.lease.clone()));
+            }
+
+            let held = lease(mode, pipe_name)?;
+            let lease = held.lease.clone();
+            leases.push(held);
+            LeaseCommand::ReplyLease(lease)
+        }
+
+        LeaseCommand::RequestRelease(id) => {
+            let index = leases
+                .iter()
+                .position(|held| held.lease.id == id)
+                .ok_or_else(|| eyre::eyre!("monitor {id} isn't leased"))?;
+            release(id, leases.remove(index).teardown, pipe_name)?;
+            LeaseCommand::ReplyOk
+        }
+
+        LeaseCommand::ReplyOk | LeaseCommand::ReplyError(_) | LeaseCommand::ReplyLease(_) => {
+            eyre::bail!("unexpected command");
+        }
+    };
+
+    Ok(reply)
+}
+
+fn lease(mode: ActiveMode, pipe_name: &str) -> eyre::Result<Held> {
+    eyre::ensure!(
+        mode.width > 0 && mode.height > 0 && mode.refresh_rate > 0,
+        "invalid mode {}x{}@{}",
+        mode.width,
+        mode.height,
+        mode.refresh_rate
+    );
+
+    let mut connection = driver::connect(pipe_name)?;
+    let monitors = connection.list()?;
+
+    let prefers = |monitor: &Monitor| {
+        monitor.modes.first().is_some_and(|preferred| {
+            preferred.width == mode.width
+                && preferred.height == mode.height
+                && preferred.refresh_rates.first() == Some(&mode.refresh_rate)
+        })
+    };
+
+    let (id, teardown) = if let Some(monitor) = monitors
+        .iter()
+        .find(|monitor| monitor.enabled && monitor.active_mode == Some(mode))
+    {
+        (monitor.id, Teardown::Keep)
+    } else if let Some(monitor) = monitors
+        .iter()
+        .find(|monitor| !monitor.enabled && prefers(monitor))
+    {
+        let id = monitor.id;
+        let monitor = Monitor {
+            enabled: true,
+            ..monitor.clone()
+        };
+        connection.send(&Command::DriverNotify(vec![monitor]))?;
+        (id, Teardown::Disable)
+    } else {
+        #[allow(clippy::maybe_infinite_iter)]
+        let id = (0..)
+            .find(|id| !monitors.iter().any(|monitor| monitor.id == *id))
+            .expect("failed to get a new ID");
+
+        let modes = vec![Mode {
+            width: mode.width,
+            height: mode.height,
+            refresh_rates: vec![mode.refresh_rate],
+        }];
+        let monitor = Monitor {
+            // not restored after a crash that skipped the teardown
+            ephemeral: true,
+            auto_plug: false,
+            ..Monitor::new(id, modes)
+        };
+        connection.send(&Command::DriverNotify(vec![monitor]))?;
+        (id, Teardown::Remove)
+    };
+    // the driver pipe takes one client at a time
+    drop(connection);
+
+    match wait_for_display(id, pipe_name) {
+        Ok(lease) => Ok(Held {
+            lease,
+            mode,
+            teardown,
+        }),
+        Err(e) => {
+            // don't leave a monitor behind that nobody holds
+            _ = release(id, teardown, pipe_name);
+            Err(e)
+        }
+    }
+}
+
+fn release(id: Id, teardown: Teardown, pipe_name: &str) -> eyre::Result<()> {
+    match teardown {
+        Teardown::Remove => {
+            driver::connect(pipe_name)?.send(&Command::DriverRemove(vec![id.into()]))?;
+        }
+
+        Teardown::Disable => {
+            let mut connection = driver::connect(pipe_name)?;
+            let Some(monitor) = connection
+                .list()?
+                .into_iter()
+                .find(|monitor| monitor.id == id)
+            else {
+                // removed by someone else in the meantime
+                return Ok(());
+            };
+
+            let monitor = Monitor {
+                enabled: false,
+                ..monitor
+            };
+            connection.send(&Command::DriverNotify(vec![monitor]))?;
+        }
+
+        Teardown::Keep => (),
+    }
+
+    Ok(())
+}
+
+fn wait_for_display(id: Id, pipe_name: &str) -> eyre::Result<Lease> {
+    let start = Instant::now();
+
+    loop {
+        let target = driver::connect(pipe_name)?
+            .list()?
+            .into_iter()
+            .find(|monitor| monitor.id == id)
+            .and_then(|monitor| monitor.target);
+
+        if let Some(target) = target {
+            if let Some(display) = display::gdi_name(target)? {
+                return Ok(Lease {
+                    id,
+                    display,
+                    target,
+                });
+            }
+        }
+
+        if start.elapsed() > DISPLAY_TIMEOUT {
+            eyre::bail!("monitor {id} didn't show up as a display");
+        }
+        thread::sleep(DISPLAY_POLL_INTERVAL);
+    }
+}
@@ -0,0 +1,182 @@
+use std::thread;
+
+use clap::Parser;
+
+#[cfg(feature = "agent")]
+mod agent;
+#[cfg(feature = "lease")]
+mod display;
+mod driver;
+#[cfg(feature = "encoder")]
+mod encoder;
+#[cfg(feature = "lease")]
+mod lease;
+#[cfg(feature = "encoder")]
+mod media_foundation;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "mqtt")]
+mod mqtt;
+#[cfg(feature = "schedule")]
+mod schedule;
+#[cfg(feature = "session")]
+mod session;
+#[cfg(feature = "streamdeck")]
+mod streamdeck;
+
+/// Background daemon for the Virtual Display Driver.
+#[derive(Debug, Parser)]
+struct Args {
+    /// Driver instance to talk to, by pipe name. Defaults to the stock
+    /// driver instance.
+    #[clap(long, default_value = driver_ipc::DEFAULT_PIPE_NAME)]
+    instance: String,
+
+    /// Serve Prometheus metrics at `http://<ADDR>/metrics`, e.g.
+    /// `127.0.0.1:9101`.
+    #[cfg(feature = "metrics")]
+    #[clap(long, value_name = "ADDR")]
+    metrics: Option<std::net::SocketAddr>,
+
+    /// Publish the state of monitors to an MQTT broker at `HOST[:PORT]`,
+    /// and take commands to turn them on and off, with Home Assistant
+    /// discovery.
+    #[cfg(feature = "mqtt")]
+    #[clap(long, value_name = "HOST[:PORT]")]
+    mqtt: Option<String>,
+
+    /// User name to log in to the MQTT broker with.
+    #[cfg(feature = "mqtt")]
+    #[clap(long, requires = "mqtt", requires = "mqtt_password")]
+    mqtt_username: Option<String>,
+
+    /// Password to log in to the MQTT broker with.
+    #[cfg(feature = "mqtt")]
+    #[clap(long, requires = "mqtt_username")]
+    mqtt_password: Option<String>,
+
+    /// Enable monitors with the `Remote` session policy when a remote
+    /// desktop session connects, and disable them when the console is used
+    /// again.
+    #[cfg(feature = "session")]
+    #[clap(long)]
+    sessions: bool,
+
+    /// Load the monitor profiles of `virtual-display-driver-cli schedule`
+    /// when their trigger fires, and apply the process rules of
+    /// `virtual-display-driver-cli rule`.
+    #[cfg(feature = "schedule")]
+    #[clap(long)]
+    schedule: bool,
+
+    /// Serve an action API for Stream Deck plugins on this port of
+    /// localhost, to show and toggle monitors and load profiles from keys.
+    #[cfg(feature = "streamdeck")]
+    #[clap(long, value_name = "PORT")]
+    streamdeck: Option<u16>,
+
+    /// Directory with the profiles Stream Deck keys can load, json files of
+    /// monitors as printed by `virtual-display-driver-cli list --json`.
+    #[cfg(feature = "streamdeck")]
+    #[clap(long, value_name = "DIR", requires = "streamdeck")]
+    streamdeck_profiles: Option<std::path::PathBuf>,
+
+    /// Encode the frames of monitors with gpu export enabled to H.264/HEVC,
+    /// started and stopped over the `virtualdisplaydriver-encoder` pipe.
+    #[cfg(feature = "encoder")]
+    #[clap(long)]
+    encoder: bool,
+
+    /// Lease monitors in the exact mode of a streaming client over the
+    /// `virtualdisplaydriver-lease` pipe, and release them when it
+    /// disconnects.
+    #[cfg(feature = "lease")]
+    #[clap(long)]
+    leases: bool,
+
+    /// Take driver commands from `virtual-display-driver-cli --host` on
+    /// other machines at this address, e.g. `0.0.0.0:9900`.
+    #[cfg(feature = "agent")]
+    #[clap(long, value_name = "ADDR", requires = "agent_key_file")]
+    agent: Option<std::net::SocketAddr>,
+
+    /// File with the key remote clients have to know, shared with them.
+    #[cfg(feature = "agent")]
+    #[clap(long, value_name = "PATH", requires = "agent")]
+    agent_key_file: Option<std::path::PathBuf>,
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let mut services = Vec::<thread::JoinHandle<eyre::Result<()>>>::new();
+
+    #[cfg(feature = "metrics")]
+    if let Some(addr) = args.metrics {
+        let pipe_name = args.instance.clone();
+        services.push(thread::spawn(move || metrics::serve(addr, &pipe_name)));
+    }
+
+    #[cfg(feature = "mqtt")]
+    if let Some(broker) = args.mqtt.clone() {
+        let pipe_name = args.instance.clone();
+        let credentials = args.mqtt_username.clone().zip(args.mqtt_password.clone());
+        services.push(thread::spawn(move || {
+            mqtt::bridge(&broker, credentials, &pipe_name)
+        }));
+    }
+
+    #[cfg(feature = "session")]
+    if args.sessions {
+        let pipe_name = args.instance.clone();
+        services.push(thread::spawn(move || session::watch(&pipe_name)));
+    }
+
+    #[cfg(feature = "schedule")]
+    if args.schedule {
+        let pipe_name = args.instance.clone();
+        services.push(thread::spawn(move || schedule::run(&pipe_name)));
+    }
+
+    #[cfg(feature = "streamdeck")]
+    if let Some(port) = args.streamdeck {
+        let pipe_name = args.instance.clone();
+        let profiles = args.streamdeck_profiles.clone();
+        services.push(thread::spawn(move || {
+            streamdeck::serve(port, profiles.as_deref(), &pipe_name)
+        }));
+    }
+
+    #[cfg(feature = "encoder")]
+    if args.encoder {
+        let pipe_name = args.instance.clone();
+        services.push(thread::spawn(move || encoder::serve(&pipe_name)));
+    }
+
+    #[cfg(feature = "lease")]
+    if args.leases {
+        let pipe_name = args.instance.clone();
+        services.push(thread::spawn(move || lease::serve(&pipe_name)));
+    }
+
+    #[cfg(feature = "agent")]
+    if let Some((addr, key_file)) = args.agent.zip(args.agent_key_file.clone()) {
+        let pipe_name = args.instance.clone();
+        services.push(thread::spawn(move || {
+            agent::serve(addr, &key_file, &pipe_name)
+        }));
+    }
+
+    if services.is_empty() {
+        eyre::bail!("nothing to do, enable at least one service (e.g. --metrics)");
+    }
+
+    // services only return on fatal errors
+    for service in services {
+        service
+            .join()
+            .map_err(|_| eyre::eyre!("service panicked"))??;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+use std::{
+    ffi::c_void,
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{Codec, EncodeSettings, SharedFrame};
+use eyre::Context as _;
+use windows::{
+    core::{Interface, GUID, HSTRING},
+    Win32::{
+        Foundation::S_OK,
+        Graphics::{
+            Direct3D::D3D_DRIVER_TYPE_HARDWARE,
+            Direct3D11::{
+                D3D11CreateDevice, ID3D11Device, ID3D11Device1, ID3D11DeviceContext,
+                ID3D11Multithread, ID3D11Texture2D, D3D11_CREATE_DEVICE_BGRA_SUPPORT,
+                D3D11_CREATE_DEVICE_VIDEO_SUPPORT, D3D11_SDK_VERSION, D3D11_TEXTURE2D_DESC,
+            },
+            Dxgi::{
+                Common::DXGI_FORMAT_B8G8R8A8_UNORM, IDXGIKeyedMutex, DXGI_SHARED_RESOURCE_READ,
+                DXGI_SHARED_RESOURCE_WRITE,
+            },
+        },
+        Media::MediaFoundation::{
+            IMFAttributes, IMFDXGIBuffer, IMFDXGIDeviceManager, IMFMediaType, IMFSample,
+            IMFSinkWriter, IMFVideoSampleAllocatorEx, MFCreateAttributes,
+            MFCreateDXGIDeviceManager, MFCreateMediaType, MFCreateSinkWriterFromURL,
+            MFCreateVideoSampleAllocatorEx, MFMediaType_Video, MFTranscodeContainerType_FMPEG4,
+            MFVideoFormat_ARGB32, MFVideoFormat_H264, MFVideoFormat_HEVC,
+            MFVideoInterlace_Progressive, MF_LOW_LATENCY, MF_MT_AVG_BITRATE, MF_MT_FRAME_RATE,
+            MF_MT_FRAME_SIZE, MF_MT_INTERLACE_MODE, MF_MT_MAJOR_TYPE, MF_MT_PIXEL_ASPECT_RATIO,
+            MF_MT_SUBTYPE, MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS, MF_SINK_WRITER_D3D_MANAGER,
+            MF_TRANSCODE_CONTAINERTYPE,
+        },
+    },
+};
+
+// Keys of the shared texture's keyed mutex, see `SharedFrame`
+const KEY_DRIVER: u64 = 0;
+const KEY_CONSUMER: u64 = 1;
+
+// Frame rate the encoder is configured for. Frames are timestamped by when they arrive, so this
+// only guides the encoder's rate control
+const NOMINAL_FPS: u32 = 60;
+
+// Samples in flight between us and the encoder, copying waits for one to come back when all
+// are in use
+const MAX_SAMPLES: u32 = 8;
+
+/// Encodes the frames of a shared texture into a file, on the gpu
+///
+/// Uses the hardware encoder (NVENC, AMF, QSV) when the gpu has one, and the software encoder
+/// otherwise.
+pub struct Encoder {
+    device: ID3D11Device1,
+    context: ID3D11DeviceContext,
+    shared: ID3D11Texture2D,
+    mutex: IDXGIKeyedMutex,
+    allocator: IMFVideoSampleAllocatorEx,
+    writer: IMFSinkWriter,
+    stream: u32,
+    // the encoder uses the device through this, it has to outlive the writer
+    _manager: IMFDXGIDeviceManager,
+    start: Option<Instant>,
+    last_time: i64,
+}
+
+impl Encoder {
+    /// Open the shared texture and start writing `settings.output`
+    ///
+    /// Media Foundation has to be started on this thread first
+    pub fn new(frame: &SharedFrame, settings: &EncodeSettings) -> eyre::Result<Self> {
+        // desktop frames are bgra, anything else (e.g. hdr) would need a conversion pass first
+        if u32::try_from(DXGI_FORMAT_B8G8R8A8_UNORM.0) != Ok(frame.format) {
+            eyre::bail!("unsupported frame format {}", frame.format);
+        }
+
+        let (device, context) = create_device()?;
+        let (shared, mutex) = open_shared(&device, frame)?;
+
+        let mut reset_token = 0;
+        let mut manager = None;
+        unsafe { MFCreateDXGIDeviceManager(&mut reset_token, &mut manager)? };
+        let manager = manager.ok_or_else(|| eyre::eyre!("no dxgi device manager"))?;
+        unsafe { manager.ResetDevice(&device, reset_token)? };
+
+        let input_type = video_type(&MFVideoFormat_ARGB32, frame)?;
+        let output_type = video_type(
+            match settings.codec {
+                Codec::H264 => &MFVideoFormat_H264,
+                Codec::Hevc => &MFVideoFormat_HEVC,
+            },
+            frame,
+        )?;
+        unsafe {
+            output_type.SetUINT32(&MF_MT_AVG_BITRATE, settings.bitrate.saturating_mul(1000))?;
+        }
+
+        let attributes = attributes(4)?;
+        unsafe { attributes.SetUINT32(&MF_READWRITE_ENABLE_HARDWARE_TRANSFORMS, 1)? };
+        unsafe { attributes.SetUINT32(&MF_LOW_LATENCY, 1)? };
+        unsafe { attributes.SetUnknown(&MF_SINK_WRITER_D3D_MANAGER, &manager)? };
+        unsafe {
+            attributes.SetGUID(
+                &MF_TRANSCODE_CONTAINERTYPE,
+                &MFTranscodeContainerType_FMPEG4,
+            )?;
+        }
+
+        let writer = unsafe {
+            MFCreateSinkWriterFromURL(&HSTRING::from(&settings.output), None, &attributes)
+                .wrap_err("failed to create the output file")?
+        };
+        let stream = unsafe { writer.AddStream(&output_type)? };
+        unsafe {
+            writer
+                .SetInputMediaType(stream, &input_type, None)
+                .wrap_err("no encoder supports this codec and frame size")?;
+        }
+
+        let mut allocator = std::ptr::null_mut::<c_void>();
+        unsafe {
+            MFCreateVideoSampleAllocatorEx(&IMFVideoSampleAllocatorEx::IID, &mut allocator)?;
+        }
+        // SAFETY: created above with the interface's iid, and owned from here on
+        let allocator = unsafe { IMFVideoSampleAllocatorEx::from_raw(allocator) };
+        unsafe { allocator.SetDirectXManager(&manager)? };
+        unsafe { allocator.InitializeSampleAllocatorEx(2, MAX_SAMPLES, None, &input_type)? };
+
+        unsafe { writer.BeginWriting()? };
+
+        Ok(Self {
+            device,
+            context,
+            shared,
+            mutex,
+            allocator,
+            writer,
+            stream,
+            _manager: manager,
+            start: None,
+            last_time: 0,
+        })
+    }
+
+    /// Continue with the texture the driver re-created, e.g. after the swap chain was re-created
+    ///
+    /// The frame size and format can't change within a file.
+    pub fn reopen(&mut self, frame: &SharedFrame) -> eyre::Result<()> {
+        let mut desc = D3D11_TEXTURE2D_DESC::default();
+        unsafe { self.shared.GetDesc(&mut desc) };
+
+        if (desc.Width, desc.Height) != (frame.width, frame.height)
+            || u32::try_from(desc.Format.0) != Ok(frame.format)
+        {
+            eyre::bail!("the frame size or format changed");
+        }
+
+        (self.shared, self.mutex) = open_shared(&self.device, frame)?;
+
+        Ok(())
+    }
+
+    /// Wait up to `timeout` for the next frame and encode it
+    ///
+    /// Returns whether a frame arrived
+    pub fn encode_next(&mut self, timeout: Duration) -> eyre::Result<bool> {
+        let timeout = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+
+        // a timeout is a success code, which the wrapper would turn into `Ok`
+        let hr = unsafe {
+            (Interface::vtable(&self.mutex).AcquireSync)(
+                Interface::as_raw(&self.mutex),
+                KEY_CONSUMER,
+                timeout,
+            )
+        };
+        if hr != S_OK {
+            hr.ok()?;
+            return Ok(false);
+        }
+
+        // hand the texture back to the driver right after copying, even if that failed
+        let sample = self.copy_frame();
+        unsafe { self.mutex.ReleaseSync(KEY_DRIVER)? };
+        let sample = sample?;
+
+        // 100ns units
+        let now = Instant::now();
+        let start = *self.start.get_or_insert(now);
+        let time = i64::try_from(now.duration_since(start).as_nanos() / 100).unwrap_or(i64::MAX);
+
+        unsafe { sample.SetSampleTime(time)? };
+        unsafe { sample.SetSampleDuration((time - self.last_time).max(1))? };
+        unsafe { self.writer.WriteSample(self.stream, &sample)? };
+        self.last_time = time;
+
+        Ok(true)
+    }
+
+    fn copy_frame(&self) -> eyre::Result<IMFSample> {
+        let sample = unsafe { self.allocator.AllocateSample()? };
+
+        let buffer = unsafe { sample.GetBufferByIndex(0)? }.cast::<IMFDXGIBuffer>()?;
+        let mut texture = std::ptr::null_mut::<c_void>();
+        unsafe { buffer.GetResource(&ID3D11Texture2D::IID, &mut texture)? };
+        // SAFETY: requested with the interface's iid above, and owned from here on
+        let texture = unsafe { ID3D11Texture2D::from_raw(texture) };
+
+        unsafe { self.context.CopyResource(&texture, &self.shared) };
+
+        Ok(sample)
+    }
+
+    /// Flush the encoder and finish the output file
+    pub fn finish(self) -> eyre::Result<()> {
+        unsafe { self.writer.Finalize()? };
+        Ok(())
+    }
+}
+
+fn create_device() -> eyre::Result<(ID3D11Device1, ID3D11DeviceContext)> {
+    let mut device = None;
+    let mut context = None;
+
+    unsafe {
+        D3D11CreateDevice(
+            None,
+            D3D_DRIVER_TYPE_HARDWARE,
+            None,
+            D3D11_CREATE_DEVICE_BGRA_SUPPORT | D3D11_CREATE_DEVICE_VIDEO_SUPPORT,
+            None,
+            D3D11_SDK_VERSION,
+            Some(&mut device),
+            None,
+            Some(&mut context),
+        )
+        .wrap_err("failed to create d3d11 device")?;
+    }
+
+    let device = device.ok_or_else(|| eyre::eyre!("no d3d11 device"))?;
+    let context = context.ok_or_else(|| eyre::eyre!("no d3d11 device context"))?;
+
+    // the encoder uses the device from its own threads
+    unsafe {
+        _ = device
+            .cast::<ID3D11Multithread>()?
+            .SetMultithreadProtected(true)
+    };
+
+    Ok((device.cast()?, context))
+}
+
+fn open_shared(
+    device: &ID3D11Device1,
+    frame: &SharedFrame,
+) -> eyre::Result<(ID3D11Texture2D, IDXGIKeyedMutex)> {
+    // the texture can only be opened on the gpu the driver renders on, which is the default
+    // one unless the render adapter was changed
+    let shared = unsafe {
+        device
+            .OpenSharedResourceByName::<_, ID3D11Texture2D>(
+                &HSTRING::from(&frame.name),
+                DXGI_SHARED_RESOURCE_READ | DXGI_SHARED_RESOURCE_WRITE,
+            )
+            .wrap_err("failed to open the shared frame")?
+    };
+    let mutex = shared.cast::<IDXGIKeyedMutex>()?;
+
+    Ok((shared, mutex))
+}
+
+fn attributes(size: u32) -> eyre::Result<IMFAttributes> {
+    let mut attributes = None;
+    unsafe { MFCreateAttributes(&mut attributes, size)? };
+    attributes.ok_or_else(|| eyre::eyre!("no attributes"))
+}
+
+fn video_type(subtype: &GUID, frame: &SharedFrame) -> eyre::Result<IMFMediaType> {
+    let media_type = unsafe { MFCreateMediaType()? };
+
+    // sizes and ratios are packed as two u32s, high one first
+    let pack = |high: u32, low: u32| (u64::from(high) << 32) | u64::from(low);
+
+    #[allow(clippy::cast_sign_loss)]
+    let interlace = MFVideoInterlace_Progressive.0 as u32;
+
+    unsafe { media_type.SetGUID(&MF_MT_MAJOR_TYPE, &MFMediaType_Video)? };
+    unsafe { media_type.SetGUID(&MF_MT_SUBTYPE, subtype)? };
+    unsafe { media_type.SetUINT32(&MF_MT_INTERLACE_MODE, interlace)? };
+    unsafe { media_type.SetUINT64(&MF_MT_FRAME_SIZE, pack(frame.width, frame.height))? };
+    unsafe { media_type.SetUINT64(&MF_MT_FRAME_RATE, pack(NOMINAL_FPS, 1))? };
+    unsafe { media_type.SetUINT64(&MF_MT_PIXEL_ASPECT_RATIO, pack(1, 1))? };
+
+    Ok(media_type)
+}
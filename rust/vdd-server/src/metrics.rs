@@ -0,0 +1,168 @@
+use std::{
+    fmt::Write as _,
+    io::{BufRead as _, BufReader, Write as _},
+    net::{SocketAddr, TcpListener, TcpStream},
+};
+
+use crate::driver::{self, Snapshot};
+
+/// Serve Prometheus metrics at `/metrics` until the listener fails
+///
+/// Every scrape takes a fresh snapshot of the driver, so the numbers are never stale.
+pub fn serve(addr: SocketAddr, pipe_name: &str) -> eyre::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    println!("Serving metrics at http://{addr}/metrics");
+
+    // scrapes that failed to talk to the driver
+    let mut pipe_errors = 0u64;
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+
+        if let Err(e) = handle(stream, pipe_name, &mut pipe_errors) {
+            eprintln!("Failed to handle metrics request: {e:?}");
+        }
+    }
+
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream, pipe_name: &str, pipe_errors: &mut u64) -> eyre::Result<()> {
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    // e.g. `GET /metrics HTTP/1.1`
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (parts.next(), parts.next());
+
+    if method != Some("GET") || path != Some("/metrics") {
+        stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")?;
+        return Ok(());
+    }
+
+    let snapshot = driver::snapshot(pipe_name)
+        .map_err(|e| {
+            *pipe_errors += 1;
+            eprintln!("Failed to get driver state: {e:?}");
+        })
+        .ok();
+
+    let body = render(snapshot.as_ref(), *pipe_errors);
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\r\n{body}",
+        body.len()
+    )?;
+
+    Ok(())
+}
+
+/// Render the snapshot in the Prometheus text exposition format
+fn render(snapshot: Option<&Snapshot>, pipe_errors: u64) -> String {
+    let mut out = String::new();
+
+    metric(
+        &mut out,
+        "vdd_up",
+        "gauge",
+        "Whether the last scrape of the driver succeeded",
+        &[(None, u8::from(snapshot.is_some()).to_string())],
+    );
+    metric(
+        &mut out,
+        "vdd_pipe_errors_total",
+        "counter",
+        "Scrapes that failed to talk to the driver pipe",
+        &[(None, pipe_errors.to_string())],
+    );
+
+    let Some(snapshot) = snapshot else {
+        return out;
+    };
+
+    let active = snapshot.monitors.iter().filter(|m| m.enabled).count();
+    metric(
+        &mut out,
+        "vdd_monitors",
+        "gauge",
+        "Configured virtual monitors",
+        &[(None, snapshot.monitors.len().to_string())],
+    );
+    metric(
+        &mut out,
+        "vdd_monitors_active",
+        "gauge",
+        "Enabled virtual monitors",
+        &[(None, active.to_string())],
+    );
+
+    let per_monitor = |value: fn(&driver_ipc::Stats) -> String| {
+        snapshot
+            .stats
+            .iter()
+            .map(|stats| (Some(stats.id), value(stats)))
+            .collect::<Vec<_>>()
+    };
+
+    metric(
+        &mut out,
+        "vdd_frames_presented_total",
+        "counter",
+        "Frames acquired from the os",
+        &per_monitor(|s| s.frames_presented.to_string()),
+    );
+    metric(
+        &mut out,
+        "vdd_frames_dropped_total",
+        "counter",
+        "Frames presented by the os that were never acquired",
+        &per_monitor(|s| s.frames_dropped.to_string()),
+    );
+    metric(
+        &mut out,
+        "vdd_shared_frames_dropped_total",
+        "counter",
+        "Frames the consumer of the shared frames missed because it fell behind",
+        &per_monitor(|s| s.shared_frames_dropped.to_string()),
+    );
+    metric(
+        &mut out,
+        "vdd_frame_latency_microseconds",
+        "gauge",
+        "Average time between acquiring and releasing a frame",
+        &per_monitor(|s| s.avg_latency_us.to_string()),
+    );
+    metric(
+        &mut out,
+        "vdd_refresh_rate_hertz",
+        "gauge",
+        "Measured refresh rate, 0 if idle",
+        &per_monitor(|s| s.refresh_rate.to_string()),
+    );
+    metric(
+        &mut out,
+        "vdd_ring_consumers",
+        "gauge",
+        "Consumers reading from the frame ring",
+        &per_monitor(|s| s.consumers.len().to_string()),
+    );
+
+    out
+}
+
+/// Write a single metric, samples with a monitor id get a `monitor` label
+fn metric(out: &mut String, name: &str, kind: &str, help: &str, samples: &[(Option<u32>, String)]) {
+    _ = writeln!(out, "# HELP {name} {help}");
+    _ = writeln!(out, "# TYPE {name} {kind}");
+
+    for (monitor, value) in samples {
+        if let Some(id) = monitor {
+            _ = writeln!(out, "{name}{{monitor=\"{id}\"}} {value}");
+        } else {
+            _ = writeln!(out, "{name} {value}");
+        }
+    }
+}
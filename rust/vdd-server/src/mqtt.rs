@@ -0,0 +1,234 @@
+use std::{
+    collections::HashMap,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use driver_ipc::{Command, Id, Monitor, MonitorControl as _};
+use eyre::Context as _;
+use rumqttc::{Client, Event, LastWill, MqttOptions, Packet, QoS};
+
+use crate::driver;
+
+// How often the driver state is checked for changes to publish
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+// `online` while the bridge is connected, the broker sets it to `offline` otherwise
+const AVAILABILITY_TOPIC: &str = "vdd/available";
+const DISCOVERY_PREFIX: &str = "homeassistant";
+
+enum Message {
+    // (re)connected to the broker, which forgets subscriptions of clean sessions
+    Connected,
+    // payload sent to `vdd/monitor/<id>/set`
+    Set(Id, Vec<u8>),
+}
+
+/// Publish the state of every monitor to an MQTT broker at `broker` (`host` or `host:port`), and
+/// enable or disable monitors on commands, with Home Assistant discovery
+///
+/// Every monitor is a switch. Its state is published to `vdd/monitor/<id>/state` as `ON` or
+/// `OFF`, and `ON`, `OFF` or `TOGGLE` sent to `vdd/monitor/<id>/set` changes it.
+pub fn bridge(
+    broker: &str,
+    credentials: Option<(String, String)>,
+    pipe_name: &str,
+) -> eyre::Result<()> {
+    let (host, port) = match broker.rsplit_once(':') {
+        Some((host, port)) => (host, port.parse().wrap_err("invalid MQTT broker port")?),
+        None => (broker, 1883),
+    };
+
+    let mut options = MqttOptions::new("vdd-server", host, port);
+    options
+        .set_keep_alive(Duration::from_secs(30))
+        .set_last_will(LastWill::new(
+            AVAILABILITY_TOPIC,
+            "offline",
+            QoS::AtLeastOnce,
+            true,
+        ));
+    if let Some((username, password)) = credentials {
+        options.set_credentials(username, password);
+    }
+
+    let (client, mut connection) = Client::new(options, 64);
+
+    // the connection has to be polled for the client to make progress, also while the driver
+    // is queried
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for event in connection.iter() {
+            let message = match event {
+                Ok(Event::Incoming(Packet::ConnAck(_))) => Message::Connected,
+                Ok(Event::Incoming(Packet::Publish(publish))) => {
+                    let Some(id) = command_id(&publish.topic) else {
+                        continue;
+                    };
+                    Message::Set(id, publish.payload.to_vec())
+                }
+                Ok(_) => continue,
+                Err(e) => {
+                    // reconnects on the next iteration
+                    eprintln!("MQTT connection failed: {e}");
+                    thread::sleep(POLL_INTERVAL);
+                    continue;
+                }
+            };
+
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    println!("Bridging monitors to the MQTT broker at {host}:{port}");
+
+    // what was last published for each monitor
+    let mut published = HashMap::<Id, Monitor>::new();
+
+    loop {
+        match receiver.recv_timeout(POLL_INTERVAL) {
+            Ok(Message::Connected) => {
+                client.subscribe("vdd/monitor/+/set", QoS::AtLeastOnce)?;
+                client.publish(AVAILABILITY_TOPIC, QoS::AtLeastOnce, true, "online")?;
+                // the broker may have lost the retained messages
+                published.clear();
+            }
+            Ok(Message::Set(id, payload)) => {
+                if let Err(e) = set(pipe_name, id, &payload) {
+                    eprintln!("Failed to apply MQTT command for monitor {id}: {e:?}");
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => eyre::bail!("MQTT connection closed"),
+        }
+
+        match driver::connect(pipe_name).and_then(|mut connection| Ok(connection.list()?)) {
+            Ok(monitors) => publish(&client, &monitors, &mut published)?,
+            // retried on the next poll
+            Err(e) => eprintln!("Failed to get driver state: {e:?}"),
+        }
+    }
+}
+
+// ID of a `vdd/monitor/<id>/set` topic
+fn command_id(topic: &str) -> Option<Id> {
+    topic
+        .strip_prefix("vdd/monitor/")?
+        .strip_suffix("/set")?
+        .parse()
+        .ok()
+}
+
+fn set(pipe_name: &str, id: Id, payload: &[u8]) -> eyre::Result<()> {
+    let mut connection = driver::connect(pipe_name)?;
+
+    let Some(mut monitor) = connection
+        .list()?
+        .into_iter()
+        .find(|monitor| monitor.id == id)
+    else {
+        eyre::bail!("no monitor with ID {id}");
+    };
+
+    let enabled = match payload {
+        b"ON" => true,
+        b"OFF" => false,
+        b"TOGGLE" => !monitor.enabled,
+        _ => eyre::bail!(
+            "expected ON, OFF or TOGGLE, got {:?}",
+            String::from_utf8_lossy(payload)
+        ),
+    };
+
+    if monitor.enabled != enabled {
+        println!(
+            "{} monitor {id} over MQTT",
+            if enabled { "Enabling" } else { "Disabling" }
+        );
+
+        monitor.enabled = enabled;
+        connection.send(&Command::DriverNotify(vec![monitor]))?;
+    }
+
+    Ok(())
+}
+
+/// Publish the monitors that changed since the last call, and clear the retained messages of
+/// removed ones
+fn publish(
+    client: &Client,
+    monitors: &[Monitor],
+    published: &mut HashMap<Id, Monitor>,
+) -> eyre::Result<()> {
+    for monitor in monitors {
+        let last = published.get(&monitor.id);
+
+        if last.map(|last| &last.name) != Some(&monitor.name) {
+            client.publish(
+                discovery_topic(monitor.id),
+                QoS::AtLeastOnce,
+                true,
+                discovery(monitor).to_string(),
+            )?;
+        }
+
+        if last.map(|last| last.enabled) != Some(monitor.enabled) {
+            client.publish(
+                format!("vdd/monitor/{}/state", monitor.id),
+                QoS::AtLeastOnce,
+                true,
+                if monitor.enabled { "ON" } else { "OFF" },
+            )?;
+        }
+
+        published.insert(monitor.id, monitor.clone());
+    }
+
+    let removed = published
+        .keys()
+        .copied()
+        .filter(|id| monitors.iter().all(|monitor| monitor.id != *id))
+        .collect::<Vec<_>>();
+
+    for id in removed {
+        // empty retained messages delete them, and the entity in Home Assistant
+        client.publish(discovery_topic(id), QoS::AtLeastOnce, true, "")?;
+        client.publish(
+            format!("vdd/monitor/{id}/state"),
+            QoS::AtLeastOnce,
+            true,
+            "",
+        )?;
+        published.remove(&id);
+    }
+
+    Ok(())
+}
+
+fn discovery_topic(id: Id) -> String {
+    format!("{DISCOVERY_PREFIX}/switch/vdd_monitor_{id}/config")
+}
+
+/// Home Assistant discovery message of a monitor, which makes it a switch
+fn discovery(monitor: &Monitor) -> serde_json::Value {
+    let id = monitor.id;
+    let name = monitor
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("Virtual monitor {id}"));
+
+    serde_json::json!({
+        "name": name,
+        "unique_id": format!("vdd_monitor_{id}"),
+        "state_topic": format!("vdd/monitor/{id}/state"),
+        "command_topic": format!("vdd/monitor/{id}/set"),
+        "availability_topic": AVAILABILITY_TOPIC,
+        "icon": "mdi:monitor",
+        "device": {
+            "identifiers": ["virtual-display-driver"],
+            "name": "Virtual Display Driver",
+        },
+    })
+}
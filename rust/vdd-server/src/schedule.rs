@@ -0,0 +1,287 @@
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    path::Path,
+    thread,
+    time::Duration,
+};
+
+use driver_ipc::{
+    Command, HookSession, Id, Monitor, MonitorControl as _, Rule, ScheduleEntry, Trigger,
+};
+use eyre::Context as _;
+use serde::de::DeserializeOwned;
+use windows::Win32::{
+    Foundation::{CloseHandle, FILETIME, STILL_ACTIVE},
+    System::{
+        Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+            TH32CS_SNAPPROCESS,
+        },
+        SystemInformation::GetLocalTime,
+        Threading::{
+            GetExitCodeProcess, GetProcessTimes, OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
+        },
+    },
+};
+use winreg::{
+    enums::{HKEY_CURRENT_USER, KEY_WRITE},
+    RegKey,
+};
+
+use crate::driver;
+
+// How often the schedule, the time and the running processes are checked
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Load the profiles of the schedule edited with `virtual-display-driver-cli schedule` when
+/// their trigger fires, and apply the rules of `virtual-display-driver-cli rule` when their
+/// process starts or exits. Also undoes the profile of `virtual-display-driver-cli hook
+/// pre-launch` once its launcher exits
+///
+/// Both are read again on every poll, so changes apply without a restart.
+pub fn run(pipe_name: &str) -> eyre::Result<()> {
+    let mut started = false;
+    let mut last_time = None;
+    // process entries whose profile is loaded, with the IDs of its monitors
+    let mut loaded = Vec::<(ScheduleEntry, Vec<Id>)>::new();
+    // whether the process of each rule was running when the rule was last applied
+    let mut applied = HashMap::<Rule, bool>::new();
+
+    loop {
+        let schedule = read_setting::<ScheduleEntry>(driver_ipc::SCHEDULE_VALUE)?;
+        let rules = read_setting::<Rule>(driver_ipc::RULES_VALUE)?;
+        let time = local_time();
+        let processes = running_processes()?;
+
+        for entry in &schedule {
+            let fire = match &entry.trigger {
+                Trigger::Login => !started,
+                Trigger::At { hour, minute } => last_time != Some(time) && time == (*hour, *minute),
+                Trigger::Process(name) => {
+                    processes.contains(&name.to_lowercase())
+                        && loaded.iter().all(|(loaded, _)| loaded != entry)
+                }
+            };
+            if !fire {
+                continue;
+            }
+
+            let replace = !matches!(entry.trigger, Trigger::Process(_));
+            match driver::load_profile(pipe_name, Path::new(&entry.profile), replace) {
+                Ok(ids) => {
+                    println!(
+                        "Loaded profile {} ({})",
+                        entry.profile,
+                        trigger(&entry.trigger)
+                    );
+                    if !replace {
+                        loaded.push((entry.clone(), ids));
+                    }
+                }
+                // process entries are retried on the next poll
+                Err(e) => eprintln!("Failed to load profile {}: {e:?}", entry.profile),
+            }
+        }
+
+        // profiles are also unloaded when their entry is removed from the schedule
+        let mut index = 0;
+        while let Some((entry, ids)) = loaded.get(index) {
+            let Trigger::Process(name) = &entry.trigger else {
+                unreachable!("only process entries are kept loaded");
+            };
+
+            if schedule.contains(entry) && processes.contains(&name.to_lowercase()) {
+                index += 1;
+                continue;
+            }
+
+            match unload(pipe_name, ids) {
+                Ok(()) => {
+                    println!("Unloaded profile {} ({name} exited)", entry.profile);
+                    loaded.remove(index);
+                }
+                Err(e) => {
+                    eprintln!("Failed to unload profile {}: {e:?}", entry.profile);
+                    index += 1;
+                }
+            }
+        }
+
+        // only transitions are applied, so the monitors can still be toggled by hand in between
+        applied.retain(|rule, _| rules.contains(rule));
+        for rule in &rules {
+            let running = processes.contains(&rule.process.to_lowercase());
+            if applied.get(rule) == Some(&running) {
+                continue;
+            }
+
+            let enabled = rule.enabled == running;
+            match set_enabled(pipe_name, &rule.monitor, enabled) {
+                Ok(()) => {
+                    applied.insert(rule.clone(), running);
+                }
+                // retried on the next poll
+                Err(e) => eprintln!("Failed to apply rule for {}: {e:?}", rule.process),
+            }
+        }
+
+        // the post-launch hook didn't run, e.g. because the game or the launcher crashed
+        if let Some(session) = read_value::<HookSession>(driver_ipc::HOOK_VALUE)? {
+            if process_started(session.launcher) != Some(session.launcher_started) {
+                match undo_hook(pipe_name, &session) {
+                    Ok(()) => println!(
+                        "Undid profile {} (launcher {} exited)",
+                        session.profile, session.launcher
+                    ),
+                    // retried on the next poll
+                    Err(e) => eprintln!("Failed to undo profile {}: {e:?}", session.profile),
+                }
+            }
+        }
+
+        started = true;
+        last_time = Some(time);
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn trigger(trigger: &Trigger) -> String {
+    match trigger {
+        Trigger::Login => "login".to_owned(),
+        Trigger::At { hour, minute } => format!("{hour:02}:{minute:02}"),
+        Trigger::Process(name) => format!("{name} started"),
+    }
+}
+
+/// Read a json list from a value of [`driver_ipc::SETTINGS_KEY`], which is empty if it's unset
+fn read_setting<T: DeserializeOwned>(value: &str) -> eyre::Result<Vec<T>> {
+    Ok(read_value(value)?.unwrap_or_default())
+}
+
+/// Read json from a value of [`driver_ipc::SETTINGS_KEY`], `None` if it's unset
+fn read_value<T: DeserializeOwned>(value: &str) -> eyre::Result<Option<T>> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let Ok(settings) = hkcu.open_subkey(driver_ipc::SETTINGS_KEY) else {
+        return Ok(None);
+    };
+    let Ok(data) = settings.get_value::<String, _>(value) else {
+        return Ok(None);
+    };
+
+    serde_json::from_str(&data).wrap_err_with(|| format!("invalid `{value}` in the registry"))
+}
+
+fn unload(pipe_name: &str, ids: &[Id]) -> eyre::Result<()> {
+    let mut connection = driver::connect(pipe_name)?;
+
+    // some may have been removed by hand in the meantime
+    let removed = connection
+        .list()?
+        .into_iter()
+        .map(|monitor| monitor.id)
+        .filter(|id| ids.contains(id))
+        .map(Into::into)
+        .collect::<Vec<_>>();
+
+    if !removed.is_empty() {
+        connection.send(&Command::DriverRemove(removed))?;
+    }
+
+    Ok(())
+}
+
+/// Restore the monitors a hook session changed, and forget the session
+fn undo_hook(pipe_name: &str, session: &HookSession) -> eyre::Result<()> {
+    if !session.previous.is_empty() {
+        driver::connect(pipe_name)?.send(&Command::DriverNotify(session.previous.clone()))?;
+    }
+    unload(pipe_name, &session.added)?;
+
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    hkcu.open_subkey_with_flags(driver_ipc::SETTINGS_KEY, KEY_WRITE)
+        .and_then(|settings| settings.delete_value(driver_ipc::HOOK_VALUE))
+        .wrap_err("failed to delete the hook session from the registry")
+}
+
+/// Enable or disable the monitor with the ID or name `query`. A missing monitor isn't an error,
+/// it may only be added later
+fn set_enabled(pipe_name: &str, query: &str, enabled: bool) -> eyre::Result<()> {
+    let mut connection = driver::connect(pipe_name)?;
+    let monitors = connection.list()?;
+
+    let Some(monitor) = driver::find(&monitors, query) else {
+        eprintln!("Rule for monitor {query} skipped, it doesn't exist");
+        return Ok(());
+    };
+
+    if monitor.enabled != enabled {
+        println!(
+            "{} monitor {query}",
+            if enabled { "Enabling" } else { "Disabling" }
+        );
+
+        connection.send(&Command::DriverNotify(vec![Monitor {
+            enabled,
+            ..monitor.clone()
+        }]))?;
+    }
+
+    Ok(())
+}
+
+/// Hour and minute of the local time
+fn local_time() -> (u8, u8) {
+    let time = unsafe { GetLocalTime() };
+
+    #[allow(clippy::cast_possible_truncation)]
+    (time.wHour as u8, time.wMinute as u8)
+}
+
+/// Lowercase executable names of the running processes, e.g. `sunshine.exe`
+fn running_processes() -> eyre::Result<HashSet<String>> {
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)? };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let mut entry = PROCESSENTRY32W {
+        dwSize: mem::size_of::<PROCESSENTRY32W>() as u32,
+        ..Default::default()
+    };
+
+    let mut processes = HashSet::new();
+    let mut more = unsafe { Process32FirstW(snapshot, &mut entry) }.is_ok();
+    while more {
+        let len = entry
+            .szExeFile
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(entry.szExeFile.len());
+        processes.insert(String::from_utf16_lossy(&entry.szExeFile[..len]).to_lowercase());
+
+        more = unsafe { Process32NextW(snapshot, &mut entry) }.is_ok();
+    }
+
+    unsafe { CloseHandle(snapshot)? };
+
+    Ok(processes)
+}
+
+/// Creation time of a running process, `None` if it exited
+fn process_started(pid: u32) -> Option<u64> {
+    let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }.ok()?;
+
+    let mut exit_code = 0;
+    let running = unsafe { GetExitCodeProcess(process, &mut exit_code) }.is_ok()
+        && i32::try_from(exit_code) == Ok(STILL_ACTIVE.0);
+
+    let [mut created, mut exited, mut kernel, mut user] = [FILETIME::default(); 4];
+    let timed =
+        unsafe { GetProcessTimes(process, &mut created, &mut exited, &mut kernel, &mut user) }
+            .is_ok();
+
+    _ = unsafe { CloseHandle(process) };
+
+    (running && timed)
+        .then(|| (u64::from(created.dwHighDateTime) << 32) | u64::from(created.dwLowDateTime))
+}
@@ -0,0 +1,78 @@
+use std::{slice, thread, time::Duration};
+
+use driver_ipc::{Command, Monitor, MonitorControl as _, SessionPolicy};
+use windows::Win32::System::RemoteDesktop::{
+    WTSActive, WTSEnumerateSessionsW, WTSFreeMemory, WTSGetActiveConsoleSessionId,
+    WTS_CURRENT_SERVER_HANDLE, WTS_SESSION_INFOW,
+};
+
+use crate::driver;
+
+// How often the session state is checked
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Enable or disable monitors with [`SessionPolicy::Remote`] whenever a remote desktop session
+/// connects, or the console takes over again
+///
+/// Only transitions are applied, so monitors can still be toggled by hand in between.
+pub fn watch(pipe_name: &str) -> eyre::Result<()> {
+    let mut applied = None;
+
+    loop {
+        let remote = remote_session_active()?;
+
+        if applied != Some(remote) {
+            match apply(pipe_name, remote) {
+                Ok(()) => applied = Some(remote),
+                // retried on the next poll
+                Err(e) => eprintln!("Failed to apply session policies: {e:?}"),
+            }
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn apply(pipe_name: &str, remote: bool) -> eyre::Result<()> {
+    let mut connection = driver::connect(pipe_name)?;
+
+    let changed = connection
+        .list()?
+        .into_iter()
+        .filter(|monitor| monitor.session == SessionPolicy::Remote && monitor.enabled != remote)
+        .map(|monitor| Monitor {
+            enabled: remote,
+            ..monitor
+        })
+        .collect::<Vec<_>>();
+
+    if !changed.is_empty() {
+        println!(
+            "{} {} monitors for the {} session",
+            if remote { "Enabling" } else { "Disabling" },
+            changed.len(),
+            if remote { "remote" } else { "console" },
+        );
+
+        connection.send(&Command::DriverNotify(changed))?;
+    }
+
+    Ok(())
+}
+
+/// Whether a session other than the console one is connected and active, e.g. over RDP
+fn remote_session_active() -> eyre::Result<bool> {
+    let console = unsafe { WTSGetActiveConsoleSessionId() };
+
+    let mut sessions = std::ptr::null_mut::<WTS_SESSION_INFOW>();
+    let mut count = 0u32;
+    unsafe { WTSEnumerateSessionsW(WTS_CURRENT_SERVER_HANDLE, 0, 1, &mut sessions, &mut count)? };
+
+    let remote = unsafe { slice::from_raw_parts(sessions, count as usize) }
+        .iter()
+        .any(|session| session.State == WTSActive && session.SessionId != console);
+
+    unsafe { WTSFreeMemory(sessions.cast()) };
+
+    Ok(remote)
+}
@@ -0,0 +1,223 @@
+use std::{
+    fs,
+    io::{BufRead as _, BufReader, Write as _},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    path::Path,
+};
+
+use driver_ipc::{Command, Monitor, MonitorControl as _};
+use serde_json::{json, Value};
+
+use crate::driver;
+
+struct HttpError(u16, String);
+
+impl From<eyre::Report> for HttpError {
+    fn from(e: eyre::Report) -> Self {
+        Self(502, format!("{e:#}"))
+    }
+}
+
+/// Serve the action API for Stream Deck plugins on `127.0.0.1:<port>` until the listener fails
+///
+/// - `GET /monitors` and `GET /monitors/<id or name>`: state of the monitors, to show on keys
+/// - `POST /monitors/<id or name>/toggle` (or `enable`, `disable`): returns the new state
+/// - `GET /profiles` and `POST /profiles/<name>/load`: `<name>.json` files in `profiles`
+pub fn serve(port: u16, profiles: Option<&Path>, pipe_name: &str) -> eyre::Result<()> {
+    let addr = SocketAddr::from((Ipv4Addr::LOCALHOST, port));
+    let listener = TcpListener::bind(addr)?;
+    println!("Serving Stream Deck actions at http://{addr}");
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+
+        if let Err(e) = handle(stream, profiles, pipe_name) {
+            eprintln!("Failed to handle Stream Deck request: {e:?}");
+        }
+    }
+
+    Ok(())
+}
+
+fn handle(mut stream: TcpStream, profiles: Option<&Path>, pipe_name: &str) -> eyre::Result<()> {
+    let mut reader = BufReader::new(&stream);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // browsers send an origin with cross-site requests, and websites mustn't control monitors.
+    // plugins run in node, which doesn't
+    let mut from_browser = false;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim().is_empty() {
+            break;
+        }
+        if let Some((name, _)) = header.split_once(':') {
+            from_browser |= name.eq_ignore_ascii_case("origin");
+        }
+    }
+
+    // e.g. `POST /monitors/1/toggle HTTP/1.1`
+    let mut parts = request_line.split_whitespace();
+    let (method, path) = (
+        parts.next().unwrap_or_default(),
+        parts.next().unwrap_or_default(),
+    );
+
+    let reply = if from_browser {
+        Err(HttpError(
+            403,
+            "requests from browsers aren't allowed".to_owned(),
+        ))
+    } else {
+        route(method, path, profiles, pipe_name)
+    };
+
+    let (status, body) = match reply {
+        Ok(body) => (200, body),
+        Err(HttpError(status, error)) => (status, json!({ "error": error })),
+    };
+    let reason = match status {
+        200 => "OK",
+        403 => "Forbidden",
+        404 => "Not Found",
+        _ => "Bad Gateway",
+    };
+    let body = body.to_string();
+
+    write!(
+        stream,
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+
+    Ok(())
+}
+
+fn route(
+    method: &str,
+    path: &str,
+    profiles: Option<&Path>,
+    pipe_name: &str,
+) -> Result<Value, HttpError> {
+    let segments = path
+        .trim_matches('/')
+        .split('/')
+        .map(decode)
+        .collect::<Vec<_>>();
+    let segments = segments.iter().map(String::as_str).collect::<Vec<_>>();
+
+    match (method, segments.as_slice()) {
+        ("GET", ["monitors"]) => {
+            let monitors = driver::connect(pipe_name)?.list()?;
+            Ok(monitors.iter().map(key).collect())
+        }
+
+        ("GET", ["monitors", query]) => {
+            let monitors = driver::connect(pipe_name)?.list()?;
+            let monitor = driver::find(&monitors, query).ok_or_else(|| not_found(query))?;
+            Ok(key(monitor))
+        }
+
+        ("POST", ["monitors", query, action @ ("toggle" | "enable" | "disable")]) => {
+            let mut connection = driver::connect(pipe_name)?;
+            let monitors = connection.list()?;
+            let monitor = driver::find(&monitors, query).ok_or_else(|| not_found(query))?;
+
+            let monitor = Monitor {
+                enabled: match *action {
+                    "enable" => true,
+                    "disable" => false,
+                    _ => !monitor.enabled,
+                },
+                ..monitor.clone()
+            };
+            connection.send(&Command::DriverNotify(vec![monitor.clone()]))?;
+
+            Ok(key(&monitor))
+        }
+
+        ("GET", ["profiles"]) => {
+            let Some(profiles) = profiles else {
+                return Ok(json!([]));
+            };
+
+            let mut names = fs::read_dir(profiles)
+                .map_err(eyre::Report::from)?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == "json")
+                })
+                .filter_map(|path| Some(path.file_stem()?.to_str()?.to_owned()))
+                .collect::<Vec<_>>();
+            names.sort();
+
+            Ok(json!(names))
+        }
+
+        ("POST", ["profiles", name, "load"]) => {
+            // names can't leave the profiles directory
+            let path = profiles
+                .filter(|_| !name.contains(['/', '\\']) && !name.starts_with('.'))
+                .map(|profiles| profiles.join(format!("{name}.json")))
+                .filter(|path| path.is_file())
+                .ok_or_else(|| HttpError(404, format!("no profile `{name}`")))?;
+
+            driver::load_profile(pipe_name, &path, true)?;
+            println!("Loaded profile {name} from the Stream Deck");
+
+            Ok(json!({ "profile": name }))
+        }
+
+        _ => Err(HttpError(404, format!("no action {method} {path}"))),
+    }
+}
+
+fn not_found(query: &str) -> HttpError {
+    HttpError(404, format!("no monitor `{query}`"))
+}
+
+/// State of a monitor for a key. `state` is the index of the key's state in the plugin
+/// manifest, 1 while the monitor is enabled
+fn key(monitor: &Monitor) -> Value {
+    let name = monitor
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("Monitor {}", monitor.id));
+
+    json!({
+        "id": monitor.id,
+        "name": name,
+        "enabled": monitor.enabled,
+        "state": u8::from(monitor.enabled),
+        "title": format!("{name}\n{}", if monitor.enabled { "ON" } else { "OFF" }),
+    })
+}
+
+// percent-decode a path segment, e.g. monitor names with spaces
+fn decode(segment: &str) -> String {
+    let mut bytes = Vec::with_capacity(segment.len());
+    let mut rest = segment.as_bytes();
+
+    while let Some((&byte, tail)) = rest.split_first() {
+        let escaped = (byte == b'%')
+            .then(|| tail.get(..2))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(std::str::from_utf8(hex).ok()?, 16).ok());
+
+        if let Some(escaped) = escaped {
+            bytes.push(escaped);
+            rest = &tail[2..];
+        } else {
+            bytes.push(byte);
+            rest = tail;
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
@@ -0,0 +1,195 @@
+use std::time::Duration;
+
+use driver_ipc::{Lut, SharedFrame};
+use eyre::Context as _;
+use jpeg_encoder::{ColorType, Encoder};
+use windows::{
+    core::{Interface, HSTRING},
+    Win32::{
+        Foundation::S_OK,
+        Graphics::{
+            Direct3D::D3D_DRIVER_TYPE_HARDWARE,
+            Direct3D11::{
+                D3D11CreateDevice, ID3D11Device1, ID3D11DeviceContext, ID3D11Texture2D,
+                D3D11_CPU_ACCESS_READ, D3D11_CREATE_DEVICE_BGRA_SUPPORT, D3D11_MAPPED_SUBRESOURCE,
+                D3D11_MAP_READ, D3D11_SDK_VERSION, D3D11_TEXTURE2D_DESC, D3D11_USAGE_STAGING,
+            },
+            Dxgi::{
+                Common::{DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_SAMPLE_DESC},
+                IDXGIKeyedMutex, DXGI_SHARED_RESOURCE_READ, DXGI_SHARED_RESOURCE_WRITE,
+            },
+        },
+    },
+};
+
+// Keys of the shared texture's keyed mutex, see `SharedFrame`
+const KEY_DRIVER: u64 = 0;
+const KEY_CONSUMER: u64 = 1;
+
+/// Reads the frames of a shared texture back from the gpu, and compresses them to jpeg
+pub struct Capture {
+    frame: SharedFrame,
+    context: ID3D11DeviceContext,
+    shared: ID3D11Texture2D,
+    mutex: IDXGIKeyedMutex,
+    staging: ID3D11Texture2D,
+    // tightly packed pixels, the mapped staging texture may have padded rows
+    pixels: Vec<u8>,
+}
+
+impl Capture {
+    pub fn open(frame: SharedFrame) -> eyre::Result<Self> {
+        // desktop frames are bgra, anything else (e.g. hdr) would need a conversion pass first
+        if u32::try_from(DXGI_FORMAT_B8G8R8A8_UNORM.0) != Ok(frame.format) {
+            eyre::bail!("unsupported frame format {}", frame.format);
+        }
+
+        let (device, context) = create_device()?;
+
+        // the texture can only be opened on the gpu the driver renders on, which is the default
+        // one unless the render adapter was changed
+        let shared = unsafe {
+            device
+                .OpenSharedResourceByName::<_, ID3D11Texture2D>(
+                    &HSTRING::from(&frame.name),
+                    DXGI_SHARED_RESOURCE_READ | DXGI_SHARED_RESOURCE_WRITE,
+                )
+                .wrap_err("failed to open the shared frame")?
+        };
+        let mutex = shared.cast::<IDXGIKeyedMutex>()?;
+
+        #[allow(clippy::cast_sign_loss)]
+        let desc = D3D11_TEXTURE2D_DESC {
+            Width: frame.width,
+            Height: frame.height,
+            MipLevels: 1,
+            ArraySize: 1,
+            Format: DXGI_FORMAT_B8G8R8A8_UNORM,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: 1,
+                Quality: 0,
+            },
+            Usage: D3D11_USAGE_STAGING,
+            BindFlags: 0,
+            CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
+            MiscFlags: 0,
+        };
+
+        let mut staging = None;
+        unsafe { device.CreateTexture2D(&desc, None, Some(&mut staging))? };
+        let staging = staging.ok_or_else(|| eyre::eyre!("no staging texture"))?;
+
+        Ok(Self {
+            pixels: Vec::with_capacity(frame.width as usize * frame.height as usize * 4),
+            frame,
+            context,
+            shared,
+            mutex,
+            staging,
+        })
+    }
+
+    pub fn frame(&self) -> &SharedFrame {
+        &self.frame
+    }
+
+    /// Wait up to `timeout` for the next frame, correct its colors with `lut`, and compress it
+    ///
+    /// Returns `None` if no frame arrived
+    pub fn next_jpeg(
+        &mut self,
+        timeout: Duration,
+        quality: u8,
+        lut: Option<&Lut>,
+    ) -> eyre::Result<Option<Vec<u8>>> {
+        let timeout = u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX);
+
+        // a timeout is a success code, which the wrapper would turn into `Ok`
+        let hr = unsafe {
+            (Interface::vtable(&self.mutex).AcquireSync)(
+                Interface::as_raw(&self.mutex),
+                KEY_CONSUMER,
+                timeout,
+            )
+        };
+        if hr != S_OK {
+            hr.ok()?;
+            return Ok(None);
+        }
+
+        // hand the texture back to the driver before the slow part
+        unsafe { self.context.CopyResource(&self.staging, &self.shared) };
+        unsafe { self.mutex.ReleaseSync(KEY_DRIVER)? };
+
+        self.read_staging()?;
+
+        if let Some(lut) = lut {
+            for pixel in self.pixels.chunks_exact_mut(4) {
+                let [r, g, b] = lut.apply([pixel[2], pixel[1], pixel[0]]);
+                pixel[..3].copy_from_slice(&[b, g, r]);
+            }
+        }
+
+        let width = u16::try_from(self.frame.width)?;
+        let height = u16::try_from(self.frame.height)?;
+
+        let mut jpeg = Vec::new();
+        Encoder::new(&mut jpeg, quality).encode(&self.pixels, width, height, ColorType::Bgra)?;
+
+        Ok(Some(jpeg))
+    }
+
+    fn read_staging(&mut self) -> eyre::Result<()> {
+        let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+        // waits for the copy to finish
+        unsafe {
+            self.context
+                .Map(&self.staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped))?;
+        }
+
+        let row = self.frame.width as usize * 4;
+        let pitch = mapped.RowPitch as usize;
+        let height = self.frame.height as usize;
+
+        // SAFETY: rows of a mapped 2d texture start `RowPitch` bytes apart, the last one isn't
+        // padded
+        let data = unsafe {
+            std::slice::from_raw_parts(mapped.pData.cast::<u8>(), pitch * (height - 1) + row)
+        };
+
+        self.pixels.clear();
+        for y in 0..height {
+            self.pixels
+                .extend_from_slice(&data[y * pitch..y * pitch + row]);
+        }
+
+        unsafe { self.context.Unmap(&self.staging, 0) };
+
+        Ok(())
+    }
+}
+
+fn create_device() -> eyre::Result<(ID3D11Device1, ID3D11DeviceContext)> {
+    let mut device = None;
+    let mut context = None;
+
+    unsafe {
+        D3D11CreateDevice(
+            None,
+            D3D_DRIVER_TYPE_HARDWARE,
+            None,
+            D3D11_CREATE_DEVICE_BGRA_SUPPORT,
+            None,
+            D3D11_SDK_VERSION,
+            Some(&mut device),
+            None,
+            Some(&mut context),
+        )
+        .wrap_err("failed to create d3d11 device")?;
+    }
+
+    let device = device.ok_or_else(|| eyre::eyre!("no d3d11 device"))?;
+    let context = context.ok_or_else(|| eyre::eyre!("no d3d11 device context"))?;
+
+    Ok((device.cast()?, context))
+}
@@ -0,0 +1,30 @@
+use driver_ipc::{Client, Command, Id, Picture, SharedFrame};
+use eyre::Context as _;
+
+/// The texture a monitor's frames are shared in, if it has one
+pub fn shared_frame(pipe_name: &str, id: Id) -> eyre::Result<Option<SharedFrame>> {
+    let Command::ReplySharedFrame(frame) =
+        request(pipe_name, &Command::RequestSharedFrame(id.into()))?
+    else {
+        eyre::bail!("received unexpected reply from driver pipe");
+    };
+
+    Ok(frame)
+}
+
+/// The picture settings of a monitor, of which the lookup table is applied to its frames
+pub fn picture(pipe_name: &str, id: Id) -> eyre::Result<Picture> {
+    let Command::ReplyPicture(picture) = request(pipe_name, &Command::RequestPicture(id.into()))?
+    else {
+        eyre::bail!("received unexpected reply from driver pipe");
+    };
+
+    Ok(picture)
+}
+
+// Connects only for this request, since the driver pipe only accepts a single client at a time
+fn request(pipe_name: &str, command: &Command) -> eyre::Result<Command> {
+    let mut client = Client::connect(pipe_name).context("Failed to connect to the driver")?;
+
+    Ok(client.request(command)?)
+}
@@ -0,0 +1,131 @@
+use std::{collections::HashMap, io::Write as _};
+
+use clap::Parser;
+use driver_ipc::{Id, StreamCommand, StreamSettings};
+use eyre::Context as _;
+
+use crate::{capture::Capture, stream::Stream};
+
+mod capture;
+mod driver;
+mod stream;
+
+/// Serves virtual monitors over http, so a browser (e.g. on a tablet) can
+/// show them as a second display.
+///
+/// Streams are started and stopped with `virtual-display-driver-cli stream`.
+#[derive(Debug, Parser)]
+struct Args {
+    /// Driver instance to talk to, by pipe name. Defaults to the stock
+    /// driver instance.
+    #[clap(long, default_value = driver_ipc::DEFAULT_PIPE_NAME)]
+    instance: String,
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    let server = win_pipes::NamedPipeServerOptions::new(driver_ipc::STREAM_PIPE_NAME)
+        .reject_remote()
+        .read_message()
+        .write_message()
+        .access_duplex()
+        .first_pipe_instance()
+        .max_instances(1)
+        .wait()
+        .create()
+        .wrap_err("failed to create the stream pipe, is vdd-stream already running?")?;
+
+    let mut streams = HashMap::<Id, Stream>::new();
+
+    for client in server.incoming() {
+        let Ok((reader, mut writer)) = client else {
+            continue;
+        };
+
+        for data in reader.iter_read_full() {
+            let Ok(command) = serde_json::from_slice::<StreamCommand>(&data) else {
+                _ = server.disconnect();
+                continue;
+            };
+
+            let reply = handle(command, &mut streams, &args.instance)
+                .unwrap_or_else(|e| StreamCommand::ReplyError(format!("{e:#}")));
+
+            let message = serde_json::to_vec(&reply)?;
+            _ = writer.write_all(&message);
+        }
+    }
+
+    Ok(())
+}
+
+fn handle(
+    command: StreamCommand,
+    streams: &mut HashMap<Id, Stream>,
+    pipe_name: &str,
+) -> eyre::Result<StreamCommand> {
+    let reply = match command {
+        StreamCommand::RequestStart(settings) => {
+            validate(&settings)?;
+
+            if let Some(stream) = streams.remove(&settings.id) {
+                stream.stop()?;
+            }
+
+            let id = settings.id;
+            let frame = driver::shared_frame(pipe_name, id)?.ok_or_else(|| {
+                eyre::eyre!("monitor {id} has no shared frame, add it with `--gpu-export`")
+            })?;
+
+            let capture = Capture::open(frame)?;
+            streams.insert(id, Stream::start(settings, capture, pipe_name)?);
+            StreamCommand::ReplyOk
+        }
+
+        StreamCommand::RequestStop(id) => {
+            let stream = streams
+                .remove(&id)
+                .ok_or_else(|| eyre::eyre!("monitor {id} isn't streamed"))?;
+            stream.stop()?;
+            StreamCommand::ReplyOk
+        }
+
+        StreamCommand::RequestStatus => {
+            let mut streams = streams.values().map(Stream::info).collect::<Vec<_>>();
+            streams.sort_by_key(|info| info.settings.id);
+            StreamCommand::ReplyStatus(streams)
+        }
+
+        StreamCommand::ReplyOk | StreamCommand::ReplyError(_) | StreamCommand::ReplyStatus(_) => {
+            eyre::bail!("unexpected command");
+        }
+    };
+
+    Ok(reply)
+}
+
+fn validate(settings: &StreamSettings) -> eyre::Result<()> {
+    // tokens are put into urls and html as is
+    if settings.token.is_empty()
+        || !settings
+            .token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        eyre::bail!("tokens may only contain letters, digits, `-` and `_`");
+    }
+
+    if settings.token.len() < driver_ipc::MIN_STREAM_TOKEN_LEN {
+        eyre::bail!(
+            "tokens need to be at least {} characters long",
+            driver_ipc::MIN_STREAM_TOKEN_LEN
+        );
+    }
+
+    if !(1..=100).contains(&settings.quality) {
+        eyre::bail!("quality must be between 1 and 100");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,320 @@
+use std::{
+    io::{BufRead as _, BufReader, Write as _},
+    net::{Ipv4Addr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicBool, AtomicU32, Ordering},
+        Arc, Condvar, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{StreamInfo, StreamSettings};
+
+use crate::{capture::Capture, driver};
+
+// How long to wait for a frame before checking whether to stop. Monitors only produce frames
+// when something on them changes
+const FRAME_TIMEOUT: Duration = Duration::from_millis(100);
+
+// How long a monitor has to be idle before checking whether its shared texture was re-created
+// under a new name
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+// How often to check whether the monitor's lookup table changed, while it's being served
+const LUT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+// Clients that don't send their request in time are dropped
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+// Separates the frames of the mjpeg stream
+const BOUNDARY: &str = "vdd-frame";
+
+/// A monitor served over http, as mjpeg
+///
+/// Frames are captured and compressed once, on their own thread, and handed to every client.
+pub struct Stream {
+    settings: StreamSettings,
+    state: Arc<State>,
+    capture: JoinHandle<()>,
+    listener: JoinHandle<()>,
+}
+
+#[derive(Default)]
+struct State {
+    stop: AtomicBool,
+    clients: AtomicU32,
+    latest: Mutex<Latest>,
+    new_frame: Condvar,
+    error: Mutex<Option<String>>,
+}
+
+#[derive(Default)]
+struct Latest {
+    sequence: u64,
+    jpeg: Option<Arc<Vec<u8>>>,
+}
+
+impl State {
+    fn fail(&self, e: &eyre::Report) {
+        eprintln!("Streaming failed: {e:?}");
+        if let Ok(mut error) = self.error.lock() {
+            *error = Some(format!("{e:#}"));
+        }
+    }
+}
+
+impl Stream {
+    pub fn start(
+        settings: StreamSettings,
+        capture: Capture,
+        pipe_name: &str,
+    ) -> eyre::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, settings.port))?;
+        println!(
+            "Serving monitor {} at http://0.0.0.0:{}/",
+            settings.id, settings.port
+        );
+
+        let state = Arc::new(State::default());
+
+        let capture = thread::spawn({
+            let state = state.clone();
+            let id = settings.id;
+            let quality = settings.quality;
+            let pipe_name = pipe_name.to_owned();
+
+            move || {
+                if let Err(e) = run_capture(capture, id, quality, &pipe_name, &state) {
+                    state.fail(&e);
+                }
+                // wake up clients, so they notice
+                state.new_frame.notify_all();
+            }
+        });
+
+        let listener = thread::spawn({
+            let state = state.clone();
+            let token = settings.token.clone();
+
+            move || serve(&listener, &token, &state)
+        });
+
+        Ok(Self {
+            settings,
+            state,
+            capture,
+            listener,
+        })
+    }
+
+    pub fn info(&self) -> StreamInfo {
+        StreamInfo {
+            settings: self.settings.clone(),
+            clients: self.state.clients.load(Ordering::Relaxed),
+            error: self.state.error.lock().ok().and_then(|e| e.clone()),
+        }
+    }
+
+    pub fn stop(self) -> eyre::Result<()> {
+        self.state.stop.store(true, Ordering::Relaxed);
+        self.state.new_frame.notify_all();
+
+        // wake up the listener, it's blocked on accepting the next client
+        _ = TcpStream::connect((Ipv4Addr::LOCALHOST, self.settings.port));
+
+        self.capture
+            .join()
+            .map_err(|_| eyre::eyre!("capture thread panicked"))?;
+        self.listener
+            .join()
+            .map_err(|_| eyre::eyre!("listener thread panicked"))
+    }
+}
+
+fn run_capture(
+    mut capture: Capture,
+    id: driver_ipc::Id,
+    quality: u8,
+    pipe_name: &str,
+    state: &State,
+) -> eyre::Result<()> {
+    let mut idle_since = Instant::now();
+    let mut lut = None;
+    let mut lut_checked = None::<Instant>;
+
+    while !state.stop.load(Ordering::Relaxed) {
+        // nobody is watching, so don't spend time on compressing frames
+        if state.clients.load(Ordering::Relaxed) == 0 {
+            thread::sleep(FRAME_TIMEOUT);
+            continue;
+        }
+
+        // the driver may be busy with another client, then the last one is kept for now
+        if lut_checked.map_or(true, |checked| checked.elapsed() >= LUT_CHECK_INTERVAL) {
+            lut_checked = Some(Instant::now());
+            if let Ok(picture) = driver::picture(pipe_name, id) {
+                lut = picture.lut;
+            }
+        }
+
+        if let Some(jpeg) = capture.next_jpeg(FRAME_TIMEOUT, quality, lut.as_ref())? {
+            let mut latest = state
+                .latest
+                .lock()
+                .map_err(|_| eyre::eyre!("frame lock poisoned"))?;
+            latest.sequence += 1;
+            latest.jpeg = Some(Arc::new(jpeg));
+            drop(latest);
+
+            state.new_frame.notify_all();
+            idle_since = Instant::now();
+            continue;
+        }
+
+        // idle monitors are normal, so the driver is only asked now and then whether the texture
+        // was replaced, the pipe only takes one client at a time
+        if idle_since.elapsed() < IDLE_CHECK_INTERVAL {
+            continue;
+        }
+        idle_since = Instant::now();
+
+        // the swap chain may be gone for now, e.g. while the monitor is off, and the driver may be
+        // busy with another client, both are checked again later
+        if let Ok(Some(current)) = driver::shared_frame(pipe_name, id) {
+            if current != *capture.frame() {
+                capture = Capture::open(current)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn serve(listener: &TcpListener, token: &str, state: &Arc<State>) {
+    for stream in listener.incoming() {
+        if state.stop.load(Ordering::Relaxed) {
+            break;
+        }
+
+        let Ok(stream) = stream else {
+            continue;
+        };
+
+        let token = token.to_owned();
+        let state = state.clone();
+        thread::spawn(move || {
+            // clients going away is normal
+            _ = handle(stream, &token, &state);
+        });
+    }
+}
+
+/// Whether `given` is `token`, in the same time wherever they differ, so it can't be guessed one
+/// character at a time by timing the replies
+fn token_matches(given: &str, token: &str) -> bool {
+    given.len() == token.len()
+        && given
+            .bytes()
+            .zip(token.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn handle(mut stream: TcpStream, token: &str, state: &State) -> eyre::Result<()> {
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+
+    // e.g. `GET /stream?token=... HTTP/1.1`
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = (parts.next(), parts.next().unwrap_or_default());
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    let authorized = query
+        .split('&')
+        .filter_map(|param| param.strip_prefix("token="))
+        .any(|given| token_matches(given, token));
+
+    match (method, path) {
+        (Some("GET"), _) if !authorized => {
+            stream.write_all(
+                b"HTTP/1.1 401 Unauthorized\r\nContent-Type: text/plain\r\nContent-Length: 14\r\n\r\nmissing token\n",
+            )?;
+        }
+
+        (Some("GET"), "/") => {
+            // tokens are checked to be url and html safe when a stream is started
+            let body = format!(
+                r#"<!DOCTYPE html>
+<html>
+<head>
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Virtual Display</title>
+<style>html, body {{ margin: 0; height: 100%; background: #000; }} img {{ width: 100%; height: 100%; object-fit: contain; }}</style>
+</head>
+<body><img src="/stream?token={token}"></body>
+</html>
+"#
+            );
+
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            )?;
+        }
+
+        (Some("GET"), "/stream") => stream_frames(stream, state)?,
+
+        _ => {
+            stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n")?;
+        }
+    }
+
+    Ok(())
+}
+
+fn stream_frames(mut stream: TcpStream, state: &State) -> eyre::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: multipart/x-mixed-replace; boundary={BOUNDARY}\r\nCache-Control: no-cache\r\n\r\n"
+    )?;
+
+    state.clients.fetch_add(1, Ordering::Relaxed);
+    let result = (|| {
+        let mut sent = 0;
+
+        while !state.stop.load(Ordering::Relaxed) {
+            let latest = state
+                .latest
+                .lock()
+                .map_err(|_| eyre::eyre!("frame lock poisoned"))?;
+            let (latest, _) = state
+                .new_frame
+                .wait_timeout_while(latest, FRAME_TIMEOUT, |latest| latest.sequence == sent)
+                .map_err(|_| eyre::eyre!("frame lock poisoned"))?;
+
+            let Some(jpeg) = latest.jpeg.clone().filter(|_| latest.sequence != sent) else {
+                continue;
+            };
+            sent = latest.sequence;
+            drop(latest);
+
+            // slow clients skip frames, they always get the newest one
+            write!(
+                stream,
+                "--{BOUNDARY}\r\nContent-Type: image/jpeg\r\nContent-Length: {}\r\n\r\n",
+                jpeg.len()
+            )?;
+            stream.write_all(&jpeg)?;
+            stream.write_all(b"\r\n")?;
+        }
+
+        eyre::Ok(())
+    })();
+    state.clients.fetch_sub(1, Ordering::Relaxed);
+
+    result
+}
@@ -0,0 +1,88 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// Payload sizes (in bytes) used when the user doesn't specify any.
+pub const DEFAULT_PAYLOAD_SIZES: &[usize] = &[0, 1024, 16 * 1024, 64 * 1024];
+
+/// Number of unmeasured round trips done before each measurement, so the
+/// pipe and allocator are warmed up.
+const WARMUP_ITERATIONS: usize = 10;
+
+/// Result of benchmarking the driver pipe with a single payload size.
+/// Latencies are round-trip times in microseconds.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct BenchResult {
+    pub payload_size: usize,
+    pub iterations: usize,
+    pub p50_us: f64,
+    pub p95_us: f64,
+    pub p99_us: f64,
+    pub max_us: f64,
+    pub messages_per_sec: f64,
+    pub bytes_per_sec: f64,
+}
+
+/// Measure `iterations` echo round trips of a payload of `payload_size`
+/// bytes. Returns an error if the driver replies with a different payload.
+pub fn run(
+    client: &mut Client,
+    payload_size: usize,
+    iterations: usize,
+) -> eyre::Result<BenchResult> {
+    eyre::ensure!(iterations > 0, "iterations must be greater than 0");
+
+    #[allow(clippy::cast_possible_truncation)]
+    let payload = (0..payload_size).map(|i| i as u8).collect::<Vec<_>>();
+
+    for _ in 0..WARMUP_ITERATIONS {
+        client.echo(payload.clone())?;
+    }
+
+    let mut latencies = Vec::with_capacity(iterations);
+    let start = Instant::now();
+    for _ in 0..iterations {
+        let sent = Instant::now();
+        let reply = client.echo(payload.clone())?;
+        latencies.push(sent.elapsed());
+
+        eyre::ensure!(reply == payload, "driver echoed back a different payload");
+    }
+    let total = start.elapsed().as_secs_f64();
+
+    latencies.sort_unstable();
+
+    #[allow(clippy::cast_precision_loss)]
+    let messages_per_sec = iterations as f64 / total;
+    #[allow(clippy::cast_precision_loss)]
+    let bytes_per_sec = messages_per_sec * payload_size as f64;
+
+    Ok(BenchResult {
+        payload_size,
+        iterations,
+        p50_us: micros(percentile(&latencies, 50.0)),
+        p95_us: micros(percentile(&latencies, 95.0)),
+        p99_us: micros(percentile(&latencies, 99.0)),
+        max_us: micros(latencies[latencies.len() - 1]),
+        messages_per_sec,
+        bytes_per_sec,
+    })
+}
+
+/// Nearest-rank percentile of an already sorted, non-empty list of samples.
+fn percentile(sorted: &[Duration], percentile: f64) -> Duration {
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    let rank = (percentile / 100.0 * sorted.len() as f64).ceil() as usize;
+
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
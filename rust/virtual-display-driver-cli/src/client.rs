@@ -1,70 +1,238 @@
-use std::{collections::HashSet, io::Write as _};
+use std::{collections::HashSet, fs, path::Path};
 
-use driver_ipc::Monitor;
+#[cfg(windows)]
+use driver_ipc::Instance;
+use driver_ipc::{Monitor, MonitorControl};
 use eyre::Context as _;
-use win_pipes::{NamedPipeClientReader, NamedPipeClientWriter};
 
 pub struct Client {
-    writer: NamedPipeClientWriter,
-    state: Vec<Monitor>,
+    client: driver_ipc::Client,
+    // all monitors, only requested once something needs them
+    state: Option<Vec<Monitor>>,
+}
+
+#[cfg(windows)]
+fn find_instance<'a>(instances: &'a [Instance], query: &str) -> Option<&'a Instance> {
+    let by_index = query
+        .parse::<usize>()
+        .ok()
+        .and_then(|index| instances.get(index));
+    let by_name = || {
+        instances
+            .iter()
+            .find(|instance| instance.pipe_name.eq_ignore_ascii_case(query))
+    };
+
+    by_index.or_else(by_name)
+}
+
+/// Resolve the pipe name of a driver instance, given by index or pipe name as
+/// listed by `instances`. Without one, the default instance is used.
+#[cfg(windows)]
+pub fn resolve_instance(query: Option<&str>) -> eyre::Result<String> {
+    let Some(query) = query else {
+        return Ok(driver_ipc::DEFAULT_PIPE_NAME.to_owned());
+    };
+
+    let instances = driver_ipc::discover().context("Failed to discover driver instances")?;
+
+    find_instance(&instances, query)
+        .map(|instance| instance.pipe_name.clone())
+        .ok_or_else(|| eyre::eyre!("driver instance {query} not found"))
+}
+
+/// Resolve the device interface path of a driver instance, like
+/// [`resolve_instance`].
+#[cfg(windows)]
+pub fn resolve_interface(query: Option<&str>) -> eyre::Result<String> {
+    let instances = driver_ipc::discover().context("Failed to discover driver instances")?;
+
+    let instance = match query {
+        Some(query) => find_instance(&instances, query),
+        None => instances
+            .iter()
+            .find(|instance| instance.pipe_name == driver_ipc::DEFAULT_PIPE_NAME),
+    };
+
+    instance
+        .map(|instance| instance.interface_path.clone())
+        .ok_or_else(|| eyre::eyre!("driver instance {} not found", query.unwrap_or("(default)")))
 }
 
 impl Client {
-    pub fn connect() -> eyre::Result<Self> {
-        let (mut reader, mut writer) =
-            win_pipes::NamedPipeClientOptions::new("virtualdisplaydriver")
-                .wait()
-                .access_duplex()
-                .mode_message()
-                .create()
-                .context("Failed to connect to Virtual Display Driver; please ensure the driver is installed and working. Other program using the driver must also be closed, such as the Virtual Display Driver Control app.")?;
+    #[cfg(windows)]
+    pub fn connect(pipe_name: &str) -> eyre::Result<Self> {
+        let client = driver_ipc::Client::connect(pipe_name)
+            .context("Failed to connect to Virtual Display Driver; please ensure the driver is installed and working. Other program using the driver must also be closed, such as the Virtual Display Driver Control app.")?;
+
+        Ok(Self::with_client(client))
+    }
+
+    /// Connect over device control requests on the adapter's device interface
+    /// instead of the pipe, for systems where the driver can't create one.
+    #[cfg(windows)]
+    pub fn connect_ioctl(interface_path: &str) -> eyre::Result<Self> {
+        let client = driver_ipc::Client::connect_ioctl(interface_path)
+            .context("Failed to open the Virtual Display Driver device; please ensure the driver is installed and working.")?;
+
+        Ok(Self::with_client(client))
+    }
 
-        send_command(&mut writer, &driver_ipc::Command::RequestState)?;
-        let state = receive_command(&mut reader)?;
-        let driver_ipc::Command::ReplyState(state) = state else {
-            eyre::bail!("received unexpected reply from driver pipe");
+    /// Connect to `vdd-emulator --listen` at `addr`.
+    pub fn connect_tcp(addr: &str) -> eyre::Result<Self> {
+        let client = driver_ipc::Client::connect_tcp(addr)
+            .wrap_err_with(|| format!("Failed to connect to the emulator at {addr}"))?;
+
+        Ok(Self::with_client(client))
+    }
+
+    /// Connect to the driver of another machine through its `vdd-server
+    /// --agent`, at `host` with an optional port. Both sides prove they know
+    /// the key in `key_file`.
+    pub fn connect_remote(host: &str, key_file: &Path) -> eyre::Result<Self> {
+        let key = fs::read_to_string(key_file)
+            .wrap_err_with(|| format!("failed to read key file {}", key_file.display()))?;
+
+        let has_port = host
+            .rsplit_once(':')
+            .is_some_and(|(_, port)| port.parse::<u16>().is_ok());
+        let addr = if has_port {
+            host.to_owned()
+        } else {
+            format!("{host}:{}", driver_ipc::remote::DEFAULT_AGENT_PORT)
         };
 
-        Ok(Self { writer, state })
+        // the key file is text, so it can end with a line break
+        let (reader, writer) = driver_ipc::remote::connect(addr.as_str(), key.trim().as_bytes())
+            .wrap_err_with(|| format!("Failed to connect to the agent at {addr}"))?;
+
+        Ok(Self::with_client(driver_ipc::Client::remote(
+            reader, writer,
+        )))
+    }
+
+    fn with_client(client: driver_ipc::Client) -> Self {
+        Self {
+            client,
+            state: None,
+        }
     }
 
-    pub fn monitors(&self) -> &[Monitor] {
-        &self.state
+    /// All monitors, requested from the driver the first time they're
+    /// needed.
+    pub fn monitors(&mut self) -> eyre::Result<&[Monitor]> {
+        if self.state.is_none() {
+            let state = self.request_state()?;
+            self.state = Some(state);
+        }
+
+        Ok(self.state.as_deref().unwrap_or_default())
+    }
+
+    /// Get a single monitor, without requesting all of them.
+    pub fn monitor(&mut self, id: driver_ipc::Id) -> eyre::Result<Option<Monitor>> {
+        let command = driver_ipc::Command::RequestMonitor(id.into());
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyMonitor(monitor) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(monitor)
     }
 
-    /// Find a monitor by ID or name.
-    pub fn find_monitor(&self, query: &str) -> eyre::Result<Monitor> {
+    /// Find a monitor by ID, alias or name. Only aliases and names need all
+    /// monitors to be requested.
+    pub fn find_monitor(&mut self, query: &str) -> eyre::Result<Monitor> {
         let query_id: Option<driver_ipc::Id> = query.parse().ok();
         if let Some(query_id) = query_id {
-            let monitor_by_id = self.state.iter().find(|monitor| monitor.id == query_id);
-            if let Some(monitor) = monitor_by_id {
-                return Ok(monitor.clone());
+            if let Some(monitor) = self.monitor(query_id)? {
+                return Ok(monitor);
             }
         }
 
-        let monitor_by_name = self
-            .state
+        // aliases are unique, unlike names
+        let monitors = self.monitors()?;
+        let monitor = monitors
             .iter()
-            .find(|monitor| monitor.name.as_deref().is_some_and(|name| name == query));
-        if let Some(monitor) = monitor_by_name {
+            .find(|monitor| monitor.alias.as_deref() == Some(query))
+            .or_else(|| {
+                monitors
+                    .iter()
+                    .find(|monitor| monitor.name.as_deref().is_some_and(|name| name == query))
+            });
+        if let Some(monitor) = monitor {
             return Ok(monitor.clone());
         }
 
         eyre::bail!("virtual monitor with ID {} not found", query);
     }
 
-    pub fn notify(&mut self, monitors: Vec<driver_ipc::Monitor>) -> eyre::Result<()> {
-        let command = driver_ipc::Command::DriverNotify(monitors);
+    /// All monitors tagged `tag`, failing if there are none.
+    pub fn tagged(&mut self, tag: &str) -> eyre::Result<Vec<Monitor>> {
+        let monitors = self
+            .monitors()?
+            .iter()
+            .filter(|monitor| monitor.tags.iter().any(|t| t == tag))
+            .cloned()
+            .collect::<Vec<_>>();
+        if monitors.is_empty() {
+            eyre::bail!("no virtual monitors are tagged {tag:?}");
+        }
+
+        Ok(monitors)
+    }
 
-        send_command(&mut self.writer, &command)?;
+    /// Add or update monitors, returning what the driver did to apply each
+    /// of them.
+    pub fn notify(
+        &mut self,
+        monitors: Vec<driver_ipc::Monitor>,
+    ) -> eyre::Result<Vec<driver_ipc::MonitorDiff>> {
+        let command = driver_ipc::Command::RequestNotify(monitors);
 
-        Ok(())
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyNotify(diffs) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+        self.state = None;
+
+        Ok(diffs)
     }
 
     pub fn remove(&mut self, ids: Vec<driver_ipc::Id>) -> eyre::Result<()> {
-        let command = driver_ipc::Command::DriverRemove(ids);
+        let command = driver_ipc::Command::DriverRemove(ids.into_iter().map(Into::into).collect());
 
-        send_command(&mut self.writer, &command)?;
+        self.send(&command)?;
+        self.state = None;
+
+        Ok(())
+    }
+
+    /// Unplug a monitor and plug it in again, keeping its configuration.
+    pub fn replug(&mut self, id: driver_ipc::Id) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverReplug(id.into());
+
+        self.send(&command)?;
+
+        Ok(())
+    }
+
+    /// Give the driver instance's frame processing realtime GPU priority,
+    /// or take it away again.
+    pub fn set_realtime_gpu_priority(&mut self, enabled: bool) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverSetRealtimeGpuPriority(enabled);
+
+        self.send(&command)?;
+
+        Ok(())
+    }
+
+    /// Plug in a monitor that was added without being plugged in.
+    pub fn plug(&mut self, id: driver_ipc::Id) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverPlug(id.into());
+
+        self.send(&command)?;
 
         Ok(())
     }
@@ -72,14 +240,290 @@ impl Client {
     pub fn remove_all(&mut self) -> eyre::Result<()> {
         let command = driver_ipc::Command::DriverRemoveAll;
 
-        send_command(&mut self.writer, &command)?;
+        self.send(&command)?;
+        self.state = None;
+
+        Ok(())
+    }
+
+    /// Ask the driver for the EDID of a connected physical display, such as
+    /// `DISPLAY2`.
+    pub fn display_edid(&mut self, display: &str) -> eyre::Result<Vec<u8>> {
+        let command = driver_ipc::Command::RequestDisplayEdid(display.to_owned());
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyDisplayEdid(edid) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        edid.ok_or_else(|| {
+            eyre::eyre!("failed to read EDID of display {display}, please ensure it is connected")
+        })
+    }
+
+    /// Get the EDID a virtual monitor is presented to the os with, `None`
+    /// if it isn't plugged in.
+    pub fn edid(&mut self, id: driver_ipc::Id) -> eyre::Result<Option<Vec<u8>>> {
+        let command = driver_ipc::Command::RequestEdid(id.into());
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyEdid(edid) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(edid)
+    }
+
+    pub fn set_log_level(&mut self, level: driver_ipc::LogLevel) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverSetLogLevel(level);
+
+        self.send(&command)?;
 
         Ok(())
     }
 
+    /// Share a test pattern instead of a monitor's desktop, or the desktop
+    /// again with `None`.
+    pub fn set_test_pattern(
+        &mut self,
+        id: driver_ipc::Id,
+        pattern: Option<driver_ipc::TestPattern>,
+    ) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverSetTestPattern(id.into(), pattern);
+
+        self.send(&command)?;
+
+        Ok(())
+    }
+
+    /// Get the driver's buffered log records newer than `since`. The driver
+    /// limits how many records are sent at once, so this may need to be
+    /// called again until it returns nothing.
+    pub fn logs(&mut self, since: u64) -> eyre::Result<Vec<driver_ipc::LogRecord>> {
+        let command = driver_ipc::Command::RequestLogs(since);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyLogs(records) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(records)
+    }
+
+    /// Get frame statistics of the monitor with the given ID, or of all
+    /// monitors if `None`.
+    pub fn stats(&mut self, id: Option<driver_ipc::Id>) -> eyre::Result<Vec<driver_ipc::Stats>> {
+        let command = driver_ipc::Command::RequestStats(id.map(Into::into));
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyStats(stats) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(stats)
+    }
+
+    /// Get success/failure counts of the driver's `IddCx` callbacks.
+    pub fn callback_stats(&mut self) -> eyre::Result<Vec<driver_ipc::CallbackStats>> {
+        let command = driver_ipc::Command::RequestCallbackStats;
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyCallbackStats(stats) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(stats)
+    }
+
+    /// Get the texture a monitor's frames are currently shared in.
+    pub fn shared_frame(
+        &mut self,
+        id: driver_ipc::Id,
+    ) -> eyre::Result<Option<driver_ipc::SharedFrame>> {
+        let command = driver_ipc::Command::RequestSharedFrame(id.into());
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplySharedFrame(frame) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(frame)
+    }
+
+    /// Ask for a monitor's frames to be shared in `format`, and get the texture
+    /// they're currently shared in, which switches to it with the next frame.
+    pub fn shared_frame_as(
+        &mut self,
+        id: driver_ipc::Id,
+        format: driver_ipc::FrameFormat,
+    ) -> eyre::Result<Option<driver_ipc::SharedFrame>> {
+        let command = driver_ipc::Command::RequestSharedFrameAs(id.into(), format);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplySharedFrame(frame) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(frame)
+    }
+
+    /// Ask for a monitor's frames to be handed over with the given
+    /// synchronization, and get the texture they're currently shared in.
+    pub fn shared_frame_sync(
+        &mut self,
+        id: driver_ipc::Id,
+        options: driver_ipc::SyncOptions,
+    ) -> eyre::Result<Option<driver_ipc::SharedFrame>> {
+        let command = driver_ipc::Command::RequestSharedFrameSync(id.into(), options);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplySharedFrame(frame) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(frame)
+    }
+
+    /// Ask for a monitor's frames to be shared in a ring too, which several
+    /// consumers can read from at once, and get the ring if it's there yet.
+    pub fn shared_ring(
+        &mut self,
+        id: driver_ipc::Id,
+    ) -> eyre::Result<Option<driver_ipc::SharedRing>> {
+        let command = driver_ipc::Command::RequestSharedRing(id.into());
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplySharedRing(ring) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(ring)
+    }
+
+    /// Ask for an active monitor to be switched to another of its modes.
+    pub fn change_mode(
+        &mut self,
+        id: driver_ipc::Id,
+        mode: driver_ipc::ActiveMode,
+    ) -> eyre::Result<driver_ipc::ModeChange> {
+        let command = driver_ipc::Command::RequestModeChange(id.into(), mode);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyModeChange(change) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(change)
+    }
+
+    /// Get the brightness, contrast and gamma ramp set for a monitor.
+    pub fn picture(&mut self, id: driver_ipc::Id) -> eyre::Result<driver_ipc::Picture> {
+        let command = driver_ipc::Command::RequestPicture(id.into());
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyPicture(picture) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(picture)
+    }
+
+    /// Set the color lookup table of a monitor, or remove it with `None`.
+    pub fn set_lut(
+        &mut self,
+        id: driver_ipc::Id,
+        lut: Option<driver_ipc::Lut>,
+    ) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverSetLut(id.into(), lut);
+
+        self.send(&command)?;
+
+        Ok(())
+    }
+
+    /// Get the hardware cursor of a monitor, without its shape if that's
+    /// still the one with `shape_id`. `None` if it has no hardware cursor.
+    pub fn cursor(
+        &mut self,
+        id: driver_ipc::Id,
+        shape_id: u32,
+    ) -> eyre::Result<Option<driver_ipc::CursorState>> {
+        let command = driver_ipc::Command::RequestCursor(id.into(), shape_id);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyCursor(cursor) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(cursor)
+    }
+
+    /// Draw the hardware cursor of a monitor into its shared frames or not.
+    pub fn set_cursor_composite(
+        &mut self,
+        id: driver_ipc::Id,
+        composite: bool,
+    ) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverSetCursorComposite(id.into(), composite);
+
+        self.send(&command)?;
+
+        Ok(())
+    }
+
+    /// Choose what happens to the frames of a monitor while the consumer of its shared frames
+    /// falls behind.
+    pub fn set_backpressure(
+        &mut self,
+        id: driver_ipc::Id,
+        backpressure: driver_ipc::Backpressure,
+    ) -> eyre::Result<()> {
+        let command = driver_ipc::Command::DriverSetBackpressure(id.into(), backpressure);
+
+        self.send(&command)?;
+
+        Ok(())
+    }
+
+    /// Get the driver version and the optional features it can't use.
+    pub fn driver_info(&mut self) -> eyre::Result<driver_ipc::DriverInfo> {
+        let command = driver_ipc::Command::RequestDriverInfo;
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyDriverInfo(info) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(info)
+    }
+
+    /// Get the driver's buffered events newer than `since`, like [`Self::logs`].
+    pub fn events(&mut self, since: u64) -> eyre::Result<Vec<driver_ipc::Event>> {
+        let command = driver_ipc::Command::RequestEvents(since);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyEvents(events) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(events)
+    }
+
+    /// Send a payload to the driver and wait for it to be echoed back.
+    pub fn echo(&mut self, payload: Vec<u8>) -> eyre::Result<Vec<u8>> {
+        let command = driver_ipc::Command::RequestEcho(payload);
+
+        let reply = self.request(&command)?;
+        let driver_ipc::Command::ReplyEcho(payload) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(payload)
+    }
+
     pub fn new_id(&mut self, preferred_id: Option<driver_ipc::Id>) -> eyre::Result<driver_ipc::Id> {
         let existing_ids = self
-            .state
+            .monitors()?
             .iter()
             .map(|monitor| monitor.id)
             .collect::<HashSet<_>>();
@@ -99,28 +543,64 @@ impl Client {
             Ok(new_id)
         }
     }
-}
 
-fn send_command(
-    ipc_writer: &mut NamedPipeClientWriter,
-    command: &driver_ipc::Command,
-) -> eyre::Result<()> {
-    // Create a vector with the full message, then send it as a single
-    // write. This is required because the pipe is in message mode.
-    let message = serde_json::to_vec(command).wrap_err("failed to serialize command")?;
-    ipc_writer
-        .write_all(&message)
-        .wrap_err("failed to write to driver pipe")?;
-    ipc_writer.flush().wrap_err("failed to flush driver pipe")?;
-
-    Ok(())
+    /// Make sure the aliases of `monitors` are valid, and not used by other
+    /// monitors, which the driver would refuse.
+    pub fn check_aliases(&mut self, monitors: &[Monitor]) -> eyre::Result<()> {
+        match driver_ipc::alias_conflict(self.monitors()?, monitors) {
+            Some(problem) => Err(eyre::eyre!(problem)),
+            None => Ok(()),
+        }
+    }
+
+    fn request_state(&mut self) -> eyre::Result<Vec<Monitor>> {
+        let reply = self.request(&driver_ipc::Command::RequestState)?;
+        let driver_ipc::Command::ReplyState(state) = reply else {
+            eyre::bail!("received unexpected reply from driver");
+        };
+
+        Ok(state)
+    }
+
+    /// Send a command without a reply.
+    fn send(&mut self, command: &driver_ipc::Command) -> eyre::Result<()> {
+        self.client
+            .send(command)
+            .wrap_err("failed to send command to driver")
+    }
+
+    /// Send a command and wait for its reply.
+    fn request(&mut self, command: &driver_ipc::Command) -> eyre::Result<driver_ipc::Command> {
+        self.client
+            .request(command)
+            .wrap_err("failed to send command to driver")
+    }
 }
 
-fn receive_command(ipc_reader: &mut NamedPipeClientReader) -> eyre::Result<driver_ipc::Command> {
-    let response = ipc_reader
-        .read_full()
-        .wrap_err("failed to read from driver pipe")?;
-    let command = serde_json::from_slice(&response).wrap_err("failed to deserialize command")?;
+// Inherent methods take precedence, so these call the ones above
+impl MonitorControl for Client {
+    type Error = eyre::Report;
+
+    fn list(&mut self) -> eyre::Result<Vec<Monitor>> {
+        let state = self.request_state()?;
+        self.state = Some(state.clone());
+
+        Ok(state)
+    }
+
+    fn get(&mut self, id: driver_ipc::Id) -> eyre::Result<Option<Monitor>> {
+        self.monitor(id)
+    }
 
-    Ok(command)
+    fn notify(&mut self, monitors: Vec<Monitor>) -> eyre::Result<Vec<driver_ipc::MonitorDiff>> {
+        self.notify(monitors)
+    }
+
+    fn remove(&mut self, ids: Vec<driver_ipc::Id>) -> eyre::Result<()> {
+        self.remove(ids)
+    }
+
+    fn events(&mut self, since: u64) -> eyre::Result<Vec<driver_ipc::Event>> {
+        self.events(since)
+    }
 }
@@ -0,0 +1,150 @@
+use std::ffi::OsString;
+
+use eyre::bail;
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use crate::GlobalOptions;
+
+/// A deprecated command line spelling and its replacement.
+///
+/// Deprecated spellings keep working for at least one release after they
+/// were deprecated, so scripts have time to migrate.
+///
+/// Spellings starting with `--` are flags, matched anywhere before `--`.
+/// Others are subcommands, only matched in the subcommand position.
+struct Rule {
+    /// The deprecated spelling.
+    old: &'static str,
+    /// What it's rewritten to.
+    new: &'static [&'static str],
+    /// The release that deprecated the spelling.
+    since: &'static str,
+}
+
+const RULES: &[Rule] = &[Rule {
+    old: "remove-all",
+    new: &["remove", "--all"],
+    since: "0.1.0",
+}];
+
+// Global options that take a value, needed to find the subcommand position
+const GLOBAL_VALUE_OPTIONS: &[&str] = &[
+    "--instance",
+    "--pipe",
+    "--transport",
+    "--tcp",
+    "--host",
+    "--key-file",
+];
+
+/// A deprecated spelling that was used, reported as a warning (or an error
+/// in `--strict` mode).
+#[derive(Debug, Clone, Serialize)]
+pub struct Deprecation {
+    pub deprecated: String,
+    pub replacement: String,
+    pub since: &'static str,
+}
+
+/// Rewrite deprecated spellings in `args` to their current spelling.
+pub fn migrate(args: impl IntoIterator<Item = OsString>) -> (Vec<OsString>, Vec<Deprecation>) {
+    let mut migrated = Vec::new();
+    let mut deprecations = Vec::new();
+
+    let mut args = args.into_iter();
+    // program name
+    migrated.extend(args.next());
+
+    let mut found_subcommand = false;
+    let mut takes_value = false;
+    let mut passthrough = false;
+
+    for arg in args {
+        let Some(arg_str) = arg.to_str().filter(|_| !passthrough) else {
+            migrated.push(arg);
+            continue;
+        };
+
+        if arg_str == "--" {
+            passthrough = true;
+            migrated.push(arg);
+            continue;
+        }
+
+        let is_subcommand = !found_subcommand && !takes_value && !arg_str.starts_with('-');
+        if !found_subcommand {
+            takes_value = !takes_value && GLOBAL_VALUE_OPTIONS.contains(&arg_str);
+            found_subcommand = is_subcommand;
+        }
+
+        let rule = RULES.iter().find_map(|rule| {
+            if rule.old.starts_with("--") {
+                // keep an inline `=value`
+                let value = arg_str.strip_prefix(rule.old)?;
+                (value.is_empty() || value.starts_with('=')).then_some((rule, value))
+            } else {
+                (is_subcommand && rule.old == arg_str).then_some((rule, ""))
+            }
+        });
+
+        let Some((rule, value)) = rule else {
+            migrated.push(arg);
+            continue;
+        };
+
+        let (last, rest) = rule.new.split_last().expect("rule without replacement");
+        migrated.extend(rest.iter().map(OsString::from));
+        migrated.push(OsString::from(format!("{last}{value}")));
+
+        deprecations.push(Deprecation {
+            deprecated: rule.old.to_owned(),
+            replacement: rule.new.join(" "),
+            since: rule.since,
+        });
+    }
+
+    (migrated, deprecations)
+}
+
+/// Warn about deprecated spellings on stderr, or fail in `--strict` mode.
+pub fn report(deprecations: &[Deprecation], opts: &GlobalOptions) -> eyre::Result<()> {
+    if deprecations.is_empty() {
+        return Ok(());
+    }
+
+    if opts.strict {
+        let used = deprecations
+            .iter()
+            .map(|d| format!("`{}` (use `{}`)", d.deprecated, d.replacement))
+            .collect::<Vec<_>>();
+        bail!("deprecated arguments used: {}", used.join(", "));
+    }
+
+    for deprecation in deprecations {
+        if opts.json {
+            #[derive(Serialize)]
+            struct Warning<'a> {
+                warning: &'static str,
+                #[serde(flatten)]
+                deprecation: &'a Deprecation,
+            }
+
+            let warning = Warning {
+                warning: "deprecated",
+                deprecation,
+            };
+            eprintln!("{}", serde_json::to_string(&warning)?);
+        } else {
+            eprintln!(
+                "{}: `{}` is deprecated since {} and will be removed in a future release, use `{}` instead",
+                "warning".yellow(),
+                deprecation.deprecated,
+                deprecation.since,
+                deprecation.replacement.green()
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,377 @@
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use driver_ipc::{CursorPolicy, ExportSize, Id, Monitor, SessionPolicy};
+use eyre::Context as _;
+use joinery::JoinableIterator as _;
+use schemars::{schema::RootSchema, schema_for, JsonSchema};
+use serde::Deserialize;
+
+use crate::{client::Client, mode, plan::Plan};
+
+/// Desired set of virtual monitors, as read by `apply` from a YAML (or
+/// JSON) file.
+#[derive(Debug, Clone, Default, Deserialize, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    #[serde(default)]
+    pub monitors: Vec<MonitorConfig>,
+}
+
+/// A virtual monitor in the config. It's matched to an existing monitor
+/// by `id` if set, and by `name` otherwise. Omitted settings get the same
+/// defaults as with `add`, also for existing monitors.
+#[derive(Debug, Clone, Deserialize, JsonSchema, PartialEq)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct MonitorConfig {
+    pub name: String,
+    pub id: Option<Id>,
+    /// Slug to match the monitor by if there's no `id`, which unlike IDs
+    /// stays the same when monitors are removed and added again.
+    pub alias: Option<String>,
+    /// The first mode is the preferred one.
+    pub modes: Vec<mode::Mode>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// File with a custom EDID, relative to the config file.
+    pub edid: Option<PathBuf>,
+    /// Clone the EDID of a connected physical display, such as `DISPLAY2`.
+    pub edid_from_display: Option<String>,
+    #[serde(default)]
+    pub audio: bool,
+    #[serde(default)]
+    pub ephemeral: bool,
+    #[serde(default)]
+    pub hardware_cursor: bool,
+    #[serde(default)]
+    pub remote_session: bool,
+    pub fps_limit: Option<u32>,
+    #[serde(default)]
+    pub gpu_export: bool,
+    #[serde(default)]
+    pub exclusive_capture: bool,
+    pub export_size: Option<ExportSize>,
+    #[serde(default = "default_true")]
+    pub auto_plug: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// JSON Schema of [`Config`], for editors and other tools to validate config files with.
+pub fn schema() -> RootSchema {
+    schema_for!(Config)
+}
+
+// parsed from the same strings as on the command line. like the schema, it's here rather than in
+// `mode`, which the fuzz targets build without serde and schemars
+impl<'de> Deserialize<'de> for mode::Mode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse()
+            .map_err(|e: eyre::Error| serde::de::Error::custom(e.chain().join_with(": ")))
+    }
+}
+
+impl JsonSchema for mode::Mode {
+    fn schema_name() -> String {
+        "Mode".to_owned()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(r"^\d+x\d+(@\d+(/\d+)*)?$".to_owned()),
+                ..Default::default()
+            })),
+            metadata: Some(Box::new(schemars::schema::Metadata {
+                description: Some("A resolution with optional refresh rates, e.g. `1920x1080` or `3840x2160@60/120`".to_owned()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+impl Config {
+    pub fn read(path: &Path) -> eyre::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .with_context(|| format!("failed to read config file {}", path.display()))?;
+        let config: Self = serde_yaml::from_str(&contents)
+            .with_context(|| format!("invalid config file {}", path.display()))?;
+
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    fn validate(&self) -> eyre::Result<()> {
+        let mut names = HashSet::new();
+        let mut ids = HashSet::new();
+        let mut aliases = HashSet::new();
+
+        for monitor in &self.monitors {
+            let name = &monitor.name;
+
+            eyre::ensure!(
+                names.insert(name.as_str()),
+                "monitor `{name}` is in the config more than once"
+            );
+            if let Some(id) = monitor.id {
+                eyre::ensure!(
+                    ids.insert(id),
+                    "ID {id} is used by more than one monitor in the config"
+                );
+            }
+            if let Some(alias) = &monitor.alias {
+                eyre::ensure!(
+                    driver_ipc::is_valid_alias(alias),
+                    "monitor `{name}` needs an `alias` of lowercase letters, digits, `-` and `_`, starting with a letter"
+                );
+                eyre::ensure!(
+                    aliases.insert(alias.as_str()),
+                    "alias `{alias}` is used by more than one monitor in the config"
+                );
+            }
+
+            eyre::ensure!(!monitor.modes.is_empty(), "monitor `{name}` has no modes");
+            eyre::ensure!(
+                monitor.edid.is_none() || monitor.edid_from_display.is_none(),
+                "monitor `{name}` can't have both `edid` and `edid_from_display`"
+            );
+            eyre::ensure!(
+                monitor.fps_limit != Some(0),
+                "monitor `{name}` needs an `fps_limit` above 0"
+            );
+        }
+
+        Ok(())
+    }
+
+    /// The changes that make the driver's monitors match the config.
+    /// Monitors that aren't in the config are removed if `prune` is set, and
+    /// left alone otherwise. Relative EDID paths are resolved against `base`.
+    pub fn plan(&self, client: &mut Client, base: &Path, prune: bool) -> eyre::Result<Plan> {
+        let current = client.monitors()?.to_vec();
+
+        let matched = self
+            .monitors
+            .iter()
+            .map(|config| match (config.id, &config.alias) {
+                (Some(id), _) => current.iter().find(|monitor| monitor.id == id),
+                (None, Some(alias)) => current
+                    .iter()
+                    .find(|monitor| monitor.alias.as_ref() == Some(alias)),
+                (None, None) => current
+                    .iter()
+                    .find(|monitor| monitor.name.as_deref() == Some(config.name.as_str())),
+            })
+            .collect::<Vec<_>>();
+
+        // new monitors don't take over the ID of one that's pruned, it would be updated
+        // instead of being replaced
+        let mut used = current
+            .iter()
+            .map(|monitor| monitor.id)
+            .chain(self.monitors.iter().filter_map(|config| config.id))
+            .collect::<HashSet<_>>();
+
+        let mut desired = Vec::<Monitor>::with_capacity(self.monitors.len());
+        for (config, existing) in self.monitors.iter().zip(matched) {
+            let id = match (config.id, existing) {
+                (Some(id), _) => id,
+                (None, Some(existing)) => existing.id,
+                #[allow(clippy::maybe_infinite_iter)]
+                (None, None) => (0..)
+                    .find(|id| !used.contains(id))
+                    .expect("failed to get a new ID"),
+            };
+            used.insert(id);
+
+            // a monitor matched by name can have the ID another one asks for
+            if let Some(other) = desired.iter().find(|monitor| monitor.id == id) {
+                eyre::bail!(
+                    "monitors `{}` and `{}` in the config both match ID {id}",
+                    other.name.as_deref().unwrap_or_default(),
+                    config.name
+                );
+            }
+
+            desired.push(config.monitor(client, id, base)?);
+        }
+        client.check_aliases(&desired)?;
+
+        Ok(Plan::diff(&current, &desired, prune))
+    }
+}
+
+impl MonitorConfig {
+    fn monitor(&self, client: &mut Client, id: Id, base: &Path) -> eyre::Result<Monitor> {
+        let edid = if let Some(path) = &self.edid {
+            let path = base.join(path);
+            let edid = fs::read(&path)
+                .with_context(|| format!("failed to read EDID file {}", path.display()))?;
+            Some(edid)
+        } else {
+            self.edid_from_display
+                .as_deref()
+                .map(|display| client.display_edid(display))
+                .transpose()?
+        };
+
+        Ok(Monitor {
+            name: Some(self.name.clone()),
+            alias: self.alias.clone(),
+            enabled: self.enabled,
+            edid,
+            audio: self.audio,
+            ephemeral: self.ephemeral,
+            cursor: CursorPolicy {
+                hardware: self.hardware_cursor,
+                ..CursorPolicy::default()
+            },
+            session: if self.remote_session {
+                SessionPolicy::Remote
+            } else {
+                SessionPolicy::Manual
+            },
+            fps_limit: self.fps_limit,
+            gpu_export: self.gpu_export,
+            exclusive_capture: self.exclusive_capture,
+            export_size: self.export_size,
+            auto_plug: self.auto_plug,
+            tags: self.tags.clone(),
+            ..Monitor::new(id, modes(&self.modes))
+        })
+    }
+}
+
+// like `mode::merge`, but keeps the order, so the preferred mode stays first
+fn modes(modes: &[mode::Mode]) -> Vec<driver_ipc::Mode> {
+    let mut merged = Vec::<mode::Mode>::new();
+
+    for mode in modes {
+        match merged
+            .iter_mut()
+            .find(|merged| (merged.width, merged.height) == (mode.width, mode.height))
+        {
+            Some(merged) => merged.refresh_rates.extend(&mode.refresh_rates),
+            None => merged.push(mode.clone()),
+        }
+    }
+
+    merged.into_iter().map(driver_ipc::Mode::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> eyre::Result<Config> {
+        let config: Config = serde_yaml::from_str(yaml)?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    #[test]
+    fn defaults() {
+        let config = parse(
+            "
+monitors:
+  - name: desk
+    modes: [1920x1080@60/120]
+",
+        )
+        .unwrap();
+
+        let monitor = &config.monitors[0];
+        assert_eq!(monitor.name, "desk");
+        assert_eq!(monitor.id, None);
+        assert_eq!(
+            monitor.modes,
+            ["1920x1080@60/120".parse::<mode::Mode>().unwrap()]
+        );
+        assert!(monitor.enabled);
+        assert!(monitor.auto_plug);
+        assert!(!monitor.gpu_export);
+    }
+
+    #[test]
+    fn rejects_invalid_configs() {
+        for yaml in [
+            // typos aren't silently ignored
+            "
+monitors:
+  - name: a
+    modes: [1920x1080]
+    enable: false
+",
+            "
+monitors:
+  - name: a
+    modes: [1920y1080]
+",
+            "
+monitors:
+  - name: a
+    modes: []
+",
+            "
+monitors:
+  - name: a
+    modes: [1920x1080]
+  - name: a
+    modes: [1280x720]
+",
+            "
+monitors:
+  - name: a
+    id: 1
+    modes: [1920x1080]
+  - name: b
+    id: 1
+    modes: [1280x720]
+",
+            "
+monitors:
+  - name: a
+    alias: Living Room
+    modes: [1920x1080]
+",
+            "
+monitors:
+  - name: a
+    alias: tv
+    modes: [1920x1080]
+  - name: b
+    alias: tv
+    modes: [1280x720]
+",
+        ] {
+            assert!(parse(yaml).is_err(), "{yaml}");
+        }
+    }
+
+    #[test]
+    fn modes_keep_their_order() {
+        let modes = modes(&[
+            "1280x720".parse().unwrap(),
+            "1920x1080@120".parse().unwrap(),
+            "1280x720@30".parse().unwrap(),
+        ]);
+
+        assert_eq!(modes[0].width, 1280);
+        assert_eq!(modes[0].refresh_rates, [30]);
+        assert_eq!(modes[1].width, 1920);
+    }
+}
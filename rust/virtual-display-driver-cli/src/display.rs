@@ -0,0 +1,433 @@
+//! The displays Windows shows, changed with the display configuration api.
+//!
+//! Unless they're saved to Windows' display database, [`restore`] brings back
+//! the layout Windows saved for the connected displays.
+
+use std::{
+    mem::size_of,
+    ptr, thread,
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{DisplayTarget, Id};
+use joinery::JoinableIterator as _;
+use serde::{Deserialize, Serialize};
+use windows::Win32::{
+    Devices::Display::{
+        DisplayConfigGetDeviceInfo, DisplayConfigSetDeviceInfo, GetDisplayConfigBufferSizes,
+        QueryDisplayConfig, SetDisplayConfig, DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+        DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME, DISPLAYCONFIG_DEVICE_INFO_HEADER,
+        DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE, DISPLAYCONFIG_DEVICE_INFO_TYPE,
+        DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE, DISPLAYCONFIG_PATH_INFO,
+        DISPLAYCONFIG_SOURCE_DEVICE_NAME, DISPLAYCONFIG_SOURCE_MODE,
+        DISPLAYCONFIG_TARGET_DEVICE_NAME, QDC_ALL_PATHS, QDC_ONLY_ACTIVE_PATHS,
+        QUERY_DISPLAY_CONFIG_FLAGS, SDC_ALLOW_CHANGES, SDC_APPLY, SDC_SAVE_TO_DATABASE,
+        SDC_USE_DATABASE_CURRENT, SDC_USE_SUPPLIED_DISPLAY_CONFIG,
+    },
+    Foundation::{ERROR_INSUFFICIENT_BUFFER, LUID, WIN32_ERROR},
+    Graphics::Gdi::{DISPLAYCONFIG_PATH_ACTIVE, DISPLAYCONFIG_PATH_MODE_IDX_INVALID},
+};
+
+use crate::client::Client;
+
+// how long Windows gets to show a monitor once it's plugged in
+const DISPLAY_TIMEOUT: Duration = Duration::from_secs(10);
+const DISPLAY_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// A display Windows currently shows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Display {
+    /// GDI name, such as `\\.\DISPLAY1`.
+    pub name: String,
+    /// Name of the monitor, from its EDID.
+    pub friendly_name: String,
+    pub target: DisplayTarget,
+    pub primary: bool,
+}
+
+impl Display {
+    /// Whether the display is called `query`, such as `DISPLAY1` or
+    /// `\\.\DISPLAY1`.
+    pub fn is_named(&self, query: &str) -> bool {
+        let name = self.name.trim_start_matches(r"\\.\");
+        name.eq_ignore_ascii_case(query.trim_start_matches(r"\\.\"))
+    }
+}
+
+// undocumented device info types for the scale of a source, which the
+// display settings use
+const DPI_SCALE_GET: DISPLAYCONFIG_DEVICE_INFO_TYPE = DISPLAYCONFIG_DEVICE_INFO_TYPE(-3);
+const DPI_SCALE_SET: DISPLAYCONFIG_DEVICE_INFO_TYPE = DISPLAYCONFIG_DEVICE_INFO_TYPE(-4);
+// the scales the display settings offer, in percent, up to the largest a
+// display allows
+const DPI_SCALES: [u32; 12] = [100, 125, 150, 175, 200, 225, 250, 300, 350, 400, 450, 500];
+
+// the scales a source allows, as steps from the recommended one
+#[repr(C)]
+#[derive(Default)]
+struct DpiScale {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    min: i32,
+    current: i32,
+    max: i32,
+}
+
+#[repr(C)]
+struct DpiScaleSet {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    relative: i32,
+}
+
+// `DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE`, whose only bit turns it on
+#[repr(C)]
+struct AdvancedColorSet {
+    header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    enable: u32,
+}
+
+/// Paths from sources (desktops) to targets (monitors), and their modes.
+struct Config {
+    paths: Vec<DISPLAYCONFIG_PATH_INFO>,
+    modes: Vec<DISPLAYCONFIG_MODE_INFO>,
+}
+
+impl Config {
+    fn query(flags: QUERY_DISPLAY_CONFIG_FLAGS) -> eyre::Result<Self> {
+        // displays can change between getting the sizes and querying them
+        loop {
+            let (mut path_count, mut mode_count) = (0, 0);
+            unsafe { GetDisplayConfigBufferSizes(flags, &mut path_count, &mut mode_count) }.ok()?;
+
+            let mut paths = vec![DISPLAYCONFIG_PATH_INFO::default(); path_count as usize];
+            let mut modes = vec![DISPLAYCONFIG_MODE_INFO::default(); mode_count as usize];
+            let status = unsafe {
+                QueryDisplayConfig(
+                    flags,
+                    &mut path_count,
+                    paths.as_mut_ptr(),
+                    &mut mode_count,
+                    modes.as_mut_ptr(),
+                    None,
+                )
+            };
+            if status == ERROR_INSUFFICIENT_BUFFER {
+                continue;
+            }
+            status.ok()?;
+
+            paths.truncate(path_count as usize);
+            modes.truncate(mode_count as usize);
+            return Ok(Self { paths, modes });
+        }
+    }
+
+    /// Apply the active paths. With `save`, Windows keeps the layout for the
+    /// connected displays.
+    fn apply(&self, save: bool) -> eyre::Result<()> {
+        let mut flags = SDC_APPLY | SDC_USE_SUPPLIED_DISPLAY_CONFIG | SDC_ALLOW_CHANGES;
+        if save {
+            flags |= SDC_SAVE_TO_DATABASE;
+        }
+
+        let status = unsafe { SetDisplayConfig(Some(&self.paths), Some(&self.modes), flags) };
+        check(status)
+    }
+
+    fn path(&self, target: DisplayTarget) -> Option<&DISPLAYCONFIG_PATH_INFO> {
+        self.paths.iter().find(|path| path_target(path) == target)
+    }
+
+    fn source_mode(&self, path: &DISPLAYCONFIG_PATH_INFO) -> Option<DISPLAYCONFIG_SOURCE_MODE> {
+        let index = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
+        let mode = self
+            .modes
+            .get(index as usize)
+            .filter(|mode| mode.infoType == DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE)?;
+
+        Some(unsafe { mode.Anonymous.sourceMode })
+    }
+}
+
+/// The displays Windows currently shows.
+pub fn displays() -> eyre::Result<Vec<Display>> {
+    let config = Config::query(QDC_ONLY_ACTIVE_PATHS)?;
+
+    config
+        .paths
+        .iter()
+        .map(|path| {
+            let source = unsafe {
+                device_info::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>(
+                    DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+                    path.sourceInfo.adapterId,
+                    path.sourceInfo.id,
+                )
+            }?;
+            let target = unsafe {
+                device_info::<DISPLAYCONFIG_TARGET_DEVICE_NAME>(
+                    DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+                    path.targetInfo.adapterId,
+                    path.targetInfo.id,
+                )
+            }?;
+
+            // the primary display is the one at the origin of the desktop
+            let primary = config
+                .source_mode(path)
+                .is_some_and(|mode| mode.position.x == 0 && mode.position.y == 0);
+
+            Ok(Display {
+                name: from_wide(&source.viewGdiDeviceName),
+                friendly_name: from_wide(&target.monitorFriendlyDeviceName),
+                target: path_target(path),
+                primary,
+            })
+        })
+        .collect()
+}
+
+/// Make a display the primary one, by moving the desktop so it starts at
+/// the display.
+pub fn set_primary(target: DisplayTarget) -> eyre::Result<()> {
+    let mut config = Config::query(QDC_ONLY_ACTIVE_PATHS)?;
+
+    let origin = config
+        .path(target)
+        .and_then(|path| config.source_mode(path))
+        .ok_or_else(|| eyre::eyre!("the display isn't active"))?
+        .position;
+
+    for mode in &mut config.modes {
+        if mode.infoType != DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE {
+            continue;
+        }
+
+        let position = unsafe { &mut mode.Anonymous.sourceMode.position };
+        position.x -= origin.x;
+        position.y -= origin.y;
+    }
+
+    config.apply(false)
+}
+
+/// Turn off displays, leaving the others as they are.
+pub fn deactivate(targets: &[DisplayTarget], save: bool) -> eyre::Result<()> {
+    let mut config = Config::query(QDC_ONLY_ACTIVE_PATHS)?;
+
+    // displays without a path are turned off
+    config
+        .paths
+        .retain(|path| !targets.contains(&path_target(path)));
+    if config.paths.is_empty() {
+        eyre::bail!("can't turn off every display");
+    }
+
+    config.apply(save)
+}
+
+/// Turn on connected displays, extending the desktop to them. Active ones
+/// are left as they are.
+pub fn activate(targets: &[DisplayTarget], save: bool) -> eyre::Result<()> {
+    let mut config = Config::query(QDC_ONLY_ACTIVE_PATHS)?;
+    let all = Config::query(QDC_ALL_PATHS)?;
+
+    for &target in targets {
+        if config.path(target).is_some() {
+            continue;
+        }
+
+        // every free source of the adapter works, and Windows picks the modes
+        let mut path = *all
+            .paths
+            .iter()
+            .filter(|path| path.targetInfo.targetAvailable.as_bool())
+            .find(|path| {
+                path_target(path) == target
+                    && !config.paths.iter().any(|active| {
+                        active.sourceInfo.adapterId == path.sourceInfo.adapterId
+                            && active.sourceInfo.id == path.sourceInfo.id
+                    })
+            })
+            .ok_or_else(|| eyre::eyre!("the display isn't connected"))?;
+
+        path.flags |= DISPLAYCONFIG_PATH_ACTIVE;
+        path.sourceInfo.Anonymous.modeInfoIdx = DISPLAYCONFIG_PATH_MODE_IDX_INVALID;
+        path.targetInfo.Anonymous.modeInfoIdx = DISPLAYCONFIG_PATH_MODE_IDX_INVALID;
+        config.paths.push(path);
+    }
+
+    config.apply(save)
+}
+
+/// Every connected display, also the ones that are turned off, with the
+/// name of its monitor.
+pub fn connected() -> eyre::Result<Vec<(DisplayTarget, String)>> {
+    let config = Config::query(QDC_ALL_PATHS)?;
+
+    // there's a path from every source that could show a target
+    let mut targets = Vec::<(DisplayTarget, String)>::new();
+    for path in &config.paths {
+        let target = path_target(path);
+        if !path.targetInfo.targetAvailable.as_bool()
+            || targets.iter().any(|(known, _)| *known == target)
+        {
+            continue;
+        }
+
+        let name = unsafe {
+            device_info::<DISPLAYCONFIG_TARGET_DEVICE_NAME>(
+                DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+                path.targetInfo.adapterId,
+                path.targetInfo.id,
+            )
+        }?;
+        targets.push((target, from_wide(&name.monitorFriendlyDeviceName)));
+    }
+
+    Ok(targets)
+}
+
+/// Apply the layout Windows saved for the connected displays, undoing the
+/// changes made with this module.
+pub fn restore() -> eyre::Result<()> {
+    let status = unsafe { SetDisplayConfig(None, None, SDC_APPLY | SDC_USE_DATABASE_CURRENT) };
+    check(status)
+}
+
+/// Set the scale of a display, such as 150 for 150%, which Windows offers
+/// for the display in the settings.
+pub fn set_scale(target: DisplayTarget, percent: u32) -> eyre::Result<()> {
+    let config = Config::query(QDC_ONLY_ACTIVE_PATHS)?;
+    let path = config
+        .path(target)
+        .ok_or_else(|| eyre::eyre!("the display isn't active"))?;
+    let (adapter, source) = (path.sourceInfo.adapterId, path.sourceInfo.id);
+
+    // the scales are steps relative to the one Windows recommends for the display
+    let range = unsafe { device_info::<DpiScale>(DPI_SCALE_GET, adapter, source) }?;
+    let recommended = range.min.unsigned_abs() as usize;
+    let offered = DPI_SCALES
+        .get(..=recommended + range.max.unsigned_abs() as usize)
+        .unwrap_or(&DPI_SCALES);
+    let Some(step) = offered.iter().position(|&scale| scale == percent) else {
+        eyre::bail!(
+            "{percent}% isn't a scale Windows offers for the display, it offers {}",
+            offered
+                .iter()
+                .map(|scale| format!("{scale}%"))
+                .join_with(", ")
+        );
+    };
+
+    // at most a dozen steps
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    let relative = step as i32 - recommended as i32;
+    if relative == range.current {
+        return Ok(());
+    }
+
+    let mut set = DpiScaleSet {
+        header: header::<DpiScaleSet>(DPI_SCALE_SET, adapter, source),
+        relative,
+    };
+    check(unsafe { DisplayConfigSetDeviceInfo(ptr::addr_of_mut!(set).cast()) })
+}
+
+/// Turn HDR (advanced color) on or off for a display that supports it.
+pub fn set_hdr(target: DisplayTarget, enabled: bool) -> eyre::Result<()> {
+    let config = Config::query(QDC_ONLY_ACTIVE_PATHS)?;
+    let path = config
+        .path(target)
+        .ok_or_else(|| eyre::eyre!("the display isn't active"))?;
+
+    let mut set = AdvancedColorSet {
+        header: header::<AdvancedColorSet>(
+            DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+            path.targetInfo.adapterId,
+            path.targetInfo.id,
+        ),
+        enable: u32::from(enabled),
+    };
+    check(unsafe { DisplayConfigSetDeviceInfo(ptr::addr_of_mut!(set).cast()) })
+}
+
+/// Wait for Windows to show a virtual monitor that was just plugged in, and
+/// return its display.
+pub fn wait_for_monitor(client: &mut Client, id: Id) -> eyre::Result<Display> {
+    let start = Instant::now();
+
+    loop {
+        if let Some(target) = client.monitor(id)?.and_then(|monitor| monitor.target) {
+            let display = displays()?
+                .into_iter()
+                .find(|display| display.target == target);
+            if let Some(display) = display {
+                return Ok(display);
+            }
+        }
+
+        if start.elapsed() > DISPLAY_TIMEOUT {
+            eyre::bail!(
+                "virtual monitor {id} didn't show up as a display, check that it's active in the display settings"
+            );
+        }
+        thread::sleep(DISPLAY_POLL_INTERVAL);
+    }
+}
+
+fn path_target(path: &DISPLAYCONFIG_PATH_INFO) -> DisplayTarget {
+    let adapter = path.targetInfo.adapterId;
+
+    DisplayTarget {
+        adapter: (i64::from(adapter.HighPart) << 32) | i64::from(adapter.LowPart),
+        id: path.targetInfo.id,
+    }
+}
+
+/// # Safety
+///
+/// `T` must be the `DISPLAYCONFIG_*` struct for `kind`, which starts with a
+/// `DISPLAYCONFIG_DEVICE_INFO_HEADER`.
+unsafe fn device_info<T: Default>(
+    kind: DISPLAYCONFIG_DEVICE_INFO_TYPE,
+    adapter: LUID,
+    id: u32,
+) -> eyre::Result<T> {
+    let mut info = T::default();
+    let header = ptr::addr_of_mut!(info).cast::<DISPLAYCONFIG_DEVICE_INFO_HEADER>();
+
+    unsafe { header.write(self::header::<T>(kind, adapter, id)) };
+    check(unsafe { DisplayConfigGetDeviceInfo(header) })?;
+
+    Ok(info)
+}
+
+// the header of a `DISPLAYCONFIG_*` struct `T`
+fn header<T>(
+    kind: DISPLAYCONFIG_DEVICE_INFO_TYPE,
+    adapter: LUID,
+    id: u32,
+) -> DISPLAYCONFIG_DEVICE_INFO_HEADER {
+    #[allow(clippy::cast_possible_truncation)]
+    let size = size_of::<T>() as u32;
+
+    DISPLAYCONFIG_DEVICE_INFO_HEADER {
+        r#type: kind,
+        size,
+        adapterId: adapter,
+        id,
+    }
+}
+
+// the display config functions return win32 error codes
+fn check(status: i32) -> eyre::Result<()> {
+    #[allow(clippy::cast_sign_loss)]
+    WIN32_ERROR(status as u32).ok()?;
+
+    Ok(())
+}
+
+fn from_wide(wide: &[u16]) -> String {
+    let len = wide.iter().position(|&c| c == 0).unwrap_or(wide.len());
+    String::from_utf16_lossy(&wide[..len])
+}
@@ -0,0 +1,143 @@
+//! Filtering and sorting of `list`, shared by its pretty printed and JSON
+//! output so scripts see the same monitors as people do.
+
+use driver_ipc::{Id, Monitor};
+
+#[derive(Debug, clap::Parser)]
+pub struct MonitorFilter {
+    /// Only list enabled virtual monitors.
+    #[clap(long, conflicts_with = "disabled")]
+    enabled: bool,
+
+    /// Only list disabled virtual monitors.
+    #[clap(long)]
+    disabled: bool,
+
+    /// Only list the virtual monitors with these IDs. Can be given more
+    /// than once.
+    #[clap(long = "id")]
+    ids: Vec<Id>,
+
+    /// Only list the virtual monitors whose name contains this, ignoring
+    /// case.
+    #[clap(long)]
+    name_contains: Option<String>,
+
+    /// Only list the virtual monitors with this tag.
+    #[clap(long)]
+    tag: Option<String>,
+
+    /// Order to list the virtual monitors in.
+    #[clap(long, value_enum, default_value_t = Sort::Id)]
+    sort: Sort,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Sort {
+    #[default]
+    Id,
+    /// By name, ignoring case. Monitors without one come last.
+    Name,
+    /// By the pixel count of the preferred mode, largest first.
+    Resolution,
+}
+
+impl MonitorFilter {
+    pub fn matches(&self, monitor: &Monitor) -> bool {
+        let name_contains = self.name_contains.as_ref().map(|part| part.to_lowercase());
+
+        (!self.enabled || monitor.enabled)
+            && (!self.disabled || !monitor.enabled)
+            && (self.ids.is_empty() || self.ids.contains(&monitor.id))
+            && name_contains.map_or(true, |part| {
+                monitor
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.to_lowercase().contains(&part))
+            })
+            && self
+                .tag
+                .as_ref()
+                .map_or(true, |tag| monitor.tags.contains(tag))
+    }
+
+    /// The monitors that match, in the requested order.
+    pub fn apply<'a>(&self, monitors: &'a [Monitor]) -> Vec<&'a Monitor> {
+        let mut monitors = monitors
+            .iter()
+            .filter(|monitor| self.matches(monitor))
+            .collect::<Vec<_>>();
+
+        match self.sort {
+            Sort::Id => monitors.sort_by_key(|monitor| monitor.id),
+            Sort::Name => monitors.sort_by_cached_key(|monitor| {
+                let name = monitor.name.as_ref().map(|name| name.to_lowercase());
+                (name.is_none(), name, monitor.id)
+            }),
+            Sort::Resolution => monitors.sort_by_key(|monitor| {
+                let pixels = monitor
+                    .modes
+                    .first()
+                    .map_or(0, |mode| u64::from(mode.width) * u64::from(mode.height));
+                (std::cmp::Reverse(pixels), monitor.id)
+            }),
+        }
+
+        monitors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+
+    use super::*;
+
+    fn monitor(id: Id, name: Option<&str>, enabled: bool, size: (u32, u32)) -> Monitor {
+        let modes = vec![driver_ipc::Mode {
+            width: size.0,
+            height: size.1,
+            refresh_rates: vec![60],
+        }];
+
+        Monitor {
+            name: name.map(str::to_owned),
+            enabled,
+            ..Monitor::new(id, modes)
+        }
+    }
+
+    fn ids(args: &[&str], monitors: &[Monitor]) -> Vec<Id> {
+        let filter = MonitorFilter::parse_from(std::iter::once("list").chain(args.iter().copied()));
+        filter
+            .apply(monitors)
+            .iter()
+            .map(|monitor| monitor.id)
+            .collect()
+    }
+
+    #[test]
+    fn filters_and_sorts() {
+        let mut tagged = monitor(0, Some("Desk"), true, (1920, 1080));
+        tagged.tags.push("work".to_owned());
+        let monitors = [
+            monitor(3, None, true, (3840, 2160)),
+            tagged,
+            monitor(1, Some("stream"), false, (1280, 720)),
+            monitor(2, Some("desk 2"), true, (2560, 1440)),
+        ];
+
+        assert_eq!(ids(&[], &monitors), [0, 1, 2, 3]);
+        assert_eq!(ids(&["--enabled"], &monitors), [0, 2, 3]);
+        assert_eq!(ids(&["--disabled"], &monitors), [1]);
+        assert_eq!(ids(&["--id", "3", "--id", "1"], &monitors), [1, 3]);
+        assert_eq!(ids(&["--name-contains", "DESK"], &monitors), [0, 2]);
+        assert_eq!(ids(&["--tag", "work"], &monitors), [0]);
+        assert_eq!(ids(&["--sort", "name"], &monitors), [0, 2, 1, 3]);
+        assert_eq!(ids(&["--sort", "resolution"], &monitors), [3, 2, 0, 1]);
+        assert_eq!(
+            ids(&["--enabled", "--sort", "resolution"], &monitors),
+            [3, 2, 0]
+        );
+    }
+}
@@ -0,0 +1,154 @@
+//! Loading a profile while a game runs, from the launch hooks of game
+//! launchers. The profile is undone by [`post_launch`], or by `vdd-server
+//! --schedule` if the launcher exits first, e.g. after a crash.
+
+use std::{mem::size_of, path::Path};
+
+use driver_ipc::{HookSession, Monitor, HOOK_VALUE};
+use eyre::Context as _;
+use windows::Win32::{
+    Foundation::{CloseHandle, FILETIME, STILL_ACTIVE},
+    System::{
+        Diagnostics::ToolHelp::{
+            CreateToolhelp32Snapshot, Process32FirstW, Process32NextW, PROCESSENTRY32W,
+            TH32CS_SNAPPROCESS,
+        },
+        Threading::{
+            GetCurrentProcessId, GetExitCodeProcess, GetProcessTimes, OpenProcess,
+            PROCESS_QUERY_LIMITED_INFORMATION,
+        },
+    },
+};
+
+use crate::{client::Client, settings};
+
+/// Load a profile, remembering how to undo it. `launcher` is the process
+/// that outlives the game, the process that ran this one by default.
+pub fn pre_launch(
+    client: &mut Client,
+    profile: &Path,
+    launcher: Option<u32>,
+) -> eyre::Result<HookSession> {
+    // vdd-server runs elsewhere, so relative paths wouldn't resolve
+    let profile = std::env::current_dir()?.join(profile);
+    let contents = std::fs::read(&profile)
+        .wrap_err_with(|| format!("failed to read profile {}", profile.display()))?;
+    let monitors = serde_json::from_slice::<Vec<Monitor>>(&contents).wrap_err_with(|| {
+        format!(
+            "invalid profile {}, expected the output of `list --json`",
+            profile.display()
+        )
+    })?;
+
+    let launcher = match launcher {
+        Some(pid) => pid,
+        None => parent_pid()?,
+    };
+    let launcher_started = process_started(launcher)
+        .ok_or_else(|| eyre::eyre!("launcher process {launcher} isn't running"))?;
+
+    // the last game's post-launch hook didn't run, and vdd-server didn't
+    // catch it either
+    post_launch(client)?;
+
+    let existing = client.monitors()?;
+    let previous = existing
+        .iter()
+        .filter(|monitor| monitors.iter().any(|loaded| loaded.id == monitor.id))
+        .cloned()
+        .collect();
+    let added = monitors
+        .iter()
+        .map(|monitor| monitor.id)
+        .filter(|&id| existing.iter().all(|monitor| monitor.id != id))
+        .collect();
+
+    let session = HookSession {
+        profile: profile
+            .into_os_string()
+            .into_string()
+            .map_err(|_| eyre::eyre!("profile path must be valid unicode"))?,
+        previous,
+        added,
+        launcher,
+        launcher_started,
+    };
+
+    // saved first, so a failed load can be undone too
+    settings::write(HOOK_VALUE, &session)?;
+    client.notify(monitors)?;
+
+    Ok(session)
+}
+
+/// Undo the profile of [`pre_launch`]. Returns `None` if there was nothing
+/// to undo.
+pub fn post_launch(client: &mut Client) -> eyre::Result<Option<HookSession>> {
+    let Some(session) = settings::read_value::<HookSession>(HOOK_VALUE)? else {
+        return Ok(None);
+    };
+
+    if !session.previous.is_empty() {
+        client.notify(session.previous.clone())?;
+    }
+
+    // some may have been removed by hand in the meantime
+    let added = client
+        .monitors()?
+        .iter()
+        .map(|monitor| monitor.id)
+        .filter(|id| session.added.contains(id))
+        .collect::<Vec<_>>();
+    if !added.is_empty() {
+        client.remove(added)?;
+    }
+
+    settings::remove(HOOK_VALUE)?;
+
+    Ok(Some(session))
+}
+
+fn parent_pid() -> eyre::Result<u32> {
+    let pid = unsafe { GetCurrentProcessId() };
+    let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPPROCESS, 0)? };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let mut entry = PROCESSENTRY32W {
+        dwSize: size_of::<PROCESSENTRY32W>() as u32,
+        ..Default::default()
+    };
+
+    let mut parent = None;
+    let mut more = unsafe { Process32FirstW(snapshot, &mut entry) }.is_ok();
+    while more {
+        if entry.th32ProcessID == pid {
+            parent = Some(entry.th32ParentProcessID);
+            break;
+        }
+
+        more = unsafe { Process32NextW(snapshot, &mut entry) }.is_ok();
+    }
+
+    unsafe { CloseHandle(snapshot)? };
+
+    parent.ok_or_else(|| eyre::eyre!("failed to find the launcher, pass its PID with --pid"))
+}
+
+/// Creation time of a running process, `None` if it exited.
+fn process_started(pid: u32) -> Option<u64> {
+    let process = unsafe { OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid) }.ok()?;
+
+    let mut exit_code = 0;
+    let running = unsafe { GetExitCodeProcess(process, &mut exit_code) }.is_ok()
+        && i32::try_from(exit_code) == Ok(STILL_ACTIVE.0);
+
+    let [mut created, mut exited, mut kernel, mut user] = [FILETIME::default(); 4];
+    let timed =
+        unsafe { GetProcessTimes(process, &mut created, &mut exited, &mut kernel, &mut user) }
+            .is_ok();
+
+    _ = unsafe { CloseHandle(process) };
+
+    (running && timed)
+        .then(|| (u64::from(created.dwHighDateTime) << 32) | u64::from(created.dwLowDateTime))
+}
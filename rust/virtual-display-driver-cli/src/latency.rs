@@ -0,0 +1,157 @@
+//! Frame timings of a virtual monitor, for `benchmark`. Unlike `bench`, which measures the driver
+//! pipe, this measures frames.
+//!
+//! Frames have to come from Windows: the driver's test patterns only replace the frames it shares
+//! with other programs, and never go through the swap chain whose present latency and acquisition
+//! is measured here.
+
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+
+/// How often the driver's statistics are sampled during a measurement.
+const SAMPLE_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Frame timings of a virtual monitor in a single mode. Latencies are in
+/// microseconds.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ModeResult {
+    pub width: driver_ipc::Dimen,
+    pub height: driver_ipc::Dimen,
+    pub refresh_rate: driver_ipc::RefreshRate,
+    /// Frames acquired while measuring.
+    pub frames: u64,
+    /// Frames the OS presented that the driver never acquired.
+    pub dropped: u64,
+    /// Frames acquired per second, over the whole measurement.
+    pub fps: f64,
+    /// Average time between the OS presenting a frame and the driver
+    /// acquiring it.
+    pub present_latency_us: f64,
+    /// Average time between the driver acquiring a frame and releasing it.
+    pub processing_us: f64,
+    /// Average deviation of the time between frames from its average.
+    pub jitter_us: f64,
+}
+
+/// Measure each mode of a virtual monitor for `duration`, switching to it
+/// and waiting `settle` for the OS to follow first.
+///
+/// The monitor is restricted to one mode at a time, and restored afterwards,
+/// even if measuring failed.
+pub fn run(
+    client: &mut Client,
+    monitor: &driver_ipc::Monitor,
+    duration: Duration,
+    settle: Duration,
+) -> eyre::Result<Vec<ModeResult>> {
+    eyre::ensure!(
+        monitor.enabled,
+        "virtual monitor {} is disabled, enable it first",
+        monitor.id
+    );
+
+    let modes = monitor
+        .modes
+        .iter()
+        .flat_map(|mode| {
+            mode.refresh_rates
+                .iter()
+                .map(|&refresh_rate| (mode.width, mode.height, refresh_rate))
+        })
+        .collect::<Vec<_>>();
+
+    let results = modes
+        .into_iter()
+        .map(|(width, height, refresh_rate)| {
+            let mut single = monitor.clone();
+            single.modes = vec![driver_ipc::Mode {
+                width,
+                height,
+                refresh_rates: vec![refresh_rate],
+            }];
+            client.notify(vec![single])?;
+
+            std::thread::sleep(settle);
+            measure(client, monitor.id, duration, width, height, refresh_rate)
+        })
+        .collect::<eyre::Result<Vec<_>>>();
+
+    client.notify(vec![monitor.clone()])?;
+
+    results
+}
+
+fn measure(
+    client: &mut Client,
+    id: driver_ipc::Id,
+    duration: Duration,
+    width: driver_ipc::Dimen,
+    height: driver_ipc::Dimen,
+    refresh_rate: driver_ipc::RefreshRate,
+) -> eyre::Result<ModeResult> {
+    let first = stats(client, id)?;
+    let start = Instant::now();
+
+    // the driver only keeps a moving average of the jitter, so it's averaged
+    // again over the samples taken while frames were coming in
+    let mut jitter = Vec::new();
+    let mut last = first.clone();
+    while start.elapsed() < duration {
+        std::thread::sleep(SAMPLE_INTERVAL.min(duration.saturating_sub(start.elapsed())));
+
+        last = stats(client, id)?;
+        if last.refresh_rate > 0.0 {
+            jitter.push(last.jitter_us);
+        }
+    }
+    let elapsed = start.elapsed().as_secs_f64();
+
+    let frames = last.frames_presented.saturating_sub(first.frames_presented);
+
+    // the driver reports averages since the monitor was added, so the
+    // averages of this measurement are taken from the difference of the sums.
+    // The OS gives every frame a present time, so they're all counted
+    #[allow(clippy::cast_precision_loss)]
+    let window_average = |first_average: f64, last_average: f64| {
+        if frames == 0 {
+            return 0.0;
+        }
+
+        let first_sum = first_average * first.frames_presented as f64;
+        let last_sum = last_average * last.frames_presented as f64;
+        (last_sum - first_sum).max(0.0) / frames as f64
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let result = ModeResult {
+        width,
+        height,
+        refresh_rate,
+        frames,
+        dropped: last.frames_dropped.saturating_sub(first.frames_dropped),
+        fps: frames as f64 / elapsed,
+        present_latency_us: window_average(
+            first.avg_present_latency_us,
+            last.avg_present_latency_us,
+        ),
+        processing_us: window_average(first.avg_latency_us, last.avg_latency_us),
+        jitter_us: if jitter.is_empty() {
+            0.0
+        } else {
+            jitter.iter().sum::<f64>() / jitter.len() as f64
+        },
+    };
+
+    Ok(result)
+}
+
+fn stats(client: &mut Client, id: driver_ipc::Id) -> eyre::Result<driver_ipc::Stats> {
+    client
+        .stats(Some(id))?
+        .into_iter()
+        .next()
+        .ok_or_else(|| eyre::eyre!("driver has no statistics for virtual monitor {id}"))
+}
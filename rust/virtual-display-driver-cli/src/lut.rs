@@ -0,0 +1,172 @@
+//! Reading color lookup tables from .cube files, which most color grading and calibration tools
+//! export.
+
+use std::path::Path;
+
+use driver_ipc::Lut;
+use eyre::Context as _;
+
+pub fn read_cube(path: &Path) -> eyre::Result<Lut> {
+    let text = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read LUT {}", path.display()))?;
+
+    parse_cube(&text).wrap_err_with(|| format!("invalid LUT {}", path.display()))
+}
+
+fn parse_cube(text: &str) -> eyre::Result<Lut> {
+    let mut size_1d = None;
+    let mut size_3d = None;
+    let mut table = Vec::new();
+
+    for (number, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let values = &words[1..];
+        let number = number + 1;
+
+        match words[0] {
+            "TITLE" => (),
+            "LUT_1D_SIZE" => size_1d = Some(parse_size(values, number)?),
+            "LUT_3D_SIZE" => size_3d = Some(parse_size(values, number)?),
+            "DOMAIN_MIN" => check_domain(values, 0.0, number)?,
+            "DOMAIN_MAX" => check_domain(values, 1.0, number)?,
+            "LUT_1D_INPUT_RANGE" | "LUT_3D_INPUT_RANGE" => {
+                let range = parse_floats(values, number)?;
+                eyre::ensure!(
+                    range == [0.0, 1.0],
+                    "line {number}: only an input range of 0 to 1 is supported"
+                );
+            }
+            _ => {
+                let [r, g, b] = parse_floats(&words, number)?[..] else {
+                    eyre::bail!("line {number}: expected a red, green and blue value");
+                };
+                table.push([r, g, b].map(to_level));
+            }
+        }
+    }
+
+    let lut = match (size_1d, size_3d) {
+        (Some(size), None) => {
+            eyre::ensure!(
+                table.len() == size,
+                "expected {size} entries, found {}",
+                table.len()
+            );
+            Lut::OneD(table)
+        }
+        (None, Some(size)) => {
+            eyre::ensure!(
+                table.len() == size.pow(3),
+                "expected {} entries, found {}",
+                size.pow(3),
+                table.len()
+            );
+            Lut::ThreeD {
+                size: u32::try_from(size)?,
+                table,
+            }
+        }
+        (Some(_), Some(_)) => eyre::bail!("combined 1D and 3D LUTs aren't supported"),
+        (None, None) => eyre::bail!("missing LUT_1D_SIZE or LUT_3D_SIZE"),
+    };
+
+    eyre::ensure!(
+        lut.is_valid(),
+        "unsupported size, at most {} 1D or {} 3D entries are supported",
+        driver_ipc::MAX_LUT_1D_SIZE,
+        driver_ipc::MAX_LUT_3D_SIZE
+    );
+
+    Ok(lut)
+}
+
+fn parse_size(values: &[&str], number: usize) -> eyre::Result<usize> {
+    let [size] = values else {
+        eyre::bail!("line {number}: expected a size");
+    };
+
+    size.parse()
+        .wrap_err_with(|| format!("line {number}: invalid size {size}"))
+}
+
+fn parse_floats(values: &[&str], number: usize) -> eyre::Result<Vec<f32>> {
+    values
+        .iter()
+        .map(|value| {
+            value
+                .parse::<f32>()
+                .wrap_err_with(|| format!("line {number}: invalid value {value}"))
+        })
+        .collect()
+}
+
+// other domains would need the input to be scaled before looking it up
+fn check_domain(values: &[&str], expected: f32, number: usize) -> eyre::Result<()> {
+    let domain = parse_floats(values, number)?;
+    eyre::ensure!(
+        domain == [expected; 3],
+        "line {number}: only a domain of 0 to 1 is supported"
+    );
+
+    Ok(())
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn to_level(value: f32) -> u16 {
+    (value.clamp(0.0, 1.0) * f32::from(u16::MAX)).round() as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_d() {
+        let text = "\
+TITLE \"warm\"
+# comment
+LUT_3D_SIZE 2
+DOMAIN_MIN 0.0 0.0 0.0
+DOMAIN_MAX 1.0 1.0 1.0
+
+0 0 0
+1 0 0
+0 1 0
+1 1 0
+0 0 1
+1 0 1
+0 1 1
+1.2 1 0.5
+";
+
+        let Lut::ThreeD { size, table } = parse_cube(text).unwrap() else {
+            panic!("expected a 3D LUT");
+        };
+        assert_eq!(size, 2);
+        assert_eq!(table[1], [65535, 0, 0]);
+        assert_eq!(table[7], [65535, 65535, 32768]);
+    }
+
+    #[test]
+    fn one_d() {
+        let lut = parse_cube("LUT_1D_SIZE 2\n1 1 1\n0 0 0\n").unwrap();
+        assert_eq!(lut, Lut::OneD(vec![[65535; 3], [0; 3]]));
+    }
+
+    #[test]
+    fn wrong_entry_count() {
+        let error = parse_cube("LUT_3D_SIZE 2\n0 0 0\n").unwrap_err();
+        assert_eq!(error.to_string(), "expected 8 entries, found 1");
+    }
+
+    #[test]
+    fn other_domains() {
+        let error = parse_cube("LUT_1D_SIZE 2\nDOMAIN_MAX 2 2 2\n0 0 0\n1 1 1\n").unwrap_err();
+        assert!(error.to_string().contains("domain"), "{error}");
+    }
+}
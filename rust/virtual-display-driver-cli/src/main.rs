@@ -1,12 +1,37 @@
 use clap::Parser;
 use client::Client;
+use driver_ipc::edid;
+use eyre::Context as _;
 use joinery::JoinableIterator;
 use lazy_format::lazy_format;
 use owo_colors::OwoColorize;
 use serde::{Deserialize, Serialize};
 
+mod bench;
 mod client;
+mod compat;
+mod config;
+#[cfg(windows)]
+mod display;
+mod filter;
+#[cfg(windows)]
+mod hook;
+mod latency;
+mod lut;
 mod mode;
+#[cfg(windows)]
+mod physical;
+mod plan;
+mod preset;
+mod schedule;
+#[cfg(windows)]
+mod settings;
+#[cfg(windows)]
+mod stream;
+#[cfg(windows)]
+mod sunshine;
+mod template;
+mod trace_profile;
 
 #[derive(Debug, Parser)]
 struct Args {
@@ -22,29 +47,177 @@ struct GlobalOptions {
     /// Format output as JSON.
     #[clap(short, long)]
     json: bool,
+
+    /// Driver instance to manage, by index or pipe name as listed by
+    /// `instances`. Defaults to the stock driver instance.
+    #[clap(long, global = true)]
+    instance: Option<String>,
+
+    /// Connect to this pipe directly instead of looking up a driver
+    /// instance, e.g. the one of `vdd-emulator --pipe <NAME>`.
+    #[clap(long, global = true, conflicts_with = "instance")]
+    pipe: Option<String>,
+
+    /// How to talk to the driver. `ioctl` sends commands to the adapter's
+    /// device instead of its pipe, for systems where security software
+    /// blocks the driver from creating pipes.
+    #[clap(long, global = true, value_enum, default_value_t = Transport::Pipe)]
+    transport: Transport,
+
+    /// Connect to `vdd-emulator --listen <ADDR>` over TCP instead of the
+    /// driver, e.g. on other platforms than Windows.
+    #[clap(
+        long,
+        global = true,
+        value_name = "ADDR",
+        conflicts_with_all = ["instance", "pipe"]
+    )]
+    tcp: Option<String>,
+
+    /// Manage the driver of another machine through its `vdd-server
+    /// --agent`, at `HOST[:PORT]`. The port defaults to 9900.
+    #[clap(
+        long,
+        global = true,
+        value_name = "HOST[:PORT]",
+        requires = "key_file",
+        conflicts_with_all = ["instance", "pipe", "tcp"]
+    )]
+    host: Option<String>,
+
+    /// File with the key shared with the agent of `--host`.
+    #[clap(long, global = true, value_name = "PATH", requires = "host")]
+    key_file: Option<std::path::PathBuf>,
+
+    /// Fail on deprecated arguments instead of warning about them.
+    #[clap(long, global = true)]
+    strict: bool,
 }
 
 #[derive(Debug, Parser)]
 enum Command {
     /// List currently connected virtual monitors.
-    List,
+    List(ListCommand),
+    /// Show a single virtual monitor.
+    Get(GetCommand),
+    /// Show the EDID the driver presents a virtual monitor with, e.g. to
+    /// check that a custom EDID came through.
+    GetEdid(GetEdidCommand),
     /// Add a new virtual monitor.
     Add(AddCommand),
+    /// Make sure a virtual monitor with the given name and modes exists,
+    /// creating or updating it as needed.
+    Ensure(EnsureCommand),
+    /// Make the virtual monitors match a config file, creating and updating
+    /// them as needed.
+    Apply(ApplyCommand),
+    /// Load monitor profiles at login, at a time of day, or while a process
+    /// runs. Applied by `vdd-server --schedule`.
+    #[clap(subcommand)]
+    Schedule(ScheduleCommand),
+    /// Keep a virtual monitor enabled (or disabled) while a process runs,
+    /// e.g. for streaming hosts. Applied by `vdd-server --schedule`.
+    #[clap(subcommand)]
+    Rule(RuleCommand),
     /// Add a new resolution/refresh rate mode to an existing virtual monitor.
     AddMode(AddModeCommand),
     /// Remove a resolution/refresh rate mode to an existing virtual monitor.
     RemoveMode(RemoveModeCommand),
+    /// Switch an active virtual monitor to another of its modes, which
+    /// otherwise only the Display Settings can.
+    SwitchMode(SwitchModeCommand),
+    /// Limit the frame rate the driver processes for a virtual monitor,
+    /// to save GPU time on monitors that don't need every frame.
+    LimitFps(LimitFpsCommand),
+    /// Set the alias of a virtual monitor, which commands take instead of
+    /// its ID.
+    Alias(AliasCommand),
+    /// Scale the frames a virtual monitor shares with `--gpu-export` to a
+    /// fixed size, whatever mode Windows uses.
+    ExportSize(ExportSizeCommand),
     /// Enable a virtual monitor.
     Enable(EnableCommand),
     /// Disable a virtual monitor.
     Disable(DisableCommand),
     /// Remove one or more virtual monitors.
     Remove(RemoveCommand),
-    /// Remove all virtual monitors.
-    RemoveAll,
+    /// Plug in a virtual monitor added with `--no-auto-plug`.
+    Plug(PlugCommand),
+    /// Unplug a virtual monitor and plug it in again, for applications that
+    /// only look for displays when one is plugged in.
+    Replug(ReplugCommand),
+    /// Measure the round-trip latency and throughput of the driver pipe.
+    BenchIpc(BenchIpcCommand),
+    /// Measure the frame latency, jitter and sustained frame rate of a
+    /// virtual monitor in each of its modes.
+    Benchmark(BenchmarkCommand),
+    /// Show recent log messages from the driver.
+    Logs(LogsCommand),
+    /// Show frame statistics of virtual monitors.
+    Stats(StatsCommand),
+    /// Show recent driver events, such as recovered swap chain stalls.
+    Events(EventsCommand),
+    /// Show the name of the GPU texture a virtual monitor's frames are
+    /// shared in.
+    SharedFrame(SharedFrameCommand),
+    /// Choose what happens to the frames of a virtual monitor while the
+    /// consumer of its shared frames falls behind.
+    Backpressure(BackpressureCommand),
+    /// Show the brightness, contrast and gamma ramp set for a virtual
+    /// monitor, e.g. by night light or Monitorian, so clients showing its
+    /// frames can apply them.
+    Picture(PictureCommand),
+    /// Correct the colors of a virtual monitor's frames for the device
+    /// they're shown on with a lookup table, which `vdd-stream` applies.
+    #[clap(subcommand)]
+    Lut(LutCommand),
+    /// Show the hardware cursor of a virtual monitor, or draw it into its
+    /// shared frames. Needs `--hardware-cursor`.
+    #[clap(subcommand)]
+    Cursor(CursorCommand),
+    /// Serve virtual monitors over HTTP with `vdd-stream`, e.g. to use a
+    /// tablet's browser as a second display.
+    #[clap(subcommand)]
+    Stream(StreamCommand),
+    /// Set up a virtual monitor for another program, and tear it down again.
+    #[clap(subcommand)]
+    Setup(SetupCommand),
+    /// Turn physical displays off and on again, e.g. to only show virtual
+    /// monitors.
+    #[clap(subcommand)]
+    Physical(PhysicalCommand),
+    /// Load a profile before a game and undo it afterwards, from the launch
+    /// options or scripts of game launchers like Steam and Playnite.
+    #[clap(subcommand)]
+    Hook(HookCommand),
+    /// Run a command with a temporary virtual monitor, e.g. a GUI test suite
+    /// in CI. The monitor is removed when the command exits, and its exit
+    /// code is passed on.
+    WithMonitor(WithMonitorCommand),
+    /// Share a generated test pattern instead of the desktop of a virtual
+    /// monitor, to validate capture and encode pipelines.
+    TestPattern(TestPatternCommand),
+    /// Generate a WPR recording profile for the driver's ETW events.
+    TraceProfile(TraceProfileCommand),
+    /// Show the built-in monitor templates of `add --template`.
+    #[clap(subcommand)]
+    Templates(TemplatesCommand),
+    /// Print the JSON Schema of the driver's json or of `apply` config files,
+    /// to validate them in other tools.
+    Schema(SchemaCommand),
+    /// Let the os schedule the frame processing of a driver instance ahead
+    /// of other GPU work, for latency-sensitive streaming. The setting is
+    /// kept per instance, see `--instance`.
+    GpuPriority(GpuPriorityCommand),
+    /// List the detected virtual display adapters.
+    Instances,
+    /// Check the driver, and show optional features that aren't available
+    /// on this version of Windows.
+    Doctor,
 }
 
 #[derive(Debug, Parser)]
+#[allow(clippy::struct_excessive_bools)]
 struct AddCommand {
     /// One or more resolutions/refresh rates to add to the virtual monitor.
     /// Example values: `1920x1080`, `3840x2160@120`, `1280x720@60/120`.
@@ -59,9 +232,112 @@ struct AddCommand {
     #[clap(long)]
     name: Option<String>,
 
+    /// Unique slug like `living-room-tv`, which commands take instead of the
+    /// ID. Unlike the ID, it stays the same when the monitor is removed and
+    /// added again.
+    #[clap(long, value_parser = parse_alias)]
+    alias: Option<String>,
+
     /// Set the virtual monitor to disabled on creation.
     #[clap(long)]
     disabled: bool,
+
+    /// Tag the virtual monitor, to enable, disable, remove or list it with
+    /// others by tag. Can be given more than once.
+    #[clap(long)]
+    tag: Vec<String>,
+
+    /// Clone the EDID of a connected physical display, such as `DISPLAY2`,
+    /// so the virtual monitor looks like the real hardware.
+    #[clap(long, value_name = "DISPLAY")]
+    edid_from_display: Option<String>,
+
+    /// Advertise HDMI audio support in the virtual monitor's EDID, so audio
+    /// drivers and streaming hosts see it as audio-capable.
+    #[clap(long, conflicts_with = "edid_from_display")]
+    audio: bool,
+
+    /// Make the virtual monitor look like a common device, with its modes
+    /// and an EDID like its own, see `templates list`. Modes given as well
+    /// replace the template's.
+    #[clap(long, conflicts_with = "edid_from_display")]
+    template: Option<String>,
+
+    #[clap(flatten)]
+    edid: EdidOptions,
+
+    /// Don't restore the virtual monitor after a reboot, even if monitors
+    /// are persisted.
+    #[clap(long)]
+    ephemeral: bool,
+
+    /// Hand the cursor to the driver separately instead of drawing it into
+    /// the frames. Useful for consumers that draw their own pointer.
+    #[clap(long)]
+    hardware_cursor: bool,
+
+    /// Largest hardware cursor width and height, in pixels.
+    #[clap(long, default_value_t = 64, requires = "hardware_cursor")]
+    cursor_size: u32,
+
+    /// Hardware cursor formats to accept, other cursors are drawn into the
+    /// frames.
+    #[clap(long, value_enum, default_value_t = CursorFormat::Alpha, requires = "hardware_cursor")]
+    cursor_format: CursorFormat,
+
+    /// Only enable the virtual monitor while a remote desktop session is
+    /// connected. Requires `vdd-server --sessions` to be running.
+    #[clap(long)]
+    remote_session: bool,
+
+    /// Share the frames with other processes as a GPU texture, see
+    /// `shared-frame`.
+    #[clap(long)]
+    gpu_export: bool,
+
+    /// Keep the virtual monitor out of the desktop, as a render target for
+    /// VR and kiosk renderers that take it over. Its frames are still
+    /// processed, e.g. for `--gpu-export`.
+    #[clap(long)]
+    exclusive_capture: bool,
+
+    /// Only configure the virtual monitor in the driver, and plug it in
+    /// later with `plug`, which is faster than adding it then.
+    #[clap(long)]
+    no_auto_plug: bool,
+}
+
+#[derive(Debug, Parser)]
+struct EnsureCommand {
+    /// Name of the virtual monitor. An existing monitor with this name is
+    /// updated, otherwise a new one is added.
+    name: String,
+
+    /// One or more resolutions/refresh rates the virtual monitor should have.
+    /// Example values: `1920x1080`, `3840x2160@120`, `1280x720@60/120`.
+    mode: Vec<mode::Mode>,
+
+    /// The virtual monitor should be disabled.
+    #[clap(long)]
+    disabled: bool,
+
+    /// Only show the planned changes, don't apply them.
+    #[clap(long)]
+    dry_run: bool,
+}
+
+#[derive(Debug, Parser)]
+struct ApplyCommand {
+    /// YAML (or JSON) file describing the desired virtual monitors.
+    config: std::path::PathBuf,
+
+    /// Also remove virtual monitors that aren't in the config file.
+    #[clap(long)]
+    prune: bool,
+
+    /// Only show the planned changes, don't apply them.
+    #[clap(long)]
+    dry_run: bool,
 }
 
 #[derive(Debug, Parser)]
@@ -87,278 +363,2950 @@ struct RemoveModeCommand {
 }
 
 #[derive(Debug, Parser)]
-struct EnableCommand {
-    // The ID or name of the monitor to enable.
+struct SwitchModeCommand {
+    /// ID or name of the virtual monitor to switch.
     id: String,
+
+    /// One of the virtual monitor's modes, which becomes its preferred
+    /// one. Omitting the refresh rate picks the first one of the
+    /// resolution. Example values: `1280x720`, `1920x1080@120`.
+    mode: mode::Mode,
 }
 
 #[derive(Debug, Parser)]
-struct DisableCommand {
-    // The ID or name of the monitor to disable.
+struct LimitFpsCommand {
+    /// ID or name of the virtual monitor to limit.
     id: String,
-}
 
-#[derive(Debug, Parser)]
-struct RemoveCommand {
-    // One or more monitor IDs or names to remove.
-    id: Vec<String>,
+    /// Highest frame rate to process, or `off` to remove the limit.
+    fps: FpsLimit,
 }
 
-fn main() -> eyre::Result<()> {
-    let Args { options, command } = Args::parse();
-    let mut client = Client::connect()?;
+#[derive(Debug, Clone, Copy)]
+struct FpsLimit(Option<u32>);
 
-    match command {
-        Command::List => {
-            list(&mut client, &options)?;
-        }
-        Command::Add(command) => {
-            add(&mut client, &options, command)?;
-        }
-        Command::AddMode(command) => {
-            add_mode(&mut client, &options, command)?;
-        }
-        Command::RemoveMode(command) => {
-            remove_mode(&mut client, &options, &command)?;
-        }
-        Command::Enable(command) => {
-            enable(&mut client, &options, &command)?;
-        }
-        Command::Disable(command) => {
-            disable(&mut client, &options, &command)?;
-        }
-        Command::Remove(command) => {
-            remove(&mut client, &options, &command)?;
+impl std::str::FromStr for FpsLimit {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("off") {
+            return Ok(Self(None));
         }
-        Command::RemoveAll => {
-            remove_all(&mut client, &options)?;
+
+        match s.parse::<u32>() {
+            Ok(0) | Err(_) => Err("expected a frame rate above 0, or `off`".to_owned()),
+            Ok(fps) => Ok(Self(Some(fps))),
         }
     }
-
-    Ok(())
 }
 
-fn list(client: &mut Client, opts: &GlobalOptions) -> eyre::Result<()> {
-    let monitors = client.monitors();
+#[derive(Debug, Parser)]
+struct AliasCommand {
+    /// ID, alias or name of the virtual monitor.
+    id: String,
 
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &monitors)?;
-    } else if !monitors.is_empty() {
-        println!("{}", "Virtual monitors".underline());
-        for (i, monitor) in monitors.iter().enumerate() {
-            if i > 0 {
-                println!();
-            }
+    /// Unique slug like `living-room-tv`, or `off` to remove the alias.
+    alias: AliasArg,
+}
 
-            let name_label = lazy_format!(match (&monitor.name) {
-                Some(name) => (" {}{name}{}", "[".dimmed(), "]".dimmed()),
-                None => "",
-            });
-            let disabled_label = lazy_format!(if monitor.enabled => ""
-            else =>
-                (" {}", "(disabled)".red())
-            );
-            println!(
-                "Monitor {}{name_label}{disabled_label}:",
-                monitor.id.green(),
-            );
+#[derive(Debug, Clone)]
+struct AliasArg(Option<String>);
 
-            if monitor.modes.is_empty() {
-                println!("{} {}", "-".dimmed(), "No modes".red());
-            } else {
-                for mode in &monitor.modes {
-                    let refresh_rate_labels = mode
-                        .refresh_rates
-                        .iter()
-                        .map(|rate| lazy_format!("{}", rate.blue()))
-                        .join_with("/");
-                    println!(
-                        "{} {}{}{}{}{}",
-                        "-".dimmed(),
-                        mode.width.green(),
-                        "x".dimmed(),
-                        mode.height.green(),
-                        "@".dimmed(),
-                        refresh_rate_labels,
-                    );
-                }
-            }
+impl std::str::FromStr for AliasArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "off" {
+            return Ok(Self(None));
         }
-    } else {
-        println!("No virtual monitors found.");
-    }
 
-    Ok(())
+        parse_alias(s).map(|alias| Self(Some(alias)))
+    }
 }
 
-fn add(client: &mut Client, opts: &GlobalOptions, command: AddCommand) -> eyre::Result<()> {
-    let modes = command
-        .mode
-        .into_iter()
-        .map(driver_ipc::Mode::from)
-        .collect::<Vec<_>>();
-
-    let id = client.new_id(command.id)?;
-    let new_monitor = driver_ipc::Monitor {
-        id,
-        enabled: !command.disabled,
-        name: command.name,
-        modes,
-    };
-    client.notify(vec![new_monitor])?;
-
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &id)?;
+fn parse_alias(s: &str) -> Result<String, String> {
+    if driver_ipc::is_valid_alias(s) {
+        Ok(s.to_owned())
     } else {
-        let disabled_footnote = lazy_format!(
-            if command.disabled => (" {}", "(disabled)".red())
-            else => ""
-        );
-        println!(
-            "Added virtual monitor with ID {}{disabled_footnote}.",
-            id.green()
-        );
+        Err(format!(
+            "expected lowercase letters, digits, `-` and `_`, starting with a letter and at most {} long, like `living-room-tv`",
+            driver_ipc::MAX_ALIAS_LEN
+        ))
     }
+}
 
-    Ok(())
+#[derive(Debug, Parser)]
+struct ExportSizeCommand {
+    /// ID or name of the virtual monitor to scale the frames of.
+    id: String,
+
+    /// Size to share frames at, like `1920x1080`, or `off` to share them at
+    /// the size of the mode.
+    size: ExportSizeArg,
+
+    /// Stretch frames to the size instead of keeping their aspect ratio with
+    /// black bars.
+    #[clap(long)]
+    stretch: bool,
 }
 
-fn add_mode(
-    client: &mut Client,
-    opts: &GlobalOptions,
-    command: AddModeCommand,
-) -> eyre::Result<()> {
-    let mut monitor = client.find_monitor(&command.id)?;
+#[derive(Debug, Clone, Copy)]
+struct ExportSizeArg(Option<(u32, u32)>);
 
-    let existing_modes = monitor.modes.iter().cloned().map(mode::Mode::from);
-    let new_modes = mode::merge(existing_modes.chain(command.mode));
-    let new_modes: Vec<driver_ipc::Mode> =
-        new_modes.into_iter().map(driver_ipc::Mode::from).collect();
+impl std::str::FromStr for ExportSizeArg {
+    type Err = String;
 
-    monitor.modes = new_modes.clone();
-    client.notify(vec![monitor.clone()])?;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("off") {
+            return Ok(Self(None));
+        }
 
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &new_modes)?;
-    } else {
-        println!(
-            "Added modes to virtual monitor with ID {}.",
-            monitor.id.green()
-        );
+        let size = s
+            .split_once('x')
+            .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)));
+
+        match size {
+            Some((width, height)) if width > 0 && height > 0 => Ok(Self(Some((width, height)))),
+            _ => Err("expected a size like `1920x1080`, or `off`".to_owned()),
+        }
     }
+}
 
-    Ok(())
+#[derive(Debug, Parser)]
+#[allow(clippy::struct_field_names)]
+struct EdidOptions {
+    /// Three letter PNP id of the manufacturer to put in the generated
+    /// EDID, such as `DEL`.
+    #[clap(long, value_name = "PNP", conflicts_with = "edid_from_display")]
+    edid_vendor: Option<String>,
+
+    /// Product code to put in the generated EDID.
+    #[clap(long, conflicts_with = "edid_from_display")]
+    edid_product: Option<u16>,
+
+    /// Physical size to put in the generated EDID, in centimeters, like
+    /// `60x34`.
+    #[clap(long, value_name = "WxH", conflicts_with = "edid_from_display")]
+    edid_size: Option<ScreenSizeArg>,
 }
 
-fn remove_mode(
-    client: &mut Client,
-    opts: &GlobalOptions,
-    command: &RemoveModeCommand,
-) -> eyre::Result<()> {
-    let mut monitor = client.find_monitor(&command.id)?;
+#[derive(Debug, Clone, Copy)]
+struct ScreenSizeArg(u8, u8);
 
-    let modes = monitor.modes.iter().cloned().map(mode::Mode::from);
-    let new_modes = mode::remove(modes, &command.mode)?;
-    let new_modes: Vec<driver_ipc::Mode> =
-        new_modes.into_iter().map(driver_ipc::Mode::from).collect();
+impl std::str::FromStr for ScreenSizeArg {
+    type Err = String;
 
-    monitor.modes = new_modes.clone();
-    client.notify(vec![monitor.clone()])?;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let size = s
+            .split_once('x')
+            .and_then(|(width, height)| Some((width.parse().ok()?, height.parse().ok()?)));
 
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &new_modes)?;
-    } else {
-        println!(
-            "Removed mode {} from virtual monitor with ID {}.",
-            command.mode.blue(),
-            monitor.id.green()
-        );
+        match size {
+            Some((width, height)) if width > 0 && height > 0 => Ok(Self(width, height)),
+            _ => Err("expected a size in centimeters like `60x34`, at most `255x255`".to_owned()),
+        }
     }
-
-    Ok(())
 }
 
-fn enable(client: &mut Client, opts: &GlobalOptions, command: &EnableCommand) -> eyre::Result<()> {
-    let outcome = set_enabled(client, &command.id, true)?;
+#[derive(Debug, Parser)]
+struct ListCommand {
+    /// Also show what the driver reports, like the mode Windows currently
+    /// uses for each virtual monitor.
+    #[clap(long, short)]
+    verbose: bool,
 
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &outcome)?;
-    } else {
-        let footnote = if outcome.toggled {
-            ""
-        } else {
-            " (was already enabled)"
-        };
-        println!(
-            "Enabled virtual monitor with ID {}{footnote}.",
-            outcome.monitor.id.green()
-        );
-    }
+    #[clap(flatten)]
+    filter: filter::MonitorFilter,
+}
 
-    Ok(())
+#[derive(Debug, Parser)]
+struct GetCommand {
+    // The ID or name of the monitor to show.
+    id: String,
 }
 
-fn disable(
-    client: &mut Client,
-    opts: &GlobalOptions,
-    command: &DisableCommand,
-) -> eyre::Result<()> {
-    let outcome = set_enabled(client, &command.id, false)?;
+#[derive(Debug, Parser)]
+struct GetEdidCommand {
+    /// ID or name of the virtual monitor, which must be plugged in.
+    id: String,
 
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &outcome)?;
-    } else {
-        let footnote = if outcome.toggled {
-            ""
-        } else {
-            " (was already disabled)"
-        };
-        println!(
-            "Disabled virtual monitor with ID {}{footnote}.",
-            outcome.monitor.id.green()
-        );
-    }
+    /// File to write the EDID to, instead of printing its bytes.
+    #[clap(short, long)]
+    out: Option<std::path::PathBuf>,
 
-    Ok(())
+    /// Print what's in the EDID, such as its name, timings and extension
+    /// blocks.
+    #[clap(long)]
+    decode: bool,
 }
 
-fn remove(client: &mut Client, opts: &GlobalOptions, command: &RemoveCommand) -> eyre::Result<()> {
-    let monitor_ids = command
-        .id
-        .iter()
-        .map(|query| {
-            let monitor = client.find_monitor(query)?;
-            eyre::Ok(monitor.id)
+#[derive(Debug, Parser)]
+struct EnableCommand {
+    // The ID or name of the monitor to enable.
+    #[clap(required_unless_present = "tag")]
+    id: Option<String>,
+
+    /// Enable every virtual monitor with this tag instead.
+    #[clap(long, conflicts_with = "id")]
+    tag: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct PlugCommand {
+    // The ID or name of the monitor to plug in.
+    id: String,
+}
+
+#[derive(Debug, Parser)]
+struct ReplugCommand {
+    // The ID or name of the monitor to replug.
+    id: String,
+}
+
+#[derive(Debug, Parser)]
+struct GpuPriorityCommand {
+    #[clap(value_enum)]
+    priority: GpuPriority,
+}
+
+#[derive(Debug, Parser)]
+struct DisableCommand {
+    // The ID or name of the monitor to disable.
+    #[clap(required_unless_present = "tag")]
+    id: Option<String>,
+
+    /// Disable every virtual monitor with this tag instead.
+    #[clap(long, conflicts_with = "id")]
+    tag: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct RemoveCommand {
+    // One or more monitor IDs or names to remove.
+    #[clap(required_unless_present_any = ["all", "tag"])]
+    id: Vec<String>,
+
+    /// Remove all virtual monitors.
+    #[clap(long, conflicts_with = "id")]
+    all: bool,
+
+    /// Remove every virtual monitor with this tag.
+    #[clap(long, conflicts_with_all = ["id", "all"])]
+    tag: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct BenchIpcCommand {
+    /// Number of round trips to measure for each payload size.
+    #[clap(long, default_value_t = 1000)]
+    iterations: usize,
+
+    /// Comma-separated payload sizes in bytes to benchmark. Defaults to
+    /// `0,1024,16384,65536`.
+    #[clap(long, value_delimiter = ',')]
+    sizes: Vec<usize>,
+}
+
+#[derive(Debug, Parser)]
+struct BenchmarkCommand {
+    /// ID or name of the virtual monitor to benchmark. Something has to be
+    /// animating on it, e.g. a video, or it won't receive any frames.
+    id: String,
+
+    /// Seconds to measure each mode for.
+    #[clap(long, default_value_t = 5)]
+    duration: u64,
+
+    /// Seconds to wait after switching modes before measuring.
+    #[clap(long, default_value_t = 2)]
+    settle: u64,
+}
+
+#[derive(Debug, Parser)]
+struct LogsCommand {
+    /// Keep running and print new log messages as they arrive.
+    #[clap(short, long)]
+    follow: bool,
+
+    /// Change the driver's log level first. The new level stays in effect
+    /// until it's changed again or the driver restarts.
+    #[clap(long, value_enum)]
+    level: Option<LogLevel>,
+}
+
+#[derive(Debug, Parser)]
+struct StatsCommand {
+    /// ID or name of the virtual monitor to show statistics for. Shows all
+    /// virtual monitors if omitted.
+    id: Option<String>,
+
+    /// Keep running and print updated statistics every second.
+    #[clap(short, long)]
+    follow: bool,
+
+    /// Also show how often the driver's `IddCx` callbacks succeeded and
+    /// failed, to spot chronic low-level failures.
+    #[clap(short, long)]
+    detailed: bool,
+}
+
+#[derive(Debug, Parser)]
+struct EventsCommand {
+    /// Keep running and print new events as they arrive.
+    #[clap(short, long)]
+    follow: bool,
+}
+
+#[derive(Debug, Parser)]
+struct SharedFrameCommand {
+    /// ID or name of the virtual monitor, which needs `--gpu-export`.
+    id: String,
+
+    /// Ask the driver to share frames in this format from the next frame
+    /// on, e.g. `nv12` for hardware encoders. Stays set until another
+    /// format is asked for.
+    #[clap(long, value_enum)]
+    format: Option<FrameFormat>,
+
+    /// Ask the driver to hand frames over with a keyed mutex or a shared
+    /// fence from the next frame on, e.g. `fence` for D3D12 and Vulkan
+    /// consumers. Stays set until another one is asked for.
+    #[clap(long, value_enum)]
+    sync: Option<FrameSync>,
+
+    /// Milliseconds the consumer may keep a frame before the driver gives
+    /// up on it and shares frames in a new texture. 0 waits forever.
+    #[clap(long, default_value_t = 0, requires = "sync")]
+    timeout_ms: u32,
+
+    /// Get the ring of textures the frames are shared in for several
+    /// consumers at once instead, e.g. OBS next to an encoder. The driver
+    /// starts filling it with the next frame.
+    #[clap(long)]
+    ring: bool,
+}
+
+#[derive(Debug, Parser)]
+struct BackpressureCommand {
+    /// ID or name of the virtual monitor, which needs `--gpu-export`.
+    id: String,
+
+    /// `drop-newest` skips new frames until the consumer takes the last
+    /// one, `drop-oldest` replaces the frame it hasn't taken yet, `block`
+    /// holds up the monitor for up to `--timeout-ms`.
+    policy: BackpressurePolicy,
+
+    /// Milliseconds `block` waits for the consumer before dropping the
+    /// frame.
+    #[clap(long, default_value_t = 16)]
+    timeout_ms: u32,
+}
+
+#[derive(Debug, Parser)]
+struct PictureCommand {
+    /// ID or name of the virtual monitor.
+    id: String,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum LutCommand {
+    /// Set the lookup table of a virtual monitor from a .cube file. It's
+    /// kept until the driver restarts.
+    Set(LutSetCommand),
+    /// Remove the lookup table of a virtual monitor.
+    Clear(LutClearCommand),
+}
+
+#[derive(Debug, Parser)]
+struct LutSetCommand {
+    /// ID or name of the virtual monitor.
+    id: String,
+
+    /// 1D or 3D lookup table in the .cube format.
+    file: std::path::PathBuf,
+}
+
+#[derive(Debug, Parser)]
+struct LutClearCommand {
+    /// ID or name of the virtual monitor.
+    id: String,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum CursorCommand {
+    /// Show the position and shape of the hardware cursor of a virtual
+    /// monitor.
+    Show(CursorIdCommand),
+    /// Draw the hardware cursor into the shared frames of a virtual monitor,
+    /// for consumers that don't draw it themselves. Only frames in the
+    /// monitor's size and in bgra get the cursor.
+    Composite(CursorIdCommand),
+    /// Stop drawing the hardware cursor into the shared frames, consumers
+    /// get it with `cursor show` instead.
+    Separate(CursorIdCommand),
+}
+
+#[derive(Debug, Parser)]
+struct CursorIdCommand {
+    /// ID or name of the virtual monitor.
+    id: String,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum StreamCommand {
+    /// Start serving a virtual monitor, which needs `--gpu-export`.
+    Start(StreamStartCommand),
+    /// Stop serving a virtual monitor.
+    Stop(StreamStopCommand),
+    /// List the virtual monitors being served.
+    List,
+}
+
+#[derive(Debug, Parser)]
+struct StreamStartCommand {
+    /// ID or name of the virtual monitor to serve.
+    id: String,
+
+    /// Port to serve the virtual monitor on.
+    #[clap(long, default_value_t = 8554)]
+    port: u16,
+
+    /// Token clients have to pass to watch, at least 16 characters long. A
+    /// random one is generated if omitted.
+    #[clap(long)]
+    token: Option<String>,
+
+    /// JPEG quality of the frames, from 1 to 100.
+    #[clap(long, default_value_t = 80, value_parser = clap::value_parser!(u8).range(1..=100))]
+    quality: u8,
+}
+
+#[derive(Debug, Parser)]
+struct StreamStopCommand {
+    /// ID or name of the virtual monitor to stop serving.
+    id: String,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum SetupCommand {
+    /// Add a virtual monitor in the mode of a Moonlight client and make it
+    /// the primary display, as Sunshine's "do" prep command. Use `--undo` as
+    /// its "undo" command.
+    Sunshine(SunshineCommand),
+    /// Set up a virtual monitor for a popular streaming client in one go:
+    /// made from its template, in the mode, scale and HDR it looks best
+    /// with.
+    Client(ClientCommand),
+}
+
+#[derive(Debug, Parser)]
+struct ClientCommand {
+    /// The streaming client.
+    #[clap(value_enum)]
+    client: preset::StreamingClient,
+
+    /// Name of the virtual monitor. An existing monitor with this name is
+    /// reused, otherwise one is added. Defaults to the client's template.
+    #[clap(long)]
+    name: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct SunshineCommand {
+    /// Resolution and refresh rate of the client, e.g. `2560x1440@120`. Taken
+    /// from the `SUNSHINE_CLIENT_*` variables Sunshine sets for prep commands
+    /// if omitted.
+    mode: Option<mode::Mode>,
+
+    /// Name of the virtual monitor. An existing monitor with this name is
+    /// reused, otherwise one is added.
+    #[clap(long, default_value = "Sunshine")]
+    name: String,
+
+    /// Turn off the displays that aren't virtual monitors while streaming.
+    #[clap(long)]
+    disable_physical: bool,
+
+    /// Remove the virtual monitor added by the last setup (or restore the
+    /// reused one), and restore the layout of the displays.
+    #[clap(long, conflicts_with_all = ["mode", "disable_physical"])]
+    undo: bool,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum PhysicalCommand {
+    /// List the physical displays, and the ones turned off.
+    List,
+    /// Turn off physical displays.
+    Disable(PhysicalDisableCommand),
+    /// Turn physical displays turned off with `physical disable` on again.
+    Enable(PhysicalEnableCommand),
+}
+
+#[derive(Debug, Parser)]
+struct PhysicalDisableCommand {
+    /// One or more displays to turn off, such as `DISPLAY1`, see `physical
+    /// list`.
+    #[clap(required = true)]
+    displays: Vec<String>,
+}
+
+#[derive(Debug, Parser)]
+struct PhysicalEnableCommand {
+    /// Displays to turn on again. All displays turned off with `physical
+    /// disable` if omitted.
+    displays: Vec<String>,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum HookCommand {
+    /// Load a profile before the game starts. It's undone by `hook
+    /// post-launch`, or by `vdd-server --schedule` once the launcher exits.
+    PreLaunch(HookPreLaunchCommand),
+    /// Undo the profile of `hook pre-launch` after the game exited.
+    PostLaunch,
+}
+
+#[derive(Debug, Parser)]
+struct HookPreLaunchCommand {
+    /// Json file with the monitors to load, as printed by `list --json`.
+    #[clap(long)]
+    profile: std::path::PathBuf,
+
+    /// PID of the launcher, which has to run as long as the game. Defaults
+    /// to the process that runs the hook.
+    #[clap(long)]
+    pid: Option<u32>,
+}
+
+#[derive(Debug, Parser)]
+struct WithMonitorCommand {
+    /// One or more resolutions/refresh rates of the virtual monitor, the
+    /// first one is used.
+    #[clap(required = true)]
+    mode: Vec<mode::Mode>,
+
+    /// Optional label for the virtual monitor.
+    #[clap(long)]
+    name: Option<String>,
+
+    /// Seconds to wait for Windows to show the virtual monitor before giving
+    /// up.
+    #[clap(long, default_value_t = 30)]
+    timeout: u64,
+
+    /// The command to run, and its arguments. Its environment has the
+    /// monitor's ID in `VDD_MONITOR_ID`.
+    #[clap(last = true, required = true)]
+    command: Vec<String>,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum ScheduleCommand {
+    /// List the scheduled profiles.
+    List,
+    /// Schedule a profile.
+    Add(ScheduleAddCommand),
+    /// Remove a scheduled profile.
+    Remove(ScheduleRemoveCommand),
+}
+
+#[derive(Debug, Parser)]
+#[clap(group(clap::ArgGroup::new("trigger").required(true)))]
+struct ScheduleAddCommand {
+    /// JSON file with the monitors to load, as printed by `list --json`.
+    profile: std::path::PathBuf,
+
+    /// Load the profile when `vdd-server` starts, replacing all monitors.
+    #[clap(long, group = "trigger")]
+    login: bool,
+
+    /// Load the profile every day at this local time, e.g. `18:00`,
+    /// replacing all monitors.
+    #[clap(long, group = "trigger", value_name = "TIME", value_parser = schedule::parse_time)]
+    at: Option<driver_ipc::Trigger>,
+
+    /// Add the profile's monitors while a process with this executable name
+    /// runs, e.g. `sunshine.exe`, and remove them when it exits.
+    #[clap(long, group = "trigger", value_name = "NAME")]
+    process: Option<String>,
+}
+
+#[derive(Debug, Parser)]
+struct ScheduleRemoveCommand {
+    /// Index of the scheduled profile, as shown by `schedule list`.
+    index: usize,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum RuleCommand {
+    /// List the rules.
+    List,
+    /// Add a rule.
+    Add(RuleAddCommand),
+    /// Remove a rule.
+    Remove(RuleRemoveCommand),
+}
+
+#[derive(Debug, Parser)]
+struct RuleAddCommand {
+    /// Executable name of the process, e.g. `parsecd.exe`.
+    process: String,
+
+    /// ID or name of the virtual monitor. It doesn't have to exist yet.
+    monitor: String,
+
+    /// Disable the virtual monitor while the process runs, instead of
+    /// enabling it.
+    #[clap(long)]
+    disable: bool,
+}
+
+#[derive(Debug, Parser)]
+struct RuleRemoveCommand {
+    /// Index of the rule, as shown by `rule list`.
+    index: usize,
+}
+
+#[derive(Debug, Parser)]
+struct TestPatternCommand {
+    /// ID or name of the virtual monitor, which needs `--gpu-export`.
+    id: String,
+
+    /// Pattern to share, or `off` to share the desktop again. Every
+    /// pattern shows a frame counter, to measure latency end to end.
+    #[clap(value_enum)]
+    pattern: TestPattern,
+}
+
+#[derive(Debug, Parser)]
+struct TraceProfileCommand {
+    /// File to write the profile to, e.g. `vdd.wprp`. Prints to stdout if
+    /// omitted.
+    #[clap(short, long)]
+    output: Option<std::path::PathBuf>,
+
+    /// Leave out the per-frame events, for long recordings.
+    #[clap(long)]
+    no_frames: bool,
+}
+
+#[derive(Debug, clap::Subcommand)]
+enum TemplatesCommand {
+    /// List the monitor templates, with their modes.
+    List,
+}
+
+#[derive(Debug, Parser)]
+struct SchemaCommand {
+    /// Which format to describe.
+    #[clap(value_enum)]
+    kind: SchemaKind,
+
+    /// File to write the schema to. Prints to stdout if omitted.
+    #[clap(short, long)]
+    output: Option<std::path::PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum SchemaKind {
+    /// A monitor, as shown by `list --json`.
+    Monitor,
+    /// A mode of a monitor.
+    Mode,
+    /// The commands sent to and received from the driver.
+    Command,
+    /// Config files read by `apply`.
+    Config,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum CursorFormat {
+    Monochrome,
+    Alpha,
+    AlphaXor,
+}
+
+impl From<CursorFormat> for driver_ipc::CursorFormat {
+    fn from(value: CursorFormat) -> Self {
+        match value {
+            CursorFormat::Monochrome => Self::Monochrome,
+            CursorFormat::Alpha => Self::Alpha,
+            CursorFormat::AlphaXor => Self::AlphaXor,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum TestPattern {
+    Bars,
+    Gradient,
+    MovingBox,
+    Off,
+}
+
+impl From<TestPattern> for Option<driver_ipc::TestPattern> {
+    fn from(value: TestPattern) -> Self {
+        match value {
+            TestPattern::Bars => Some(driver_ipc::TestPattern::Bars),
+            TestPattern::Gradient => Some(driver_ipc::TestPattern::Gradient),
+            TestPattern::MovingBox => Some(driver_ipc::TestPattern::MovingBox),
+            TestPattern::Off => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum FrameFormat {
+    Bgra,
+    Nv12,
+    P010,
+}
+
+impl From<FrameFormat> for driver_ipc::FrameFormat {
+    fn from(value: FrameFormat) -> Self {
+        match value {
+            FrameFormat::Bgra => Self::Bgra,
+            FrameFormat::Nv12 => Self::Nv12,
+            FrameFormat::P010 => Self::P010,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum FrameSync {
+    KeyedMutex,
+    Fence,
+}
+
+impl From<FrameSync> for driver_ipc::FrameSync {
+    fn from(value: FrameSync) -> Self {
+        match value {
+            FrameSync::KeyedMutex => Self::KeyedMutex,
+            FrameSync::Fence => Self::Fence,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum BackpressurePolicy {
+    DropNewest,
+    DropOldest,
+    Block,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Transport {
+    Pipe,
+    Ioctl,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum GpuPriority {
+    Normal,
+    Realtime,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for driver_ipc::LogLevel {
+    fn from(value: LogLevel) -> Self {
+        match value {
+            LogLevel::Error => Self::Error,
+            LogLevel::Warn => Self::Warn,
+            LogLevel::Info => Self::Info,
+            LogLevel::Debug => Self::Debug,
+            LogLevel::Trace => Self::Trace,
+        }
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn main() -> eyre::Result<()> {
+    let (args, deprecations) = compat::migrate(std::env::args_os());
+    let Args { options, command } = Args::parse_from(args);
+    compat::report(&deprecations, &options)?;
+
+    // these change this machine, which an agent can't do for them
+    if options.host.is_some()
+        && matches!(
+            command,
+            Command::TraceProfile(_)
+                | Command::Schedule(_)
+                | Command::Rule(_)
+                | Command::Instances
+                | Command::Stream(_)
+                | Command::Setup(_)
+                | Command::Physical(_)
+                | Command::Hook(_)
+        )
+    {
+        eyre::bail!("this command doesn't work with --host, run it on the remote machine");
+    }
+
+    // these don't talk to a driver instance, so they work without one running
+    match &command {
+        Command::TraceProfile(command) => return trace_profile(command),
+        Command::Schema(command) => return schema(command),
+        Command::Templates(command) => return templates(&options, command),
+        #[cfg(windows)]
+        Command::Schedule(command) => return schedule(&options, command),
+        #[cfg(windows)]
+        Command::Rule(command) => return rule(&options, command),
+        #[cfg(windows)]
+        Command::Instances => return instances(&options),
+        #[cfg(not(windows))]
+        Command::Schedule(_) | Command::Rule(_) | Command::Instances => {
+            eyre::bail!("this command only works on Windows");
+        }
+        _ => {}
+    }
+
+    let mut client = connect(&options)?;
+
+    match command {
+        Command::List(command) => {
+            list(&mut client, &options, &command)?;
+        }
+        Command::Get(command) => {
+            get(&mut client, &options, &command)?;
+        }
+        Command::GetEdid(command) => {
+            get_edid(&mut client, &options, &command)?;
+        }
+        Command::Add(command) => {
+            add(&mut client, &options, command)?;
+        }
+        Command::Ensure(command) => {
+            ensure(&mut client, &options, command)?;
+        }
+        Command::Apply(command) => {
+            apply(&mut client, &options, &command)?;
+        }
+        Command::AddMode(command) => {
+            add_mode(&mut client, &options, command)?;
+        }
+        Command::RemoveMode(command) => {
+            remove_mode(&mut client, &options, &command)?;
+        }
+        Command::SwitchMode(command) => {
+            switch_mode(&mut client, &options, &command)?;
+        }
+        Command::LimitFps(command) => {
+            limit_fps(&mut client, &options, &command)?;
+        }
+        Command::Alias(command) => {
+            alias(&mut client, &options, &command)?;
+        }
+        Command::ExportSize(command) => {
+            export_size(&mut client, &options, &command)?;
+        }
+        Command::Enable(command) => {
+            enable(&mut client, &options, &command)?;
+        }
+        Command::Disable(command) => {
+            disable(&mut client, &options, &command)?;
+        }
+        Command::Remove(command) => {
+            remove(&mut client, &options, &command)?;
+        }
+        Command::Plug(command) => {
+            plug(&mut client, &options, &command)?;
+        }
+        Command::Replug(command) => {
+            replug(&mut client, &options, &command)?;
+        }
+        Command::BenchIpc(command) => {
+            bench_ipc(&mut client, &options, &command)?;
+        }
+        Command::Benchmark(command) => {
+            benchmark(&mut client, &options, &command)?;
+        }
+        Command::Logs(command) => {
+            logs(&mut client, &options, &command)?;
+        }
+        Command::Stats(command) => {
+            stats(&mut client, &options, &command)?;
+        }
+        Command::Events(command) => {
+            events(&mut client, &options, &command)?;
+        }
+        Command::SharedFrame(command) => {
+            shared_frame(&mut client, &options, &command)?;
+        }
+        Command::Backpressure(command) => {
+            backpressure(&mut client, &options, &command)?;
+        }
+        Command::Picture(command) => {
+            picture(&mut client, &options, &command)?;
+        }
+        Command::Doctor => {
+            doctor(&mut client, &options)?;
+        }
+        Command::GpuPriority(command) => {
+            gpu_priority(&mut client, &options, &command)?;
+        }
+        Command::TestPattern(command) => {
+            test_pattern(&mut client, &options, &command)?;
+        }
+        Command::Lut(command) => {
+            lut(&mut client, &options, &command)?;
+        }
+        Command::Cursor(command) => {
+            cursor(&mut client, &options, &command)?;
+        }
+        #[cfg(windows)]
+        Command::Stream(command) => {
+            stream(client, &options, command)?;
+        }
+        #[cfg(windows)]
+        Command::Setup(command) => {
+            setup(&mut client, &options, command)?;
+        }
+        #[cfg(windows)]
+        Command::Physical(command) => {
+            physical(&mut client, &options, &command)?;
+        }
+        #[cfg(windows)]
+        Command::Hook(command) => {
+            hook(&mut client, &options, &command)?;
+        }
+        // these change this machine's displays, which only Windows has
+        #[cfg(not(windows))]
+        Command::Stream(_) | Command::Setup(_) | Command::Physical(_) | Command::Hook(_) => {
+            eyre::bail!("this command only works on Windows");
+        }
+        Command::WithMonitor(command) => {
+            with_monitor(client, &options, command)?;
+        }
+        Command::TraceProfile(_)
+        | Command::Schema(_)
+        | Command::Templates(_)
+        | Command::Schedule(_)
+        | Command::Rule(_)
+        | Command::Instances => {
+            unreachable!("handled before connecting")
+        }
+    }
+
+    Ok(())
+}
+
+/// Connect to the driver instance, or the agent, selected by the global
+/// options.
+fn connect(options: &GlobalOptions) -> eyre::Result<Client> {
+    let remote = options.host.as_deref().zip(options.key_file.as_deref());
+    if let Some((host, key_file)) = remote {
+        return Client::connect_remote(host, key_file);
+    }
+    if let Some(addr) = &options.tcp {
+        return Client::connect_tcp(addr);
+    }
+
+    #[cfg(not(windows))]
+    eyre::bail!("only --tcp and --host connect to a driver on other platforms than Windows");
+
+    #[cfg(windows)]
+    match options.transport {
+        Transport::Pipe => {
+            let pipe_name = match &options.pipe {
+                Some(pipe_name) => pipe_name.clone(),
+                None => client::resolve_instance(options.instance.as_deref())?,
+            };
+            Client::connect(&pipe_name)
+        }
+        Transport::Ioctl => {
+            let interface_path = client::resolve_interface(options.instance.as_deref())?;
+            Client::connect_ioctl(&interface_path)
+        }
+    }
+}
+
+fn list(client: &mut Client, opts: &GlobalOptions, command: &ListCommand) -> eyre::Result<()> {
+    let monitors = command.filter.apply(client.monitors()?);
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitors)?;
+    } else if !monitors.is_empty() {
+        println!("{}", "Virtual monitors".underline());
+        for (i, monitor) in monitors.iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+
+            print_monitor(monitor);
+            if command.verbose {
+                print_active_mode(monitor);
+            }
+        }
+    } else {
+        println!("No virtual monitors found.");
+    }
+
+    Ok(())
+}
+
+fn get(client: &mut Client, opts: &GlobalOptions, command: &GetCommand) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitor)?;
+    } else {
+        print_monitor(&monitor);
+    }
+
+    Ok(())
+}
+
+fn get_edid(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &GetEdidCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+    let Some(edid) = client.edid(monitor.id)? else {
+        eyre::bail!("virtual monitor with ID {} isn't plugged in", monitor.id);
+    };
+
+    if let Some(out) = &command.out {
+        std::fs::write(out, &edid)
+            .wrap_err_with(|| format!("failed to write EDID to {}", out.display()))?;
+        if !opts.json {
+            println!(
+                "Wrote the EDID of virtual monitor with ID {} to {}.",
+                monitor.id.green(),
+                out.display().green()
+            );
+        }
+    }
+
+    if command.decode {
+        let decoded = edid::Edid::parse(&edid)?;
+        if opts.json {
+            let mut stdout = std::io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, &decoded)?;
+        } else {
+            println!(
+                "EDID of virtual monitor with ID {}, {} bytes:",
+                monitor.id.green(),
+                edid.len()
+            );
+            print_edid(&edid, &decoded);
+        }
+    } else if command.out.is_none() {
+        if opts.json {
+            let mut stdout = std::io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, &edid)?;
+        } else {
+            println!(
+                "EDID of virtual monitor with ID {}, {} bytes:",
+                monitor.id.green(),
+                edid.len()
+            );
+            for line in edid.chunks(16) {
+                println!(
+                    "{}",
+                    line.iter().map(|byte| format!("{byte:02X}")).join_with(" ")
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_edid(edid: &[u8], decoded: &edid::Edid) {
+    let bullet = "-".dimmed();
+    let timing = |timing: &edid::DetailedTiming| {
+        format!(
+            "{}x{}@{:.2} Hz, {:.2} MHz pixel clock",
+            timing.width,
+            timing.height,
+            timing.refresh_rate(),
+            f64::from(timing.pixel_clock_khz) / 1000.0
+        )
+    };
+
+    let version = lazy_format!("{}.{}", decoded.version.0, decoded.version.1);
+    println!(
+        "{bullet} {}, product {:#06X}, serial {}",
+        decoded.vendor.blue(),
+        decoded.product,
+        decoded.serial
+    );
+    match decoded.week {
+        0 | 0xFF => println!("{bullet} made in {}, EDID {version}", decoded.year),
+        week => println!(
+            "{bullet} made in week {week} of {}, EDID {version}",
+            decoded.year
+        ),
+    }
+    if decoded.size_cm != (0, 0) {
+        println!("{bullet} {}x{} cm", decoded.size_cm.0, decoded.size_cm.1);
+    }
+
+    for descriptor in &decoded.descriptors {
+        match descriptor {
+            edid::Descriptor::Timing(t) => println!("{bullet} {}", timing(t)),
+            edid::Descriptor::Name(name) => println!("{bullet} name {}", name.blue()),
+            edid::Descriptor::Serial(serial) => println!("{bullet} serial {serial}"),
+            edid::Descriptor::Text(text) => println!("{bullet} text {text}"),
+            edid::Descriptor::RangeLimits(limits) => println!(
+                "{bullet} range limits {}-{} Hz, {}-{} kHz, up to {} MHz",
+                limits.vertical_hz.0,
+                limits.vertical_hz.1,
+                limits.horizontal_khz.0,
+                limits.horizontal_khz.1,
+                limits.max_pixel_clock_mhz
+            ),
+            edid::Descriptor::Other { tag, .. } => {
+                println!("{bullet} {}", format!("descriptor {tag:#04X}").dimmed());
+            }
+        }
+    }
+
+    for extension in &decoded.extensions {
+        match extension {
+            edid::Extension::Cta(ext) => {
+                let basic_audio = ext.flags & edid::CtaExtension::BASIC_AUDIO != 0;
+                let audio_label = lazy_format!(if basic_audio => ", basic audio" else => "");
+                println!(
+                    "{bullet} CTA-861 extension, revision {}{audio_label}",
+                    ext.revision
+                );
+                for block in &ext.data_blocks {
+                    print_data_block(block);
+                }
+                for t in &ext.timings {
+                    println!("  {bullet} {}", timing(t));
+                }
+            }
+            edid::Extension::Other(bytes) => {
+                let tag = bytes.first().copied().unwrap_or_default();
+                println!("{bullet} {}", format!("extension {tag:#04X}").dimmed());
+            }
+        }
+    }
+
+    for block in edid::bad_checksums(edid) {
+        println!(
+            "{bullet} {}",
+            format!("wrong checksum in block {block}").red()
+        );
+    }
+}
+
+fn print_data_block(block: &edid::DataBlock) {
+    let bullet = "-".dimmed();
+
+    match block {
+        edid::DataBlock::Audio(formats) => {
+            for format in formats {
+                let name = lazy_format!(match (format.format) {
+                    1 => "LPCM",
+                    code => ("format {code}"),
+                });
+                let rates = format
+                    .sample_rates
+                    .iter()
+                    .map(|&rate| f64::from(rate) / 1000.0)
+                    .join_with("/");
+                println!(
+                    "  {bullet} audio {name}, {} channels, {rates} kHz",
+                    format.channels
+                );
+            }
+        }
+        edid::DataBlock::Video(codes) => {
+            println!("  {bullet} video codes {}", codes.iter().join_with(", "));
+        }
+        edid::DataBlock::VendorSpecific { oui, .. } => println!(
+            "  {bullet} vendor block {:02X}-{:02X}-{:02X}",
+            oui[0], oui[1], oui[2]
+        ),
+        edid::DataBlock::SpeakerAllocation(speakers) => {
+            println!("  {bullet} speakers {:#04X}", speakers[0]);
+        }
+        edid::DataBlock::Other { tag, payload } => println!(
+            "  {bullet} {}",
+            format!("data block {tag}, {} bytes", payload.len()).dimmed()
+        ),
+    }
+}
+
+fn print_monitor(monitor: &driver_ipc::Monitor) {
+    let name_label = lazy_format!(match (&monitor.name) {
+        Some(name) => (" {}{name}{}", "[".dimmed(), "]".dimmed()),
+        None => "",
+    });
+    let alias_label = lazy_format!(match (&monitor.alias) {
+        Some(alias) => (" {}", format!("(alias {alias})").dimmed()),
+        None => "",
+    });
+    let disabled_label = lazy_format!(if monitor.enabled => ""
+    else =>
+        (" {}", "(disabled)".red())
+    );
+    let ephemeral_label = lazy_format!(
+        if monitor.ephemeral => (" {}", "(ephemeral)".dimmed())
+        else => ""
+    );
+    let cursor_label = lazy_format!(
+        if monitor.cursor.hardware => (" {}", "(hardware cursor)".dimmed())
+        else => ""
+    );
+    let session_label = lazy_format!(
+        if monitor.session == driver_ipc::SessionPolicy::Remote => (" {}", "(remote sessions)".dimmed())
+        else => ""
+    );
+    let fps_label = lazy_format!(match (monitor.fps_limit) {
+        Some(fps) => (" {}", format!("(max {fps} fps)").dimmed()),
+        None => "",
+    });
+    let export_label = lazy_format!(match ((monitor.gpu_export, monitor.export_size)) {
+        (true, Some(size)) => (
+            " {}",
+            format!("(gpu export at {}x{})", size.width, size.height).dimmed()
+        ),
+        (true, None) => (" {}", "(gpu export)".dimmed()),
+        (false, _) => "",
+    });
+    let exclusive_label = lazy_format!(
+        if monitor.exclusive_capture => (" {}", "(exclusive capture)".dimmed())
+        else => ""
+    );
+    let plug_label = lazy_format!(
+        if monitor.auto_plug => ""
+        else => (" {}", "(manual plug)".dimmed())
+    );
+    let tags_label = lazy_format!(
+        if monitor.tags.is_empty() => ""
+        else => (" {}", format!("(tags: {})", monitor.tags.iter().join_with(", ")).dimmed())
+    );
+    println!(
+        "Monitor {}{name_label}{alias_label}{disabled_label}{ephemeral_label}{cursor_label}{session_label}{fps_label}{export_label}{exclusive_label}{plug_label}{tags_label}:",
+        monitor.id.green(),
+    );
+
+    if monitor.modes.is_empty() {
+        println!("{} {}", "-".dimmed(), "No modes".red());
+    } else {
+        for mode in &monitor.modes {
+            let refresh_rate_labels = mode
+                .refresh_rates
+                .iter()
+                .map(|rate| lazy_format!("{}", rate.blue()))
+                .join_with("/");
+            println!(
+                "{} {}{}{}{}{}",
+                "-".dimmed(),
+                mode.width.green(),
+                "x".dimmed(),
+                mode.height.green(),
+                "@".dimmed(),
+                refresh_rate_labels,
+            );
+        }
+    }
+}
+
+fn print_active_mode(monitor: &driver_ipc::Monitor) {
+    match monitor.active_mode {
+        Some(mode) => println!(
+            "{} {}{}{}{}{}",
+            "Active mode:".dimmed(),
+            mode.width.green(),
+            "x".dimmed(),
+            mode.height.green(),
+            "@".dimmed(),
+            mode.refresh_rate.blue(),
+        ),
+        None => println!("{} {}", "Active mode:".dimmed(), "none".dimmed()),
+    }
+}
+
+fn add(client: &mut Client, opts: &GlobalOptions, command: AddCommand) -> eyre::Result<()> {
+    let template = command
+        .template
+        .as_deref()
+        .map(template::find)
+        .transpose()?;
+    let modes = match template {
+        Some(template) if command.mode.is_empty() => template.modes(),
+        _ => command.mode,
+    };
+    let modes = modes
+        .into_iter()
+        .map(driver_ipc::Mode::from)
+        .collect::<Vec<_>>();
+
+    let edid = command
+        .edid_from_display
+        .as_deref()
+        .map(|display| client.display_edid(display))
+        .transpose()?;
+
+    let cursor = driver_ipc::CursorPolicy {
+        hardware: command.hardware_cursor,
+        max_size: command.cursor_size,
+        format: command.cursor_format.into(),
+    };
+
+    let mut tags = Vec::<String>::new();
+    for tag in command.tag {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    let id = client.new_id(command.id)?;
+    let mut new_monitor = driver_ipc::Monitor {
+        enabled: !command.disabled,
+        name: command.name,
+        alias: command.alias,
+        edid,
+        audio: command.audio || template.is_some_and(|template| template.audio),
+        ephemeral: command.ephemeral,
+        cursor,
+        session: if command.remote_session {
+            driver_ipc::SessionPolicy::Remote
+        } else {
+            driver_ipc::SessionPolicy::Manual
+        },
+        gpu_export: command.gpu_export,
+        exclusive_capture: command.exclusive_capture,
+        auto_plug: !command.no_auto_plug,
+        tags,
+        ..driver_ipc::Monitor::new(id, modes)
+    };
+    let name = new_monitor.name.clone();
+    let edid_name = new_monitor.edid_name();
+
+    let options = &command.edid;
+    if template.is_some()
+        || options.edid_vendor.is_some()
+        || options.edid_product.is_some()
+        || options.edid_size.is_some()
+    {
+        new_monitor.edid = Some(custom_edid(
+            template,
+            options,
+            edid_name.as_deref(),
+            command.audio,
+        )?);
+    }
+
+    client.check_aliases(std::slice::from_ref(&new_monitor))?;
+    client.notify(vec![new_monitor])?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &id)?;
+    } else {
+        let disabled_footnote = lazy_format!(
+            if command.disabled => (" {}", "(disabled)".red())
+            else => ""
+        );
+        println!(
+            "Added virtual monitor with ID {}{disabled_footnote}.",
+            id.green()
+        );
+        if edid_name.is_some() && edid_name != name {
+            println!(
+                "Windows shows it as {}, EDIDs only hold {} ASCII characters.",
+                edid_name.unwrap_or_default().blue(),
+                driver_ipc::EDID_NAME_LEN
+            );
+        }
+    }
+
+    Ok(())
+}
+
+// An edid like the one the driver generates, or the template's, with what
+// `add` was given instead
+fn custom_edid(
+    template: Option<&template::Template>,
+    options: &EdidOptions,
+    name: Option<&str>,
+    audio: bool,
+) -> eyre::Result<Vec<u8>> {
+    let mut builder = template.map_or_else(edid::EdidBuilder::new, template::Template::edid);
+
+    if let Some(vendor) = &options.edid_vendor {
+        builder = builder.vendor(vendor);
+    }
+    if let Some(product) = options.edid_product {
+        builder = builder.product(product);
+    }
+    if let Some(ScreenSizeArg(width, height)) = options.edid_size {
+        builder = builder.size_cm(width, height);
+    }
+    if let Some(name) = name {
+        builder = builder.name(name);
+    }
+    if audio && !template.is_some_and(|template| template.audio) {
+        builder = builder.extension(edid::CtaExtension::hdmi_audio());
+    }
+
+    Ok(builder.build()?)
+}
+
+fn ensure(client: &mut Client, opts: &GlobalOptions, command: EnsureCommand) -> eyre::Result<()> {
+    let modes = command
+        .mode
+        .into_iter()
+        .map(driver_ipc::Mode::from)
+        .collect::<Vec<_>>();
+
+    let existing = client
+        .monitors()?
+        .iter()
+        .find(|monitor| monitor.name.as_deref() == Some(command.name.as_str()))
+        .cloned();
+
+    let desired = if let Some(existing) = existing {
+        driver_ipc::Monitor {
+            enabled: !command.disabled,
+            modes,
+            ..existing
+        }
+    } else {
+        driver_ipc::Monitor {
+            name: Some(command.name),
+            enabled: !command.disabled,
+            ..driver_ipc::Monitor::new(client.new_id(None)?, modes)
+        }
+    };
+
+    let mut plan = plan::Plan::diff(client.monitors()?, &[desired], false);
+    if !command.dry_run {
+        plan.apply(client)?;
+    }
+
+    plan.print(opts)
+}
+
+fn apply(client: &mut Client, opts: &GlobalOptions, command: &ApplyCommand) -> eyre::Result<()> {
+    let config = config::Config::read(&command.config)?;
+    // relative paths in the config are relative to the file
+    let base = command.config.parent().unwrap_or(std::path::Path::new(""));
+
+    let mut plan = config.plan(client, base, command.prune)?;
+    if !command.dry_run {
+        plan.apply(client)?;
+    }
+
+    plan.print(opts)
+}
+
+fn add_mode(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: AddModeCommand,
+) -> eyre::Result<()> {
+    let mut monitor = client.find_monitor(&command.id)?;
+
+    // drivers that don't report their capabilities are left to reject modes themselves
+    if let Some(capabilities) = monitor.capabilities {
+        let (max_width, max_height) = capabilities.max_resolution;
+        if let Some(mode) = command
+            .mode
+            .iter()
+            .find(|mode| mode.width > max_width || mode.height > max_height)
+        {
+            eyre::bail!(
+                "mode {}x{} is larger than the {max_width}x{max_height} the driver supports",
+                mode.width,
+                mode.height
+            );
+        }
+    }
+
+    let existing_modes = monitor.modes.iter().cloned().map(mode::Mode::from);
+    let new_modes = mode::merge(existing_modes.chain(command.mode));
+    let new_modes: Vec<driver_ipc::Mode> =
+        new_modes.into_iter().map(driver_ipc::Mode::from).collect();
+
+    monitor.modes = new_modes.clone();
+    client.notify(vec![monitor.clone()])?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &new_modes)?;
+    } else {
+        println!(
+            "Added modes to virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn remove_mode(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &RemoveModeCommand,
+) -> eyre::Result<()> {
+    let mut monitor = client.find_monitor(&command.id)?;
+
+    let modes = monitor.modes.iter().cloned().map(mode::Mode::from);
+    let new_modes = mode::remove(modes, &command.mode)?;
+    let new_modes: Vec<driver_ipc::Mode> =
+        new_modes.into_iter().map(driver_ipc::Mode::from).collect();
+
+    monitor.modes = new_modes.clone();
+    client.notify(vec![monitor.clone()])?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &new_modes)?;
+    } else {
+        println!(
+            "Removed mode {} from virtual monitor with ID {}.",
+            command.mode.blue(),
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn switch_mode(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &SwitchModeCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+    let mode = &command.mode;
+    eyre::ensure!(
+        mode.refresh_rates.len() <= 1,
+        "pick a single refresh rate, e.g. `{}x{}@60`",
+        mode.width,
+        mode.height
+    );
+
+    let refresh_rate = match mode.refresh_rates.first() {
+        Some(&refresh_rate) => refresh_rate,
+        None => monitor
+            .modes
+            .iter()
+            .find(|m| (m.width, m.height) == (mode.width, mode.height))
+            .and_then(|m| m.refresh_rates.first().copied())
+            .ok_or_else(|| {
+                eyre::eyre!("virtual monitor with ID {} has no mode {mode}", monitor.id)
+            })?,
+    };
+    let active = driver_ipc::ActiveMode {
+        width: mode.width,
+        height: mode.height,
+        refresh_rate,
+    };
+
+    let diff = match client.change_mode(monitor.id, active)? {
+        driver_ipc::ModeChange::Requested(diff) => diff,
+        driver_ipc::ModeChange::Unavailable => eyre::bail!(
+            "virtual monitor with ID {} isn't active or has no mode {}x{}@{}",
+            monitor.id,
+            active.width,
+            active.height,
+            active.refresh_rate
+        ),
+        driver_ipc::ModeChange::RateLimited { retry_after_ms } => eyre::bail!(
+            "virtual monitor with ID {} switched modes just now, retry in {retry_after_ms}ms",
+            monitor.id
+        ),
+    };
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &diff)?;
+    } else if diff.operations.iter().any(|operation| {
+        matches!(
+            operation,
+            driver_ipc::MonitorOperation::DisplayConfigUpdate
+                | driver_ipc::MonitorOperation::Arrival
+        )
+    }) {
+        println!(
+            "Switching virtual monitor with ID {} to {}.",
+            monitor.id.green(),
+            lazy_format!("{}x{}@{}", active.width, active.height, active.refresh_rate).blue()
+        );
+    } else {
+        println!(
+            "Virtual monitor with ID {} switches to {} when it's plugged in again.",
+            monitor.id.green(),
+            lazy_format!("{}x{}@{}", active.width, active.height, active.refresh_rate).blue()
+        );
+    }
+
+    Ok(())
+}
+
+fn limit_fps(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &LimitFpsCommand,
+) -> eyre::Result<()> {
+    let mut monitor = client.find_monitor(&command.id)?;
+
+    monitor.fps_limit = command.fps.0;
+    client.notify(vec![monitor.clone()])?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitor.fps_limit)?;
+    } else if let Some(fps) = monitor.fps_limit {
+        println!(
+            "Limited virtual monitor with ID {} to {} fps.",
+            monitor.id.green(),
+            fps.blue()
+        );
+    } else {
+        println!(
+            "Removed the frame rate limit of virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn alias(client: &mut Client, opts: &GlobalOptions, command: &AliasCommand) -> eyre::Result<()> {
+    let mut monitor = client.find_monitor(&command.id)?;
+
+    monitor.alias.clone_from(&command.alias.0);
+    client.check_aliases(std::slice::from_ref(&monitor))?;
+    client.notify(vec![monitor.clone()])?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitor.alias)?;
+    } else if let Some(alias) = &monitor.alias {
+        println!(
+            "Virtual monitor with ID {} can be used as {} now.",
+            monitor.id.green(),
+            alias.blue()
+        );
+    } else {
+        println!(
+            "Removed the alias of virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn export_size(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &ExportSizeCommand,
+) -> eyre::Result<()> {
+    let mut monitor = client.find_monitor(&command.id)?;
+
+    if command.size.0.is_some() && !monitor.gpu_export {
+        eyre::bail!(
+            "virtual monitor {} needs `--gpu-export` to share scaled frames",
+            monitor.id
+        );
+    }
+
+    let fit = if command.stretch {
+        driver_ipc::ScaleFit::Stretch
+    } else {
+        driver_ipc::ScaleFit::Letterbox
+    };
+    monitor.export_size = command
+        .size
+        .0
+        .map(|(width, height)| driver_ipc::ExportSize { width, height, fit });
+    client.notify(vec![monitor.clone()])?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitor.export_size)?;
+    } else if let Some(size) = monitor.export_size {
+        println!(
+            "Virtual monitor with ID {} now shares frames at {}.",
+            monitor.id.green(),
+            format!("{}x{}", size.width, size.height).blue()
+        );
+    } else {
+        println!(
+            "Virtual monitor with ID {} now shares frames at the size of its mode.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn test_pattern(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &TestPatternCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+
+    let pattern = Option::<driver_ipc::TestPattern>::from(command.pattern);
+    if pattern.is_some() && !monitor.gpu_export {
+        eyre::bail!(
+            "virtual monitor {} needs `--gpu-export` to share a test pattern",
+            monitor.id
+        );
+    }
+
+    client.set_test_pattern(monitor.id, pattern)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &pattern)?;
+    } else if pattern.is_some() {
+        println!(
+            "Sharing a test pattern for virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    } else {
+        println!(
+            "Sharing the desktop of virtual monitor with ID {} again.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn enable(client: &mut Client, opts: &GlobalOptions, command: &EnableCommand) -> eyre::Result<()> {
+    if let Some(tag) = &command.tag {
+        let outcomes = set_tagged_enabled(client, tag, true)?;
+        return print_tagged_outcomes(opts, tag, "Enabled", &outcomes);
+    }
+
+    let id = command
+        .id
+        .as_deref()
+        .expect("clap requires an id without --tag");
+    let outcome = set_enabled(client, id, true)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &outcome)?;
+    } else {
+        let footnote = if outcome.toggled {
+            ""
+        } else {
+            " (was already enabled)"
+        };
+        println!(
+            "Enabled virtual monitor with ID {}{footnote}.",
+            outcome.monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn disable(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &DisableCommand,
+) -> eyre::Result<()> {
+    if let Some(tag) = &command.tag {
+        let outcomes = set_tagged_enabled(client, tag, false)?;
+        return print_tagged_outcomes(opts, tag, "Disabled", &outcomes);
+    }
+
+    let id = command
+        .id
+        .as_deref()
+        .expect("clap requires an id without --tag");
+    let outcome = set_enabled(client, id, false)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &outcome)?;
+    } else {
+        let footnote = if outcome.toggled {
+            ""
+        } else {
+            " (was already disabled)"
+        };
+        println!(
+            "Disabled virtual monitor with ID {}{footnote}.",
+            outcome.monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn plug(client: &mut Client, opts: &GlobalOptions, command: &PlugCommand) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+
+    if !monitor.enabled {
+        eyre::bail!(
+            "virtual monitor with ID {} is disabled, enable it instead",
+            monitor.id
+        );
+    }
+
+    client.plug(monitor.id)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitor.id)?;
+    } else {
+        println!("Plugged in virtual monitor with ID {}.", monitor.id.green());
+    }
+
+    Ok(())
+}
+
+fn replug(client: &mut Client, opts: &GlobalOptions, command: &ReplugCommand) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+
+    if !monitor.enabled {
+        eyre::bail!(
+            "virtual monitor with ID {} is disabled, enable it instead",
+            monitor.id
+        );
+    }
+
+    client.replug(monitor.id)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &monitor.id)?;
+    } else {
+        println!("Replugged virtual monitor with ID {}.", monitor.id.green());
+    }
+
+    Ok(())
+}
+
+fn gpu_priority(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &GpuPriorityCommand,
+) -> eyre::Result<()> {
+    let realtime = command.priority == GpuPriority::Realtime;
+    client.set_realtime_gpu_priority(realtime)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &realtime)?;
+    } else if realtime {
+        println!(
+            "Frame processing now has {} GPU priority.",
+            "realtime".green()
+        );
+    } else {
+        println!(
+            "Frame processing now has {} GPU priority. Monitors keep realtime priority until they're replugged.",
+            "normal".green()
+        );
+    }
+
+    Ok(())
+}
+
+fn remove(client: &mut Client, opts: &GlobalOptions, command: &RemoveCommand) -> eyre::Result<()> {
+    if command.all {
+        return remove_all(client, opts);
+    }
+    if let Some(tag) = &command.tag {
+        let monitor_ids = client
+            .tagged(tag)?
+            .iter()
+            .map(|monitor| monitor.id)
+            .collect::<Vec<_>>();
+        client.remove(monitor_ids.clone())?;
+
+        if opts.json {
+            let mut stdout = std::io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, &monitor_ids)?;
+        } else {
+            println!(
+                "Removed {} virtual monitors tagged {}.",
+                monitor_ids.len(),
+                tag.green()
+            );
+        }
+        return Ok(());
+    }
+
+    let monitor_ids = command
+        .id
+        .iter()
+        .map(|query| {
+            let monitor = client.find_monitor(query)?;
+            eyre::Ok(monitor.id)
         })
         .collect::<eyre::Result<Vec<_>>>()?;
     client.remove(monitor_ids)?;
 
-    if opts.json {
-        let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &command.id)?;
-    } else if command.id.len() == 1 {
-        println!("Removed virtual monitor.");
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &command.id)?;
+    } else if command.id.len() == 1 {
+        println!("Removed virtual monitor.");
+    } else {
+        println!("Removed {} virtual monitors.", command.id.len());
+    }
+
+    Ok(())
+}
+
+fn remove_all(client: &mut Client, opts: &GlobalOptions) -> eyre::Result<()> {
+    client.remove_all()?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &())?;
+    } else {
+        println!("Removed all virtual monitors.");
+    }
+
+    Ok(())
+}
+
+fn bench_ipc(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &BenchIpcCommand,
+) -> eyre::Result<()> {
+    let sizes = if command.sizes.is_empty() {
+        bench::DEFAULT_PAYLOAD_SIZES
+    } else {
+        &command.sizes
+    };
+
+    let results = sizes
+        .iter()
+        .map(|&size| bench::run(client, size, command.iterations))
+        .collect::<eyre::Result<Vec<_>>>()?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &results)?;
+    } else {
+        println!(
+            "{} ({} round trips per payload size)",
+            "IPC benchmark".underline(),
+            command.iterations
+        );
+        for result in &results {
+            println!(
+                "{} {:>7} bytes: p50 {}us, p95 {}us, p99 {}us, max {}us, {} msg/s, {} KiB/s",
+                "-".dimmed(),
+                result.payload_size.green(),
+                format!("{:.1}", result.p50_us).blue(),
+                format!("{:.1}", result.p95_us).blue(),
+                format!("{:.1}", result.p99_us).blue(),
+                format!("{:.1}", result.max_us).blue(),
+                format!("{:.0}", result.messages_per_sec).green(),
+                format!("{:.0}", result.bytes_per_sec / 1024.0).green(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn benchmark(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &BenchmarkCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+
+    if !opts.json {
+        println!(
+            "Benchmarking virtual monitor with ID {} for {}s per mode...",
+            monitor.id.green(),
+            command.duration
+        );
+    }
+
+    let results = latency::run(
+        client,
+        &monitor,
+        std::time::Duration::from_secs(command.duration),
+        std::time::Duration::from_secs(command.settle),
+    )?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &results)?;
+    } else {
+        println!("{}", "Frame benchmark".underline());
+        for result in &results {
+            println!(
+                "{} {}: {} fps, present latency {}us, processing {}us, jitter {}us, {} frames, {} dropped",
+                "-".dimmed(),
+                format!("{}x{}@{}", result.width, result.height, result.refresh_rate).green(),
+                format!("{:.1}", result.fps).green(),
+                format!("{:.1}", result.present_latency_us).blue(),
+                format!("{:.1}", result.processing_us).blue(),
+                format!("{:.1}", result.jitter_us).blue(),
+                result.frames.blue(),
+                if result.dropped > 0 {
+                    result.dropped.red().to_string()
+                } else {
+                    result.dropped.green().to_string()
+                },
+            );
+        }
+
+        if results.iter().any(|result| result.frames == 0) {
+            println!(
+                "{}",
+                "Some modes received no frames, make sure something is animating on the monitor."
+                    .yellow()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn logs(client: &mut Client, opts: &GlobalOptions, command: &LogsCommand) -> eyre::Result<()> {
+    // how long to wait between polling the driver for new records
+    const FOLLOW_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+    if let Some(level) = command.level {
+        client.set_log_level(level.into())?;
+    }
+
+    let mut since = 0;
+    let mut records = Vec::new();
+    loop {
+        let new_records = client.logs(since)?;
+        if let Some(last) = new_records.last() {
+            since = last.seq;
+        }
+
+        if new_records.is_empty() {
+            if !command.follow {
+                break;
+            }
+
+            std::thread::sleep(FOLLOW_INTERVAL);
+            continue;
+        }
+
+        if command.follow {
+            // stream records as they arrive, one JSON object per line
+            for record in &new_records {
+                print_log_record(opts, record)?;
+            }
+        } else {
+            records.extend(new_records);
+        }
+    }
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &records)?;
+    } else if records.is_empty() {
+        println!("No log messages found.");
+    } else {
+        for record in &records {
+            print_log_record(opts, record)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_log_record(opts: &GlobalOptions, record: &driver_ipc::LogRecord) -> eyre::Result<()> {
+    if opts.json {
+        println!("{}", serde_json::to_string(record)?);
+        return Ok(());
+    }
+
+    // UTC time of day, the date isn't interesting for recent driver logs
+    let millis_of_day = record.timestamp % (24 * 60 * 60 * 1000);
+    let (hours, minutes, seconds, millis) = (
+        millis_of_day / 3_600_000,
+        millis_of_day / 60_000 % 60,
+        millis_of_day / 1000 % 60,
+        millis_of_day % 1000,
+    );
+    let time = lazy_format!("{hours:02}:{minutes:02}:{seconds:02}.{millis:03}");
+
+    let level = lazy_format!(match (record.level) {
+        driver_ipc::LogLevel::Error => ("{}", "ERROR".red()),
+        driver_ipc::LogLevel::Warn => ("{}", "WARN ".yellow()),
+        driver_ipc::LogLevel::Info => ("{}", "INFO ".green()),
+        driver_ipc::LogLevel::Debug => ("{}", "DEBUG".blue()),
+        driver_ipc::LogLevel::Trace => ("{}", "TRACE".dimmed()),
+    });
+
+    println!(
+        "{} {level} {}{}{} {}",
+        time.dimmed(),
+        "[".dimmed(),
+        record.target,
+        "]".dimmed(),
+        record.message
+    );
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+fn stats(client: &mut Client, opts: &GlobalOptions, command: &StatsCommand) -> eyre::Result<()> {
+    // how long to wait between printing updated statistics
+    const FOLLOW_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
+    let id = command
+        .id
+        .as_deref()
+        .map(|query| client.find_monitor(query).map(|monitor| monitor.id))
+        .transpose()?;
+
+    loop {
+        let stats = client.stats(id)?;
+        let callbacks = if command.detailed {
+            Some(client.callback_stats()?)
+        } else {
+            None
+        };
+
+        if opts.json {
+            // `--detailed` wraps the monitor statistics in an object, next to the callbacks
+            let output = match &callbacks {
+                Some(callbacks) => serde_json::to_value(DetailedStats {
+                    monitors: &stats,
+                    callbacks,
+                })?,
+                None => serde_json::to_value(&stats)?,
+            };
+
+            if command.follow {
+                // one JSON value per line, so the output can be streamed
+                println!("{output}");
+            } else {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &output)?;
+            }
+        } else {
+            if stats.is_empty() {
+                println!("No virtual monitors found.");
+            } else {
+                for stats in &stats {
+                    println!(
+                        "Monitor {}: {} presented, {} dropped, {}us avg latency, {} Hz",
+                        stats.id.green(),
+                        stats.frames_presented.blue(),
+                        if stats.frames_dropped > 0 {
+                            stats.frames_dropped.red().to_string()
+                        } else {
+                            stats.frames_dropped.green().to_string()
+                        },
+                        format!("{:.1}", stats.avg_latency_us).blue(),
+                        format!("{:.1}", stats.refresh_rate).blue(),
+                    );
+
+                    if stats.shared_frames_dropped > 0 {
+                        println!(
+                            "{} shared frames: {} dropped by backpressure",
+                            "-".dimmed(),
+                            stats.shared_frames_dropped.red(),
+                        );
+                    }
+
+                    for consumer in &stats.consumers {
+                        println!(
+                            "{} ring consumer {}: {} fps, {} read, {} dropped",
+                            "-".dimmed(),
+                            consumer.pid.green(),
+                            format!("{:.1}", consumer.fps).blue(),
+                            consumer.frames_read.blue(),
+                            if consumer.frames_dropped > 0 {
+                                consumer.frames_dropped.red().to_string()
+                            } else {
+                                consumer.frames_dropped.green().to_string()
+                            },
+                        );
+                    }
+
+                    if command.detailed {
+                        println!(
+                            "{} pool: {} created, {} reused",
+                            "-".dimmed(),
+                            stats.pool_allocations.blue(),
+                            stats.pool_reuses.green(),
+                        );
+                    }
+                }
+            }
+
+            if let Some(callbacks) = &callbacks {
+                println!();
+                println!("{}", "Driver callbacks".underline());
+                for callback in callbacks {
+                    println!(
+                        "{} {}: {} succeeded, {} failed",
+                        "-".dimmed(),
+                        callback.callback,
+                        callback.succeeded.green(),
+                        if callback.failed > 0 {
+                            callback.failed.red().to_string()
+                        } else {
+                            callback.failed.green().to_string()
+                        },
+                    );
+                }
+            }
+        }
+
+        if !command.follow {
+            break;
+        }
+
+        std::thread::sleep(FOLLOW_INTERVAL);
+        if !opts.json {
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
+fn events(client: &mut Client, opts: &GlobalOptions, command: &EventsCommand) -> eyre::Result<()> {
+    // how long to wait between polling the driver for new events
+    const FOLLOW_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+    let mut since = 0;
+    let mut events = Vec::new();
+    loop {
+        let new_events = client.events(since)?;
+        if let Some(last) = new_events.last() {
+            since = last.seq;
+        }
+
+        if new_events.is_empty() {
+            if !command.follow {
+                break;
+            }
+
+            std::thread::sleep(FOLLOW_INTERVAL);
+            continue;
+        }
+
+        if command.follow {
+            // stream events as they arrive, one JSON object per line
+            for event in &new_events {
+                print_event(opts, event)?;
+            }
+        } else {
+            events.extend(new_events);
+        }
+    }
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &events)?;
+    } else if events.is_empty() {
+        println!("No events found.");
+    } else {
+        for event in &events {
+            print_event(opts, event)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn print_event(opts: &GlobalOptions, event: &driver_ipc::Event) -> eyre::Result<()> {
+    if opts.json {
+        println!("{}", serde_json::to_string(event)?);
+        return Ok(());
+    }
+
+    let millis_of_day = event.timestamp % (24 * 60 * 60 * 1000);
+    let time = lazy_format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        millis_of_day / 3_600_000,
+        millis_of_day / 60_000 % 60,
+        millis_of_day / 1000 % 60,
+        millis_of_day % 1000
+    );
+
+    match &event.kind {
+        driver_ipc::EventKind::SwapChainStalled { id, stalled_ms } => println!(
+            "{} Monitor {}: swap chain stalled for {}ms, re-created it",
+            time.dimmed(),
+            id.green(),
+            stalled_ms.red()
+        ),
+        driver_ipc::EventKind::MonitorAdded { monitor } => {
+            println!("{} Monitor {}: added", time.dimmed(), monitor.id.green());
+        }
+        driver_ipc::EventKind::MonitorChanged { monitor, changed } => println!(
+            "{} Monitor {}: changed {}",
+            time.dimmed(),
+            monitor.id.green(),
+            changed.iter().join_with(", ")
+        ),
+        driver_ipc::EventKind::MonitorRemoved { id } => {
+            println!("{} Monitor {}: removed", time.dimmed(), id.green());
+        }
+        driver_ipc::EventKind::ActiveModeChanged { id, mode } => match mode {
+            Some(mode) => println!(
+                "{} Monitor {}: active mode {}x{}@{}",
+                time.dimmed(),
+                id.green(),
+                mode.width,
+                mode.height,
+                mode.refresh_rate
+            ),
+            None => println!("{} Monitor {}: inactive", time.dimmed(), id.green()),
+        },
+        driver_ipc::EventKind::ModeChangeRequested { id, mode } => println!(
+            "{} Monitor {}: mode change to {}x{}@{} requested",
+            time.dimmed(),
+            id.green(),
+            mode.width,
+            mode.height,
+            mode.refresh_rate
+        ),
+        driver_ipc::EventKind::ConsumerAbandoned { id, held_ms } => match held_ms {
+            Some(held_ms) => println!(
+                "{} Monitor {}: consumer held a shared frame for {}ms, sharing a new texture",
+                time.dimmed(),
+                id.green(),
+                held_ms.red()
+            ),
+            None => println!(
+                "{} Monitor {}: consumer went away while holding a shared frame",
+                time.dimmed(),
+                id.green()
+            ),
+        },
+    }
+
+    Ok(())
+}
+
+fn shared_frame(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &SharedFrameCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+    if let Some(format) = command.format {
+        client.shared_frame_as(monitor.id, format.into())?;
+    }
+    if let Some(sync) = command.sync {
+        let options = driver_ipc::SyncOptions {
+            sync: sync.into(),
+            timeout_ms: command.timeout_ms,
+        };
+        client.shared_frame_sync(monitor.id, options)?;
+    }
+    if command.ring {
+        return shared_ring(client, opts, &monitor);
+    }
+    let frame = client.shared_frame(monitor.id)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &frame)?;
+    } else if let Some(frame) = frame {
+        println!(
+            "{} {}",
+            frame.name.green(),
+            lazy_format!(
+                "({}x{}, format {})",
+                frame.width,
+                frame.height,
+                frame.format
+            )
+            .dimmed()
+        );
+        if let Some(fence) = &frame.fence {
+            println!("{} {}", fence.green(), "(fence)".dimmed());
+        }
+    } else if monitor.gpu_export {
+        println!("Virtual monitor has no frames to share yet.");
+    } else {
+        println!(
+            "Virtual monitor doesn't share its frames, add it with {}.",
+            "--gpu-export".blue()
+        );
+    }
+
+    Ok(())
+}
+
+fn backpressure(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &BackpressureCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+    eyre::ensure!(
+        monitor.gpu_export,
+        "virtual monitor with ID {} doesn't share its frames, see `--gpu-export`",
+        monitor.id
+    );
+
+    let backpressure = match command.policy {
+        BackpressurePolicy::DropNewest => driver_ipc::Backpressure::DropNewest,
+        BackpressurePolicy::DropOldest => driver_ipc::Backpressure::DropOldest,
+        BackpressurePolicy::Block => driver_ipc::Backpressure::Block {
+            timeout_ms: command.timeout_ms,
+        },
+    };
+    client.set_backpressure(monitor.id, backpressure)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &backpressure)?;
+    } else {
+        let policy = match backpressure {
+            driver_ipc::Backpressure::DropNewest => "Dropping new frames".to_owned(),
+            driver_ipc::Backpressure::DropOldest => "Replacing unread frames".to_owned(),
+            driver_ipc::Backpressure::Block { timeout_ms } => {
+                format!("Waiting up to {timeout_ms} ms")
+            }
+        };
+        println!(
+            "{policy} while the consumer of virtual monitor with ID {} falls behind.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn shared_ring(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    monitor: &driver_ipc::Monitor,
+) -> eyre::Result<()> {
+    let ring = client.shared_ring(monitor.id)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &ring)?;
+    } else if let Some(ring) = ring {
+        println!(
+            "{} {}",
+            ring.header.green(),
+            lazy_format!(
+                "({}x{}, format {}, {} textures, up to {} consumers)",
+                ring.width,
+                ring.height,
+                ring.format,
+                ring.textures.len(),
+                ring.events.len()
+            )
+            .dimmed()
+        );
+        for texture in &ring.textures {
+            println!("{} {texture}", "-".dimmed());
+        }
+    } else if monitor.gpu_export {
+        println!("Virtual monitor has no frames in its ring yet.");
+    } else {
+        println!(
+            "Virtual monitor doesn't share its frames, add it with {}.",
+            "--gpu-export".blue()
+        );
+    }
+
+    Ok(())
+}
+
+fn picture(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &PictureCommand,
+) -> eyre::Result<()> {
+    let monitor = client.find_monitor(&command.id)?;
+    let picture = client.picture(monitor.id)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &picture)?;
+        return Ok(());
+    }
+
+    println!(
+        "Virtual monitor with ID {}: brightness {}, contrast {}",
+        monitor.id.green(),
+        picture.brightness.blue(),
+        picture.contrast.blue(),
+    );
+
+    if let Some(ramp) = &picture.gamma {
+        // the output for full input shows e.g. how much blue night light takes away
+        let white_level = |channel: &[u16]| {
+            let level = channel.last().copied().unwrap_or(u16::MAX);
+            f64::from(level) / f64::from(u16::MAX) * 100.0
+        };
+
+        println!(
+            "{} custom gamma ramp, white is at red {}%, green {}%, blue {}%",
+            "-".dimmed(),
+            format!("{:.0}", white_level(&ramp.red)).blue(),
+            format!("{:.0}", white_level(&ramp.green)).blue(),
+            format!("{:.0}", white_level(&ramp.blue)).blue(),
+        );
+    } else {
+        println!("{} default gamma ramp", "-".dimmed());
+    }
+
+    match &picture.lut {
+        Some(driver_ipc::Lut::OneD(table)) => {
+            println!(
+                "{} 1D LUT with {} entries",
+                "-".dimmed(),
+                table.len().blue()
+            );
+        }
+        Some(driver_ipc::Lut::ThreeD { size, .. }) => {
+            println!(
+                "{} 3D LUT with {} entries per axis",
+                "-".dimmed(),
+                size.blue()
+            );
+        }
+        None => (),
+    }
+
+    Ok(())
+}
+
+fn lut(client: &mut Client, opts: &GlobalOptions, command: &LutCommand) -> eyre::Result<()> {
+    let (id, lut) = match command {
+        LutCommand::Set(command) => (&command.id, Some(lut::read_cube(&command.file)?)),
+        LutCommand::Clear(command) => (&command.id, None),
+    };
+
+    let monitor = client.find_monitor(id)?;
+    let set = lut.is_some();
+    client.set_lut(monitor.id, lut)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &set)?;
+    } else if set {
+        println!(
+            "Set the lookup table of virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    } else {
+        println!(
+            "Removed the lookup table of virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn cursor(client: &mut Client, opts: &GlobalOptions, command: &CursorCommand) -> eyre::Result<()> {
+    let (id, composite) = match command {
+        CursorCommand::Show(command) => return show_cursor(client, opts, &command.id),
+        CursorCommand::Composite(command) => (&command.id, true),
+        CursorCommand::Separate(command) => (&command.id, false),
+    };
+
+    let monitor = client.find_monitor(id)?;
+    eyre::ensure!(
+        monitor.cursor.hardware,
+        "virtual monitor with ID {} has no hardware cursor, see `--hardware-cursor`",
+        monitor.id
+    );
+    client.set_cursor_composite(monitor.id, composite)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &composite)?;
+    } else if composite {
+        println!(
+            "Drawing the cursor into the shared frames of virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    } else {
+        println!(
+            "Sharing the cursor separately from the frames of virtual monitor with ID {}.",
+            monitor.id.green()
+        );
+    }
+
+    Ok(())
+}
+
+fn show_cursor(client: &mut Client, opts: &GlobalOptions, id: &str) -> eyre::Result<()> {
+    let monitor = client.find_monitor(id)?;
+    let cursor = client.cursor(monitor.id, 0)?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &cursor)?;
+        return Ok(());
+    }
+
+    let Some(cursor) = cursor else {
+        eyre::bail!(
+            "virtual monitor with ID {} has no hardware cursor, or no swap chain yet",
+            monitor.id
+        );
+    };
+
+    println!("Cursor of virtual monitor with ID {}:", monitor.id.green());
+    if cursor.visible {
+        println!(
+            "{} at {}, {}",
+            "-".dimmed(),
+            cursor.x.blue(),
+            cursor.y.blue()
+        );
+    } else {
+        println!("{} hidden", "-".dimmed());
+    }
+
+    match &cursor.shape {
+        Some(shape) => {
+            let kind = match shape.kind {
+                driver_ipc::CursorShapeKind::Alpha => "alpha",
+                driver_ipc::CursorShapeKind::MaskedColor => "masked color",
+            };
+            println!(
+                "{} {} shape {}, {}x{}, hotspot at {}, {}",
+                "-".dimmed(),
+                kind,
+                cursor.shape_id.blue(),
+                shape.width.blue(),
+                shape.height.blue(),
+                shape.hot_x.blue(),
+                shape.hot_y.blue()
+            );
+        }
+        None => println!("{} no shape yet", "-".dimmed()),
+    }
+
+    Ok(())
+}
+
+fn doctor(client: &mut Client, opts: &GlobalOptions) -> eyre::Result<()> {
+    let info = client.driver_info()?;
+
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, &info)?;
+        return Ok(());
+    }
+
+    println!(
+        "Driver {} {}",
+        info.version.green(),
+        lazy_format!("(IddCx {})", info.iddcx_version).dimmed()
+    );
+
+    println!(
+        "Realtime GPU priority {}",
+        if info.realtime_gpu_priority {
+            "on".green().to_string()
+        } else {
+            "off".dimmed().to_string()
+        }
+    );
+
+    if !info.capabilities.is_empty() {
+        println!("{}", "IddCx functions".underline());
+        for capability in &info.capabilities {
+            let status = if capability.available {
+                "available".green().to_string()
+            } else if capability.built {
+                "not supported by this version of Windows"
+                    .yellow()
+                    .to_string()
+            } else {
+                lazy_format!("needs a build against IddCx {}", capability.iddcx_version)
+                    .dimmed()
+                    .to_string()
+            };
+
+            println!("{} {}: {status}", "-".dimmed(), capability.function);
+        }
+        println!();
+    }
+
+    if info.degraded.is_empty() {
+        println!("All optional features are available.");
     } else {
-        println!("Removed {} virtual monitors.", command.id.len());
+        println!("{}", "Unavailable features".underline());
+        for degraded in &info.degraded {
+            println!("{} {}", degraded.feature.yellow(), degraded.reason.dimmed());
+        }
     }
 
     Ok(())
 }
 
-fn remove_all(client: &mut Client, opts: &GlobalOptions) -> eyre::Result<()> {
-    client.remove_all()?;
+#[cfg(windows)]
+fn instances(opts: &GlobalOptions) -> eyre::Result<()> {
+    let instances = driver_ipc::discover()?;
 
     if opts.json {
         let mut stdout = std::io::stdout().lock();
-        serde_json::to_writer_pretty(&mut stdout, &())?;
+        serde_json::to_writer_pretty(&mut stdout, &instances)?;
+    } else if instances.is_empty() {
+        println!("No virtual display adapters found.");
     } else {
-        println!("Removed all virtual monitors.");
+        println!("{}", "Virtual display adapters".underline());
+        for (index, instance) in instances.iter().enumerate() {
+            let default_label = lazy_format!(
+                if instance.pipe_name == driver_ipc::DEFAULT_PIPE_NAME => (" {}", "(default)".dimmed())
+                else => ""
+            );
+            println!(
+                "{} {}{default_label} {}",
+                index.green(),
+                instance.pipe_name.blue(),
+                instance.interface_path.dimmed(),
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn trace_profile(command: &TraceProfileCommand) -> eyre::Result<()> {
+    let profile = trace_profile::generate(!command.no_frames);
+
+    if let Some(output) = &command.output {
+        std::fs::write(output, profile)?;
+        println!(
+            "Wrote recording profile to {}. Record with `wpr -start {} -filemode`.",
+            output.display().green(),
+            output.display(),
+        );
+    } else {
+        print!("{profile}");
+    }
+
+    Ok(())
+}
+
+fn templates(opts: &GlobalOptions, command: &TemplatesCommand) -> eyre::Result<()> {
+    match command {
+        TemplatesCommand::List => {
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, template::TEMPLATES)?;
+            } else {
+                println!("{}", "Monitor templates".underline());
+                for template in template::TEMPLATES {
+                    let size_label = lazy_format!(
+                        if template.size_cm != (0, 0) => (", {}x{} cm", template.size_cm.0, template.size_cm.1)
+                        else => ""
+                    );
+                    println!(
+                        "{} {}",
+                        template.name.blue(),
+                        format!("({}{size_label})", template.kind).dimmed()
+                    );
+                    for mode in template.modes {
+                        println!("  {} {mode}", "-".dimmed());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn schema(command: &SchemaCommand) -> eyre::Result<()> {
+    let schema = match command.kind {
+        SchemaKind::Monitor => driver_ipc::schema::monitor(),
+        SchemaKind::Mode => driver_ipc::schema::mode(),
+        SchemaKind::Command => driver_ipc::schema::command(),
+        SchemaKind::Config => config::schema(),
+    };
+    let schema = serde_json::to_string_pretty(&schema)?;
+
+    if let Some(output) = &command.output {
+        std::fs::write(output, schema)?;
+        println!("Wrote schema to {}.", output.display().green());
+    } else {
+        println!("{schema}");
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn schedule(opts: &GlobalOptions, command: &ScheduleCommand) -> eyre::Result<()> {
+    let mut entries = settings::read::<driver_ipc::ScheduleEntry>(driver_ipc::SCHEDULE_VALUE)?;
+
+    match command {
+        ScheduleCommand::List => {
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &entries)?;
+            } else if entries.is_empty() {
+                println!("No profiles are scheduled.");
+            } else {
+                println!("{}", "Scheduled profiles".underline());
+                for (index, entry) in entries.iter().enumerate() {
+                    println!(
+                        "{} {} {}",
+                        index.green(),
+                        entry.profile.blue(),
+                        schedule::describe(&entry.trigger).dimmed()
+                    );
+                }
+            }
+        }
+
+        ScheduleCommand::Add(command) => {
+            // vdd-server runs elsewhere, so relative paths wouldn't resolve
+            let profile = std::env::current_dir()?.join(&command.profile);
+            let contents = std::fs::read(&profile)
+                .wrap_err_with(|| format!("failed to read profile {}", profile.display()))?;
+            serde_json::from_slice::<Vec<driver_ipc::Monitor>>(&contents).wrap_err_with(|| {
+                format!(
+                    "invalid profile {}, expected the output of `list --json`",
+                    profile.display()
+                )
+            })?;
+
+            let trigger = if command.login {
+                driver_ipc::Trigger::Login
+            } else if let Some(at) = &command.at {
+                at.clone()
+            } else if let Some(process) = &command.process {
+                driver_ipc::Trigger::Process(process.clone())
+            } else {
+                unreachable!("clap requires a trigger")
+            };
+
+            let entry = driver_ipc::ScheduleEntry {
+                trigger,
+                profile: profile
+                    .into_os_string()
+                    .into_string()
+                    .map_err(|_| eyre::eyre!("profile path must be valid unicode"))?,
+            };
+
+            if !opts.json {
+                println!(
+                    "Scheduled {} {}.",
+                    entry.profile.green(),
+                    schedule::describe(&entry.trigger)
+                );
+            }
+
+            entries.push(entry);
+            settings::write(driver_ipc::SCHEDULE_VALUE, &entries)?;
+        }
+
+        ScheduleCommand::Remove(command) => {
+            eyre::ensure!(
+                command.index < entries.len(),
+                "no scheduled profile {}, see `schedule list`",
+                command.index
+            );
+
+            let entry = entries.remove(command.index);
+            settings::write(driver_ipc::SCHEDULE_VALUE, &entries)?;
+
+            if !opts.json {
+                println!("Removed scheduled profile {}.", entry.profile.green());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn rule(opts: &GlobalOptions, command: &RuleCommand) -> eyre::Result<()> {
+    fn describe(rule: &driver_ipc::Rule) -> impl std::fmt::Display + '_ {
+        lazy_format!(
+            "while {} runs, {} monitor {}",
+            rule.process.blue(),
+            if rule.enabled { "enable" } else { "disable" },
+            rule.monitor.green()
+        )
+    }
+
+    let mut rules = settings::read::<driver_ipc::Rule>(driver_ipc::RULES_VALUE)?;
+
+    match command {
+        RuleCommand::List => {
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &rules)?;
+            } else if rules.is_empty() {
+                println!("No rules are set.");
+            } else {
+                println!("{}", "Rules".underline());
+                for (index, rule) in rules.iter().enumerate() {
+                    println!("{} {}", index.green(), describe(rule));
+                }
+            }
+        }
+
+        RuleCommand::Add(command) => {
+            let rule = driver_ipc::Rule {
+                process: command.process.clone(),
+                monitor: command.monitor.clone(),
+                enabled: !command.disable,
+            };
+
+            eyre::ensure!(
+                rules
+                    .iter()
+                    .all(|other| other.process != rule.process || other.monitor != rule.monitor),
+                "monitor {} already has a rule for {}, remove it first",
+                rule.monitor,
+                rule.process
+            );
+
+            if !opts.json {
+                println!("Added rule: {}.", describe(&rule));
+            }
+
+            rules.push(rule);
+            settings::write(driver_ipc::RULES_VALUE, &rules)?;
+        }
+
+        RuleCommand::Remove(command) => {
+            eyre::ensure!(
+                command.index < rules.len(),
+                "no rule {}, see `rule list`",
+                command.index
+            );
+
+            let rule = rules.remove(command.index);
+            settings::write(driver_ipc::RULES_VALUE, &rules)?;
+
+            if !opts.json {
+                println!("Removed rule: {}.", describe(&rule));
+            }
+        }
     }
 
     Ok(())
@@ -384,8 +3332,424 @@ fn set_enabled(
     })
 }
 
+/// Enable or disable every monitor tagged `tag` at once.
+fn set_tagged_enabled(
+    client: &mut Client,
+    tag: &str,
+    enabled: bool,
+) -> eyre::Result<Vec<EnableDisableOutcome>> {
+    let outcomes = client
+        .tagged(tag)?
+        .into_iter()
+        .map(|mut monitor| {
+            let toggled = enabled != monitor.enabled;
+            monitor.enabled = enabled;
+            EnableDisableOutcome { monitor, toggled }
+        })
+        .collect::<Vec<_>>();
+
+    let changed = outcomes
+        .iter()
+        .filter(|outcome| outcome.toggled)
+        .map(|outcome| outcome.monitor.clone())
+        .collect::<Vec<_>>();
+    if !changed.is_empty() {
+        client.notify(changed)?;
+    }
+
+    Ok(outcomes)
+}
+
+fn print_tagged_outcomes(
+    opts: &GlobalOptions,
+    tag: &str,
+    verb: &str,
+    outcomes: &[EnableDisableOutcome],
+) -> eyre::Result<()> {
+    if opts.json {
+        let mut stdout = std::io::stdout().lock();
+        serde_json::to_writer_pretty(&mut stdout, outcomes)?;
+    } else {
+        let toggled = outcomes.iter().filter(|outcome| outcome.toggled).count();
+        let footnote = lazy_format!(
+            if toggled == outcomes.len() => ""
+            else => (" ({} already were)", outcomes.len() - toggled)
+        );
+        println!(
+            "{verb} {} virtual monitors tagged {}{footnote}.",
+            outcomes.len(),
+            tag.green()
+        );
+    }
+
+    Ok(())
+}
+
+/// JSON output of `stats --detailed`
+#[derive(Debug, Serialize)]
+struct DetailedStats<'a> {
+    monitors: &'a [driver_ipc::Stats],
+    callbacks: &'a [driver_ipc::CallbackStats],
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 struct EnableDisableOutcome {
     monitor: driver_ipc::Monitor,
     toggled: bool,
 }
+
+#[cfg(windows)]
+fn stream(mut client: Client, opts: &GlobalOptions, command: StreamCommand) -> eyre::Result<()> {
+    // vdd-stream talks to the driver itself, and the driver pipe only takes
+    // one client at a time, so the client is dropped before talking to it
+    match command {
+        StreamCommand::Start(command) => {
+            let monitor = client.find_monitor(&command.id)?;
+            drop(client);
+
+            if !monitor.gpu_export {
+                eyre::bail!(
+                    "virtual monitor {} needs `--gpu-export` to be streamed",
+                    monitor.id
+                );
+            }
+
+            let settings = driver_ipc::StreamSettings {
+                id: monitor.id,
+                port: command.port,
+                token: match command.token {
+                    Some(token) => token,
+                    None => stream::random_token()?,
+                },
+                quality: command.quality,
+            };
+            stream::request(&driver_ipc::StreamCommand::RequestStart(settings.clone()))?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &settings)?;
+            } else {
+                println!(
+                    "Serving virtual monitor {} at {}",
+                    monitor.id,
+                    lazy_format!(
+                        "http://<this pc>:{}/?token={}",
+                        settings.port,
+                        settings.token
+                    )
+                    .green()
+                );
+            }
+        }
+
+        StreamCommand::Stop(command) => {
+            let monitor = client.find_monitor(&command.id)?;
+            drop(client);
+
+            stream::request(&driver_ipc::StreamCommand::RequestStop(monitor.id))?;
+
+            if !opts.json {
+                println!("Stopped serving virtual monitor {}.", monitor.id);
+            }
+        }
+
+        StreamCommand::List => {
+            drop(client);
+
+            let driver_ipc::StreamCommand::ReplyStatus(streams) =
+                stream::request(&driver_ipc::StreamCommand::RequestStatus)?
+            else {
+                eyre::bail!("received unexpected reply from stream pipe");
+            };
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &streams)?;
+            } else if streams.is_empty() {
+                println!("No virtual monitors are served.");
+            } else {
+                println!("{}", "Served virtual monitors".underline());
+                for info in &streams {
+                    println!(
+                        "Monitor {} on port {} {}",
+                        info.settings.id,
+                        info.settings.port.green(),
+                        lazy_format!("({} clients)", info.clients).dimmed()
+                    );
+                    if let Some(error) = &info.error {
+                        println!("  {} {error}", "stopped:".red());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn setup(client: &mut Client, opts: &GlobalOptions, command: SetupCommand) -> eyre::Result<()> {
+    match command {
+        SetupCommand::Sunshine(command) if command.undo => {
+            let id = sunshine::undo(client)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &id)?;
+            } else if let Some(id) = id {
+                println!(
+                    "Tore down virtual monitor {} and restored the displays.",
+                    id.green()
+                );
+            } else {
+                println!("Nothing to tear down.");
+            }
+        }
+
+        SetupCommand::Sunshine(command) => {
+            let mode = match command.mode {
+                Some(mode) => mode,
+                None => sunshine::client_mode()?,
+            };
+            let setup = sunshine::setup(client, &command.name, mode, command.disable_physical)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &setup)?;
+            } else {
+                println!(
+                    "Virtual monitor {} is the primary display {} at {}.",
+                    setup.id.green(),
+                    setup.display.blue(),
+                    mode::Mode::from(setup.mode),
+                );
+                if !setup.disabled.is_empty() {
+                    println!("Turned off {}.", setup.disabled.iter().join_with(", "));
+                }
+            }
+        }
+
+        SetupCommand::Client(command) => {
+            let setup = preset::setup(client, command.name.as_deref(), command.client)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &setup)?;
+            } else {
+                let mode = setup.preset.mode;
+                println!(
+                    "Virtual monitor {} looks like a {} on display {}, at {}x{}@{} and {}% scale.",
+                    setup.id.green(),
+                    setup.preset.template.blue(),
+                    setup.display.blue(),
+                    mode.width,
+                    mode.height,
+                    mode.refresh_rate,
+                    setup.preset.scale
+                );
+                if setup.preset.hdr && !setup.hdr {
+                    println!("HDR is left off, the driver doesn't support it.");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn physical(
+    client: &mut Client,
+    opts: &GlobalOptions,
+    command: &PhysicalCommand,
+) -> eyre::Result<()> {
+    match command {
+        PhysicalCommand::List => {
+            let physical = physical::list(client)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &physical)?;
+            } else {
+                println!("{}", "Physical displays".underline());
+                for display in &physical.active {
+                    let primary_label = lazy_format!(
+                        if display.primary => (" {}", "(primary)".dimmed())
+                        else => ""
+                    );
+                    println!(
+                        "{} {} {}{}{}{primary_label}",
+                        "-".dimmed(),
+                        display.name.green(),
+                        "[".dimmed(),
+                        display.friendly_name,
+                        "]".dimmed(),
+                    );
+                }
+                for display in &physical.disabled {
+                    println!(
+                        "{} {} {}{}{} {}",
+                        "-".dimmed(),
+                        display.name.green(),
+                        "[".dimmed(),
+                        display.friendly_name,
+                        "]".dimmed(),
+                        "(off)".red(),
+                    );
+                }
+            }
+        }
+
+        PhysicalCommand::Disable(command) => {
+            let disabled = physical::disable(client, &command.displays)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &disabled)?;
+            } else {
+                let names = disabled.iter().map(|display| &display.name);
+                println!("Turned off {}.", names.join_with(", "));
+            }
+        }
+
+        PhysicalCommand::Enable(command) => {
+            let enabled = physical::enable(&command.displays)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &enabled)?;
+            } else if enabled.is_empty() {
+                println!("No displays are turned off.");
+            } else {
+                let names = enabled.iter().map(|display| &display.name);
+                println!("Turned on {}.", names.join_with(", "));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(windows)]
+fn hook(client: &mut Client, opts: &GlobalOptions, command: &HookCommand) -> eyre::Result<()> {
+    match command {
+        HookCommand::PreLaunch(command) => {
+            let session = hook::pre_launch(client, &command.profile, command.pid)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &session)?;
+            } else {
+                println!(
+                    "Loaded profile {} until process {} exits.",
+                    session.profile.green(),
+                    session.launcher.green()
+                );
+            }
+        }
+
+        HookCommand::PostLaunch => {
+            let session = hook::post_launch(client)?;
+
+            if opts.json {
+                let mut stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(&mut stdout, &session)?;
+            } else if let Some(session) = session {
+                println!("Undid profile {}.", session.profile.green());
+            } else {
+                println!("No profile to undo.");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn with_monitor(
+    mut client: Client,
+    options: &GlobalOptions,
+    command: WithMonitorCommand,
+) -> eyre::Result<()> {
+    let id = client.new_id(None)?;
+    let modes = command
+        .mode
+        .into_iter()
+        .map(driver_ipc::Mode::from)
+        .collect();
+    let monitor = driver_ipc::Monitor {
+        name: command.name,
+        // not restored after a reboot that skipped the removal
+        ephemeral: true,
+        ..driver_ipc::Monitor::new(id, modes)
+    };
+    client.notify(vec![monitor])?;
+
+    let timeout = std::time::Duration::from_secs(command.timeout);
+    let shown = wait_for_active_mode(&mut client, id, timeout);
+    // the command may use the driver itself, which only takes one client at a time
+    drop(client);
+
+    let status = shown.and_then(|mode| {
+        eprintln!(
+            "Virtual monitor {} is shown in {}x{}@{}.",
+            id.green(),
+            mode.width,
+            mode.height,
+            mode.refresh_rate
+        );
+
+        // Ctrl+C is meant for the command, the monitor is removed once it exits
+        #[cfg(windows)]
+        unsafe {
+            windows::Win32::System::Console::SetConsoleCtrlHandler(Some(ignore_ctrl_c), true)
+        }?;
+
+        std::process::Command::new(&command.command[0])
+            .args(&command.command[1..])
+            .env("VDD_MONITOR_ID", id.to_string())
+            .status()
+            .wrap_err_with(|| format!("failed to run {}", command.command[0]))
+    });
+
+    // the command's exit code matters more to scripts than a monitor left behind
+    match connect(options).and_then(|mut client| client.remove(vec![id])) {
+        Ok(()) => eprintln!("Removed virtual monitor {}.", id.green()),
+        Err(e) => eprintln!(
+            "{}: failed to remove virtual monitor {id}: {e:#}",
+            "warning".yellow()
+        ),
+    }
+
+    // a command killed by a signal has no exit code
+    std::process::exit(status?.code().unwrap_or(1));
+}
+
+fn wait_for_active_mode(
+    client: &mut Client,
+    id: driver_ipc::Id,
+    timeout: std::time::Duration,
+) -> eyre::Result<driver_ipc::ActiveMode> {
+    const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+    let start = std::time::Instant::now();
+    loop {
+        let active_mode = client.monitor(id)?.and_then(|monitor| monitor.active_mode);
+        if let Some(mode) = active_mode {
+            return Ok(mode);
+        }
+
+        if start.elapsed() > timeout {
+            eyre::bail!(
+                "virtual monitor {id} wasn't shown within {}s, check that it's active in the display settings",
+                timeout.as_secs()
+            );
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+#[cfg(windows)]
+unsafe extern "system" fn ignore_ctrl_c(_: u32) -> windows::Win32::Foundation::BOOL {
+    // handled, so the default handler doesn't exit
+    windows::Win32::Foundation::TRUE
+}
@@ -21,7 +21,7 @@ const DEFAULT_REFRESH_RATE: driver_ipc::RefreshRate = 60;
 /// - It implements [`std::fmt::Display`] with a nice output format.
 /// - The list of resolutions is represented with a set, meaning that duplicate
 ///   resolutions will be ignored.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Mode {
     pub width: driver_ipc::Dimen,
     pub height: driver_ipc::Dimen,
@@ -187,3 +187,151 @@ pub fn remove(
         .collect();
     Ok(modes)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use proptest::{collection, prelude::*, sample};
+
+    use super::*;
+
+    /// Modes from a few resolutions and refresh rates, so that they overlap
+    fn mode() -> impl Strategy<Value = Mode> {
+        (
+            sample::select(vec![1280, 1920, 2560]),
+            sample::select(vec![720, 1080]),
+            collection::btree_set(sample::select(vec![30, 60, 120, 144]), 0..=3),
+        )
+            .prop_map(|(width, height, refresh_rates)| Mode {
+                width,
+                height,
+                refresh_rates,
+            })
+    }
+
+    fn modes() -> impl Strategy<Value = Vec<Mode>> {
+        collection::vec(mode(), 0..8)
+    }
+
+    /// `merge` and `remove` return modes in any order
+    fn sorted(mut modes: Vec<Mode>) -> Vec<Mode> {
+        modes.sort_by_key(|mode| (mode.width, mode.height));
+        modes
+    }
+
+    fn same_resolution(a: &Mode, b: &Mode) -> bool {
+        (a.width, a.height) == (b.width, b.height)
+    }
+
+    fn find<'a>(modes: &'a [Mode], resolution: &Mode) -> Option<&'a Mode> {
+        modes.iter().find(|mode| same_resolution(mode, resolution))
+    }
+
+    proptest! {
+        #[test]
+        fn display_parse_round_trip(mode in mode()) {
+            prop_assert_eq!(mode.to_string().parse::<Mode>().unwrap(), mode);
+        }
+
+        #[test]
+        fn parse_never_panics(s in "\\PC*") {
+            _ = s.parse::<Mode>();
+        }
+
+        #[test]
+        fn parse_formatted(
+            width: driver_ipc::Dimen,
+            height: driver_ipc::Dimen,
+            refresh_rates in collection::vec(any::<driver_ipc::RefreshRate>(), 1..4),
+        ) {
+            let s = format!("{width}x{height}@{}", refresh_rates.iter().join_with("/"));
+            let mode = s.parse::<Mode>().unwrap();
+
+            prop_assert_eq!((mode.width, mode.height), (width, height));
+            prop_assert_eq!(mode.refresh_rates, refresh_rates.into_iter().collect::<BTreeSet<_>>());
+        }
+
+        #[test]
+        fn merge_is_idempotent(modes in modes()) {
+            let merged = merge(modes);
+            prop_assert_eq!(sorted(merge(merged.clone())), sorted(merged));
+        }
+
+        #[test]
+        fn merge_is_commutative(a in modes(), b in modes()) {
+            let ab = merge(a.iter().chain(&b).cloned());
+            let ba = merge(b.iter().chain(&a).cloned());
+            prop_assert_eq!(sorted(ab), sorted(ba));
+        }
+
+        #[test]
+        fn merge_combines_refresh_rates(modes in modes()) {
+            let merged = merge(modes.clone());
+
+            let resolutions = merged
+                .iter()
+                .map(|mode| (mode.width, mode.height))
+                .collect::<HashSet<_>>();
+            prop_assert_eq!(resolutions.len(), merged.len());
+
+            for mode in &modes {
+                let merged = find(&merged, mode).unwrap();
+                prop_assert!(mode.refresh_rates.is_subset(&merged.refresh_rates));
+            }
+            for mode in &merged {
+                let refresh_rates = modes
+                    .iter()
+                    .filter(|other| same_resolution(other, mode))
+                    .flat_map(|other| &other.refresh_rates)
+                    .copied()
+                    .collect::<BTreeSet<_>>();
+                prop_assert_eq!(&mode.refresh_rates, &refresh_rates);
+            }
+        }
+
+        #[test]
+        fn remove_keeps_unrelated_refresh_rates(modes in modes(), remove_mode in mode()) {
+            let before = merge(modes.iter().cloned().map(|mut mode| {
+                mode.ensure_refresh_rate();
+                mode
+            }));
+
+            if let Ok(after) = remove(modes, &remove_mode) {
+                for mode in &after {
+                    prop_assert!(!mode.refresh_rates.is_empty());
+                    prop_assert!(find(&before, mode).is_some());
+                }
+
+                for mode in &before {
+                    let refresh_rates = find(&after, mode)
+                        .map(|mode| mode.refresh_rates.clone())
+                        .unwrap_or_default();
+
+                    if !same_resolution(mode, &remove_mode) {
+                        prop_assert_eq!(&refresh_rates, &mode.refresh_rates);
+                    } else if remove_mode.refresh_rates.is_empty() {
+                        prop_assert!(refresh_rates.is_empty());
+                    } else {
+                        let expected = &mode.refresh_rates - &remove_mode.refresh_rates;
+                        prop_assert_eq!(refresh_rates, expected);
+                    }
+                }
+            } else {
+                // only if the resolution or one of the refresh rates doesn't exist
+                let exists = find(&before, &remove_mode).is_some_and(|mode| {
+                    remove_mode.refresh_rates.is_subset(&mode.refresh_rates)
+                });
+                prop_assert!(!exists);
+            }
+        }
+
+        #[test]
+        fn remove_existing_mode(modes in modes(), index: sample::Index) {
+            prop_assume!(!modes.is_empty());
+            let remove_mode = index.get(&modes).clone();
+
+            prop_assert!(remove(modes, &remove_mode).is_ok());
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Turning physical displays off and on again. Windows doesn't name displays
+//! that are off, so the ones turned off are remembered in the registry.
+
+use driver_ipc::DisplayTarget;
+use serde::Serialize;
+
+use crate::{
+    client::Client,
+    display::{self, Display},
+    settings,
+};
+
+// registry value of the settings key with the displays turned off
+const DISABLED_VALUE: &str = "physical";
+
+#[derive(Debug, Serialize)]
+pub struct Physical {
+    /// Physical displays that are on.
+    pub active: Vec<Display>,
+    /// Displays turned off with `physical disable`.
+    pub disabled: Vec<Display>,
+}
+
+pub fn list(client: &mut Client) -> eyre::Result<Physical> {
+    let virtual_targets = virtual_targets(client)?;
+
+    let active = display::displays()?
+        .into_iter()
+        .filter(|display| !virtual_targets.contains(&display.target))
+        .collect();
+    let disabled = settings::read(DISABLED_VALUE)?;
+
+    Ok(Physical { active, disabled })
+}
+
+/// Turn off physical displays, such as `DISPLAY1`, and remember them to turn
+/// them on again.
+pub fn disable(client: &mut Client, queries: &[String]) -> eyre::Result<Vec<Display>> {
+    let virtual_targets = virtual_targets(client)?;
+    let displays = display::displays()?;
+
+    let selected = queries
+        .iter()
+        .map(|query| {
+            let display = displays
+                .iter()
+                .find(|display| display.is_named(query))
+                .ok_or_else(|| eyre::eyre!("display {query} not found, or it's already off"))?;
+
+            if virtual_targets.contains(&display.target) {
+                eyre::bail!("{query} is a virtual monitor, use `disable` instead");
+            }
+
+            Ok(display.clone())
+        })
+        .collect::<eyre::Result<Vec<_>>>()?;
+    let targets = selected
+        .iter()
+        .map(|display| display.target)
+        .collect::<Vec<_>>();
+
+    // saved, so Windows doesn't turn them on again for the same displays
+    display::deactivate(&targets, true)?;
+
+    let mut disabled = settings::read::<Display>(DISABLED_VALUE)?;
+    disabled.retain(|display| !targets.contains(&display.target));
+    disabled.extend(selected.iter().cloned());
+    settings::write(DISABLED_VALUE, &disabled)?;
+
+    Ok(selected)
+}
+
+/// Turn displays turned off with [`disable`] on again, or all of them if
+/// `queries` is empty.
+pub fn enable(queries: &[String]) -> eyre::Result<Vec<Display>> {
+    let mut disabled = settings::read::<Display>(DISABLED_VALUE)?;
+
+    let selected = if queries.is_empty() {
+        disabled.clone()
+    } else {
+        queries
+            .iter()
+            .map(|query| {
+                disabled
+                    .iter()
+                    .find(|display| display.is_named(query))
+                    .cloned()
+                    .ok_or_else(|| {
+                        eyre::eyre!("display {query} wasn't turned off with `physical disable`")
+                    })
+            })
+            .collect::<eyre::Result<Vec<_>>>()?
+    };
+
+    // adapters get other IDs after a reboot, then the monitor's name has to do
+    let connected = display::connected()?;
+    let targets = selected
+        .iter()
+        .map(|display| {
+            connected
+                .iter()
+                .find(|(target, _)| *target == display.target)
+                .or_else(|| {
+                    connected
+                        .iter()
+                        .find(|(_, name)| *name == display.friendly_name)
+                })
+                .map(|(target, _)| *target)
+                .ok_or_else(|| eyre::eyre!("display {} isn't connected", display.name))
+        })
+        .collect::<eyre::Result<Vec<DisplayTarget>>>()?;
+
+    display::activate(&targets, true)?;
+
+    disabled.retain(|display| {
+        !selected
+            .iter()
+            .any(|enabled| enabled.target == display.target)
+    });
+    settings::write(DISABLED_VALUE, &disabled)?;
+
+    Ok(selected)
+}
+
+fn virtual_targets(client: &mut Client) -> eyre::Result<Vec<DisplayTarget>> {
+    Ok(client
+        .monitors()?
+        .iter()
+        .filter_map(|monitor| monitor.target)
+        .collect())
+}
@@ -0,0 +1,153 @@
+use driver_ipc::{Id, Monitor};
+use lazy_format::lazy_format;
+use owo_colors::OwoColorize;
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, GlobalOptions};
+
+/// Changes needed to get from the current to a desired set of monitors.
+///
+/// Shared by all declarative commands, so their dry-run and applied output
+/// looks the same, both pretty printed and as JSON.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct Plan {
+    /// Whether the changes were only planned, not applied.
+    pub dry_run: bool,
+    pub changes: Vec<Change>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Change {
+    pub action: Action,
+    pub id: Id,
+    pub name: Option<String>,
+    /// Why the change is needed, e.g. which fields differ.
+    pub reason: String,
+    /// The desired monitor, `None` when deleting.
+    pub monitor: Option<Monitor>,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Action {
+    Create,
+    Update,
+    Delete,
+}
+
+impl Plan {
+    /// Diff `desired` against `current`, matching monitors by ID. Monitors
+    /// that are only in `current` are deleted if `prune` is set, and left
+    /// alone otherwise.
+    pub fn diff(current: &[Monitor], desired: &[Monitor], prune: bool) -> Self {
+        let mut changes = Vec::new();
+
+        for monitor in desired {
+            let existing = current.iter().find(|existing| existing.id == monitor.id);
+
+            let (action, reason) = match existing {
+                None => (Action::Create, "not present".to_owned()),
+                Some(existing) => {
+                    let fields = existing.changed_fields(monitor);
+                    if fields.is_empty() {
+                        continue;
+                    }
+
+                    (Action::Update, format!("{} changed", fields.join(", ")))
+                }
+            };
+
+            changes.push(Change {
+                action,
+                id: monitor.id,
+                name: monitor.name.clone(),
+                reason,
+                monitor: Some(monitor.clone()),
+            });
+        }
+
+        if prune {
+            for monitor in current {
+                if desired.iter().all(|desired| desired.id != monitor.id) {
+                    changes.push(Change {
+                        action: Action::Delete,
+                        id: monitor.id,
+                        name: monitor.name.clone(),
+                        reason: "not in desired state".to_owned(),
+                        monitor: None,
+                    });
+                }
+            }
+        }
+
+        Self {
+            dry_run: true,
+            changes,
+        }
+    }
+
+    /// Send the planned changes to the driver.
+    pub fn apply(&mut self, client: &mut Client) -> eyre::Result<()> {
+        let notify = self
+            .changes
+            .iter()
+            .filter_map(|change| change.monitor.clone())
+            .collect::<Vec<_>>();
+        let remove = self
+            .changes
+            .iter()
+            .filter(|change| change.action == Action::Delete)
+            .map(|change| change.id)
+            .collect::<Vec<_>>();
+
+        if !notify.is_empty() {
+            client.notify(notify)?;
+        }
+        if !remove.is_empty() {
+            client.remove(remove)?;
+        }
+
+        self.dry_run = false;
+
+        Ok(())
+    }
+
+    pub fn print(&self, opts: &GlobalOptions) -> eyre::Result<()> {
+        if opts.json {
+            let mut stdout = std::io::stdout().lock();
+            serde_json::to_writer_pretty(&mut stdout, self)?;
+            return Ok(());
+        }
+
+        if self.changes.is_empty() {
+            println!("No changes needed.");
+            return Ok(());
+        }
+
+        if self.dry_run {
+            println!("{} {}", "Planned changes".underline(), "(dry run)".dimmed());
+        } else {
+            println!("{}", "Applied changes".underline());
+        }
+
+        for change in &self.changes {
+            let action = lazy_format!(match (change.action) {
+                Action::Create => ("{}", "+ create".green()),
+                Action::Update => ("{}", "~ update".yellow()),
+                Action::Delete => ("{}", "- delete".red()),
+            });
+            let name_label = lazy_format!(match (&change.name) {
+                Some(name) => (" {}{name}{}", "[".dimmed(), "]".dimmed()),
+                None => "",
+            });
+
+            println!(
+                "{action} monitor {}{name_label}: {}",
+                change.id.green(),
+                change.reason.dimmed()
+            );
+        }
+
+        Ok(())
+    }
+}
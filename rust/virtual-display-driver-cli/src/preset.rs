@@ -0,0 +1,172 @@
+//! Presets of `setup client` for popular streaming clients, which set up a
+//! virtual monitor from a template in the mode, scale and HDR the client
+//! looks best with.
+
+#[cfg(any(windows, test))]
+use driver_ipc::ActiveMode;
+#[cfg(windows)]
+use driver_ipc::{Id, Monitor};
+#[cfg(any(windows, test))]
+use serde::Serialize;
+
+#[cfg(windows)]
+use crate::{client::Client, display, template};
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum StreamingClient {
+    /// Steam Deck, streaming with Steam Link or Moonlight.
+    Steamdeck,
+    /// Steam Deck OLED, with its 90 Hz HDR screen.
+    SteamdeckOled,
+    /// Meta Quest 2, streaming a desktop with e.g. Virtual Desktop.
+    Quest2,
+    /// Meta Quest 3, streaming a desktop with e.g. Virtual Desktop.
+    Quest3,
+    /// iPad Pro 12.9", streaming with Moonlight.
+    IpadPro,
+}
+
+/// How a virtual monitor is set up for a streaming client.
+#[cfg(any(windows, test))]
+#[derive(Debug, Serialize)]
+pub struct Preset {
+    /// Name of the [`Template`](crate::template::Template) the monitor is made from.
+    pub template: &'static str,
+    /// Mode to use, one of the template's.
+    pub mode: ActiveMode,
+    /// Scale in percent, one the display settings offer.
+    pub scale: u32,
+    /// Whether to turn HDR on, where the driver supports it.
+    pub hdr: bool,
+}
+
+#[cfg(any(windows, test))]
+impl StreamingClient {
+    pub fn preset(self) -> Preset {
+        let preset =
+            |template, (width, height, refresh_rate): (u32, u32, u32), scale, hdr| Preset {
+                template,
+                mode: ActiveMode {
+                    width,
+                    height,
+                    refresh_rate,
+                },
+                scale,
+                hdr,
+            };
+
+        match self {
+            Self::Steamdeck => preset("Steam Deck", (1280, 800, 60), 100, false),
+            Self::SteamdeckOled => preset("Steam Deck OLED", (1280, 800, 90), 100, true),
+            // the desktop is a screen floating in front of the viewer
+            Self::Quest2 => preset("Quest 2", (1832, 1920, 90), 150, false),
+            Self::Quest3 => preset("Quest 3", (2064, 2208, 90), 150, false),
+            Self::IpadPro => preset("iPad Pro 12.9", (2732, 2048, 120), 200, true),
+        }
+    }
+}
+
+/// What `setup client` did, for the output.
+#[cfg(windows)]
+#[derive(Debug, Serialize)]
+pub struct Setup {
+    pub id: Id,
+    /// GDI name of the monitor's display.
+    pub display: String,
+    pub preset: Preset,
+    /// Whether HDR was turned on. It's left off if the driver doesn't
+    /// support it.
+    pub hdr: bool,
+}
+
+/// Make the virtual monitor named `name`, or after the client's template,
+/// look like the template in the preset's mode, adding it if there's none,
+/// then set its scale and HDR once Windows shows it.
+#[cfg(windows)]
+pub fn setup(
+    client: &mut Client,
+    name: Option<&str>,
+    streaming: StreamingClient,
+) -> eyre::Result<Setup> {
+    let preset = streaming.preset();
+    let template = template::find(preset.template)?;
+    let name = name.unwrap_or(template.name);
+
+    let modes = template
+        .modes()
+        .into_iter()
+        .map(driver_ipc::Mode::from)
+        .collect::<Vec<_>>();
+    let edid = template.edid().build()?;
+
+    let existing = client
+        .monitors()?
+        .iter()
+        .find(|monitor| monitor.name.as_deref() == Some(name))
+        .cloned();
+    let mut monitor = match existing {
+        Some(existing) => Monitor {
+            enabled: true,
+            modes,
+            edid: Some(edid),
+            audio: template.audio,
+            auto_plug: true,
+            ..existing
+        },
+        None => Monitor {
+            name: Some(name.to_owned()),
+            edid: Some(edid),
+            audio: template.audio,
+            ..Monitor::new(client.new_id(None)?, modes)
+        },
+    };
+    if !monitor.prefer_mode(preset.mode) {
+        eyre::bail!(
+            "the {} template doesn't have the preset's mode",
+            template.name
+        );
+    }
+    let id = monitor.id;
+    client.notify(vec![monitor])?;
+
+    let shown = display::wait_for_monitor(client, id)?;
+    display::set_scale(shown.target, preset.scale)?;
+
+    let hdr_supported = client
+        .monitor(id)?
+        .and_then(|monitor| monitor.capabilities)
+        .is_some_and(|capabilities| capabilities.hdr_supported);
+    let hdr = preset.hdr && hdr_supported;
+    if hdr {
+        display::set_hdr(shown.target, true)?;
+    }
+
+    Ok(Setup {
+        id,
+        display: shown.name,
+        preset,
+        hdr,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::ValueEnum;
+
+    use super::*;
+    use crate::template;
+
+    #[test]
+    fn presets_use_modes_of_their_templates() {
+        for client in StreamingClient::value_variants() {
+            let preset = client.preset();
+            let template = template::find(preset.template).unwrap();
+
+            let has_mode = template.modes().iter().any(|mode| {
+                (mode.width, mode.height) == (preset.mode.width, preset.mode.height)
+                    && mode.refresh_rates.contains(&preset.mode.refresh_rate)
+            });
+            assert!(has_mode, "{client:?}");
+        }
+    }
+}
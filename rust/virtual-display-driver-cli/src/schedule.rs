@@ -0,0 +1,46 @@
+use driver_ipc::Trigger;
+
+/// Parse a local time of day like `18:00`, for `--at`.
+pub fn parse_time(s: &str) -> Result<Trigger, String> {
+    let error = || format!("invalid time `{s}`, expected a time like `18:00`");
+
+    let (hour, minute) = s.split_once(':').ok_or_else(error)?;
+    let hour = hour.parse::<u8>().map_err(|_| error())?;
+    let minute = minute.parse::<u8>().map_err(|_| error())?;
+
+    if hour > 23 || minute > 59 {
+        return Err(error());
+    }
+
+    Ok(Trigger::At { hour, minute })
+}
+
+#[cfg(windows)]
+pub fn describe(trigger: &Trigger) -> String {
+    match trigger {
+        Trigger::Login => "at login".to_owned(),
+        Trigger::At { hour, minute } => format!("at {hour:02}:{minute:02}"),
+        Trigger::Process(name) => format!("while {name} runs"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times() {
+        assert_eq!(
+            parse_time("18:00"),
+            Ok(Trigger::At {
+                hour: 18,
+                minute: 0
+            })
+        );
+        assert_eq!(parse_time("7:05"), Ok(Trigger::At { hour: 7, minute: 5 }));
+
+        for time in ["24:00", "12:60", "1800", "18:", "-1:00"] {
+            assert!(parse_time(time).is_err(), "{time}");
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Settings kept in the registry next to the persisted monitors, for
+//! `vdd-server` to apply.
+
+use eyre::Context as _;
+use serde::{de::DeserializeOwned, Serialize};
+use winreg::{enums::HKEY_CURRENT_USER, RegKey};
+
+/// Read a json list from a value of the settings key, such as
+/// [`driver_ipc::SCHEDULE_VALUE`]. It's empty if the value isn't set.
+pub fn read<T: DeserializeOwned>(value: &str) -> eyre::Result<Vec<T>> {
+    Ok(read_value(value)?.unwrap_or_default())
+}
+
+/// Read json from a value of the settings key, `None` if it isn't set.
+pub fn read_value<T: DeserializeOwned>(value: &str) -> eyre::Result<Option<T>> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let Ok(settings) = hkcu.open_subkey(driver_ipc::SETTINGS_KEY) else {
+        return Ok(None);
+    };
+    let Ok(data) = settings.get_value::<String, _>(value) else {
+        return Ok(None);
+    };
+
+    serde_json::from_str(&data).wrap_err_with(|| format!("invalid `{value}` in the registry"))
+}
+
+/// Replace a value of the settings key. `vdd-server` picks up the change on
+/// its own.
+pub fn write<T: Serialize + ?Sized>(value: &str, data: &T) -> eyre::Result<()> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let (settings, _) = hkcu
+        .create_subkey(driver_ipc::SETTINGS_KEY)
+        .wrap_err("failed to open the settings registry key")?;
+
+    let data = serde_json::to_string(data)?;
+    settings
+        .set_value(value, &data)
+        .wrap_err_with(|| format!("failed to write `{value}` to the registry"))?;
+
+    Ok(())
+}
+
+/// Delete a value of the settings key, if it's set.
+pub fn remove(value: &str) -> eyre::Result<()> {
+    let hkcu = RegKey::predef(HKEY_CURRENT_USER);
+    let Ok(settings) =
+        hkcu.open_subkey_with_flags(driver_ipc::SETTINGS_KEY, winreg::enums::KEY_WRITE)
+    else {
+        return Ok(());
+    };
+
+    match settings.delete_value(value) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+            Err(e).wrap_err_with(|| format!("failed to delete `{value}` from the registry"))
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,43 @@
+use std::io::{self, Write as _};
+
+use driver_ipc::StreamCommand;
+use eyre::Context as _;
+
+/// Send a command to `vdd-stream` and wait for its reply.
+pub fn request(command: &StreamCommand) -> eyre::Result<StreamCommand> {
+    let (mut reader, mut writer) =
+        win_pipes::NamedPipeClientOptions::new(driver_ipc::STREAM_PIPE_NAME)
+            .access_duplex()
+            .mode_message()
+            .create()
+            .context("Failed to connect to vdd-stream; please ensure it's running.")?;
+
+    // the pipe is in message mode, so the whole command must go out in a
+    // single write
+    let message = serde_json::to_vec(command).wrap_err("failed to serialize command")?;
+    writer
+        .write_all(&message)
+        .wrap_err("failed to write to stream pipe")?;
+    writer.flush().wrap_err("failed to flush stream pipe")?;
+
+    let reply = reader
+        .read_full()
+        .wrap_err("failed to read from stream pipe")?;
+    let reply = serde_json::from_slice(&reply).wrap_err("failed to deserialize command")?;
+
+    if let StreamCommand::ReplyError(e) = reply {
+        eyre::bail!("vdd-stream: {e}");
+    }
+
+    Ok(reply)
+}
+
+/// A random token for a stream, when none was given.
+pub fn random_token() -> eyre::Result<String> {
+    let mut bytes = [0; 16];
+    getrandom::getrandom(&mut bytes)
+        .map_err(io::Error::from)
+        .wrap_err("failed to generate a stream token")?;
+
+    Ok(bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
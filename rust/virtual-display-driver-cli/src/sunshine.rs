@@ -0,0 +1,149 @@
+//! Setting up a virtual monitor for a Sunshine stream, as its "do" prep
+//! command, and tearing it down again as its "undo" command.
+
+use driver_ipc::{Id, Monitor};
+use serde::{Deserialize, Serialize};
+
+use crate::{client::Client, display, mode, settings};
+
+// registry value of the settings key remembering what `setup` changed
+const SESSION_VALUE: &str = "sunshine";
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Session {
+    id: Id,
+    // the monitor before the setup, `None` if it was added for the stream
+    previous: Option<Monitor>,
+}
+
+/// What `setup` did, for the output.
+#[derive(Debug, Serialize)]
+pub struct Setup {
+    pub id: Id,
+    /// GDI name of the monitor's display, for Sunshine's `output_name`.
+    pub display: String,
+    pub mode: driver_ipc::Mode,
+    /// Displays that were turned off.
+    pub disabled: Vec<String>,
+}
+
+/// The client's mode, from the variables Sunshine sets for prep commands.
+pub fn client_mode() -> eyre::Result<mode::Mode> {
+    let var = |name| {
+        std::env::var(name).map_err(|_| {
+            eyre::eyre!("{name} isn't set, pass a mode or run this as a Sunshine prep command")
+        })
+    };
+
+    format!(
+        "{}x{}@{}",
+        var("SUNSHINE_CLIENT_WIDTH")?,
+        var("SUNSHINE_CLIENT_HEIGHT")?,
+        var("SUNSHINE_CLIENT_FPS")?,
+    )
+    .parse()
+}
+
+/// Enable a virtual monitor named `name` in `mode`, adding it if there's
+/// none, and make it the primary display. With `disable_physical`, the
+/// displays of other drivers are turned off.
+pub fn setup(
+    client: &mut Client,
+    name: &str,
+    mode: mode::Mode,
+    disable_physical: bool,
+) -> eyre::Result<Setup> {
+    let mode = driver_ipc::Mode::from(mode);
+
+    let existing = client
+        .monitors()?
+        .iter()
+        .find(|monitor| monitor.name.as_deref() == Some(name))
+        .cloned();
+
+    // a previous stream that wasn't torn down already changed the monitor
+    let session = settings::read_value::<Session>(SESSION_VALUE)?.filter(|session| {
+        existing
+            .as_ref()
+            .is_some_and(|monitor| monitor.id == session.id)
+    });
+    let previous = match session {
+        Some(session) => session.previous,
+        None => existing.clone(),
+    };
+
+    let monitor = match existing {
+        Some(existing) => Monitor {
+            enabled: true,
+            modes: vec![mode.clone()],
+            auto_plug: true,
+            ..existing
+        },
+        None => Monitor {
+            name: Some(name.to_owned()),
+            // not restored after a reboot that skipped the teardown
+            ephemeral: true,
+            ..Monitor::new(client.new_id(None)?, vec![mode.clone()])
+        },
+    };
+    let id = monitor.id;
+
+    // saved first, so a failed setup can be undone too
+    settings::write(SESSION_VALUE, &Session { id, previous })?;
+    client.notify(vec![monitor])?;
+
+    let shown = display::wait_for_monitor(client, id)?;
+
+    let mut disabled = Vec::new();
+    if disable_physical {
+        let virtual_targets = client
+            .monitors()?
+            .iter()
+            .filter_map(|monitor| monitor.target)
+            .collect::<Vec<_>>();
+        let physical = display::displays()?
+            .into_iter()
+            .filter(|display| !virtual_targets.contains(&display.target))
+            .collect::<Vec<_>>();
+
+        if !physical.is_empty() {
+            let targets = physical
+                .iter()
+                .map(|display| display.target)
+                .collect::<Vec<_>>();
+            display::deactivate(&targets, false)?;
+            disabled = physical.into_iter().map(|display| display.name).collect();
+        }
+    }
+
+    // turning off the primary display moves the role to another one
+    display::set_primary(shown.target)?;
+
+    Ok(Setup {
+        id,
+        display: shown.name,
+        mode,
+        disabled,
+    })
+}
+
+/// Undo `setup`: remove the monitor it added, or restore the one it
+/// changed, and restore the layout of the displays. Returns the ID of the
+/// monitor, or `None` if there was nothing to undo.
+pub fn undo(client: &mut Client) -> eyre::Result<Option<Id>> {
+    let Some(session) = settings::read_value::<Session>(SESSION_VALUE)? else {
+        return Ok(None);
+    };
+
+    match session.previous {
+        Some(previous) => {
+            client.notify(vec![previous])?;
+        }
+        None => client.remove(vec![session.id])?,
+    }
+
+    display::restore()?;
+    settings::remove(SESSION_VALUE)?;
+
+    Ok(Some(session.id))
+}
@@ -0,0 +1,323 @@
+//! Built-in monitor templates for `add --template`, to make a virtual monitor
+//! look like a common TV, monitor, phone or handheld.
+
+use driver_ipc::edid::{CtaExtension, DetailedTiming, EdidBuilder, RangeLimits};
+use serde::Serialize;
+
+use crate::mode;
+
+/// A device a virtual monitor can look like: its modes, and what its EDID
+/// says about it.
+///
+/// The EDID is made like the driver's own, with the vendor, product, name,
+/// size and timings of the device. It isn't a copy of the device's EDID.
+#[derive(Debug, Serialize)]
+pub struct Template {
+    pub name: &'static str,
+    pub kind: Kind,
+    /// Modes like on the command line, the first one is the native one.
+    pub modes: &'static [&'static str],
+    /// Three letter PNP id of the manufacturer.
+    pub vendor: &'static str,
+    pub product: u16,
+    /// Display product name in the EDID, which Windows shows.
+    pub edid_name: &'static str,
+    /// Width and height of the screen in centimeters, 0 if it has none.
+    pub size_cm: (u8, u8),
+    /// Whether it advertises HDMI audio.
+    pub audio: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    Monitor,
+    Tv,
+    Phone,
+    Tablet,
+    Handheld,
+    Headset,
+}
+
+impl std::fmt::Display for Kind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Monitor => "monitor",
+            Self::Tv => "TV",
+            Self::Phone => "phone",
+            Self::Tablet => "tablet",
+            Self::Handheld => "handheld",
+            Self::Headset => "headset",
+        })
+    }
+}
+
+pub const TEMPLATES: &[Template] = &[
+    Template {
+        name: "LG C2 4K120",
+        kind: Kind::Tv,
+        modes: &["3840x2160@60/120", "2560x1440@60/120", "1920x1080@60/120"],
+        vendor: "GSM",
+        product: 0xC0A9,
+        edid_name: "LG TV SSCR2",
+        size_cm: (121, 68),
+        audio: true,
+    },
+    Template {
+        name: "Samsung QN90B 4K144",
+        kind: Kind::Tv,
+        modes: &[
+            "3840x2160@60/120/144",
+            "2560x1440@60/120/144",
+            "1920x1080@60/120/144",
+        ],
+        vendor: "SAM",
+        product: 0x7375,
+        edid_name: "QCQ90S",
+        size_cm: (121, 68),
+        audio: true,
+    },
+    Template {
+        name: "Dell U2723QE",
+        kind: Kind::Monitor,
+        modes: &["3840x2160@60", "2560x1440@60", "1920x1080@60"],
+        vendor: "DEL",
+        product: 0x4293,
+        edid_name: "DELL U2723QE",
+        size_cm: (60, 34),
+        audio: false,
+    },
+    Template {
+        name: "Samsung Odyssey G9",
+        kind: Kind::Monitor,
+        modes: &[
+            "5120x1440@60/120/240",
+            "3840x1080@60/120/240",
+            "2560x1440@60/120/240",
+        ],
+        vendor: "SAM",
+        product: 0x7066,
+        edid_name: "Odyssey G9",
+        size_cm: (119, 34),
+        audio: false,
+    },
+    Template {
+        name: "1440p 165Hz",
+        kind: Kind::Monitor,
+        modes: &["2560x1440@60/120/144/165", "1920x1080@60/120/144/165"],
+        vendor: "VDD",
+        product: 0x1440,
+        edid_name: "QHD 165Hz",
+        size_cm: (60, 34),
+        audio: false,
+    },
+    Template {
+        name: "iPad Pro 12.9",
+        kind: Kind::Tablet,
+        modes: &["2732x2048@60/120", "2048x1536@60/120", "1366x1024@60/120"],
+        vendor: "APP",
+        product: 0xAE31,
+        edid_name: "iPad Pro",
+        size_cm: (26, 20),
+        audio: false,
+    },
+    Template {
+        name: "iPhone 15 Pro",
+        kind: Kind::Phone,
+        modes: &["2556x1179@60/120", "1920x886@60/120"],
+        vendor: "APP",
+        product: 0xAE15,
+        edid_name: "iPhone",
+        size_cm: (14, 7),
+        audio: false,
+    },
+    Template {
+        name: "Pixel 8",
+        kind: Kind::Phone,
+        modes: &["2400x1080@60/120", "1920x864@60/120"],
+        vendor: "GGL",
+        product: 0x0008,
+        edid_name: "Pixel 8",
+        size_cm: (14, 6),
+        audio: false,
+    },
+    Template {
+        name: "Steam Deck",
+        kind: Kind::Handheld,
+        modes: &["1280x800@40/45/50/60"],
+        vendor: "VLV",
+        product: 0x3003,
+        edid_name: "Steam Deck",
+        size_cm: (15, 9),
+        audio: false,
+    },
+    Template {
+        name: "Steam Deck OLED",
+        kind: Kind::Handheld,
+        modes: &["1280x800@45/60/90"],
+        vendor: "VLV",
+        product: 0x3004,
+        edid_name: "Steam Deck",
+        size_cm: (16, 10),
+        audio: false,
+    },
+    Template {
+        name: "Quest 2",
+        kind: Kind::Headset,
+        modes: &["3664x1920@72/90/120", "1832x1920@72/90/120"],
+        vendor: "OVR",
+        product: 0x0002,
+        edid_name: "Quest 2",
+        size_cm: (0, 0),
+        audio: false,
+    },
+    Template {
+        name: "Quest 3",
+        kind: Kind::Headset,
+        modes: &["4128x2208@72/90/120", "2064x2208@72/90/120"],
+        vendor: "OVR",
+        product: 0x0003,
+        edid_name: "Quest 3",
+        size_cm: (0, 0),
+        audio: false,
+    },
+];
+
+/// Find a template by its name, ignoring case, spaces and punctuation, so
+/// `lg-c2-4k120` finds "LG C2 4K120".
+pub fn find(name: &str) -> eyre::Result<&'static Template> {
+    let key = |name: &str| {
+        name.chars()
+            .filter(char::is_ascii_alphanumeric)
+            .map(|c| c.to_ascii_lowercase())
+            .collect::<String>()
+    };
+
+    let wanted = key(name);
+    TEMPLATES
+        .iter()
+        .find(|template| key(template.name) == wanted)
+        .ok_or_else(|| eyre::eyre!("unknown template {name:?}, see `templates list`"))
+}
+
+impl Template {
+    pub fn modes(&self) -> Vec<mode::Mode> {
+        self.modes
+            .iter()
+            .map(|mode| mode.parse().expect("templates have valid modes"))
+            .collect()
+    }
+
+    /// An EDID builder with what the template says about the device.
+    pub fn edid(&self) -> EdidBuilder {
+        let modes = self.modes();
+        let timing = |mode: &mode::Mode, rate| {
+            let width = u16::try_from(mode.width).unwrap_or(u16::MAX);
+            let height = u16::try_from(mode.height).unwrap_or(u16::MAX);
+            DetailedTiming::reduced_blanking(width, height, rate)
+        };
+        let timings = || {
+            modes.iter().flat_map(move |mode| {
+                mode.refresh_rates
+                    .iter()
+                    .rev()
+                    .map(move |&rate| timing(mode, rate))
+            })
+        };
+        let rates = || {
+            modes
+                .iter()
+                .flat_map(|mode| mode.refresh_rates.iter().copied())
+        };
+
+        let max_clock_khz = timings().map(|t| t.pixel_clock_khz).max().unwrap_or(0);
+        let limits = RangeLimits {
+            vertical_hz: (
+                u8::try_from(rates().min().unwrap_or(60)).unwrap_or(u8::MAX),
+                u8::try_from(rates().max().unwrap_or(60)).unwrap_or(u8::MAX),
+            ),
+            horizontal_khz: (15, 255),
+            max_pixel_clock_mhz: u16::try_from(max_clock_khz.div_ceil(10_000) * 10)
+                .unwrap_or(u16::MAX)
+                .min(2550),
+        };
+
+        let mut builder = EdidBuilder::new()
+            .vendor(self.vendor)
+            .product(self.product)
+            .size_cm(self.size_cm.0, self.size_cm.1)
+            .name(self.edid_name)
+            .range_limits(limits);
+
+        // the fastest timing of the first mode that fits into a descriptor,
+        // the native modes of headsets are too large for one
+        if let Some(timing) = timings().find(DetailedTiming::fits) {
+            builder = builder.preferred_timing(timing);
+        }
+        if self.audio {
+            builder = builder.extension(CtaExtension::hdmi_audio());
+        }
+
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use driver_ipc::edid::{Descriptor, Edid, Extension};
+
+    use super::*;
+
+    #[test]
+    fn templates_build() {
+        for template in TEMPLATES {
+            let edid = template.edid().build().unwrap();
+            let edid = Edid::parse(&edid).unwrap();
+
+            assert_eq!(edid.vendor, template.vendor, "{}", template.name);
+            assert!(
+                edid.descriptors
+                    .contains(&Descriptor::Name(template.edid_name.to_owned())),
+                "{}",
+                template.name
+            );
+            assert_eq!(
+                edid.extensions
+                    .iter()
+                    .any(|ext| matches!(ext, Extension::Cta(_))),
+                template.audio,
+                "{}",
+                template.name
+            );
+
+            let Some(Descriptor::Timing(timing)) = edid.descriptors.first() else {
+                panic!("{} has no preferred timing", template.name);
+            };
+            let native = &template.modes()[0];
+            assert!(
+                template.modes().iter().any(|mode| {
+                    (mode.width, mode.height) == (u32::from(timing.width), u32::from(timing.height))
+                }),
+                "{} prefers {}x{} over {native}",
+                template.name,
+                timing.width,
+                timing.height
+            );
+        }
+    }
+
+    #[test]
+    fn names_are_unique() {
+        for template in TEMPLATES {
+            assert_eq!(find(template.name).unwrap().name, template.name);
+        }
+    }
+
+    #[test]
+    fn find_ignores_case_and_punctuation() {
+        assert_eq!(find("lg-c2-4k120").unwrap().name, "LG C2 4K120");
+        assert_eq!(find("steamdeck").unwrap().name, "Steam Deck");
+        assert_eq!(find("Steam Deck OLED").unwrap().name, "Steam Deck OLED");
+        assert!(find("CRT").is_err());
+    }
+}
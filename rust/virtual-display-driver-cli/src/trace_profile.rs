@@ -0,0 +1,56 @@
+use driver_ipc::{
+    TRACE_KEYWORD_CALLS, TRACE_KEYWORD_DEVICE, TRACE_KEYWORD_MONITOR, TRACE_KEYWORD_SWAP_CHAIN,
+    TRACE_PROVIDER_GUID, TRACE_PROVIDER_NAME,
+};
+
+/// ETW level that includes the per-frame events.
+const LEVEL_VERBOSE: u8 = 5;
+/// ETW level that includes everything except the per-frame events.
+const LEVEL_INFORMATIONAL: u8 = 4;
+
+/// Generate a WPR recording profile (`.wprp`) for the driver's trace
+/// provider. Record with `wpr -start <file>.wprp -filemode`, stop with
+/// `wpr -stop trace.etl`, and open the result in WPA.
+pub fn generate(frames: bool) -> String {
+    let level = if frames {
+        LEVEL_VERBOSE
+    } else {
+        LEVEL_INFORMATIONAL
+    };
+    let keywords = TRACE_KEYWORD_DEVICE
+        | TRACE_KEYWORD_MONITOR
+        | TRACE_KEYWORD_SWAP_CHAIN
+        | TRACE_KEYWORD_CALLS;
+    let name = TRACE_PROVIDER_NAME;
+
+    format!(
+        r#"<?xml version="1.0" encoding="utf-8"?>
+<WindowsPerformanceRecorder Version="1.0" Author="{name}">
+  <Profiles>
+    <EventCollector Id="EventCollector_{name}" Name="{name} Event Collector">
+      <BufferSize Value="64" />
+      <Buffers Value="64" />
+    </EventCollector>
+
+    <EventProvider Id="EventProvider_{name}" Name="{TRACE_PROVIDER_GUID}" Level="{level}">
+      <Keywords>
+        <Keyword Value="{keywords:#x}" />
+      </Keywords>
+    </EventProvider>
+
+    <Profile Id="{name}.Verbose.File" Name="{name}" Description="{name} events" LoggingMode="File" DetailLevel="Verbose">
+      <Collectors>
+        <EventCollectorId Value="EventCollector_{name}">
+          <EventProviders>
+            <EventProviderId Value="EventProvider_{name}" />
+          </EventProviders>
+        </EventCollectorId>
+      </Collectors>
+    </Profile>
+
+    <Profile Id="{name}.Verbose.Memory" Name="{name}" Description="{name} events" Base="{name}.Verbose.File" LoggingMode="Memory" DetailLevel="Verbose" />
+  </Profiles>
+</WindowsPerformanceRecorder>
+"#
+    )
+}
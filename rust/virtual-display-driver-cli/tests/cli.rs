@@ -0,0 +1,1279 @@
+//! End-to-end tests of the cli against `vdd-emulator`, which serves a port of its own for every
+//! test, so they run in parallel, without the driver and on any platform
+
+mod common;
+
+use assert_cmd::Command;
+use common::Driver;
+use driver_ipc::{ActiveMode, EventKind, GammaRamp, Monitor, Picture};
+use serde_json::Value;
+
+/// Keys of a json object, to check output schemas
+fn keys(value: &Value) -> Vec<&str> {
+    let mut keys = value
+        .as_object()
+        .expect("expected an object")
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    keys.sort_unstable();
+
+    keys
+}
+
+#[test]
+fn list_empty() {
+    let driver = Driver::start();
+
+    assert_eq!(driver.text(&["list"]), "No virtual monitors found.\n");
+    assert_eq!(driver.json::<Vec<Monitor>>(&["list"]), []);
+}
+
+#[test]
+fn add_and_list() {
+    let driver = Driver::start();
+
+    let id = driver.json::<u32>(&["add", "1920x1080@60/120", "2560x1440", "--name", "desk"]);
+    assert_eq!(id, 0);
+
+    let monitor = driver.monitor(0);
+    assert_eq!(monitor.name.as_deref(), Some("desk"));
+    assert!(monitor.enabled);
+    assert_eq!(monitor.modes.len(), 2);
+    for mode in &monitor.modes {
+        let refresh_rates = if mode.width == 1920 {
+            &[60, 120][..]
+        } else {
+            &[60]
+        };
+        assert_eq!(mode.refresh_rates, refresh_rates);
+    }
+
+    let output = driver.text(&["add", "1280x720", "--disabled", "--id", "7"]);
+    assert_eq!(output, "Added virtual monitor with ID 7 (disabled).\n");
+    assert!(!driver.monitor(7).enabled);
+
+    let list = driver.text(&["list"]);
+    assert!(list.contains("Monitor 0 [desk]:"), "{list}");
+    assert!(list.contains("Monitor 7 (disabled):"), "{list}");
+
+    let list = driver.json::<Value>(&["list"]);
+    assert_eq!(list.as_array().unwrap().len(), 2);
+    for key in ["id", "name", "enabled", "modes"] {
+        assert!(keys(&list[0]).contains(&key), "missing {key} in {list}");
+    }
+}
+
+#[test]
+fn list_verbose() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080@60/120", "1280x720", "--name", "desk"]);
+    driver.text(&["add", "1280x720", "--disabled", "--id", "7"]);
+
+    // not shown by default
+    assert!(!driver.text(&["list"]).contains("Active mode"));
+
+    let list = driver.text(&["list", "--verbose"]);
+    assert!(list.contains("Active mode: 1920x1080@60\n"), "{list}");
+    assert!(list.contains("Active mode: none\n"), "{list}");
+
+    // picked by the user in display settings
+    assert!(driver.emulator().set_active_mode(
+        0,
+        ActiveMode {
+            width: 1280,
+            height: 720,
+            refresh_rate: 60,
+        },
+    ));
+    let list = driver.text(&["list", "-v"]);
+    assert!(list.contains("Active mode: 1280x720@60\n"), "{list}");
+
+    let list = driver.json::<Value>(&["list"]);
+    assert_eq!(list[0]["active_mode"]["width"], 1280);
+    assert_eq!(list[1]["active_mode"], Value::Null);
+
+    let output = driver.text(&["events"]);
+    assert!(
+        output.contains("Monitor 0: active mode 1920x1080@60\n"),
+        "{output}"
+    );
+    assert!(
+        output.contains("Monitor 0: active mode 1280x720@60\n"),
+        "{output}"
+    );
+}
+
+#[test]
+fn list_filters() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "Desk"]);
+    driver.text(&["add", "3840x2160", "--name", "stream", "--disabled"]);
+    driver.text(&["add", "2560x1440", "--name", "desk 2"]);
+
+    let ids = |args: &[&str]| {
+        driver
+            .json::<Vec<Monitor>>(&[&["list"][..], args].concat())
+            .iter()
+            .map(|monitor| monitor.id)
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&["--enabled"]), [0, 2]);
+    assert_eq!(ids(&["--disabled"]), [1]);
+    assert_eq!(ids(&["--id", "2", "--id", "1"]), [1, 2]);
+    assert_eq!(
+        ids(&["--name-contains", "desk", "--sort", "resolution"]),
+        [2, 0]
+    );
+    assert_eq!(ids(&["--sort", "name"]), [0, 2, 1]);
+
+    let list = driver.text(&["list", "--disabled"]);
+    assert!(list.contains("Monitor 1"), "{list}");
+    assert!(!list.contains("Monitor 0"), "{list}");
+
+    let error = driver.fails(&["list", "--enabled", "--disabled"]);
+    assert!(error.contains("cannot be used with"), "{error}");
+}
+
+#[test]
+fn get() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "desk"]);
+    driver.text(&["add", "1280x720", "--id", "7"]);
+
+    let output = driver.text(&["get", "desk"]);
+    assert!(output.starts_with("Monitor 0 [desk]:\n"), "{output}");
+    assert!(!output.contains("Monitor 7"), "{output}");
+
+    assert_eq!(driver.json::<Monitor>(&["get", "7"]), driver.monitor(7));
+
+    let error = driver.fails(&["get", "3"]);
+    assert!(
+        error.contains("virtual monitor with ID 3 not found"),
+        "{error}"
+    );
+}
+
+#[test]
+fn add_options() {
+    let driver = Driver::start();
+
+    driver.text(&[
+        "add",
+        "1920x1080",
+        "--ephemeral",
+        "--hardware-cursor",
+        "--cursor-size",
+        "128",
+        "--remote-session",
+        "--gpu-export",
+        "--exclusive-capture",
+        "--no-auto-plug",
+        "--audio",
+    ]);
+
+    let monitor = driver.monitor(0);
+    assert!(monitor.ephemeral);
+    assert!(monitor.cursor.hardware);
+    assert_eq!(monitor.cursor.max_size, 128);
+    assert_eq!(monitor.session, driver_ipc::SessionPolicy::Remote);
+    assert!(monitor.gpu_export);
+    assert!(monitor.exclusive_capture);
+    assert!(!monitor.auto_plug);
+    assert!(monitor.audio);
+    assert!(!driver.emulator().is_arrived(0));
+}
+
+#[test]
+fn add_with_edid_from_display() {
+    let driver = Driver::start();
+    driver
+        .emulator()
+        .add_display("DISPLAY2", vec![0, 255, 255, 0]);
+
+    driver.text(&["add", "1920x1080", "--edid-from-display", "DISPLAY2"]);
+    assert_eq!(driver.monitor(0).edid, Some(vec![0, 255, 255, 0]));
+
+    let error = driver.fails(&["add", "1920x1080", "--edid-from-display", "DISPLAY9"]);
+    assert!(
+        error.contains("failed to read EDID of display DISPLAY9"),
+        "{error}"
+    );
+}
+
+#[test]
+fn get_edid() {
+    let driver = Driver::start();
+
+    // a base block with nothing but its header and a name
+    let mut edid = vec![0; 128];
+    edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+    edid[54..72].copy_from_slice(b"\0\0\0\xFC\0desk\n        ");
+    edid[127] = edid.iter().fold(0u8, |sum, &byte| sum.wrapping_sub(byte));
+    driver.emulator().add_display("DISPLAY2", edid.clone());
+    driver.text(&["add", "1920x1080", "--edid-from-display", "DISPLAY2"]);
+
+    assert_eq!(driver.json::<Vec<u8>>(&["get-edid", "0"]), edid);
+
+    let output = driver.text(&["get-edid", "0", "--decode"]);
+    assert!(output.contains("- name desk"), "{output}");
+    assert!(!output.contains("wrong checksum"), "{output}");
+    let decoded = driver.json::<Value>(&["get-edid", "0", "--decode"]);
+    assert_eq!(decoded["descriptors"][0]["name"], "desk");
+
+    let out = std::env::temp_dir().join(format!("vdd-edid-{}.bin", std::process::id()));
+    driver.text(&["get-edid", "0", "--out", out.to_str().unwrap()]);
+    assert_eq!(std::fs::read(&out).unwrap(), edid);
+    _ = std::fs::remove_file(out);
+
+    driver.text(&["add", "1920x1080", "--disabled"]);
+    let error = driver.fails(&["get-edid", "1"]);
+    assert!(error.contains("isn't plugged in"), "{error}");
+}
+
+#[test]
+fn add_with_edid_options() {
+    let driver = Driver::start();
+
+    driver.text(&[
+        "add",
+        "1920x1080",
+        "--name",
+        "desk",
+        "--edid-vendor",
+        "DEL",
+        "--edid-product",
+        "16",
+        "--edid-size",
+        "60x34",
+    ]);
+
+    let decoded = driver.json::<Value>(&["get-edid", "0", "--decode"]);
+    assert_eq!(decoded["vendor"], "DEL");
+    assert_eq!(decoded["product"], 16);
+    assert_eq!(decoded["size_cm"], serde_json::json!([60, 34]));
+    let names = decoded["descriptors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|descriptor| descriptor["name"].as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["desk"]);
+
+    let error = driver.fails(&["add", "1920x1080", "--edid-vendor", "dell"]);
+    assert!(error.contains("isn't three letters"), "{error}");
+    let error = driver.fails(&["add", "1920x1080", "--edid-size", "600x340"]);
+    assert!(error.contains("expected a size in centimeters"), "{error}");
+    assert_eq!(driver.monitors().len(), 1);
+}
+
+#[test]
+fn add_from_a_template() {
+    let driver = Driver::start();
+
+    let output = driver.text(&["templates", "list"]);
+    assert!(output.contains("LG C2 4K120"), "{output}");
+    assert!(output.contains("- 3840x2160@60/120"), "{output}");
+    let templates = driver.json::<Vec<Value>>(&["templates", "list"]);
+    assert!(templates
+        .iter()
+        .any(|template| template["name"] == "Steam Deck"));
+
+    driver.text(&["add", "--template", "lg-c2-4k120"]);
+    let monitor = driver.monitor(0);
+    assert_eq!(monitor.modes[0].width, 3840);
+    assert_eq!(monitor.modes[0].refresh_rates, [60, 120]);
+    assert!(monitor.audio);
+
+    let decoded = driver.json::<Value>(&["get-edid", "0", "--decode"]);
+    assert_eq!(decoded["vendor"], "GSM");
+    assert!(decoded["descriptors"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|descriptor| descriptor["name"] == "LG TV SSCR2"));
+    assert!(decoded["extensions"][0].get("cta").is_some());
+
+    // given modes and options replace the template's
+    driver.text(&[
+        "add",
+        "1280x720",
+        "--template",
+        "Steam Deck",
+        "--edid-vendor",
+        "DEL",
+    ]);
+    assert_eq!(driver.monitor(1).modes[0].width, 1280);
+    let decoded = driver.json::<Value>(&["get-edid", "1", "--decode"]);
+    assert_eq!(decoded["vendor"], "DEL");
+
+    let error = driver.fails(&["add", "--template", "CRT"]);
+    assert!(error.contains("unknown template"), "{error}");
+}
+
+#[test]
+fn add_with_a_long_name() {
+    let driver = Driver::start();
+
+    let output = driver.text(&["add", "1920x1080", "--name", "desk"]);
+    assert_eq!(output, "Added virtual monitor with ID 0.\n");
+
+    let output = driver.text(&["add", "1920x1080", "--name", "Wohnzimmer-Fernseher"]);
+    assert!(
+        output.contains("Windows shows it as Wohnzimmer-Fe, EDIDs only hold 13 ASCII characters."),
+        "{output}"
+    );
+    assert_eq!(
+        driver.monitor(1).name.as_deref(),
+        Some("Wohnzimmer-Fernseher")
+    );
+}
+
+#[test]
+fn add_errors() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--id", "3"]);
+
+    let error = driver.fails(&["add", "1920x1080", "--id", "3"]);
+    assert!(
+        error.contains("monitor with ID 3 already exists"),
+        "{error}"
+    );
+
+    let error = driver.fails(&["add", "1920"]);
+    assert!(error.contains("invalid resolution"), "{error}");
+
+    assert_eq!(driver.monitors().len(), 1);
+}
+
+#[test]
+fn ensure() {
+    let driver = Driver::start();
+
+    let output = driver.text(&["ensure", "desk", "1920x1080", "--dry-run"]);
+    assert!(!output.is_empty());
+    assert!(driver.monitors().is_empty());
+
+    driver.text(&["ensure", "desk", "1920x1080"]);
+    assert_eq!(driver.monitors().len(), 1);
+
+    // updates the existing monitor instead of adding one
+    driver.text(&["ensure", "desk", "2560x1440", "--disabled"]);
+    let monitors = driver.monitors();
+    assert_eq!(monitors.len(), 1);
+    assert!(!monitors[0].enabled);
+    assert_eq!(monitors[0].modes[0].width, 2560);
+}
+
+#[test]
+fn apply() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "desk"]);
+    driver.text(&["add", "1280x720", "--name", "unmanaged"]);
+
+    let config = std::env::temp_dir().join(format!("vdd-apply-{}.yaml", std::process::id()));
+    std::fs::write(
+        &config,
+        "
+monitors:
+  - name: desk
+    modes: [2560x1440@60/120, 1920x1080]
+  - name: stream
+    modes: [3840x2160]
+    gpu_export: true
+",
+    )
+    .unwrap();
+    let config = config.to_str().unwrap();
+
+    let plan = driver.json::<Value>(&["apply", config, "--dry-run"]);
+    assert_eq!(plan["dry_run"], true);
+    assert_eq!(plan["changes"].as_array().unwrap().len(), 2);
+    assert_eq!(driver.monitors().len(), 2);
+
+    let output = driver.text(&["apply", config]);
+    assert!(
+        output.contains("~ update monitor 0 [desk]: modes changed"),
+        "{output}"
+    );
+    assert!(output.contains("+ create monitor 2 [stream]"), "{output}");
+
+    let desk = driver.monitor(0);
+    assert_eq!(desk.modes[0].refresh_rates, [60, 120]);
+    assert_eq!(desk.modes[1].width, 1920);
+    assert!(driver.monitor(2).gpu_export);
+    // unmanaged monitors are kept without `--prune`
+    assert_eq!(driver.monitors().len(), 3);
+
+    // applying it again is a no-op
+    assert_eq!(driver.text(&["apply", config]), "No changes needed.\n");
+
+    let output = driver.text(&["apply", config, "--prune"]);
+    assert!(
+        output.contains("- delete monitor 1 [unmanaged]"),
+        "{output}"
+    );
+    assert_eq!(driver.monitors().len(), 2);
+
+    std::fs::write(config, "monitors:\n  - name: desk\n    mode: [1920x1080]\n").unwrap();
+    let error = driver.fails(&["apply", config]);
+    assert!(error.contains("unknown field `mode`"), "{error}");
+
+    std::fs::remove_file(config).unwrap();
+}
+
+#[test]
+fn add_and_remove_modes() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080@60", "--name", "desk"]);
+
+    let modes = driver.json::<Vec<driver_ipc::Mode>>(&["add-mode", "desk", "1920x1080@120"]);
+    assert_eq!(modes.len(), 1);
+    assert_eq!(modes[0].refresh_rates, [60, 120]);
+
+    let output = driver.text(&["add-mode", "0", "1280x720"]);
+    assert_eq!(output, "Added modes to virtual monitor with ID 0.\n");
+    assert_eq!(driver.monitor(0).modes.len(), 2);
+
+    let output = driver.text(&["remove-mode", "desk", "1920x1080@60"]);
+    assert_eq!(
+        output,
+        "Removed mode 1920x1080@60 from virtual monitor with ID 0.\n"
+    );
+    let monitor = driver.monitor(0);
+    let mode = monitor
+        .modes
+        .iter()
+        .find(|mode| mode.width == 1920)
+        .unwrap();
+    assert_eq!(mode.refresh_rates, [120]);
+
+    driver.text(&["remove-mode", "desk", "1280x720"]);
+    assert_eq!(driver.monitor(0).modes.len(), 1);
+
+    let error = driver.fails(&["remove-mode", "desk", "800x600"]);
+    assert!(error.contains("mode 800x600 not found"), "{error}");
+
+    let error = driver.fails(&["add-mode", "desk", "20000x1080"]);
+    assert!(
+        error.contains("mode 20000x1080 is larger than the 16384x16384 the driver supports"),
+        "{error}"
+    );
+    assert_eq!(driver.monitor(0).modes.len(), 1);
+}
+
+#[test]
+fn switch_mode() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080@60/120", "1280x720", "--name", "desk"]);
+    driver.text(&["add", "1920x1080", "--disabled", "--name", "off"]);
+
+    let output = driver.text(&["switch-mode", "desk", "1280x720"]);
+    assert_eq!(
+        output,
+        "Switching virtual monitor with ID 0 to 1280x720@60.\n"
+    );
+    let monitor = driver.monitor(0);
+    assert_eq!(monitor.active_mode.unwrap().width, 1280);
+    assert_eq!(monitor.modes[0].width, 1280);
+
+    let output = driver.text(&["events"]);
+    assert!(
+        output.contains("Monitor 0: mode change to 1280x720@60 requested\n"),
+        "{output}"
+    );
+
+    // consumers can't flip modes back and forth
+    let error = driver.fails(&["switch-mode", "desk", "1920x1080@120"]);
+    assert!(error.contains("switched modes just now"), "{error}");
+
+    let error = driver.fails(&["switch-mode", "off", "1920x1080"]);
+    assert!(error.contains("isn't active"), "{error}");
+    let error = driver.fails(&["switch-mode", "desk", "800x600"]);
+    assert!(error.contains("has no mode 800x600"), "{error}");
+    let error = driver.fails(&["switch-mode", "desk", "1920x1080@60/120"]);
+    assert!(error.contains("single refresh rate"), "{error}");
+}
+
+#[test]
+fn limit_fps() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080"]);
+
+    assert_eq!(
+        driver.json::<Option<u32>>(&["limit-fps", "0", "30"]),
+        Some(30)
+    );
+    assert_eq!(driver.monitor(0).fps_limit, Some(30));
+
+    let output = driver.text(&["limit-fps", "0", "off"]);
+    assert_eq!(
+        output,
+        "Removed the frame rate limit of virtual monitor with ID 0.\n"
+    );
+    assert_eq!(driver.monitor(0).fps_limit, None);
+
+    driver.fails(&["limit-fps", "0", "0"]);
+}
+
+#[test]
+fn shared_frame_formats() {
+    let driver = Driver::start();
+    driver.text(&["add", "1366x768", "--gpu-export"]);
+
+    // the emulator converts right away, the driver with the next frame
+    let frame = driver.json::<Value>(&["shared-frame", "0", "--format", "nv12"]);
+    assert_eq!(frame["format"], 103);
+    assert_eq!(frame["width"], 1366);
+
+    // the format stays until another one is asked for
+    let frame = driver.json::<Value>(&["shared-frame", "0"]);
+    assert_eq!(frame["format"], 103);
+
+    let frame = driver.json::<Value>(&["shared-frame", "0", "--format", "bgra"]);
+    assert_eq!(frame["format"], 87);
+
+    driver.fails(&["shared-frame", "0", "--format", "yuy2"]);
+}
+
+#[test]
+fn shared_frame_sync() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--gpu-export"]);
+
+    let frame = driver.json::<Value>(&["shared-frame", "0"]);
+    assert_eq!(frame["sync"], "KeyedMutex");
+    assert_eq!(frame.get("fence"), None);
+
+    let frame = driver.json::<Value>(&[
+        "shared-frame",
+        "0",
+        "--sync",
+        "fence",
+        "--timeout-ms",
+        "500",
+    ]);
+    assert_eq!(frame["sync"], "Fence");
+    assert!(
+        frame["fence"].as_str().unwrap().contains("Fence"),
+        "{frame}"
+    );
+
+    let output = driver.text(&["shared-frame", "0"]);
+    assert!(output.contains("(fence)"), "{output}");
+
+    let error = driver.fails(&["shared-frame", "0", "--timeout-ms", "500"]);
+    assert!(error.contains("--sync"), "{error}");
+}
+
+#[test]
+fn shared_ring() {
+    let driver = Driver::start();
+    driver.text(&["add", "1280x720", "--gpu-export", "--name", "export"]);
+    driver.text(&["add", "1280x720", "--name", "plain"]);
+
+    let ring = driver.json::<Value>(&["shared-frame", "export", "--ring", "--format", "nv12"]);
+    assert_eq!(ring["width"], 1280);
+    assert_eq!(ring["format"], 103);
+    assert_eq!(
+        ring["textures"].as_array().unwrap().len(),
+        driver_ipc::RING_SLOTS
+    );
+    assert_eq!(
+        ring["events"].as_array().unwrap().len(),
+        driver_ipc::RING_CONSUMERS
+    );
+
+    let output = driver.text(&["shared-frame", "export", "--ring"]);
+    assert!(output.contains("4 textures, up to 8 consumers"), "{output}");
+
+    // nobody reads from the emulator's ring
+    let stats = driver.json::<Value>(&["stats", "export"]);
+    assert_eq!(stats[0]["consumers"], Value::Array(Vec::new()));
+
+    let output = driver.text(&["shared-frame", "plain", "--ring"]);
+    assert!(output.contains("--gpu-export"), "{output}");
+}
+
+#[test]
+fn backpressure() {
+    let driver = Driver::start();
+    driver.text(&["add", "1280x720", "--gpu-export", "--name", "export"]);
+    driver.text(&["add", "1280x720", "--name", "plain"]);
+
+    let frame = driver.json::<Value>(&["shared-frame", "export"]);
+    assert_eq!(frame["backpressure"], "DropNewest");
+
+    let output = driver.text(&["backpressure", "export", "block", "--timeout-ms", "8"]);
+    assert!(output.contains("Waiting up to 8 ms"), "{output}");
+    let frame = driver.json::<Value>(&["shared-frame", "export"]);
+    assert_eq!(frame["backpressure"]["Block"]["timeout_ms"], 8);
+
+    driver.text(&["backpressure", "export", "drop-oldest"]);
+    let frame = driver.json::<Value>(&["shared-frame", "export"]);
+    assert_eq!(frame["backpressure"], "DropOldest");
+
+    let stats = driver.json::<Value>(&["stats", "export"]);
+    assert_eq!(stats[0]["shared_frames_dropped"], 0);
+
+    let error = driver.fails(&["backpressure", "plain", "drop-oldest"]);
+    assert!(error.contains("--gpu-export"), "{error}");
+}
+
+#[test]
+fn cursor() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "plain"]);
+    driver.text(&["add", "1920x1080", "--hardware-cursor", "--gpu-export"]);
+
+    let error = driver.fails(&["cursor", "composite", "plain"]);
+    assert!(error.contains("no hardware cursor"), "{error}");
+
+    driver.emulator().set_cursor(
+        1,
+        driver_ipc::CursorState {
+            visible: true,
+            x: 100,
+            y: 200,
+            shape_id: 3,
+            shape: Some(driver_ipc::CursorShape {
+                kind: driver_ipc::CursorShapeKind::Alpha,
+                width: 1,
+                height: 1,
+                hot_x: 0,
+                hot_y: 0,
+                data: vec![255; 4],
+            }),
+        },
+    );
+
+    let cursor = driver.json::<Value>(&["cursor", "show", "1"]);
+    assert_eq!(cursor["x"], 100);
+    assert_eq!(cursor["shape"]["width"], 1);
+
+    let output = driver.text(&["cursor", "show", "1"]);
+    assert!(output.contains("alpha shape 3, 1x1"), "{output}");
+
+    driver.text(&["cursor", "composite", "1"]);
+    assert!(driver.emulator().cursor_composited(1));
+    driver.text(&["cursor", "separate", "1"]);
+    assert!(!driver.emulator().cursor_composited(1));
+}
+
+#[test]
+fn export_size() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "plain"]);
+    driver.text(&["add", "2560x1440", "--name", "export", "--gpu-export"]);
+
+    let error = driver.fails(&["export-size", "plain", "1280x720"]);
+    assert!(error.contains("needs `--gpu-export`"), "{error}");
+    driver.fails(&["export-size", "export", "1280x0"]);
+
+    let size = driver.json::<Value>(&["export-size", "export", "1280x720", "--stretch"]);
+    assert_eq!(size["width"], 1280);
+    assert_eq!(size["fit"], "Stretch");
+
+    let frame = driver.json::<Value>(&["shared-frame", "export"]);
+    assert_eq!(frame["width"], 1280);
+    assert_eq!(frame["height"], 720);
+
+    let output = driver.text(&["export-size", "export", "off"]);
+    assert_eq!(
+        output,
+        "Virtual monitor with ID 1 now shares frames at the size of its mode.\n"
+    );
+    assert_eq!(driver.monitor(1).export_size, None);
+}
+
+#[test]
+fn enable_and_disable() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "desk"]);
+
+    let outcome = driver.json::<Value>(&["disable", "desk"]);
+    assert_eq!(keys(&outcome), ["monitor", "toggled"]);
+    assert_eq!(outcome["toggled"], true);
+    assert!(!driver.emulator().is_arrived(0));
+
+    let output = driver.text(&["disable", "desk"]);
+    assert_eq!(
+        output,
+        "Disabled virtual monitor with ID 0 (was already disabled).\n"
+    );
+
+    let output = driver.text(&["enable", "0"]);
+    assert_eq!(output, "Enabled virtual monitor with ID 0.\n");
+    assert!(driver.emulator().is_arrived(0));
+}
+
+#[test]
+fn plug_and_replug() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--no-auto-plug"]);
+    assert!(!driver.emulator().is_arrived(0));
+
+    assert_eq!(driver.json::<u32>(&["plug", "0"]), 0);
+    assert!(driver.emulator().is_arrived(0));
+
+    let output = driver.text(&["replug", "0"]);
+    assert_eq!(output, "Replugged virtual monitor with ID 0.\n");
+    assert!(driver.emulator().is_arrived(0));
+
+    driver.text(&["disable", "0"]);
+    let error = driver.fails(&["plug", "0"]);
+    assert!(error.contains("is disabled, enable it instead"), "{error}");
+    let error = driver.fails(&["replug", "0"]);
+    assert!(error.contains("is disabled, enable it instead"), "{error}");
+}
+
+#[test]
+fn remove() {
+    let driver = Driver::start();
+    for name in ["a", "b", "c", "d"] {
+        driver.text(&["add", "1920x1080", "--name", name]);
+    }
+
+    assert_eq!(driver.text(&["remove", "a"]), "Removed virtual monitor.\n");
+    assert_eq!(
+        driver.json::<Vec<String>>(&["remove", "b", "2"]),
+        ["b", "2"]
+    );
+    assert_eq!(driver.monitors().len(), 1);
+
+    assert_eq!(
+        driver.text(&["remove", "--all"]),
+        "Removed all virtual monitors.\n"
+    );
+    assert!(driver.monitors().is_empty());
+
+    // deprecated spelling
+    driver.text(&["add", "1920x1080"]);
+    driver.text(&["remove-all"]);
+    assert!(driver.monitors().is_empty());
+}
+
+#[test]
+fn tags() {
+    let driver = Driver::start();
+    driver.text(&[
+        "add",
+        "1920x1080",
+        "--tag",
+        "work",
+        "--tag",
+        "test",
+        "--tag",
+        "work",
+    ]);
+    driver.text(&["add", "1920x1080", "--tag", "test"]);
+    driver.text(&["add", "1920x1080"]);
+    assert_eq!(driver.monitor(0).tags, ["work", "test"]);
+
+    let tagged = driver.json::<Vec<Monitor>>(&["list", "--tag", "test"]);
+    assert_eq!(
+        tagged.iter().map(|monitor| monitor.id).collect::<Vec<_>>(),
+        [0, 1]
+    );
+
+    driver.text(&["disable", "1"]);
+    assert_eq!(
+        driver.text(&["disable", "--tag", "test"]),
+        "Disabled 2 virtual monitors tagged test (1 already were).\n"
+    );
+    assert!(!driver.monitor(0).enabled);
+    assert!(driver.monitor(2).enabled);
+
+    let outcomes = driver.json::<Value>(&["enable", "--tag", "work"]);
+    assert_eq!(outcomes[0]["toggled"], true);
+    assert!(driver.monitor(0).enabled);
+
+    let error = driver.fails(&["enable", "--tag", "home"]);
+    assert!(error.contains("no virtual monitors are tagged"), "{error}");
+
+    assert_eq!(
+        driver.json::<Vec<u32>>(&["remove", "--tag", "test"]),
+        [0, 1]
+    );
+    assert_eq!(driver.monitors().len(), 1);
+}
+
+#[test]
+fn aliases() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--alias", "tv", "--name", "desk"]);
+    driver.text(&["add", "1920x1080", "--name", "tv"]);
+    assert_eq!(driver.monitor(0).alias.as_deref(), Some("tv"));
+
+    // aliases are looked up before names
+    assert_eq!(driver.json::<Monitor>(&["get", "tv"]).id, 0);
+    assert!(driver
+        .text(&["list"])
+        .contains("Monitor 0 [desk] (alias tv)"));
+
+    let error = driver.fails(&["add", "1280x720", "--alias", "tv"]);
+    assert!(error.contains("is used by monitors 0 and 2"), "{error}");
+    let error = driver.fails(&["add", "1280x720", "--alias", "Living Room"]);
+    assert!(error.contains("like `living-room-tv`"), "{error}");
+
+    assert_eq!(
+        driver.text(&["alias", "1", "couch"]),
+        "Virtual monitor with ID 1 can be used as couch now.\n"
+    );
+    driver.text(&["disable", "couch"]);
+    assert!(!driver.monitor(1).enabled);
+
+    // the alias stays when the monitor is added again with another ID
+    driver.text(&["remove", "couch"]);
+    driver.text(&["add", "1920x1080", "--alias", "couch", "--id", "5"]);
+    assert_eq!(driver.json::<Monitor>(&["get", "couch"]).id, 5);
+
+    driver.text(&["alias", "couch", "off"]);
+    assert_eq!(driver.monitor(5).alias, None);
+    driver.fails(&["get", "couch"]);
+}
+
+#[test]
+fn unknown_monitor() {
+    let driver = Driver::start();
+
+    for args in [
+        &["enable", "desk"][..],
+        &["disable", "3"],
+        &["remove", "desk"],
+        &["add-mode", "desk", "1920x1080"],
+        &["remove-mode", "desk", "1920x1080"],
+        &["limit-fps", "desk", "30"],
+        &["plug", "desk"],
+        &["replug", "desk"],
+        &["stats", "desk"],
+        &["shared-frame", "desk"],
+        &["picture", "desk"],
+        &["test-pattern", "desk", "bars"],
+        &["benchmark", "desk"],
+    ] {
+        let error = driver.fails(args);
+        assert!(error.contains("not found"), "{args:?}: {error}");
+    }
+}
+
+#[test]
+fn bench_ipc() {
+    let driver = Driver::start();
+
+    let results = driver.json::<Value>(&["bench-ipc", "--iterations", "10", "--sizes", "0,1024"]);
+    let results = results.as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[1]["payload_size"], 1024);
+    assert!(keys(&results[0]).contains(&"p99_us"));
+
+    let output = driver.text(&["bench-ipc", "--iterations", "10", "--sizes", "0"]);
+    assert!(output.starts_with("IPC benchmark (10 round trips per payload size)"));
+}
+
+#[test]
+fn benchmark() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080@60"]);
+
+    let results = driver.json::<Value>(&["benchmark", "0", "--duration", "1", "--settle", "0"]);
+    let results = results.as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["refresh_rate"], 60);
+    assert!(results[0]["frames"].as_u64().unwrap() > 0);
+
+    driver.text(&["disable", "0"]);
+    let error = driver.fails(&["benchmark", "0", "--duration", "1"]);
+    assert!(error.contains("is disabled, enable it first"), "{error}");
+}
+
+#[test]
+fn logs() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080"]);
+
+    let records = driver.json::<Value>(&["logs"]);
+    let records = records.as_array().unwrap();
+    assert!(!records.is_empty());
+    assert_eq!(
+        keys(&records[0]),
+        ["level", "message", "seq", "target", "timestamp"]
+    );
+
+    let output = driver.text(&["logs", "--level", "error"]);
+    assert!(
+        output.contains("INFO  [vdd_emulator] Monitor 0 arrived"),
+        "{output}"
+    );
+
+    // info isn't logged anymore
+    driver.text(&["replug", "0"]);
+    assert_eq!(driver.json::<Vec<Value>>(&["logs"]).len(), records.len());
+}
+
+#[test]
+fn stats() {
+    let driver = Driver::start();
+
+    assert_eq!(driver.text(&["stats"]), "No virtual monitors found.\n");
+
+    driver.text(&["add", "1920x1080@60"]);
+    let stats = driver.json::<Value>(&["stats", "0"]);
+    assert_eq!(stats[0]["id"], 0);
+    assert_eq!(stats[0]["refresh_rate"], 60.0);
+
+    let detailed = driver.json::<Value>(&["stats", "--detailed"]);
+    assert_eq!(keys(&detailed), ["callbacks", "monitors"]);
+    assert!(!detailed["callbacks"].as_array().unwrap().is_empty());
+
+    let output = driver.text(&["stats", "--detailed"]);
+    assert!(output.contains("Driver callbacks"), "{output}");
+    assert!(
+        output.contains("AssignSwapChain: 1 succeeded, 0 failed"),
+        "{output}"
+    );
+}
+
+#[test]
+fn events() {
+    let driver = Driver::start();
+
+    assert_eq!(driver.text(&["events"]), "No events found.\n");
+
+    driver.emulator().push_event(EventKind::SwapChainStalled {
+        id: 0,
+        stalled_ms: 1500,
+    });
+
+    let events = driver.json::<Value>(&["events"]);
+    assert_eq!(keys(&events[0]), ["kind", "seq", "timestamp"]);
+
+    let output = driver.text(&["events"]);
+    assert!(
+        output.contains("Monitor 0: swap chain stalled for 1500ms, re-created it"),
+        "{output}"
+    );
+
+    driver.emulator().push_event(EventKind::ConsumerAbandoned {
+        id: 0,
+        held_ms: Some(2000),
+    });
+    let output = driver.text(&["events"]);
+    assert!(
+        output.contains("Monitor 0: consumer held a shared frame for 2000ms"),
+        "{output}"
+    );
+
+    driver.text(&["add", "1920x1080", "--name", "desk"]);
+    driver.text(&["remove", "desk"]);
+
+    let output = driver.text(&["events"]);
+    assert!(output.contains("Monitor 0: added\n"), "{output}");
+    assert!(output.contains("Monitor 0: removed\n"), "{output}");
+}
+
+#[test]
+fn shared_frame_and_test_pattern() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "plain"]);
+    driver.text(&["add", "1920x1080", "--name", "export", "--gpu-export"]);
+
+    let output = driver.text(&["shared-frame", "plain"]);
+    assert_eq!(
+        output,
+        "Virtual monitor doesn't share its frames, add it with --gpu-export.\n"
+    );
+    assert_eq!(
+        driver.json::<Value>(&["shared-frame", "plain"]),
+        Value::Null
+    );
+
+    let frame = driver.json::<Value>(&["shared-frame", "export"]);
+    assert_eq!(
+        keys(&frame),
+        ["backpressure", "format", "height", "name", "sync", "width"]
+    );
+    assert_eq!(frame["width"], 1920);
+
+    let error = driver.fails(&["test-pattern", "plain", "bars"]);
+    assert!(error.contains("needs `--gpu-export`"), "{error}");
+
+    driver.text(&["test-pattern", "export", "moving-box"]);
+    assert_eq!(
+        driver.emulator().test_pattern(1),
+        Some(driver_ipc::TestPattern::MovingBox)
+    );
+    driver.text(&["test-pattern", "export", "off"]);
+    assert_eq!(driver.emulator().test_pattern(1), None);
+}
+
+#[test]
+fn picture() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080"]);
+
+    let output = driver.text(&["picture", "0"]);
+    assert_eq!(
+        output,
+        "Virtual monitor with ID 0: brightness 100, contrast 100\n- default gamma ramp\n"
+    );
+
+    let ramp = (0..=255u16).map(|i| i * 257).collect::<Vec<_>>();
+    driver.emulator().set_picture(
+        0,
+        Picture {
+            brightness: 40,
+            contrast: 60,
+            gamma: Some(GammaRamp {
+                red: ramp.clone(),
+                green: ramp.clone(),
+                blue: ramp.iter().map(|level| level / 2).collect(),
+            }),
+            lut: None,
+        },
+    );
+
+    let picture = driver.json::<Value>(&["picture", "0"]);
+    assert_eq!(keys(&picture), ["brightness", "contrast", "gamma"]);
+    assert_eq!(picture["brightness"], 40);
+
+    let output = driver.text(&["picture", "0"]);
+    assert!(
+        output.contains("white is at red 100%, green 100%, blue 50%"),
+        "{output}"
+    );
+}
+
+#[test]
+fn lut() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080"]);
+
+    let cube = std::env::temp_dir().join(format!("vdd-lut-{}.cube", std::process::id()));
+    std::fs::write(
+        &cube,
+        "LUT_3D_SIZE 2\n0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 0.5\n",
+    )
+    .unwrap();
+
+    assert!(driver.json::<bool>(&["lut", "set", "0", cube.to_str().unwrap()]));
+    let picture = driver.json::<Value>(&["picture", "0"]);
+    assert_eq!(picture["lut"]["ThreeD"]["size"], 2);
+
+    let output = driver.text(&["picture", "0"]);
+    assert!(
+        output.contains("3D LUT with 2 entries per axis"),
+        "{output}"
+    );
+
+    driver.text(&["lut", "clear", "0"]);
+    let picture = driver.json::<Value>(&["picture", "0"]);
+    assert_eq!(keys(&picture), ["brightness", "contrast", "gamma"]);
+
+    std::fs::write(&cube, "LUT_3D_SIZE 2\n0 0 0\n").unwrap();
+    let error = driver.fails(&["lut", "set", "0", cube.to_str().unwrap()]);
+    assert!(error.contains("expected 8 entries, found 1"), "{error}");
+
+    _ = std::fs::remove_file(cube);
+}
+
+#[test]
+fn gpu_priority_and_doctor() {
+    let driver = Driver::start();
+
+    assert!(driver.json::<bool>(&["gpu-priority", "realtime"]));
+    let info = driver.json::<Value>(&["doctor"]);
+    assert_eq!(info["realtime_gpu_priority"], true);
+    for key in ["version", "iddcx_version", "degraded", "capabilities"] {
+        assert!(keys(&info).contains(&key), "missing {key} in {info}");
+    }
+
+    driver.text(&["gpu-priority", "normal"]);
+    let output = driver.text(&["doctor"]);
+    assert!(output.contains("Realtime GPU priority off"), "{output}");
+    assert!(
+        output.contains("All optional features are available."),
+        "{output}"
+    );
+}
+
+#[test]
+#[cfg(windows)]
+fn stream_needs_gpu_export() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080"]);
+
+    let error = driver.fails(&["stream", "start", "0"]);
+    assert!(
+        error.contains("needs `--gpu-export` to be streamed"),
+        "{error}"
+    );
+}
+
+#[test]
+#[cfg(windows)]
+fn setup_sunshine_needs_a_mode() {
+    let driver = Driver::start();
+
+    // outside of a sunshine prep command, the client's mode isn't known
+    let error = driver.fails(&["setup", "sunshine"]);
+    assert!(error.contains("SUNSHINE_CLIENT_WIDTH isn't set"), "{error}");
+    assert_eq!(driver.json::<Vec<Monitor>>(&["list"]), []);
+}
+
+#[test]
+fn with_monitor() {
+    let driver = Driver::start();
+
+    let exit = if cfg!(windows) {
+        ["cmd", "/C", "exit 3"]
+    } else {
+        ["sh", "-c", "exit 3"]
+    };
+    let output = driver
+        .cli()
+        .args(["with-monitor", "1920x1080", "--"])
+        .args(exit)
+        .assert()
+        .code(3);
+    let log = common::strip_colors(&output.get_output().stderr);
+    assert!(
+        log.contains("Virtual monitor 0 is shown in 1920x1080@60."),
+        "{log}"
+    );
+    assert_eq!(driver.monitors(), []);
+
+    // the driver is free for the command while it runs
+    let cli = assert_cmd::cargo::cargo_bin("virtual-display-driver-cli");
+    let list = driver.text(&[
+        "with-monitor",
+        "1280x720",
+        "--name",
+        "ci",
+        "--",
+        cli.to_str().unwrap(),
+        "--tcp",
+        &driver.addr,
+        "list",
+    ]);
+    assert!(list.contains("Monitor 0 [ci] (ephemeral):"), "{list}");
+    assert_eq!(driver.monitors(), []);
+
+    let error = driver.fails(&["with-monitor", "1280x720", "--", "does-not-exist"]);
+    assert!(error.contains("failed to run does-not-exist"), "{error}");
+    assert_eq!(driver.monitors(), []);
+}
+
+#[test]
+fn remote_host() {
+    let driver = Driver::start();
+    let addr = driver.agent(b"lab key 0123456789").to_string();
+
+    let key_file = std::env::temp_dir().join(format!("vdd-key-{}", std::process::id()));
+    std::fs::write(&key_file, "lab key 0123456789\n").unwrap();
+    let key_file = key_file.to_str().unwrap();
+
+    let remote = |args: &[&str]| {
+        Command::cargo_bin("virtual-display-driver-cli")
+            .unwrap()
+            .args(["--host", &addr, "--key-file", key_file])
+            .args(args)
+            .assert()
+    };
+
+    remote(&["add", "1920x1080", "--name", "lab"]).success();
+    assert_eq!(driver.monitor(0).name.as_deref(), Some("lab"));
+    let output = remote(&["list"]).success();
+    let list = common::strip_colors(&output.get_output().stdout);
+    assert!(list.contains("Monitor 0 [lab]:"), "{list}");
+
+    // changing this machine's displays isn't proxied
+    let output = remote(&["physical", "list"]).failure();
+    let error = common::strip_colors(&output.get_output().stderr);
+    assert!(error.contains("doesn't work with --host"), "{error}");
+
+    std::fs::write(key_file, "not the lab key 0123\n").unwrap();
+    let output = remote(&["list"]).failure();
+    let error = common::strip_colors(&output.get_output().stderr);
+    assert!(error.contains("the agent doesn't know the key"), "{error}");
+
+    std::fs::remove_file(key_file).unwrap();
+}
+
+#[test]
+fn trace_profile() {
+    let driver = Driver::start();
+
+    let profile = driver.text(&["trace-profile"]);
+    assert!(profile.contains("VirtualDisplayDriver"), "{profile}");
+}
+
+#[test]
+fn schema() {
+    let driver = Driver::start();
+
+    let schema = driver.json::<Value>(&["schema", "monitor"]);
+    assert_eq!(schema["title"], "Monitor");
+    assert!(schema["definitions"]["Mode"].is_object());
+
+    let schema = driver.json::<Value>(&["schema", "command"]);
+    let variants = schema["oneOf"].to_string();
+    assert!(variants.contains("DriverNotify"), "{variants}");
+
+    let schema = driver.json::<Value>(&["schema", "config"]);
+    assert_eq!(schema["title"], "Config");
+    assert_eq!(
+        schema["definitions"]["MonitorConfig"]["additionalProperties"],
+        false
+    );
+}
+
+#[test]
+fn invalid_arguments() {
+    let driver = Driver::start();
+
+    driver.fails(&["add", "--cursor-size", "32", "1920x1080"]);
+    driver.fails(&["remove"]);
+    driver.fails(&["remove", "0", "--all"]);
+    driver.fails(&["--instance", "0", "list"]);
+
+    let mut cli = Command::cargo_bin("virtual-display-driver-cli").unwrap();
+    cli.args(["--strict", "--tcp", &driver.addr, "remove-all"])
+        .assert()
+        .failure();
+}
+
+#[test]
+#[cfg(windows)]
+fn concurrent_clients() {
+    let driver = Driver::start();
+
+    // the pipe takes one client at a time like the driver's, so neither may lose its change
+    let clients = (0..2)
+        .map(|i| {
+            let pipe = driver.pipe.clone();
+            std::thread::spawn(move || {
+                for j in 0..5 {
+                    Command::cargo_bin("virtual-display-driver-cli")
+                        .unwrap()
+                        .args(["--pipe", &pipe, "add", "1920x1080"])
+                        .args(["--name", &format!("{i}-{j}")])
+                        .assert()
+                        .success();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for client in clients {
+        client.join().unwrap();
+    }
+
+    let mut ids = driver
+        .monitors()
+        .iter()
+        .map(|monitor| monitor.id)
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    assert_eq!(ids, (0..10).collect::<Vec<_>>());
+}
@@ -0,0 +1,126 @@
+//! Driver fixture shared by the integration tests
+
+#![allow(dead_code)]
+
+#[cfg(windows)]
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use assert_cmd::Command;
+use driver_ipc::Monitor;
+use serde::de::DeserializeOwned;
+use vdd_emulator::Emulator;
+
+/// A `vdd-emulator` serving a port of its own, so tests run in parallel on any platform
+pub struct Driver {
+    pub addr: String,
+    /// Pipe the emulator serves too, like the driver's
+    #[cfg(windows)]
+    pub pipe: String,
+    emulator: Arc<Mutex<Emulator>>,
+}
+
+impl Driver {
+    pub fn start() -> Self {
+        let emulator = Arc::new(Mutex::new(Emulator::new()));
+        let (addr, _) =
+            vdd_emulator::spawn_tcp(SocketAddr::from(([127, 0, 0, 1], 0)), emulator.clone())
+                .expect("failed to start the emulator");
+
+        #[cfg(windows)]
+        let pipe = {
+            static NEXT: AtomicU32 = AtomicU32::new(0);
+
+            let pipe = format!(
+                "vdd-cli-test-{}-{}",
+                std::process::id(),
+                NEXT.fetch_add(1, Ordering::Relaxed)
+            );
+            vdd_emulator::spawn_pipe(&pipe, emulator.clone())
+                .expect("failed to start the emulator");
+            pipe
+        };
+
+        Self {
+            addr: addr.to_string(),
+            #[cfg(windows)]
+            pipe,
+            emulator,
+        }
+    }
+
+    pub fn cli(&self) -> Command {
+        let mut cli = Command::cargo_bin("virtual-display-driver-cli").unwrap();
+        cli.args(["--tcp", &self.addr]);
+        cli
+    }
+
+    /// Serve the emulator to `--host` clients too, like `vdd-server --agent`
+    pub fn agent(&self, key: &[u8]) -> SocketAddr {
+        let addr = SocketAddr::from(([127, 0, 0, 1], 0));
+        let (addr, _) = vdd_emulator::spawn_agent(addr, key.to_vec(), self.emulator.clone())
+            .expect("failed to start the agent");
+
+        addr
+    }
+
+    pub fn emulator(&self) -> MutexGuard<'_, Emulator> {
+        self.emulator.lock().unwrap()
+    }
+
+    pub fn monitors(&self) -> Vec<Monitor> {
+        self.emulator().monitors().cloned().collect()
+    }
+
+    pub fn monitor(&self, id: u32) -> Monitor {
+        self.monitors()
+            .into_iter()
+            .find(|monitor| monitor.id == id)
+            .expect("monitor doesn't exist")
+    }
+
+    /// Run with `--json`, and parse the output
+    pub fn json<T: DeserializeOwned>(&self, args: &[&str]) -> T {
+        let output = self.cli().arg("--json").args(args).assert().success();
+        let stdout = &output.get_output().stdout;
+
+        serde_json::from_slice(stdout).unwrap_or_else(|e| {
+            panic!(
+                "invalid json output of {args:?}: {e}\n{}",
+                String::from_utf8_lossy(stdout)
+            )
+        })
+    }
+
+    /// Run successfully, and return the output without colors
+    pub fn text(&self, args: &[&str]) -> String {
+        let output = self.cli().args(args).assert().success();
+        strip_colors(&output.get_output().stdout)
+    }
+
+    /// Run unsuccessfully, and return the error output without colors
+    pub fn fails(&self, args: &[&str]) -> String {
+        let output = self.cli().args(args).assert().failure();
+        strip_colors(&output.get_output().stderr)
+    }
+}
+
+pub fn strip_colors(output: &[u8]) -> String {
+    let output = String::from_utf8_lossy(output);
+
+    let mut stripped = String::with_capacity(output.len());
+    let mut chars = output.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // CSI sequences end with a letter
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            stripped.push(c);
+        }
+    }
+
+    stripped
+}
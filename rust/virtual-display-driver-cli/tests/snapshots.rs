@@ -0,0 +1,107 @@
+//! Snapshots of the cli's human-readable output. Scripts scrape it, so changes to it must be
+//! intentional; review them with `cargo insta review`.
+
+mod common;
+
+use common::Driver;
+
+/// Colors made visible, `\e[32m` instead of the escape character
+fn show_colors(output: &[u8]) -> String {
+    String::from_utf8_lossy(output).replace('\x1b', "\\e")
+}
+
+/// The error without where it was raised, which changes with every edit
+fn without_location(error: String) -> String {
+    match error.split_once("\n\nLocation:") {
+        Some((error, _)) => format!("{error}\n"),
+        None => error,
+    }
+}
+
+/// A driver with monitors showing every label
+fn driver_with_monitors() -> Driver {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080@60/120", "--name", "desk"]);
+    driver.text(&["limit-fps", "desk", "30"]);
+    driver.text(&[
+        "add",
+        "2560x1440@144",
+        "--disabled",
+        "--ephemeral",
+        "--no-auto-plug",
+    ]);
+    driver.text(&[
+        "add",
+        "1280x720",
+        "--hardware-cursor",
+        "--remote-session",
+        "--gpu-export",
+    ]);
+
+    driver
+}
+
+#[test]
+fn list() {
+    let driver = driver_with_monitors();
+
+    insta::assert_snapshot!(driver.text(&["list"]));
+}
+
+#[test]
+fn list_colored() {
+    let driver = driver_with_monitors();
+    let output = driver.cli().arg("list").assert().success();
+
+    insta::assert_snapshot!(show_colors(&output.get_output().stdout));
+}
+
+#[test]
+fn list_empty() {
+    let driver = Driver::start();
+
+    insta::assert_snapshot!(driver.text(&["list"]));
+}
+
+#[test]
+fn add() {
+    let driver = Driver::start();
+
+    insta::assert_snapshot!("add", driver.text(&["add", "1920x1080"]));
+    insta::assert_snapshot!(
+        "add_disabled",
+        driver.text(&["add", "1920x1080", "--disabled"])
+    );
+}
+
+#[test]
+fn add_colored() {
+    let driver = Driver::start();
+    let output = driver
+        .cli()
+        .args(["add", "1920x1080", "--disabled"])
+        .assert()
+        .success();
+
+    insta::assert_snapshot!(show_colors(&output.get_output().stdout));
+}
+
+#[test]
+fn errors() {
+    let driver = Driver::start();
+    driver.text(&["add", "1920x1080", "--name", "desk"]);
+
+    insta::assert_snapshot!(
+        "unknown_monitor",
+        without_location(driver.fails(&["remove", "tv"]))
+    );
+    insta::assert_snapshot!(
+        "existing_id",
+        without_location(driver.fails(&["add", "1920x1080", "--id", "0"]))
+    );
+    insta::assert_snapshot!(
+        "unknown_mode",
+        without_location(driver.fails(&["remove-mode", "desk", "800x600"]))
+    );
+    insta::assert_snapshot!("invalid_mode", driver.fails(&["add", "1920"]));
+}
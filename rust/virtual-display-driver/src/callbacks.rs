@@ -1,55 +1,64 @@
 use std::{
     mem::{self, MaybeUninit},
     ptr::NonNull,
+    slice,
 };
 
+use driver_ipc::{ActiveMode, EventKind};
 use log::error;
+use wdf_umdf::{idd_driver, wdf_callback, IddCallback, IddDriver, WdfObjectContext, WdfRequest};
 use wdf_umdf_sys::{
     DISPLAYCONFIG_VIDEO_SIGNAL_INFO__bindgen_ty_1,
     DISPLAYCONFIG_VIDEO_SIGNAL_INFO__bindgen_ty_1__bindgen_ty_1, DISPLAYCONFIG_2DREGION,
     DISPLAYCONFIG_RATIONAL, DISPLAYCONFIG_SCANLINE_ORDERING, DISPLAYCONFIG_TARGET_MODE,
     DISPLAYCONFIG_VIDEO_SIGNAL_INFO, IDARG_IN_ADAPTER_INIT_FINISHED, IDARG_IN_COMMITMODES,
-    IDARG_IN_GETDEFAULTDESCRIPTIONMODES, IDARG_IN_PARSEMONITORDESCRIPTION,
-    IDARG_IN_QUERYTARGETMODES, IDARG_IN_SETSWAPCHAIN, IDARG_OUT_GETDEFAULTDESCRIPTIONMODES,
-    IDARG_OUT_PARSEMONITORDESCRIPTION, IDARG_OUT_QUERYTARGETMODES, IDDCX_ADAPTER__,
-    IDDCX_MONITOR_MODE, IDDCX_MONITOR_MODE_ORIGIN, IDDCX_MONITOR__, IDDCX_TARGET_MODE, NTSTATUS,
-    WDFDEVICE, WDF_POWER_DEVICE_STATE,
+    IDARG_IN_I2C_RECEIVE, IDARG_IN_I2C_TRANSMIT, IDARG_IN_PARSEMONITORDESCRIPTION,
+    IDARG_IN_QUERYTARGETMODES, IDARG_IN_SETSWAPCHAIN, IDARG_IN_SET_GAMMARAMP,
+    IDARG_OUT_I2C_RECEIVE, IDARG_OUT_PARSEMONITORDESCRIPTION, IDARG_OUT_QUERYTARGETMODES,
+    IDDCX_ADAPTER, IDDCX_GAMMARAMP_TYPE, IDDCX_MONITOR, IDDCX_MONITOR_MODE,
+    IDDCX_MONITOR_MODE_ORIGIN, IDDCX_PATH_FLAGS, IDDCX_TARGET_MODE, NTSTATUS, PDRIVER_OBJECT,
+    WDFDEVICE, WDFDEVICE_INIT, WDF_OBJECT_ATTRIBUTES, WDF_POWER_DEVICE_STATE,
 };
 
 use crate::{
     context::{DeviceContext, MonitorContext},
+    ddc,
     edid::Edid,
+    entry, events, ioctl,
     ipc::{AdapterObject, FlattenModes, ADAPTER, MONITOR_MODES},
+    panic, picture,
+    stats::{self, Callback},
+    trace,
 };
 
-pub extern "C-unwind" fn adapter_init_finished(
-    adapter_object: *mut IDDCX_ADAPTER__,
-    _p_in_args: *const IDARG_IN_ADAPTER_INIT_FINISHED,
-) -> NTSTATUS {
-    let Some(adapter_ptr) = NonNull::new(adapter_object) else {
-        error!("Adapter ptr was null");
-        return NTSTATUS::STATUS_INVALID_ADDRESS;
-    };
-
-    // store adapter object for listener to use
-    if ADAPTER.set(AdapterObject(adapter_ptr)).is_err() {
-        error!("Failed to set adapter");
-        return NTSTATUS::STATUS_ADAPTER_HARDWARE_ERROR;
-    }
+/// Run a callback with [`panic::guard`], counting whether it succeeded
+fn counted(callback: Callback, f: impl FnOnce() -> NTSTATUS) -> NTSTATUS {
+    let status = panic::guard(f);
+    stats::callback(callback, status.is_success());
 
-    DeviceContext::finish_init();
-
-    NTSTATUS::STATUS_SUCCESS
+    status
 }
 
-pub extern "C-unwind" fn device_d0_entry(
-    device: WDFDEVICE,
-    _previous_state: WDF_POWER_DEVICE_STATE,
-) -> NTSTATUS {
+#[wdf_callback(guard = counted(Callback::DeviceD0Entry))]
+pub fn device_d0_entry(device: WDFDEVICE, _previous_state: WDF_POWER_DEVICE_STATE) -> NTSTATUS {
     let status: NTSTATUS = unsafe {
-        DeviceContext::get_mut(device.cast(), |context| {
-            if let Err(e) = context.init_adapter() {
-                error!("Failed to init adapter: {e:?}");
+        WdfObjectContext::<DeviceContext>::get_mut(device.cast(), |context| {
+            // coming back from sleep/hibernation, the adapter survives but the monitors don't
+            if context.is_initialized() {
+                if let Err(e) = context.resume() {
+                    error!("Failed to resume monitors: {e:?}");
+                }
+                trace::device_event("Resume", NTSTATUS::STATUS_SUCCESS);
+
+                return;
+            }
+
+            match context.init_adapter() {
+                Ok(()) => trace::device_event("AdapterInit", NTSTATUS::STATUS_SUCCESS),
+                Err(e) => {
+                    error!("Failed to init adapter: {e:?}");
+                    trace::device_event("AdapterInit", NTSTATUS::STATUS_UNSUCCESSFUL);
+                }
             }
         })
         .into()
@@ -62,6 +71,23 @@ pub extern "C-unwind" fn device_d0_entry(
     NTSTATUS::STATUS_SUCCESS
 }
 
+#[wdf_callback(guard = counted(Callback::DeviceD0Exit))]
+pub fn device_d0_exit(_device: WDFDEVICE, _target_state: WDF_POWER_DEVICE_STATE) -> NTSTATUS {
+    // monitors are departed and re-arrived in `device_d0_entry`, so they survive sleep,
+    // hibernation, and fast startup
+    let status = match DeviceContext::suspend() {
+        Ok(()) => NTSTATUS::STATUS_SUCCESS,
+        Err(e) => {
+            error!("Failed to suspend monitors: {e:?}");
+            NTSTATUS::STATUS_UNSUCCESSFUL
+        }
+    };
+    trace::device_event("Suspend", status);
+
+    // only logged, failing D0 exit would make the framework tear down the whole device
+    NTSTATUS::STATUS_SUCCESS
+}
+
 fn display_info(width: u32, height: u32, refresh_rate: u32) -> DISPLAYCONFIG_VIDEO_SIGNAL_INFO {
     let clock_rate = refresh_rate * (height + 4) * (height + 4) + 1000;
 
@@ -97,95 +123,20 @@ fn display_info(width: u32, height: u32, refresh_rate: u32) -> DISPLAYCONFIG_VID
     }
 }
 
-pub extern "C-unwind" fn parse_monitor_description(
-    p_in_args: *const IDARG_IN_PARSEMONITORDESCRIPTION,
-    p_out_args: *mut IDARG_OUT_PARSEMONITORDESCRIPTION,
-) -> NTSTATUS {
-    let in_args = unsafe { &*p_in_args };
-    let out_args = unsafe { &mut *p_out_args };
-
-    let Some(monitors) = MONITOR_MODES.get() else {
-        error!("Failed to get monitor oncelock data");
-        return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
-    };
-    let Ok(monitors) = monitors.lock() else {
-        error!("MONITOR_MODES mutex poisoned");
-        return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
-    };
-
-    let edid = unsafe {
-        std::slice::from_raw_parts(
-            in_args.MonitorDescription.pData as *const u8,
-            in_args.MonitorDescription.DataSize as usize,
-        )
-    };
-
-    let monitor_index = Edid::get_serial(edid);
-    let Ok(monitor_index) = monitor_index else {
-        error!(
-            "We got an edid {} bytes long, but this is incorrect",
-            edid.len()
-        );
-        return NTSTATUS::STATUS_INVALID_VIEW_SIZE;
-    };
-
-    let Some(monitor) = monitors.iter().find(|&m| m.monitor.id == monitor_index) else {
-        error!("Failed to find monitor id {monitor_index}");
-        return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
-    };
-
-    let number_of_modes: u32 = monitor
-        .monitor
-        .modes
-        .iter()
-        .map(|m| u32::try_from(m.refresh_rates.len()).expect("Cannot use > u32::MAX refresh rates"))
-        .sum();
-
-    out_args.MonitorModeBufferOutputCount = number_of_modes;
-    if in_args.MonitorModeBufferInputCount < number_of_modes {
-        // Return success if there was no buffer, since the caller was only asking for a count of modes
-        return if in_args.MonitorModeBufferInputCount > 0 {
-            NTSTATUS::STATUS_BUFFER_TOO_SMALL
-        } else {
-            NTSTATUS::STATUS_SUCCESS
-        };
+fn active_mode(info: &DISPLAYCONFIG_VIDEO_SIGNAL_INFO) -> ActiveMode {
+    let DISPLAYCONFIG_RATIONAL {
+        Numerator: numerator,
+        Denominator: denominator,
+    } = info.vSyncFreq;
+
+    ActiveMode {
+        width: info.activeSize.cx,
+        height: info.activeSize.cy,
+        // target modes are created with whole refresh rates, see `target_mode`
+        refresh_rate: (numerator + denominator / 2)
+            .checked_div(denominator)
+            .unwrap_or_default(),
     }
-
-    let monitor_modes = unsafe {
-        std::slice::from_raw_parts_mut(
-            in_args
-                .pMonitorModes
-                .cast::<MaybeUninit<IDDCX_MONITOR_MODE>>(),
-            number_of_modes as usize,
-        )
-    };
-
-    for (mode, out_mode) in monitor
-        .monitor
-        .modes
-        .flatten()
-        .zip(monitor_modes.iter_mut())
-    {
-        out_mode.write(IDDCX_MONITOR_MODE {
-            #[allow(clippy::cast_possible_truncation)]
-            Size: mem::size_of::<IDDCX_MONITOR_MODE>() as u32,
-            Origin: IDDCX_MONITOR_MODE_ORIGIN::IDDCX_MONITOR_MODE_ORIGIN_MONITORDESCRIPTOR,
-            MonitorVideoSignalInfo: display_info(mode.width, mode.height, mode.refresh_rate),
-        });
-    }
-
-    // Set the preferred mode as represented in the EDID
-    out_args.PreferredMonitorModeIdx = 0;
-
-    NTSTATUS::STATUS_SUCCESS
-}
-
-pub extern "C-unwind" fn monitor_get_default_modes(
-    _monitor_object: *mut IDDCX_MONITOR__,
-    _p_in_args: *const IDARG_IN_GETDEFAULTDESCRIPTIONMODES,
-    _p_out_args: *mut IDARG_OUT_GETDEFAULTDESCRIPTIONMODES,
-) -> NTSTATUS {
-    NTSTATUS::STATUS_NOT_IMPLEMENTED
 }
 
 pub fn target_mode(width: u32, height: u32, refresh_rate: u32) -> IDDCX_TARGET_MODE {
@@ -229,102 +180,383 @@ pub fn target_mode(width: u32, height: u32, refresh_rate: u32) -> IDDCX_TARGET_M
     }
 }
 
-pub extern "C-unwind" fn monitor_query_modes(
-    monitor_object: *mut IDDCX_MONITOR__,
-    p_in_args: *const IDARG_IN_QUERYTARGETMODES,
-    p_out_args: *mut IDARG_OUT_QUERYTARGETMODES,
-) -> NTSTATUS {
-    // find out which monitor this belongs too
+/// This driver, its `DriverEntry` is generated by `idd_driver`
+#[idd_driver]
+pub struct VirtualDisplayDriver;
+
+impl IddDriver for VirtualDisplayDriver {
+    const GAMMA_RAMP: bool = true;
+    const I2C: bool = true;
+    const DEVICE_IO_CONTROL: bool = true;
+
+    fn guard(callback: IddCallback, f: impl FnOnce() -> NTSTATUS) -> NTSTATUS {
+        let callback = match callback {
+            IddCallback::AdapterInitFinished => Callback::AdapterInitFinished,
+            IddCallback::ParseMonitorDescription => Callback::ParseMonitorDescription,
+            IddCallback::MonitorQueryModes => Callback::MonitorQueryModes,
+            IddCallback::AdapterCommitModes => Callback::AdapterCommitModes,
+            IddCallback::AssignSwapChain => Callback::AssignSwapChain,
+            IddCallback::UnassignSwapChain => Callback::UnassignSwapChain,
+            IddCallback::MonitorSetGammaRamp => Callback::MonitorSetGammaRamp,
+            IddCallback::MonitorI2CTransmit => Callback::MonitorI2CTransmit,
+            IddCallback::MonitorI2CReceive => Callback::MonitorI2CReceive,
+            IddCallback::DriverEntry
+            | IddCallback::DriverUnload
+            | IddCallback::DeviceAdd
+            | IddCallback::MonitorGetDefaultModes
+            | IddCallback::DeviceIoControl => return panic::guard(f),
+        };
 
-    let Some(monitors) = MONITOR_MODES.get() else {
-        error!("Failed to get monitor oncelock data");
-        return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
-    };
-    let Ok(monitors) = monitors.lock() else {
-        error!("MONITOR_MODES mutex poisoned");
-        return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
-    };
+        counted(callback, f)
+    }
 
-    // we have stored the monitor object per id, so we should be able to compare pointers
-    let Some(monitor) = monitors.iter().find(|&m| {
-        m.monitor_object
-            .is_some_and(|p| p.as_ptr() == monitor_object)
-    }) else {
-        error!("Failed to find monitor object in cache for {monitor_object:?}");
-        return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
-    };
+    fn driver_entry(driver: PDRIVER_OBJECT) -> NTSTATUS {
+        entry::driver_entry(driver)
+    }
+
+    fn driver_unload() {
+        entry::driver_unload();
+    }
+
+    fn device_init(init: &mut WDFDEVICE_INIT) -> NTSTATUS {
+        entry::device_init(init)
+    }
+
+    fn device_attributes() -> WDF_OBJECT_ATTRIBUTES {
+        entry::device_attributes()
+    }
+
+    fn device_added(device: WDFDEVICE) -> NTSTATUS {
+        entry::device_added(device)
+    }
+
+    fn adapter_init_finished(
+        adapter: IDDCX_ADAPTER,
+        args: &IDARG_IN_ADAPTER_INIT_FINISHED,
+    ) -> NTSTATUS {
+        trace::device_event("AdapterInitFinished", args.AdapterInitStatus);
 
-    let number_of_modes = monitor
-        .monitor
-        .modes
-        .iter()
-        .map(|m| u32::try_from(m.refresh_rates.len()).expect("Cannot use > u32::MAX modes"))
-        .sum();
+        let Some(adapter_ptr) = NonNull::new(adapter) else {
+            error!("Adapter ptr was null");
+            return NTSTATUS::STATUS_INVALID_ADDRESS;
+        };
+
+        // store adapter object for listener to use. there's one adapter per host process, see
+        // `ipc::startup`
+        if ADAPTER.set(AdapterObject(adapter_ptr)).is_err() {
+            error!("Another adapter was already initialized in this host process");
+            return NTSTATUS::STATUS_ADAPTER_HARDWARE_ERROR;
+        }
+
+        DeviceContext::finish_init()
+    }
+
+    fn parse_monitor_description(
+        in_args: &IDARG_IN_PARSEMONITORDESCRIPTION,
+        out_args: &mut IDARG_OUT_PARSEMONITORDESCRIPTION,
+    ) -> NTSTATUS {
+        let Some(monitors) = MONITOR_MODES.get() else {
+            error!("Failed to get monitor oncelock data");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
+        let Ok(monitors) = monitors.lock() else {
+            error!("MONITOR_MODES mutex poisoned");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
 
-    // Create a set of modes supported for frame processing and scan-out. These are typically not based on the
-    // monitor's descriptor and instead are based on the static processing capability of the device. The OS will
-    // report the available set of modes for a given output as the intersection of monitor modes with target modes.
+        let edid = unsafe {
+            std::slice::from_raw_parts(
+                in_args.MonitorDescription.pData as *const u8,
+                in_args.MonitorDescription.DataSize as usize,
+            )
+        };
 
-    let out_args = unsafe { &mut *p_out_args };
-    out_args.TargetModeBufferOutputCount = number_of_modes;
+        let serial = Edid::get_serial(edid);
+        let Ok(serial) = serial else {
+            error!(
+                "We got an edid {} bytes long, but this is incorrect",
+                edid.len()
+            );
+            return NTSTATUS::STATUS_INVALID_VIEW_SIZE;
+        };
 
-    let in_args = unsafe { &*p_in_args };
+        let Some(monitor) = monitors
+            .iter()
+            .find(|&m| Edid::serial_for(m.monitor.id) == serial)
+        else {
+            error!("Failed to find monitor for edid serial {serial}");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
 
-    if in_args.TargetModeBufferInputCount >= number_of_modes {
-        let out_target_modes = unsafe {
+        let number_of_modes: u32 = monitor
+            .monitor
+            .modes
+            .iter()
+            .map(|m| {
+                u32::try_from(m.refresh_rates.len()).expect("Cannot use > u32::MAX refresh rates")
+            })
+            .sum();
+
+        out_args.MonitorModeBufferOutputCount = number_of_modes;
+        if in_args.MonitorModeBufferInputCount < number_of_modes {
+            // Return success if there was no buffer, since the caller was only asking for a count of modes
+            return if in_args.MonitorModeBufferInputCount > 0 {
+                NTSTATUS::STATUS_BUFFER_TOO_SMALL
+            } else {
+                NTSTATUS::STATUS_SUCCESS
+            };
+        }
+
+        let monitor_modes = unsafe {
             std::slice::from_raw_parts_mut(
                 in_args
-                    .pTargetModes
-                    .cast::<MaybeUninit<IDDCX_TARGET_MODE>>(),
+                    .pMonitorModes
+                    .cast::<MaybeUninit<IDDCX_MONITOR_MODE>>(),
                 number_of_modes as usize,
             )
         };
 
-        for (mode, out_target) in monitor
+        for (mode, out_mode) in monitor
             .monitor
             .modes
             .flatten()
-            .zip(out_target_modes.iter_mut())
+            .zip(monitor_modes.iter_mut())
         {
-            let target_mode = target_mode(mode.width, mode.height, mode.refresh_rate);
+            out_mode.write(IDDCX_MONITOR_MODE {
+                #[allow(clippy::cast_possible_truncation)]
+                Size: mem::size_of::<IDDCX_MONITOR_MODE>() as u32,
+                Origin: IDDCX_MONITOR_MODE_ORIGIN::IDDCX_MONITOR_MODE_ORIGIN_MONITORDESCRIPTOR,
+                MonitorVideoSignalInfo: display_info(mode.width, mode.height, mode.refresh_rate),
+            });
+        }
+
+        // Set the preferred mode as represented in the EDID
+        out_args.PreferredMonitorModeIdx = 0;
+
+        NTSTATUS::STATUS_SUCCESS
+    }
+
+    fn monitor_query_modes(
+        monitor_object: IDDCX_MONITOR,
+        in_args: &IDARG_IN_QUERYTARGETMODES,
+        out_args: &mut IDARG_OUT_QUERYTARGETMODES,
+    ) -> NTSTATUS {
+        // find out which monitor this belongs too
+
+        let Some(monitors) = MONITOR_MODES.get() else {
+            error!("Failed to get monitor oncelock data");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
+        let Ok(monitors) = monitors.lock() else {
+            error!("MONITOR_MODES mutex poisoned");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
+
+        // we have stored the monitor object per id, so we should be able to compare pointers
+        let Some(monitor) = monitors.iter().find(|&m| {
+            m.monitor_object
+                .is_some_and(|p| p.as_ptr() == monitor_object)
+        }) else {
+            error!("Failed to find monitor object in cache for {monitor_object:?}");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
+
+        let number_of_modes = monitor
+            .monitor
+            .modes
+            .iter()
+            .map(|m| u32::try_from(m.refresh_rates.len()).expect("Cannot use > u32::MAX modes"))
+            .sum();
+
+        // Create a set of modes supported for frame processing and scan-out. These are typically not based on the
+        // monitor's descriptor and instead are based on the static processing capability of the device. The OS will
+        // report the available set of modes for a given output as the intersection of monitor modes with target modes.
+
+        out_args.TargetModeBufferOutputCount = number_of_modes;
+
+        if in_args.TargetModeBufferInputCount >= number_of_modes {
+            let out_target_modes = unsafe {
+                std::slice::from_raw_parts_mut(
+                    in_args
+                        .pTargetModes
+                        .cast::<MaybeUninit<IDDCX_TARGET_MODE>>(),
+                    number_of_modes as usize,
+                )
+            };
+
+            for (mode, out_target) in monitor
+                .monitor
+                .modes
+                .flatten()
+                .zip(out_target_modes.iter_mut())
+            {
+                let target_mode = target_mode(mode.width, mode.height, mode.refresh_rate);
 
-            out_target.write(target_mode);
+                out_target.write(target_mode);
+            }
         }
+
+        NTSTATUS::STATUS_SUCCESS
     }
 
-    NTSTATUS::STATUS_SUCCESS
-}
+    fn adapter_commit_modes(_adapter: IDDCX_ADAPTER, args: &IDARG_IN_COMMITMODES) -> NTSTATUS {
+        let Some(monitors) = MONITOR_MODES.get() else {
+            error!("Failed to get monitor oncelock data");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
+        let Ok(mut monitors) = monitors.lock() else {
+            error!("MONITOR_MODES mutex poisoned");
+            return NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR;
+        };
 
-pub extern "C-unwind" fn adapter_commit_modes(
-    _adapter_object: *mut IDDCX_ADAPTER__,
-    _p_in_args: *const IDARG_IN_COMMITMODES,
-) -> NTSTATUS {
-    NTSTATUS::STATUS_SUCCESS
-}
+        let paths = if args.pPaths.is_null() {
+            &[][..]
+        } else {
+            unsafe { slice::from_raw_parts(args.pPaths, args.PathCount as usize) }
+        };
 
-pub extern "C-unwind" fn assign_swap_chain(
-    monitor_object: *mut IDDCX_MONITOR__,
-    p_in_args: *const IDARG_IN_SETSWAPCHAIN,
-) -> NTSTATUS {
-    let p_in_args = unsafe { &*p_in_args };
-
-    unsafe {
-        MonitorContext::get_mut(monitor_object.cast(), |context| {
-            context.assign_swap_chain(
-                p_in_args.hSwapChain,
-                p_in_args.RenderAdapterLuid,
-                p_in_args.hNextSurfaceAvailable,
-            );
-        })
-        .into()
+        // every monitor of the adapter has a path, monitors without an active one aren't shown
+        for monitor in monitors.iter_mut() {
+            let mode = paths
+                .iter()
+                .filter(|path| path.Flags.0 & IDDCX_PATH_FLAGS::IDDCX_PATH_FLAGS_ACTIVE.0 != 0)
+                .find(|path| {
+                    monitor
+                        .monitor_object
+                        .is_some_and(|object| object.as_ptr() == path.MonitorObject)
+                })
+                .map(|path| active_mode(&path.TargetVideoSignalInfo));
+
+            if monitor.monitor.active_mode != mode {
+                monitor.monitor.active_mode = mode;
+
+                // pushed with the lock held, like the other monitor events
+                events::push(EventKind::ActiveModeChanged {
+                    id: monitor.monitor.id,
+                    mode,
+                });
+            }
+        }
+
+        NTSTATUS::STATUS_SUCCESS
     }
-}
 
-pub extern "C-unwind" fn unassign_swap_chain(monitor_object: *mut IDDCX_MONITOR__) -> NTSTATUS {
-    unsafe {
-        MonitorContext::get_mut(monitor_object.cast(), |context| {
-            context.unassign_swap_chain();
-        })
-        .into()
+    fn assign_swap_chain(
+        monitor_object: IDDCX_MONITOR,
+        in_args: &IDARG_IN_SETSWAPCHAIN,
+    ) -> NTSTATUS {
+        unsafe {
+            WdfObjectContext::<MonitorContext>::get_mut(monitor_object.cast(), |context| {
+                context.assign_swap_chain(
+                    in_args.hSwapChain,
+                    in_args.RenderAdapterLuid,
+                    in_args.hNextSurfaceAvailable,
+                );
+            })
+            .into()
+        }
+    }
+
+    fn unassign_swap_chain(monitor_object: IDDCX_MONITOR) -> NTSTATUS {
+        unsafe {
+            WdfObjectContext::<MonitorContext>::get_mut(monitor_object.cast(), |context| {
+                context.unassign_swap_chain();
+            })
+            .into()
+        }
+    }
+
+    fn monitor_set_gamma_ramp(
+        monitor_object: IDDCX_MONITOR,
+        in_args: &IDARG_IN_SET_GAMMARAMP,
+    ) -> NTSTATUS {
+        let ramp = match in_args.Type {
+            IDDCX_GAMMARAMP_TYPE::IDDCX_GAMMARAMP_TYPE_DEFAULT => None,
+
+            IDDCX_GAMMARAMP_TYPE::IDDCX_GAMMARAMP_TYPE_RGB256x3x16 => {
+                if in_args.pGammaRampData.is_null() {
+                    return NTSTATUS::STATUS_INVALID_PARAMETER;
+                }
+
+                let data = unsafe {
+                    slice::from_raw_parts(
+                        in_args.pGammaRampData.cast::<u16>(),
+                        in_args.GammaRampSizeInBytes as usize / mem::size_of::<u16>(),
+                    )
+                };
+
+                let Some(ramp) = picture::ramp_from_rgb256x3x16(data) else {
+                    error!(
+                        "Unexpected gamma ramp size {}",
+                        in_args.GammaRampSizeInBytes
+                    );
+                    return NTSTATUS::STATUS_INVALID_PARAMETER;
+                };
+
+                Some(ramp)
+            }
+
+            // color space transforms are only used with hdr, which isn't supported
+            _ => return NTSTATUS::STATUS_NOT_SUPPORTED,
+        };
+
+        unsafe {
+            WdfObjectContext::<MonitorContext>::get(monitor_object.cast(), |context| {
+                context.set_gamma_ramp(ramp);
+            })
+            .into()
+        }
+    }
+
+    fn monitor_i2c_transmit(
+        monitor_object: IDDCX_MONITOR,
+        in_args: &IDARG_IN_I2C_TRANSMIT,
+    ) -> NTSTATUS {
+        // only DDC/CI is emulated, e.g. the edid can't be read over i2c
+        if in_args.SevenBitI2CAddress != ddc::ADDRESS || in_args.pData.is_null() {
+            return NTSTATUS::STATUS_NOT_SUPPORTED;
+        }
+
+        let data = unsafe {
+            slice::from_raw_parts(in_args.pData.cast::<u8>(), in_args.DataSizeInBytes as usize)
+        };
+
+        let mut valid = false;
+        let status: NTSTATUS = unsafe {
+            WdfObjectContext::<MonitorContext>::get_mut(monitor_object.cast(), |context| {
+                valid = context.ddc_transmit(data);
+            })
+            .into()
+        };
+
+        if status.is_success() && !valid {
+            return NTSTATUS::STATUS_INVALID_PARAMETER;
+        }
+
+        status
+    }
+
+    fn monitor_i2c_receive(
+        monitor_object: IDDCX_MONITOR,
+        in_args: &IDARG_IN_I2C_RECEIVE,
+        _out_args: &mut IDARG_OUT_I2C_RECEIVE,
+    ) -> NTSTATUS {
+        if in_args.SevenBitI2CAddress != ddc::ADDRESS || in_args.pData.is_null() {
+            return NTSTATUS::STATUS_NOT_SUPPORTED;
+        }
+
+        let buffer = unsafe {
+            slice::from_raw_parts_mut(in_args.pData.cast::<u8>(), in_args.DataSizeInBytes as usize)
+        };
+
+        unsafe {
+            WdfObjectContext::<MonitorContext>::get_mut(monitor_object.cast(), |context| {
+                context.ddc_receive(buffer);
+            })
+            .into()
+        }
+    }
+    fn device_io_control(_device: WDFDEVICE, request: WdfRequest) {
+        ioctl::handle(request);
     }
 }
@@ -1,35 +1,59 @@
 use std::{
     mem::{self, size_of},
     num::{ParseIntError, TryFromIntError},
-    ptr::{addr_of_mut, NonNull},
+    ptr::{addr_of, NonNull},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
 };
 
 use anyhow::anyhow;
+use driver_ipc::{CursorFormat, CursorPolicy, DisplayTarget, ExportSize, GammaRamp};
 use log::error;
 use wdf_umdf::{
-    IddCxAdapterInitAsync, IddCxError, IddCxMonitorArrival, IddCxMonitorCreate, WdfError,
+    AdapterInit, HardwareCursor, IddCxError, IddCxMonitorArrival, IddCxMonitorCreate,
+    WdfDeviceCreateDeviceInterface, WdfDeviceSetDeviceInterfaceState, WdfError, WdfObjectContext,
     WdfObjectDelete, WDF_DECLARE_CONTEXT_TYPE,
 };
 use wdf_umdf_sys::{
-    DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY, HANDLE, IDARG_IN_ADAPTER_INIT, IDARG_IN_MONITORCREATE,
-    IDARG_OUT_ADAPTER_INIT, IDARG_OUT_MONITORARRIVAL, IDARG_OUT_MONITORCREATE, IDDCX_ADAPTER,
-    IDDCX_ADAPTER_CAPS, IDDCX_ENDPOINT_DIAGNOSTIC_INFO, IDDCX_ENDPOINT_VERSION,
-    IDDCX_FEATURE_IMPLEMENTATION, IDDCX_MONITOR, IDDCX_MONITOR_DESCRIPTION,
-    IDDCX_MONITOR_DESCRIPTION_TYPE, IDDCX_MONITOR_INFO, IDDCX_SWAPCHAIN, IDDCX_TRANSMISSION_TYPE,
-    LUID, NTSTATUS, WDFDEVICE, WDFOBJECT, WDF_OBJECT_ATTRIBUTES, IDDCX_CURSOR_CAPS, IDARG_IN_SETUP_HWCURSOR
+    DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY, HANDLE, IDARG_IN_MONITORCREATE,
+    IDARG_OUT_MONITORARRIVAL, IDARG_OUT_MONITORCREATE, IDDCX_ADAPTER, IDDCX_FEATURE_IMPLEMENTATION,
+    IDDCX_MONITOR, IDDCX_MONITOR_DESCRIPTION, IDDCX_MONITOR_DESCRIPTION_TYPE, IDDCX_MONITOR_INFO,
+    IDDCX_SWAPCHAIN, IDDCX_TRANSMISSION_TYPE, IDDCX_XOR_CURSOR_SUPPORT, LUID, NTSTATUS,
+    UNICODE_STRING, WDFDEVICE, WDFOBJECT, _GUID,
+};
+use windows::{
+    core::GUID,
+    Win32::{
+        Foundation::{CloseHandle, HANDLE as WHANDLE},
+        System::Threading::CreateEventW,
+    },
 };
-use windows::{core::{w, GUID}, Win32::System::Threading::CreateEventA};
 
 use crate::{
-    direct_3d_device::Direct3DDevice,
-    edid::Edid,
+    cursor::CursorSource,
+    ddc,
+    direct_3d_device::{Direct3DDevice, Direct3DError},
+    edid::{Edid, EdidError},
+    gpu_priority,
     ipc::{startup, MONITOR_MODES},
-    swap_chain_processor::SwapChainProcessor,
+    picture,
+    pool::Resources,
+    shared_frame::{ConsumerSlot, FrameExporter},
+    state,
+    stats::{self, Callback, FrameStats},
+    swap_chain_processor::{FrameLimit, SwapChainProcessor},
+    test_pattern::PatternSlot,
+    trace,
 };
 
 // Maximum amount of monitors that can be connected
 pub const MAX_MONITORS: u8 = 16;
 
+// Set while the device is out of D0, monitors can't arrive until it's back
+static SUSPENDED: AtomicBool = AtomicBool::new(false);
+
 pub struct DeviceContext {
     device: WDFDEVICE,
     adapter: Option<IDDCX_ADAPTER>,
@@ -39,19 +63,42 @@ pub struct DeviceContext {
 unsafe impl Send for DeviceContext {}
 unsafe impl Sync for DeviceContext {}
 
-// for now, `device` is hardcoded into the macro, so it needs to be there even if unused
 #[allow(unused)]
 pub struct MonitorContext {
     device: IDDCX_MONITOR,
+    id: u32,
+    stats: Arc<FrameStats>,
+    limit: Arc<FrameLimit>,
+    pattern: Arc<PatternSlot>,
+    cursor: CursorPolicy,
+    // signalled on new cursor data, once a hardware cursor was set up
+    cursor_event: Option<WHANDLE>,
+    gpu_export: bool,
+    export_size: Option<ExportSize>,
+    consumer: Arc<ConsumerSlot>,
+    ddc: ddc::Endpoint,
     swap_chain_processor: Option<SwapChainProcessor>,
+    // what the last swap chain processed frames with, for the next one
+    recycled: Option<Resources>,
 }
 
 // SAFETY: Raw ptr is managed by external library
 unsafe impl Send for MonitorContext {}
 unsafe impl Sync for MonitorContext {}
 
-WDF_DECLARE_CONTEXT_TYPE!(pub DeviceContext);
-WDF_DECLARE_CONTEXT_TYPE!(pub MonitorContext);
+impl Drop for MonitorContext {
+    fn drop(&mut self) {
+        // the processing thread waits on the cursor event
+        self.stop_swap_chain_processor();
+
+        if let Some(event) = self.cursor_event.take() {
+            _ = unsafe { CloseHandle(event) };
+        }
+    }
+}
+
+WDF_DECLARE_CONTEXT_TYPE!(DeviceContext);
+WDF_DECLARE_CONTEXT_TYPE!(MonitorContext);
 
 #[derive(Debug, thiserror::Error)]
 pub enum ContextError {
@@ -65,6 +112,8 @@ pub enum ContextError {
     IddCx(#[from] IddCxError),
     #[error("Failed to convert to WdfError: {0:?}")]
     Wdf(#[from] WdfError),
+    #[error("Failed to create EDID: {0:?}")]
+    Edid(#[from] EdidError),
     #[error("Windows Error: {0:?}")]
     Win(#[from] windows::core::Error),
     #[error("{0:?}")]
@@ -80,71 +129,180 @@ impl DeviceContext {
     }
 
     pub fn init_adapter(&mut self) -> Result<(), ContextError> {
-        let mut version = IDDCX_ENDPOINT_VERSION {
-            #[allow(clippy::cast_possible_truncation)]
-            Size: size_of::<IDDCX_ENDPOINT_VERSION>() as u32,
+        // the adapter shares the device's context, without keeping it alive
+        let mut attr = WdfObjectContext::<Self>::attributes();
+
+        let adapter = AdapterInit::new("Virtual Display Driver Adapter", u32::from(MAX_MONITORS))
+            .manufacturer("Cherry")
+            .model("Pro")
+            .version(
+                env!("CARGO_PKG_VERSION_MAJOR").parse::<u32>()?,
+                env!("CARGO_PKG_VERSION_MINOR").parse::<u32>()?,
+                env!("CARGO_PKG_VERSION_PATCH").parse::<u32>()?,
+            )
+            // ramps are handed to clients, which apply them, see `picture`
+            .gamma_support(IDDCX_FEATURE_IMPLEMENTATION::IDDCX_FEATURE_IMPLEMENTATION_SOFTWARE)
+            .transmission_type(IDDCX_TRANSMISSION_TYPE::IDDCX_TRANSMISSION_TYPE_WIRED_OTHER);
 
-            MajorVer: env!("CARGO_PKG_VERSION_MAJOR").parse::<u32>()?,
-            MinorVer: env!("CARGO_PKG_VERSION_MINOR").parse::<u32>()?,
-            Build: env!("CARGO_PKG_VERSION_PATCH").parse::<u32>()?,
-            ..Default::default()
+        let adapter = unsafe { adapter.init_async(self.device, &mut attr)? };
+
+        self.adapter = Some(adapter);
+
+        unsafe {
+            WdfObjectContext::<Self>::share(self.device as WDFOBJECT, adapter as WDFOBJECT)?;
         };
 
-        let mut adapter_caps = IDDCX_ADAPTER_CAPS {
-            #[allow(clippy::cast_possible_truncation)]
-            Size: size_of::<IDDCX_ADAPTER_CAPS>() as u32,
+        Ok(())
+    }
 
-            MaxMonitorsSupported: u32::from(MAX_MONITORS),
+    /// Register the device interface clients discover this adapter by, see `driver_ipc::discover`
+    ///
+    /// The reference string is the name of the pipe this instance listens on.
+    pub fn register_interface(&self, pipe_name: &str) -> Result<(), ContextError> {
+        // SAFETY: windows-rs + generated _GUID types are same size, with same fields, and repr C
+        let guid: _GUID =
+            unsafe { mem::transmute(GUID::from_u128(driver_ipc::DEVICE_INTERFACE_GUID)) };
+
+        let mut name = pipe_name.encode_utf16().collect::<Vec<_>>();
+        let byte_len = u16::try_from(name.len() * size_of::<u16>())?;
+        let reference = UNICODE_STRING {
+            Length: byte_len,
+            MaximumLength: byte_len,
+            Buffer: name.as_mut_ptr(),
+        };
 
-            EndPointDiagnostics: IDDCX_ENDPOINT_DIAGNOSTIC_INFO {
-                #[allow(clippy::cast_possible_truncation)]
-                Size: size_of::<IDDCX_ENDPOINT_DIAGNOSTIC_INFO>() as u32,
-                GammaSupport: IDDCX_FEATURE_IMPLEMENTATION::IDDCX_FEATURE_IMPLEMENTATION_NONE,
-                TransmissionType: IDDCX_TRANSMISSION_TYPE::IDDCX_TRANSMISSION_TYPE_WIRED_OTHER,
+        unsafe {
+            WdfDeviceCreateDeviceInterface(self.device, &guid, Some(addr_of!(reference)))?;
+            // interfaces created after the device started aren't enabled automatically
+            WdfDeviceSetDeviceInterfaceState(self.device, &guid, Some(addr_of!(reference)), 1)?;
+        }
 
-                pEndPointFriendlyName: w!("Virtual Display Driver Adapter").as_ptr(),
-                pEndPointManufacturerName: w!("Cherry").as_ptr(),
-                pEndPointModelName: w!("Pro").as_ptr(),
+        Ok(())
+    }
 
-                pFirmwareVersion: addr_of_mut!(version).cast(),
-                pHardwareVersion: addr_of_mut!(version).cast(),
-            },
+    /// Load the settings persisted for this adapter
+    pub fn load_settings(&self) {
+        unsafe { gpu_priority::load(self.device) };
+    }
 
-            ..Default::default()
-        };
+    /// Change the realtime gpu priority setting of this adapter, see `gpu_priority`
+    pub fn set_realtime_gpu_priority(&self, enabled: bool) {
+        unsafe { gpu_priority::set(self.device, enabled) };
+    }
+
+    pub fn is_initialized(&self) -> bool {
+        self.adapter.is_some()
+    }
+
+    pub fn is_suspended() -> bool {
+        SUSPENDED.load(Ordering::Acquire)
+    }
 
-        let mut attr = WDF_OBJECT_ATTRIBUTES::init_context_type(unsafe { Self::get_type_info() });
+    /// Depart all monitors before the device leaves D0, e.g. for sleep or hibernation
+    ///
+    /// Their configuration stays in `MONITOR_MODES`, so [`Self::resume`] can bring them back
+    /// without clients having to add them again.
+    pub fn suspend() -> Result<(), ContextError> {
+        SUSPENDED.store(true, Ordering::Release);
 
-        let adapter_init = IDARG_IN_ADAPTER_INIT {
-            // this is WdfDevice because that's what we set last
-            WdfDevice: self.device,
-            pCaps: addr_of_mut!(adapter_caps).cast(),
-            ObjectAttributes: addr_of_mut!(attr).cast(),
+        // the adapter never finished initializing, so there are no monitors
+        let Some(monitors) = MONITOR_MODES.get() else {
+            return Ok(());
         };
 
-        let mut adapter_init_out = IDARG_OUT_ADAPTER_INIT::default();
-        unsafe { IddCxAdapterInitAsync(&adapter_init, &mut adapter_init_out)? };
+        for departure in state::take_objects(monitors) {
+            departure.depart();
+        }
+
+        Ok(())
+    }
 
-        self.adapter = Some(adapter_init_out.AdapterObject);
+    /// Re-arrive the plugged in monitors once the device is back in D0
+    pub fn resume(&mut self) -> Result<(), ContextError> {
+        SUSPENDED.store(false, Ordering::Release);
 
-        unsafe { self.clone_into(adapter_init_out.AdapterObject as WDFOBJECT)? };
+        let Some(monitors) = MONITOR_MODES.get() else {
+            return Ok(());
+        };
+
+        // `create_monitor` locks the monitors too
+        let ids = monitors
+            .lock()
+            .map_err(|_| anyhow!("Failed to lock mutex"))?
+            .iter()
+            .filter(|monitor| monitor.plugged && monitor.monitor_object.is_none())
+            .map(|monitor| monitor.monitor.id)
+            .collect::<Vec<_>>();
+
+        for id in ids {
+            if let Err(e) = self.create_monitor(id) {
+                error!("Failed to restore monitor {id}: {e:?}");
+            }
+        }
 
         Ok(())
     }
 
     pub fn finish_init() -> NTSTATUS {
         // start the socket listener to listen for messages from the client
-        startup();
+        if let Err(e) = startup() {
+            error!("Failed to start listening for clients: {e}");
+            return NTSTATUS::STATUS_ADAPTER_HARDWARE_ERROR;
+        }
 
         NTSTATUS::STATUS_SUCCESS
     }
 
     pub fn create_monitor(&mut self, index: u32) -> Result<(), ContextError> {
-        let mut attr =
-            WDF_OBJECT_ATTRIBUTES::init_context_type(unsafe { MonitorContext::get_type_info() });
-
-        // use the edid serial number to represent the monitor index for later identification
-        let mut edid = Edid::generate_with(index);
+        // the context is dropped when the os deletes the monitor object after its departure
+        let mut attr = WdfObjectContext::<MonitorContext>::attributes();
+
+        let (
+            custom_edid,
+            edid_name,
+            audio,
+            cursor,
+            stats,
+            limit,
+            pattern,
+            consumer,
+            gpu_export,
+            exclusive,
+            export_size,
+        ) = MONITOR_MODES
+            .get()
+            .ok_or(anyhow!("Failed to get OnceLock"))?
+            .lock()
+            .map_err(|_| anyhow!("Failed to lock mutex"))?
+            .iter()
+            .find(|monitor| monitor.monitor.id == index)
+            .map(|monitor| {
+                (
+                    monitor.monitor.edid.clone(),
+                    monitor.monitor.edid_name(),
+                    monitor.monitor.audio,
+                    monitor.monitor.cursor,
+                    monitor.stats.clone(),
+                    monitor.limit.clone(),
+                    monitor.pattern.clone(),
+                    monitor.consumer.clone(),
+                    monitor.monitor.gpu_export,
+                    monitor.monitor.exclusive_capture,
+                    monitor.monitor.export_size,
+                )
+            })
+            .unwrap_or_default();
+
+        // the edid serial number is derived from the monitor index, used for later identification
+        // a custom edid (e.g. copied from a physical display) gets its serial replaced for the same reason
+        let serial = Edid::serial_for(index);
+        let mut edid = match custom_edid {
+            Some(edid) => Edid::with_serial(&edid, serial)?,
+            None => Edid::generate_with(serial, audio, edid_name.as_deref())?,
+        };
+        if exclusive {
+            Edid::add_specialized_extension(&mut edid)?;
+        }
 
         let mut monitor_info = IDDCX_MONITOR_INFO {
             #[allow(clippy::cast_possible_truncation)]
@@ -176,65 +334,123 @@ impl DeviceContext {
         };
 
         let mut monitor_create_out = IDARG_OUT_MONITORCREATE::default();
-        unsafe {
+        let status = unsafe {
             IddCxMonitorCreate(
                 self.adapter.ok_or(anyhow!("Failed to get adapter"))?,
                 &monitor_create,
                 &mut monitor_create_out,
-            )?
+            )
         };
-
-        // store monitor object for later
-        {
-            let mut lock = MONITOR_MODES
-                .get()
-                .ok_or(anyhow!("Failed to get OnceLock"))?
-                .lock()
-                .map_err(|_| anyhow!("Failed to lock mutex"))?;
-
-            for monitor in &mut *lock {
-                if monitor.monitor.id == index {
-                    monitor.monitor_object = Some(
-                        NonNull::new(monitor_create_out.MonitorObject)
-                            .ok_or(anyhow!("MonitorObject was null"))?,
-                    );
-                }
-            }
+        trace::monitor_event("Create", index, trace_status(&status));
+        stats::callback(Callback::MonitorCreate, status.is_ok());
+        status?;
+
+        // store monitor object for later, the monitor could have been removed or plugged in by
+        // another thread while the os created it
+        let monitor_object = NonNull::new(monitor_create_out.MonitorObject)
+            .ok_or(anyhow!("MonitorObject was null"))?;
+        let monitors = MONITOR_MODES
+            .get()
+            .ok_or(anyhow!("Failed to get OnceLock"))?;
+        if let Err(monitor_object) = state::attach(monitors, index, monitor_object, edid.clone()) {
+            unsafe { WdfObjectDelete(monitor_object.as_ptr() as WDFOBJECT)? };
+            return Ok(());
         }
 
-        unsafe {
-            let context = MonitorContext::new(monitor_create_out.MonitorObject);
-            context.init(monitor_create_out.MonitorObject as WDFOBJECT)?;
-        }
+        let context = MonitorContext::new(
+            monitor_create_out.MonitorObject,
+            index,
+            stats,
+            limit,
+            pattern,
+            cursor,
+            gpu_export,
+            export_size,
+            consumer,
+        );
+        unsafe { WdfObjectContext::init(monitor_create_out.MonitorObject as WDFOBJECT, context)? };
 
         // tell os monitor is plugged in
 
         let mut arrival_out = IDARG_OUT_MONITORARRIVAL::default();
 
-        unsafe {
-            IddCxMonitorArrival(monitor_create_out.MonitorObject, &mut arrival_out)?;
-        }
+        let status =
+            unsafe { IddCxMonitorArrival(monitor_create_out.MonitorObject, &mut arrival_out) };
+        trace::monitor_event("Arrival", index, trace_status(&status));
+        stats::callback(Callback::MonitorArrival, status.is_ok());
+        status?;
+
+        let target = DisplayTarget {
+            adapter: (i64::from(arrival_out.OsAdapterLuid.HighPart) << 32)
+                | i64::from(arrival_out.OsAdapterLuid.LowPart),
+            id: arrival_out.OsTargetId,
+        };
+        state::arrived(monitors, monitor_object, target);
 
         Ok(())
     }
 }
 
+fn trace_status(result: &Result<NTSTATUS, IddCxError>) -> NTSTATUS {
+    match result {
+        Ok(status) => *status,
+        Err(IddCxError::CallFailed(status) | IddCxError::NtStatus(status)) => *status,
+        Err(IddCxError::IddCxFunctionNotAvailable(_)) => NTSTATUS::STATUS_NOT_FOUND,
+    }
+}
+
 impl MonitorContext {
-    pub fn new(device: IDDCX_MONITOR) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        device: IDDCX_MONITOR,
+        id: u32,
+        stats: Arc<FrameStats>,
+        limit: Arc<FrameLimit>,
+        pattern: Arc<PatternSlot>,
+        cursor: CursorPolicy,
+        gpu_export: bool,
+        export_size: Option<ExportSize>,
+        consumer: Arc<ConsumerSlot>,
+    ) -> Self {
         Self {
             device,
+            id,
+            stats,
+            limit,
+            pattern,
+            cursor,
+            cursor_event: None,
+            gpu_export,
+            export_size,
+            consumer,
+            ddc: ddc::Endpoint::default(),
             swap_chain_processor: None,
+            recycled: None,
         }
     }
 
+    pub fn set_gamma_ramp(&self, ramp: Option<GammaRamp>) {
+        picture::update(self.id, |picture| picture.gamma = ramp);
+    }
+
+    /// Handle a DDC/CI request, returns `false` if it isn't a valid one
+    pub fn ddc_transmit(&mut self, data: &[u8]) -> bool {
+        self.ddc.transmit(self.id, data)
+    }
+
+    /// Read the reply to the last DDC/CI request
+    pub fn ddc_receive(&mut self, buffer: &mut [u8]) {
+        self.ddc.receive(buffer);
+    }
+
     pub fn assign_swap_chain(
         &mut self,
         swap_chain: IDDCX_SWAPCHAIN,
         render_adapter: LUID,
         new_frame_event: HANDLE,
     ) {
-        // drop processing thread
-        drop(self.swap_chain_processor.take());
+        // stop processing thread, the new one takes over its resources
+        self.stop_swap_chain_processor();
 
         // transmute would work, but one less unsafe block, so why not
         let luid = windows::Win32::Foundation::LUID {
@@ -242,47 +458,44 @@ impl MonitorContext {
             HighPart: render_adapter.HighPart,
         };
 
-        let device = Direct3DDevice::init(luid);
+        let resources = self.resources(luid);
+
+        if let Ok(resources) = resources {
+            // without a hardware cursor, the os composites the cursor into the frames
+            let cursor = if self.cursor.hardware {
+                self.setup_hardware_cursor()
+                    .map_err(|e| {
+                        error!(
+                            "Failed to set up hardware cursor of monitor {}: {e}",
+                            self.id
+                        );
+                    })
+                    .ok()
+            } else {
+                None
+            };
 
-        if let Ok(device) = device {
             let mut processor = SwapChainProcessor::new();
 
-            processor.run(swap_chain, device, new_frame_event);
+            processor.run(
+                swap_chain,
+                resources,
+                new_frame_event,
+                self.id,
+                self.stats.clone(),
+                self.limit.clone(),
+                self.pattern.clone(),
+                cursor,
+            );
+            trace::swap_chain_event("Assigned", self.id);
+            stats::callback(Callback::SwapChainDeviceInit, true);
 
             self.swap_chain_processor = Some(processor);
-
-            // create an event to get notified new cursor data
-            let mouse_event = unsafe {
-                CreateEventA(
-                    None,
-                    false,
-                    false,
-                    "arbitraryMouseEventName",
-                ).unwrap()
-            };
-
-            // set up cursor capabilities
-            let cursor_info = IDDCX_CURSOR_CAPS {
-                Size: size_of::<IDDCX_CURSOR_CAPS>() as u32,
-                AlphaCursorSupport: true,
-                MaxX: 64,
-                MaxY: 64,
-                ColorXorCursorSupport: IDDCX_XOR_CURSOR_SUPPORT_NONE,
-            };
-
-            // prepare IddCxMonitorSetupHardwareCursor arguments
-            let hw_cursor = IDARG_IN_SETUP_HWCURSOR {
-                CursorInfo: cursor_info,
-                hNewCursorDataAvailable: mouse_event, // this event will be called when new cursor data is available
-            };
-
-            let status = IddCxMonitorSetupHardwareCursor(
-                m_Monitor, // handle to the monitor we want to enable hardware mouse on
-                &hw_cursor,
-            );
         } else {
             // It's important to delete the swap-chain if D3D initialization fails, so that the OS knows to generate a new
             // swap-chain and try again.
+            trace::swap_chain_event("DeviceInitFailed", self.id);
+            stats::callback(Callback::SwapChainDeviceInit, false);
 
             unsafe {
                 let _ = WdfObjectDelete(swap_chain.cast());
@@ -291,6 +504,74 @@ impl MonitorContext {
     }
 
     pub fn unassign_swap_chain(&mut self) {
-        self.swap_chain_processor.take();
+        self.stop_swap_chain_processor();
+        trace::swap_chain_event("Unassigned", self.id);
+    }
+
+    /// Get the cursor separately from the frames, it has to be set up again for every swap chain
+    fn setup_hardware_cursor(&mut self) -> Result<CursorSource, ContextError> {
+        let event = match self.cursor_event {
+            Some(event) => event,
+            None => {
+                // signalled by the os when there's new cursor data
+                let event = unsafe { CreateEventW(None, false, false, None) }?;
+                *self.cursor_event.insert(event)
+            }
+        };
+
+        let xor = if self.cursor.format == CursorFormat::AlphaXor {
+            IDDCX_XOR_CURSOR_SUPPORT::IDDCX_XOR_CURSOR_SUPPORT_FULL
+        } else {
+            IDDCX_XOR_CURSOR_SUPPORT::IDDCX_XOR_CURSOR_SUPPORT_NONE
+        };
+
+        let cursor = HardwareCursor::new(event.0 as HANDLE)
+            .max_size(self.cursor.max_size, self.cursor.max_size)
+            .alpha(self.cursor.format != CursorFormat::Monochrome)
+            .xor(xor);
+
+        // SAFETY: The monitor arrived, and the event lives as long as its context
+        unsafe { cursor.setup(self.device)? };
+
+        // SAFETY: Same as above, and the processing thread is stopped before the event is closed
+        Ok(unsafe { CursorSource::new(self.device, event, self.id, self.cursor.max_size) })
+    }
+
+    fn stop_swap_chain_processor(&mut self) {
+        if let Some(processor) = self.swap_chain_processor.take() {
+            self.recycled = processor.stop();
+        }
+    }
+
+    /// The device and exporter for a new swap chain, from the last one if they fit
+    fn resources(
+        &mut self,
+        luid: windows::Win32::Foundation::LUID,
+    ) -> Result<Resources, Direct3DError> {
+        if let Some(resources) = self
+            .recycled
+            .take()
+            .filter(|resources| resources.reusable_for(luid))
+        {
+            self.stats.pool().reused();
+            return Ok(resources);
+        }
+
+        let device = Direct3DDevice::init(luid)?;
+        self.stats.pool().allocated();
+
+        Ok(Resources {
+            luid,
+            device,
+            exporter: self.gpu_export.then(|| {
+                FrameExporter::new(
+                    self.id,
+                    self.stats.clone(),
+                    self.export_size,
+                    self.consumer.clone(),
+                )
+            }),
+            realtime_priority: false,
+        })
     }
 }
@@ -0,0 +1,415 @@
+//! Hardware cursor of monitors, see `CursorPolicy::hardware`
+//!
+//! The os signals a monitor's cursor event when its cursor moved or changed shape. The swap chain
+//! thread queries it then, see [`CursorSource`], and publishes it for the ipc. Consumers of shared
+//! frames either draw it themselves, or ask for it to be drawn into the frames, see
+//! [`CursorOverlay`].
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use driver_ipc::{CursorShape, CursorShapeKind, CursorState};
+use log::debug;
+use wdf_umdf::IddCxMonitorQueryHardwareCursor;
+use wdf_umdf_sys::{
+    IDARG_IN_QUERY_HWCURSOR, IDARG_OUT_QUERY_HWCURSOR, IDDCX_CURSOR_SHAPE_INFO,
+    IDDCX_CURSOR_SHAPE_TYPE, IDDCX_MONITOR,
+};
+use windows::Win32::{
+    Foundation::{HANDLE, WAIT_OBJECT_0},
+    Graphics::{
+        Direct3D11::{
+            ID3D11Texture2D, D3D11_BOX, D3D11_CPU_ACCESS_READ, D3D11_MAPPED_SUBRESOURCE,
+            D3D11_MAP_READ, D3D11_TEXTURE2D_DESC, D3D11_USAGE_STAGING,
+        },
+        Dxgi::Common::{DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_SAMPLE_DESC},
+    },
+    System::Threading::WaitForSingleObject,
+};
+
+use crate::{direct_3d_device::Direct3DDevice, helpers::Sendable};
+
+#[derive(Debug, Default)]
+struct Published {
+    // `None` until the monitor's hardware cursor was set up
+    state: Option<CursorState>,
+    composite: bool,
+}
+
+// Cursor of each monitor, for the ipc, and whether it's drawn into the shared frames
+static CURSORS: OnceLock<Mutex<HashMap<u32, Published>>> = OnceLock::new();
+
+fn cursors() -> &'static Mutex<HashMap<u32, Published>> {
+    CURSORS.get_or_init(Mutex::default)
+}
+
+/// The hardware cursor of a monitor, without its shape if it's the one with `shape_id`
+pub fn get(monitor_id: u32, shape_id: u32) -> Option<CursorState> {
+    let cursors = cursors().lock().ok()?;
+    let mut state = cursors.get(&monitor_id)?.state.clone()?;
+
+    if state.shape_id == shape_id {
+        state.shape = None;
+    }
+
+    Some(state)
+}
+
+/// Draw the cursor of a monitor into its shared frames or not
+pub fn set_composite(monitor_id: u32, composite: bool) {
+    if let Ok(mut cursors) = cursors().lock() {
+        cursors.entry(monitor_id).or_default().composite = composite;
+    }
+}
+
+/// Whether the cursor of a monitor is drawn into its shared frames
+pub fn composited(monitor_id: u32) -> bool {
+    cursors()
+        .lock()
+        .ok()
+        .and_then(|cursors| cursors.get(&monitor_id).map(|cursor| cursor.composite))
+        .unwrap_or_default()
+}
+
+/// Forget a removed monitor's cursor
+pub fn clear(monitor_id: u32) {
+    if let Ok(mut cursors) = cursors().lock() {
+        cursors.remove(&monitor_id);
+    }
+}
+
+fn publish(monitor_id: u32, state: &CursorState) {
+    if let Ok(mut cursors) = cursors().lock() {
+        cursors.entry(monitor_id).or_default().state = Some(state.clone());
+    }
+}
+
+/// Queries the hardware cursor of one monitor
+pub struct CursorSource {
+    monitor: Sendable<IDDCX_MONITOR>,
+    event: HANDLE,
+    monitor_id: u32,
+    // the os writes new shapes in here
+    buffer: Vec<u8>,
+    state: CursorState,
+}
+
+impl CursorSource {
+    /// # Safety
+    ///
+    /// The hardware cursor of `monitor` must be set up with `event`, and both must outlive this
+    pub unsafe fn new(
+        monitor: IDDCX_MONITOR,
+        event: HANDLE,
+        monitor_id: u32,
+        max_size: u32,
+    ) -> Self {
+        let state = CursorState::default();
+        publish(monitor_id, &state);
+
+        Self {
+            monitor: unsafe { Sendable::new(monitor) },
+            event,
+            monitor_id,
+            // shapes have 32 bits per pixel, and are at most `max_size` wide and high
+            buffer: vec![0; max_size as usize * max_size as usize * 4],
+            state,
+        }
+    }
+
+    pub fn state(&self) -> &CursorState {
+        &self.state
+    }
+
+    /// Query the cursor if the os has new data for it, returns whether it changed
+    pub fn poll(&mut self) -> bool {
+        // the event resets when the wait succeeds, so this only queries once per change
+        let signalled = unsafe { WaitForSingleObject(self.event, 0) } == WAIT_OBJECT_0;
+        if !signalled {
+            return false;
+        }
+
+        let in_args = IDARG_IN_QUERY_HWCURSOR {
+            LastShapeId: self.state.shape_id,
+            ShapeBufferSizeInBytes: u32::try_from(self.buffer.len()).unwrap_or(u32::MAX),
+            pShapeBuffer: self.buffer.as_mut_ptr(),
+        };
+        let mut out_args = IDARG_OUT_QUERY_HWCURSOR::default();
+
+        let res =
+            unsafe { IddCxMonitorQueryHardwareCursor(*self.monitor, &in_args, &mut out_args) };
+        if let Err(e) = res {
+            debug!(
+                "Failed to query cursor of monitor {}: {e:?}",
+                self.monitor_id
+            );
+            return false;
+        }
+
+        self.state.visible = out_args.IsCursorVisible != 0;
+        self.state.x = out_args.X;
+        self.state.y = out_args.Y;
+
+        if out_args.IsCursorShapeUpdated != 0 {
+            let info = &out_args.CursorShapeInfo;
+            self.state.shape_id = info.ShapeId;
+            self.state.shape = shape(info, &self.buffer);
+        }
+
+        publish(self.monitor_id, &self.state);
+
+        true
+    }
+}
+
+// Copy a new shape out of the buffer the os wrote it to, rows there can have padding
+fn shape(info: &IDDCX_CURSOR_SHAPE_INFO, buffer: &[u8]) -> Option<CursorShape> {
+    let kind = match info.CursorType {
+        IDDCX_CURSOR_SHAPE_TYPE::IDDCX_CURSOR_SHAPE_TYPE_ALPHA => CursorShapeKind::Alpha,
+        IDDCX_CURSOR_SHAPE_TYPE::IDDCX_CURSOR_SHAPE_TYPE_MASKED_COLOR => {
+            CursorShapeKind::MaskedColor
+        }
+        _ => return None,
+    };
+
+    let row_bytes = info.Width as usize * 4;
+    let pitch = info.Pitch as usize;
+    if pitch < row_bytes {
+        return None;
+    }
+
+    let data = (0..info.Height as usize)
+        .map(|row| buffer.get(row * pitch..row * pitch + row_bytes))
+        .collect::<Option<Vec<_>>>()?
+        .concat();
+
+    Some(CursorShape {
+        kind,
+        width: info.Width,
+        height: info.Height,
+        hot_x: info.XHot,
+        hot_y: info.YHot,
+        data,
+    })
+}
+
+// Pixels of a texture the cursor was drawn over
+struct Region {
+    area: D3D11_BOX,
+    pixels: Vec<u8>,
+}
+
+/// Draws the cursor into a bgra texture, and takes it out again when it moves
+///
+/// The pixels under the cursor are read back through a small staging texture, blended on the
+/// cpu and written back, which is cheap for cursor sized areas.
+#[derive(Default)]
+pub struct CursorOverlay {
+    // readback texture, and the size it was created for
+    staging: Option<(ID3D11Texture2D, u32, u32)>,
+    // what the cursor was last drawn over, to restore it when the cursor moves without a new
+    // frame
+    under: Option<Region>,
+}
+
+impl CursorOverlay {
+    /// Forget what the cursor was drawn over, `texture` got a new frame
+    pub fn reset(&mut self) {
+        self.under = None;
+    }
+
+    /// Take the cursor out of `texture` again, then draw `cursor` if it's visible
+    ///
+    /// Must be called while holding the texture's keyed mutex.
+    pub fn draw(
+        &mut self,
+        device: &Direct3DDevice,
+        texture: &ID3D11Texture2D,
+        desc: &D3D11_TEXTURE2D_DESC,
+        cursor: Option<&CursorState>,
+    ) -> windows::core::Result<()> {
+        if let Some(under) = self.under.take() {
+            write(device, texture, &under.area, &under.pixels);
+        }
+
+        let Some((cursor, shape)) = cursor
+            .filter(|cursor| cursor.visible)
+            .and_then(|cursor| Some((cursor, cursor.shape.as_ref()?)))
+        else {
+            return Ok(());
+        };
+
+        // only the part of the cursor on the texture is drawn
+        let clamp =
+            |value: i64, max: u32| u32::try_from(value.clamp(0, i64::from(max))).unwrap_or(0);
+        let area = D3D11_BOX {
+            left: clamp(i64::from(cursor.x), desc.Width),
+            top: clamp(i64::from(cursor.y), desc.Height),
+            front: 0,
+            right: clamp(i64::from(cursor.x) + i64::from(shape.width), desc.Width),
+            bottom: clamp(i64::from(cursor.y) + i64::from(shape.height), desc.Height),
+            back: 1,
+        };
+        if area.right <= area.left || area.bottom <= area.top {
+            return Ok(());
+        }
+
+        let pixels = self.read(device, texture, &area)?;
+
+        let mut blended = pixels.clone();
+        // where the drawn part starts in the shape, past what's off the texture
+        let skipped = |start: u32, position: i32| {
+            usize::try_from(i64::from(start) - i64::from(position)).unwrap_or_default()
+        };
+        let offset = (skipped(area.left, cursor.x), skipped(area.top, cursor.y));
+        blend(
+            &mut blended,
+            (area.right - area.left) as usize,
+            shape,
+            offset,
+        );
+
+        write(device, texture, &area, &blended);
+        self.under = Some(Region { area, pixels });
+
+        Ok(())
+    }
+
+    // tightly packed bgra rows of `area`
+    fn read(
+        &mut self,
+        device: &Direct3DDevice,
+        texture: &ID3D11Texture2D,
+        area: &D3D11_BOX,
+    ) -> windows::core::Result<Vec<u8>> {
+        let (width, height) = (area.right - area.left, area.bottom - area.top);
+        let staging = self.staging(device, width, height)?;
+
+        unsafe {
+            device.device_context.CopySubresourceRegion(
+                &staging,
+                0,
+                0,
+                0,
+                0,
+                texture,
+                0,
+                Some(area),
+            );
+        }
+
+        let mut mapped = D3D11_MAPPED_SUBRESOURCE::default();
+        // waits for the copy, which is small
+        unsafe {
+            device
+                .device_context
+                .Map(&staging, 0, D3D11_MAP_READ, 0, Some(&mut mapped))?;
+        }
+
+        let row_bytes = width as usize * 4;
+        let pitch = mapped.RowPitch as usize;
+        let mut pixels = Vec::with_capacity(row_bytes * height as usize);
+        for row in 0..height as usize {
+            // SAFETY: the texture is mapped and at least `height` rows of `pitch` bytes
+            let start = unsafe { mapped.pData.cast::<u8>().add(row * pitch) };
+            let row = unsafe { std::slice::from_raw_parts(start, row_bytes) };
+            pixels.extend_from_slice(row);
+        }
+
+        unsafe { device.device_context.Unmap(&staging, 0) };
+
+        Ok(pixels)
+    }
+
+    // a staging texture of at least `width` and `height`
+    fn staging(
+        &mut self,
+        device: &Direct3DDevice,
+        width: u32,
+        height: u32,
+    ) -> windows::core::Result<ID3D11Texture2D> {
+        if let Some((staging, staging_width, staging_height)) = &self.staging {
+            if *staging_width >= width && *staging_height >= height {
+                return Ok(staging.clone());
+            }
+        }
+
+        #[allow(clippy::cast_sign_loss)]
+        let desc = D3D11_TEXTURE2D_DESC {
+            Width: width,
+            Height: height,
+            MipLevels: 1,
+            ArraySize: 1,
+            Format: DXGI_FORMAT_B8G8R8A8_UNORM,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: 1,
+                Quality: 0,
+            },
+            Usage: D3D11_USAGE_STAGING,
+            BindFlags: 0,
+            CPUAccessFlags: D3D11_CPU_ACCESS_READ.0 as u32,
+            MiscFlags: 0,
+        };
+
+        let mut staging = None;
+        unsafe {
+            device
+                .device
+                .CreateTexture2D(&desc, None, Some(&mut staging))?;
+        }
+        let staging = staging.ok_or_else(windows::core::Error::from_win32)?;
+
+        self.staging = Some((staging.clone(), width, height));
+        Ok(staging)
+    }
+}
+
+fn write(device: &Direct3DDevice, texture: &ID3D11Texture2D, area: &D3D11_BOX, pixels: &[u8]) {
+    unsafe {
+        device.device_context.UpdateSubresource(
+            texture,
+            0,
+            Some(area),
+            pixels.as_ptr().cast(),
+            (area.right - area.left) * 4,
+            0,
+        );
+    }
+}
+
+// Draw `shape` over bgra `pixels`, rows of `width` pixels starting at `offset` in the shape
+#[allow(clippy::cast_possible_truncation)]
+fn blend(pixels: &mut [u8], width: usize, shape: &CursorShape, offset: (usize, usize)) {
+    let shape_width = shape.width as usize;
+
+    for (y, row) in pixels.chunks_exact_mut(width * 4).enumerate() {
+        for (x, pixel) in row.chunks_exact_mut(4).enumerate() {
+            let at = ((y + offset.1) * shape_width + x + offset.0) * 4;
+            let Some(source) = shape.data.get(at..at + 4) else {
+                continue;
+            };
+
+            match shape.kind {
+                CursorShapeKind::Alpha => {
+                    let alpha = u32::from(source[3]);
+                    for channel in 0..3 {
+                        let value = u32::from(source[channel]) * alpha
+                            + u32::from(pixel[channel]) * (255 - alpha);
+                        pixel[channel] = ((value + 127) / 255) as u8;
+                    }
+                }
+
+                CursorShapeKind::MaskedColor if source[3] == 0 => {
+                    pixel[..3].copy_from_slice(&source[..3]);
+                }
+                CursorShapeKind::MaskedColor => {
+                    for channel in 0..3 {
+                        pixel[channel] ^= source[channel];
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! DDC/CI endpoint of a monitor, so monitor control tools (e.g. Monitorian, or the brightness
+//! slider) can change its brightness and contrast
+//!
+//! Windows hands DDC/CI messages to the driver as i2c transfers: the host writes a request, and
+//! reads the reply, if there is one, with the next transfer.
+
+use crate::picture;
+
+/// 7 bit i2c address of DDC/CI
+pub const ADDRESS: u32 = 0x37;
+
+// Addresses at the start of messages, which they're checksummed with. Replies are checksummed
+// with the virtual host address instead of the sender's
+const DISPLAY_ADDRESS: u8 = 0x6E;
+const HOST_ADDRESS: u8 = 0x51;
+const VIRTUAL_HOST_ADDRESS: u8 = 0x50;
+
+// Length bytes have this bit set
+const LENGTH_FLAG: u8 = 0x80;
+
+const GET_VCP: u8 = 0x01;
+const GET_VCP_REPLY: u8 = 0x02;
+const SET_VCP: u8 = 0x03;
+const SAVE_SETTINGS: u8 = 0x0C;
+const CAPABILITIES: u8 = 0xF3;
+const CAPABILITIES_REPLY: u8 = 0xE3;
+
+const VCP_BRIGHTNESS: u8 = 0x10;
+const VCP_CONTRAST: u8 = 0x12;
+// Both controls go from 0 to this
+const VCP_MAX: u16 = 100;
+
+const CAPABILITIES_STRING: &[u8] =
+    b"(prot(monitor)type(lcd)model(VirtualDisplay)cmds(01 02 03 0C F3)vcp(10 12)mccs_ver(2.1))";
+// Bytes of the capabilities string sent per reply, the host asks for the next ones by offset
+const CAPABILITIES_FRAGMENT: usize = 32;
+
+/// DDC/CI state of a single monitor
+#[derive(Debug, Default)]
+pub struct Endpoint {
+    // reply to the last request, until it's read
+    reply: Option<Vec<u8>>,
+}
+
+impl Endpoint {
+    /// Handle a message the host wrote
+    ///
+    /// Returns `false` if it isn't a valid DDC/CI request
+    pub fn transmit(&mut self, monitor_id: u32, data: &[u8]) -> bool {
+        let Some(payload) = parse(data) else {
+            return false;
+        };
+
+        self.reply = match *payload {
+            [GET_VCP, code] => Some(message(&get_vcp(monitor_id, code))),
+
+            [SET_VCP, code, high, low] => {
+                set_vcp(monitor_id, code, u16::from_be_bytes([high, low]));
+                None
+            }
+
+            // settings aren't persisted, there's nothing to save
+            [SAVE_SETTINGS] => None,
+
+            [CAPABILITIES, high, low] => {
+                Some(message(&capabilities(u16::from_be_bytes([high, low]))))
+            }
+
+            _ => return false,
+        };
+
+        true
+    }
+
+    /// Read the reply to the last request into `buffer`, padded with zeroes
+    ///
+    /// Without a reply, this is the null message, which tells the host there's nothing to read
+    pub fn receive(&mut self, buffer: &mut [u8]) {
+        let reply = self.reply.take().unwrap_or_else(|| message(&[]));
+
+        let len = reply.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&reply[..len]);
+        buffer[len..].fill(0);
+    }
+}
+
+/// The payload of a request, after checking its address, length and checksum
+fn parse(data: &[u8]) -> Option<&[u8]> {
+    let &[HOST_ADDRESS, length, ref rest @ ..] = data else {
+        return None;
+    };
+
+    if length & LENGTH_FLAG == 0 {
+        return None;
+    }
+    let length = usize::from(length & !LENGTH_FLAG);
+
+    let payload = rest.get(..length)?;
+    let checksum = *rest.get(length)?;
+
+    let expected = data[..length + 2]
+        .iter()
+        .fold(DISPLAY_ADDRESS, |checksum, byte| checksum ^ byte);
+
+    (checksum == expected).then_some(payload)
+}
+
+/// A reply carrying `payload`
+fn message(payload: &[u8]) -> Vec<u8> {
+    let mut message = Vec::with_capacity(payload.len() + 3);
+
+    message.push(DISPLAY_ADDRESS);
+    // payloads are at most 35 bytes
+    #[allow(clippy::cast_possible_truncation)]
+    message.push(LENGTH_FLAG | payload.len() as u8);
+    message.extend_from_slice(payload);
+
+    let checksum = message
+        .iter()
+        .fold(VIRTUAL_HOST_ADDRESS, |checksum, byte| checksum ^ byte);
+    message.push(checksum);
+
+    message
+}
+
+fn get_vcp(monitor_id: u32, code: u8) -> [u8; 8] {
+    let picture = picture::get(monitor_id);
+
+    let value = match code {
+        VCP_BRIGHTNESS => picture.brightness,
+        VCP_CONTRAST => picture.contrast,
+        // unsupported code
+        _ => return [GET_VCP_REPLY, 0x01, code, 0, 0, 0, 0, 0],
+    };
+
+    let [max_high, max_low] = VCP_MAX.to_be_bytes();
+    let [high, low] = value.to_be_bytes();
+
+    // no error, and a "set parameter" type control
+    [
+        GET_VCP_REPLY,
+        0x00,
+        code,
+        0x00,
+        max_high,
+        max_low,
+        high,
+        low,
+    ]
+}
+
+fn set_vcp(monitor_id: u32, code: u8, value: u16) {
+    let value = value.min(VCP_MAX);
+
+    match code {
+        VCP_BRIGHTNESS => picture::update(monitor_id, |picture| picture.brightness = value),
+        VCP_CONTRAST => picture::update(monitor_id, |picture| picture.contrast = value),
+        // unsupported codes are ignored, set requests have no reply to report them in
+        _ => (),
+    }
+}
+
+fn capabilities(offset: u16) -> Vec<u8> {
+    let start = usize::from(offset).min(CAPABILITIES_STRING.len());
+    let end = (start + CAPABILITIES_FRAGMENT).min(CAPABILITIES_STRING.len());
+
+    // an empty fragment tells the host it has the whole string
+    let [high, low] = offset.to_be_bytes();
+    let mut payload = vec![CAPABILITIES_REPLY, high, low];
+    payload.extend_from_slice(&CAPABILITIES_STRING[start..end]);
+
+    payload
+}
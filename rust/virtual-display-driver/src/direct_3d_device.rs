@@ -29,13 +29,17 @@ impl From<&'static str> for Direct3DError {
     }
 }
 
+/// The device swap chain buffers are acquired on
+///
+/// This is D3D11 on purpose: IddCx 1.4 only hands swap chain buffers to a DXGI device
+/// (`IDXGIDevice`), which D3D12 devices aren't, so frames can't be processed on D3D12.
 #[derive(Debug)]
 pub struct Direct3DDevice {
     // The following are already refcounted, so they're safe to use directly without additional drop impls
     _dxgi_factory: IDXGIFactory5,
     _adapter: IDXGIAdapter1,
     pub device: ID3D11Device,
-    _device_context: ID3D11DeviceContext,
+    pub device_context: ID3D11DeviceContext,
 }
 
 impl Direct3DDevice {
@@ -70,7 +74,7 @@ impl Direct3DDevice {
             _dxgi_factory: dxgi_factory,
             _adapter: adapter,
             device,
-            _device_context: device_context,
+            device_context,
         })
     }
 }
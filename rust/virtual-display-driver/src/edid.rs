@@ -1,23 +1,18 @@
-use std::{array::TryFromSliceError, ops::Deref};
+use std::{array::TryFromSliceError, mem::size_of, ops::Deref, sync::OnceLock};
 
 use bytemuck::{Pod, Zeroable};
-
-const _EDID: [u8; 128] = [
-    0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x0D, 0x19, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-    0xFF, 0x21, 0x01, 0x03, 0x80, 0x32, 0x1F, 0x78, 0x07, 0xEE, 0x95, 0xA3, 0x54, 0x4C, 0x99, 0x26,
-    0x0F, 0x50, 0x54, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
-    0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x02, 0x3A, 0x80, 0x18, 0x71, 0x38, 0x2D, 0x40, 0x58, 0x2C,
-    0x45, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1E, 0x00, 0x00, 0x00, 0xFD, 0x00, 0x17, 0xF0, 0x0F,
-    0xFF, 0x0F, 0x00, 0x0A, 0x20, 0x20, 0x20, 0x20, 0x20, 0x20, 0x00, 0x00, 0x00, 0xFC, 0x00, 0x56,
-    0x69, 0x72, 0x74, 0x75, 0x44, 0x69, 0x73, 0x70, 0x6C, 0x61, 0x79, 0x2B, 0x00, 0x00, 0x00, 0x00,
-    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-];
-
-const EDID_LEN: usize = _EDID.len();
-
-static EDID: AlignedEdid<EDID_LEN> = AlignedEdid {
-    data: _EDID,
-    _align: [],
+use driver_ipc::edid::{CtaExtension, EdidBuilder, BLOCK_LEN, HEADER};
+use log::warn;
+use windows::{
+    core::HSTRING,
+    Win32::{
+        Graphics::Gdi::{EnumDisplayDevicesW, DISPLAY_DEVICEW},
+        UI::WindowsAndMessaging::EDD_GET_DEVICE_INTERFACE_NAME,
+    },
+};
+use winreg::{
+    enums::{HKEY_LOCAL_MACHINE, KEY_READ, KEY_WOW64_64KEY},
+    RegKey,
 };
 
 #[repr(C)]
@@ -58,32 +53,174 @@ pub struct Edid {
     revision: u8,
 }
 
-impl Edid {
-    pub fn generate_with(serial: u32) -> Vec<u8> {
-        // change serial number in the header
-        let mut header = *EDID;
-        header.serial_number = serial;
+#[derive(Debug, thiserror::Error)]
+pub enum EdidError {
+    #[error("Display {0} not found")]
+    DisplayNotFound(String),
+    #[error("Unexpected monitor device id {0}")]
+    InvalidDeviceId(String),
+    #[error("Failed to read EDID from registry: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("EDID is malformed")]
+    Malformed,
+    #[error("Failed to build EDID: {0}")]
+    Build(#[from] driver_ipc::edid::EdidError),
+}
+
+/// The machine GUID, used to make EDID serial numbers unique per machine
+static MACHINE_GUID: OnceLock<String> = OnceLock::new();
 
-        header.generate()
+fn machine_guid() -> &'static str {
+    MACHINE_GUID.get_or_init(|| {
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let key = r"SOFTWARE\Microsoft\Cryptography";
+
+        let guid = hklm
+            .open_subkey_with_flags(key, KEY_READ | KEY_WOW64_64KEY)
+            .and_then(|key| key.get_value::<String, _>("MachineGuid"));
+
+        guid.unwrap_or_else(|e| {
+            warn!("Failed to read MachineGuid, EDID serials will not be unique per machine: {e}");
+            String::new()
+        })
+    })
+}
+
+impl Edid {
+    /// Generate a stable serial number for a monitor id.
+    ///
+    /// The serial is derived from the id and the machine GUID, so each monitor gets
+    /// a distinct EDID and Windows keeps its per-monitor settings apart. The same
+    /// id always maps to the same serial on a given machine.
+    pub fn serial_for(id: u32) -> u32 {
+        // FNV-1a, since it must be stable across builds and std's hasher is not
+        const FNV_OFFSET: u32 = 0x811C_9DC5;
+        const FNV_PRIME: u32 = 0x0100_0193;
+
+        let hash = machine_guid()
+            .bytes()
+            .chain(id.to_le_bytes())
+            .fold(FNV_OFFSET, |hash, byte| {
+                (hash ^ u32::from(byte)).wrapping_mul(FNV_PRIME)
+            });
+
+        // a serial of 0 means "no serial number" in EDID
+        hash.max(1)
     }
 
-    pub fn get_serial(edid: &[u8]) -> Result<u32, TryFromSliceError> {
-        let edid = AlignedEdid::<EDID_LEN>::new(edid)?;
-        Ok(edid.serial_number)
+    /// Generate an EDID with `serial`, named `name` instead of `VirtuDisplay+` if given, see
+    /// `Monitor::edid_name`
+    pub fn generate_with(
+        serial: u32,
+        audio: bool,
+        name: Option<&str>,
+    ) -> Result<Vec<u8>, EdidError> {
+        let mut builder = EdidBuilder::new().serial(serial);
+        if let Some(name) = name {
+            builder = builder.name(name);
+        }
+        if audio {
+            builder = builder.extension(CtaExtension::hdmi_audio());
+        }
+
+        Ok(builder.build()?)
     }
 
-    fn generate(&self) -> Vec<u8> {
-        let header = bytemuck::bytes_of(self);
+    /// Append a CEA-861 extension block marking the display as a specialized one, which windows
+    /// doesn't extend the desktop to. Works for custom EDIDs as well
+    pub fn add_specialized_extension(edid: &mut Vec<u8>) -> Result<(), EdidError> {
+        let block = CtaExtension::specialized().to_block()?;
+
+        // bump the extension block count of the base block, custom EDIDs can already have the
+        // most there can be
+        if edid.len() < BLOCK_LEN {
+            return Err(EdidError::Malformed);
+        }
+        edid[126] = edid[126].checked_add(1).ok_or(EdidError::Malformed)?;
+        Self::gen_checksum(edid);
+        edid.extend_from_slice(&block);
+
+        Ok(())
+    }
 
-        // slice of monitor edid minus header
-        let data = &EDID.data[EDID_SIZE..];
+    /// Copy an existing EDID, replacing its serial number
+    ///
+    /// Extension blocks are kept as is, only the base block is changed
+    pub fn with_serial(edid: &[u8], serial: u32) -> Result<Vec<u8>, EdidError> {
+        let is_valid =
+            edid.len() >= BLOCK_LEN && edid.len() % BLOCK_LEN == 0 && edid[..8] == HEADER;
+        if !is_valid {
+            return Err(EdidError::Malformed);
+        }
+
+        let mut header =
+            *AlignedEdid::<BLOCK_LEN>::new(&edid[..BLOCK_LEN]).map_err(|_| EdidError::Malformed)?;
+        header.serial_number = serial;
 
-        // splice together header and the rest of the EDID
-        let mut edid: Vec<u8> = header.iter().chain(data).copied().collect();
-        // regenerate checksum
+        let mut edid = edid.to_vec();
+        edid[..EDID_SIZE].copy_from_slice(bytemuck::bytes_of(&header));
         Self::gen_checksum(&mut edid);
 
-        edid
+        Ok(edid)
+    }
+
+    /// Read the EDID of a connected physical display, e.g. `DISPLAY2` or `\\.\DISPLAY2`
+    pub fn from_display(display: &str) -> Result<Vec<u8>, EdidError> {
+        let display = if display.starts_with(r"\\.\") {
+            display.to_owned()
+        } else {
+            format!(r"\\.\{display}")
+        };
+
+        let mut device = DISPLAY_DEVICEW {
+            #[allow(clippy::cast_possible_truncation)]
+            cb: size_of::<DISPLAY_DEVICEW>() as u32,
+            ..Default::default()
+        };
+
+        // the first device of a display adapter is the monitor attached to it
+        let found = unsafe {
+            EnumDisplayDevicesW(
+                &HSTRING::from(display.as_str()),
+                0,
+                &mut device,
+                EDD_GET_DEVICE_INTERFACE_NAME,
+            )
+        };
+        if !found.as_bool() {
+            return Err(EdidError::DisplayNotFound(display));
+        }
+
+        let len = device
+            .DeviceID
+            .iter()
+            .position(|&c| c == 0)
+            .unwrap_or(device.DeviceID.len());
+        let device_id = String::from_utf16_lossy(&device.DeviceID[..len]);
+
+        // device interface looks like `\\?\DISPLAY#GSM5B08#5&2a1b3c4&0&UID4353#{e6f07b5f-...}`
+        let mut parts = device_id.split('#').skip(1);
+        let (Some(model), Some(instance)) = (parts.next(), parts.next()) else {
+            return Err(EdidError::InvalidDeviceId(device_id));
+        };
+
+        let hklm = RegKey::predef(HKEY_LOCAL_MACHINE);
+        let key =
+            format!(r"SYSTEM\CurrentControlSet\Enum\DISPLAY\{model}\{instance}\Device Parameters");
+
+        let edid = hklm
+            .open_subkey_with_flags(key, KEY_READ)?
+            .get_raw_value("EDID")?
+            .bytes;
+
+        Ok(edid)
+    }
+
+    pub fn get_serial(edid: &[u8]) -> Result<u32, TryFromSliceError> {
+        // only the base block is needed, edids with extension blocks are longer
+        let base = edid.get(..BLOCK_LEN).unwrap_or(edid);
+        let edid = AlignedEdid::<BLOCK_LEN>::new(base)?;
+        Ok(edid.serial_number)
     }
 
     fn gen_checksum(data: &mut [u8]) {
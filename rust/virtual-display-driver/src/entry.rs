@@ -1,32 +1,23 @@
 use std::time::{Duration, Instant};
 
 use driver_logger::DriverLogger;
-use log::{error, info, Level};
+use log::{info, Level};
 use wdf_umdf::{
-    IddCxDeviceInitConfig, IddCxDeviceInitialize, WdfDeviceCreate,
-    WdfDeviceInitSetPnpPowerEventCallbacks, WdfDeviceSetFailed, WdfDriverCreate,
+    wdf_callback, WdfDeviceInitSetPnpPowerEventCallbacks, WdfDeviceSetFailed, WdfObjectContext,
 };
 use wdf_umdf_sys::{
-    IDD_CX_CLIENT_CONFIG, NTSTATUS, WDFDEVICE_INIT, WDFDRIVER__, WDFOBJECT,
-    WDF_DEVICE_FAILED_ACTION, WDF_DRIVER_CONFIG, WDF_OBJECT_ATTRIBUTES,
-    WDF_PNPPOWER_EVENT_CALLBACKS, _DRIVER_OBJECT, _UNICODE_STRING,
+    NTSTATUS, PDRIVER_OBJECT, WDFDEVICE, WDFDEVICE_INIT, WDFOBJECT, WDF_DEVICE_FAILED_ACTION,
+    WDF_OBJECT_ATTRIBUTES, WDF_PNPPOWER_EVENT_CALLBACKS,
 };
 
-use crate::callbacks::{
-    adapter_commit_modes, adapter_init_finished, assign_swap_chain, device_d0_entry,
-    monitor_get_default_modes, monitor_query_modes, parse_monitor_description, unassign_swap_chain,
-};
-use crate::{context::DeviceContext, helpers::Sendable};
-
-//
-// Our driver's entry point
-// See windows::Wdk::System::SystemServices::DRIVER_INITIALIZE
-//
-#[no_mangle]
-extern "C-unwind" fn DriverEntry(
-    driver_object: *mut _DRIVER_OBJECT,
-    registry_path: *mut _UNICODE_STRING,
-) -> NTSTATUS {
+use crate::callbacks::{device_d0_entry, device_d0_exit};
+use crate::{context::DeviceContext, helpers::Sendable, panic, trace, watchdog};
+
+// Amount of log records kept in memory for the `logs` ipc command
+const LOG_RING_CAPACITY: usize = 1024;
+
+/// Set up logging, the panic hook and tracing, first thing in `DriverEntry`
+pub fn driver_entry(driver_object: PDRIVER_OBJECT) -> NTSTATUS {
     // During system bootup, `RegisterEventSourceW` fails and causes the driver to not bootup
     // Pretty unfortunate, therefore, we will run this on a thread until it succeeds and let the rest of
     // the driver start. I know this is suboptimal considering it's our main code to catch panics.
@@ -40,6 +31,9 @@ extern "C-unwind" fn DriverEntry(
             Level::Info
         });
 
+        // keep the latest records in memory, so clients can fetch them over ipc
+        logger.ring(LOG_RING_CAPACITY);
+
         if cfg!(debug_assertions) {
             logger.debug();
         } else if logger.name("VirtualDisplayDriver").is_err() {
@@ -107,81 +101,57 @@ extern "C-unwind" fn DriverEntry(
     }
 
     // set the panic hook to capture and log panics
-    crate::panic::set_hook();
+    panic::set_hook();
 
-    let mut attributes = WDF_OBJECT_ATTRIBUTES::init();
+    // etw events for analyzing stalls, unregistered again in `driver_unload`
+    trace::register();
 
-    let mut config = WDF_DRIVER_CONFIG::init(Some(driver_add));
+    NTSTATUS::STATUS_SUCCESS
+}
 
-    unsafe {
-        WdfDriverCreate(
-            driver_object,
-            registry_path,
-            Some(&mut attributes),
-            &mut config,
-            None,
-        )
-    }
-    .into()
+pub fn driver_unload() {
+    trace::unregister();
 }
 
-extern "C-unwind" fn driver_add(
-    _driver: *mut WDFDRIVER__,
-    mut init: *mut WDFDEVICE_INIT,
-) -> NTSTATUS {
+/// Register the pnp and power callbacks, before the device is created
+pub fn device_init(init: &mut WDFDEVICE_INIT) -> NTSTATUS {
     let mut callbacks = WDF_PNPPOWER_EVENT_CALLBACKS::init();
 
     callbacks.EvtDeviceD0Entry = Some(device_d0_entry);
+    callbacks.EvtDeviceD0Exit = Some(device_d0_exit);
 
     unsafe {
         _ = WdfDeviceInitSetPnpPowerEventCallbacks(init, &mut callbacks);
     }
 
-    let Some(mut config) = IDD_CX_CLIENT_CONFIG::init() else {
-        error!("Failed to create IDD_CX_CLIENT_CONFIG");
-        return NTSTATUS::STATUS_NOT_FOUND;
-    };
-
-    config.EvtIddCxAdapterInitFinished = Some(adapter_init_finished);
-
-    config.EvtIddCxParseMonitorDescription = Some(parse_monitor_description);
-    config.EvtIddCxMonitorGetDefaultDescriptionModes = Some(monitor_get_default_modes);
-    config.EvtIddCxMonitorQueryTargetModes = Some(monitor_query_modes);
-    config.EvtIddCxAdapterCommitModes = Some(adapter_commit_modes);
-    config.EvtIddCxMonitorAssignSwapChain = Some(assign_swap_chain);
-    config.EvtIddCxMonitorUnassignSwapChain = Some(unassign_swap_chain);
-
-    let init_data = unsafe { &mut *init };
-    let status = unsafe { IddCxDeviceInitConfig(init_data, &config) };
-    if let Err(e) = status {
-        error!("Failed to init iddcx config: {e:?}");
-        return e.into();
-    }
+    NTSTATUS::STATUS_SUCCESS
+}
 
-    let mut attributes =
-        WDF_OBJECT_ATTRIBUTES::init_context_type(unsafe { DeviceContext::get_type_info() });
+pub fn device_attributes() -> WDF_OBJECT_ATTRIBUTES {
+    let mut attributes = WdfObjectContext::<DeviceContext>::attributes();
 
+    // also stops reporting panics to the device, see `event_cleanup`
     attributes.EvtCleanupCallback = Some(event_cleanup);
 
-    let mut device = std::ptr::null_mut();
-
-    let status = unsafe { WdfDeviceCreate(&mut init, Some(&mut attributes), &mut device) };
-    if let Err(e) = status {
-        error!("Failed to create device: {e:?}");
-        return e.into();
-    }
+    attributes
+}
 
-    let status = unsafe { IddCxDeviceInitialize(device) };
-    if let Err(e) = status {
-        error!("Failed to init iddcx device: {e:?}");
-        return e.into();
-    }
+pub fn device_added(device: WDFDEVICE) -> NTSTATUS {
+    // from now on, a panic in a callback fails the device instead of just the callback
+    panic::set_device(device);
 
     let context = DeviceContext::new(device);
 
-    unsafe { context.init(device as WDFOBJECT).into() }
+    let status: NTSTATUS = unsafe { WdfObjectContext::init(device as WDFOBJECT, context).into() };
+    if status == NTSTATUS::STATUS_SUCCESS {
+        watchdog::start(device);
+    }
+
+    status
 }
 
-unsafe extern "C-unwind" fn event_cleanup(wdf_object: WDFOBJECT) {
-    _ = unsafe { DeviceContext::drop(wdf_object) };
+#[wdf_callback(guard = panic::catch)]
+fn event_cleanup(wdf_object: WDFOBJECT) {
+    panic::clear_device();
+    _ = unsafe { WdfObjectContext::<DeviceContext>::drop(wdf_object) };
 }
@@ -0,0 +1,129 @@
+use std::{
+    collections::VecDeque,
+    sync::OnceLock,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use driver_ipc::{Event, EventKind};
+
+use crate::sync::Mutex;
+
+// Amount of events kept in memory for the `events` ipc command
+const CAPACITY: usize = 256;
+
+static EVENTS: OnceLock<EventLog> = OnceLock::new();
+
+fn events() -> &'static EventLog {
+    EVENTS.get_or_init(EventLog::new)
+}
+
+/// Record an event, so clients polling for events see it
+pub fn push(kind: EventKind) {
+    events().push(kind);
+}
+
+/// Get up to `limit` events with a sequence number greater than `since`
+pub fn since(since: u64, limit: usize) -> Vec<Event> {
+    events().since(since, limit)
+}
+
+/// The last [`CAPACITY`] events, pushed by any thread and polled by ipc clients
+struct EventLog(Mutex<Events>);
+
+struct Events {
+    next_seq: u64,
+    events: VecDeque<Event>,
+}
+
+impl EventLog {
+    fn new() -> Self {
+        Self(Mutex::new(Events {
+            next_seq: 1,
+            events: VecDeque::new(),
+        }))
+    }
+
+    fn push(&self, kind: EventKind) {
+        let Ok(mut events) = self.0.lock() else {
+            return;
+        };
+
+        if events.events.len() == CAPACITY {
+            events.events.pop_front();
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_millis() as u64);
+
+        let seq = events.next_seq;
+        events.next_seq += 1;
+
+        events.events.push_back(Event {
+            seq,
+            timestamp,
+            kind,
+        });
+    }
+
+    fn since(&self, since: u64, limit: usize) -> Vec<Event> {
+        let Ok(events) = self.0.lock() else {
+            return Vec::new();
+        };
+
+        events
+            .events
+            .iter()
+            .skip_while(|event| event.seq <= since)
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(all(test, loom))]
+mod tests {
+    use driver_ipc::EventKind;
+    use loom::{sync::Arc, thread};
+
+    use super::*;
+
+    fn stalled(id: u32) -> EventKind {
+        EventKind::SwapChainStalled { id, stalled_ms: 0 }
+    }
+
+    // swap chain threads push while a client polls, which must see every event exactly once
+    #[test]
+    fn poll_while_pushing() {
+        loom::model(|| {
+            let log = Arc::new(EventLog::new());
+
+            let pushers = (0..2)
+                .map(|id| {
+                    let log = log.clone();
+                    thread::spawn(move || log.push(stalled(id)))
+                })
+                .collect::<Vec<_>>();
+
+            // like a client polls, from the last event it saw
+            let mut seen = log.since(0, CAPACITY);
+            let last = seen.last().map_or(0, |event| event.seq);
+            seen.extend(log.since(last, CAPACITY));
+
+            for pusher in pushers {
+                pusher.join().unwrap();
+            }
+
+            let last = seen.last().map_or(0, |event| event.seq);
+            seen.extend(log.since(last, CAPACITY));
+
+            let seqs = seen.iter().map(|event| event.seq).collect::<Vec<_>>();
+            assert_eq!(seqs, [1, 2]);
+
+            for id in 0..2 {
+                assert!(seen.iter().any(|event| event.kind == stalled(id)));
+            }
+        });
+    }
+}
@@ -0,0 +1,148 @@
+use driver_ipc::{Capability, DegradedFeature, DriverInfo, MonitorCapabilities};
+use wdf_umdf_sys::{IddCxIsFunctionAvailable, IDDCX_VERSION};
+use windows::Win32::Graphics::Direct3D11::D3D11_REQ_TEXTURE2D_U_OR_V_DIMENSION;
+
+use crate::gpu_priority;
+
+// Features that need newer IddCx headers than the baseline, only reported if the driver is built
+// against older ones, or windows doesn't have them
+const NEEDS_NEWER_IDDCX: &[(&str, &str)] = &[("display config updates", "1.10")];
+
+// Optional features the driver doesn't implement, so they're reported however it's built. The
+// functions some of them need are wrapped with the `iddcx-1_10` feature, but nothing calls them
+const NOT_IMPLEMENTED: &[&str] = &["in-place updates of HDR modes", "hardware cursor v2", "HDR"];
+
+pub fn driver_info() -> DriverInfo {
+    DriverInfo {
+        version: env!("CARGO_PKG_VERSION").to_owned(),
+        iddcx_version: IDDCX_VERSION.to_owned(),
+        degraded: degraded(),
+        realtime_gpu_priority: gpu_priority::enabled(),
+        capabilities: capabilities(),
+    }
+}
+
+/// What the driver supports for a monitor, reported to clients with its state
+pub fn monitor_capabilities() -> MonitorCapabilities {
+    MonitorCapabilities {
+        // neither hdr modes nor color space transforms are implemented
+        hdr_supported: false,
+        vrr_supported: false,
+        cursor_supported: IddCxIsFunctionAvailable!(IddCxMonitorSetupHardwareCursor),
+        // frames are processed as d3d11 textures
+        max_resolution: (
+            D3D11_REQ_TEXTURE2D_U_OR_V_DIMENSION,
+            D3D11_REQ_TEXTURE2D_U_OR_V_DIMENSION,
+        ),
+    }
+}
+
+/// A capability for `$function`, which IddCx `$version` introduced
+///
+/// Functions newer than the 1.4 baseline are only in the bindings with the `iddcx-*` feature of
+/// their version, without it they're reported as not built.
+macro_rules! capability {
+    ($function:ident, $version:literal) => {
+        Capability {
+            function: stringify!($function).to_owned(),
+            iddcx_version: $version.to_owned(),
+            built: true,
+            available: IddCxIsFunctionAvailable!($function),
+        }
+    };
+
+    ($function:ident, $version:literal, $feature:literal) => {{
+        #[cfg(feature = $feature)]
+        let capability = capability!($function, $version);
+
+        #[cfg(not(feature = $feature))]
+        let capability = Capability {
+            function: stringify!($function).to_owned(),
+            iddcx_version: $version.to_owned(),
+            built: false,
+            available: false,
+        };
+
+        capability
+    }};
+}
+
+/// Optional IddCx functions, and whether the driver can use them
+fn capabilities() -> Vec<Capability> {
+    vec![
+        capability!(IddCxMonitorUpdateModes, "1.4"),
+        capability!(IddCxMonitorSetupHardwareCursor, "1.4"),
+        capability!(IddCxSetRealtimeGPUPriority, "1.4"),
+        capability!(IddCxMonitorUpdateModes2, "1.10", "iddcx-1_10"),
+        capability!(IddCxMonitorQueryHardwareCursor3, "1.10", "iddcx-1_10"),
+        capability!(IddCxSwapChainReleaseAndAcquireBuffer2, "1.10", "iddcx-1_10"),
+        capability!(IddCxAdapterDisplayConfigUpdate, "1.10", "iddcx-1_10"),
+    ]
+}
+
+/// Optional features that aren't active, and why
+///
+/// Without these the driver falls back to something that works everywhere, e.g. re-plugging a
+/// monitor instead of updating its modes in place, which is easy to miss without this report
+fn degraded() -> Vec<DegradedFeature> {
+    let mut degraded = Vec::new();
+
+    let mut check = |feature: &str, available: bool| {
+        if !available {
+            degraded.push(DegradedFeature {
+                feature: feature.to_owned(),
+                reason: "not supported by this version of Windows".to_owned(),
+            });
+        }
+    };
+
+    check(
+        "in-place mode updates",
+        IddCxIsFunctionAvailable!(IddCxMonitorUpdateModes),
+    );
+    check(
+        "hardware cursor",
+        IddCxIsFunctionAvailable!(IddCxMonitorSetupHardwareCursor),
+    );
+    check(
+        "realtime gpu priority",
+        IddCxIsFunctionAvailable!(IddCxSetRealtimeGPUPriority),
+    );
+    #[cfg(feature = "iddcx-1_10")]
+    check(
+        "display config updates",
+        IddCxIsFunctionAvailable!(IddCxAdapterDisplayConfigUpdate),
+    );
+
+    degraded.extend(
+        NEEDS_NEWER_IDDCX
+            .iter()
+            .filter(|&&(_, version)| is_older(IDDCX_VERSION, version))
+            .map(|&(feature, version)| DegradedFeature {
+                feature: feature.to_owned(),
+                reason: format!(
+                    "needs IddCx {version}, the driver is built against IddCx {IDDCX_VERSION}"
+                ),
+            }),
+    );
+
+    degraded.extend(NOT_IMPLEMENTED.iter().map(|&feature| DegradedFeature {
+        feature: feature.to_owned(),
+        reason: "not implemented by the driver".to_owned(),
+    }));
+
+    degraded
+}
+
+/// Whether a dotted version is older than another, comparing each part as a number so 1.10 is
+/// newer than 1.4
+fn is_older(version: &str, than: &str) -> bool {
+    let parts = |version: &str| {
+        version
+            .split('.')
+            .map(|part| part.parse::<u32>().unwrap_or_default())
+            .collect::<Vec<_>>()
+    };
+
+    parts(version) < parts(than)
+}
@@ -0,0 +1,58 @@
+//! Realtime gpu priority of the adapter's frame processing, opt-in
+//!
+//! With it, the os schedules the gpu work of processing frames ahead of everything else on the
+//! render adapter, which keeps latency low for streaming while games load the gpu, at their
+//! expense. It's a setting of the adapter, persisted in the registry key of its device, and can be
+//! changed over ipc.
+//!
+//! IddCx can't take the priority back from a device, so turning it off only applies to devices
+//! created afterwards, e.g. after a replug.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use log::warn;
+use wdf_umdf::WdfRegistryKey;
+use wdf_umdf_sys::{KEY_QUERY_VALUE, KEY_SET_VALUE, WDFDEVICE};
+
+const VALUE: &str = "RealtimeGpuPriority";
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Load the setting of the adapter of `device`
+///
+/// # Safety
+///
+/// `device` must be a valid device
+pub unsafe fn load(device: WDFDEVICE) {
+    // SAFETY: Upheld by the caller, and the key is closed right away
+    let key = unsafe { WdfRegistryKey::open_device(device, KEY_QUERY_VALUE) };
+
+    let enabled = key
+        .and_then(|key| key.get::<bool>(VALUE))
+        .map_err(|e| warn!("Failed to load realtime gpu priority: {e}"))
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Change the setting, and persist it for the next time the adapter of `device` starts
+///
+/// # Safety
+///
+/// `device` must be a valid device
+pub unsafe fn set(device: WDFDEVICE, enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+
+    // SAFETY: Upheld by the caller, and the key is closed right away
+    let key = unsafe { WdfRegistryKey::open_device(device, KEY_SET_VALUE) };
+
+    if let Err(e) = key.and_then(|key| key.set(VALUE, &enabled)) {
+        warn!("Failed to persist realtime gpu priority: {e}");
+    }
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
@@ -0,0 +1,147 @@
+//! Device control (IOCTL) alternative to the pipe
+//!
+//! Some security products block drivers from creating named pipes, and device interfaces can be
+//! locked down with standard ACL tooling. Clients open the adapter's device interface and send
+//! the same json [`Command`]s as over the pipe with [`IOCTL_COMMAND`], the reply (if any) is
+//! written to the output buffer, or kept to fetch with [`IOCTL_REPLY`] if it doesn't fit.
+
+use std::{cell::RefCell, collections::VecDeque, sync::Mutex};
+
+use driver_ipc::{Command, IOCTL_COMMAND, IOCTL_REPLY, PENDING_REPLY_LEN};
+use log::warn;
+use wdf_umdf::WdfRequest;
+use wdf_umdf_sys::NTSTATUS;
+
+use crate::ipc::{self, MONITOR_MODES};
+
+// Replies which didn't fit the output buffer of their command are kept for the client to fetch,
+// only the latest of them in case clients never do
+const MAX_PENDING_REPLIES: usize = 8;
+
+// keyed by random ids, so clients can't guess and take each other's replies
+static PENDING: Mutex<VecDeque<(u64, Vec<u8>)>> = Mutex::new(VecDeque::new());
+
+thread_local! {
+    // replies are serialized into this, so it's reused by every request the queue thread handles
+    static REPLY: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Handle a device control request, IddCx hands them over from its queue
+pub fn handle(request: WdfRequest) {
+    match request.code() {
+        IOCTL_COMMAND => command(request),
+        IOCTL_REPLY => pending_reply(request),
+        _ => request.complete(NTSTATUS::STATUS_INVALID_DEVICE_REQUEST),
+    }
+}
+
+fn command(mut request: WdfRequest) {
+    // the monitor state only exists once the adapter is initialized
+    if MONITOR_MODES.get().is_none() {
+        request.complete(NTSTATUS::STATUS_DEVICE_NOT_READY);
+        return;
+    }
+
+    let command = match request.input(1) {
+        Ok(input) => serde_json::from_slice::<Command>(input),
+        Err(e) => {
+            warn!("Failed to get ioctl input: {e}");
+            request.complete(NTSTATUS::STATUS_INVALID_PARAMETER);
+            return;
+        }
+    };
+
+    let Ok(command) = command else {
+        request.complete(NTSTATUS::STATUS_INVALID_PARAMETER);
+        return;
+    };
+
+    // buffered io shares the input and output buffer, the input is parsed by now. There has to be
+    // room to tell the client about a reply which doesn't fit, before the command runs
+    if request.output(PENDING_REPLY_LEN).is_err() {
+        request.complete(NTSTATUS::STATUS_BUFFER_TOO_SMALL);
+        return;
+    }
+
+    REPLY.with_borrow_mut(|buffer| run(request, command, buffer));
+}
+
+/// Run `command` and complete `request` with its reply, serialized into `buffer`
+fn run(mut request: WdfRequest, command: Command, buffer: &mut Vec<u8>) {
+    if !ipc::handle(command, buffer) {
+        request.complete(NTSTATUS::STATUS_SUCCESS);
+        return;
+    }
+
+    match request.output(buffer.len()) {
+        Ok(output) => {
+            output[..buffer.len()].copy_from_slice(buffer);
+            request.complete_with_output(NTSTATUS::STATUS_SUCCESS, buffer.len());
+        }
+
+        // keep the reply instead of having the client send the command again
+        Err(_) => {
+            let mut id = [0; 8];
+            if getrandom::getrandom(&mut id).is_err() {
+                request.complete(NTSTATUS::STATUS_INTERNAL_ERROR);
+                return;
+            }
+            let id = u64::from_le_bytes(id);
+            let len = buffer.len() as u64;
+
+            if let Ok(mut pending) = PENDING.lock() {
+                if pending.len() == MAX_PENDING_REPLIES {
+                    pending.pop_front();
+                }
+                // copied, so the buffer keeps its capacity for the next request. Replies that
+                // don't fit the 64 KiB `IoctlClient` offers are rare
+                pending.push_back((id, buffer.clone()));
+            }
+
+            let Ok(output) = request.output(PENDING_REPLY_LEN) else {
+                request.complete(NTSTATUS::STATUS_BUFFER_TOO_SMALL);
+                return;
+            };
+
+            output[..8].copy_from_slice(&id.to_le_bytes());
+            output[8..PENDING_REPLY_LEN].copy_from_slice(&len.to_le_bytes());
+            // a warning status, so the output still reaches the client
+            request.complete_with_output(NTSTATUS::STATUS_BUFFER_OVERFLOW, PENDING_REPLY_LEN);
+        }
+    }
+}
+
+/// Hand out a reply which didn't fit the output buffer of its command
+fn pending_reply(mut request: WdfRequest) {
+    let id = match request.input(8) {
+        Ok(input) => u64::from_le_bytes(input[..8].try_into().unwrap_or_default()),
+        Err(_) => {
+            request.complete(NTSTATUS::STATUS_INVALID_PARAMETER);
+            return;
+        }
+    };
+
+    let Ok(mut pending) = PENDING.lock() else {
+        request.complete(NTSTATUS::STATUS_INTERNAL_ERROR);
+        return;
+    };
+
+    let Some(index) = pending.iter().position(|&(pending_id, _)| pending_id == id) else {
+        request.complete(NTSTATUS::STATUS_NOT_FOUND);
+        return;
+    };
+
+    let reply = &pending[index].1;
+    let Ok(output) = request.output(reply.len()) else {
+        // kept, so the client can fetch it again with a large enough buffer
+        request.complete(NTSTATUS::STATUS_BUFFER_TOO_SMALL);
+        return;
+    };
+
+    let len = reply.len();
+    output[..len].copy_from_slice(reply);
+    pending.remove(index);
+    drop(pending);
+
+    request.complete_with_output(NTSTATUS::STATUS_SUCCESS, len);
+}
@@ -1,15 +1,22 @@
 use std::{
+    collections::HashMap,
     io::Write,
-    mem::size_of,
+    mem::{self, size_of},
     ptr::{addr_of_mut, NonNull},
-    sync::{Mutex, OnceLock},
+    sync::{Arc, OnceLock},
     thread,
+    time::{Duration, Instant, UNIX_EPOCH},
 };
 
-use driver_ipc::{Command, Dimen, Mode, Monitor, RefreshRate};
-use log::{error, warn};
-use wdf_umdf::IddCxMonitorDeparture;
-use wdf_umdf_sys::{IDDCX_ADAPTER__, IDDCX_MONITOR__};
+use driver_ipc::{
+    ActiveMode, Backpressure, Command, Dimen, EventKind, FrameFormat, LogLevel, LogRecord, Lut,
+    Mode, ModeChange, Monitor, MonitorDiff, MonitorOperation, MonitorRef, RefreshRate, SharedFrame,
+    SyncOptions, TestPattern, MODE_CHANGE_INTERVAL_MS,
+};
+use log::{error, warn, LevelFilter};
+use serde::{Serialize, Serializer};
+use wdf_umdf::{IddCxMonitorUpdateModes, WdfObjectContext};
+use wdf_umdf_sys::{IDARG_IN_UPDATEMODES, IDDCX_ADAPTER__, IDDCX_MONITOR__, IDDCX_UPDATE_REASON};
 use win_pipes::NamedPipeServerOptions;
 use windows::Win32::{
     Security::{
@@ -23,10 +30,38 @@ use winreg::{
     RegKey,
 };
 
-use crate::context::DeviceContext;
+use crate::{
+    callbacks::target_mode,
+    context::DeviceContext,
+    cursor,
+    edid::Edid,
+    events, features, picture, ring,
+    shared_frame::{self, ConsumerSlot},
+    state::{self, Monitors},
+    stats::{self, FrameStats},
+    swap_chain_processor::FrameLimit,
+    sync::Mutex,
+    test_pattern::PatternSlot,
+    trace,
+};
+
+// Size of the pipe buffers, also used as the initial capacity of the reply buffer
+const PIPE_BUFFER_SIZE: u32 = 4096;
+
+// Maximum amount of driver instances (adapters) that can run side by side
+const MAX_INSTANCES: u32 = 16;
+
+// Maximum amount of log records sent in a single reply
+const MAX_LOG_RECORDS: usize = 256;
+
+// Maximum amount of events sent in a single reply
+const MAX_EVENTS: usize = 256;
 
 pub static ADAPTER: OnceLock<AdapterObject> = OnceLock::new();
-pub static MONITOR_MODES: OnceLock<Mutex<Vec<MonitorObject>>> = OnceLock::new();
+pub static MONITOR_MODES: OnceLock<Monitors> = OnceLock::new();
+
+// When each monitor's mode was last changed with `RequestModeChange`
+static MODE_CHANGES: OnceLock<Mutex<HashMap<u32, Instant>>> = OnceLock::new();
 
 #[derive(Debug)]
 pub struct AdapterObject(pub NonNull<IDDCX_ADAPTER__>);
@@ -37,12 +72,35 @@ unsafe impl Send for AdapterObject {}
 pub struct MonitorObject {
     pub monitor_object: Option<NonNull<IDDCX_MONITOR__>>,
     pub monitor: Monitor,
+    pub stats: Arc<FrameStats>,
+    pub limit: Arc<FrameLimit>,
+    pub pattern: Arc<PatternSlot>,
+    pub consumer: Arc<ConsumerSlot>,
+    /// Modes the monitor was last arrived with, the os only knows about these
+    pub arrived_modes: Vec<Mode>,
+    /// The edid the os was last given for the monitor, see `RequestEdid`
+    pub edid: Option<Vec<u8>>,
+    /// Whether the monitor should be visible to the os, see `Monitor::auto_plug`. Stays set
+    /// while the device is suspended, when `monitor_object` is gone
+    pub plugged: bool,
 }
 unsafe impl Sync for MonitorObject {}
 unsafe impl Send for MonitorObject {}
 
-pub fn startup() {
-    MONITOR_MODES.set(Mutex::new(Vec::new())).unwrap();
+#[derive(Debug, thiserror::Error)]
+pub enum StartupError {
+    #[error("Another adapter was already started in this host process")]
+    AlreadyStarted,
+}
+
+/// Start listening for clients, once the adapter is initialized
+///
+/// The monitor list is per process, which works since every adapter gets its own host process,
+/// see `UmdfHostProcessSharing` in the inf. Fails if an adapter was already started in this one.
+pub fn startup() -> Result<(), StartupError> {
+    MONITOR_MODES
+        .set(Mutex::new(Vec::new()))
+        .map_err(|_| StartupError::AlreadyStarted)?;
 
     thread::spawn(move || {
         let monitors = get_data();
@@ -81,19 +139,44 @@ pub fn startup() {
             bInheritHandle: false.into(),
         };
 
-        let server = NamedPipeServerOptions::new("virtualdisplaydriver")
-            .reject_remote()
-            .read_message()
-            .write_message()
-            .access_duplex()
-            .first_pipe_instance()
-            .max_instances(1)
-            .in_buffer_size(4096)
-            .out_buffer_size(4096)
-            .security_attributes(&sa)
-            .wait()
-            .create()
-            .unwrap();
+        // every adapter gets its own pipe, the first free one is used. `first_pipe_instance`
+        // makes creation fail if another instance already owns the name
+        let server = (0..MAX_INSTANCES).find_map(|index| {
+            let pipe_name = driver_ipc::pipe_name(index);
+            NamedPipeServerOptions::new(&pipe_name)
+                .reject_remote()
+                .read_message()
+                .write_message()
+                .access_duplex()
+                .first_pipe_instance()
+                .max_instances(1)
+                .in_buffer_size(PIPE_BUFFER_SIZE)
+                .out_buffer_size(PIPE_BUFFER_SIZE)
+                .security_attributes(&sa)
+                .wait()
+                .create()
+                .ok()
+                .map(|server| (server, pipe_name))
+        });
+        let Some((server, pipe_name)) = server else {
+            error!("No free pipe name for this driver instance, all {MAX_INSTANCES} are in use");
+            return;
+        };
+
+        // let clients find this instance's pipe through the device interface
+        let adapter = ADAPTER.get().unwrap().0.as_ptr();
+        let res = unsafe {
+            WdfObjectContext::<DeviceContext>::get(adapter.cast(), |context| {
+                context.load_settings();
+
+                if let Err(e) = context.register_interface(&pipe_name) {
+                    error!("Failed to register device interface: {e:?}");
+                }
+            })
+        };
+        if let Err(e) = res {
+            error!("Failed to get device context: {e:?}");
+        }
 
         for client in server.incoming() {
             let Ok((reader, mut writer)) = client else {
@@ -101,57 +184,335 @@ pub fn startup() {
                 continue;
             };
 
+            // replies are serialized into this buffer, so it's reused for the whole connection.
+            // Requests still allocate: the reader hands out every message in a buffer of its own,
+            // and commands own what they carry, e.g. the monitors of `DriverNotify`
+            let mut buffer = Vec::with_capacity(PIPE_BUFFER_SIZE as usize);
+
             for data in reader.iter_read_full() {
-                let Ok(msg) = std::str::from_utf8(&data) else {
+                let Ok(msg) = serde_json::from_slice::<Command>(&data) else {
                     _ = server.disconnect();
                     continue;
                 };
 
-                let Ok(msg) = serde_json::from_str::<Command>(msg) else {
-                    _ = server.disconnect();
-                    continue;
-                };
+                if handle(msg, &mut buffer) {
+                    _ = writer.write_all(&buffer);
+                }
+            }
+        }
+    });
 
-                #[allow(clippy::match_wildcard_for_single_variants)]
-                match msg {
-                    Command::DriverNotify(monitors) => notify(monitors),
+    Ok(())
+}
 
-                    Command::DriverRemove(ids) => remove(&ids),
+/// Run a command from a client, serializing its reply into `buffer` if it has one
+///
+/// Returns whether there is a reply. Shared by the pipe and device control requests, see `ioctl`
+pub fn handle(command: Command, buffer: &mut Vec<u8>) -> bool {
+    #[allow(clippy::match_wildcard_for_single_variants)]
+    match command {
+        Command::DriverNotify(monitors) => _ = notify(monitors),
 
-                    Command::DriverRemoveAll => remove_all(),
+        Command::DriverRemove(monitors) => remove(&monitors),
 
-                    Command::RequestState => {
-                        let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
-                        let monitors = lock.iter().map(|m| m.monitor.clone()).collect::<Vec<_>>();
-                        let command = Command::ReplyState(monitors);
+        Command::DriverRemoveAll => remove_all(),
 
-                        let Ok(serialized) = serde_json::to_string(&command) else {
-                            continue;
-                        };
+        Command::DriverSetLogLevel(level) => set_log_level(level),
 
-                        _ = writer.write_all(serialized.as_bytes());
-                    }
+        Command::DriverSetTestPattern(monitor, pattern) => {
+            if let Some(id) = resolve(&monitor) {
+                set_test_pattern(id, pattern);
+            }
+        }
 
-                    // Everything else is an invalid command
-                    _ => continue,
-                }
+        Command::DriverSetLut(monitor, lut) => {
+            if let Some(id) = resolve(&monitor) {
+                set_lut(id, lut);
             }
         }
-    });
+
+        Command::DriverSetCursorComposite(monitor, composite) => {
+            if let Some(id) = resolve(&monitor) {
+                set_cursor_composite(id, composite);
+            }
+        }
+
+        Command::DriverSetBackpressure(monitor, backpressure) => {
+            if let Some(id) = resolve(&monitor) {
+                set_backpressure(id, backpressure);
+            }
+        }
+
+        Command::DriverReplug(monitor) => {
+            if let Some(id) = resolve(&monitor) {
+                replug(id);
+            }
+        }
+
+        Command::DriverPlug(monitor) => {
+            if let Some(id) = resolve(&monitor) {
+                plug(id);
+            }
+        }
+
+        Command::DriverSetRealtimeGpuPriority(enabled) => {
+            set_realtime_gpu_priority(enabled);
+        }
+
+        Command::RequestState => {
+            let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+            // serialize straight from the monitor state, no need to clone it
+            let command = ReplyRef::ReplyState(MonitorsRef(&lock));
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestMonitor(monitor) => {
+            let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+            let monitor = lock.iter().find(|mon| monitor.matches(&mon.monitor));
+            let command = ReplyRef::ReplyMonitor(monitor.map(|mon| &mon.monitor));
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestEdid(monitor) => {
+            let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+            let edid = lock
+                .iter()
+                .find(|mon| monitor.matches(&mon.monitor) && mon.monitor_object.is_some())
+                .and_then(|mon| mon.edid.clone());
+            let command = Command::ReplyEdid(edid);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestDisplayEdid(display) => {
+            let edid = Edid::from_display(&display)
+                .map_err(|e| warn!("Failed to read edid of {display}: {e}"))
+                .ok();
+            let command = Command::ReplyDisplayEdid(edid);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestLogs(since) => {
+            let records = driver_logger::records_since(since, MAX_LOG_RECORDS)
+                .into_iter()
+                .map(log_record)
+                .collect();
+            let command = Command::ReplyLogs(records);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestStats(monitor) => {
+            let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+            let stats = lock
+                .iter()
+                .filter(|mon| {
+                    monitor
+                        .as_ref()
+                        .map_or(true, |monitor| monitor.matches(&mon.monitor))
+                })
+                .map(|mon| mon.stats.snapshot(mon.monitor.id))
+                .collect();
+            let command = Command::ReplyStats(stats);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestCallbackStats => {
+            let command = Command::ReplyCallbackStats(stats::callback_snapshot());
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestEvents(since) => {
+            let command = Command::ReplyEvents(events::since(since, MAX_EVENTS));
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestNotify(monitors) => {
+            let command = Command::ReplyNotify(notify(monitors));
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestPicture(monitor) => {
+            let picture = resolve(&monitor).map_or(picture::DEFAULT, picture::get);
+            let command = Command::ReplyPicture(picture);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestSharedFrame(monitor) => {
+            let frame = resolve(&monitor).and_then(current_shared_frame);
+            let command = Command::ReplySharedFrame(frame);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestSharedFrameAs(monitor, format) => {
+            let frame = resolve(&monitor).and_then(|id| {
+                set_frame_format(id, format);
+                current_shared_frame(id)
+            });
+            let command = Command::ReplySharedFrame(frame);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestSharedFrameSync(monitor, options) => {
+            let frame = resolve(&monitor).and_then(|id| {
+                set_frame_sync(id, options);
+                current_shared_frame(id)
+            });
+            let command = Command::ReplySharedFrame(frame);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestSharedRing(monitor) => {
+            let ring = resolve(&monitor).and_then(|id| {
+                request_ring(id);
+                ring::get(id)
+            });
+            let command = Command::ReplySharedRing(ring);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestCursor(monitor, shape_id) => {
+            let cursor = resolve(&monitor).and_then(|id| cursor::get(id, shape_id));
+            let command = Command::ReplyCursor(cursor);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestModeChange(monitor, mode) => {
+            let change =
+                resolve(&monitor).map_or(ModeChange::Unavailable, |id| change_mode(id, mode));
+            let command = Command::ReplyModeChange(change);
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestDriverInfo => {
+            let command = Command::ReplyDriverInfo(features::driver_info());
+
+            return reply(buffer, &command);
+        }
+
+        Command::RequestEcho(payload) => {
+            let command = Command::ReplyEcho(payload);
+
+            return reply(buffer, &command);
+        }
+
+        // Everything else is an invalid command
+        _ => (),
+    }
+
+    false
+}
+
+/// Borrowed counterpart of the reply variants of [`Command`]
+///
+/// Serializes exactly like [`Command`], but without requiring an owned copy of the data
+#[derive(Serialize)]
+enum ReplyRef<'a> {
+    ReplyState(MonitorsRef<'a>),
+    ReplyMonitor(Option<&'a Monitor>),
+}
+
+/// Serializes the monitors of a [`MonitorObject`] slice as a list of [`Monitor`]
+struct MonitorsRef<'a>(&'a [MonitorObject]);
+
+impl Serialize for MonitorsRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|m| &m.monitor))
+    }
+}
+
+/// Serialize a reply into `buffer`, returns whether it could be
+///
+/// The buffer is cleared first and keeps its capacity, so steady-state replies don't allocate
+fn reply(buffer: &mut Vec<u8>, command: &impl Serialize) -> bool {
+    buffer.clear();
+
+    if let Err(e) = serde_json::to_writer(&mut *buffer, command) {
+        error!("Failed to serialize reply: {e}");
+        return false;
+    }
+
+    true
+}
+
+fn set_log_level(level: LogLevel) {
+    let level = match level {
+        LogLevel::Error => LevelFilter::Error,
+        LogLevel::Warn => LevelFilter::Warn,
+        LogLevel::Info => LevelFilter::Info,
+        LogLevel::Debug => LevelFilter::Debug,
+        LogLevel::Trace => LevelFilter::Trace,
+    };
+
+    driver_logger::set_level(level);
+}
+
+fn log_record(record: driver_logger::RingRecord) -> LogRecord {
+    let level = match record.level {
+        log::Level::Error => LogLevel::Error,
+        log::Level::Warn => LogLevel::Warn,
+        log::Level::Info => LogLevel::Info,
+        log::Level::Debug => LogLevel::Debug,
+        log::Level::Trace => LogLevel::Trace,
+    };
+
+    #[allow(clippy::cast_possible_truncation)]
+    let timestamp = record
+        .timestamp
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |time| time.as_millis() as u64);
+
+    LogRecord {
+        seq: record.seq,
+        timestamp,
+        level,
+        target: record.target,
+        message: record.message,
+    }
 }
 
 fn get_data() -> Vec<Monitor> {
     let hklm = RegKey::predef(HKEY_CURRENT_USER);
-    let key = r"SOFTWARE\VirtualDisplayDriver";
 
-    let Ok(driver_settings) = hklm.open_subkey_with_flags(key, KEY_READ) else {
+    let Ok(driver_settings) = hklm.open_subkey_with_flags(driver_ipc::SETTINGS_KEY, KEY_READ)
+    else {
         return Vec::new();
     };
 
-    driver_settings
+    let mut monitors = driver_settings
         .get_value::<String, _>("data")
         .map(|data| serde_json::from_str::<Vec<Monitor>>(&data).unwrap_or_default())
-        .unwrap_or_default()
+        .unwrap_or_default();
+
+    // clients shouldn't persist ephemeral monitors, but never restore them in case they did
+    monitors.retain(|monitor| !monitor.ephemeral);
+
+    monitors
+}
+
+/// The id of the monitor `monitor` refers to, `None` for an alias no monitor has
+fn resolve(monitor: &MonitorRef) -> Option<u32> {
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+    let id = monitor.resolve(lock.iter().map(|mon| &mon.monitor));
+    if id.is_none() {
+        warn!("No monitor has the alias {monitor}");
+    }
+
+    id
 }
 
 /// used to check the validity of a Vec<Monitor>
@@ -199,27 +560,60 @@ fn has_duplicates(monitors: &[Monitor]) -> bool {
 }
 
 /// Adds if it doesn't exist,
-/// Or, if it does exist, updates it by detaching, update, and re-attaching
+/// Or, if it does exist, diffs it against the current state and does the least needed to apply it
 ///
-/// Only adds/detaches, if required in order to update monitor state in the OS.
-/// e.g. only a name update would not detach/arrive a monitor
-fn notify(monitors: Vec<Monitor>) {
+/// - only stored state changed (e.g. name, session policy): nothing is done in the os
+/// - enabled/disabled: the monitor arrives/departs
+/// - added or enabled with `auto_plug` off: only stored, until `DriverPlug` arrives it
+/// - modes changed, but all of them were known at arrival: modes are updated in place
+/// - anything else the os reads at arrival changed (edid, audio, cursor, new modes): the monitor
+///   departs and arrives again
+///
+/// Returns what was done for each monitor
+fn notify(monitors: Vec<Monitor>) -> Vec<MonitorDiff> {
     // Duplicated id's will not cause any issue, however duplicated resolutions/refresh rates are possible
     // They should all be unique anyways. So warn + noop if the sender sent incorrect data
     if has_duplicates(&monitors) {
         warn!(
             "notify(): Duplicate data was detected; nothing was changed; please fix your program"
         );
-        return;
+        return Vec::new();
+    }
+
+    // aliases are unique across all monitors, not only the notified ones
+    let conflict = {
+        let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+        driver_ipc::alias_conflict(lock.iter().map(|mon| &mon.monitor), &monitors)
+    };
+    if let Some(problem) = conflict {
+        warn!("{problem}");
+        warn!("notify(): Conflicting aliases were detected; nothing was changed");
+        return Vec::new();
     }
 
+    let mut diffs = Vec::with_capacity(monitors.len());
+
     let adapter = ADAPTER.get().unwrap().0.as_ptr();
 
+    let capabilities = features::monitor_capabilities();
+
     let cb = |context: &mut DeviceContext| {
-        for monitor in monitors {
+        for mut monitor in monitors {
             let id = monitor.id;
+            // whatever the client sent, the driver reports its own
+            monitor.capabilities = Some(capabilities);
+            monitor.active_mode = None;
+            monitor.target = None;
 
-            let should_arrive;
+            let mut should_arrive;
+            let mut departure = None;
+            let mut update = None;
+            let mut diff = MonitorDiff {
+                id,
+                created: false,
+                changed: Vec::new(),
+                operations: Vec::new(),
+            };
 
             {
                 let mut lock = MONITOR_MODES.get().unwrap().lock().unwrap();
@@ -230,94 +624,536 @@ fn notify(monitors: Vec<Monitor>) {
                     .find(|(_, mon)| mon.monitor.id == id);
 
                 if let Some((i, mon)) = cur_mon {
-                    let modes_changed = mon.monitor.modes != monitor.modes;
+                    // only the os changes it, in `adapter_commit_modes`
+                    monitor.active_mode = mon.monitor.active_mode;
+                    monitor.target = mon.monitor.target;
+                    diff.changed = mon
+                        .monitor
+                        .changed_fields(&monitor)
+                        .into_iter()
+                        .map(str::to_owned)
+                        .collect();
+
+                    // a different edid also means a different set of modes for the os
+                    let description_changed = mon.monitor.edid != monitor.edid
+                        // the name is in the generated edid
+                        || mon.monitor.edid_name() != monitor.edid_name()
+                        || mon.monitor.audio != monitor.audio
+                        || mon.monitor.exclusive_capture != monitor.exclusive_capture
+                        // the cursor and frame export are set up when the swap chain is
+                        // assigned, after arrival
+                        || mon.monitor.cursor != monitor.cursor
+                        || mon.monitor.gpu_export != monitor.gpu_export
+                        || mon.monitor.export_size != monitor.export_size;
 
-                    #[allow(clippy::nonminimal_bool)]
+                    // modes the os already knows from arrival can be updated in place,
+                    // which avoids the flicker and window shuffling of a re-plug
+                    if mon.monitor.modes != monitor.modes
+                        && !description_changed
+                        && mon.monitor.enabled
+                        && monitor.enabled
+                        && is_subset(&monitor.modes, &mon.arrived_modes)
                     {
-                        should_arrive =
-                            // previously was disabled, and it was just enabled
-                            (!mon.monitor.enabled && monitor.enabled) ||
-                            // OR monitor is enabled and the display modes changed
-                            (monitor.enabled && modes_changed);
+                        update = mon.monitor_object.map(|object| state::ModeUpdate {
+                            id,
+                            object,
+                            modes: monitor.modes.clone(),
+                            preferred_changed: mon.monitor.modes.flatten().next()
+                                != monitor.modes.flatten().next(),
+                        });
                     }
 
+                    let modes_changed = description_changed
+                        || (mon.monitor.modes != monitor.modes && update.is_none());
+
+                    // monitors that aren't plugged in automatically still are when they're
+                    // enabled after being disabled
+                    let plugged = monitor.enabled
+                        && (mon.plugged || monitor.auto_plug || !mon.monitor.enabled);
+
+                    // was just plugged in, OR is plugged in and the display modes changed
+                    should_arrive = plugged && (!mon.plugged || modes_changed);
+
                     // should only detach if modes changed, or if state is false
                     if modes_changed || !monitor.enabled {
-                        if let Some(mut obj) = mon.monitor_object.take() {
-                            let obj = unsafe { obj.as_mut() };
-                            unsafe {
-                                IddCxMonitorDeparture(obj).unwrap();
-                            }
+                        departure = state::departure(mon);
+                        if departure.is_some() {
+                            monitor.target = None;
+                            diff.operations.push(MonitorOperation::Departure);
                         }
                     }
 
+                    let arrived_modes = if should_arrive {
+                        monitor.modes.clone()
+                    } else {
+                        mem::take(&mut mon.arrived_modes)
+                    };
+
+                    // applies to the running swap chain right away
+                    mon.limit.set(monitor.fps_limit);
+
+                    // recorded with the lock held, so events are in the order the list changed
+                    if !diff.changed.is_empty() {
+                        events::push(EventKind::MonitorChanged {
+                            monitor: monitor.clone(),
+                            changed: diff.changed.clone(),
+                        });
+                    }
+
                     // replace existing item with new object
                     lock[i] = MonitorObject {
                         monitor_object: mon.monitor_object,
                         monitor,
+                        stats: mon.stats.clone(),
+                        limit: mon.limit.clone(),
+                        pattern: mon.pattern.clone(),
+                        consumer: mon.consumer.clone(),
+                        arrived_modes,
+                        edid: mem::take(&mut mon.edid),
+                        plugged,
                     };
                 } else {
-                    should_arrive = monitor.enabled;
+                    should_arrive = monitor.enabled && monitor.auto_plug;
+                    diff.created = true;
+
+                    let limit = Arc::new(FrameLimit::default());
+                    limit.set(monitor.fps_limit);
+
+                    events::push(EventKind::MonitorAdded {
+                        monitor: monitor.clone(),
+                    });
 
                     lock.push(MonitorObject {
                         monitor_object: None,
+                        arrived_modes: monitor.modes.clone(),
                         monitor,
+                        stats: Arc::default(),
+                        limit,
+                        pattern: Arc::default(),
+                        consumer: Arc::default(),
+                        edid: None,
+                        plugged: should_arrive,
                     });
                 }
             }
 
-            if should_arrive {
-                if let Err(e) = context.create_monitor(id) {
-                    error!("Failed to create monitor: {e:?}");
-                };
+            // the os calls back into the driver while it departs the monitor, or updates its
+            // modes
+            if let Some(departure) = departure {
+                departure.depart();
             }
+
+            if let Some(update) = update {
+                if update_modes(update.object, id, &update.modes) {
+                    diff.operations.push(MonitorOperation::UpdateModes);
+
+                    // the os keeps the current mode otherwise, even if the preferred one changed
+                    if update.preferred_changed && update_display_config(adapter, update.object, id)
+                    {
+                        diff.operations.push(MonitorOperation::DisplayConfigUpdate);
+                    }
+                } else if let Some(departure) =
+                    state::take_failed_update(MONITOR_MODES.get().unwrap(), &update)
+                {
+                    departure.depart();
+                    diff.operations.push(MonitorOperation::Departure);
+                    should_arrive = true;
+                }
+            }
+
+            // while suspended, the monitor is arrived on resume instead
+            if should_arrive && !DeviceContext::is_suspended() {
+                match context.create_monitor(id) {
+                    Ok(()) => diff.operations.push(MonitorOperation::Arrival),
+                    Err(e) => error!("Failed to create monitor: {e:?}"),
+                }
+            }
+
+            diffs.push(diff);
         }
     };
 
     unsafe {
-        DeviceContext::get_mut(adapter.cast(), cb).unwrap();
+        WdfObjectContext::<DeviceContext>::get_mut(adapter.cast(), cb).unwrap();
     }
+
+    diffs
 }
 
-fn remove_all() {
-    let mut lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+/// Switch an active monitor to another of its modes, by making it the preferred one and having
+/// the os apply it, see `RequestModeChange`
+fn change_mode(id: u32, mode: ActiveMode) -> ModeChange {
+    let (monitor, monitor_object) = {
+        let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+
+        let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) else {
+            warn!("change_mode(): Monitor {id} doesn't exist");
+            return ModeChange::Unavailable;
+        };
 
-    for monitor in lock.drain(..) {
-        if let Some(mut monitor_object) = monitor.monitor_object {
-            let obj = unsafe { monitor_object.as_mut() };
-            unsafe {
-                IddCxMonitorDeparture(obj).unwrap();
+        let mut monitor = mon.monitor.clone();
+        let Some(monitor_object) = mon.monitor_object.filter(|_| monitor.active_mode.is_some())
+        else {
+            return ModeChange::Unavailable;
+        };
+        if !monitor.prefer_mode(mode) {
+            return ModeChange::Unavailable;
+        }
+
+        (monitor, monitor_object)
+    };
+
+    if let Some(wait) = mode_change_wait(id) {
+        #[allow(clippy::cast_possible_truncation)]
+        return ModeChange::RateLimited {
+            retry_after_ms: wait.as_millis() as u64,
+        };
+    }
+
+    events::push(EventKind::ModeChangeRequested { id, mode });
+
+    let Some(mut diff) = notify(vec![monitor]).pop() else {
+        return ModeChange::Unavailable;
+    };
+
+    // the preferred mode didn't change, e.g. because another one was picked in display settings,
+    // so the os only switches back when told to
+    let switched = diff.operations.iter().any(|operation| {
+        matches!(
+            operation,
+            MonitorOperation::DisplayConfigUpdate | MonitorOperation::Arrival
+        )
+    });
+    let adapter = ADAPTER.get().unwrap().0.as_ptr();
+    if !switched && update_display_config(adapter, monitor_object, id) {
+        diff.operations.push(MonitorOperation::DisplayConfigUpdate);
+    }
+
+    ModeChange::Requested(diff)
+}
+
+/// How long until the mode of monitor `id` may be changed again, `None` if it may be now, in
+/// which case the change is recorded
+fn mode_change_wait(id: u32) -> Option<Duration> {
+    let mut changes = MODE_CHANGES.get_or_init(Mutex::default).lock().unwrap();
+
+    let interval = Duration::from_millis(MODE_CHANGE_INTERVAL_MS);
+    let wait = changes
+        .get(&id)
+        .map(|changed| interval.saturating_sub(changed.elapsed()))
+        .filter(|wait| !wait.is_zero());
+    if wait.is_none() {
+        changes.insert(id, Instant::now());
+    }
+
+    wait
+}
+
+/// Simulate a hotplug: depart the monitor and arrive it again with the same configuration
+fn replug(id: u32) {
+    let adapter = ADAPTER.get().unwrap().0.as_ptr();
+
+    let cb = |context: &mut DeviceContext| {
+        let monitors = MONITOR_MODES.get().unwrap();
+
+        {
+            let mut lock = monitors.lock().unwrap();
+
+            let Some(mon) = lock.iter_mut().find(|mon| mon.monitor.id == id) else {
+                warn!("replug(): Monitor {id} doesn't exist");
+                return;
+            };
+
+            if !mon.plugged {
+                return;
             }
+
+            mon.arrived_modes = mon.monitor.modes.clone();
         }
+
+        if let Some(departure) = state::take_object(monitors, id) {
+            departure.depart();
+        }
+
+        // while suspended, the monitor is arrived on resume instead
+        if !DeviceContext::is_suspended() {
+            if let Err(e) = context.create_monitor(id) {
+                error!("Failed to create monitor: {e:?}");
+            }
+        }
+    };
+
+    unsafe {
+        WdfObjectContext::<DeviceContext>::get_mut(adapter.cast(), cb).unwrap();
     }
 }
 
-fn remove(ids: &[u32]) {
-    let mut lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+/// Show a test pattern on a monitor instead of what the os renders to it, or stop showing one
+// The swap chain thread picks the pattern up from the monitor's slot, without taking this lock
+fn set_test_pattern(id: u32, pattern: Option<TestPattern>) {
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
 
-    for &id in ids {
-        lock.retain_mut(|monitor| {
-            if id == monitor.monitor.id {
-                if let Some(mut monitor_object) = monitor.monitor_object.take() {
-                    let obj = unsafe { monitor_object.as_mut() };
-                    unsafe {
-                        IddCxMonitorDeparture(obj).unwrap();
-                    }
-                }
+    let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) else {
+        warn!("set_test_pattern(): Monitor {id} doesn't exist");
+        return;
+    };
+
+    mon.pattern.set(pattern);
+}
+
+// Like the test pattern, the swap chain thread picks the format up from the monitor's slot
+fn set_frame_format(id: u32, format: FrameFormat) {
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+
+    let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) else {
+        warn!("set_frame_format(): Monitor {id} doesn't exist");
+        return;
+    };
+
+    mon.consumer.set_format(format);
+}
+
+fn set_frame_sync(id: u32, options: SyncOptions) {
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+
+    let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) else {
+        warn!("set_frame_sync(): Monitor {id} doesn't exist");
+        return;
+    };
+
+    mon.consumer.set_sync(options);
+}
+
+fn set_backpressure(id: u32, backpressure: Backpressure) {
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+
+    let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) else {
+        warn!("set_backpressure(): Monitor {id} doesn't exist");
+        return;
+    };
+
+    mon.consumer.set_backpressure(backpressure);
+}
+
+// The shared frame of a monitor, with its current backpressure policy, which changes without
+// the texture being shared anew
+fn current_shared_frame(id: u32) -> Option<SharedFrame> {
+    let mut frame = shared_frame::get(id)?;
+
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+    if let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) {
+        frame.backpressure = mon.consumer.backpressure();
+    }
+
+    Some(frame)
+}
+
+fn request_ring(id: u32) {
+    let lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+
+    let Some(mon) = lock.iter().find(|mon| mon.monitor.id == id) else {
+        warn!("request_ring(): Monitor {id} doesn't exist");
+        return;
+    };
+
+    mon.consumer.request_ring();
+}
+
+fn set_lut(id: u32, lut: Option<Lut>) {
+    if lut.as_ref().is_some_and(|lut| !lut.is_valid()) {
+        warn!("set_lut(): Invalid LUT for monitor {id}");
+        return;
+    }
+
+    let exists = MONITOR_MODES
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|mon| mon.monitor.id == id);
+    if !exists {
+        warn!("set_lut(): Monitor {id} doesn't exist");
+        return;
+    }
+
+    picture::update(id, |picture| picture.lut = lut);
+}
+
+fn set_cursor_composite(id: u32, composite: bool) {
+    let exists = MONITOR_MODES
+        .get()
+        .unwrap()
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|mon| mon.monitor.id == id);
+    if !exists {
+        warn!("set_cursor_composite(): Monitor {id} doesn't exist");
+        return;
+    }
+
+    cursor::set_composite(id, composite);
+}
 
-                false
-            } else {
-                true
+/// Plug in a monitor that was configured without being plugged in
+fn plug(id: u32) {
+    let adapter = ADAPTER.get().unwrap().0.as_ptr();
+
+    let cb = |context: &mut DeviceContext| {
+        {
+            let mut lock = MONITOR_MODES.get().unwrap().lock().unwrap();
+
+            let Some(mon) = lock.iter_mut().find(|mon| mon.monitor.id == id) else {
+                warn!("plug(): Monitor {id} doesn't exist");
+                return;
+            };
+
+            // disabled monitors are plugged in by enabling them
+            if !mon.monitor.enabled || mon.plugged {
+                return;
             }
-        });
+
+            mon.plugged = true;
+            mon.arrived_modes = mon.monitor.modes.clone();
+        }
+
+        // while suspended, the monitor is arrived on resume instead
+        if !DeviceContext::is_suspended() {
+            if let Err(e) = context.create_monitor(id) {
+                error!("Failed to create monitor: {e:?}");
+            }
+        }
+    };
+
+    unsafe {
+        WdfObjectContext::<DeviceContext>::get_mut(adapter.cast(), cb).unwrap();
     }
 }
 
+fn remove_all() {
+    remove_where(|_| true);
+}
+
+fn remove(monitors: &[MonitorRef]) {
+    remove_where(|mon| monitors.iter().any(|monitor| monitor.matches(&mon.monitor)));
+}
+
+fn remove_where(f: impl Fn(&MonitorObject) -> bool) {
+    let removed = state::remove(MONITOR_MODES.get().unwrap(), f, |id| {
+        events::push(EventKind::MonitorRemoved { id });
+    });
+
+    for id in removed.ids {
+        picture::clear(id);
+        cursor::clear(id);
+    }
+
+    for departure in removed.departures {
+        departure.depart();
+    }
+}
+
+/// Whether every mode in `modes` is also in `known`
+fn is_subset(modes: &[Mode], known: &[Mode]) -> bool {
+    modes
+        .flatten()
+        .all(|mode| known.flatten().any(|known| known == mode))
+}
+
+fn set_realtime_gpu_priority(enabled: bool) {
+    let adapter = ADAPTER.get().unwrap().0.as_ptr();
+
+    let res = unsafe {
+        WdfObjectContext::<DeviceContext>::get(adapter.cast(), |context| {
+            context.set_realtime_gpu_priority(enabled);
+        })
+    };
+    if let Err(e) = res {
+        error!("Failed to get device context: {e:?}");
+    }
+}
+
+/// Replace the target modes of an arrived monitor without re-plugging it
+///
+/// Returns `false` if it failed, e.g. because the os doesn't support it, in which case the
+/// monitor needs to be re-plugged instead
+fn update_modes(monitor_object: NonNull<IDDCX_MONITOR__>, id: u32, modes: &[Mode]) -> bool {
+    let mut target_modes = modes
+        .flatten()
+        .map(|mode| target_mode(mode.width, mode.height, mode.refresh_rate))
+        .collect::<Vec<_>>();
+
+    let in_args = IDARG_IN_UPDATEMODES {
+        Reason: IDDCX_UPDATE_REASON::IDDCX_UPDATE_REASON_OTHER,
+        TargetModeCount: u32::try_from(target_modes.len()).expect("Cannot use > u32::MAX modes"),
+        pTargetModes: target_modes.as_mut_ptr(),
+    };
+
+    let status = unsafe { IddCxMonitorUpdateModes(monitor_object.as_ptr(), &in_args) };
+    match status {
+        Ok(status) => {
+            trace::monitor_event("UpdateModes", id, status);
+            true
+        }
+
+        Err(e) => {
+            warn!("Failed to update modes of monitor {id} in place, re-plugging it: {e:?}");
+            false
+        }
+    }
+}
+
+/// Have the os re-apply the display config of an arrived monitor, e.g. after its preferred mode
+/// changed, without re-plugging it or re-creating the adapter
+///
+/// Returns `false` if it wasn't done, e.g. because the os or this build doesn't support it
+#[cfg(feature = "iddcx-1_10")]
+fn update_display_config(
+    adapter: *mut IDDCX_ADAPTER__,
+    monitor_object: NonNull<IDDCX_MONITOR__>,
+    id: u32,
+) -> bool {
+    let mut path = wdf_umdf_sys::IDDCX_DISPLAYCONFIGPATH {
+        #[allow(clippy::cast_possible_truncation)]
+        Size: size_of::<wdf_umdf_sys::IDDCX_DISPLAYCONFIGPATH>() as u32,
+        MonitorObject: monitor_object.as_ptr(),
+        ..Default::default()
+    };
+
+    let in_args = wdf_umdf_sys::IDARG_IN_ADAPTERDISPLAYCONFIGUPDATE {
+        PathCount: 1,
+        pPaths: addr_of_mut!(path),
+    };
+
+    let status = unsafe { wdf_umdf::IddCxAdapterDisplayConfigUpdate(adapter, &in_args) };
+    match status {
+        Ok(status) => {
+            trace::monitor_event("DisplayConfigUpdate", id, status);
+            true
+        }
+
+        Err(e) => {
+            warn!("Failed to update the display config of monitor {id}: {e:?}");
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "iddcx-1_10"))]
+fn update_display_config(
+    _adapter: *mut IDDCX_ADAPTER__,
+    _monitor_object: NonNull<IDDCX_MONITOR__>,
+    _id: u32,
+) -> bool {
+    false
+}
+
 pub trait FlattenModes {
     fn flatten(&self) -> impl Iterator<Item = ModeItem>;
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq)]
 pub struct ModeItem {
     pub width: Dimen,
     pub height: Dimen,
@@ -325,7 +1161,7 @@ pub struct ModeItem {
 }
 
 /// Takes a slice of modes and creates a flattened structure that can be iterated over
-impl FlattenModes for Vec<Mode> {
+impl FlattenModes for [Mode] {
     fn flatten(&self) -> impl Iterator<Item = ModeItem> {
         self.iter().flat_map(|m| {
             m.refresh_rates.iter().map(|&rr| ModeItem {
@@ -336,3 +1172,91 @@ impl FlattenModes for Vec<Mode> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, ptr::NonNull, rc::Rc, slice};
+
+    use driver_ipc::Mode;
+    use wdf_umdf::mock_iddcx;
+    use wdf_umdf_sys::NTSTATUS;
+
+    use super::update_modes;
+    use crate::state::Departure;
+
+    fn modes() -> Vec<Mode> {
+        vec![
+            Mode {
+                width: 1920,
+                height: 1080,
+                refresh_rates: vec![60, 120],
+            },
+            Mode {
+                width: 1280,
+                height: 720,
+                refresh_rates: vec![60],
+            },
+        ]
+    }
+
+    #[test]
+    fn update_modes_sends_every_refresh_rate() {
+        let sent = Rc::new(RefCell::new(Vec::new()));
+        let recorder = sent.clone();
+        let _mock = mock_iddcx!(IddCxMonitorUpdateModes, move |(_monitor, in_args)| {
+            // SAFETY: The driver passes a reference to its arguments
+            let in_args = unsafe { &*in_args };
+            // SAFETY: The driver passes as many modes as it says
+            let modes = unsafe {
+                slice::from_raw_parts(in_args.pTargetModes, in_args.TargetModeCount as usize)
+            };
+
+            recorder.borrow_mut().extend(modes.iter().map(|mode| {
+                let signal = &mode.TargetVideoSignalInfo.targetVideoSignalInfo;
+                (
+                    signal.activeSize.cx,
+                    signal.activeSize.cy,
+                    signal.vSyncFreq.Numerator,
+                )
+            }));
+
+            NTSTATUS::STATUS_SUCCESS
+        });
+
+        assert!(update_modes(NonNull::dangling(), 0, &modes()));
+        assert_eq!(
+            *sent.borrow(),
+            [(1920, 1080, 60), (1920, 1080, 120), (1280, 720, 60)]
+        );
+    }
+
+    #[test]
+    fn failed_update_modes_needs_replug() {
+        let _mock = mock_iddcx!(IddCxMonitorUpdateModes, |(_monitor, _in_args)| {
+            NTSTATUS::STATUS_NOT_SUPPORTED
+        });
+
+        assert!(!update_modes(NonNull::dangling(), 0, &modes()));
+    }
+
+    #[test]
+    fn unavailable_update_modes_needs_replug() {
+        // e.g. on an os too old for it, nothing is mocked here
+        assert!(!update_modes(NonNull::dangling(), 0, &modes()));
+    }
+
+    #[test]
+    fn departure_departs_its_object() {
+        let departed = Rc::new(RefCell::new(Vec::new()));
+        let recorder = departed.clone();
+        let _mock = mock_iddcx!(IddCxMonitorDeparture, move |(monitor,)| {
+            recorder.borrow_mut().push(monitor);
+            NTSTATUS::STATUS_SUCCESS
+        });
+
+        let object = NonNull::dangling();
+        Departure { id: 3, object }.depart();
+
+        assert_eq!(*departed.borrow(), [object.as_ptr()]);
+    }
+}
@@ -2,12 +2,29 @@ mod helpers;
 
 mod callbacks;
 mod context;
+mod cursor;
+mod ddc;
 mod direct_3d_device;
 mod edid;
 mod entry;
+mod events;
+mod features;
+mod gpu_priority;
+mod ioctl;
 mod ipc;
 mod panic;
+mod picture;
+mod pool;
+mod ring;
+mod scale;
+mod shared_frame;
+mod state;
+mod stats;
 mod swap_chain_processor;
+mod sync;
+mod test_pattern;
+mod trace;
+mod watchdog;
 
 use wdf_umdf_sys::{NTSTATUS, PUNICODE_STRING, PVOID};
 
@@ -1,8 +1,17 @@
 #[cfg(debug_assertions)]
 use std::backtrace::Backtrace;
-use std::panic;
+use std::{
+    env, panic,
+    path::PathBuf,
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
 
 use log::error;
+use wdf_umdf_sys::{NTSTATUS, WDFDEVICE, WDFDEVICE__};
+
+// The device to fail when a callback panics, null until it's created
+static DEVICE: AtomicPtr<WDFDEVICE__> = AtomicPtr::new(ptr::null_mut());
 
 pub fn set_hook() {
     panic::set_hook(Box::new(|v| {
@@ -17,4 +26,40 @@ pub fn set_hook() {
         #[cfg(not(debug_assertions))]
         error!("{v}");
     }));
+
+    // runs before the logging hook above, and also works when the event log isn't available yet
+    wdf_umdf::install_panic_hook(report_path());
+}
+
+/// Where crash reports are appended to, `%ProgramData%\VirtualDisplayDriver\crash.log`
+fn report_path() -> PathBuf {
+    let program_data = env::var_os("ProgramData").unwrap_or_else(|| r"C:\ProgramData".into());
+
+    PathBuf::from(program_data)
+        .join("VirtualDisplayDriver")
+        .join("crash.log")
+}
+
+pub fn set_device(device: WDFDEVICE) {
+    DEVICE.store(device, Ordering::Release);
+}
+
+pub fn clear_device() {
+    DEVICE.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Run a callback, catching panics so they never unwind into the framework
+///
+/// A panic fails the device (so the framework restarts it) and returns `None`
+pub fn catch<R>(f: impl FnOnce() -> R) -> Option<R> {
+    let device = DEVICE.load(Ordering::Acquire);
+    let device = (!device.is_null()).then_some(device);
+
+    // SAFETY: the device is only set while it's alive, and cleared again in its cleanup callback
+    unsafe { wdf_umdf::catch_panic(device, f) }
+}
+
+/// Like [`catch`], for callbacks returning a status
+pub fn guard(f: impl FnOnce() -> NTSTATUS) -> NTSTATUS {
+    catch(f).unwrap_or(NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR)
 }
@@ -0,0 +1,72 @@
+//! Picture settings of monitors: the gamma ramp windows sets (e.g. for color calibration or night
+//! light), brightness and contrast set over DDC/CI, see `ddc`, and lookup tables set by clients
+//!
+//! They aren't applied to frames, only kept for clients, which apply them where the frames are
+//! shown.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use driver_ipc::{GammaRamp, Picture};
+
+// Entries per channel of a 256x3x16 ramp
+const RAMP_ENTRIES: usize = 256;
+
+// Settings of a monitor that hasn't changed any yet, which leave frames as they are
+pub const DEFAULT: Picture = Picture {
+    brightness: 100,
+    contrast: 100,
+    gamma: None,
+    lut: None,
+};
+
+// Settings of each monitor that changed any of them
+static PICTURES: OnceLock<Mutex<HashMap<u32, Picture>>> = OnceLock::new();
+
+fn pictures() -> &'static Mutex<HashMap<u32, Picture>> {
+    PICTURES.get_or_init(Mutex::default)
+}
+
+pub fn get(monitor_id: u32) -> Picture {
+    pictures()
+        .lock()
+        .ok()
+        .and_then(|pictures| pictures.get(&monitor_id).cloned())
+        .unwrap_or(DEFAULT)
+}
+
+/// Change a monitor's settings
+pub fn update(monitor_id: u32, f: impl FnOnce(&mut Picture)) {
+    let Ok(mut pictures) = pictures().lock() else {
+        return;
+    };
+
+    f(pictures.entry(monitor_id).or_insert(DEFAULT));
+}
+
+/// Forget a removed monitor's settings
+pub fn clear(monitor_id: u32) {
+    if let Ok(mut pictures) = pictures().lock() {
+        pictures.remove(&monitor_id);
+    }
+}
+
+/// Split a `D3DDDI_GAMMA_RAMP_RGB256x3x16`, which is 256 red, then green, then blue entries
+///
+/// Returns `None` if `data` has the wrong size
+pub fn ramp_from_rgb256x3x16(data: &[u16]) -> Option<GammaRamp> {
+    if data.len() != RAMP_ENTRIES * 3 {
+        return None;
+    }
+
+    let (red, rest) = data.split_at(RAMP_ENTRIES);
+    let (green, blue) = rest.split_at(RAMP_ENTRIES);
+
+    Some(GammaRamp {
+        red: red.to_vec(),
+        green: green.to_vec(),
+        blue: blue.to_vec(),
+    })
+}
@@ -0,0 +1,116 @@
+//! Reuse of what frames are processed with
+//!
+//! A monitor gets a new swap chain on every mode change, and whenever the os re-assigns it.
+//! Instead of creating a device and shared textures for each one, the previous swap chain's are
+//! handed on to the next one, as long as it renders on the same adapter. Textures that no longer
+//! match the frames are kept around too, in case the monitor switches back.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use windows::Win32::{
+    Foundation::LUID,
+    Graphics::{Direct3D11::D3D11_TEXTURE2D_DESC, Dxgi::Common::DXGI_FORMAT},
+};
+
+use crate::{direct_3d_device::Direct3DDevice, gpu_priority, shared_frame::FrameExporter};
+
+// Textures kept per device that don't match the current frames
+const CAPACITY: usize = 2;
+
+/// Pool counters of a single monitor, see `FrameStats`
+#[derive(Debug, Default)]
+pub struct PoolStats {
+    // devices and textures created
+    allocations: AtomicU64,
+    // devices and textures taken from the pool instead
+    reuses: AtomicU64,
+}
+
+impl PoolStats {
+    pub fn allocated(&self) {
+        self.allocations.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn reused(&self) {
+        self.reuses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Allocations and reuses so far
+    pub fn snapshot(&self) -> (u64, u64) {
+        (
+            self.allocations.load(Ordering::Relaxed),
+            self.reuses.load(Ordering::Relaxed),
+        )
+    }
+}
+
+/// What a swap chain processes frames with, handed on to the monitor's next swap chain
+pub struct Resources {
+    // the render adapter the device was created on
+    pub luid: LUID,
+    pub device: Direct3DDevice,
+    pub exporter: Option<FrameExporter>,
+    // whether the device got realtime gpu priority, see `gpu_priority`
+    pub realtime_priority: bool,
+}
+
+impl Resources {
+    /// Whether a swap chain rendered on `luid` can use these
+    pub fn reusable_for(&self, luid: LUID) -> bool {
+        // a removed device (e.g. after a driver update or gpu reset) can't be used anymore, and
+        // one with realtime priority keeps it after it was turned off
+        self.luid == luid
+            && unsafe { self.device.device.GetDeviceRemovedReason() }.is_ok()
+            && (!self.realtime_priority || gpu_priority::enabled())
+    }
+}
+
+/// What makes textures interchangeable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextureKey {
+    width: u32,
+    height: u32,
+    format: DXGI_FORMAT,
+    // how the texture is shared, textures handed over with a fence have no keyed mutex
+    misc_flags: u32,
+}
+
+impl From<&D3D11_TEXTURE2D_DESC> for TextureKey {
+    fn from(desc: &D3D11_TEXTURE2D_DESC) -> Self {
+        Self {
+            width: desc.Width,
+            height: desc.Height,
+            format: desc.Format,
+            misc_flags: desc.MiscFlags,
+        }
+    }
+}
+
+/// Textures of a single device that currently aren't used
+pub struct TexturePool<T> {
+    // least recently used first
+    free: Vec<(TextureKey, T)>,
+}
+
+impl<T> Default for TexturePool<T> {
+    fn default() -> Self {
+        Self { free: Vec::new() }
+    }
+}
+
+impl<T> TexturePool<T> {
+    /// Take a texture out of the pool, if there's one that fits
+    pub fn take(&mut self, key: TextureKey) -> Option<T> {
+        let index = self.free.iter().position(|&(free, _)| free == key)?;
+        Some(self.free.remove(index).1)
+    }
+
+    /// Keep a texture for later, dropping the least recently used one if the pool is full
+    pub fn put(&mut self, key: TextureKey, texture: T) {
+        if self.free.len() == CAPACITY {
+            self.free.remove(0);
+        }
+
+        self.free.push((key, texture));
+    }
+}
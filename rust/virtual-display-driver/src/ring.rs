@@ -0,0 +1,372 @@
+//! Shared frames for several consumers at once, see `driver_ipc::SharedRing`
+//!
+//! The single shared texture of `shared_frame` is handed back and forth with one consumer, so a
+//! second one would take frames away from the first. Once a consumer asks for the ring, frames
+//! are produced into a texture of the ring's own, which is then copied into the shared texture as
+//! before, and into the ring texture with the oldest frame that no consumer is reading.
+//!
+//! Consumers keep their read cursors in the ring's header, see `driver_ipc::RingHeader`, so each
+//! takes frames at its own pace. Their entries are checked once a second, to free the ones of
+//! consumers that exited and to measure how fast the others read, for the stats.
+
+use std::{
+    collections::HashMap,
+    mem::size_of,
+    ptr::NonNull,
+    sync::{atomic::Ordering, Mutex, OnceLock},
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{Backpressure, ConsumerStats, RingHeader, SharedRing, RING_CONSUMERS, RING_SLOTS};
+use log::warn;
+use windows::{
+    core::{Interface, HSTRING},
+    Win32::{
+        Foundation::{
+            CloseHandle, ERROR_INVALID_PARAMETER, HANDLE, INVALID_HANDLE_VALUE, S_OK, WAIT_OBJECT_0,
+        },
+        Graphics::{
+            Direct3D11::{
+                ID3D11Texture2D, D3D11_RESOURCE_MISC_SHARED_KEYEDMUTEX,
+                D3D11_RESOURCE_MISC_SHARED_NTHANDLE, D3D11_TEXTURE2D_DESC,
+            },
+            Dxgi::{
+                IDXGIKeyedMutex, IDXGIResource1, DXGI_SHARED_RESOURCE_READ,
+                DXGI_SHARED_RESOURCE_WRITE,
+            },
+        },
+        Security::SECURITY_DESCRIPTOR,
+        System::{
+            Memory::{
+                CreateFileMappingW, MapViewOfFile, UnmapViewOfFile, FILE_MAP_ALL_ACCESS,
+                MEMORY_MAPPED_VIEW_ADDRESS, PAGE_READWRITE,
+            },
+            Threading::{
+                CreateEventW, OpenProcess, SetEvent, WaitForSingleObject, PROCESS_SYNCHRONIZE,
+            },
+        },
+    },
+};
+
+use crate::{direct_3d_device::Direct3DDevice, shared_frame};
+
+// Key everyone acquires and releases ring textures with, they're only ever held briefly
+const KEY: u64 = 0;
+
+// How often consumer entries are checked
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+// Current ring of each monitor, for the ipc
+static RINGS: OnceLock<Mutex<HashMap<u32, SharedRing>>> = OnceLock::new();
+
+fn rings() -> &'static Mutex<HashMap<u32, SharedRing>> {
+    RINGS.get_or_init(Mutex::default)
+}
+
+/// The ring a monitor's frames are currently shared in, if a consumer asked for one
+pub fn get(monitor_id: u32) -> Option<SharedRing> {
+    rings().lock().ok()?.get(&monitor_id).cloned()
+}
+
+/// The frame ring of one monitor, on one device
+pub struct FrameRing {
+    monitor_id: u32,
+    // frames are produced in here, then copied to the shared texture and the ring
+    frame: ID3D11Texture2D,
+    desc: D3D11_TEXTURE2D_DESC,
+    slots: Vec<Slot>,
+    mapping: HANDLE,
+    header: NonNull<RingHeader>,
+    // of the header's consumer entries, by entry
+    events: Vec<HANDLE>,
+    shared: SharedRing,
+    // when consumer entries were last checked, and how many frames each had read by then
+    sampled: Instant,
+    reads: [u64; RING_CONSUMERS],
+}
+
+struct Slot {
+    texture: ID3D11Texture2D,
+    mutex: IDXGIKeyedMutex,
+    handle: HANDLE,
+}
+
+// the header is only accessed through atomics, and handles can be used from any thread
+unsafe impl Send for FrameRing {}
+
+impl FrameRing {
+    /// A ring for frames described by `desc`, published for the ipc
+    pub fn new(
+        device: &Direct3DDevice,
+        monitor_id: u32,
+        desc: &D3D11_TEXTURE2D_DESC,
+    ) -> windows::core::Result<Self> {
+        let generation = shared_frame::next_generation();
+        let name =
+            |kind: &str| format!("Global\\VirtualDisplayDriver-{kind}-{monitor_id}-{generation}");
+
+        let desc = D3D11_TEXTURE2D_DESC {
+            MiscFlags: 0,
+            ..*desc
+        };
+        let frame = create_texture(device, &desc)?;
+
+        let mut sd = SECURITY_DESCRIPTOR::default();
+        let sa = shared_frame::everyone(&mut sd)?;
+
+        // everything created so far is closed by `Drop` if a later step fails
+        let mut ring = Self {
+            monitor_id,
+            frame,
+            desc,
+            slots: Vec::new(),
+            mapping: HANDLE::default(),
+            header: NonNull::dangling(),
+            events: Vec::new(),
+            shared: SharedRing {
+                header: name("Ring"),
+                textures: Vec::new(),
+                events: Vec::new(),
+                width: desc.Width,
+                height: desc.Height,
+                format: u32::try_from(desc.Format.0).unwrap_or_default(),
+            },
+            sampled: Instant::now(),
+            reads: [0; RING_CONSUMERS],
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let size = size_of::<RingHeader>() as u32;
+        ring.mapping = unsafe {
+            CreateFileMappingW(
+                INVALID_HANDLE_VALUE,
+                Some(&sa),
+                PAGE_READWRITE,
+                0,
+                size,
+                &HSTRING::from(&ring.shared.header),
+            )?
+        };
+        let view = unsafe { MapViewOfFile(ring.mapping, FILE_MAP_ALL_ACCESS, 0, 0, 0) };
+        let Some(header) = NonNull::new(view.Value.cast::<RingHeader>()) else {
+            return Err(windows::core::Error::from_win32());
+        };
+        // the mapping is page aligned and as large as the header
+        unsafe { header.as_ptr().write(RingHeader::new(RING_SLOTS)) };
+        ring.header = header;
+
+        #[allow(clippy::cast_sign_loss)]
+        let slot_desc = D3D11_TEXTURE2D_DESC {
+            MiscFlags: (D3D11_RESOURCE_MISC_SHARED_NTHANDLE.0
+                | D3D11_RESOURCE_MISC_SHARED_KEYEDMUTEX.0) as u32,
+            ..desc
+        };
+        for slot in 0..RING_SLOTS {
+            let name = format!("{}-{slot}", name("RingFrame"));
+            let texture = create_texture(device, &slot_desc)?;
+            let mutex = texture.cast()?;
+            let handle = unsafe {
+                texture.cast::<IDXGIResource1>()?.CreateSharedHandle(
+                    Some(&sa),
+                    DXGI_SHARED_RESOURCE_READ | DXGI_SHARED_RESOURCE_WRITE,
+                    &HSTRING::from(&name),
+                )?
+            };
+
+            ring.slots.push(Slot {
+                texture,
+                mutex,
+                handle,
+            });
+            ring.shared.textures.push(name);
+        }
+
+        for entry in 0..RING_CONSUMERS {
+            let name = format!("{}-{entry}", name("RingEvent"));
+            // auto-reset, so every consumer wakes once per frame
+            let event = unsafe { CreateEventW(Some(&sa), false, false, &HSTRING::from(&name))? };
+
+            ring.events.push(event);
+            ring.shared.events.push(name);
+        }
+
+        match rings().lock() {
+            Ok(mut rings) => {
+                rings.insert(monitor_id, ring.shared.clone());
+            }
+            Err(e) => warn!("Failed to publish frame ring: {e}"),
+        }
+
+        Ok(ring)
+    }
+
+    /// Whether the ring takes frames described by `desc`, apart from how they're shared
+    pub fn matches(&self, desc: &D3D11_TEXTURE2D_DESC) -> bool {
+        (self.desc.Width, self.desc.Height, self.desc.Format)
+            == (desc.Width, desc.Height, desc.Format)
+    }
+
+    /// The texture frames are produced in, before they're copied with [`Self::push`]
+    pub fn frame(&self) -> &ID3D11Texture2D {
+        &self.frame
+    }
+
+    /// Copy the produced frame into the ring, and wake its consumers
+    ///
+    /// The frame is dropped if consumers are reading every texture of the ring, unless
+    /// `backpressure` says to wait for the oldest one. Consumers that don't keep up skip frames
+    /// either way, the ring always replaces its oldest frame.
+    pub fn push(
+        &mut self,
+        device: &Direct3DDevice,
+        backpressure: Backpressure,
+    ) -> windows::core::Result<()> {
+        let header = self.header();
+        let oldest = header.oldest();
+
+        // don't wait for consumers, they only copy the frame out
+        let mut free = oldest
+            .iter()
+            .copied()
+            .find(|&slot| acquire(&self.slots[slot].mutex, 0));
+        if let (None, Backpressure::Block { timeout_ms }, Some(&slot)) =
+            (free, backpressure, oldest.first())
+        {
+            free = acquire(&self.slots[slot].mutex, timeout_ms).then_some(slot);
+        }
+
+        if let Some(slot) = free {
+            let mutex = &self.slots[slot].mutex;
+
+            unsafe {
+                device
+                    .device_context
+                    .CopyResource(&self.slots[slot].texture, &self.frame);
+            }
+            unsafe { mutex.ReleaseSync(KEY)? };
+            header.written(slot);
+
+            for (consumer, &event) in header.consumers.iter().zip(&self.events) {
+                if consumer.pid.load(Ordering::Relaxed) != 0 {
+                    unsafe { SetEvent(event)? };
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The ring's consumers, once a second, freeing the entries of the ones that exited
+    pub fn consumers(&mut self) -> Option<Vec<ConsumerStats>> {
+        let elapsed = self.sampled.elapsed();
+        if elapsed < SAMPLE_INTERVAL {
+            return None;
+        }
+        self.sampled = Instant::now();
+
+        let header = self.header();
+        let mut previous_reads = self.reads;
+        let mut consumers = Vec::new();
+
+        for (entry, (consumer, previous)) in
+            header.consumers.iter().zip(&mut previous_reads).enumerate()
+        {
+            let pid = consumer.pid.load(Ordering::Relaxed);
+            let reads = consumer.reads.load(Ordering::Relaxed);
+            let read_since = reads.saturating_sub(*previous);
+            *previous = reads;
+
+            if pid == 0 {
+                continue;
+            }
+            if !running(pid) {
+                header.leave(entry);
+                continue;
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            consumers.push(ConsumerStats {
+                pid,
+                frames_read: reads,
+                fps: read_since as f64 / elapsed.as_secs_f64(),
+                frames_dropped: consumer.dropped.load(Ordering::Relaxed),
+            });
+        }
+
+        self.reads = previous_reads;
+        Some(consumers)
+    }
+
+    fn header(&self) -> &RingHeader {
+        // mapped for as long as the ring lives
+        unsafe { self.header.as_ref() }
+    }
+}
+
+impl Drop for FrameRing {
+    fn drop(&mut self) {
+        // a new swap chain may have published its ring already
+        if let Ok(mut rings) = rings().lock() {
+            if rings
+                .get(&self.monitor_id)
+                .is_some_and(|ring| ring.header == self.shared.header)
+            {
+                rings.remove(&self.monitor_id);
+            }
+        }
+
+        // consumers keep their own handles, so everything lives on until they close them too
+        for slot in &self.slots {
+            _ = unsafe { CloseHandle(slot.handle) };
+        }
+        for &event in &self.events {
+            _ = unsafe { CloseHandle(event) };
+        }
+
+        if self.header != NonNull::dangling() {
+            let view = MEMORY_MAPPED_VIEW_ADDRESS {
+                Value: self.header.as_ptr().cast(),
+            };
+            _ = unsafe { UnmapViewOfFile(view) };
+        }
+        if !self.mapping.is_invalid() {
+            _ = unsafe { CloseHandle(self.mapping) };
+        }
+    }
+}
+
+// Whether a ring texture was acquired within `timeout_ms`
+fn acquire(mutex: &IDXGIKeyedMutex, timeout_ms: u32) -> bool {
+    let hr = unsafe {
+        (Interface::vtable(mutex).AcquireSync)(Interface::as_raw(mutex), KEY, timeout_ms)
+    };
+
+    hr == S_OK || hr == shared_frame::WAIT_ABANDONED
+}
+
+fn create_texture(
+    device: &Direct3DDevice,
+    desc: &D3D11_TEXTURE2D_DESC,
+) -> windows::core::Result<ID3D11Texture2D> {
+    let mut texture = None;
+    unsafe {
+        device
+            .device
+            .CreateTexture2D(desc, None, Some(&mut texture))?;
+    }
+
+    texture.ok_or_else(windows::core::Error::from_win32)
+}
+
+// Whether the process with `pid` still runs. Processes the driver may not open are assumed to
+fn running(pid: u32) -> bool {
+    let process = match unsafe { OpenProcess(PROCESS_SYNCHRONIZE, false, pid) } {
+        Ok(process) => process,
+        Err(e) => return e.code() != ERROR_INVALID_PARAMETER.to_hresult(),
+    };
+
+    let exited = unsafe { WaitForSingleObject(process, 0) } == WAIT_OBJECT_0;
+    _ = unsafe { CloseHandle(process) };
+
+    !exited
+}
@@ -0,0 +1,315 @@
+//! Scaling of shared frames to a monitor's export size, see `Monitor::export_size`, and
+//! conversion to the format the consumer asked for, see `FrameFormat`
+//!
+//! Frames are scaled and converted on the gpu with the D3D11 video processor, which every D3D11
+//! device has, so no shaders are needed. Frames keep being handed over as a whole, consumers get
+//! the export size and format instead of the mode's.
+//!
+//! This is also what keeps the copy and BGRA to NV12 conversion off the cpu at high resolutions and
+//! refresh rates: the frame never leaves the gpu until the consumer maps it, so there is no cpu
+//! copy or conversion path to vectorize.
+
+use std::mem::ManuallyDrop;
+
+use driver_ipc::{ExportSize, FrameFormat, ScaleFit};
+use windows::{
+    core::Interface,
+    Win32::{
+        Foundation::RECT,
+        Graphics::{
+            Direct3D11::{
+                ID3D11Texture2D, ID3D11VideoContext, ID3D11VideoDevice, ID3D11VideoProcessor,
+                ID3D11VideoProcessorEnumerator, ID3D11VideoProcessorInputView,
+                ID3D11VideoProcessorOutputView, D3D11_TEX2D_VPIV, D3D11_TEX2D_VPOV,
+                D3D11_TEXTURE2D_DESC, D3D11_VIDEO_COLOR, D3D11_VIDEO_COLOR_0,
+                D3D11_VIDEO_COLOR_RGBA, D3D11_VIDEO_FRAME_FORMAT_PROGRESSIVE,
+                D3D11_VIDEO_PROCESSOR_COLOR_SPACE, D3D11_VIDEO_PROCESSOR_CONTENT_DESC,
+                D3D11_VIDEO_PROCESSOR_FORMAT_SUPPORT_OUTPUT, D3D11_VIDEO_PROCESSOR_INPUT_VIEW_DESC,
+                D3D11_VIDEO_PROCESSOR_INPUT_VIEW_DESC_0, D3D11_VIDEO_PROCESSOR_OUTPUT_VIEW_DESC,
+                D3D11_VIDEO_PROCESSOR_OUTPUT_VIEW_DESC_0, D3D11_VIDEO_PROCESSOR_STREAM,
+                D3D11_VIDEO_USAGE_OPTIMAL_SPEED, D3D11_VPIV_DIMENSION_TEXTURE2D,
+                D3D11_VPOV_DIMENSION_TEXTURE2D,
+            },
+            Dxgi::{
+                Common::{DXGI_FORMAT, DXGI_FORMAT_NV12, DXGI_FORMAT_P010, DXGI_RATIONAL},
+                DXGI_ERROR_UNSUPPORTED,
+            },
+        },
+    },
+};
+
+use crate::direct_3d_device::Direct3DDevice;
+
+// Bars of letterboxed frames
+const BLACK: D3D11_VIDEO_COLOR = D3D11_VIDEO_COLOR {
+    Anonymous: D3D11_VIDEO_COLOR_0 {
+        RGBA: D3D11_VIDEO_COLOR_RGBA {
+            R: 0.0,
+            G: 0.0,
+            B: 0.0,
+            A: 1.0,
+        },
+    },
+};
+
+// Color space of converted yuv frames, BT.709 (`YCbCr_Matrix`) in limited range (`Nominal_Range`)
+const YUV_COLOR_SPACE: D3D11_VIDEO_PROCESSOR_COLOR_SPACE = D3D11_VIDEO_PROCESSOR_COLOR_SPACE {
+    _bitfield: 1 << 2 | 1 << 4,
+};
+
+/// Scales and converts the frames of one monitor, on one device
+pub struct Scaler {
+    size: Option<ExportSize>,
+    format: FrameFormat,
+    // created for the size and format of the last frame
+    processor: Option<VideoProcessor>,
+}
+
+struct VideoProcessor {
+    input: (u32, u32, DXGI_FORMAT),
+    video_device: ID3D11VideoDevice,
+    video_context: ID3D11VideoContext,
+    enumerator: ID3D11VideoProcessorEnumerator,
+    processor: ID3D11VideoProcessor,
+    // the texture frames were last scaled into, which rarely changes
+    output: Option<(ID3D11Texture2D, ID3D11VideoProcessorOutputView)>,
+}
+
+impl Scaler {
+    /// `None` if frames can be shared as they are, or `size` can't be scaled to
+    pub fn new(size: Option<ExportSize>, format: FrameFormat) -> Option<Self> {
+        let size = size.filter(|size| size.width > 0 && size.height > 0);
+
+        (size.is_some() || format != FrameFormat::Bgra).then_some(Self {
+            size,
+            format,
+            processor: None,
+        })
+    }
+
+    pub fn format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// Description of the texture frames of `desc` are scaled into
+    pub fn output_desc(&self, desc: &D3D11_TEXTURE2D_DESC) -> D3D11_TEXTURE2D_DESC {
+        let (width, height) = self
+            .size
+            .map_or((desc.Width, desc.Height), |size| (size.width, size.height));
+
+        let (format, width, height) = match self.format {
+            FrameFormat::Bgra => (desc.Format, width, height),
+            // 4:2:0 needs an even size
+            FrameFormat::Nv12 => (DXGI_FORMAT_NV12, width & !1, height & !1),
+            FrameFormat::P010 => (DXGI_FORMAT_P010, width & !1, height & !1),
+        };
+
+        D3D11_TEXTURE2D_DESC {
+            Width: width,
+            Height: height,
+            Format: format,
+            ..*desc
+        }
+    }
+
+    /// Scale `surface` into `target`, which must be described by [`Self::output_desc`]
+    pub fn scale(
+        &mut self,
+        device: &Direct3DDevice,
+        surface: &ID3D11Texture2D,
+        desc: &D3D11_TEXTURE2D_DESC,
+        target: &ID3D11Texture2D,
+    ) -> windows::core::Result<()> {
+        let input = (desc.Width, desc.Height, desc.Format);
+
+        let processor = match self.processor.take() {
+            Some(processor) if processor.input == input => processor,
+            // first frame, or the mode changed
+            _ => {
+                let fit = self.size.map_or(ScaleFit::Stretch, |size| size.fit);
+                VideoProcessor::new(device, input, &self.output_desc(desc), fit)?
+            }
+        };
+        let processor = self.processor.insert(processor);
+
+        processor.blt(surface, target)
+    }
+}
+
+impl VideoProcessor {
+    fn new(
+        device: &Direct3DDevice,
+        input: (u32, u32, DXGI_FORMAT),
+        output: &D3D11_TEXTURE2D_DESC,
+        fit: ScaleFit,
+    ) -> windows::core::Result<Self> {
+        let video_device = device.device.cast::<ID3D11VideoDevice>()?;
+        let video_context = device.device_context.cast::<ID3D11VideoContext>()?;
+
+        let content = D3D11_VIDEO_PROCESSOR_CONTENT_DESC {
+            InputFrameFormat: D3D11_VIDEO_FRAME_FORMAT_PROGRESSIVE,
+            InputFrameRate: DXGI_RATIONAL {
+                Numerator: 60,
+                Denominator: 1,
+            },
+            InputWidth: input.0,
+            InputHeight: input.1,
+            OutputFrameRate: DXGI_RATIONAL {
+                Numerator: 60,
+                Denominator: 1,
+            },
+            OutputWidth: output.Width,
+            OutputHeight: output.Height,
+            Usage: D3D11_VIDEO_USAGE_OPTIMAL_SPEED,
+        };
+
+        let enumerator = unsafe { video_device.CreateVideoProcessorEnumerator(&content)? };
+
+        // every device outputs bgra, the yuv formats depend on the gpu
+        let support = unsafe { enumerator.CheckVideoProcessorFormat(output.Format)? };
+        #[allow(clippy::cast_sign_loss)]
+        if support & D3D11_VIDEO_PROCESSOR_FORMAT_SUPPORT_OUTPUT.0 as u32 == 0 {
+            return Err(DXGI_ERROR_UNSUPPORTED.into());
+        }
+
+        let processor = unsafe { video_device.CreateVideoProcessor(&enumerator, 0)? };
+
+        let source = RECT {
+            left: 0,
+            top: 0,
+            right: i32::try_from(input.0).unwrap_or(i32::MAX),
+            bottom: i32::try_from(input.1).unwrap_or(i32::MAX),
+        };
+        let dest = dest_rect((input.0, input.1), (output.Width, output.Height), fit);
+
+        unsafe {
+            video_context.VideoProcessorSetStreamFrameFormat(
+                &processor,
+                0,
+                D3D11_VIDEO_FRAME_FORMAT_PROGRESSIVE,
+            );
+        }
+        unsafe {
+            video_context.VideoProcessorSetStreamSourceRect(&processor, 0, true, Some(&source));
+        }
+        unsafe { video_context.VideoProcessorSetStreamDestRect(&processor, 0, true, Some(&dest)) };
+        unsafe { video_context.VideoProcessorSetOutputBackgroundColor(&processor, false, &BLACK) };
+        if matches!(output.Format, DXGI_FORMAT_NV12 | DXGI_FORMAT_P010) {
+            unsafe {
+                video_context.VideoProcessorSetOutputColorSpace(&processor, &YUV_COLOR_SPACE);
+            };
+        }
+
+        Ok(Self {
+            input,
+            video_device,
+            video_context,
+            enumerator,
+            processor,
+            output: None,
+        })
+    }
+
+    fn blt(
+        &mut self,
+        surface: &ID3D11Texture2D,
+        target: &ID3D11Texture2D,
+    ) -> windows::core::Result<()> {
+        let output_view = match self.output.take() {
+            Some((texture, view)) if texture == *target => view,
+            _ => self.output_view(target)?,
+        };
+        self.output = Some((target.clone(), output_view.clone()));
+
+        // swap chain buffers take turns, so the view is made for every frame
+        let input_desc = D3D11_VIDEO_PROCESSOR_INPUT_VIEW_DESC {
+            FourCC: 0,
+            ViewDimension: D3D11_VPIV_DIMENSION_TEXTURE2D,
+            Anonymous: D3D11_VIDEO_PROCESSOR_INPUT_VIEW_DESC_0 {
+                Texture2D: D3D11_TEX2D_VPIV {
+                    MipSlice: 0,
+                    ArraySlice: 0,
+                },
+            },
+        };
+        let mut input_view = None::<ID3D11VideoProcessorInputView>;
+        unsafe {
+            self.video_device.CreateVideoProcessorInputView(
+                surface,
+                &self.enumerator,
+                &input_desc,
+                Some(&mut input_view),
+            )?;
+        }
+
+        let mut stream = D3D11_VIDEO_PROCESSOR_STREAM {
+            Enable: true.into(),
+            pInputSurface: ManuallyDrop::new(input_view),
+            ..Default::default()
+        };
+
+        let res = unsafe {
+            self.video_context.VideoProcessorBlt(
+                &self.processor,
+                &output_view,
+                0,
+                std::slice::from_ref(&stream),
+            )
+        };
+
+        // the stream doesn't release its view
+        unsafe { ManuallyDrop::drop(&mut stream.pInputSurface) };
+
+        res
+    }
+
+    fn output_view(
+        &self,
+        target: &ID3D11Texture2D,
+    ) -> windows::core::Result<ID3D11VideoProcessorOutputView> {
+        let desc = D3D11_VIDEO_PROCESSOR_OUTPUT_VIEW_DESC {
+            ViewDimension: D3D11_VPOV_DIMENSION_TEXTURE2D,
+            Anonymous: D3D11_VIDEO_PROCESSOR_OUTPUT_VIEW_DESC_0 {
+                Texture2D: D3D11_TEX2D_VPOV { MipSlice: 0 },
+            },
+        };
+
+        let mut view = None;
+        unsafe {
+            self.video_device.CreateVideoProcessorOutputView(
+                target,
+                &self.enumerator,
+                &desc,
+                Some(&mut view),
+            )?;
+        }
+
+        view.ok_or_else(windows::core::Error::from_win32)
+    }
+}
+
+/// Where frames of `input` size end up in the `output` size
+fn dest_rect((width, height): (u32, u32), output: (u32, u32), fit: ScaleFit) -> RECT {
+    let (out_width, out_height) = (u64::from(output.0), u64::from(output.1));
+    let (width, height) = (u64::from(width).max(1), u64::from(height).max(1));
+
+    let (scaled_width, scaled_height) = match fit {
+        ScaleFit::Stretch => (out_width, out_height),
+        // limited by the width if the frame is relatively wider than the export size
+        ScaleFit::Letterbox if out_width * height <= out_height * width => {
+            (out_width, height * out_width / width)
+        }
+        ScaleFit::Letterbox => (width * out_height / height, out_height),
+    };
+
+    let left = (out_width - scaled_width) / 2;
+    let top = (out_height - scaled_height) / 2;
+    let coord = |value: u64| i32::try_from(value).unwrap_or(i32::MAX);
+
+    RECT {
+        left: coord(left),
+        top: coord(top),
+        right: coord(left + scaled_width),
+        bottom: coord(top + scaled_height),
+    }
+}
@@ -0,0 +1,913 @@
+//! Zero-copy handoff of frames to other processes
+//!
+//! Every acquired frame is copied on the gpu into a texture shared through a named NT handle,
+//! which a consumer opens on its own D3D11/D3D12 device, so frames never go through system memory.
+//!
+//! Access is handed back and forth with the texture's keyed mutex: the driver acquires key `0`,
+//! copies the frame and releases key `1`. The consumer acquires key `1`, reads the frame and
+//! releases key `0`. Consumers that can't use keyed mutexes, e.g. on D3D12 or Vulkan, can ask for
+//! a shared fence instead, which the driver signals to an odd value after writing a frame, and the
+//! consumer to the next even one after reading it. While the consumer holds the texture, new frames
+//! are skipped instead of waited for.
+//!
+//! What happens to new frames while the consumer is behind is up to the monitor's
+//! `Backpressure`: they're dropped by default, can replace the frame the consumer hasn't read yet
+//! by taking it back with the consumer's key, or can be held until the consumer catches up.
+//!
+//! A consumer whose device is lost while it holds the texture gives it back through the keyed
+//! mutex. One that keeps a frame longer than the timeout it asked for is given up on, and frames
+//! are shared in a new texture, so a hung consumer doesn't stop sharing for good.
+//!
+//! Textures are pooled, see `pool`: a texture is only created for a size and format the monitor
+//! didn't have recently, and it keeps its name when it's reused.
+//!
+//! Monitors with an export size get their frames scaled to it instead of copied, and consumers
+//! can ask for frames in another format, e.g. NV12 for encoders, see `scale`.
+//!
+//! Consumers can also ask for the hardware cursor to be drawn into the frames, see `cursor`. That
+//! only works for bgra frames in the mode's size.
+//!
+//! Several consumers at once read from a ring of textures instead, see `ring`.
+
+use std::{
+    collections::HashMap,
+    ffi::c_void,
+    mem::size_of,
+    ptr::addr_of_mut,
+    sync::{
+        atomic::{AtomicBool, AtomicU32, AtomicU8, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{
+    Backpressure, CursorState, EventKind, ExportSize, FrameFormat, FrameSync, SharedFrame,
+    SyncOptions,
+};
+use log::warn;
+use windows::{
+    core::{Interface, HRESULT, HSTRING},
+    Win32::{
+        Foundation::{CloseHandle, GENERIC_ALL, HANDLE, S_OK},
+        Graphics::{
+            Direct3D11::{
+                ID3D11Device5, ID3D11DeviceContext4, ID3D11Fence, ID3D11Texture2D,
+                D3D11_BIND_RENDER_TARGET, D3D11_BIND_SHADER_RESOURCE, D3D11_FENCE_FLAG_SHARED,
+                D3D11_RESOURCE_MISC_SHARED, D3D11_RESOURCE_MISC_SHARED_KEYEDMUTEX,
+                D3D11_RESOURCE_MISC_SHARED_NTHANDLE, D3D11_TEXTURE2D_DESC, D3D11_USAGE_DEFAULT,
+            },
+            Dxgi::{
+                Common::{DXGI_FORMAT_B8G8R8A8_UNORM, DXGI_SAMPLE_DESC},
+                IDXGIKeyedMutex, IDXGIResource, IDXGIResource1, DXGI_SHARED_RESOURCE_READ,
+                DXGI_SHARED_RESOURCE_WRITE,
+            },
+        },
+        Security::{
+            InitializeSecurityDescriptor, SetSecurityDescriptorDacl, PSECURITY_DESCRIPTOR,
+            SECURITY_ATTRIBUTES, SECURITY_DESCRIPTOR,
+        },
+        System::SystemServices::SECURITY_DESCRIPTOR_REVISION,
+    },
+};
+
+use crate::{
+    cursor::CursorOverlay,
+    direct_3d_device::Direct3DDevice,
+    events,
+    pool::{TextureKey, TexturePool},
+    ring::FrameRing,
+    scale::Scaler,
+    stats::FrameStats,
+};
+
+// Formats by their index in a `ConsumerSlot`
+const FORMATS: [FrameFormat; 3] = [FrameFormat::Bgra, FrameFormat::Nv12, FrameFormat::P010];
+
+// Key the driver acquires the texture with, and the consumer releases it with
+const KEY_DRIVER: u64 = 0;
+// Key the consumer acquires the texture with, and the driver releases it with
+const KEY_CONSUMER: u64 = 1;
+
+// `AcquireSync` got the texture because the consumer's device went away while holding it
+pub const WAIT_ABANDONED: HRESULT = HRESULT(0x80);
+
+// Textures get a new name every time they're created, since a name stays taken as long as a
+// consumer still has the old texture open
+static GENERATION: AtomicU32 = AtomicU32::new(0);
+
+// Currently shared texture of each monitor, for the ipc
+static SHARED: OnceLock<Mutex<HashMap<u32, SharedFrame>>> = OnceLock::new();
+
+fn shared() -> &'static Mutex<HashMap<u32, SharedFrame>> {
+    SHARED.get_or_init(Mutex::default)
+}
+
+/// The texture currently shared for a monitor, if any
+pub fn get(monitor_id: u32) -> Option<SharedFrame> {
+    shared().lock().ok()?.get(&monitor_id).cloned()
+}
+
+/// Number for the names of newly created shared objects
+pub fn next_generation() -> u32 {
+    GENERATION.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Security attributes that allow anyone access, the consumer usually runs as a different user
+/// than the driver
+pub fn everyone(sd: &mut SECURITY_DESCRIPTOR) -> windows::core::Result<SECURITY_ATTRIBUTES> {
+    unsafe {
+        InitializeSecurityDescriptor(
+            PSECURITY_DESCRIPTOR(addr_of_mut!(*sd).cast()),
+            SECURITY_DESCRIPTOR_REVISION,
+        )?;
+    }
+    unsafe {
+        SetSecurityDescriptorDacl(
+            PSECURITY_DESCRIPTOR(addr_of_mut!(*sd).cast()),
+            true,
+            None,
+            false,
+        )?;
+    }
+
+    Ok(SECURITY_ATTRIBUTES {
+        #[allow(clippy::cast_possible_truncation)]
+        nLength: size_of::<SECURITY_ATTRIBUTES>() as u32,
+        lpSecurityDescriptor: addr_of_mut!(*sd).cast(),
+        bInheritHandle: false.into(),
+    })
+}
+
+/// Format and synchronization the consumer of a monitor asked for, whether any consumer asked
+/// for the frame ring, and the monitor's backpressure policy, set over ipc
+///
+/// Shared with the processing thread, which checks it every frame without taking a lock
+#[derive(Debug, Default)]
+pub struct ConsumerSlot {
+    format: AtomicU8,
+    fence: AtomicBool,
+    timeout_ms: AtomicU32,
+    ring: AtomicBool,
+    // 0 to drop the newest frame, 1 the oldest, 2 to block for `block_ms`
+    backpressure: AtomicU8,
+    block_ms: AtomicU32,
+}
+
+impl ConsumerSlot {
+    pub fn set_format(&self, format: FrameFormat) {
+        let index = FORMATS
+            .iter()
+            .position(|&f| f == format)
+            .unwrap_or_default();
+
+        // there are only a few formats
+        #[allow(clippy::cast_possible_truncation)]
+        self.format.store(index as u8, Ordering::Relaxed);
+    }
+
+    pub fn format(&self) -> FrameFormat {
+        let index = usize::from(self.format.load(Ordering::Relaxed));
+        FORMATS.get(index).copied().unwrap_or_default()
+    }
+
+    pub fn set_sync(&self, options: SyncOptions) {
+        self.fence
+            .store(options.sync == FrameSync::Fence, Ordering::Relaxed);
+        self.timeout_ms.store(options.timeout_ms, Ordering::Relaxed);
+    }
+
+    pub fn sync(&self) -> SyncOptions {
+        SyncOptions {
+            sync: if self.fence.load(Ordering::Relaxed) {
+                FrameSync::Fence
+            } else {
+                FrameSync::KeyedMutex
+            },
+            timeout_ms: self.timeout_ms.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Share frames in a ring too from now on, see `ring`
+    pub fn request_ring(&self) {
+        self.ring.store(true, Ordering::Relaxed);
+    }
+
+    pub fn ring(&self) -> bool {
+        self.ring.load(Ordering::Relaxed)
+    }
+
+    pub fn set_backpressure(&self, backpressure: Backpressure) {
+        let (index, timeout_ms) = match backpressure {
+            Backpressure::DropNewest => (0, 0),
+            Backpressure::DropOldest => (1, 0),
+            Backpressure::Block { timeout_ms } => (2, timeout_ms),
+        };
+
+        self.block_ms.store(timeout_ms, Ordering::Relaxed);
+        self.backpressure.store(index, Ordering::Relaxed);
+    }
+
+    pub fn backpressure(&self) -> Backpressure {
+        match self.backpressure.load(Ordering::Relaxed) {
+            1 => Backpressure::DropOldest,
+            2 => Backpressure::Block {
+                timeout_ms: self.block_ms.load(Ordering::Relaxed),
+            },
+            _ => Backpressure::DropNewest,
+        }
+    }
+}
+
+/// What became of a frame written with `Texture::write`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Written {
+    // the consumer was behind, so the frame was dropped
+    Skipped,
+    Handed,
+    // handed over in place of a frame the consumer hadn't read, see `Backpressure::DropOldest`
+    Replaced,
+}
+
+struct Texture {
+    texture: ID3D11Texture2D,
+    handover: Handover,
+    handle: HANDLE,
+    desc: D3D11_TEXTURE2D_DESC,
+    name: String,
+    monitor_id: u32,
+    // when the last frame was handed to the consumer, until the driver has the texture again
+    handed_over: Option<Instant>,
+    // whether a consumer gave the texture back before, one nobody took a frame of isn't abandoned
+    consumed: bool,
+}
+
+// How the texture is handed back and forth, see `FrameSync`
+enum Handover {
+    KeyedMutex(IDXGIKeyedMutex),
+    Fence {
+        fence: ID3D11Fence,
+        context: ID3D11DeviceContext4,
+        handle: HANDLE,
+        name: String,
+        // the driver's turn once the fence reached this, the value before is the consumer's
+        turn: u64,
+    },
+}
+
+impl Texture {
+    /// Write to the texture once the consumer gave it back, then hand it to the consumer
+    ///
+    /// Does nothing if the consumer is still behind, see [`Backpressure`]
+    fn write(
+        &mut self,
+        backpressure: Backpressure,
+        write: impl FnOnce(&ID3D11Texture2D, &D3D11_TEXTURE2D_DESC),
+    ) -> windows::core::Result<Written> {
+        let Some(replaced) = self.acquire(backpressure) else {
+            return Ok(Written::Skipped);
+        };
+
+        write(&self.texture, &self.desc);
+
+        match &mut self.handover {
+            Handover::KeyedMutex(mutex) => unsafe { mutex.ReleaseSync(KEY_CONSUMER)? },
+            Handover::Fence {
+                fence,
+                context,
+                turn,
+                ..
+            } => {
+                unsafe { context.Signal(&*fence, *turn + 1)? };
+                *turn += 2;
+            }
+        }
+        self.handed_over = Some(Instant::now());
+
+        Ok(if replaced {
+            Written::Replaced
+        } else {
+            Written::Handed
+        })
+    }
+
+    // `None` if the consumer still has the texture, otherwise whether the driver took back a
+    // frame the consumer hadn't read
+    fn acquire(&mut self, backpressure: Backpressure) -> Option<bool> {
+        // don't wait for the consumer unless asked to, the next frame is coming anyways
+        let timeout_ms = match backpressure {
+            Backpressure::Block { timeout_ms } => timeout_ms,
+            Backpressure::DropNewest | Backpressure::DropOldest => 0,
+        };
+
+        let acquired = match &self.handover {
+            Handover::KeyedMutex(mutex) => {
+                if self.acquire_key(mutex, KEY_DRIVER, timeout_ms) {
+                    Some(false)
+                } else if backpressure == Backpressure::DropOldest
+                    && self.acquire_key(mutex, KEY_CONSUMER, 0)
+                {
+                    // the consumer never took the frame, so it isn't holding up anything
+                    self.handed_over = None;
+                    Some(true)
+                } else {
+                    None
+                }
+            }
+
+            Handover::Fence { fence, turn, .. } => {
+                let deadline = Instant::now() + Duration::from_millis(timeout_ms.into());
+                loop {
+                    let completed = unsafe { fence.GetCompletedValue() };
+                    if completed >= *turn {
+                        break Some(false);
+                    }
+                    if Instant::now() >= deadline {
+                        break None;
+                    }
+
+                    thread::sleep(Duration::from_millis(1));
+                }
+            }
+        };
+
+        if acquired.is_some() && self.handed_over.take().is_some() {
+            self.consumed = true;
+        }
+
+        acquired
+    }
+
+    // Whether the texture's keyed mutex was acquired with `key` within `timeout_ms`
+    fn acquire_key(&self, mutex: &IDXGIKeyedMutex, key: u64, timeout_ms: u32) -> bool {
+        let hr = unsafe {
+            (Interface::vtable(mutex).AcquireSync)(Interface::as_raw(mutex), key, timeout_ms)
+        };
+        if hr == WAIT_ABANDONED {
+            abandoned(self.monitor_id, None);
+        }
+
+        hr == S_OK || hr == WAIT_ABANDONED
+    }
+
+    /// How long the consumer has held the texture, if that's longer than `timeout`
+    fn abandoned(&mut self, timeout: Duration) -> Option<Duration> {
+        let held = self.handed_over?.elapsed();
+        if !self.consumed || timeout.is_zero() || held <= timeout {
+            return None;
+        }
+
+        // the consumer may have given it back while there were no new frames
+        if self.acquire(Backpressure::DropNewest).is_some() {
+            // keep it until the next frame
+            if let Handover::KeyedMutex(mutex) = &self.handover {
+                _ = unsafe { mutex.ReleaseSync(KEY_DRIVER) };
+            }
+
+            return None;
+        }
+
+        Some(held)
+    }
+}
+
+impl Drop for Texture {
+    fn drop(&mut self) {
+        // consumers keep their own handles, so the texture lives on until they close them too
+        _ = unsafe { CloseHandle(self.handle) };
+
+        if let Handover::Fence { handle, .. } = self.handover {
+            _ = unsafe { CloseHandle(handle) };
+        }
+    }
+}
+
+// The consumer of a monitor's shared frames went away while holding one, for `held` if it was
+// given up on
+fn abandoned(monitor_id: u32, held: Option<Duration>) {
+    match held {
+        Some(held) => warn!(
+            "Consumer of monitor {monitor_id} held a shared frame for {held:?}, sharing frames in \
+             a new texture"
+        ),
+        None => warn!("Consumer of monitor {monitor_id} went away while holding a shared frame"),
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    events::push(EventKind::ConsumerAbandoned {
+        id: monitor_id,
+        held_ms: held.map(|held| held.as_millis() as u64),
+    });
+}
+
+// Write a frame with `produce`, straight into the shared texture, or with a ring into the ring's
+// own texture first, which is then copied into the shared texture and the ring
+fn write_frame(
+    texture: &mut Texture,
+    ring: Option<&mut FrameRing>,
+    device: &Direct3DDevice,
+    backpressure: Backpressure,
+    produce: impl FnOnce(&ID3D11Texture2D, &D3D11_TEXTURE2D_DESC),
+) -> windows::core::Result<Written> {
+    let Some(ring) = ring else {
+        return texture.write(backpressure, produce);
+    };
+
+    produce(ring.frame(), &texture.desc);
+    let written = texture.write(backpressure, |shared, _| {
+        unsafe { device.device_context.CopyResource(shared, ring.frame()) };
+    })?;
+    ring.push(device, backpressure)?;
+
+    Ok(written)
+}
+
+// How textures handed over with `sync` are shared
+#[allow(clippy::cast_sign_loss)]
+fn misc_flags(sync: FrameSync) -> u32 {
+    let handover = match sync {
+        FrameSync::KeyedMutex => D3D11_RESOURCE_MISC_SHARED_KEYEDMUTEX.0,
+        // the fence is shared on its own
+        FrameSync::Fence => D3D11_RESOURCE_MISC_SHARED.0,
+    };
+
+    (D3D11_RESOURCE_MISC_SHARED_NTHANDLE.0 | handover) as u32
+}
+
+/// Shares the frames of one monitor, on one device
+pub struct FrameExporter {
+    monitor_id: u32,
+    stats: Arc<FrameStats>,
+    texture: Option<Texture>,
+    pool: TexturePool<Texture>,
+    export_size: Option<ExportSize>,
+    consumer: Arc<ConsumerSlot>,
+    scaler: Option<Scaler>,
+    cursor: CursorOverlay,
+    ring: Option<FrameRing>,
+}
+
+impl FrameExporter {
+    pub fn new(
+        monitor_id: u32,
+        stats: Arc<FrameStats>,
+        export_size: Option<ExportSize>,
+        consumer: Arc<ConsumerSlot>,
+    ) -> Self {
+        Self {
+            monitor_id,
+            stats,
+            texture: None,
+            pool: TexturePool::default(),
+            scaler: Scaler::new(export_size, consumer.format()),
+            export_size,
+            consumer,
+            cursor: CursorOverlay::default(),
+            ring: None,
+        }
+    }
+
+    /// Copy the acquired surface into the shared texture, scaled to the export size and converted
+    /// to the requested format if needed, with `cursor` drawn into it
+    ///
+    /// Does nothing if the consumer still holds the previous frame
+    ///
+    /// # Safety
+    ///
+    /// `surface` must be the surface of a frame that is still acquired
+    pub unsafe fn export(
+        &mut self,
+        device: &Direct3DDevice,
+        surface: *mut c_void,
+        cursor: Option<&CursorState>,
+    ) -> windows::core::Result<()> {
+        let Some(surface) = (unsafe { IDXGIResource::from_raw_borrowed(&surface) }) else {
+            return Ok(());
+        };
+        let surface = surface.cast::<ID3D11Texture2D>()?;
+
+        let mut desc = D3D11_TEXTURE2D_DESC::default();
+        unsafe { surface.GetDesc(&mut desc) };
+
+        let target = self.target_desc(&desc);
+        self.prepare(device, &target)?;
+        let Some(texture) = &mut self.texture else {
+            return Ok(());
+        };
+
+        // the cursor is only drawn over bgra pixels
+        let cursor = cursor.filter(|_| texture.desc.Format == DXGI_FORMAT_B8G8R8A8_UNORM);
+
+        let ring = self.ring.as_mut();
+        let backpressure = self.consumer.backpressure();
+        let mut produced = Ok(());
+        let written = write_frame(
+            texture,
+            ring,
+            device,
+            backpressure,
+            |shared, shared_desc| {
+                // the frame replaces what the cursor was drawn over
+                self.cursor.reset();
+
+                produced = if let Some(scaler) = &mut self.scaler {
+                    scaler.scale(device, &surface, &desc, shared)
+                } else {
+                    unsafe { device.device_context.CopyResource(shared, &surface) };
+                    self.cursor.draw(device, shared, shared_desc, cursor)
+                };
+            },
+        )?;
+
+        if written != Written::Handed {
+            self.stats.shared_frame_dropped();
+        }
+
+        produced
+    }
+
+    /// Draw `cursor` into the shared texture again, or take it out if it's `None`, for cursor
+    /// changes between frames
+    ///
+    /// Returns `false` if the consumer is still behind, so it should be tried again
+    pub fn draw_cursor(
+        &mut self,
+        device: &Direct3DDevice,
+        cursor: Option<&CursorState>,
+    ) -> windows::core::Result<bool> {
+        // scaled and converted frames never get a cursor
+        let Some(texture) = self.texture.as_mut().filter(|texture| {
+            self.scaler.is_none() && texture.desc.Format == DXGI_FORMAT_B8G8R8A8_UNORM
+        }) else {
+            return Ok(true);
+        };
+
+        let ring = self.ring.as_mut();
+        let backpressure = self.consumer.backpressure();
+        let mut drawn = Ok(());
+        let written = write_frame(
+            texture,
+            ring,
+            device,
+            backpressure,
+            |shared, shared_desc| {
+                drawn = self.cursor.draw(device, shared, shared_desc, cursor);
+            },
+        )?;
+
+        drawn.map(|()| written != Written::Skipped)
+    }
+
+    /// Make sure the shared texture matches the acquired surface, without copying it
+    ///
+    /// # Safety
+    ///
+    /// `surface` must be the surface of a frame that is still acquired
+    pub unsafe fn prepare_for(
+        &mut self,
+        device: &Direct3DDevice,
+        surface: *mut c_void,
+    ) -> windows::core::Result<()> {
+        let Some(surface) = (unsafe { IDXGIResource::from_raw_borrowed(&surface) }) else {
+            return Ok(());
+        };
+        let surface = surface.cast::<ID3D11Texture2D>()?;
+
+        let mut desc = D3D11_TEXTURE2D_DESC::default();
+        unsafe { surface.GetDesc(&mut desc) };
+
+        let target = self.target_desc(&desc);
+        self.prepare(device, &target)?;
+        Ok(())
+    }
+
+    // what the shared texture for frames described by `desc` looks like
+    fn target_desc(&mut self, desc: &D3D11_TEXTURE2D_DESC) -> D3D11_TEXTURE2D_DESC {
+        // the consumer may have asked for another format or sync since the last frame
+        let format = self.consumer.format();
+        if self
+            .scaler
+            .as_ref()
+            .map_or(FrameFormat::Bgra, Scaler::format)
+            != format
+        {
+            self.scaler = Scaler::new(self.export_size, format);
+        }
+
+        let target = self
+            .scaler
+            .as_ref()
+            .map_or(*desc, |scaler| scaler.output_desc(desc));
+
+        D3D11_TEXTURE2D_DESC {
+            MiscFlags: misc_flags(self.consumer.sync().sync),
+            ..target
+        }
+    }
+
+    /// Give up on a consumer that kept a frame longer than its timeout, see
+    /// [`SyncOptions::timeout_ms`], and share frames in a new texture
+    ///
+    /// Checked by the processing loop, so that also happens while the desktop doesn't change
+    pub fn check_consumer(&mut self, device: &Direct3DDevice) -> windows::core::Result<()> {
+        let timeout = Duration::from_millis(self.consumer.sync().timeout_ms.into());
+        let Some(held) = self
+            .texture
+            .as_mut()
+            .and_then(|texture| texture.abandoned(timeout))
+        else {
+            return Ok(());
+        };
+
+        abandoned(self.monitor_id, Some(held));
+
+        // not pooled, the consumer may never give it back
+        let desc = self.texture.take().map(|texture| texture.desc);
+        if let Some(desc) = desc {
+            self.prepare(device, &desc)?;
+        }
+
+        Ok(())
+    }
+
+    /// Set up the frame ring once a consumer asks for it, and update the stats of its consumers
+    ///
+    /// Checked by the processing loop, so the ring also comes up while the desktop doesn't change
+    pub fn check_ring(&mut self, device: &Direct3DDevice) -> windows::core::Result<()> {
+        self.prepare_ring(device)?;
+
+        if let Some(consumers) = self.ring.as_mut().and_then(FrameRing::consumers) {
+            self.stats.set_consumers(consumers);
+        }
+
+        Ok(())
+    }
+
+    /// Size of the shared texture, if it exists and takes bgra pixels
+    pub fn bgra_size(&self) -> Option<(u32, u32)> {
+        self.texture
+            .as_ref()
+            .filter(|texture| texture.desc.Format == DXGI_FORMAT_B8G8R8A8_UNORM)
+            .map(|texture| (texture.desc.Width, texture.desc.Height))
+    }
+
+    /// Share generated pixels instead of a frame, see [`Self::bgra_size`]
+    ///
+    /// `pixels` are tightly packed bgra rows of the texture's size
+    pub fn export_pixels(
+        &mut self,
+        device: &Direct3DDevice,
+        pixels: &[u8],
+    ) -> windows::core::Result<()> {
+        let Some(texture) = &mut self.texture else {
+            return Ok(());
+        };
+        debug_assert_eq!(
+            pixels.len(),
+            texture.desc.Width as usize * texture.desc.Height as usize * 4
+        );
+
+        let ring = self.ring.as_mut();
+        let backpressure = self.consumer.backpressure();
+        let written = write_frame(
+            texture,
+            ring,
+            device,
+            backpressure,
+            |shared, shared_desc| {
+                self.cursor.reset();
+
+                unsafe {
+                    device.device_context.UpdateSubresource(
+                        shared,
+                        0,
+                        None,
+                        pixels.as_ptr().cast(),
+                        shared_desc.Width * 4,
+                        0,
+                    );
+                }
+            },
+        )?;
+
+        if written != Written::Handed {
+            self.stats.shared_frame_dropped();
+        }
+
+        Ok(())
+    }
+
+    fn prepare(
+        &mut self,
+        device: &Direct3DDevice,
+        desc: &D3D11_TEXTURE2D_DESC,
+    ) -> windows::core::Result<()> {
+        let key = TextureKey::from(desc);
+
+        let texture = match self.texture.take() {
+            Some(texture) if TextureKey::from(&texture.desc) == key => texture,
+
+            // first frame, or the mode changed
+            current => {
+                // what the cursor was drawn over belongs to the old texture
+                self.cursor.reset();
+
+                if let Some(current) = current {
+                    self.pool.put(TextureKey::from(&current.desc), current);
+                }
+
+                if let Some(texture) = self.pool.take(key) {
+                    self.stats.pool().reused();
+                    self.publish(&texture);
+                    texture
+                } else {
+                    let texture = self.create(device, desc)?;
+                    self.stats.pool().allocated();
+                    self.publish(&texture);
+                    texture
+                }
+            }
+        };
+
+        self.texture = Some(texture);
+        self.prepare_ring(device)
+    }
+
+    // (re-)create the ring for the shared texture's frames, if a consumer asked for it
+    fn prepare_ring(&mut self, device: &Direct3DDevice) -> windows::core::Result<()> {
+        let Some(texture) = &self.texture else {
+            return Ok(());
+        };
+        if !self.consumer.ring()
+            || self
+                .ring
+                .as_ref()
+                .is_some_and(|ring| ring.matches(&texture.desc))
+        {
+            return Ok(());
+        }
+
+        // the cursor is drawn into the ring's texture from now on
+        self.cursor.reset();
+
+        self.ring = None;
+        self.ring = Some(FrameRing::new(device, self.monitor_id, &texture.desc)?);
+
+        Ok(())
+    }
+
+    /// Make `texture` the one the ipc hands out
+    fn publish(&self, texture: &Texture) {
+        match shared().lock() {
+            Ok(mut shared) => {
+                shared.insert(
+                    self.monitor_id,
+                    SharedFrame {
+                        name: texture.name.clone(),
+                        width: texture.desc.Width,
+                        height: texture.desc.Height,
+                        format: u32::try_from(texture.desc.Format.0).unwrap_or_default(),
+                        sync: match texture.handover {
+                            Handover::KeyedMutex(_) => FrameSync::KeyedMutex,
+                            Handover::Fence { .. } => FrameSync::Fence,
+                        },
+                        fence: match &texture.handover {
+                            Handover::KeyedMutex(_) => None,
+                            Handover::Fence { name, .. } => Some(name.clone()),
+                        },
+                        backpressure: self.consumer.backpressure(),
+                    },
+                );
+            }
+
+            Err(e) => warn!("Failed to publish shared frame: {e}"),
+        }
+    }
+
+    fn create(
+        &self,
+        device: &Direct3DDevice,
+        surface_desc: &D3D11_TEXTURE2D_DESC,
+    ) -> windows::core::Result<Texture> {
+        #[allow(clippy::cast_sign_loss)]
+        let desc = D3D11_TEXTURE2D_DESC {
+            Width: surface_desc.Width,
+            Height: surface_desc.Height,
+            MipLevels: 1,
+            ArraySize: 1,
+            Format: surface_desc.Format,
+            SampleDesc: DXGI_SAMPLE_DESC {
+                Count: 1,
+                Quality: 0,
+            },
+            Usage: D3D11_USAGE_DEFAULT,
+            BindFlags: (D3D11_BIND_SHADER_RESOURCE.0 | D3D11_BIND_RENDER_TARGET.0) as u32,
+            CPUAccessFlags: 0,
+            // see `misc_flags`
+            MiscFlags: surface_desc.MiscFlags,
+        };
+
+        let mut texture = None;
+        unsafe {
+            device
+                .device
+                .CreateTexture2D(&desc, None, Some(&mut texture))?;
+        }
+        let texture = texture.ok_or_else(windows::core::Error::from_win32)?;
+
+        let generation = next_generation();
+        let name = format!(
+            "Global\\VirtualDisplayDriver-Frame-{}-{generation}",
+            self.monitor_id
+        );
+
+        let mut sd = SECURITY_DESCRIPTOR::default();
+        let sa = everyone(&mut sd)?;
+
+        let handle = unsafe {
+            texture.cast::<IDXGIResource1>()?.CreateSharedHandle(
+                Some(&sa),
+                DXGI_SHARED_RESOURCE_READ | DXGI_SHARED_RESOURCE_WRITE,
+                &HSTRING::from(&name),
+            )?
+        };
+
+        let handover = if desc.MiscFlags == misc_flags(FrameSync::Fence) {
+            let name = format!(
+                "Global\\VirtualDisplayDriver-Fence-{}-{generation}",
+                self.monitor_id
+            );
+            let fence = create_fence(device, &sa, &name);
+
+            // the texture's handle isn't closed yet
+            match fence {
+                Ok(fence) => fence,
+                Err(e) => {
+                    _ = unsafe { CloseHandle(handle) };
+                    return Err(e);
+                }
+            }
+        } else {
+            Handover::KeyedMutex(texture.cast::<IDXGIKeyedMutex>()?)
+        };
+
+        Ok(Texture {
+            texture,
+            handover,
+            handle,
+            desc,
+            name,
+            monitor_id: self.monitor_id,
+            handed_over: None,
+            consumed: false,
+        })
+    }
+}
+
+// A shared fence for `Handover::Fence`, which needs a D3D11.4 device
+fn create_fence(
+    device: &Direct3DDevice,
+    sa: &SECURITY_ATTRIBUTES,
+    name: &str,
+) -> windows::core::Result<Handover> {
+    let mut fence = None::<ID3D11Fence>;
+    unsafe {
+        device.device.cast::<ID3D11Device5>()?.CreateFence(
+            0,
+            D3D11_FENCE_FLAG_SHARED,
+            &mut fence,
+        )?;
+    }
+    let fence = fence.ok_or_else(windows::core::Error::from_win32)?;
+    let context = device.device_context.cast::<ID3D11DeviceContext4>()?;
+
+    let handle =
+        unsafe { fence.CreateSharedHandle(Some(sa), GENERIC_ALL.0, &HSTRING::from(name))? };
+
+    Ok(Handover::Fence {
+        fence,
+        context,
+        handle,
+        name: name.to_owned(),
+        turn: 0,
+    })
+}
+
+impl Drop for FrameExporter {
+    fn drop(&mut self) {
+        let Some(texture) = &self.texture else {
+            return;
+        };
+
+        // a new swap chain may have published its texture already
+        if let Ok(mut shared) = shared().lock() {
+            if shared
+                .get(&self.monitor_id)
+                .is_some_and(|frame| frame.name == texture.name)
+            {
+                shared.remove(&self.monitor_id);
+            }
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! Changes to the monitor list that need os calls
+//!
+//! The os calls back into the driver while it handles some of its calls, e.g. it asks for the modes
+//! of a monitor while it arrives, and those callbacks lock the list too. So os calls are never made
+//! while holding the lock: these functions only change the list, and hand the monitor objects to
+//! depart back to the caller, to depart them once it's unlocked.
+
+use std::ptr::NonNull;
+
+use driver_ipc::{DisplayTarget, Mode};
+use log::error;
+use wdf_umdf::IddCxMonitorDeparture;
+use wdf_umdf_sys::IDDCX_MONITOR__;
+
+use crate::{ipc::MonitorObject, sync::Mutex, trace};
+
+pub type Monitors = Mutex<Vec<MonitorObject>>;
+
+/// The os object of an arrived monitor, taken out of the list
+#[derive(Debug)]
+pub struct Departure {
+    pub id: u32,
+    pub object: NonNull<IDDCX_MONITOR__>,
+}
+
+impl Departure {
+    /// Tell the os the monitor was unplugged, after which it deletes the object
+    pub fn depart(self) {
+        match unsafe { IddCxMonitorDeparture(self.object.as_ptr()) } {
+            Ok(status) => trace::monitor_event("Departure", self.id, status),
+            Err(e) => error!("Failed to depart monitor {}: {e:?}", self.id),
+        }
+    }
+}
+
+/// New modes for an arrived monitor, which the os updates in place, without it departing
+///
+/// Recorded while the list is locked, and applied after it's unlocked, since the os asks for the
+/// modes of the monitor again while it updates them.
+#[derive(Debug)]
+pub struct ModeUpdate {
+    pub id: u32,
+    pub object: NonNull<IDDCX_MONITOR__>,
+    pub modes: Vec<Mode>,
+    /// The preferred mode changed too, which the os only switches to when its display config is
+    /// updated
+    pub preferred_changed: bool,
+}
+
+/// Take the os object out of a monitor in the locked list, to depart it once it's unlocked
+pub fn departure(monitor: &mut MonitorObject) -> Option<Departure> {
+    let object = monitor.monitor_object.take()?;
+    monitor.monitor.target = None;
+
+    Some(Departure {
+        id: monitor.monitor.id,
+        object,
+    })
+}
+
+/// Monitors taken out of the list
+#[derive(Debug, Default)]
+pub struct Removed {
+    pub ids: Vec<u32>,
+    pub departures: Vec<Departure>,
+}
+
+/// Remove the monitors `remove` returns true for
+///
+/// `removed_id` is called with the id of each of them while the list is still locked, e.g. to
+/// record events in the order the list changed.
+pub fn remove(
+    monitors: &Monitors,
+    remove: impl Fn(&MonitorObject) -> bool,
+    mut removed_id: impl FnMut(u32),
+) -> Removed {
+    let mut lock = monitors.lock().unwrap();
+
+    let mut removed = Removed::default();
+    lock.retain_mut(|monitor| {
+        if !remove(monitor) {
+            return true;
+        }
+
+        let id = monitor.monitor.id;
+        removed.departures.extend(departure(monitor));
+        removed.ids.push(id);
+        removed_id(id);
+
+        false
+    });
+
+    removed
+}
+
+/// Take the os object of a monitor, which stays in the list so it can arrive again
+pub fn take_object(monitors: &Monitors, id: u32) -> Option<Departure> {
+    let mut lock = monitors.lock().unwrap();
+
+    let monitor = lock.iter_mut().find(|monitor| monitor.monitor.id == id)?;
+    departure(monitor)
+}
+
+/// Take the os object of a monitor whose modes couldn't be updated in place, to re-plug it with
+/// them instead
+///
+/// Returns `None` if the monitor was removed or departed since the update was recorded, in which
+/// case there's nothing to re-plug.
+pub fn take_failed_update(monitors: &Monitors, update: &ModeUpdate) -> Option<Departure> {
+    let mut lock = monitors.lock().unwrap();
+
+    let monitor = lock
+        .iter_mut()
+        .find(|monitor| monitor.monitor_object == Some(update.object))?;
+    monitor.arrived_modes = monitor.monitor.modes.clone();
+    departure(monitor)
+}
+
+/// Take the os objects of all monitors, e.g. before the device sleeps
+pub fn take_objects(monitors: &Monitors) -> Vec<Departure> {
+    let mut lock = monitors.lock().unwrap();
+
+    lock.iter_mut().filter_map(departure).collect()
+}
+
+/// Store the os object of a monitor that was just created, and the edid it was created with,
+/// before it arrives
+///
+/// The list isn't locked while the os creates the object, so another thread can remove the
+/// monitor, unplug it or create it too in the meantime. Then the object is handed back, for the
+/// caller to delete it instead of arriving it.
+pub fn attach(
+    monitors: &Monitors,
+    id: u32,
+    object: NonNull<IDDCX_MONITOR__>,
+    edid: Vec<u8>,
+) -> Result<(), NonNull<IDDCX_MONITOR__>> {
+    let mut lock = monitors.lock().unwrap();
+
+    let monitor = lock.iter_mut().find(|monitor| {
+        monitor.monitor.id == id && monitor.plugged && monitor.monitor_object.is_none()
+    });
+
+    match monitor {
+        Some(monitor) => {
+            monitor.monitor_object = Some(object);
+            monitor.edid = Some(edid);
+            Ok(())
+        }
+        None => Err(object),
+    }
+}
+
+/// Store the display the os shows a monitor as, once it arrived. Nothing changes if the monitor
+/// departed in the meantime
+pub fn arrived(monitors: &Monitors, object: NonNull<IDDCX_MONITOR__>, target: DisplayTarget) {
+    let mut lock = monitors.lock().unwrap();
+
+    if let Some(monitor) = lock
+        .iter_mut()
+        .find(|monitor| monitor.monitor_object == Some(object))
+    {
+        monitor.monitor.target = Some(target);
+    }
+}
+
+#[cfg(all(test, loom))]
+mod tests {
+    use std::ptr::NonNull;
+
+    use driver_ipc::{Mode, Monitor};
+    use loom::{sync::Arc, thread};
+    use wdf_umdf_sys::IDDCX_MONITOR__;
+
+    use super::*;
+
+    fn monitors(ids: &[u32]) -> Arc<Monitors> {
+        let monitors = ids
+            .iter()
+            .map(|&id| MonitorObject {
+                monitor_object: None,
+                monitor: Monitor::new(
+                    id,
+                    vec![Mode {
+                        width: 1920,
+                        height: 1080,
+                        refresh_rates: vec![60],
+                    }],
+                ),
+                stats: std::sync::Arc::default(),
+                limit: std::sync::Arc::default(),
+                pattern: std::sync::Arc::default(),
+                consumer: std::sync::Arc::default(),
+                arrived_modes: Vec::new(),
+                edid: None,
+                plugged: true,
+            })
+            .collect();
+
+        Arc::new(Mutex::new(monitors))
+    }
+
+    /// A fake os object, which is never dereferenced
+    fn object(n: usize) -> NonNull<IDDCX_MONITOR__> {
+        NonNull::new((n * 8) as *mut IDDCX_MONITOR__).unwrap()
+    }
+
+    fn departed(departures: &[Departure]) -> Vec<NonNull<IDDCX_MONITOR__>> {
+        departures
+            .iter()
+            .map(|departure| departure.object)
+            .collect()
+    }
+
+    fn object_of(monitors: &Monitors, id: u32) -> Option<NonNull<IDDCX_MONITOR__>> {
+        let lock = monitors.lock().unwrap();
+        lock.iter()
+            .find(|monitor| monitor.monitor.id == id)
+            .and_then(|monitor| monitor.monitor_object)
+    }
+
+    // a monitor is removed while another thread is creating its object
+    #[test]
+    fn remove_while_creating() {
+        loom::model(|| {
+            let monitors = monitors(&[0, 1]);
+
+            let creating = {
+                let monitors = monitors.clone();
+                thread::spawn(move || attach(&monitors, 0, object(1), Vec::new()))
+            };
+            let removed = remove(&monitors, |monitor| monitor.monitor.id == 0, |_| ());
+            let attached = creating.join().unwrap();
+
+            assert_eq!(removed.ids, [0]);
+
+            // the object is either removed with the monitor, or deleted by its creator
+            match attached {
+                Ok(()) => assert_eq!(departed(&removed.departures), [object(1)]),
+                Err(handed_back) => {
+                    assert_eq!(handed_back, object(1));
+                    assert!(removed.departures.is_empty());
+                }
+            }
+
+            // the other monitor is left alone
+            assert_eq!(monitors.lock().unwrap().len(), 1);
+        });
+    }
+
+    // e.g. the pipe and a device control request plug the same monitor in at the same time
+    #[test]
+    fn create_twice() {
+        loom::model(|| {
+            let monitors = monitors(&[0]);
+
+            let other = {
+                let monitors = monitors.clone();
+                thread::spawn(move || attach(&monitors, 0, object(1), Vec::new()))
+            };
+            let this = attach(&monitors, 0, object(2), Vec::new());
+            let other = other.join().unwrap();
+
+            // only one of them arrives, the other object is deleted
+            let winner = match (this, other) {
+                (Ok(()), Err(handed_back)) => {
+                    assert_eq!(handed_back, object(1));
+                    object(2)
+                }
+                (Err(handed_back), Ok(())) => {
+                    assert_eq!(handed_back, object(2));
+                    object(1)
+                }
+                results => panic!("expected exactly one object to be attached: {results:?}"),
+            };
+            assert_eq!(object_of(&monitors, 0), Some(winner));
+        });
+    }
+
+    // a replug races with the monitor's removal, no object may be leaked or departed twice
+    #[test]
+    fn replug_while_removing() {
+        loom::model(|| {
+            let monitors = monitors(&[0]);
+            attach(&monitors, 0, object(1), Vec::new()).unwrap();
+
+            let replug = {
+                let monitors = monitors.clone();
+                thread::spawn(move || {
+                    let departure = take_object(&monitors, 0);
+                    let attached = attach(&monitors, 0, object(2), Vec::new());
+                    (departure, attached)
+                })
+            };
+            let removed = remove(&monitors, |_| true, |_| ());
+            let (departure, attached) = replug.join().unwrap();
+
+            let mut departed = departed(&removed.departures);
+            departed.extend(departure.map(|departure| departure.object));
+            if let Err(handed_back) = attached {
+                departed.push(handed_back);
+            }
+            departed.sort_unstable();
+
+            assert_eq!(departed, [object(1), object(2)]);
+            assert!(monitors.lock().unwrap().is_empty());
+        });
+    }
+
+    // the os asks for the modes of a monitor while it updates them in place, and the update can
+    // fail and fall back to a re-plug while the monitor is removed
+    #[test]
+    fn callbacks_during_mode_update() {
+        loom::model(|| {
+            let monitors = monitors(&[0]);
+            attach(&monitors, 0, object(1), Vec::new()).unwrap();
+
+            // recorded with the list locked, like notify does
+            let update = {
+                let lock = monitors.lock().unwrap();
+                ModeUpdate {
+                    id: 0,
+                    object: lock[0].monitor_object.unwrap(),
+                    modes: lock[0].monitor.modes.clone(),
+                    preferred_changed: false,
+                }
+            };
+
+            let notify = {
+                let monitors = monitors.clone();
+                thread::spawn(move || {
+                    // the os queries the modes on another thread, and waits for the callback
+                    let callback = {
+                        let monitors = monitors.clone();
+                        thread::spawn(move || object_of(&monitors, 0))
+                    };
+                    let queried = callback.join().unwrap();
+                    assert!(queried.is_none() || queried == Some(object(1)));
+
+                    // then the update fails
+                    take_failed_update(&monitors, &update)
+                })
+            };
+            let removed = remove(&monitors, |_| true, |_| ());
+            let replug = notify.join().unwrap();
+
+            // the object is departed exactly once, by whoever took it
+            let mut departed = departed(&removed.departures);
+            departed.extend(replug.map(|departure| departure.object));
+            assert_eq!(departed, [object(1)]);
+            assert!(monitors.lock().unwrap().is_empty());
+        });
+    }
+
+    // os callbacks lock the list, e.g. to look up the modes of a monitor while the os departs it,
+    // which must not wait on a thread that departs with the lock held
+    #[test]
+    fn callbacks_during_departure() {
+        loom::model(|| {
+            let monitors = monitors(&[0, 1]);
+            attach(&monitors, 0, object(1), Vec::new()).unwrap();
+            attach(&monitors, 1, object(2), Vec::new()).unwrap();
+
+            let suspend = {
+                let monitors = monitors.clone();
+                thread::spawn(move || {
+                    for departure in take_objects(&monitors) {
+                        // the os handles the departure on another thread, and waits for the
+                        // callbacks it makes
+                        let callback = {
+                            let monitors = monitors.clone();
+                            thread::spawn(move || object_of(&monitors, departure.id))
+                        };
+                        assert_eq!(callback.join().unwrap(), None);
+                    }
+                })
+            };
+            let query = object_of(&monitors, 1);
+            suspend.join().unwrap();
+
+            assert!(query.is_none() || query == Some(object(2)));
+            assert_eq!(object_of(&monitors, 0), None);
+            assert_eq!(object_of(&monitors, 1), None);
+        });
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, OnceLock,
+    },
+    time::{Duration, Instant},
+};
+
+use driver_ipc::{CallbackStats, ConsumerStats, Id, Stats};
+use windows::Win32::System::Performance::{QueryPerformanceCounter, QueryPerformanceFrequency};
+
+use crate::pool::PoolStats;
+
+// A monitor that hasn't presented a frame for this long is reported as idle
+const IDLE_AFTER: Duration = Duration::from_secs(1);
+
+// Reference point for timestamps, which are stored as atomics
+static EPOCH: OnceLock<Instant> = OnceLock::new();
+
+/// Frame counters of a single monitor
+///
+/// Written by the monitor's swap chain processor thread, read by the ipc thread. They survive the
+/// monitor being re-created (e.g. on mode changes), and reset when it's removed.
+#[derive(Debug, Default)]
+pub struct FrameStats {
+    presented: AtomicU64,
+    dropped: AtomicU64,
+    // frames the consumer of the shared texture missed, see `Backpressure`
+    shared_dropped: AtomicU64,
+    // sum of all acquire-to-release latencies
+    latency_us: AtomicU64,
+    // sum of all present-to-acquire latencies, and how many frames had one
+    present_latency_us: AtomicU64,
+    present_latencies: AtomicU64,
+    // moving averages of the time between frames, and of its deviation from that average
+    interval_us: AtomicU64,
+    jitter_us: AtomicU64,
+    // when the last frame was acquired, relative to `EPOCH`
+    last_frame_us: AtomicU64,
+    pool: PoolStats,
+    // readers of the frame ring, see `ring`, updated once a second
+    consumers: Mutex<Vec<ConsumerStats>>,
+}
+
+impl FrameStats {
+    /// Record a processed frame
+    ///
+    /// `interval` is the time since the previous frame was acquired, if there was one, and
+    /// `present_latency` the time since the os presented it, if it said when
+    pub fn frame(
+        &self,
+        latency: Duration,
+        interval: Option<Duration>,
+        present_latency: Option<Duration>,
+    ) {
+        self.presented.fetch_add(1, Ordering::Relaxed);
+        self.latency_us
+            .fetch_add(micros(latency), Ordering::Relaxed);
+        self.last_frame_us
+            .store(micros(epoch().elapsed()), Ordering::Relaxed);
+
+        if let Some(present_latency) = present_latency {
+            self.present_latency_us
+                .fetch_add(micros(present_latency), Ordering::Relaxed);
+            self.present_latencies.fetch_add(1, Ordering::Relaxed);
+        }
+
+        if let Some(interval) = interval {
+            let sample = micros(interval);
+            let average = self.interval_us.load(Ordering::Relaxed);
+
+            // only this monitor's processor thread writes, so a plain load + store is fine
+            let (average, jitter) = if average == 0 {
+                (sample, 0)
+            } else {
+                let jitter = self.jitter_us.load(Ordering::Relaxed);
+                (
+                    moving_average(average, sample),
+                    moving_average(jitter, sample.abs_diff(average)),
+                )
+            };
+            self.interval_us.store(average, Ordering::Relaxed);
+            self.jitter_us.store(jitter, Ordering::Relaxed);
+        }
+    }
+
+    pub fn dropped(&self, count: u64) {
+        self.dropped.fetch_add(count, Ordering::Relaxed);
+    }
+
+    pub fn shared_frame_dropped(&self) {
+        self.shared_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Counters of the device and textures frames are processed with, see `pool`
+    pub fn pool(&self) -> &PoolStats {
+        &self.pool
+    }
+
+    pub fn set_consumers(&self, consumers: Vec<ConsumerStats>) {
+        if let Ok(mut current) = self.consumers.lock() {
+            *current = consumers;
+        }
+    }
+
+    pub fn snapshot(&self, id: Id) -> Stats {
+        let presented = self.presented.load(Ordering::Relaxed);
+        let latency_us = self.latency_us.load(Ordering::Relaxed);
+        let interval_us = self.interval_us.load(Ordering::Relaxed);
+        let present_latency_us = self.present_latency_us.load(Ordering::Relaxed);
+        let present_latencies = self.present_latencies.load(Ordering::Relaxed);
+
+        let since_last_frame =
+            micros(epoch().elapsed()).saturating_sub(self.last_frame_us.load(Ordering::Relaxed));
+        let idle = presented == 0 || since_last_frame > micros(IDLE_AFTER);
+
+        #[allow(clippy::cast_precision_loss)]
+        let avg_latency_us = if presented == 0 {
+            0.0
+        } else {
+            latency_us as f64 / presented as f64
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let avg_present_latency_us = if present_latencies == 0 {
+            0.0
+        } else {
+            present_latency_us as f64 / present_latencies as f64
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let refresh_rate = if idle || interval_us == 0 {
+            0.0
+        } else {
+            1_000_000.0 / interval_us as f64
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let jitter_us = if idle {
+            0.0
+        } else {
+            self.jitter_us.load(Ordering::Relaxed) as f64
+        };
+
+        let (pool_allocations, pool_reuses) = self.pool.snapshot();
+
+        Stats {
+            id,
+            frames_presented: presented,
+            frames_dropped: self.dropped.load(Ordering::Relaxed),
+            avg_latency_us,
+            refresh_rate,
+            avg_present_latency_us,
+            jitter_us,
+            pool_allocations,
+            pool_reuses,
+            shared_frames_dropped: self.shared_dropped.load(Ordering::Relaxed),
+            consumers: self
+                .consumers
+                .lock()
+                .map(|consumers| consumers.clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+// Weighs a new sample by 1/8
+fn moving_average(average: u64, sample: u64) -> u64 {
+    average - average / 8 + sample / 8
+}
+
+/// IddCx callbacks and calls whose outcomes are counted
+#[derive(Debug, Clone, Copy)]
+pub enum Callback {
+    AdapterInitFinished,
+    DeviceD0Entry,
+    DeviceD0Exit,
+    ParseMonitorDescription,
+    MonitorQueryModes,
+    AdapterCommitModes,
+    AssignSwapChain,
+    // creating the d3d device for an assigned swap chain, the os retries with a new swap chain
+    SwapChainDeviceInit,
+    UnassignSwapChain,
+    MonitorCreate,
+    MonitorArrival,
+    MonitorSetGammaRamp,
+    MonitorI2CTransmit,
+    MonitorI2CReceive,
+}
+
+impl Callback {
+    const ALL: [Self; 14] = [
+        Self::AdapterInitFinished,
+        Self::DeviceD0Entry,
+        Self::DeviceD0Exit,
+        Self::ParseMonitorDescription,
+        Self::MonitorQueryModes,
+        Self::AdapterCommitModes,
+        Self::AssignSwapChain,
+        Self::SwapChainDeviceInit,
+        Self::UnassignSwapChain,
+        Self::MonitorCreate,
+        Self::MonitorArrival,
+        Self::MonitorSetGammaRamp,
+        Self::MonitorI2CTransmit,
+        Self::MonitorI2CReceive,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::AdapterInitFinished => "AdapterInitFinished",
+            Self::DeviceD0Entry => "DeviceD0Entry",
+            Self::DeviceD0Exit => "DeviceD0Exit",
+            Self::ParseMonitorDescription => "ParseMonitorDescription",
+            Self::MonitorQueryModes => "MonitorQueryModes",
+            Self::AdapterCommitModes => "AdapterCommitModes",
+            Self::AssignSwapChain => "AssignSwapChain",
+            Self::SwapChainDeviceInit => "SwapChainDeviceInit",
+            Self::UnassignSwapChain => "UnassignSwapChain",
+            Self::MonitorCreate => "MonitorCreate",
+            Self::MonitorArrival => "MonitorArrival",
+            Self::MonitorSetGammaRamp => "MonitorSetGammaRamp",
+            Self::MonitorI2CTransmit => "MonitorI2CTransmit",
+            Self::MonitorI2CReceive => "MonitorI2CReceive",
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CallbackCounter {
+    succeeded: AtomicU64,
+    failed: AtomicU64,
+}
+
+const NEW_COUNTER: CallbackCounter = CallbackCounter {
+    succeeded: AtomicU64::new(0),
+    failed: AtomicU64::new(0),
+};
+
+// Outcomes since the driver started, indexed by `Callback`
+static CALLBACKS: [CallbackCounter; Callback::ALL.len()] = [NEW_COUNTER; Callback::ALL.len()];
+
+/// Count the outcome of a callback
+pub fn callback(callback: Callback, success: bool) {
+    let counter = &CALLBACKS[callback as usize];
+
+    if success {
+        counter.succeeded.fetch_add(1, Ordering::Relaxed);
+    } else {
+        counter.failed.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+pub fn callback_snapshot() -> Vec<CallbackStats> {
+    Callback::ALL
+        .iter()
+        .map(|&callback| {
+            let counter = &CALLBACKS[callback as usize];
+
+            CallbackStats {
+                callback: callback.name().to_owned(),
+                succeeded: counter.succeeded.load(Ordering::Relaxed),
+                failed: counter.failed.load(Ordering::Relaxed),
+            }
+        })
+        .collect()
+}
+
+/// Reference point for timestamps stored in atomics
+pub fn epoch() -> Instant {
+    *EPOCH.get_or_init(Instant::now)
+}
+
+pub fn micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
+
+/// Time since a `QueryPerformanceCounter` timestamp, e.g. when the os presented a frame
+///
+/// Returns `None` for 0, which the os uses when it has no timestamp
+pub fn since_qpc(timestamp: u64) -> Option<Duration> {
+    static FREQUENCY: OnceLock<u64> = OnceLock::new();
+
+    if timestamp == 0 {
+        return None;
+    }
+
+    let frequency = *FREQUENCY.get_or_init(|| {
+        let mut frequency = 0;
+        // can't fail since Windows XP
+        _ = unsafe { QueryPerformanceFrequency(&mut frequency) };
+        u64::try_from(frequency).unwrap_or_default()
+    });
+
+    let mut now = 0;
+    unsafe { QueryPerformanceCounter(&mut now).ok()? };
+
+    let ticks = u64::try_from(now).ok()?.checked_sub(timestamp)?;
+    let micros = u128::from(ticks) * 1_000_000 / u128::from(frequency.max(1));
+
+    Some(Duration::from_micros(u64::try_from(micros).ok()?))
+}
@@ -1,19 +1,21 @@
 use std::{
+    ffi::c_void,
     sync::{
-        atomic::{AtomicBool, Ordering},
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
         Arc,
     },
     thread::{self, JoinHandle},
+    time::{Duration, Instant},
 };
 
 use log::{debug, error};
 use wdf_umdf::{
-    IddCxSwapChainFinishedProcessingFrame, IddCxSwapChainReleaseAndAcquireBuffer,
-    IddCxSwapChainSetDevice, WdfObjectDelete,
+    IddCxSetRealtimeGPUPriority, IddCxSwapChainFinishedProcessingFrame,
+    IddCxSwapChainReleaseAndAcquireBuffer, IddCxSwapChainSetDevice, WdfError, WdfObjectDelete,
 };
 use wdf_umdf_sys::{
-    HANDLE, IDARG_IN_SWAPCHAINSETDEVICE, IDARG_OUT_RELEASEANDACQUIREBUFFER, IDDCX_SWAPCHAIN,
-    NTSTATUS, WAIT_TIMEOUT, WDFOBJECT,
+    HANDLE, IDARG_IN_SETREALTIMEGPUPRIORITY, IDARG_IN_SWAPCHAINSETDEVICE,
+    IDARG_OUT_RELEASEANDACQUIREBUFFER, IDDCX_SWAPCHAIN, NTSTATUS, WAIT_TIMEOUT, WDFOBJECT,
 };
 use windows::{
     core::{w, Interface},
@@ -26,11 +28,159 @@ use windows::{
     },
 };
 
-use crate::{direct_3d_device::Direct3DDevice, helpers::Sendable};
+use crate::{
+    cursor::{self, CursorSource},
+    direct_3d_device::Direct3DDevice,
+    gpu_priority,
+    helpers::Sendable,
+    pool::Resources,
+    stats::{self, FrameStats},
+    test_pattern::{PatternSlot, PatternSource},
+    trace, watchdog,
+};
+
+// Consecutive slow frames before acquisition is slowed down
+const SLOW_FRAMES: u32 = 30;
+// Consecutive fast frames before acquisition is sped back up
+const FAST_FRAMES: u32 = 120;
+// Slowest the processing loop is allowed to back off to
+const MAX_INTERVAL: Duration = Duration::from_millis(250);
+// Intervals below this go straight back to full rate
+const MIN_INTERVAL: Duration = Duration::from_millis(1);
+
+/// Adapts how often frames are acquired to how fast they are processed
+///
+/// When processing a frame persistently takes longer than the time between frames (e.g. a slow
+/// consumer), the minimum interval between acquisitions is doubled instead of spinning at full
+/// rate and dropping most frames. Once processing persistently takes less than half the interval,
+/// it's halved again. The gap between both thresholds, plus the required streaks, give hysteresis
+/// so the rate doesn't oscillate.
+#[derive(Debug, Default)]
+struct Backoff {
+    interval: Duration,
+    slow_streak: u32,
+    fast_streak: u32,
+}
+
+impl Backoff {
+    fn record(&mut self, frame_interval: Duration, processing: Duration) {
+        if processing > frame_interval {
+            self.fast_streak = 0;
+            self.slow_streak += 1;
+
+            if self.slow_streak >= SLOW_FRAMES {
+                self.slow_streak = 0;
+                self.interval = (self.interval * 2).max(processing).min(MAX_INTERVAL);
+                debug!(
+                    "Frame processing is slow, backing off to {:?}",
+                    self.interval
+                );
+            }
+        } else if !self.interval.is_zero() && processing < self.interval / 2 {
+            self.slow_streak = 0;
+            self.fast_streak += 1;
+
+            if self.fast_streak >= FAST_FRAMES {
+                self.fast_streak = 0;
+                self.interval /= 2;
+                if self.interval < MIN_INTERVAL {
+                    self.interval = Duration::ZERO;
+                }
+                debug!(
+                    "Frame processing caught up, speeding up to {:?}",
+                    self.interval
+                );
+            }
+        } else {
+            self.slow_streak = 0;
+            self.fast_streak = 0;
+        }
+    }
+
+    /// Time left to wait before acquiring the next frame
+    fn remaining(&self, since_acquire: Duration) -> Duration {
+        self.interval.saturating_sub(since_acquire)
+    }
+}
+
+/// Frame rate cap of a monitor
+///
+/// Shared with the processing thread, so a new limit applies without re-creating the swap chain
+#[derive(Debug, Default)]
+pub struct FrameLimit(AtomicU32);
+
+impl FrameLimit {
+    pub fn set(&self, fps: Option<u32>) {
+        self.0.store(fps.unwrap_or(0), Ordering::Relaxed);
+    }
+
+    /// Shortest time between two acquired frames
+    fn interval(&self) -> Duration {
+        match self.0.load(Ordering::Relaxed) {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / fps,
+        }
+    }
+
+    /// Time left to wait before acquiring the next frame
+    fn remaining(&self, since_acquire: Duration) -> Duration {
+        self.interval().saturating_sub(since_acquire)
+    }
+}
+
+/// State shared between a processing thread, its owner, and the watchdog
+pub struct ProcessorState {
+    pub monitor_id: u32,
+    swap_chain: Sendable<IDDCX_SWAPCHAIN>,
+    terminate: AtomicBool,
+    // when the processing loop last made progress, relative to `stats::epoch`
+    heartbeat_us: AtomicU64,
+    // set by the watchdog, a stalled thread might never return, so it isn't joined
+    stalled: AtomicBool,
+    // the swap chain is deleted by either the thread or the watchdog, but only once
+    deleted: AtomicBool,
+}
+
+impl ProcessorState {
+    fn beat(&self) {
+        self.heartbeat_us
+            .store(stats::micros(stats::epoch().elapsed()), Ordering::Relaxed);
+    }
+
+    /// Time since the processing loop last made progress
+    pub fn since_heartbeat(&self) -> Duration {
+        let now = stats::micros(stats::epoch().elapsed());
+        Duration::from_micros(now.saturating_sub(self.heartbeat_us.load(Ordering::Relaxed)))
+    }
+
+    /// Whether the swap chain is gone, i.e. there's nothing left to watch
+    pub fn is_deleted(&self) -> bool {
+        self.deleted.load(Ordering::Acquire)
+    }
+
+    /// Mark the processor as stalled and ask its thread to stop
+    ///
+    /// Returns `false` if it was already marked
+    pub fn mark_stalled(&self) -> bool {
+        self.terminate.store(true, Ordering::Relaxed);
+        !self.stalled.swap(true, Ordering::AcqRel)
+    }
+
+    /// Delete the swap chain, unless that already happened
+    ///
+    /// The os then unassigns it and assigns a new one, with a new device
+    pub fn delete_swap_chain(&self) -> Result<(), WdfError> {
+        if self.deleted.swap(true, Ordering::AcqRel) {
+            return Ok(());
+        }
+
+        unsafe { WdfObjectDelete(*self.swap_chain as WDFOBJECT) }
+    }
+}
 
 pub struct SwapChainProcessor {
-    terminate: Arc<AtomicBool>,
-    thread: Option<JoinHandle<()>>,
+    state: Option<Arc<ProcessorState>>,
+    thread: Option<JoinHandle<Resources>>,
 }
 
 unsafe impl Send for SwapChainProcessor {}
@@ -39,37 +189,68 @@ unsafe impl Sync for SwapChainProcessor {}
 impl SwapChainProcessor {
     pub fn new() -> Self {
         Self {
-            terminate: Arc::new(AtomicBool::new(false)),
+            state: None,
             thread: None,
         }
     }
 
+    /// Process frames on a new thread, with `resources` from the monitor's previous swap chain
+    /// or new ones, see [`Self::stop`]
+    #[allow(clippy::too_many_arguments)]
     pub fn run(
         &mut self,
         swap_chain: IDDCX_SWAPCHAIN,
-        device: Direct3DDevice,
+        resources: Resources,
         available_buffer_event: HANDLE,
+        monitor_id: u32,
+        stats: Arc<FrameStats>,
+        limit: Arc<FrameLimit>,
+        pattern: Arc<PatternSlot>,
+        cursor: Option<CursorSource>,
     ) {
         let available_buffer_event = unsafe { Sendable::new(available_buffer_event) };
-        let swap_chain = unsafe { Sendable::new(swap_chain) };
-        let terminate = self.terminate.clone();
 
+        let state = Arc::new(ProcessorState {
+            monitor_id,
+            swap_chain: unsafe { Sendable::new(swap_chain) },
+            terminate: AtomicBool::new(false),
+            heartbeat_us: AtomicU64::new(0),
+            stalled: AtomicBool::new(false),
+            deleted: AtomicBool::new(false),
+        });
+        state.beat();
+        watchdog::watch(&state);
+
+        let thread_state = state.clone();
         let join_handle = thread::spawn(move || {
+            let state = thread_state;
+            let mut resources = resources;
+            let mut cursor = cursor;
+
             // It is very important to prioritize this thread by making use of the Multimedia Scheduler Service.
             // It will intelligently prioritize the thread for improved throughput in high CPU-load scenarios.
             let mut av_task = 0u32;
             let res = unsafe { AvSetMmThreadCharacteristicsW(w!("Distribution"), &mut av_task) };
             let Ok(av_handle) = res else {
                 error!("Failed to prioritize thread: {res:?}");
-                return;
+                return resources;
             };
 
-            Self::run_core(*swap_chain, &device, *available_buffer_event, &terminate);
+            Self::run_core(
+                *state.swap_chain,
+                &mut resources,
+                *available_buffer_event,
+                &state,
+                &stats,
+                &limit,
+                &pattern,
+                cursor.as_mut(),
+            );
 
-            let res = unsafe { WdfObjectDelete(*swap_chain as WDFOBJECT) };
+            let res = state.delete_swap_chain();
             if let Err(e) = res {
                 error!("Failed to delete wdf object: {e:?}");
-                return;
+                return resources;
             }
 
             // Revert the thread to normal once it's done
@@ -77,17 +258,37 @@ impl SwapChainProcessor {
             if let Err(e) = res {
                 error!("Failed to revert prioritize thread: {e:?}");
             }
+
+            resources
         });
 
+        self.state = Some(state);
         self.thread = Some(join_handle);
     }
 
+    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
     fn run_core(
         swap_chain: IDDCX_SWAPCHAIN,
-        device: &Direct3DDevice,
+        resources: &mut Resources,
         available_buffer_event: HANDLE,
-        terminate: &AtomicBool,
+        state: &ProcessorState,
+        stats: &FrameStats,
+        limit: &FrameLimit,
+        pattern_slot: &PatternSlot,
+        mut cursor: Option<&mut CursorSource>,
     ) {
+        let monitor_id = state.monitor_id;
+        let terminate = &state.terminate;
+
+        let Resources {
+            device,
+            exporter,
+            realtime_priority,
+            ..
+        } = resources;
+        let device = &*device;
+        let mut exporter = exporter.as_mut();
+
         let dxgi_device = device.device.cast::<IDXGIDevice>();
         let Ok(dxgi_device) = dxgi_device else {
             error!("Failed to cast ID3D11Device to IDXGIDevice: {dxgi_device:?}");
@@ -99,19 +300,104 @@ impl SwapChainProcessor {
         };
 
         let res = unsafe { IddCxSwapChainSetDevice(swap_chain, &set_device) };
-        if res.is_err() {
-            debug!("Failed to set swapchain device: {res:?}");
+        if let Err(e) = res {
+            debug!("Failed to set swapchain device: {e:?}");
+            trace::iddcx_failed("IddCxSwapChainSetDevice", monitor_id, e.into());
             return;
         }
 
+        // only tried once per swap chain, so a failing call isn't repeated every frame
+        let mut priority_tried = false;
+
+        let mut backoff = Backoff::default();
+        let mut last_acquire: Option<Instant> = None;
+        // when we started waiting for the current buffer, and the last frame number we got
+        let mut wait_start: Option<Instant> = None;
+        let mut last_frame: Option<u64> = None;
+        let mut pattern = PatternSource::default();
+        // whether the cursor is drawn into the shared frames, and needs to be drawn again
+        let mut composited = false;
+        let mut cursor_dirty = false;
+
         loop {
-            let mut buffer = IDARG_OUT_RELEASEANDACQUIREBUFFER::default();
-            let hr: NTSTATUS =
-                unsafe { IddCxSwapChainReleaseAndAcquireBuffer(swap_chain, &mut buffer).into() };
+            state.beat();
+
+            // the setting may be turned on while frames are processed
+            if !*realtime_priority && !priority_tried && gpu_priority::enabled() {
+                *realtime_priority = set_realtime_priority(swap_chain, device, monitor_id);
+                priority_tried = true;
+            }
+
+            // patterns are shared whether or not the desktop changes
+            let pattern_active = exporter
+                .as_deref_mut()
+                .is_some_and(|exporter| pattern.tick(exporter, device, pattern_slot));
+
+            // a consumer that stopped taking frames shouldn't keep them from being shared
+            if let Some(exporter) = exporter.as_deref_mut() {
+                if let Err(e) = exporter.check_consumer(device) {
+                    debug!("Failed to replace abandoned shared frame: {e:?}");
+                }
+
+                // consumers may ask for the frame ring at any time
+                if let Err(e) = exporter.check_ring(device) {
+                    debug!("Failed to set up frame ring: {e:?}");
+                }
+            }
+
+            // the os doesn't send new frames for cursor changes, so they are drawn right away
+            if let Some(cursor) = cursor.as_deref_mut() {
+                cursor_dirty |= cursor.poll();
+
+                let composite = cursor::composited(monitor_id);
+                cursor_dirty |= composite != composited;
+                composited = composite;
+            }
+            let drawn_cursor = cursor
+                .as_deref()
+                .filter(|_| composited)
+                .map(CursorSource::state);
+
+            if cursor_dirty && !pattern_active {
+                cursor_dirty = match exporter.as_deref_mut() {
+                    Some(exporter) => match exporter.draw_cursor(device, drawn_cursor) {
+                        Ok(drawn) => !drawn,
+                        Err(e) => {
+                            debug!("Failed to draw cursor: {e:?}");
+                            false
+                        }
+                    },
+                    None => false,
+                };
+            }
+
+            // slow consumers get frames at a reduced rate, see `Backoff`, and so do monitors
+            // with a frame rate limit
+            if let Some(last_acquire) = last_acquire {
+                let remaining_for =
+                    |elapsed: Duration| backoff.remaining(elapsed).max(limit.remaining(elapsed));
+
+                let mut remaining = remaining_for(last_acquire.elapsed());
+                while !remaining.is_zero() {
+                    // sleep in small steps, so a termination request isn't delayed
+                    thread::sleep(remaining.min(Duration::from_millis(16)));
+
+                    if terminate.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    state.beat();
+
+                    remaining = remaining_for(last_acquire.elapsed());
+                }
+            }
+
+            let (hr, buffer) = AcquiredBuffer::release_and_acquire(swap_chain);
 
             #[allow(clippy::items_after_statements)]
             const E_PENDING: u32 = 0x8000_000A;
             if u32::from(hr) == E_PENDING {
+                wait_start.get_or_insert_with(Instant::now);
+
                 let wait_result =
                     unsafe { WaitForSingleObject(WHANDLE(available_buffer_event as _), 16).0 };
 
@@ -130,29 +416,271 @@ impl SwapChainProcessor {
                 // The wait was cancelled or something unexpected happened
                 break;
             } else if hr.is_success() {
+                let acquired = Instant::now();
+                let present_latency = stats::since_qpc(buffer.present_qpc_time);
+
+                let frame_number = buffer.frame_number;
+                let buffer_wait = wait_start.take().map(|start| acquired - start);
+
+                trace::frame_acquired(
+                    monitor_id,
+                    frame_number,
+                    buffer_wait.unwrap_or_default(),
+                    buffer.dirty_rect_count,
+                    buffer.move_region_count,
+                );
+
+                // the os skipped frame numbers we never got a chance to acquire
+                if let Some(dropped) = last_frame
+                    .and_then(|last| frame_number.checked_sub(last + 1))
+                    .filter(|&dropped| dropped > 0)
+                {
+                    trace::frames_dropped(monitor_id, frame_number, dropped);
+                    stats.dropped(dropped);
+                }
+                last_frame = Some(frame_number);
+
+                if let Some(exporter) = exporter.as_deref_mut() {
+                    let surface = buffer.surface;
+                    // SAFETY: the frame is only released below
+                    let res = if pattern_active {
+                        // the pattern replaces the frame, but still follows mode changes
+                        unsafe { exporter.prepare_for(device, surface) }
+                    } else {
+                        unsafe { exporter.export(device, surface, drawn_cursor) }
+                    };
+                    if let Err(e) = res {
+                        debug!("Failed to share frame: {e:?}");
+                    }
+                }
+
                 // This is the most performance-critical section of code in an IddCx driver. It's important that whatever
                 // is done with the acquired surface be finished as quickly as possible.
                 let hr = unsafe { IddCxSwapChainFinishedProcessingFrame(swap_chain) };
 
-                if hr.is_err() {
+                if let Err(e) = hr {
+                    trace::iddcx_failed(
+                        "IddCxSwapChainFinishedProcessingFrame",
+                        monitor_id,
+                        e.into(),
+                    );
                     break;
                 }
+
+                let processing = acquired.elapsed();
+                let frame_interval = last_acquire.map(|last_acquire| acquired - last_acquire);
+                stats.frame(processing, frame_interval, present_latency);
+
+                if let Some(frame_interval) = frame_interval {
+                    backoff.record(frame_interval, processing);
+                }
+                last_acquire = Some(acquired);
             } else {
                 // The swap-chain was likely abandoned (e.g. DXGI_ERROR_ACCESS_LOST), so exit the processing loop
+                trace::iddcx_failed("IddCxSwapChainReleaseAndAcquireBuffer", monitor_id, hr);
                 break;
             }
         }
     }
 }
 
+/// What the processing loop uses of an acquired buffer's metadata
+struct AcquiredBuffer {
+    frame_number: u64,
+    // when the os presented the frame, 0 if it didn't say
+    present_qpc_time: u64,
+    dirty_rect_count: u32,
+    move_region_count: u32,
+    surface: *mut c_void,
+}
+
+impl AcquiredBuffer {
+    /// Release the previous buffer and acquire the next one
+    ///
+    /// Uses `IddCxSwapChainReleaseAndAcquireBuffer2` where Windows has it and the driver is built
+    /// against IddCx 1.10. The metadata is only meaningful if the returned status is a success.
+    fn release_and_acquire(swap_chain: IDDCX_SWAPCHAIN) -> (NTSTATUS, Self) {
+        #[cfg(feature = "iddcx-1_10")]
+        if wdf_umdf_sys::IddCxIsFunctionAvailable!(IddCxSwapChainReleaseAndAcquireBuffer2) {
+            let in_args = wdf_umdf_sys::IDARG_IN_RELEASEANDACQUIREBUFFER2 {
+                #[allow(clippy::cast_possible_truncation)]
+                Size: std::mem::size_of::<wdf_umdf_sys::IDARG_IN_RELEASEANDACQUIREBUFFER2>() as u32,
+                ..Default::default()
+            };
+            let mut out_args = wdf_umdf_sys::IDARG_OUT_RELEASEANDACQUIREBUFFER2::default();
+
+            let hr: NTSTATUS = unsafe {
+                wdf_umdf::IddCxSwapChainReleaseAndAcquireBuffer2(
+                    swap_chain,
+                    &in_args,
+                    &mut out_args,
+                )
+                .into()
+            };
+
+            let metadata = &out_args.MetaData;
+            return (
+                hr,
+                Self {
+                    frame_number: metadata.PresentationFrameNumber,
+                    present_qpc_time: metadata.PresentDisplayQPCTime,
+                    dirty_rect_count: metadata.DirtyRectCount,
+                    move_region_count: metadata.MoveRegionCount,
+                    surface: metadata.pSurface.cast(),
+                },
+            );
+        }
+
+        let mut out_args = IDARG_OUT_RELEASEANDACQUIREBUFFER::default();
+        let hr: NTSTATUS =
+            unsafe { IddCxSwapChainReleaseAndAcquireBuffer(swap_chain, &mut out_args).into() };
+
+        let metadata = &out_args.MetaData;
+        (
+            hr,
+            Self {
+                frame_number: metadata.PresentationFrameNumber,
+                present_qpc_time: metadata.PresentDisplayQPCTime,
+                dirty_rect_count: metadata.DirtyRectCount,
+                move_region_count: metadata.MoveRegionCount,
+                surface: metadata.pSurface.cast(),
+            },
+        )
+    }
+}
+
+/// Let the os schedule the gpu work of `device` ahead of other work, see `gpu_priority`
+///
+/// Returns whether it succeeded
+fn set_realtime_priority(
+    swap_chain: IDDCX_SWAPCHAIN,
+    device: &Direct3DDevice,
+    monitor_id: u32,
+) -> bool {
+    let Ok(dxgi_device) = device.device.cast::<IDXGIDevice>() else {
+        return false;
+    };
+
+    let args = IDARG_IN_SETREALTIMEGPUPRIORITY {
+        pDevice: dxgi_device.as_raw().cast(),
+    };
+
+    let res = unsafe { IddCxSetRealtimeGPUPriority(swap_chain, &args) };
+    if let Err(e) = res {
+        trace::iddcx_failed("IddCxSetRealtimeGPUPriority", monitor_id, e.into());
+        return false;
+    }
+
+    true
+}
+
+impl SwapChainProcessor {
+    /// Stop the processing thread, and get back what it processed frames with
+    ///
+    /// Returns `None` if the thread stalled, what it holds can't be trusted anymore
+    pub fn stop(mut self) -> Option<Resources> {
+        self.join()
+    }
+
+    fn join(&mut self) -> Option<Resources> {
+        let state = self.state.take()?;
+
+        // send signal to end thread
+        state.terminate.store(true, Ordering::Relaxed);
+
+        let handle = self.thread.take()?;
+
+        // a stalled thread may be stuck for good, so leave it behind instead of hanging too
+        if state.stalled.load(Ordering::Acquire) {
+            return None;
+        }
+
+        // wait until thread is finished
+        handle.join().ok()
+    }
+}
+
 impl Drop for SwapChainProcessor {
     fn drop(&mut self) {
-        if let Some(handle) = self.thread.take() {
-            // send signal to end thread
-            self.terminate.store(true, Ordering::Relaxed);
+        drop(self.join());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-            // wait until thread is finished
-            _ = handle.join();
+    const FRAME: Duration = Duration::from_millis(16);
+
+    fn record(backoff: &mut Backoff, frames: u32, processing: Duration) {
+        for _ in 0..frames {
+            backoff.record(FRAME, processing);
         }
     }
+
+    #[test]
+    fn backs_off_when_slow() {
+        let mut backoff = Backoff::default();
+        let slow = Duration::from_millis(40);
+
+        record(&mut backoff, SLOW_FRAMES - 1, slow);
+        assert_eq!(backoff.interval, Duration::ZERO);
+
+        // at least as long as processing takes, then doubling
+        record(&mut backoff, 1, slow);
+        assert_eq!(backoff.interval, slow);
+        record(&mut backoff, SLOW_FRAMES, slow);
+        assert_eq!(backoff.interval, slow * 2);
+
+        assert_eq!(
+            backoff.remaining(Duration::from_millis(30)),
+            slow * 2 - Duration::from_millis(30)
+        );
+        assert_eq!(backoff.remaining(Duration::from_secs(1)), Duration::ZERO);
+    }
+
+    #[test]
+    fn capped() {
+        let mut backoff = Backoff::default();
+
+        record(&mut backoff, SLOW_FRAMES * 10, Duration::from_secs(1));
+        assert_eq!(backoff.interval, MAX_INTERVAL);
+    }
+
+    #[test]
+    fn streaks_reset() {
+        let mut backoff = Backoff::default();
+        let slow = Duration::from_millis(40);
+
+        // a frame in time breaks the streak
+        record(&mut backoff, SLOW_FRAMES - 1, slow);
+        record(&mut backoff, 1, FRAME);
+        record(&mut backoff, SLOW_FRAMES - 1, slow);
+        assert_eq!(backoff.interval, Duration::ZERO);
+
+        record(&mut backoff, 1, slow);
+        assert_eq!(backoff.interval, slow);
+
+        // and so does one that's neither slow nor fast enough to speed up again
+        record(&mut backoff, FAST_FRAMES - 1, Duration::from_millis(5));
+        record(&mut backoff, 1, Duration::from_millis(30));
+        record(&mut backoff, FAST_FRAMES - 1, Duration::from_millis(5));
+        assert_eq!(backoff.interval, slow);
+    }
+
+    #[test]
+    fn speeds_up_again() {
+        let mut backoff = Backoff::default();
+        let slow = Duration::from_millis(40);
+        record(&mut backoff, SLOW_FRAMES, slow);
+
+        let fast = Duration::from_micros(100);
+        record(&mut backoff, FAST_FRAMES, fast);
+        assert_eq!(backoff.interval, slow / 2);
+
+        // halved until it's below the minimum, then back to full rate
+        record(&mut backoff, FAST_FRAMES * 5, fast);
+        assert_eq!(backoff.interval, Duration::ZERO);
+        assert_eq!(backoff.remaining(Duration::ZERO), Duration::ZERO);
+    }
 }
@@ -0,0 +1,7 @@
+//! Locks shared between the ipc threads and os callbacks, which are loom's when testing with
+//! `RUSTFLAGS="--cfg loom"`, so the tests can explore every interleaving
+
+#[cfg(loom)]
+pub use loom::sync::Mutex;
+#[cfg(not(loom))]
+pub use std::sync::Mutex;
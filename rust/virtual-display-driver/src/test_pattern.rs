@@ -0,0 +1,317 @@
+//! Generated test patterns, shared instead of the desktop
+//!
+//! Patterns are drawn on the cpu into a bgra buffer, which the frame exporter uploads into the
+//! shared texture. They're produced at a steady rate, whether or not the desktop changes.
+
+use std::{
+    sync::atomic::{AtomicU8, Ordering},
+    time::{Duration, Instant},
+};
+
+use driver_ipc::TestPattern;
+use log::debug;
+
+use crate::{direct_3d_device::Direct3DDevice, shared_frame::FrameExporter};
+
+// Time between two pattern frames
+const INTERVAL: Duration = Duration::from_micros(16_667);
+
+// Patterns by their index in a `PatternSlot`, offset by one, 0 is no pattern
+const PATTERNS: [TestPattern; 3] = [
+    TestPattern::Bars,
+    TestPattern::Gradient,
+    TestPattern::MovingBox,
+];
+
+/// Active pattern of a monitor, set over ipc
+///
+/// Shared with the processing thread, which checks it every frame without taking a lock
+#[derive(Debug, Default)]
+pub struct PatternSlot(AtomicU8);
+
+impl PatternSlot {
+    pub fn set(&self, pattern: Option<TestPattern>) {
+        let index = pattern
+            .and_then(|pattern| PATTERNS.iter().position(|&p| p == pattern))
+            .map_or(0, |index| index + 1);
+
+        // there are only a few patterns
+        #[allow(clippy::cast_possible_truncation)]
+        self.0.store(index as u8, Ordering::Relaxed);
+    }
+
+    pub fn get(&self) -> Option<TestPattern> {
+        let index = usize::from(self.0.load(Ordering::Relaxed));
+        PATTERNS.get(index.checked_sub(1)?).copied()
+    }
+}
+
+type Color = [u8; 3];
+
+const WHITE: Color = [255, 255, 255];
+const BLACK: Color = [0, 0, 0];
+
+// 75% bars, left to right
+const BARS: [Color; 7] = [
+    [191, 191, 191],
+    [191, 191, 0],
+    [0, 191, 191],
+    [0, 191, 0],
+    [191, 0, 191],
+    [191, 0, 0],
+    [0, 0, 191],
+];
+// the short strip below the bars
+const REVERSE_BARS: [Color; 7] = [
+    [0, 0, 191],
+    BLACK,
+    [191, 0, 191],
+    BLACK,
+    [0, 191, 191],
+    BLACK,
+    [191, 191, 191],
+];
+// -I, white, +Q, black
+const BOTTOM: [Color; 4] = [[0, 33, 76], WHITE, [50, 0, 106], [19, 19, 19]];
+
+// 3x5 digits, one row per byte, most significant of the low 3 bits is the left column
+const DIGITS: [[u8; 5]; 10] = [
+    [0b111, 0b101, 0b101, 0b101, 0b111],
+    [0b010, 0b110, 0b010, 0b010, 0b111],
+    [0b111, 0b001, 0b111, 0b100, 0b111],
+    [0b111, 0b001, 0b111, 0b001, 0b111],
+    [0b101, 0b101, 0b111, 0b001, 0b001],
+    [0b111, 0b100, 0b111, 0b001, 0b111],
+    [0b111, 0b100, 0b111, 0b101, 0b111],
+    [0b111, 0b001, 0b001, 0b001, 0b001],
+    [0b111, 0b101, 0b111, 0b101, 0b111],
+    [0b111, 0b101, 0b111, 0b001, 0b111],
+];
+
+// Bits of the frame counter in the cell row along the top
+const COUNTER_BITS: u32 = 32;
+
+/// Shares pattern frames of one swap chain at a steady rate
+#[derive(Default)]
+pub struct PatternSource {
+    renderer: Renderer,
+    last_frame: Option<Instant>,
+}
+
+impl PatternSource {
+    /// Share the next pattern frame if the monitor has a pattern, and one is due
+    ///
+    /// Returns whether a pattern is active, in which case desktop frames must not be shared
+    pub fn tick(
+        &mut self,
+        exporter: &mut FrameExporter,
+        device: &Direct3DDevice,
+        slot: &PatternSlot,
+    ) -> bool {
+        let Some(pattern) = slot.get() else {
+            self.last_frame = None;
+            return false;
+        };
+
+        // the texture takes the desktop's size, so nothing can be drawn before its first frame
+        let Some((width, height)) = exporter.bgra_size() else {
+            return true;
+        };
+
+        if self
+            .last_frame
+            .is_some_and(|last_frame| last_frame.elapsed() < INTERVAL)
+        {
+            return true;
+        }
+        self.last_frame = Some(Instant::now());
+
+        let pixels = self.renderer.render(pattern, width, height);
+        if let Err(e) = exporter.export_pixels(device, pixels) {
+            debug!("Failed to share test pattern: {e:?}");
+        }
+
+        true
+    }
+}
+
+/// Draws pattern frames, keeping the static part around between frames
+#[derive(Default)]
+struct Renderer {
+    background: Option<(TestPattern, u32, u32)>,
+    base: Vec<u8>,
+    frame: Vec<u8>,
+    counter: u64,
+}
+
+impl Renderer {
+    /// Draw the next frame, as tightly packed bgra
+    fn render(&mut self, pattern: TestPattern, width: u32, height: u32) -> &[u8] {
+        if self.background != Some((pattern, width, height)) {
+            self.base.clear();
+            self.base.resize(width as usize * height as usize * 4, 255);
+            draw_background(&mut Canvas::new(&mut self.base, width, height), pattern);
+            self.background = Some((pattern, width, height));
+        }
+
+        self.frame.clear();
+        self.frame.extend_from_slice(&self.base);
+
+        let mut canvas = Canvas::new(&mut self.frame, width, height);
+        if pattern == TestPattern::MovingBox {
+            draw_box(&mut canvas, self.counter);
+        }
+        draw_counter(&mut canvas, self.counter);
+
+        self.counter += 1;
+
+        &self.frame
+    }
+}
+
+struct Canvas<'a> {
+    pixels: &'a mut [u8],
+    width: u32,
+    height: u32,
+}
+
+impl<'a> Canvas<'a> {
+    fn new(pixels: &'a mut [u8], width: u32, height: u32) -> Self {
+        Self {
+            pixels,
+            width,
+            height,
+        }
+    }
+
+    /// Fill a rectangle, clipped to the canvas
+    fn fill(&mut self, x: u32, y: u32, width: u32, height: u32, [r, g, b]: Color) {
+        let x_end = x.saturating_add(width).min(self.width);
+        let y_end = y.saturating_add(height).min(self.height);
+        if x >= x_end || y >= y_end {
+            return;
+        }
+
+        for row in y..y_end {
+            let start = (row as usize * self.width as usize + x as usize) * 4;
+            let end = (row as usize * self.width as usize + x_end as usize) * 4;
+
+            for pixel in self.pixels[start..end].chunks_exact_mut(4) {
+                pixel.copy_from_slice(&[b, g, r, 255]);
+            }
+        }
+    }
+
+    /// Fill rows `y..y + height` with colors, in equal parts from left to right
+    fn stripes(&mut self, y: u32, height: u32, colors: &[Color]) {
+        #[allow(clippy::cast_possible_truncation)]
+        let count = colors.len() as u32;
+
+        for (i, &color) in (0..count).zip(colors) {
+            let x = self.width * i / count;
+            let x_end = self.width * (i + 1) / count;
+            self.fill(x, y, x_end - x, height, color);
+        }
+    }
+}
+
+fn draw_background(canvas: &mut Canvas, pattern: TestPattern) {
+    let (width, height) = (canvas.width, canvas.height);
+
+    match pattern {
+        TestPattern::Bars => {
+            let bars = height * 2 / 3;
+            let strip = height / 12;
+
+            canvas.stripes(0, bars, &BARS);
+            canvas.stripes(bars, strip, &REVERSE_BARS);
+
+            // -I, white and +Q take up the width of 5 bars, black the rest
+            let y = bars + strip;
+            let part = width * 5 / 28;
+            for (i, &color) in (0..3).zip(&BOTTOM[..3]) {
+                canvas.fill(part * i, y, part, height - y, color);
+            }
+            canvas.fill(part * 3, y, width - part * 3, height - y, BOTTOM[3]);
+        }
+
+        TestPattern::Gradient => {
+            // grayscale in the top half, red, green and blue in the bottom one
+            let half = height / 2;
+            let third = (height - half) / 3;
+            let bands = [
+                (0, half, [1, 1, 1]),
+                (half, third, [1, 0, 0]),
+                (half + third, third, [0, 1, 0]),
+                (half + third * 2, height - half - third * 2, [0, 0, 1]),
+            ];
+
+            for x in 0..width {
+                #[allow(clippy::cast_possible_truncation)]
+                let level = (u64::from(x) * 255 / u64::from(width.max(2) - 1)) as u8;
+
+                for (y, band_height, [r, g, b]) in bands {
+                    canvas.fill(x, y, 1, band_height, [level * r, level * g, level * b]);
+                }
+            }
+        }
+
+        TestPattern::MovingBox => canvas.fill(0, 0, width, height, BLACK),
+    }
+}
+
+/// A box bouncing between the left and right edges, one step per frame
+fn draw_box(canvas: &mut Canvas, counter: u64) {
+    let size = (canvas.height / 8).max(1);
+    let step = (canvas.width / 120).max(1);
+    let travel = u64::from(canvas.width.saturating_sub(size).max(1));
+
+    // there and back again
+    let position = (counter * u64::from(step)) % (travel * 2);
+    let x = if position < travel {
+        position
+    } else {
+        travel * 2 - position
+    };
+
+    let x = u32::try_from(x).unwrap_or_default();
+    canvas.fill(x, (canvas.height - size) / 2, size, size, WHITE);
+}
+
+/// The frame counter as digits in the bottom left corner, and as cells along the top edge
+fn draw_counter(canvas: &mut Canvas, counter: u64) {
+    let cell = (canvas.width / (COUNTER_BITS * 2)).clamp(1, 16);
+    for bit in 0..COUNTER_BITS {
+        let set = (counter >> (COUNTER_BITS - 1 - bit)) & 1 == 1;
+        canvas.fill(bit * cell, 0, cell, cell, if set { WHITE } else { BLACK });
+    }
+
+    let digits = counter.to_string();
+    let scale = (canvas.height / 60).max(2);
+    // 3 columns and 1 column of spacing per digit, 1 column/row of margin around
+    #[allow(clippy::cast_possible_truncation)]
+    let box_width = (digits.len() as u32 * 4 + 1) * scale;
+    let box_height = 7 * scale;
+    let y = canvas.height.saturating_sub(box_height);
+
+    canvas.fill(0, y, box_width, box_height, BLACK);
+
+    for (i, digit) in (0..).zip(digits.bytes()) {
+        let glyph = DIGITS[usize::from(digit - b'0')];
+        let x = (1 + i * 4) * scale;
+
+        for (row, bits) in (0..).zip(glyph) {
+            for column in 0..3 {
+                if (bits >> (2 - column)) & 1 == 1 {
+                    canvas.fill(
+                        x + column * scale,
+                        y + (1 + row) * scale,
+                        scale,
+                        scale,
+                        WHITE,
+                    );
+                }
+            }
+        }
+    }
+}
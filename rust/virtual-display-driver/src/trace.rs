@@ -0,0 +1,231 @@
+//! ETW TraceLogging events, for analyzing stalls with WPR/WPA or xperf
+//!
+//! The provider name and guid are documented in the README, and the cli can generate a WPR
+//! recording profile for them (`virtual-display-driver-cli trace-profile`).
+
+use std::time::Duration;
+
+use tracelogging as tlg;
+use wdf_umdf_sys::NTSTATUS;
+
+// Must stay in sync with `driver_ipc::TRACE_PROVIDER_NAME` and `driver_ipc::TRACE_PROVIDER_GUID`
+tlg::define_provider!(
+    PROVIDER,
+    "VirtualDisplayDriver",
+    id("e473de08-5950-547b-e905-a9d89c310666")
+);
+
+// Device and adapter lifetime
+pub const KEYWORD_DEVICE: u64 = driver_ipc::TRACE_KEYWORD_DEVICE;
+// Monitor creation, arrival and departure
+pub const KEYWORD_MONITOR: u64 = driver_ipc::TRACE_KEYWORD_MONITOR;
+// Swap chain assignment and per frame events
+pub const KEYWORD_SWAP_CHAIN: u64 = driver_ipc::TRACE_KEYWORD_SWAP_CHAIN;
+// Every WDF and IddCx call, with the `trace-calls` feature
+pub const KEYWORD_CALLS: u64 = driver_ipc::TRACE_KEYWORD_CALLS;
+
+pub fn register() {
+    // SAFETY: the provider is unregistered in the driver unload callback, before the dll unloads
+    let status = unsafe { PROVIDER.register() };
+    if status != 0 {
+        log::warn!("Failed to register trace provider: {status}");
+    }
+
+    #[cfg(feature = "trace-calls")]
+    calls::install();
+}
+
+pub fn unregister() {
+    PROVIDER.unregister();
+}
+
+pub fn device_event(name: &str, status: NTSTATUS) {
+    tlg::write_event!(
+        PROVIDER,
+        "Device",
+        level(Informational),
+        keyword(KEYWORD_DEVICE),
+        str8("Event", name),
+        ntstatus("Status", &i32::from(status)),
+    );
+}
+
+pub fn monitor_event(name: &str, monitor_id: u32, status: NTSTATUS) {
+    tlg::write_event!(
+        PROVIDER,
+        "Monitor",
+        level(Informational),
+        keyword(KEYWORD_MONITOR),
+        str8("Event", name),
+        u32("MonitorId", &monitor_id),
+        ntstatus("Status", &i32::from(status)),
+    );
+}
+
+pub fn swap_chain_event(name: &str, monitor_id: u32) {
+    tlg::write_event!(
+        PROVIDER,
+        "SwapChain",
+        level(Informational),
+        keyword(KEYWORD_SWAP_CHAIN),
+        str8("Event", name),
+        u32("MonitorId", &monitor_id),
+    );
+}
+
+/// A frame was acquired from the swap chain, after waiting `buffer_wait` for it
+pub fn frame_acquired(
+    monitor_id: u32,
+    frame_number: u64,
+    buffer_wait: Duration,
+    dirty_rects: u32,
+    move_regions: u32,
+) {
+    tlg::write_event!(
+        PROVIDER,
+        "FrameAcquired",
+        level(Verbose),
+        keyword(KEYWORD_SWAP_CHAIN),
+        u32("MonitorId", &monitor_id),
+        u64("FrameNumber", &frame_number),
+        u64("BufferWaitUs", &micros(buffer_wait)),
+        u32("DirtyRects", &dirty_rects),
+        u32("MoveRegions", &move_regions),
+    );
+}
+
+/// Frames were presented by the os, but never acquired by us
+pub fn frames_dropped(monitor_id: u32, frame_number: u64, count: u64) {
+    tlg::write_event!(
+        PROVIDER,
+        "FramesDropped",
+        level(Warning),
+        keyword(KEYWORD_SWAP_CHAIN),
+        u32("MonitorId", &monitor_id),
+        u64("FrameNumber", &frame_number),
+        u64("Count", &count),
+    );
+}
+
+/// An IddCx call returned a failure status
+pub fn iddcx_failed(call: &str, monitor_id: u32, status: NTSTATUS) {
+    tlg::write_event!(
+        PROVIDER,
+        "IddCxCallFailed",
+        level(Error),
+        keyword(KEYWORD_SWAP_CHAIN),
+        str8("Call", call),
+        u32("MonitorId", &monitor_id),
+        ntstatus("Status", &i32::from(status)),
+    );
+}
+
+fn micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
+}
+
+// Routes the call events of wdf-umdf to ETW, and to the log
+#[cfg(feature = "trace-calls")]
+mod calls {
+    use std::fmt::{Debug, Write};
+
+    use tracelogging as tlg;
+    use tracing::{
+        field::{Field, Visit},
+        span, Event, Level, Metadata, Subscriber,
+    };
+
+    use super::{KEYWORD_CALLS, PROVIDER};
+
+    pub fn install() {
+        if let Err(e) = tracing::subscriber::set_global_default(CallSubscriber) {
+            log::warn!("Failed to install call tracing: {e}");
+        }
+    }
+
+    struct CallSubscriber;
+
+    impl Subscriber for CallSubscriber {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == wdf_umdf::trace::TARGET
+        }
+
+        // only events are traced
+        fn new_span(&self, _span: &span::Attributes<'_>) -> span::Id {
+            span::Id::from_u64(1)
+        }
+
+        fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+        fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut call = Call::default();
+            event.record(&mut call);
+
+            if *event.metadata().level() == Level::WARN {
+                log::warn!("{}({}) failed: {}", call.name, call.args, call.result);
+
+                tlg::write_event!(
+                    PROVIDER,
+                    "Call",
+                    level(Warning),
+                    keyword(KEYWORD_CALLS),
+                    str8("Call", &call.name),
+                    str8("Args", &call.args),
+                    str8("Result", &call.result),
+                    u64("ElapsedUs", &call.elapsed_us),
+                );
+            } else {
+                log::debug!("{}({}) = {}", call.name, call.args, call.result);
+
+                tlg::write_event!(
+                    PROVIDER,
+                    "Call",
+                    level(Verbose),
+                    keyword(KEYWORD_CALLS),
+                    str8("Call", &call.name),
+                    str8("Args", &call.args),
+                    str8("Result", &call.result),
+                    u64("ElapsedUs", &call.elapsed_us),
+                );
+            }
+        }
+
+        fn enter(&self, _span: &span::Id) {}
+
+        fn exit(&self, _span: &span::Id) {}
+    }
+
+    #[derive(Default)]
+    struct Call {
+        name: String,
+        args: String,
+        // the status, or the error of a failed call
+        result: String,
+        elapsed_us: u64,
+    }
+
+    impl Visit for Call {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            match field.name() {
+                "call" => self.name = value.to_owned(),
+                "args" => self.args = value.to_owned(),
+                _ => self.record_debug(field, &value),
+            }
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            if field.name() == "elapsed_us" {
+                self.elapsed_us = value;
+            }
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if matches!(field.name(), "status" | "error") {
+                self.result.clear();
+                _ = write!(self.result, "{value:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{
+    sync::{Arc, Mutex, OnceLock, Weak},
+    time::Duration,
+};
+
+use driver_ipc::EventKind;
+use log::{error, warn};
+use wdf_umdf::WdfTimer;
+use wdf_umdf_sys::WDFDEVICE;
+
+use crate::{events, swap_chain_processor::ProcessorState, trace};
+
+// A swap chain whose processing loop made no progress for this long is considered stalled,
+// e.g. after a gpu reset (TDR) left `ReleaseAndAcquireBuffer` hanging
+const STALL_TIMEOUT: Duration = Duration::from_secs(5);
+// How often the processors are checked
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+static WATCHED: Mutex<Vec<Weak<ProcessorState>>> = Mutex::new(Vec::new());
+// never dropped, the framework deletes it along with the device
+static TIMER: OnceLock<WdfTimer> = OnceLock::new();
+
+/// Start checking the watched processors every `CHECK_INTERVAL`, until `device` is deleted
+pub fn start(device: WDFDEVICE) {
+    let timer = WdfTimer::builder()
+        .period(CHECK_INTERVAL)
+        // a stall is only noticed after seconds anyway
+        .tolerable_delay(CHECK_INTERVAL / 2);
+
+    // SAFETY: The device was just added, and the timer is deleted along with it
+    match unsafe { timer.create(device.cast(), check) } {
+        Ok(timer) => {
+            timer.start(CHECK_INTERVAL);
+            // there's one device per host process, see `ipc::startup`
+            _ = TIMER.set(timer);
+        }
+
+        Err(e) => error!("Failed to create the swap chain watchdog timer: {e:?}"),
+    }
+}
+
+/// Watch a swap chain processor until it's dropped
+pub fn watch(state: &Arc<ProcessorState>) {
+    if let Ok(mut watched) = WATCHED.lock() {
+        watched.push(Arc::downgrade(state));
+    }
+}
+
+fn check() {
+    let stalled = {
+        let Ok(mut watched) = WATCHED.lock() else {
+            return;
+        };
+
+        watched.retain(|state| state.strong_count() > 0);
+
+        watched
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|state| !state.is_deleted() && state.since_heartbeat() >= STALL_TIMEOUT)
+            .collect::<Vec<_>>()
+    };
+
+    for state in stalled {
+        recover(&state);
+    }
+}
+
+/// Tear down a stalled swap chain, the os then assigns a new one which gets a fresh device
+fn recover(state: &ProcessorState) {
+    let stalled_for = state.since_heartbeat();
+    if !state.mark_stalled() {
+        return;
+    }
+
+    let id = state.monitor_id;
+    warn!("Swap chain of monitor {id} made no progress for {stalled_for:?}, re-creating it");
+    trace::swap_chain_event("Stalled", id);
+
+    #[allow(clippy::cast_possible_truncation)]
+    events::push(EventKind::SwapChainStalled {
+        id,
+        stalled_ms: stalled_for.as_millis() as u64,
+    });
+
+    if let Err(e) = state.delete_swap_chain() {
+        error!("Failed to delete stalled swap chain of monitor {id}: {e:?}");
+    }
+}
@@ -0,0 +1,161 @@
+//! Attribute macros generating the unsafe plumbing of a wdf-umdf driver
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    spanned::Spanned,
+    Error, Expr, FnArg, Ident, ItemFn, ItemStruct, ReturnType, Token,
+};
+
+/// Turn a function into a framework callback, which never unwinds into the framework
+///
+/// The function keeps its signature, and is wrapped in an `extern "C-unwind"` shim which runs it
+/// through a guard catching panics. The guard defaults to `wdf_umdf::guard`, and can be any
+/// function taking the callback as closure. For calls, the closure is passed as last argument.
+///
+/// ```rust,ignore
+/// #[wdf_callback(guard = counted(Callback::DeviceD0Exit))]
+/// fn device_d0_exit(device: WDFDEVICE, target_state: WDF_POWER_DEVICE_STATE) -> NTSTATUS {
+///     NTSTATUS::STATUS_SUCCESS
+/// }
+/// ```
+///
+/// Callbacks without a return value discard what the guard returns.
+#[proc_macro_attribute]
+pub fn wdf_callback(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as CallbackArgs);
+    let item = parse_macro_input!(item as ItemFn);
+
+    callback(args, &item)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Generate the `DriverEntry` of a driver, from a type implementing `wdf_umdf::IddDriver`
+///
+/// ```rust,ignore
+/// #[idd_driver]
+/// pub struct VirtualDisplayDriver;
+/// ```
+#[proc_macro_attribute]
+pub fn idd_driver(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return Error::new(Span::call_site(), "idd_driver takes no arguments")
+            .into_compile_error()
+            .into();
+    }
+
+    let item = parse_macro_input!(item as ItemStruct);
+    let name = &item.ident;
+
+    if !item.generics.params.is_empty() {
+        return Error::new(item.generics.span(), "a driver can't be generic")
+            .into_compile_error()
+            .into();
+    }
+
+    quote! {
+        #item
+
+        #[no_mangle]
+        extern "C-unwind" fn DriverEntry(
+            driver_object: *mut ::wdf_umdf::wdf_umdf_sys::_DRIVER_OBJECT,
+            registry_path: *mut ::wdf_umdf::wdf_umdf_sys::_UNICODE_STRING,
+        ) -> ::wdf_umdf::wdf_umdf_sys::NTSTATUS {
+            // SAFETY: Called by the framework with its own driver object and registry path
+            unsafe { ::wdf_umdf::driver_entry::<#name>(driver_object, registry_path) }
+        }
+    }
+    .into()
+}
+
+struct CallbackArgs {
+    guard: Option<Expr>,
+}
+
+impl Parse for CallbackArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self { guard: None });
+        }
+
+        let key = input.parse::<Ident>()?;
+        if key != "guard" {
+            return Err(Error::new(key.span(), "expected `guard = ...`"));
+        }
+
+        input.parse::<Token![=]>()?;
+        let guard = input.parse()?;
+
+        Ok(Self { guard: Some(guard) })
+    }
+}
+
+fn callback(args: CallbackArgs, item: &ItemFn) -> syn::Result<TokenStream2> {
+    let sig = &item.sig;
+
+    if let Some(abi) = &sig.abi {
+        return Err(Error::new(abi.span(), "the abi is added by wdf_callback"));
+    }
+
+    if !sig.generics.params.is_empty() || sig.asyncness.is_some() || sig.variadic.is_some() {
+        return Err(Error::new(
+            sig.span(),
+            "callbacks can't be generic, async or variadic",
+        ));
+    }
+
+    let mut inputs = Vec::new();
+    let mut names = Vec::new();
+    for (i, input) in sig.inputs.iter().enumerate() {
+        let FnArg::Typed(input) = input else {
+            return Err(Error::new(input.span(), "callbacks can't take self"));
+        };
+
+        // the shim takes plain names, so the callback can still use patterns like `mut init`
+        let name = format_ident!("__arg{i}");
+        let ty = &input.ty;
+        inputs.push(quote!(#name: #ty));
+        names.push(name);
+    }
+
+    let ident = &sig.ident;
+    let output = &sig.output;
+    let unsafety = &sig.unsafety;
+    let vis = &item.vis;
+    let attrs = &item.attrs;
+    let block = &item.block;
+
+    let call = if unsafety.is_some() {
+        // SAFETY: The shim has the same contract as the callback
+        quote!(move || unsafe { #ident(#(#names),*) })
+    } else {
+        quote!(move || #ident(#(#names),*))
+    };
+
+    let guarded = match args.guard {
+        Some(Expr::Call(mut guard)) => {
+            guard.args.push(parse_quote!(#call));
+            quote!(#guard)
+        }
+        Some(guard) => quote!((#guard)(#call)),
+        None => quote!(::wdf_umdf::guard(#call)),
+    };
+
+    let body = match output {
+        ReturnType::Default => quote!(_ = #guarded;),
+        ReturnType::Type(..) => guarded,
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #unsafety extern "C-unwind" fn #ident(#(#inputs),*) #output {
+            #sig #block
+
+            #body
+        }
+    })
+}
@@ -1,5 +1,5 @@
 use bindgen::Abi;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[cfg(target_os = "windows")]
 use winreg::enums::HKEY_LOCAL_MACHINE;
@@ -12,6 +12,8 @@ pub enum Error {
     IoError(#[from] std::io::Error),
     #[error("cannot find the directory")]
     DirectoryNotFound,
+    #[error("WDK version {0} is not installed")]
+    VersionNotFound(String),
 }
 
 /// Retrieves the path to the Windows Kits directory. The default should be
@@ -34,34 +36,51 @@ pub enum DirectoryType {
     Library,
 }
 
-/// Retrieves the path to the user mode libraries. The path may look something like:
-/// `C:\Program Files (x86)\Windows Kits\10\lib\10.0.18362.0\um`.
+/// Retrieves the directory of the WDK version to build against in `dir`, which has to contain
+/// `subdir`. That's the one set in `WDK_VERSION` (e.g. `10.0.22621.0`), or the newest one.
 ///
 /// # Errors
 /// Returns IO error if failed
 #[cfg(target_os = "windows")]
-pub fn get_um_dir(dir_type: DirectoryType) -> Result<PathBuf, Error> {
-    // We first append lib to the path and read the directory..
-    let dir = get_windows_kits_dir()?
-        .join(match dir_type {
-            DirectoryType::Include => "Include",
-            DirectoryType::Library => "Lib",
-        })
-        .read_dir()?;
+pub fn get_version_dir(dir: &Path, subdir: &str) -> Result<PathBuf, Error> {
+    if let Some(version) = wdk_version_override() {
+        let dir = dir.join(&version);
+        return if dir.join(subdir).is_dir() {
+            Ok(dir)
+        } else {
+            Err(Error::VersionNotFound(version))
+        };
+    }
 
-    // In the lib directory we may have one or more directories named after the version of Windows,
+    // In the directory we may have one or more directories named after the version of Windows,
     // we will be looking for the highest version number.
-    let mut dir = dir
+    dir.read_dir()?
         .filter_map(Result::ok)
         .map(|dir| dir.path())
         .filter(|dir| {
             dir.components()
                 .last()
                 .and_then(|c| c.as_os_str().to_str())
-                .map_or(false, |c| c.starts_with("10.") && dir.join("um").is_dir())
+                .map_or(false, |c| c.starts_with("10.") && dir.join(subdir).is_dir())
         })
         .max()
-        .ok_or_else(|| Error::DirectoryNotFound)?;
+        .ok_or_else(|| Error::DirectoryNotFound)
+}
+
+/// Retrieves the path to the user mode libraries. The path may look something like:
+/// `C:\Program Files (x86)\Windows Kits\10\lib\10.0.18362.0\um`.
+///
+/// # Errors
+/// Returns IO error if failed
+#[cfg(target_os = "windows")]
+pub fn get_um_dir(dir_type: DirectoryType) -> Result<PathBuf, Error> {
+    // We first append lib to the path and read the directory..
+    let dir = get_windows_kits_dir()?.join(match dir_type {
+        DirectoryType::Include => "Include",
+        DirectoryType::Library => "Lib",
+    });
+
+    let mut dir = get_version_dir(&dir, "um")?;
 
     dir.push("um");
 
@@ -77,9 +96,11 @@ pub fn get_um_dir(dir_type: DirectoryType) -> Result<PathBuf, Error> {
 /// Returns IO error if failed
 #[cfg(target_os = "windows")]
 pub fn get_umdf_dir(dir_type: DirectoryType) -> Result<PathBuf, Error> {
+    let version = umdf_version();
+
     Ok(get_windows_kits_dir()?.join(match dir_type {
-        DirectoryType::Include => PathBuf::from_iter(["Include", "wdf", "umdf", "2.31"]),
-        DirectoryType::Library => PathBuf::from_iter(["Lib", "wdf", "umdf", "x64", "2.31"]),
+        DirectoryType::Include => PathBuf::from_iter(["Include", "wdf", "umdf", &version]),
+        DirectoryType::Library => PathBuf::from_iter(["Lib", "wdf", "umdf", "x64", &version]),
     }))
 }
 
@@ -90,29 +111,48 @@ pub fn get_umdf_dir(dir_type: DirectoryType) -> Result<PathBuf, Error> {
 /// Returns IO error if failed
 #[cfg(target_os = "windows")]
 pub fn get_shared_dir() -> Result<PathBuf, Error> {
-    // We first append lib to the path and read the directory..
-    let dir = get_windows_kits_dir()?.join("Include").read_dir()?;
-
-    // In the lib directory we may have one or more directories named after the version of Windows,
-    // we will be looking for the highest version number.
-    let dir = dir
-        .filter_map(Result::ok)
-        .map(|dir| dir.path())
-        .filter(|dir| {
-            dir.components()
-                .last()
-                .and_then(|c| c.as_os_str().to_str())
-                .map_or(false, |c| {
-                    c.starts_with("10.") && dir.join("shared").is_dir()
-                })
-        })
-        .max()
-        .ok_or_else(|| Error::DirectoryNotFound)?;
+    let dir = get_version_dir(&get_windows_kits_dir()?.join("Include"), "shared")?;
 
     // Finally append shared to the path to get the path to the shared headers.
     Ok(dir.join("shared"))
 }
 
+/// IddCx versions that can be generated against, newest first, with the features selecting them
+#[cfg(target_os = "windows")]
+const IDDCX_VERSIONS: &[(&str, &str)] = &[
+    ("CARGO_FEATURE_IDDCX_1_10", "1.10"),
+    ("CARGO_FEATURE_IDDCX_1_9", "1.9"),
+    ("CARGO_FEATURE_IDDCX_1_8", "1.8"),
+    ("CARGO_FEATURE_IDDCX_1_7", "1.7"),
+    ("CARGO_FEATURE_IDDCX_1_6", "1.6"),
+    ("CARGO_FEATURE_IDDCX_1_5", "1.5"),
+];
+
+/// The IddCx version to generate the bindings against. Features enable the versions before them
+/// too, so this is the newest one enabled, or 1.4 without any.
+#[cfg(target_os = "windows")]
+fn iddcx_version() -> &'static str {
+    IDDCX_VERSIONS
+        .iter()
+        .find(|(feature, _)| std::env::var_os(feature).is_some())
+        .map_or("1.4", |&(_, version)| version)
+}
+
+/// The WDK version set in `WDK_VERSION`, if any
+#[cfg(target_os = "windows")]
+fn wdk_version_override() -> Option<String> {
+    std::env::var("WDK_VERSION").ok().filter(|v| !v.is_empty())
+}
+
+/// The UMDF version set in `UMDF_VERSION`, 2.31 by default
+#[cfg(target_os = "windows")]
+fn umdf_version() -> String {
+    std::env::var("UMDF_VERSION")
+        .ok()
+        .filter(|v| !v.is_empty())
+        .unwrap_or_else(|| "2.31".to_owned())
+}
+
 #[cfg(target_os = "windows")]
 fn build_dir() -> PathBuf {
     PathBuf::from(
@@ -131,6 +171,15 @@ fn generate() {
 
     // Tell Cargo to re-run this if src/wrapper.h gets changed.
     println!("cargo:rerun-if-changed=c/wrapper.h");
+    println!("cargo:rerun-if-env-changed=WDK_VERSION");
+    println!("cargo:rerun-if-env-changed=UMDF_VERSION");
+
+    // let the crate report which WDK it was built with
+    let wdk_version = include_um_dir
+        .parent()
+        .and_then(Path::file_name)
+        .map_or_else(String::new, |v| v.to_string_lossy().into_owned());
+    println!("cargo:rustc-env=WDK_VERSION={wdk_version}");
 
     //
     // UMDF
@@ -149,9 +198,14 @@ fn generate() {
     // IDDCX
     //
 
+    let iddcx_version = iddcx_version();
+
+    // let the crate report which headers it was generated from
+    println!("cargo:rustc-env=IDDCX_VERSION={iddcx_version}");
+
     let mut iddcx_lib_dir = lib_um_dir.clone();
     iddcx_lib_dir.push("iddcx");
-    iddcx_lib_dir.push("1.4");
+    iddcx_lib_dir.push(iddcx_version);
 
     println!("cargo:rustc-link-search={}", iddcx_lib_dir.display());
 
@@ -180,6 +234,8 @@ fn generate() {
         // umdf includes
         .clang_arg(format!("-I{}", wdf_include_dir.display()))
         .clang_arg(format!("-I{}", shared.display()))
+        // the iddcx header of the selected version, see `c/wrapper.h`
+        .clang_arg(format!("-DIDDCX_HEADER=<iddcx/{iddcx_version}/IddCx.h>"))
         .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
         .blocklist_type("_?P?IMAGE_TLS_DIRECTORY.*")
         // we will use our own custom type
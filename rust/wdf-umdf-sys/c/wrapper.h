@@ -21,4 +21,5 @@
 
 #define IDD_STUB
 
-#include <iddcx\1.4\IddCx.h>
+// Set by build.rs to the header of the selected IddCx version, e.g. <iddcx/1.4/IddCx.h>
+#include IDDCX_HEADER
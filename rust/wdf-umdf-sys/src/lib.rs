@@ -9,6 +9,12 @@ pub use bindings::*;
 pub use ntstatus::*;
 pub use paste::paste;
 
+/// IddCx version the bindings were generated against, e.g. `1.4`, see the `iddcx-*` features
+pub const IDDCX_VERSION: &str = env!("IDDCX_VERSION");
+
+/// Windows SDK/WDK version the crate was built with, e.g. `10.0.22621.0`, see `WDK_VERSION`
+pub const WDK_VERSION: &str = env!("WDK_VERSION");
+
 #[macro_export]
 macro_rules! WdfIsFunctionAvailable {
     ($name:ident) => {{
@@ -167,6 +173,75 @@ impl WDF_PNPPOWER_EVENT_CALLBACKS {
     }
 }
 
+impl WDF_TIMER_CONFIG {
+    /// Initializes the [`WDF_TIMER_CONFIG`] structure for a non-periodic timer
+    /// <https://github.com/microsoft/Windows-Driver-Frameworks/blob/a94b8c30dad524352fab90872aefc83920b98e56/src/publicinc/wdf/umdf/2.33/wdftimer.h#L75/>
+    ///
+    /// Sets `AutomaticSerialization` to `TRUE`
+    #[must_use]
+    pub fn init(EvtTimerFunc: PFN_WDF_TIMER) -> Self {
+        // SAFETY: All fields are zero-able
+        let mut config: Self = unsafe { core::mem::zeroed() };
+
+        config.Size = WDF_STRUCTURE_SIZE!(Self);
+        config.EvtTimerFunc = EvtTimerFunc;
+        config.AutomaticSerialization = 1;
+
+        config
+    }
+}
+
+impl WDF_WORKITEM_CONFIG {
+    /// Initializes the [`WDF_WORKITEM_CONFIG`] structure
+    /// <https://github.com/microsoft/Windows-Driver-Frameworks/blob/a94b8c30dad524352fab90872aefc83920b98e56/src/publicinc/wdf/umdf/2.33/wdfworkitem.h#L58/>
+    ///
+    /// Sets `AutomaticSerialization` to `TRUE`
+    #[must_use]
+    pub fn init(EvtWorkItemFunc: PFN_WDF_WORKITEM) -> Self {
+        // SAFETY: All fields are zero-able
+        let mut config: Self = unsafe { core::mem::zeroed() };
+
+        config.Size = WDF_STRUCTURE_SIZE!(Self);
+        config.EvtWorkItemFunc = EvtWorkItemFunc;
+        config.AutomaticSerialization = 1;
+
+        config
+    }
+}
+
+impl WDF_IO_QUEUE_CONFIG {
+    /// Initializes the [`WDF_IO_QUEUE_CONFIG`] structure
+    /// <https://github.com/microsoft/Windows-Driver-Frameworks/blob/a94b8c30dad524352fab90872aefc83920b98e56/src/publicinc/wdf/umdf/2.33/wdfio.h#L434/>
+    ///
+    /// Sets `PowerManaged` to [`WDF_TRI_STATE::WdfUseDefault`], and parallel queues to present any
+    /// number of requests at once
+    #[must_use]
+    pub fn init(DispatchType: WDF_IO_QUEUE_DISPATCH_TYPE) -> Self {
+        // SAFETY: All fields are zero-able
+        let mut config: Self = unsafe { core::mem::zeroed() };
+
+        config.Size = WDF_STRUCTURE_SIZE!(Self);
+        config.PowerManaged = WDF_TRI_STATE::WdfUseDefault;
+        config.DispatchType = DispatchType;
+
+        if DispatchType == WDF_IO_QUEUE_DISPATCH_TYPE::WdfIoQueueDispatchParallel {
+            config.Settings.Parallel.NumberOfPresentedRequests = ULONG::MAX;
+        }
+
+        config
+    }
+
+    /// Initializes the [`WDF_IO_QUEUE_CONFIG`] structure for the default queue of a device
+    /// <https://github.com/microsoft/Windows-Driver-Frameworks/blob/a94b8c30dad524352fab90872aefc83920b98e56/src/publicinc/wdf/umdf/2.33/wdfio.h#L458/>
+    #[must_use]
+    pub fn init_default_queue(DispatchType: WDF_IO_QUEUE_DISPATCH_TYPE) -> Self {
+        let mut config = Self::init(DispatchType);
+        config.DefaultQueue = 1;
+
+        config
+    }
+}
+
 /// If this returns None, the struct is NOT available to be used
 macro_rules! IDD_STRUCTURE_SIZE {
     ($name:ty) => {{
@@ -0,0 +1,298 @@
+//! Builders for the structs an indirect display driver hands to IddCx on startup
+
+use std::{marker::PhantomData, mem::size_of, ptr::addr_of_mut};
+
+use wdf_umdf_sys::{
+    IDARG_IN_ADAPTER_INIT, IDARG_IN_ADAPTER_INIT_FINISHED, IDARG_IN_COMMITMODES,
+    IDARG_IN_GETDEFAULTDESCRIPTIONMODES, IDARG_IN_I2C_RECEIVE, IDARG_IN_I2C_TRANSMIT,
+    IDARG_IN_PARSEMONITORDESCRIPTION, IDARG_IN_QUERYTARGETMODES, IDARG_IN_SETSWAPCHAIN,
+    IDARG_IN_SET_GAMMARAMP, IDARG_OUT_ADAPTER_INIT, IDARG_OUT_GETDEFAULTDESCRIPTIONMODES,
+    IDARG_OUT_I2C_RECEIVE, IDARG_OUT_PARSEMONITORDESCRIPTION, IDARG_OUT_QUERYTARGETMODES,
+    IDDCX_ADAPTER, IDDCX_ADAPTER_CAPS, IDDCX_ADAPTER_FLAGS, IDDCX_ADAPTER__,
+    IDDCX_ENDPOINT_DIAGNOSTIC_INFO, IDDCX_ENDPOINT_VERSION, IDDCX_FEATURE_IMPLEMENTATION,
+    IDDCX_MONITOR__, IDDCX_TRANSMISSION_TYPE, IDD_CX_CLIENT_CONFIG, NTSTATUS, WDFDEVICE,
+    WDFDEVICE_INIT, WDFREQUEST, WDF_OBJECT_ATTRIBUTES,
+};
+
+use crate::{IddCxAdapterInitAsync, IddCxDeviceInitConfig, IddCxError};
+
+pub type AdapterInitFinished =
+    extern "C-unwind" fn(*mut IDDCX_ADAPTER__, *const IDARG_IN_ADAPTER_INIT_FINISHED) -> NTSTATUS;
+pub type ParseMonitorDescription = extern "C-unwind" fn(
+    *const IDARG_IN_PARSEMONITORDESCRIPTION,
+    *mut IDARG_OUT_PARSEMONITORDESCRIPTION,
+) -> NTSTATUS;
+pub type MonitorGetDefaultModes = extern "C-unwind" fn(
+    *mut IDDCX_MONITOR__,
+    *const IDARG_IN_GETDEFAULTDESCRIPTIONMODES,
+    *mut IDARG_OUT_GETDEFAULTDESCRIPTIONMODES,
+) -> NTSTATUS;
+pub type MonitorQueryTargetModes = extern "C-unwind" fn(
+    *mut IDDCX_MONITOR__,
+    *const IDARG_IN_QUERYTARGETMODES,
+    *mut IDARG_OUT_QUERYTARGETMODES,
+) -> NTSTATUS;
+pub type AdapterCommitModes =
+    extern "C-unwind" fn(*mut IDDCX_ADAPTER__, *const IDARG_IN_COMMITMODES) -> NTSTATUS;
+pub type MonitorAssignSwapChain =
+    extern "C-unwind" fn(*mut IDDCX_MONITOR__, *const IDARG_IN_SETSWAPCHAIN) -> NTSTATUS;
+pub type MonitorUnassignSwapChain = extern "C-unwind" fn(*mut IDDCX_MONITOR__) -> NTSTATUS;
+pub type MonitorSetGammaRamp =
+    extern "C-unwind" fn(*mut IDDCX_MONITOR__, *const IDARG_IN_SET_GAMMARAMP) -> NTSTATUS;
+pub type MonitorI2CTransmit =
+    extern "C-unwind" fn(*mut IDDCX_MONITOR__, *const IDARG_IN_I2C_TRANSMIT) -> NTSTATUS;
+pub type MonitorI2CReceive = extern "C-unwind" fn(
+    *mut IDDCX_MONITOR__,
+    *const IDARG_IN_I2C_RECEIVE,
+    *mut IDARG_OUT_I2C_RECEIVE,
+) -> NTSTATUS;
+pub type DeviceIoControl = extern "C-unwind" fn(WDFDEVICE, WDFREQUEST, usize, usize, u32);
+
+/// Callbacks IddCx refuses to start a driver without
+#[derive(Debug, Clone, Copy)]
+pub struct RequiredCallbacks {
+    pub adapter_init_finished: AdapterInitFinished,
+    pub parse_monitor_description: ParseMonitorDescription,
+    pub monitor_get_default_modes: MonitorGetDefaultModes,
+    pub monitor_query_target_modes: MonitorQueryTargetModes,
+    pub adapter_commit_modes: AdapterCommitModes,
+    pub monitor_assign_swap_chain: MonitorAssignSwapChain,
+    pub monitor_unassign_swap_chain: MonitorUnassignSwapChain,
+}
+
+/// State of an [`IddCxConfigBuilder`] before the required callbacks are set
+pub struct MissingCallbacks;
+/// State of an [`IddCxConfigBuilder`] which can be built
+pub struct Ready;
+
+/// Builds the `IDD_CX_CLIENT_CONFIG` of a driver
+///
+/// Only builds once [`RequiredCallbacks`] are set, so a forgotten callback doesn't compile.
+///
+/// ```rust,ignore
+/// IddCxConfigBuilder::new()?
+///     .callbacks(RequiredCallbacks { .. })
+///     .gamma_ramp(monitor_set_gamma_ramp)
+///     .init(device_init)?;
+/// ```
+pub struct IddCxConfigBuilder<State> {
+    config: IDD_CX_CLIENT_CONFIG,
+    _state: PhantomData<State>,
+}
+
+impl IddCxConfigBuilder<MissingCallbacks> {
+    /// `None` if the framework doesn't know `IDD_CX_CLIENT_CONFIG`
+    #[must_use]
+    pub fn new() -> Option<Self> {
+        Some(Self {
+            config: IDD_CX_CLIENT_CONFIG::init()?,
+            _state: PhantomData,
+        })
+    }
+
+    #[must_use]
+    pub fn callbacks(mut self, callbacks: RequiredCallbacks) -> IddCxConfigBuilder<Ready> {
+        let config = &mut self.config;
+
+        config.EvtIddCxAdapterInitFinished = Some(callbacks.adapter_init_finished);
+        config.EvtIddCxParseMonitorDescription = Some(callbacks.parse_monitor_description);
+        config.EvtIddCxMonitorGetDefaultDescriptionModes =
+            Some(callbacks.monitor_get_default_modes);
+        config.EvtIddCxMonitorQueryTargetModes = Some(callbacks.monitor_query_target_modes);
+        config.EvtIddCxAdapterCommitModes = Some(callbacks.adapter_commit_modes);
+        config.EvtIddCxMonitorAssignSwapChain = Some(callbacks.monitor_assign_swap_chain);
+        config.EvtIddCxMonitorUnassignSwapChain = Some(callbacks.monitor_unassign_swap_chain);
+
+        IddCxConfigBuilder {
+            config: self.config,
+            _state: PhantomData,
+        }
+    }
+}
+
+impl<State> IddCxConfigBuilder<State> {
+    /// Receive the gamma ramps the os sets on monitors
+    #[must_use]
+    pub fn gamma_ramp(mut self, callback: MonitorSetGammaRamp) -> Self {
+        self.config.EvtIddCxMonitorSetGammaRamp = Some(callback);
+        self
+    }
+
+    /// Answer i2c traffic to monitors, like DDC/CI
+    #[must_use]
+    pub fn i2c(mut self, transmit: MonitorI2CTransmit, receive: MonitorI2CReceive) -> Self {
+        self.config.EvtIddCxMonitorI2CTransmit = Some(transmit);
+        self.config.EvtIddCxMonitorI2CReceive = Some(receive);
+        self
+    }
+
+    /// Handle device control requests (IOCTLs) sent to the device, which IddCx owns the queue of
+    #[must_use]
+    pub fn device_io_control(mut self, callback: DeviceIoControl) -> Self {
+        self.config.EvtIddCxDeviceIoControl = Some(callback);
+        self
+    }
+}
+
+impl IddCxConfigBuilder<Ready> {
+    #[must_use]
+    pub fn build(self) -> IDD_CX_CLIENT_CONFIG {
+        self.config
+    }
+
+    /// Build the config and hand it to the framework, see `IddCxDeviceInitConfig`
+    ///
+    /// # Safety
+    ///
+    /// `device_init` must be the one passed to `EvtDriverDeviceAdd`, before the device is created
+    pub unsafe fn init(self, device_init: &mut WDFDEVICE_INIT) -> Result<(), IddCxError> {
+        // SAFETY: See above
+        unsafe { IddCxDeviceInitConfig(device_init, &self.config) }?;
+        Ok(())
+    }
+}
+
+/// Describes an adapter for `IddCxAdapterInitAsync`, from which its `IDDCX_ADAPTER_CAPS` are built
+///
+/// ```rust,ignore
+/// let adapter = AdapterInit::new("Virtual Display Driver Adapter", 16)
+///     .manufacturer("Cherry")
+///     .version(1, 0, 0)
+///     .init_async(device, &mut attributes)?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct AdapterInit {
+    name: Vec<u16>,
+    manufacturer: Option<Vec<u16>>,
+    model: Option<Vec<u16>>,
+    version: Option<IDDCX_ENDPOINT_VERSION>,
+    max_monitors: u32,
+    gamma_support: IDDCX_FEATURE_IMPLEMENTATION,
+    transmission_type: IDDCX_TRANSMISSION_TYPE,
+    flags: IDDCX_ADAPTER_FLAGS,
+}
+
+impl AdapterInit {
+    /// An adapter with the friendly `name` shown to users, for up to `max_monitors` monitors
+    #[must_use]
+    pub fn new(name: &str, max_monitors: u32) -> Self {
+        Self {
+            name: wide(name),
+            manufacturer: None,
+            model: None,
+            version: None,
+            max_monitors,
+            gamma_support: IDDCX_FEATURE_IMPLEMENTATION::IDDCX_FEATURE_IMPLEMENTATION_NONE,
+            transmission_type: IDDCX_TRANSMISSION_TYPE::IDDCX_TRANSMISSION_TYPE_OTHER,
+            flags: IDDCX_ADAPTER_FLAGS::IDDCX_ADAPTER_FLAGS_NONE,
+        }
+    }
+
+    #[must_use]
+    pub fn manufacturer(mut self, manufacturer: &str) -> Self {
+        self.manufacturer = Some(wide(manufacturer));
+        self
+    }
+
+    #[must_use]
+    pub fn model(mut self, model: &str) -> Self {
+        self.model = Some(wide(model));
+        self
+    }
+
+    /// Reported as both the firmware and hardware version
+    #[must_use]
+    pub fn version(mut self, major: u32, minor: u32, build: u32) -> Self {
+        self.version = Some(IDDCX_ENDPOINT_VERSION {
+            #[allow(clippy::cast_possible_truncation)]
+            Size: size_of::<IDDCX_ENDPOINT_VERSION>() as u32,
+            MajorVer: major,
+            MinorVer: minor,
+            Build: build,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Who applies gamma ramps set on the adapter's monitors
+    #[must_use]
+    pub fn gamma_support(mut self, support: IDDCX_FEATURE_IMPLEMENTATION) -> Self {
+        self.gamma_support = support;
+        self
+    }
+
+    /// How frames get to the monitors
+    #[must_use]
+    pub fn transmission_type(mut self, transmission_type: IDDCX_TRANSMISSION_TYPE) -> Self {
+        self.transmission_type = transmission_type;
+        self
+    }
+
+    #[must_use]
+    pub fn flags(mut self, flags: IDDCX_ADAPTER_FLAGS) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Start initializing the adapter of `device`, it's ready once `EvtIddCxAdapterInitFinished`
+    /// is called
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a valid device initialized with `IddCxDeviceInitialize`
+    pub unsafe fn init_async(
+        mut self,
+        device: WDFDEVICE,
+        attributes: &mut WDF_OBJECT_ATTRIBUTES,
+    ) -> Result<IDDCX_ADAPTER, IddCxError> {
+        let version = self
+            .version
+            .as_mut()
+            .map_or(std::ptr::null_mut(), |version| addr_of_mut!(*version));
+
+        let mut caps = IDDCX_ADAPTER_CAPS {
+            #[allow(clippy::cast_possible_truncation)]
+            Size: size_of::<IDDCX_ADAPTER_CAPS>() as u32,
+
+            Flags: self.flags,
+            MaxMonitorsSupported: self.max_monitors,
+
+            EndPointDiagnostics: IDDCX_ENDPOINT_DIAGNOSTIC_INFO {
+                #[allow(clippy::cast_possible_truncation)]
+                Size: size_of::<IDDCX_ENDPOINT_DIAGNOSTIC_INFO>() as u32,
+                GammaSupport: self.gamma_support,
+                TransmissionType: self.transmission_type,
+
+                pEndPointFriendlyName: self.name.as_ptr(),
+                pEndPointManufacturerName: optional_ptr(self.manufacturer.as_deref()),
+                pEndPointModelName: optional_ptr(self.model.as_deref()),
+
+                pFirmwareVersion: version.cast(),
+                pHardwareVersion: version.cast(),
+            },
+
+            ..Default::default()
+        };
+
+        let args = IDARG_IN_ADAPTER_INIT {
+            WdfDevice: device,
+            pCaps: addr_of_mut!(caps).cast(),
+            ObjectAttributes: addr_of_mut!(*attributes).cast(),
+        };
+
+        let mut out = IDARG_OUT_ADAPTER_INIT::default();
+        // SAFETY: The caps point into self, which outlives the call, the device is valid, see above
+        unsafe { IddCxAdapterInitAsync(&args, &mut out) }?;
+
+        Ok(out.AdapterObject)
+    }
+}
+
+// Nul terminated wide string
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain([0]).collect()
+}
+
+fn optional_ptr(s: Option<&[u16]>) -> *const u16 {
+    s.map_or(std::ptr::null(), <[u16]>::as_ptr)
+}
@@ -0,0 +1,491 @@
+use std::{
+    cell::UnsafeCell,
+    mem::{self, MaybeUninit},
+    ptr::NonNull,
+    sync::{Arc, RwLock, Weak},
+};
+
+use wdf_umdf_sys::{
+    PCWDF_OBJECT_CONTEXT_TYPE_INFO, WDFOBJECT, WDF_OBJECT_ATTRIBUTES, _WDF_OBJECT_CONTEXT_TYPE_INFO,
+};
+
+#[cfg(not(test))]
+use crate::WdfObjectGetTypedContextWorker;
+use crate::{catch_panic, WdfError};
+#[cfg(test)]
+use tests::WdfObjectGetTypedContextWorker;
+
+// Alignment of the context memory the framework allocates, `MEMORY_ALLOCATION_ALIGNMENT`
+const CONTEXT_ALIGNMENT: usize = 2 * mem::size_of::<usize>();
+
+/// Unlike the official `WDF_DECLARE_CONTEXT_TYPE` macro, you only need to declare this on the actual data struct want to use
+///
+/// This implements [`WdfContext`] for `$context_type`, after which it can be used as the context
+/// of objects through [`WdfObjectContext`]. Safety is maintained through a `RwLock` of the underlying data
+///
+/// Example:
+/// ```rust,ignore
+/// pub struct IndirectDeviceContext {
+///     device: WDFDEVICE,
+/// }
+///
+/// WDF_DECLARE_CONTEXT_TYPE!(IndirectDeviceContext);
+///
+/// let mut attributes = WdfObjectContext::<IndirectDeviceContext>::attributes();
+/// // create the device with `attributes`, then
+/// WdfObjectContext::init(device as WDFOBJECT, IndirectDeviceContext { device }).unwrap();
+/// // elsewhere
+/// WdfObjectContext::<IndirectDeviceContext>::get_mut(device as WDFOBJECT, |context| ()).unwrap();
+/// ```
+#[macro_export]
+macro_rules! WDF_DECLARE_CONTEXT_TYPE {
+    ($context_type:ident) => {
+        // SAFETY: The type info is declared for this type right here
+        unsafe impl $crate::WdfContext for $context_type {
+            fn type_info() -> &'static $crate::ContextTypeInfo {
+                static TYPE_INFO: $crate::ContextTypeInfo =
+                    $crate::ContextTypeInfo::new::<$context_type>(
+                        concat!(stringify!($context_type), "\0"),
+                        &TYPE_INFO,
+                    );
+
+                &TYPE_INFO
+            }
+        }
+    };
+}
+
+/// A type that can be the context of objects, implemented with `WDF_DECLARE_CONTEXT_TYPE!`
+///
+/// # Safety
+///
+/// `type_info` must return type info declared for exactly this type
+pub unsafe trait WdfContext: Send + Sync + Sized + 'static {
+    fn type_info() -> &'static ContextTypeInfo;
+}
+
+/// Static type info of a context type, see `WDF_DECLARE_CONTEXT_TYPE!`
+#[repr(transparent)]
+pub struct ContextTypeInfo(UnsafeCell<_WDF_OBJECT_CONTEXT_TYPE_INFO>);
+
+// SAFETY: It's never mutated, it's only in an UnsafeCell in case C does
+unsafe impl Sync for ContextTypeInfo {}
+
+impl ContextTypeInfo {
+    /// Type info of context `T`, `unique` has to point to the returned info itself
+    ///
+    /// Fails to compile if `name` isn't nul terminated, or the context memory of the framework
+    /// can't hold what is stored for `T`
+    #[doc(hidden)]
+    #[must_use]
+    pub const fn new<T>(name: &'static str, unique: *const ContextTypeInfo) -> Self {
+        let bytes = name.as_bytes();
+        assert!(
+            !bytes.is_empty() && bytes[bytes.len() - 1] == 0,
+            "context name must be nul terminated"
+        );
+        assert!(
+            mem::align_of::<WdfObjectContext<T>>() <= CONTEXT_ALIGNMENT,
+            "context memory is not aligned enough for the context"
+        );
+        assert!(
+            mem::size_of::<WdfObjectContext<T>>() > 0,
+            "context memory must not be empty"
+        );
+
+        Self(UnsafeCell::new(_WDF_OBJECT_CONTEXT_TYPE_INFO {
+            #[allow(clippy::cast_possible_truncation)]
+            Size: mem::size_of::<_WDF_OBJECT_CONTEXT_TYPE_INFO>() as u32,
+            ContextName: name.as_ptr().cast(),
+            ContextSize: mem::size_of::<WdfObjectContext<T>>(),
+            // SAFETY: ContextTypeInfo and UnsafeCell are both repr(transparent), so cast to underlying _WDF_OBJECT_CONTEXT_TYPE_INFO is ok
+            UniqueType: unique.cast(),
+            EvtDriverGetUniqueContextType: None,
+        }))
+    }
+
+    fn as_ptr(&self) -> PCWDF_OBJECT_CONTEXT_TYPE_INFO {
+        self.0.get()
+    }
+}
+
+/// Allows us to keep ONE main Arc allocation while handing out weak pointers to the rest of the clones.
+/// In this way, we can drop the allocation by dropping 1 arc, while letting others still access it
+enum ArcPointer<T> {
+    Strong(Arc<T>),
+    Weak(Weak<T>),
+}
+
+/// Context `T` of an object, as stored in the object's context memory
+///
+/// The framework zero fills context memory, so it starts out uninitialized. Accessing the context
+/// before `init`/`share` or after `drop` fails with [`WdfError::NoContext`].
+#[repr(C)]
+pub struct WdfObjectContext<T> {
+    initialized: bool,
+    pointer: MaybeUninit<ArcPointer<RwLock<T>>>,
+}
+
+impl<T: WdfContext> WdfObjectContext<T> {
+    /// Attributes to create an object with context `T`
+    ///
+    /// The context is dropped when the object is cleaned up. Objects that need to do more on
+    /// cleanup can replace `EvtCleanupCallback`, and call [`Self::drop`] from their callback.
+    #[must_use]
+    pub fn attributes() -> WDF_OBJECT_ATTRIBUTES {
+        // SAFETY: Reading is always fine, it's never mutated
+        let type_info = unsafe { &*T::type_info().as_ptr() };
+
+        let mut attributes = WDF_OBJECT_ATTRIBUTES::init_context_type(type_info);
+        attributes.EvtCleanupCallback = Some(Self::cleanup);
+
+        attributes
+    }
+
+    /// Place `value` into the context of `handle`, dropping a previous one
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object, created with the attributes of `T`
+    /// - The context of `handle` must not be accessed at the same time
+    pub unsafe fn init(handle: WDFOBJECT, value: T) -> Result<(), WdfError> {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::context(handle) }?;
+        // SAFETY: Not accessed anywhere else at the same time, see above
+        let context = unsafe { &mut *context.as_ptr() };
+
+        context.release();
+        context
+            .pointer
+            .write(ArcPointer::Strong(Arc::new(RwLock::new(value))));
+        context.initialized = true;
+
+        Ok(())
+    }
+
+    /// Share the context of `from` with `to`, which doesn't keep it alive
+    ///
+    /// Internally, these are Arc's, so they will always point to the same data. Once the context
+    /// of `from` is dropped, accessing the context of `to` fails with [`WdfError::UpgradeFailed`].
+    ///
+    /// # Safety
+    ///
+    /// - `from` and `to` must be valid objects, created with the attributes of `T`
+    /// - The context of `to` must not be accessed at the same time
+    pub unsafe fn share(from: WDFOBJECT, to: WDFOBJECT) -> Result<(), WdfError> {
+        // SAFETY: Upheld by the caller
+        let shared = unsafe { Self::shared(from) }?;
+
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::context(to) }?;
+        // SAFETY: Not accessed anywhere else at the same time, see above
+        let context = unsafe { &mut *context.as_ptr() };
+
+        context.release();
+        context
+            .pointer
+            .write(ArcPointer::Weak(Arc::downgrade(&shared)));
+        context.initialized = true;
+
+        Ok(())
+    }
+
+    /// Drop the context of `handle`
+    ///
+    /// NOTE: Dropping a context that was shared with `share` never drops the data itself.
+    ///       That only happens once the context it was shared from is dropped
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object, created with the attributes of `T`
+    /// - The context of `handle` must not be accessed at the same time
+    pub unsafe fn drop(handle: WDFOBJECT) -> Result<(), WdfError> {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::context(handle) }?;
+        // SAFETY: Not accessed anywhere else at the same time, see above
+        let context = unsafe { &mut *context.as_ptr() };
+
+        context.release();
+
+        Ok(())
+    }
+
+    /// Cleanup callback of objects created with [`Self::attributes`], drops their context
+    ///
+    /// # Safety
+    ///
+    /// Only to be called by the framework
+    pub unsafe extern "C-unwind" fn cleanup(handle: WDFOBJECT) {
+        // SAFETY: The framework calls this once the object isn't used anymore
+        let drop = || unsafe { Self::drop(handle) };
+
+        // a panic in the context's drop must not unwind into the framework
+        _ = unsafe { catch_panic(None, drop) };
+    }
+
+    /// Borrow the context immutably
+    /// Function returns with error and won't call cb if it failed to lock
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object, created with the attributes of `T`
+    /// - Its context must not be initialized or dropped at the same time
+    pub unsafe fn get<F>(handle: WDFOBJECT, cb: F) -> Result<(), WdfError>
+    where
+        F: FnOnce(&T),
+    {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::shared(handle) }?;
+        let guard = context.read().map_err(|_| WdfError::LockFailed)?;
+
+        cb(&guard);
+
+        Ok(())
+    }
+
+    /// Borrow the context mutably
+    /// Function returns with error and won't call cb if it failed to lock
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object, created with the attributes of `T`
+    /// - Its context must not be initialized or dropped at the same time
+    pub unsafe fn get_mut<F>(handle: WDFOBJECT, cb: F) -> Result<(), WdfError>
+    where
+        F: FnOnce(&mut T),
+    {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::shared(handle) }?;
+        let mut guard = context.write().map_err(|_| WdfError::LockFailed)?;
+
+        cb(&mut guard);
+
+        Ok(())
+    }
+
+    /// Try to borrow the context immutably. Immediately returns if it's locked
+    /// Function returns with error and won't call cb if it failed to lock
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object, created with the attributes of `T`
+    /// - Its context must not be initialized or dropped at the same time
+    pub unsafe fn try_get<F>(handle: WDFOBJECT, cb: F) -> Result<(), WdfError>
+    where
+        F: FnOnce(&T),
+    {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::shared(handle) }?;
+        let guard = context.try_read().map_err(|_| WdfError::LockFailed)?;
+
+        cb(&guard);
+
+        Ok(())
+    }
+
+    /// Try to borrow the context mutably. Immediately returns if it's locked
+    /// Function returns with error and won't call cb if it failed to lock
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object, created with the attributes of `T`
+    /// - Its context must not be initialized or dropped at the same time
+    pub unsafe fn try_get_mut<F>(handle: WDFOBJECT, cb: F) -> Result<(), WdfError>
+    where
+        F: FnOnce(&mut T),
+    {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::shared(handle) }?;
+        let mut guard = context.try_write().map_err(|_| WdfError::LockFailed)?;
+
+        cb(&mut guard);
+
+        Ok(())
+    }
+
+    /// Context memory of `handle`
+    ///
+    /// # Safety
+    ///
+    /// `handle` must be a valid object
+    unsafe fn context(handle: WDFOBJECT) -> Result<NonNull<Self>, WdfError> {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { WdfObjectGetTypedContextWorker(handle, T::type_info().as_ptr()) }?;
+
+        // null if the object doesn't have a context of this type
+        NonNull::new(context.cast::<Self>()).ok_or(WdfError::NoContext)
+    }
+
+    /// The data of the context of `handle`
+    ///
+    /// # Safety
+    ///
+    /// - `handle` must be a valid object
+    /// - Its context must not be initialized or dropped at the same time
+    unsafe fn shared(handle: WDFOBJECT) -> Result<Arc<RwLock<T>>, WdfError> {
+        // SAFETY: Upheld by the caller
+        let context = unsafe { Self::context(handle) }?;
+        // SAFETY: Only read, and not written at the same time, see above
+        let context = unsafe { context.as_ref() };
+
+        if !context.initialized {
+            return Err(WdfError::NoContext);
+        }
+
+        // SAFETY: Checked that it's initialized
+        match unsafe { context.pointer.assume_init_ref() } {
+            ArcPointer::Strong(a) => Ok(a.clone()),
+            ArcPointer::Weak(a) => a.upgrade().ok_or(WdfError::UpgradeFailed),
+        }
+    }
+}
+
+impl<T> WdfObjectContext<T> {
+    fn release(&mut self) {
+        if mem::take(&mut self.initialized) {
+            // SAFETY: Was initialized, and is marked as uninitialized now
+            unsafe { self.pointer.assume_init_drop() };
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        cell::RefCell,
+        ffi::c_void,
+        ptr::{self, addr_of_mut},
+        sync::atomic::{AtomicBool, Ordering},
+    };
+
+    use super::*;
+
+    const MEMORY_SIZE: usize = 64;
+
+    // Context memory as the framework allocates it: aligned and zero filled
+    #[repr(C, align(16))]
+    struct Memory([u8; MEMORY_SIZE]);
+
+    struct Object {
+        type_info: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+        memory: Box<Memory>,
+    }
+
+    thread_local! {
+        // handles are indices into this, starting at 1
+        static OBJECTS: RefCell<Vec<Object>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Stands in for the function of the framework's function table
+    pub unsafe fn WdfObjectGetTypedContextWorker(
+        handle: WDFOBJECT,
+        type_info: PCWDF_OBJECT_CONTEXT_TYPE_INFO,
+    ) -> Result<*mut c_void, WdfError> {
+        Ok(OBJECTS.with_borrow_mut(|objects| {
+            let object = &mut objects[handle as usize - 1];
+
+            if ptr::eq(object.type_info, type_info) {
+                addr_of_mut!(*object.memory).cast()
+            } else {
+                ptr::null_mut()
+            }
+        }))
+    }
+
+    /// An object with room for a context of type `T`, as the framework creates it from the
+    /// attributes of `T`
+    pub(crate) fn create<T: WdfContext>() -> WDFOBJECT {
+        let type_info = T::type_info().as_ptr();
+        // SAFETY: Never mutated
+        assert!(unsafe { (*type_info).ContextSize } <= MEMORY_SIZE);
+
+        OBJECTS.with_borrow_mut(|objects| {
+            objects.push(Object {
+                type_info,
+                memory: Box::new(Memory([0; MEMORY_SIZE])),
+            });
+
+            objects.len() as WDFOBJECT
+        })
+    }
+
+    struct Counter(u32);
+    WDF_DECLARE_CONTEXT_TYPE!(Counter);
+
+    struct Dropped(Arc<AtomicBool>);
+    WDF_DECLARE_CONTEXT_TYPE!(Dropped);
+
+    impl Drop for Dropped {
+        fn drop(&mut self) {
+            self.0.store(true, Ordering::Relaxed);
+        }
+    }
+
+    fn count(handle: WDFOBJECT) -> Result<u32, WdfError> {
+        let mut count = 0;
+        unsafe { WdfObjectContext::<Counter>::get(handle, |counter| count = counter.0) }?;
+        Ok(count)
+    }
+
+    #[test]
+    fn init_and_get() {
+        let handle = create::<Counter>();
+        unsafe { WdfObjectContext::init(handle, Counter(1)) }.unwrap();
+
+        unsafe { WdfObjectContext::<Counter>::get_mut(handle, |counter| counter.0 += 1) }.unwrap();
+
+        assert_eq!(count(handle).unwrap(), 2);
+    }
+
+    #[test]
+    fn uninitialized() {
+        let handle = create::<Counter>();
+        assert!(matches!(count(handle), Err(WdfError::NoContext)));
+    }
+
+    #[test]
+    fn other_type() {
+        let handle = create::<Dropped>();
+        assert!(matches!(count(handle), Err(WdfError::NoContext)));
+    }
+
+    #[test]
+    fn share_does_not_keep_alive() {
+        let from = create::<Counter>();
+        let to = create::<Counter>();
+        unsafe { WdfObjectContext::init(from, Counter(1)) }.unwrap();
+        unsafe { WdfObjectContext::<Counter>::share(from, to) }.unwrap();
+
+        unsafe { WdfObjectContext::<Counter>::get_mut(to, |counter| counter.0 = 5) }.unwrap();
+        assert_eq!(count(from).unwrap(), 5);
+
+        unsafe { WdfObjectContext::<Counter>::drop(from) }.unwrap();
+        assert!(matches!(count(to), Err(WdfError::UpgradeFailed)));
+    }
+
+    #[test]
+    fn cleanup_drops() {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let handle = create::<Dropped>();
+        unsafe { WdfObjectContext::init(handle, Dropped(dropped.clone())) }.unwrap();
+
+        let cleanup = WdfObjectContext::<Dropped>::attributes()
+            .EvtCleanupCallback
+            .unwrap();
+        unsafe { cleanup(handle) };
+
+        assert!(dropped.load(Ordering::Relaxed));
+        let res = unsafe { WdfObjectContext::<Dropped>::get(handle, |_| ()) };
+        assert!(matches!(res, Err(WdfError::NoContext)));
+    }
+
+    #[test]
+    fn init_replaces() {
+        let dropped = Arc::new(AtomicBool::new(false));
+        let handle = create::<Dropped>();
+        unsafe { WdfObjectContext::init(handle, Dropped(dropped.clone())) }.unwrap();
+        unsafe { WdfObjectContext::init(handle, Dropped(Arc::default())) }.unwrap();
+
+        assert!(dropped.load(Ordering::Relaxed));
+    }
+}
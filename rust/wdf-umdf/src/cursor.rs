@@ -0,0 +1,95 @@
+//! Builder for setting up a hardware cursor on a monitor
+
+use std::mem::size_of;
+
+use wdf_umdf_sys::{
+    HANDLE, IDARG_IN_SETUP_HWCURSOR, IDDCX_CURSOR_CAPS, IDDCX_MONITOR, IDDCX_XOR_CURSOR_SUPPORT,
+};
+
+use crate::{IddCxError, IddCxMonitorSetupHardwareCursor};
+
+/// The cursor capabilities of a monitor, so the os hands the cursor over separately instead of
+/// compositing it into the frames
+///
+/// Defaults to 64x64 monochrome cursors only.
+///
+/// ```rust,ignore
+/// HardwareCursor::new(event)
+///     .max_size(128, 128)
+///     .alpha(true)
+///     .setup(monitor)?;
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct HardwareCursor {
+    event: HANDLE,
+    max_x: u32,
+    max_y: u32,
+    alpha: bool,
+    xor: IDDCX_XOR_CURSOR_SUPPORT,
+}
+
+impl HardwareCursor {
+    /// A cursor signalling `event` whenever the os has new cursor data
+    #[must_use]
+    pub fn new(event: HANDLE) -> Self {
+        Self {
+            event,
+            max_x: 64,
+            max_y: 64,
+            alpha: false,
+            xor: IDDCX_XOR_CURSOR_SUPPORT::IDDCX_XOR_CURSOR_SUPPORT_NONE,
+        }
+    }
+
+    /// Largest cursor the os hands over, larger ones are composited into the frames
+    #[must_use]
+    pub fn max_size(mut self, width: u32, height: u32) -> Self {
+        self.max_x = width;
+        self.max_y = height;
+        self
+    }
+
+    /// Whether 32 bit alpha cursors are supported, besides monochrome ones
+    #[must_use]
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// How color cursors with inverted (xor) pixels are supported
+    #[must_use]
+    pub fn xor(mut self, xor: IDDCX_XOR_CURSOR_SUPPORT) -> Self {
+        self.xor = xor;
+        self
+    }
+
+    #[must_use]
+    pub fn build(&self) -> IDARG_IN_SETUP_HWCURSOR {
+        IDARG_IN_SETUP_HWCURSOR {
+            CursorInfo: IDDCX_CURSOR_CAPS {
+                #[allow(clippy::cast_possible_truncation)]
+                Size: size_of::<IDDCX_CURSOR_CAPS>() as u32,
+                ColorXorCursorSupport: self.xor,
+                AlphaCursorSupport: self.alpha.into(),
+                MaxX: self.max_x,
+                MaxY: self.max_y,
+            },
+            hNewCursorDataAvailable: self.event,
+        }
+    }
+
+    /// Set up the cursor on `monitor`
+    ///
+    /// # Safety
+    ///
+    /// `monitor` must be a valid monitor which arrived, and the event must stay valid until the
+    /// monitor departs or the cursor is set up again
+    pub unsafe fn setup(&self, monitor: IDDCX_MONITOR) -> Result<(), IddCxError> {
+        let args = self.build();
+
+        // SAFETY: See above
+        unsafe { IddCxMonitorSetupHardwareCursor(monitor, &args) }?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+//! Closures run later by the framework, with timers and work items
+
+use std::{ptr, time::Duration};
+
+use wdf_umdf_sys::{WDFOBJECT, WDFTIMER, WDFWORKITEM, WDF_TIMER_CONFIG, WDF_WORKITEM_CONFIG};
+
+use crate::{
+    catch_panic, sync::relative_time, WdfError, WdfObjectContext, WdfObjectDelete, WdfTimerCreate,
+    WdfTimerStart, WdfTimerStop, WdfWorkItemCreate, WdfWorkItemEnqueue, WdfWorkItemFlush,
+};
+
+// Closure of a timer or work item, in its context
+struct Callback(Box<dyn Fn() + Send + Sync>);
+
+crate::WDF_DECLARE_CONTEXT_TYPE!(Callback);
+
+/// Run the closure in the context of `object`
+fn invoke(object: WDFOBJECT) {
+    // SAFETY: The context is initialized right after creation and only dropped on cleanup,
+    //         the framework doesn't call back before or after
+    let call = || unsafe { WdfObjectContext::<Callback>::get(object, |callback| (callback.0)()) };
+
+    // a panic must not unwind into the framework
+    // SAFETY: No device to report it to
+    _ = unsafe { catch_panic(None, call) };
+}
+
+unsafe extern "C-unwind" fn timer_func(timer: WDFTIMER) {
+    invoke(timer.cast());
+}
+
+unsafe extern "C-unwind" fn work_item_func(work_item: WDFWORKITEM) {
+    invoke(work_item.cast());
+}
+
+/// A framework timer running a closure, stopped and deleted when dropped
+pub struct WdfTimer(WDFTIMER);
+
+// SAFETY: Timer handles can be used from any thread
+unsafe impl Send for WdfTimer {}
+unsafe impl Sync for WdfTimer {}
+
+#[derive(Debug, Default)]
+pub struct WdfTimerBuilder {
+    period: Option<Duration>,
+    tolerable_delay: Duration,
+}
+
+impl WdfTimerBuilder {
+    /// Run the closure every `period` once started, instead of only once
+    #[must_use]
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = Some(period);
+        self
+    }
+
+    /// How late the closure may run, which lets the os save power
+    #[must_use]
+    pub fn tolerable_delay(mut self, delay: Duration) -> Self {
+        self.tolerable_delay = delay;
+        self
+    }
+
+    /// Create the timer, it only runs `callback` once started
+    ///
+    /// # Safety
+    ///
+    /// `parent` must be a valid device or queue object, the timer is deleted along with it
+    pub unsafe fn create(
+        self,
+        parent: WDFOBJECT,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Result<WdfTimer, WdfError> {
+        let mut config = WDF_TIMER_CONFIG::init(Some(timer_func));
+        config.Period = self.period.map_or(0, millis);
+        config.TolerableDelay = millis(self.tolerable_delay);
+        // closures take care of their own synchronization
+        config.AutomaticSerialization = 0;
+
+        let mut attributes = WdfObjectContext::<Callback>::attributes();
+        attributes.ParentObject = parent;
+
+        let mut timer = ptr::null_mut();
+        // SAFETY: The parent is valid, see above
+        unsafe { WdfTimerCreate(&mut config, &mut attributes, &mut timer) }?;
+        let timer = WdfTimer(timer);
+
+        // SAFETY: Just created with the attributes of the context, and not started yet
+        unsafe { WdfObjectContext::init(timer.0.cast(), Callback(Box::new(callback))) }?;
+
+        Ok(timer)
+    }
+}
+
+impl WdfTimer {
+    #[must_use]
+    pub fn builder() -> WdfTimerBuilder {
+        WdfTimerBuilder::default()
+    }
+
+    /// Run the closure after `due`, and then every period if there is one
+    ///
+    /// Restarts the timer if it's already running, returns whether it was
+    pub fn start(&self, due: Duration) -> bool {
+        // SAFETY: The timer is valid until self is dropped
+        let res = unsafe { WdfTimerStart(self.0, relative_time(due)) };
+        res.is_ok_and(|queued| queued != 0)
+    }
+
+    /// Stop the timer, returns whether it was running
+    ///
+    /// With `wait`, this also waits for a running closure to finish, so it must not be called
+    /// from the closure itself.
+    pub fn stop(&self, wait: bool) -> bool {
+        // SAFETY: The timer is valid until self is dropped
+        let res = unsafe { WdfTimerStop(self.0, u8::from(wait)) };
+        res.is_ok_and(|queued| queued != 0)
+    }
+}
+
+impl Drop for WdfTimer {
+    /// Waits for a running closure to finish, so it must not be dropped from the closure itself
+    fn drop(&mut self) {
+        self.stop(true);
+
+        // SAFETY: Stopped, and not used anymore
+        _ = unsafe { WdfObjectDelete(self.0.cast()) };
+    }
+}
+
+/// A framework work item running a closure, flushed and deleted when dropped
+pub struct WdfWorkItem(WDFWORKITEM);
+
+// SAFETY: Work item handles can be used from any thread
+unsafe impl Send for WdfWorkItem {}
+unsafe impl Sync for WdfWorkItem {}
+
+impl WdfWorkItem {
+    /// Create the work item, it only runs `callback` once enqueued
+    ///
+    /// # Safety
+    ///
+    /// `parent` must be a valid device or queue object, the work item is deleted along with it
+    pub unsafe fn create(
+        parent: WDFOBJECT,
+        callback: impl Fn() + Send + Sync + 'static,
+    ) -> Result<Self, WdfError> {
+        let mut config = WDF_WORKITEM_CONFIG::init(Some(work_item_func));
+        // closures take care of their own synchronization
+        config.AutomaticSerialization = 0;
+
+        let mut attributes = WdfObjectContext::<Callback>::attributes();
+        attributes.ParentObject = parent;
+
+        let mut work_item = ptr::null_mut();
+        // SAFETY: The parent is valid, see above
+        unsafe { WdfWorkItemCreate(&mut config, &mut attributes, &mut work_item) }?;
+        let work_item = Self(work_item);
+
+        // SAFETY: Just created with the attributes of the context, and not enqueued yet
+        unsafe { WdfObjectContext::init(work_item.0.cast(), Callback(Box::new(callback))) }?;
+
+        Ok(work_item)
+    }
+
+    /// Run the closure on a framework worker thread
+    ///
+    /// Does nothing if it's already enqueued and didn't start running yet
+    pub fn enqueue(&self) {
+        // SAFETY: The work item is valid until self is dropped
+        _ = unsafe { WdfWorkItemEnqueue(self.0) };
+    }
+
+    /// Wait until the closure finished, if it's enqueued or running
+    ///
+    /// Must not be called from the closure itself
+    pub fn flush(&self) {
+        // SAFETY: The work item is valid until self is dropped
+        _ = unsafe { WdfWorkItemFlush(self.0) };
+    }
+}
+
+impl Drop for WdfWorkItem {
+    /// Waits for the closure to finish, so it must not be dropped from the closure itself
+    fn drop(&mut self) {
+        self.flush();
+
+        // SAFETY: Flushed, and not used anymore
+        _ = unsafe { WdfObjectDelete(self.0.cast()) };
+    }
+}
+
+// Whole milliseconds, as the framework takes them for timer periods
+fn millis(duration: Duration) -> u32 {
+    u32::try_from(duration.as_millis()).unwrap_or(u32::MAX)
+}
+
+#[cfg(all(test, feature = "test-shim"))]
+mod tests {
+    use std::{
+        cell::RefCell,
+        ptr::NonNull,
+        rc::Rc,
+        sync::{
+            atomic::{AtomicU32, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use wdf_umdf_sys::NTSTATUS;
+
+    use super::*;
+    use crate::{context::tests::create, mock_wdf, shim::MockGuard};
+
+    // framework calls, in the order they were made
+    type Calls = Rc<RefCell<Vec<&'static str>>>;
+
+    fn mock_delete(calls: &Calls) -> MockGuard {
+        let calls = calls.clone();
+        mock_wdf!(WdfObjectDelete, move |(_object,)| {
+            calls.borrow_mut().push("delete");
+        })
+    }
+
+    #[test]
+    fn timer_runs_its_closure() {
+        let calls = Calls::default();
+        let created = Rc::new(RefCell::new(None));
+        let due_times = Rc::new(RefCell::new(Vec::new()));
+        let parent: WDFOBJECT = NonNull::dangling().as_ptr();
+
+        let log = created.clone();
+        let _create = mock_wdf!(WdfTimerCreate, move |(config, attributes, timer)| {
+            // SAFETY: The wrapper passes valid pointers
+            let config = unsafe { *config };
+            // SAFETY: As above
+            let parent = unsafe { (*attributes).ParentObject };
+
+            let handle = create::<Callback>();
+            // SAFETY: As above
+            unsafe { timer.write(handle.cast()) };
+
+            *log.borrow_mut() = Some((config, parent, handle));
+            NTSTATUS::STATUS_SUCCESS
+        });
+        let log = due_times.clone();
+        let _start = mock_wdf!(WdfTimerStart, move |(_timer, due)| {
+            log.borrow_mut().push(due);
+            0
+        });
+        let log = calls.clone();
+        let _stop = mock_wdf!(WdfTimerStop, move |(_timer, wait)| {
+            log.borrow_mut()
+                .push(if wait == 0 { "stop" } else { "stop and wait" });
+            1
+        });
+        let _delete = mock_delete(&calls);
+
+        let runs = Arc::new(AtomicU32::new(0));
+        let counter = runs.clone();
+        let timer = WdfTimer::builder()
+            .period(Duration::from_secs(1))
+            .tolerable_delay(Duration::from_millis(250));
+        // SAFETY: Mocked
+        let timer = unsafe {
+            timer.create(parent, move || {
+                counter.fetch_add(1, Ordering::Relaxed);
+            })
+        }
+        .unwrap();
+
+        let (config, created_parent, handle) = created.borrow_mut().take().unwrap();
+        assert_eq!(config.Period, 1000);
+        assert_eq!(config.TolerableDelay, 250);
+        assert_eq!(config.AutomaticSerialization, 0);
+        assert_eq!(created_parent, parent);
+
+        assert!(!timer.start(Duration::from_millis(10)));
+        assert_eq!(*due_times.borrow(), [-100_000]);
+
+        // as the framework calls it, every period
+        let timer_func = config.EvtTimerFunc.unwrap();
+        // SAFETY: The context of the timer was initialized when it was created
+        unsafe { timer_func(handle.cast()) };
+        // SAFETY: As above
+        unsafe { timer_func(handle.cast()) };
+        assert_eq!(runs.load(Ordering::Relaxed), 2);
+
+        drop(timer);
+        assert_eq!(*calls.borrow(), ["stop and wait", "delete"]);
+    }
+
+    #[test]
+    fn work_item_is_flushed_when_dropped() {
+        let calls = Calls::default();
+        let created = Rc::new(RefCell::new(None));
+
+        let log = created.clone();
+        let _create = mock_wdf!(WdfWorkItemCreate, move |(config, _attributes, item)| {
+            // SAFETY: The wrapper passes valid pointers
+            let config = unsafe { *config };
+
+            let handle = create::<Callback>();
+            // SAFETY: As above
+            unsafe { item.write(handle.cast()) };
+
+            *log.borrow_mut() = Some((config, handle));
+            NTSTATUS::STATUS_SUCCESS
+        });
+        let log = calls.clone();
+        let _enqueue = mock_wdf!(WdfWorkItemEnqueue, move |(_work_item,)| {
+            log.borrow_mut().push("enqueue");
+        });
+        let log = calls.clone();
+        let _flush = mock_wdf!(WdfWorkItemFlush, move |(_work_item,)| {
+            log.borrow_mut().push("flush");
+        });
+        let _delete = mock_delete(&calls);
+
+        // SAFETY: Mocked
+        let work_item =
+            unsafe { WdfWorkItem::create(NonNull::dangling().as_ptr(), || panic!("failed")) }
+                .unwrap();
+        work_item.enqueue();
+
+        let (config, handle) = created.borrow_mut().take().unwrap();
+        assert_eq!(config.AutomaticSerialization, 0);
+
+        // the panic must not unwind into the framework
+        let work_item_func = config.EvtWorkItemFunc.unwrap();
+        // SAFETY: The context of the work item was initialized when it was created
+        unsafe { work_item_func(handle.cast()) };
+
+        drop(work_item);
+        assert_eq!(*calls.borrow(), ["enqueue", "flush", "delete"]);
+    }
+}
@@ -0,0 +1,414 @@
+//! A trait based layer over the IddCx callbacks
+//!
+//! Implement [`IddDriver`] and generate its `DriverEntry` with `#[idd_driver]`, or register its
+//! IddCx callbacks with [`IddCxConfigBuilder::driver`]. The thunks here turn the raw pointers the
+//! framework passes into references, and keep panics from unwinding.
+
+use std::ptr;
+
+use log::error;
+
+use wdf_umdf_sys::{
+    IDARG_IN_ADAPTER_INIT_FINISHED, IDARG_IN_COMMITMODES, IDARG_IN_GETDEFAULTDESCRIPTIONMODES,
+    IDARG_IN_I2C_RECEIVE, IDARG_IN_I2C_TRANSMIT, IDARG_IN_PARSEMONITORDESCRIPTION,
+    IDARG_IN_QUERYTARGETMODES, IDARG_IN_SETSWAPCHAIN, IDARG_IN_SET_GAMMARAMP,
+    IDARG_OUT_GETDEFAULTDESCRIPTIONMODES, IDARG_OUT_I2C_RECEIVE, IDARG_OUT_PARSEMONITORDESCRIPTION,
+    IDARG_OUT_QUERYTARGETMODES, IDDCX_ADAPTER, IDDCX_MONITOR, NTSTATUS, PCUNICODE_STRING,
+    PDRIVER_OBJECT, PWDFDEVICE_INIT, WDFDEVICE, WDFDEVICE_INIT, WDFDRIVER, WDFREQUEST,
+    WDF_DRIVER_CONFIG, WDF_OBJECT_ATTRIBUTES,
+};
+
+use crate::{
+    guard, IddCxConfigBuilder, IddCxDeviceInitialize, MissingCallbacks, Ready, RequiredCallbacks,
+    WdfDeviceCreate, WdfDriverCreate, WdfRequest,
+};
+
+/// The callback being run, see [`IddDriver::guard`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IddCallback {
+    DriverEntry,
+    DriverUnload,
+    DeviceAdd,
+    AdapterInitFinished,
+    ParseMonitorDescription,
+    MonitorGetDefaultModes,
+    MonitorQueryModes,
+    AdapterCommitModes,
+    AssignSwapChain,
+    UnassignSwapChain,
+    MonitorSetGammaRamp,
+    MonitorI2CTransmit,
+    MonitorI2CReceive,
+    DeviceIoControl,
+}
+
+/// An indirect display driver
+///
+/// The framework has no driver instance to call back into, state lives in the contexts of the
+/// objects the callbacks get, see [`WdfObjectContext`](crate::WdfObjectContext).
+pub trait IddDriver: 'static {
+    /// Whether the framework should hand gamma ramps to [`IddDriver::monitor_set_gamma_ramp`]
+    const GAMMA_RAMP: bool = false;
+
+    /// Whether the framework should hand i2c traffic to [`IddDriver::monitor_i2c_transmit`] and
+    /// [`IddDriver::monitor_i2c_receive`]
+    const I2C: bool = false;
+
+    /// Whether the framework should hand device control requests to
+    /// [`IddDriver::device_io_control`]
+    const DEVICE_IO_CONTROL: bool = false;
+
+    /// Run the callback `f`, which must not let a panic unwind into the framework
+    ///
+    /// By default, a panic fails the callback. Override this to also count callbacks, or fail
+    /// the device.
+    fn guard(callback: IddCallback, f: impl FnOnce() -> NTSTATUS) -> NTSTATUS {
+        _ = callback;
+        guard(f)
+    }
+
+    /// Called first in `DriverEntry`, before the driver object is created
+    fn driver_entry(driver: PDRIVER_OBJECT) -> NTSTATUS {
+        _ = driver;
+        NTSTATUS::STATUS_SUCCESS
+    }
+
+    /// The driver is about to be unloaded
+    fn driver_unload() {}
+
+    /// Set up the device before it's created, e.g. with pnp and power callbacks
+    fn device_init(init: &mut WDFDEVICE_INIT) -> NTSTATUS {
+        _ = init;
+        NTSTATUS::STATUS_SUCCESS
+    }
+
+    /// Attributes the device is created with, e.g. for its context
+    fn device_attributes() -> WDF_OBJECT_ATTRIBUTES {
+        WDF_OBJECT_ATTRIBUTES::init()
+    }
+
+    /// The device was created and initialized for IddCx
+    fn device_added(device: WDFDEVICE) -> NTSTATUS {
+        _ = device;
+        NTSTATUS::STATUS_SUCCESS
+    }
+
+    /// The adapter started with `IddCxAdapterInitAsync` finished initializing, or failed to
+    fn adapter_init_finished(
+        adapter: IDDCX_ADAPTER,
+        args: &IDARG_IN_ADAPTER_INIT_FINISHED,
+    ) -> NTSTATUS;
+
+    /// The modes of a monitor from its description, usually an edid
+    fn parse_monitor_description(
+        args: &IDARG_IN_PARSEMONITORDESCRIPTION,
+        out: &mut IDARG_OUT_PARSEMONITORDESCRIPTION,
+    ) -> NTSTATUS;
+
+    /// The modes of a monitor without a description
+    fn monitor_default_modes(
+        monitor: IDDCX_MONITOR,
+        args: &IDARG_IN_GETDEFAULTDESCRIPTIONMODES,
+        out: &mut IDARG_OUT_GETDEFAULTDESCRIPTIONMODES,
+    ) -> NTSTATUS {
+        _ = (monitor, args, out);
+        NTSTATUS::STATUS_NOT_IMPLEMENTED
+    }
+
+    /// The modes the adapter can drive a monitor with
+    fn monitor_query_modes(
+        monitor: IDDCX_MONITOR,
+        args: &IDARG_IN_QUERYTARGETMODES,
+        out: &mut IDARG_OUT_QUERYTARGETMODES,
+    ) -> NTSTATUS;
+
+    /// The os committed new modes to the monitors of `adapter`
+    fn adapter_commit_modes(adapter: IDDCX_ADAPTER, args: &IDARG_IN_COMMITMODES) -> NTSTATUS {
+        _ = (adapter, args);
+        NTSTATUS::STATUS_SUCCESS
+    }
+
+    /// A swap chain to process frames from was assigned to `monitor`
+    fn assign_swap_chain(monitor: IDDCX_MONITOR, args: &IDARG_IN_SETSWAPCHAIN) -> NTSTATUS;
+
+    /// The swap chain of `monitor` must not be used anymore
+    fn unassign_swap_chain(monitor: IDDCX_MONITOR) -> NTSTATUS;
+
+    /// Only called with [`IddDriver::GAMMA_RAMP`]
+    fn monitor_set_gamma_ramp(monitor: IDDCX_MONITOR, args: &IDARG_IN_SET_GAMMARAMP) -> NTSTATUS {
+        _ = (monitor, args);
+        NTSTATUS::STATUS_NOT_SUPPORTED
+    }
+
+    /// Only called with [`IddDriver::I2C`]
+    fn monitor_i2c_transmit(monitor: IDDCX_MONITOR, args: &IDARG_IN_I2C_TRANSMIT) -> NTSTATUS {
+        _ = (monitor, args);
+        NTSTATUS::STATUS_NOT_SUPPORTED
+    }
+
+    /// Only called with [`IddDriver::I2C`]
+    fn monitor_i2c_receive(
+        monitor: IDDCX_MONITOR,
+        args: &IDARG_IN_I2C_RECEIVE,
+        out: &mut IDARG_OUT_I2C_RECEIVE,
+    ) -> NTSTATUS {
+        _ = (monitor, args, out);
+        NTSTATUS::STATUS_NOT_SUPPORTED
+    }
+
+    /// Only called with [`IddDriver::DEVICE_IO_CONTROL`]
+    fn device_io_control(device: WDFDEVICE, request: WdfRequest) {
+        _ = device;
+        request.complete(NTSTATUS::STATUS_INVALID_DEVICE_REQUEST);
+    }
+}
+
+impl IddCxConfigBuilder<MissingCallbacks> {
+    /// Register the callbacks of `D`
+    #[must_use]
+    pub fn driver<D: IddDriver>(self) -> IddCxConfigBuilder<Ready> {
+        let mut builder = self.callbacks(RequiredCallbacks {
+            adapter_init_finished: adapter_init_finished::<D>,
+            parse_monitor_description: parse_monitor_description::<D>,
+            monitor_get_default_modes: monitor_get_default_modes::<D>,
+            monitor_query_target_modes: monitor_query_modes::<D>,
+            adapter_commit_modes: adapter_commit_modes::<D>,
+            monitor_assign_swap_chain: assign_swap_chain::<D>,
+            monitor_unassign_swap_chain: unassign_swap_chain::<D>,
+        });
+
+        if D::GAMMA_RAMP {
+            builder = builder.gamma_ramp(monitor_set_gamma_ramp::<D>);
+        }
+
+        if D::I2C {
+            builder = builder.i2c(monitor_i2c_transmit::<D>, monitor_i2c_receive::<D>);
+        }
+
+        if D::DEVICE_IO_CONTROL {
+            builder = builder.device_io_control(device_io_control::<D>);
+        }
+
+        builder
+    }
+}
+
+/// The body of the `DriverEntry` generated by `#[idd_driver]`
+///
+/// # Safety
+///
+/// Must only be called from `DriverEntry`, with the arguments it got
+pub unsafe fn driver_entry<D: IddDriver>(
+    driver_object: PDRIVER_OBJECT,
+    registry_path: PCUNICODE_STRING,
+) -> NTSTATUS {
+    D::guard(IddCallback::DriverEntry, || {
+        let status = D::driver_entry(driver_object);
+        if !status.is_success() {
+            return status;
+        }
+
+        let mut attributes = WDF_OBJECT_ATTRIBUTES::init();
+
+        let mut config = WDF_DRIVER_CONFIG::init(Some(device_add::<D>));
+        config.EvtDriverUnload = Some(driver_unload::<D>);
+
+        // SAFETY: The arguments are the ones `DriverEntry` got, see above
+        unsafe {
+            WdfDriverCreate(
+                driver_object,
+                registry_path,
+                Some(&mut attributes),
+                &mut config,
+                None,
+            )
+        }
+        .into()
+    })
+}
+
+extern "C-unwind" fn driver_unload<D: IddDriver>(_driver: WDFDRIVER) {
+    _ = D::guard(IddCallback::DriverUnload, || {
+        D::driver_unload();
+        NTSTATUS::STATUS_SUCCESS
+    });
+}
+
+extern "C-unwind" fn device_add<D: IddDriver>(
+    _driver: WDFDRIVER,
+    mut init: PWDFDEVICE_INIT,
+) -> NTSTATUS {
+    D::guard(IddCallback::DeviceAdd, || {
+        // SAFETY: The framework passes a valid init, until the device is created from it
+        let init_data = unsafe { &mut *init };
+
+        let status = D::device_init(init_data);
+        if !status.is_success() {
+            return status;
+        }
+
+        let Some(config) = IddCxConfigBuilder::new() else {
+            error!("Failed to create IDD_CX_CLIENT_CONFIG");
+            return NTSTATUS::STATUS_NOT_FOUND;
+        };
+
+        // SAFETY: The device isn't created yet
+        if let Err(e) = unsafe { config.driver::<D>().init(init_data) } {
+            error!("Failed to init iddcx config: {e:?}");
+            return e.into();
+        }
+
+        let mut attributes = D::device_attributes();
+        let mut device = ptr::null_mut();
+
+        // SAFETY: The init is valid, see above
+        let status = unsafe { WdfDeviceCreate(&mut init, Some(&mut attributes), &mut device) };
+        if let Err(e) = status {
+            error!("Failed to create device: {e:?}");
+            return e.into();
+        }
+
+        // SAFETY: Just created with an iddcx config
+        if let Err(e) = unsafe { IddCxDeviceInitialize(device) } {
+            error!("Failed to init iddcx device: {e:?}");
+            return e.into();
+        }
+
+        D::device_added(device)
+    })
+}
+
+// The IddCx thunks below rely on the framework passing valid args, which live for the whole callback
+
+extern "C-unwind" fn adapter_init_finished<D: IddDriver>(
+    adapter: IDDCX_ADAPTER,
+    args: *const IDARG_IN_ADAPTER_INIT_FINISHED,
+) -> NTSTATUS {
+    D::guard(IddCallback::AdapterInitFinished, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        D::adapter_init_finished(adapter, args)
+    })
+}
+
+extern "C-unwind" fn parse_monitor_description<D: IddDriver>(
+    args: *const IDARG_IN_PARSEMONITORDESCRIPTION,
+    out: *mut IDARG_OUT_PARSEMONITORDESCRIPTION,
+) -> NTSTATUS {
+    D::guard(IddCallback::ParseMonitorDescription, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        // SAFETY: See above
+        let out = unsafe { &mut *out };
+        D::parse_monitor_description(args, out)
+    })
+}
+
+extern "C-unwind" fn monitor_get_default_modes<D: IddDriver>(
+    monitor: IDDCX_MONITOR,
+    args: *const IDARG_IN_GETDEFAULTDESCRIPTIONMODES,
+    out: *mut IDARG_OUT_GETDEFAULTDESCRIPTIONMODES,
+) -> NTSTATUS {
+    D::guard(IddCallback::MonitorGetDefaultModes, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        // SAFETY: See above
+        let out = unsafe { &mut *out };
+        D::monitor_default_modes(monitor, args, out)
+    })
+}
+
+extern "C-unwind" fn monitor_query_modes<D: IddDriver>(
+    monitor: IDDCX_MONITOR,
+    args: *const IDARG_IN_QUERYTARGETMODES,
+    out: *mut IDARG_OUT_QUERYTARGETMODES,
+) -> NTSTATUS {
+    D::guard(IddCallback::MonitorQueryModes, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        // SAFETY: See above
+        let out = unsafe { &mut *out };
+        D::monitor_query_modes(monitor, args, out)
+    })
+}
+
+extern "C-unwind" fn adapter_commit_modes<D: IddDriver>(
+    adapter: IDDCX_ADAPTER,
+    args: *const IDARG_IN_COMMITMODES,
+) -> NTSTATUS {
+    D::guard(IddCallback::AdapterCommitModes, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        D::adapter_commit_modes(adapter, args)
+    })
+}
+
+extern "C-unwind" fn assign_swap_chain<D: IddDriver>(
+    monitor: IDDCX_MONITOR,
+    args: *const IDARG_IN_SETSWAPCHAIN,
+) -> NTSTATUS {
+    D::guard(IddCallback::AssignSwapChain, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        D::assign_swap_chain(monitor, args)
+    })
+}
+
+extern "C-unwind" fn unassign_swap_chain<D: IddDriver>(monitor: IDDCX_MONITOR) -> NTSTATUS {
+    D::guard(IddCallback::UnassignSwapChain, || {
+        D::unassign_swap_chain(monitor)
+    })
+}
+
+extern "C-unwind" fn monitor_set_gamma_ramp<D: IddDriver>(
+    monitor: IDDCX_MONITOR,
+    args: *const IDARG_IN_SET_GAMMARAMP,
+) -> NTSTATUS {
+    D::guard(IddCallback::MonitorSetGammaRamp, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        D::monitor_set_gamma_ramp(monitor, args)
+    })
+}
+
+extern "C-unwind" fn monitor_i2c_transmit<D: IddDriver>(
+    monitor: IDDCX_MONITOR,
+    args: *const IDARG_IN_I2C_TRANSMIT,
+) -> NTSTATUS {
+    D::guard(IddCallback::MonitorI2CTransmit, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        D::monitor_i2c_transmit(monitor, args)
+    })
+}
+
+extern "C-unwind" fn monitor_i2c_receive<D: IddDriver>(
+    monitor: IDDCX_MONITOR,
+    args: *const IDARG_IN_I2C_RECEIVE,
+    out: *mut IDARG_OUT_I2C_RECEIVE,
+) -> NTSTATUS {
+    D::guard(IddCallback::MonitorI2CReceive, || {
+        // SAFETY: See above
+        let args = unsafe { &*args };
+        // SAFETY: See above
+        let out = unsafe { &mut *out };
+        D::monitor_i2c_receive(monitor, args, out)
+    })
+}
+
+extern "C-unwind" fn device_io_control<D: IddDriver>(
+    device: WDFDEVICE,
+    request: WDFREQUEST,
+    _output_buffer_length: usize,
+    _input_buffer_length: usize,
+    io_control_code: u32,
+) {
+    // SAFETY: The framework hands the request over to the callback. It's completed when dropped,
+    //         also on a panic
+    let request = unsafe { WdfRequest::from_raw(request, io_control_code) };
+
+    _ = D::guard(IddCallback::DeviceIoControl, || {
+        D::device_io_control(device, request);
+        NTSTATUS::STATUS_SUCCESS
+    });
+}
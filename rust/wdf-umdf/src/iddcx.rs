@@ -2,20 +2,22 @@
 #![allow(clippy::missing_errors_doc)]
 
 use wdf_umdf_sys::{
-    IDARG_IN_ADAPTER_INIT, IDARG_IN_MONITORCREATE, IDARG_IN_SWAPCHAINSETDEVICE,
-    IDARG_OUT_ADAPTER_INIT, IDARG_OUT_MONITORARRIVAL, IDARG_OUT_MONITORCREATE,
-    IDARG_OUT_RELEASEANDACQUIREBUFFER, IDDCX_ADAPTER, IDDCX_MONITOR, IDDCX_SWAPCHAIN,
-    IDD_CX_CLIENT_CONFIG, NTSTATUS, WDFDEVICE, WDFDEVICE_INIT,
+    IDARG_IN_ADAPTER_INIT, IDARG_IN_MONITORCREATE, IDARG_IN_QUERY_HWCURSOR,
+    IDARG_IN_SETREALTIMEGPUPRIORITY, IDARG_IN_SETUP_HWCURSOR, IDARG_IN_SWAPCHAINSETDEVICE,
+    IDARG_IN_UPDATEMODES, IDARG_OUT_ADAPTER_INIT, IDARG_OUT_MONITORARRIVAL,
+    IDARG_OUT_MONITORCREATE, IDARG_OUT_QUERY_HWCURSOR, IDARG_OUT_RELEASEANDACQUIREBUFFER,
+    IDDCX_ADAPTER, IDDCX_MONITOR, IDDCX_SWAPCHAIN, IDD_CX_CLIENT_CONFIG, NTSTATUS, WDFDEVICE,
+    WDFDEVICE_INIT,
 };
 
 #[derive(Debug, thiserror::Error)]
 pub enum IddCxError {
     #[error("{0}")]
     IddCxFunctionNotAvailable(&'static str),
-    #[error("{0}")]
-    CallFailed(NTSTATUS),
-    #[error("{0}")]
-    NtStatus(NTSTATUS),
+    #[error("Call failed")]
+    CallFailed(#[source] NTSTATUS),
+    #[error("Call returned an error status")]
+    NtStatus(#[source] NTSTATUS),
 }
 
 impl From<IddCxError> for NTSTATUS {
@@ -43,11 +45,23 @@ impl From<i32> for IddCxError {
 }
 
 macro_rules! IddCxCall {
-    ($name:ident ( $($args:expr),* )) => {
-        IddCxCall!(false, $name($($args),*))
-    };
+    // calls the mock of the function instead, see `shim`
+    (@shim $other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        ::paste::paste! {
+            type Pfn = ::wdf_umdf_sys::[<PFN_ $name:upper>];
+        }
 
-    ($other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        // coerces the arguments to the types of the function
+        let args: <Pfn as $crate::shim::TableFn>::Args = ($($args,)*);
+
+        match $crate::shim::call::<Pfn>(stringify!($name), args) {
+            Some(result) if $crate::is_nt_error(&result, $other_is_error) => Err(result.into()),
+            Some(result) => Ok(result.into()),
+            None => Err($crate::IddCxError::IddCxFunctionNotAvailable(concat!(stringify!($name), " is not mocked"))),
+        }
+    }};
+
+    (@table $other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
         let fn_handle = {
             ::paste::paste! {
                 const FN_INDEX: usize = ::wdf_umdf_sys::IDDFUNCENUM::[<$name TableIndex>].0 as usize;
@@ -97,6 +111,31 @@ macro_rules! IddCxCall {
             })
         }
     }};
+
+    (@call $other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        #[cfg(not(feature = "test-shim"))]
+        let result = IddCxCall!(@table $other_is_error, $name($($args),*));
+        #[cfg(feature = "test-shim")]
+        let result = IddCxCall!(@shim $other_is_error, $name($($args),*));
+
+        result
+    }};
+
+    ($name:ident ( $($args:expr),* )) => {
+        IddCxCall!(false, $name($($args),*))
+    };
+
+    ($other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        // logs the call, see `trace`
+        #[cfg(feature = "trace-calls")]
+        let result = $crate::trace::call(stringify!($name), stringify!($($args),*), || {
+            IddCxCall!(@call $other_is_error, $name($($args),*))
+        });
+        #[cfg(not(feature = "trace-calls"))]
+        let result = IddCxCall!(@call $other_is_error, $name($($args),*));
+
+        result
+    }};
 }
 
 /// # Safety
@@ -186,6 +225,45 @@ pub unsafe fn IddCxMonitorArrival(
     )
 }
 
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[rustfmt::skip]
+pub unsafe fn IddCxMonitorSetupHardwareCursor(
+    // in
+    MonitorObject: IDDCX_MONITOR,
+    // in
+    pInArgs: &IDARG_IN_SETUP_HWCURSOR,
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        IddCxMonitorSetupHardwareCursor(
+            MonitorObject,
+            pInArgs
+        )
+    )
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[rustfmt::skip]
+pub unsafe fn IddCxMonitorQueryHardwareCursor(
+    // in
+    MonitorObject: IDDCX_MONITOR,
+    // in
+    pInArgs: &IDARG_IN_QUERY_HWCURSOR,
+    // out
+    pOutArgs: &mut IDARG_OUT_QUERY_HWCURSOR,
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        IddCxMonitorQueryHardwareCursor(
+            MonitorObject,
+            pInArgs,
+            pOutArgs
+        )
+    )
+}
+
 /// # Safety
 ///
 /// None. User is responsible for safety.
@@ -205,6 +283,25 @@ pub unsafe fn IddCxSwapChainSetDevice(
     )
 }
 
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[rustfmt::skip]
+pub unsafe fn IddCxSetRealtimeGPUPriority(
+    // in
+    SwapChainObject: IDDCX_SWAPCHAIN,
+    // in
+    pInArgs: &IDARG_IN_SETREALTIMEGPUPRIORITY
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        true,
+        IddCxSetRealtimeGPUPriority(
+            SwapChainObject,
+            pInArgs
+        )
+    )
+}
+
 /// # Safety
 ///
 /// None. User is responsible for safety.
@@ -254,3 +351,105 @@ pub unsafe fn IddCxMonitorDeparture(
         )
     )
 }
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[rustfmt::skip]
+pub unsafe fn IddCxMonitorUpdateModes(
+    // in
+    MonitorObject: IDDCX_MONITOR,
+    // in
+    pInArgs: *const IDARG_IN_UPDATEMODES
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        IddCxMonitorUpdateModes(
+            MonitorObject,
+            pInArgs
+        )
+    )
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[cfg(feature = "iddcx-1_10")]
+#[rustfmt::skip]
+pub unsafe fn IddCxMonitorUpdateModes2(
+    // in
+    MonitorObject: IDDCX_MONITOR,
+    // in
+    pInArgs: *const wdf_umdf_sys::IDARG_IN_UPDATEMODES2
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        IddCxMonitorUpdateModes2(
+            MonitorObject,
+            pInArgs
+        )
+    )
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[cfg(feature = "iddcx-1_10")]
+#[rustfmt::skip]
+pub unsafe fn IddCxSwapChainReleaseAndAcquireBuffer2(
+    // in
+    SwapChainObject: IDDCX_SWAPCHAIN,
+    // in
+    pInArgs: &wdf_umdf_sys::IDARG_IN_RELEASEANDACQUIREBUFFER2,
+    // out
+    pOutArgs: &mut wdf_umdf_sys::IDARG_OUT_RELEASEANDACQUIREBUFFER2
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        true,
+        IddCxSwapChainReleaseAndAcquireBuffer2(
+            SwapChainObject,
+            pInArgs,
+            pOutArgs
+        )
+    )
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[cfg(feature = "iddcx-1_10")]
+#[rustfmt::skip]
+pub unsafe fn IddCxMonitorQueryHardwareCursor3(
+    // in
+    MonitorObject: IDDCX_MONITOR,
+    // in
+    pInArgs: &IDARG_IN_QUERY_HWCURSOR,
+    // out
+    pOutArgs: &mut wdf_umdf_sys::IDARG_OUT_QUERY_HWCURSOR3,
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        IddCxMonitorQueryHardwareCursor3(
+            MonitorObject,
+            pInArgs,
+            pOutArgs
+        )
+    )
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+#[cfg(feature = "iddcx-1_10")]
+#[rustfmt::skip]
+pub unsafe fn IddCxAdapterDisplayConfigUpdate(
+    // in
+    AdapterObject: IDDCX_ADAPTER,
+    // in
+    pInArgs: &wdf_umdf_sys::IDARG_IN_ADAPTERDISPLAYCONFIGUPDATE
+) -> Result<NTSTATUS, IddCxError> {
+    IddCxCall!(
+        true,
+        IddCxAdapterDisplayConfigUpdate(
+            AdapterObject,
+            pInArgs
+        )
+    )
+}
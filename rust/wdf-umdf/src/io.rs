@@ -0,0 +1,180 @@
+//! IO queues handling device control requests (IOCTLs) with a closure
+
+use std::{ffi::c_void, mem, ptr, slice};
+
+use wdf_umdf_sys::{
+    NTSTATUS, WDFDEVICE, WDFQUEUE, WDFREQUEST, WDF_IO_QUEUE_CONFIG, WDF_IO_QUEUE_DISPATCH_TYPE,
+};
+
+use crate::{
+    catch_panic, WdfError, WdfIoQueueCreate, WdfObjectContext, WdfRequestComplete,
+    WdfRequestCompleteWithInformation, WdfRequestRetrieveInputBuffer,
+    WdfRequestRetrieveOutputBuffer,
+};
+
+// Closure of a queue, in its context
+struct Handler(Box<dyn Fn(WdfRequest) + Send + Sync>);
+
+crate::WDF_DECLARE_CONTEXT_TYPE!(Handler);
+
+unsafe extern "C-unwind" fn device_control(
+    queue: WDFQUEUE,
+    request: WDFREQUEST,
+    _output_buffer_length: usize,
+    _input_buffer_length: usize,
+    io_control_code: u32,
+) {
+    // completed when dropped, also if there's no handler or it panics
+    // SAFETY: The framework hands the request over to the queue's callback
+    let request = unsafe { WdfRequest::from_raw(request, io_control_code) };
+
+    // SAFETY: The context is initialized right after creation and only dropped on cleanup,
+    //         the framework doesn't call back before or after
+    let call = || unsafe {
+        WdfObjectContext::<Handler>::get(queue.cast(), |handler| (handler.0)(request))
+    };
+
+    // a panic must not unwind into the framework
+    // SAFETY: No device to report it to
+    _ = unsafe { catch_panic(None, call) };
+}
+
+/// The default IO queue of a device, handing its device control requests to a closure
+///
+/// The queue belongs to the device and is deleted along with it.
+pub struct WdfIoQueue(WDFQUEUE);
+
+// SAFETY: Queue handles can be used from any thread
+unsafe impl Send for WdfIoQueue {}
+unsafe impl Sync for WdfIoQueue {}
+
+impl WdfIoQueue {
+    /// Create the default queue of `device`, which calls `handler` for every device control
+    /// request
+    ///
+    /// With [`WDF_IO_QUEUE_DISPATCH_TYPE::WdfIoQueueDispatchParallel`], `handler` can run for
+    /// several requests at once, with `WdfIoQueueDispatchSequential` only once the last request
+    /// was completed.
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a valid device, which doesn't have a default queue yet
+    pub unsafe fn create(
+        device: WDFDEVICE,
+        dispatch: WDF_IO_QUEUE_DISPATCH_TYPE,
+        handler: impl Fn(WdfRequest) + Send + Sync + 'static,
+    ) -> Result<Self, WdfError> {
+        let mut config = WDF_IO_QUEUE_CONFIG::init_default_queue(dispatch);
+        config.EvtIoDeviceControl = Some(device_control);
+
+        let mut attributes = WdfObjectContext::<Handler>::attributes();
+
+        let mut queue = ptr::null_mut();
+        // SAFETY: The device is valid, see above
+        unsafe { WdfIoQueueCreate(device, &mut config, Some(&mut attributes), Some(&mut queue)) }?;
+
+        // SAFETY: Just created with the attributes of the context. The device isn't started yet,
+        //         so there are no requests
+        unsafe { WdfObjectContext::init(queue.cast(), Handler(Box::new(handler))) }?;
+
+        Ok(Self(queue))
+    }
+
+    #[must_use]
+    pub fn as_raw(&self) -> WDFQUEUE {
+        self.0
+    }
+}
+
+/// A device control request, which has to be completed exactly once
+///
+/// Dropping it without completing it completes it with `STATUS_INTERNAL_ERROR`.
+pub struct WdfRequest {
+    request: WDFREQUEST,
+    code: u32,
+}
+
+// SAFETY: Requests can be completed from any thread
+unsafe impl Send for WdfRequest {}
+
+impl WdfRequest {
+    /// Take over completing `request`, a device control request with the IOCTL `code`
+    ///
+    /// # Safety
+    ///
+    /// `request` must be a valid request which isn't completed yet, and nothing else may complete
+    /// it
+    #[must_use]
+    pub unsafe fn from_raw(request: WDFREQUEST, code: u32) -> Self {
+        Self { request, code }
+    }
+
+    /// The IOCTL code
+    #[must_use]
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// The input buffer, which has to be at least `min_len` bytes long
+    pub fn input(&self, min_len: usize) -> Result<&[u8], WdfError> {
+        let mut buffer = ptr::null_mut::<c_void>();
+        let mut len = 0;
+
+        // SAFETY: The request is valid until it's completed, which consumes self
+        unsafe {
+            WdfRequestRetrieveInputBuffer(self.request, min_len, &mut buffer, Some(&mut len))
+        }?;
+
+        // SAFETY: The framework hands out a buffer of `len` bytes, which lives as long as the
+        //         request
+        Ok(unsafe { slice::from_raw_parts(buffer.cast::<u8>(), len) })
+    }
+
+    /// The output buffer, which has to be at least `min_len` bytes long
+    ///
+    /// With buffered IO, this is the same memory as the input buffer, so the input has to be read
+    /// before writing the output.
+    pub fn output(&mut self, min_len: usize) -> Result<&mut [u8], WdfError> {
+        let mut buffer = ptr::null_mut::<c_void>();
+        let mut len = 0;
+
+        // SAFETY: The request is valid until it's completed, which consumes self
+        unsafe {
+            WdfRequestRetrieveOutputBuffer(self.request, min_len, &mut buffer, Some(&mut len))
+        }?;
+
+        // SAFETY: The framework hands out a buffer of `len` bytes, which lives as long as the
+        //         request, and self is borrowed mutably
+        Ok(unsafe { slice::from_raw_parts_mut(buffer.cast::<u8>(), len) })
+    }
+
+    /// Complete the request with `status`, without any output
+    pub fn complete(self, status: NTSTATUS) {
+        let request = self.into_raw();
+
+        // SAFETY: Not completed yet, self is consumed
+        _ = unsafe { WdfRequestComplete(request, status) };
+    }
+
+    /// Complete the request with `status`, and `written` bytes in the output buffer
+    pub fn complete_with_output(self, status: NTSTATUS, written: usize) {
+        let request = self.into_raw();
+
+        // SAFETY: Not completed yet, self is consumed
+        _ = unsafe { WdfRequestCompleteWithInformation(request, status, written as u64) };
+    }
+
+    fn into_raw(self) -> WDFREQUEST {
+        let request = self.request;
+        mem::forget(self);
+
+        request
+    }
+}
+
+impl Drop for WdfRequest {
+    fn drop(&mut self) {
+        // SAFETY: Not completed yet, completing consumes self without dropping it
+        _ = unsafe { WdfRequestComplete(self.request, NTSTATUS::STATUS_INTERNAL_ERROR) };
+    }
+}
@@ -1,11 +1,34 @@
+mod config;
+mod context;
+mod cursor;
+mod deferred;
+mod driver;
 mod iddcx;
+mod io;
+mod panic;
+mod registry;
+#[cfg(feature = "test-shim")]
+pub mod shim;
+mod sync;
+#[cfg(feature = "trace-calls")]
+pub mod trace;
 mod wdf;
 
 use std::any::Any;
 
 pub use paste::paste;
+pub use wdf_umdf_macros::{idd_driver, wdf_callback};
 
+pub use config::*;
+pub use context::*;
+pub use cursor::*;
+pub use deferred::*;
+pub use driver::*;
 pub use iddcx::*;
+pub use io::*;
+pub use panic::*;
+pub use registry::*;
+pub use sync::*;
 pub use wdf::*;
 pub use wdf_umdf_sys;
 
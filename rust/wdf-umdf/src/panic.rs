@@ -0,0 +1,101 @@
+use std::{
+    backtrace::Backtrace,
+    fs::{self, OpenOptions},
+    io::Write as _,
+    panic::{self, AssertUnwindSafe, PanicInfo},
+    path::PathBuf,
+    thread,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use wdf_umdf_sys::{NTSTATUS, WDFDEVICE, WDF_DEVICE_FAILED_ACTION};
+
+use crate::WdfDeviceSetFailed;
+
+/// Install a panic hook which appends a crash report (panic message and backtrace) to `report_path`
+///
+/// The previously installed hook is still called afterwards, so this can be layered on top of a
+/// hook that logs the panic.
+pub fn install_panic_hook(report_path: impl Into<PathBuf>) {
+    let report_path = report_path.into();
+    let previous = panic::take_hook();
+
+    panic::set_hook(Box::new(move |info| {
+        // nothing sensible to do if writing the report fails, the previous hook still runs
+        _ = write_report(&report_path, info);
+        previous(info);
+    }));
+}
+
+fn write_report(report_path: &PathBuf, info: &PanicInfo) -> std::io::Result<()> {
+    if let Some(parent) = report_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    let thread = thread::current();
+    let thread = thread.name().unwrap_or("<unnamed>");
+    let backtrace = Backtrace::force_capture();
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(report_path)?;
+
+    writeln!(
+        file,
+        "=== crash report, unix time {timestamp}, thread '{thread}' ===\n{info}\n\nstack backtrace:\n{backtrace}\n"
+    )
+}
+
+/// Run `f`, catching a panic instead of letting it unwind into the framework
+///
+/// On panic, `device` (if any) is reported to the framework with `WdfDeviceSetFailed`, so it gets
+/// torn down and restarted in a controlled way, and `None` is returned.
+///
+/// # Safety
+///
+/// `device` must be a valid device handle, or `None`
+pub unsafe fn catch_panic<R>(device: Option<WDFDEVICE>, f: impl FnOnce() -> R) -> Option<R> {
+    let Ok(result) = panic::catch_unwind(AssertUnwindSafe(f)) else {
+        if let Some(device) = device {
+            _ = unsafe {
+                WdfDeviceSetFailed(
+                    device,
+                    WDF_DEVICE_FAILED_ACTION::WdfDeviceFailedAttemptRestart,
+                )
+            };
+        }
+
+        return None;
+    };
+
+    Some(result)
+}
+
+/// What a callback returns when it panicked, see [`guard`]
+pub trait PanicResult {
+    fn panicked() -> Self;
+}
+
+impl PanicResult for NTSTATUS {
+    fn panicked() -> Self {
+        NTSTATUS::STATUS_DRIVER_INTERNAL_ERROR
+    }
+}
+
+impl PanicResult for () {
+    fn panicked() -> Self {}
+}
+
+/// Run the callback `f`, returning [`PanicResult::panicked`] instead of unwinding into the
+/// framework
+///
+/// This is the default guard of `wdf_callback`, which doesn't fail any device on panic.
+pub fn guard<R: PanicResult>(f: impl FnOnce() -> R) -> R {
+    // SAFETY: No device to report it to
+    unsafe { catch_panic(None, f) }.unwrap_or_else(R::panicked)
+}
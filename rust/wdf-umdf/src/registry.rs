@@ -0,0 +1,226 @@
+//! Typed access to the registry keys of the driver and its devices
+
+use std::ptr;
+
+use wdf_umdf_sys::{
+    ACCESS_MASK, NTSTATUS, PCUNICODE_STRING, PLUGPLAY_REGKEY_DEVICE, REG_BINARY, REG_DWORD,
+    REG_EXPAND_SZ, REG_QWORD, REG_SZ, UNICODE_STRING, WDFDEVICE, WDFKEY, WDF_REGKEY_DEVICE_SUBKEY,
+};
+
+use crate::{
+    WdfDeviceOpenRegistryKey, WdfDriverOpenParametersRegistryKey, WdfError, WdfGetDriver,
+    WdfRegistryAssignValue, WdfRegistryClose, WdfRegistryQueryValue,
+};
+
+// Initial buffer size for querying values, grown to fit larger ones
+const QUERY_BUFFER_SIZE: usize = 64;
+
+/// A value that can be stored in the registry, see [`WdfRegistryKey`]
+pub trait RegistryValue: Sized {
+    /// Registry type and data of the value
+    fn to_registry(&self) -> (u32, Vec<u8>);
+
+    /// The value from its registry type and data, `None` if they don't fit the type
+    fn from_registry(value_type: u32, data: &[u8]) -> Option<Self>;
+}
+
+impl RegistryValue for u32 {
+    fn to_registry(&self) -> (u32, Vec<u8>) {
+        (REG_DWORD, self.to_le_bytes().to_vec())
+    }
+
+    fn from_registry(value_type: u32, data: &[u8]) -> Option<Self> {
+        (value_type == REG_DWORD)
+            .then(|| data.try_into().ok().map(Self::from_le_bytes))
+            .flatten()
+    }
+}
+
+impl RegistryValue for u64 {
+    fn to_registry(&self) -> (u32, Vec<u8>) {
+        (REG_QWORD, self.to_le_bytes().to_vec())
+    }
+
+    fn from_registry(value_type: u32, data: &[u8]) -> Option<Self> {
+        (value_type == REG_QWORD)
+            .then(|| data.try_into().ok().map(Self::from_le_bytes))
+            .flatten()
+    }
+}
+
+// stored as a DWORD, like windows does with its own flags
+impl RegistryValue for bool {
+    fn to_registry(&self) -> (u32, Vec<u8>) {
+        u32::from(*self).to_registry()
+    }
+
+    fn from_registry(value_type: u32, data: &[u8]) -> Option<Self> {
+        u32::from_registry(value_type, data).map(|value| value != 0)
+    }
+}
+
+impl RegistryValue for String {
+    fn to_registry(&self) -> (u32, Vec<u8>) {
+        let data = self
+            .encode_utf16()
+            .chain([0])
+            .flat_map(u16::to_le_bytes)
+            .collect();
+
+        (REG_SZ, data)
+    }
+
+    fn from_registry(value_type: u32, data: &[u8]) -> Option<Self> {
+        if value_type != REG_SZ && value_type != REG_EXPAND_SZ {
+            return None;
+        }
+
+        let mut wide = data
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .collect::<Vec<_>>();
+
+        // the terminating nul is part of the data, but not always
+        while wide.last() == Some(&0) {
+            wide.pop();
+        }
+
+        String::from_utf16(&wide).ok()
+    }
+}
+
+impl RegistryValue for Vec<u8> {
+    fn to_registry(&self) -> (u32, Vec<u8>) {
+        (REG_BINARY, self.clone())
+    }
+
+    fn from_registry(value_type: u32, data: &[u8]) -> Option<Self> {
+        (value_type == REG_BINARY).then(|| data.to_vec())
+    }
+}
+
+/// An open registry key, closed when dropped
+pub struct WdfRegistryKey(WDFKEY);
+
+impl WdfRegistryKey {
+    /// The driver's `Parameters` key, under its service key
+    pub fn open_parameters(access: ACCESS_MASK) -> Result<Self, WdfError> {
+        // SAFETY: Only called once the driver was created
+        let driver = unsafe { WdfGetDriver() }?;
+
+        let mut key = ptr::null_mut();
+        // SAFETY: The driver lives as long as the process
+        unsafe { WdfDriverOpenParametersRegistryKey(driver, access, None, &mut key) }?;
+
+        Ok(Self(key))
+    }
+
+    /// The driver's subkey of the hardware key of `device`, which is specific to a device
+    /// instance and writable by UMDF drivers
+    ///
+    /// # Safety
+    ///
+    /// `device` must be a valid device, and the key must be dropped before it's deleted
+    pub unsafe fn open_device(device: WDFDEVICE, access: ACCESS_MASK) -> Result<Self, WdfError> {
+        let mut key = ptr::null_mut();
+        // SAFETY: The device is valid, see above
+        unsafe {
+            WdfDeviceOpenRegistryKey(
+                device,
+                PLUGPLAY_REGKEY_DEVICE | WDF_REGKEY_DEVICE_SUBKEY,
+                access,
+                None,
+                &mut key,
+            )
+        }?;
+
+        Ok(Self(key))
+    }
+
+    /// Query the value `name`
+    ///
+    /// Returns `None` if there is no such value, or it has a different type than `T`
+    pub fn get<T: RegistryValue>(&self, name: &str) -> Result<Option<T>, WdfError> {
+        let mut data = vec![0u8; QUERY_BUFFER_SIZE];
+
+        loop {
+            let mut len = 0;
+            let mut value_type = 0;
+            let buffer_len = u32::try_from(data.len()).unwrap_or(u32::MAX);
+
+            let res = with_name(name, |name| {
+                // SAFETY: The key is valid until self is dropped, and data is buffer_len long
+                unsafe {
+                    WdfRegistryQueryValue(
+                        self.0,
+                        name,
+                        buffer_len,
+                        data.as_mut_ptr().cast(),
+                        Some(&mut len),
+                        Some(&mut value_type),
+                    )
+                }
+            })?;
+
+            match res {
+                Ok(_) => {
+                    data.truncate(len as usize);
+                    return Ok(T::from_registry(value_type, &data));
+                }
+
+                // grow to the size the value needs, and try again
+                Err(WdfError::CallFailed(status))
+                    if status == NTSTATUS::STATUS_BUFFER_OVERFLOW && len as usize > data.len() =>
+                {
+                    data.resize(len as usize, 0);
+                }
+
+                Err(WdfError::CallFailed(status))
+                    if status == NTSTATUS::STATUS_OBJECT_NAME_NOT_FOUND =>
+                {
+                    return Ok(None);
+                }
+
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Set the value `name`, creating it if needed
+    pub fn set<T: RegistryValue>(&self, name: &str, value: &T) -> Result<(), WdfError> {
+        let (value_type, mut data) = value.to_registry();
+        let len = u32::try_from(data.len())
+            .map_err(|_| WdfError::CallFailed(NTSTATUS::STATUS_INVALID_PARAMETER))?;
+
+        with_name(name, |name| {
+            // SAFETY: The key is valid until self is dropped, and data is len long
+            unsafe {
+                WdfRegistryAssignValue(self.0, name, value_type, len, data.as_mut_ptr().cast())
+            }
+        })??;
+
+        Ok(())
+    }
+}
+
+impl Drop for WdfRegistryKey {
+    fn drop(&mut self) {
+        // SAFETY: Not used anymore
+        _ = unsafe { WdfRegistryClose(self.0) };
+    }
+}
+
+/// Run `f` with `name` as a `UNICODE_STRING`
+fn with_name<R>(name: &str, f: impl FnOnce(PCUNICODE_STRING) -> R) -> Result<R, WdfError> {
+    let mut wide = name.encode_utf16().collect::<Vec<_>>();
+    let byte_len = u16::try_from(wide.len() * 2)
+        .map_err(|_| WdfError::CallFailed(NTSTATUS::STATUS_NAME_TOO_LONG))?;
+
+    let name = UNICODE_STRING {
+        Length: byte_len,
+        MaximumLength: byte_len,
+        Buffer: wide.as_mut_ptr(),
+    };
+
+    Ok(f(&name))
+}
@@ -0,0 +1,177 @@
+//! In-process stand-ins for the framework's function tables, to test driver logic on any machine
+//!
+//! With the `test-shim` feature, every WDF and IddCx call made through this crate goes to the
+//! mocks registered here instead of the framework. A call without a mock fails as if the function
+//! wasn't available. Mocks are per thread, so tests running in parallel don't see each other's.
+//!
+//! ```rust,ignore
+//! let _mock = mock_wdf!(WdfObjectDelete, |(object,)| {
+//!     deleted.push(object);
+//! });
+//! ```
+
+use std::{any::Any, cell::RefCell, collections::HashMap, marker::PhantomData};
+
+/// The signature of a function in a function table, without the driver globals it's passed
+pub trait TableFn {
+    type Args: 'static;
+    type Output: 'static;
+}
+
+macro_rules! impl_table_fn {
+    ($($arg:ident),*) => {
+        impl<G, $($arg: 'static,)* R: 'static> TableFn
+            for Option<unsafe extern "C-unwind" fn(G, $($arg),*) -> R>
+        {
+            type Args = ($($arg,)*);
+            type Output = R;
+        }
+    };
+}
+
+impl_table_fn!();
+impl_table_fn!(A);
+impl_table_fn!(A, B);
+impl_table_fn!(A, B, C);
+impl_table_fn!(A, B, C, D);
+impl_table_fn!(A, B, C, D, E);
+impl_table_fn!(A, B, C, D, E, F);
+impl_table_fn!(A, B, C, D, E, F, H);
+impl_table_fn!(A, B, C, D, E, F, H, I);
+impl_table_fn!(A, B, C, D, E, F, H, I, J);
+
+type Mock<F> = Box<dyn FnMut(<F as TableFn>::Args) -> <F as TableFn>::Output>;
+
+thread_local! {
+    // the boxed mocks, by function name
+    static MOCKS: RefCell<HashMap<&'static str, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Route calls to the function `name` with the signature `F` to `mock`, until the returned guard
+/// is dropped
+///
+/// Use [`mock_wdf!`](crate::mock_wdf) or [`mock_iddcx!`](crate::mock_iddcx) instead, they fill in
+/// `F` from the name.
+#[must_use = "the mock is removed again when the guard is dropped"]
+pub fn mock<F: TableFn>(
+    name: &'static str,
+    mock: impl FnMut(F::Args) -> F::Output + 'static,
+) -> MockGuard {
+    let mock: Mock<F> = Box::new(mock);
+
+    MOCKS.with_borrow_mut(|mocks| {
+        mocks.insert(name, Box::new(mock));
+    });
+
+    MockGuard {
+        name,
+        _not_send: PhantomData,
+    }
+}
+
+/// Call the mock of `name`, `None` if there is none
+///
+/// # Panics
+///
+/// If the mock was registered with a different signature
+pub fn call<F: TableFn>(name: &'static str, args: F::Args) -> Option<F::Output> {
+    // taken out while it runs, so it can register other mocks. It can't call itself though, that
+    // finds no mock
+    let mut mock = MOCKS.with_borrow_mut(|mocks| mocks.remove(name))?;
+
+    let output = mock
+        .downcast_mut::<Mock<F>>()
+        .map(|mock| mock(args))
+        .unwrap_or_else(|| panic!("{name} was mocked with a different signature"));
+
+    MOCKS.with_borrow_mut(|mocks| {
+        // unless the mock replaced itself
+        mocks.entry(name).or_insert(mock);
+    });
+
+    Some(output)
+}
+
+/// Removes its mock when dropped
+pub struct MockGuard {
+    name: &'static str,
+    // mocks belong to the thread which registered them
+    _not_send: PhantomData<*const ()>,
+}
+
+impl Drop for MockGuard {
+    fn drop(&mut self) {
+        MOCKS.with_borrow_mut(|mocks| mocks.remove(self.name));
+    }
+}
+
+/// Mock the WDF function `$name` with a closure taking its arguments as tuple, see [`mock`]
+#[macro_export]
+macro_rules! mock_wdf {
+    ($name:ident, $mock:expr) => {
+        $crate::paste! {
+            $crate::shim::mock::<$crate::wdf_umdf_sys::[<PFN_ $name:upper>]>(
+                stringify!($name),
+                $mock,
+            )
+        }
+    };
+}
+
+/// Mock the IddCx function `$name` with a closure taking its arguments as tuple, see [`mock`]
+#[macro_export]
+macro_rules! mock_iddcx {
+    ($name:ident, $mock:expr) => {
+        $crate::mock_wdf!($name, $mock)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, ptr, rc::Rc};
+
+    use wdf_umdf_sys::NTSTATUS;
+
+    use crate::{IddCxError, IddCxMonitorDeparture, WdfError, WdfObjectDelete};
+
+    #[test]
+    fn calls_mock() {
+        let deleted = Rc::new(Cell::new(0));
+        let counter = deleted.clone();
+        let _mock = mock_wdf!(WdfObjectDelete, move |(_object,)| {
+            counter.set(counter.get() + 1);
+        });
+
+        // SAFETY: Mocked
+        unsafe { WdfObjectDelete(ptr::null_mut()) }.unwrap();
+        // SAFETY: Mocked
+        unsafe { WdfObjectDelete(ptr::null_mut()) }.unwrap();
+
+        assert_eq!(deleted.get(), 2);
+    }
+
+    #[test]
+    fn mock_status_is_checked() {
+        let _mock = mock_iddcx!(IddCxMonitorDeparture, |(_monitor,)| {
+            NTSTATUS::STATUS_INVALID_PARAMETER
+        });
+
+        // SAFETY: Mocked
+        let res = unsafe { IddCxMonitorDeparture(ptr::null_mut()) };
+        let Err(IddCxError::CallFailed(status)) = res else {
+            panic!("expected the call to fail, got {res:?}");
+        };
+        assert_eq!(status, NTSTATUS::STATUS_INVALID_PARAMETER);
+    }
+
+    #[test]
+    fn unmocked_is_unavailable() {
+        {
+            let _mock = mock_wdf!(WdfObjectDelete, |(_object,)| ());
+        }
+
+        // SAFETY: Not mocked, so never reaches the framework
+        let res = unsafe { WdfObjectDelete(ptr::null_mut()) };
+        assert!(matches!(res, Err(WdfError::WdfFunctionNotAvailable(_))));
+    }
+}
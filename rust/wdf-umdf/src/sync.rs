@@ -0,0 +1,285 @@
+use std::{
+    cell::UnsafeCell,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    ptr,
+    time::Duration,
+};
+
+use wdf_umdf_sys::{NTSTATUS, WDFSPINLOCK, WDFWAITLOCK};
+
+use crate::{
+    WdfError, WdfObjectDelete, WdfSpinLockAcquire, WdfSpinLockCreate, WdfSpinLockRelease,
+    WdfWaitLockAcquire, WdfWaitLockCreate, WdfWaitLockRelease,
+};
+
+/// Relative time in 100ns units, as the framework takes it for timeouts and due times
+pub(crate) fn relative_time(duration: Duration) -> i64 {
+    // negative values are relative to now, positive ones absolute
+    -i64::try_from(duration.as_nanos() / 100).unwrap_or(i64::MAX)
+}
+
+/// Data protected by a framework spin lock
+///
+/// Holding it blocks other threads busily, so it's only meant for short critical sections
+pub struct WdfSpinLock<T> {
+    lock: WDFSPINLOCK,
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: The data is only ever accessed while holding the lock
+unsafe impl<T: Send> Send for WdfSpinLock<T> {}
+unsafe impl<T: Send> Sync for WdfSpinLock<T> {}
+
+impl<T> WdfSpinLock<T> {
+    pub fn new(data: T) -> Result<Self, WdfError> {
+        let mut lock = ptr::null_mut();
+        // SAFETY: Without attributes, the lock belongs to the driver until it's dropped
+        unsafe { WdfSpinLockCreate(None, &mut lock) }?;
+
+        Ok(Self {
+            lock,
+            data: UnsafeCell::new(data),
+        })
+    }
+
+    /// Acquire the lock, which is released when the guard is dropped
+    pub fn lock(&self) -> Result<WdfSpinLockGuard<'_, T>, WdfError> {
+        // SAFETY: The lock is valid until self is dropped
+        unsafe { WdfSpinLockAcquire(self.lock) }?;
+
+        Ok(WdfSpinLockGuard {
+            lock: self,
+            _not_send: PhantomData,
+        })
+    }
+}
+
+impl<T> Drop for WdfSpinLock<T> {
+    fn drop(&mut self) {
+        // SAFETY: Nothing can hold it anymore, guards borrow self
+        _ = unsafe { WdfObjectDelete(self.lock.cast()) };
+    }
+}
+
+pub struct WdfSpinLockGuard<'a, T> {
+    lock: &'a WdfSpinLock<T>,
+    // released by the thread that acquired it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<T> Deref for WdfSpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The lock is held while the guard lives
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> DerefMut for WdfSpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The lock is held while the guard lives
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for WdfSpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: The lock was acquired by this guard
+        _ = unsafe { WdfSpinLockRelease(self.lock.lock) };
+    }
+}
+
+/// Data protected by a framework wait lock
+///
+/// Threads waiting for it sleep, so it can be held across longer operations
+pub struct WdfWaitLock<T> {
+    lock: WDFWAITLOCK,
+    data: UnsafeCell<T>,
+}
+
+// SAFETY: The data is only ever accessed while holding the lock
+unsafe impl<T: Send> Send for WdfWaitLock<T> {}
+unsafe impl<T: Send> Sync for WdfWaitLock<T> {}
+
+impl<T> WdfWaitLock<T> {
+    pub fn new(data: T) -> Result<Self, WdfError> {
+        let mut lock = ptr::null_mut();
+        // SAFETY: Without attributes, the lock belongs to the driver until it's dropped
+        unsafe { WdfWaitLockCreate(None, &mut lock) }?;
+
+        Ok(Self {
+            lock,
+            data: UnsafeCell::new(data),
+        })
+    }
+
+    /// Acquire the lock, waiting as long as it takes
+    pub fn lock(&self) -> Result<WdfWaitLockGuard<'_, T>, WdfError> {
+        // SAFETY: The lock is valid until self is dropped
+        unsafe { WdfWaitLockAcquire(self.lock, None) }?;
+
+        Ok(WdfWaitLockGuard {
+            lock: self,
+            _not_send: PhantomData,
+        })
+    }
+
+    /// Acquire the lock, giving up after `timeout`
+    ///
+    /// Returns `None` if it's still held by someone else by then. A zero timeout only tries once.
+    pub fn try_lock_for(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<WdfWaitLockGuard<'_, T>>, WdfError> {
+        let mut timeout = relative_time(timeout);
+
+        // SAFETY: The lock is valid until self is dropped
+        let status = unsafe { WdfWaitLockAcquire(self.lock, Some(&mut timeout)) }?;
+        if status == NTSTATUS::STATUS_TIMEOUT {
+            return Ok(None);
+        }
+
+        Ok(Some(WdfWaitLockGuard {
+            lock: self,
+            _not_send: PhantomData,
+        }))
+    }
+}
+
+impl<T> Drop for WdfWaitLock<T> {
+    fn drop(&mut self) {
+        // SAFETY: Nothing can hold it anymore, guards borrow self
+        _ = unsafe { WdfObjectDelete(self.lock.cast()) };
+    }
+}
+
+pub struct WdfWaitLockGuard<'a, T> {
+    lock: &'a WdfWaitLock<T>,
+    // released by the thread that acquired it
+    _not_send: PhantomData<*const ()>,
+}
+
+impl<T> Deref for WdfWaitLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: The lock is held while the guard lives
+        unsafe { &*self.lock.data.get() }
+    }
+}
+
+impl<T> DerefMut for WdfWaitLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: The lock is held while the guard lives
+        unsafe { &mut *self.lock.data.get() }
+    }
+}
+
+impl<T> Drop for WdfWaitLockGuard<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: The lock was acquired by this guard
+        _ = unsafe { WdfWaitLockRelease(self.lock.lock) };
+    }
+}
+
+#[cfg(all(test, feature = "test-shim"))]
+mod tests {
+    use std::{cell::RefCell, ptr::NonNull, rc::Rc, time::Duration};
+
+    use wdf_umdf_sys::NTSTATUS;
+
+    use super::*;
+    use crate::{mock_wdf, shim::MockGuard};
+
+    // framework calls, in the order they were made
+    type Calls = Rc<RefCell<Vec<&'static str>>>;
+
+    fn mock_delete(calls: &Calls) -> MockGuard {
+        let calls = calls.clone();
+        mock_wdf!(WdfObjectDelete, move |(_object,)| {
+            calls.borrow_mut().push("delete");
+        })
+    }
+
+    #[test]
+    fn relative_times() {
+        assert_eq!(relative_time(Duration::from_millis(5)), -50_000);
+        assert_eq!(relative_time(Duration::ZERO), 0);
+        assert_eq!(relative_time(Duration::MAX), -i64::MAX);
+    }
+
+    #[test]
+    fn spin_lock_is_released_by_its_guard() {
+        let calls = Calls::default();
+
+        let _create = mock_wdf!(WdfSpinLockCreate, |(_attributes, lock)| {
+            // SAFETY: The wrapper passes a valid out pointer
+            unsafe { lock.write(NonNull::dangling().as_ptr()) };
+            NTSTATUS::STATUS_SUCCESS
+        });
+        let log = calls.clone();
+        let _acquire = mock_wdf!(WdfSpinLockAcquire, move |(_lock,)| {
+            log.borrow_mut().push("acquire");
+        });
+        let log = calls.clone();
+        let _release = mock_wdf!(WdfSpinLockRelease, move |(_lock,)| {
+            log.borrow_mut().push("release");
+        });
+        let _delete = mock_delete(&calls);
+
+        let lock = WdfSpinLock::new(1).unwrap();
+        *lock.lock().unwrap() += 1;
+        assert_eq!(*lock.lock().unwrap(), 2);
+        drop(lock);
+
+        assert_eq!(
+            *calls.borrow(),
+            ["acquire", "release", "acquire", "release", "delete"]
+        );
+    }
+
+    #[test]
+    fn failed_create_is_an_error() {
+        let _create = mock_wdf!(WdfWaitLockCreate, |(_attributes, _lock)| {
+            NTSTATUS::STATUS_INSUFFICIENT_RESOURCES
+        });
+
+        assert!(WdfWaitLock::new(()).is_err());
+    }
+
+    #[test]
+    fn wait_lock_times_out() {
+        let calls = Calls::default();
+        let timeouts = Rc::new(RefCell::new(Vec::new()));
+
+        let _create = mock_wdf!(WdfWaitLockCreate, |(_attributes, lock)| {
+            // SAFETY: The wrapper passes a valid out pointer
+            unsafe { lock.write(NonNull::dangling().as_ptr()) };
+            NTSTATUS::STATUS_SUCCESS
+        });
+        let log = timeouts.clone();
+        let _acquire = mock_wdf!(WdfWaitLockAcquire, move |(_lock, timeout)| {
+            // SAFETY: The wrapper passes a valid pointer or null
+            log.borrow_mut().push(unsafe { timeout.as_ref() }.copied());
+            NTSTATUS::STATUS_TIMEOUT
+        });
+        let log = calls.clone();
+        let _release = mock_wdf!(WdfWaitLockRelease, move |(_lock,)| {
+            log.borrow_mut().push("release");
+        });
+        let _delete = mock_delete(&calls);
+
+        let lock = WdfWaitLock::new(()).unwrap();
+        assert!(lock
+            .try_lock_for(Duration::from_millis(5))
+            .unwrap()
+            .is_none());
+        drop(lock);
+
+        assert_eq!(*timeouts.borrow(), [Some(-50_000)]);
+        // a lock that wasn't acquired isn't released
+        assert_eq!(*calls.borrow(), ["delete"]);
+    }
+}
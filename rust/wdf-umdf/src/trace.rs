@@ -0,0 +1,42 @@
+//! Logs every framework call made through this crate, with the `trace-calls` feature
+//!
+//! Each call is a [`tracing`] event with the target [`TARGET`] and the fields `call` (function
+//! name), `args` (the argument expressions), `status` or `error`, and `elapsed_us`. Successful
+//! calls are logged at debug level, failed ones as warnings. Where the events end up is up to the
+//! subscriber the driver installs.
+
+use std::{any::Any, fmt::Display, time::Instant};
+
+use wdf_umdf_sys::NTSTATUS;
+
+/// Target of the call events, to filter them by
+pub const TARGET: &str = "wdf_umdf::calls";
+
+/// Run `call`, and log its result and how long it took
+pub(crate) fn call<T: Any, E: Display>(
+    name: &'static str,
+    args: &'static str,
+    call: impl FnOnce() -> Result<T, E>,
+) -> Result<T, E> {
+    let start = Instant::now();
+    let result = call();
+    let elapsed_us = u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX);
+
+    match &result {
+        Ok(value) => {
+            // calls without a status succeed whenever they return
+            let status = (value as &dyn Any)
+                .downcast_ref::<NTSTATUS>()
+                .copied()
+                .unwrap_or(NTSTATUS::STATUS_SUCCESS);
+
+            tracing::debug!(target: TARGET, call = name, args, status = %status, elapsed_us);
+        }
+
+        Err(error) => {
+            tracing::warn!(target: TARGET, call = name, args, error = %error, elapsed_us);
+        }
+    }
+
+    result
+}
@@ -4,22 +4,27 @@
 use std::ffi::c_void;
 
 use wdf_umdf_sys::{
-    DEVPROPTYPE, NTSTATUS, PCUNICODE_STRING, PCWDF_OBJECT_CONTEXT_TYPE_INFO, PDRIVER_OBJECT,
-    POOL_TYPE, PWDFDEVICE_INIT, PWDF_DRIVER_CONFIG, PWDF_OBJECT_ATTRIBUTES, WDFDEVICE, WDFDRIVER,
-    WDFMEMORY, WDFOBJECT, WDF_DEVICE_FAILED_ACTION, WDF_NO_HANDLE, WDF_NO_OBJECT_ATTRIBUTES,
-    WDF_OBJECT_ATTRIBUTES, _WDF_DEVICE_PROPERTY_DATA, _WDF_PNPPOWER_EVENT_CALLBACKS,
+    ACCESS_MASK, BOOLEAN, DEVPROPTYPE, GUID, NTSTATUS, PCUNICODE_STRING,
+    PCWDF_OBJECT_CONTEXT_TYPE_INFO, PDRIVER_OBJECT, POOL_TYPE, PWDFDEVICE_INIT, PWDF_DRIVER_CONFIG,
+    PWDF_OBJECT_ATTRIBUTES, WDFDEVICE, WDFDRIVER, WDFKEY, WDFMEMORY, WDFOBJECT, WDFQUEUE,
+    WDFREQUEST, WDFSPINLOCK, WDFTIMER, WDFWAITLOCK, WDFWORKITEM, WDF_DEVICE_FAILED_ACTION,
+    WDF_IO_QUEUE_CONFIG, WDF_NO_HANDLE, WDF_NO_OBJECT_ATTRIBUTES, WDF_OBJECT_ATTRIBUTES,
+    WDF_TIMER_CONFIG, WDF_WORKITEM_CONFIG, _WDF_DEVICE_PROPERTY_DATA,
+    _WDF_PNPPOWER_EVENT_CALLBACKS,
 };
 
 #[derive(Debug, thiserror::Error)]
 pub enum WdfError {
     #[error("{0}")]
     WdfFunctionNotAvailable(&'static str),
-    #[error("{0}")]
-    CallFailed(NTSTATUS),
+    #[error("Call failed")]
+    CallFailed(#[source] NTSTATUS),
     #[error("Failed to upgrade Arc pointer")]
     UpgradeFailed,
     #[error("Failed to lock")]
     LockFailed,
+    #[error("Object has no context of this type")]
+    NoContext,
     #[error("Unknown")]
     Unknown,
     // this is required for success status for ()
@@ -33,6 +38,18 @@ impl From<()> for WdfError {
     }
 }
 
+impl From<BOOLEAN> for WdfError {
+    fn from(_: BOOLEAN) -> Self {
+        Self::Unknown
+    }
+}
+
+impl From<WDFDRIVER> for WdfError {
+    fn from(_: WDFDRIVER) -> Self {
+        Self::Unknown
+    }
+}
+
 impl From<*mut c_void> for WdfError {
     fn from(_: *mut c_void) -> Self {
         Self::Unknown
@@ -48,6 +65,7 @@ impl From<WdfError> for NTSTATUS {
             CallFailed(status) => status,
             UpgradeFailed => Self::STATUS_INVALID_HANDLE,
             LockFailed => Self::STATUS_WAS_LOCKED,
+            NoContext => Self::STATUS_INVALID_HANDLE,
             Unknown => Self::STATUS_DRIVER_INTERNAL_ERROR,
             _Success => 0.into(),
         }
@@ -61,11 +79,23 @@ impl From<NTSTATUS> for WdfError {
 }
 
 macro_rules! WdfCall {
-    ($name:ident ( $($args:expr),* )) => {
-        WdfCall!(false, $name($($args),*))
-    };
+    // calls the mock of the function instead, see `shim`
+    (@shim $other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        ::paste::paste! {
+            type Pfn = ::wdf_umdf_sys::[<PFN_ $name:upper>];
+        }
 
-    ($other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        // coerces the arguments to the types of the function
+        let args: <Pfn as $crate::shim::TableFn>::Args = ($($args,)*);
+
+        match $crate::shim::call::<Pfn>(stringify!($name), args) {
+            Some(result) if $crate::is_nt_error(&result, $other_is_error) => Err(result.into()),
+            Some(result) => Ok(result.into()),
+            None => Err($crate::WdfError::WdfFunctionNotAvailable(concat!(stringify!($name), " is not mocked"))),
+        }
+    }};
+
+    (@table $other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
         let fn_handle = {
             ::paste::paste! {
                 const FN_INDEX: usize = ::wdf_umdf_sys::WDFFUNCENUM::[<$name TableIndex>].0 as usize;
@@ -115,320 +145,31 @@ macro_rules! WdfCall {
             })
         }
     }};
-}
 
-/// Unlike the official `WDF_DECLARE_CONTEXT_TYPE` macro, you only need to declare this on the actual data struct want to use
-/// Safety is maintained through a `RwLock` of the underlying data
-///
-/// This generates associated fns `init`/`get`/`drop`/`get_type_info` on your `$context_type` with the same visibility
-///
-/// Example:
-/// ```rust
-/// pub struct IndirectDeviceContext {
-///     device: WDFDEVICE,
-/// }
-///
-/// impl IndirectDeviceContext {
-///     pub fn new(device: WDFDEVICE) -> Self {
-///         Self { device }
-///     }
-/// }
-///
-/// WDF_DECLARE_CONTEXT_TYPE!(pub IndirectDeviceContext);
-///
-/// // with a `device: WDFDEVICE`
-/// let context = IndirectDeviceContext::new(device as WDFOBJECT);
-/// IndirectDeviceContext::init(context);
-/// // elsewhere
-/// let mutable_access = IndirectDeviceContext::get_mut(device).unwrap();
-/// ```
-#[macro_export]
-macro_rules! WDF_DECLARE_CONTEXT_TYPE {
-    ($sv:vis $context_type:ident) => {
-        $crate::paste! {
-            // keep it in a mod block to disallow access to private types
-            #[allow(non_snake_case)]
-            mod [<WdfObject $context_type>] {
-                use super::$context_type;
-
-                // Require `T: Sync` for safety. User has to uphold the invariant themselves
-                #[repr(transparent)]
-                #[allow(non_camel_case_types)]
-                struct [<_WDF_ $context_type _STATIC_WRAPPER>]<T> {
-                    cell: ::std::cell::UnsafeCell<$crate::wdf_umdf_sys::_WDF_OBJECT_CONTEXT_TYPE_INFO>,
-                    _phantom: ::std::marker::PhantomData<T>
-                }
+    (@call $other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        #[cfg(not(feature = "test-shim"))]
+        let result = WdfCall!(@table $other_is_error, $name($($args),*));
+        #[cfg(feature = "test-shim")]
+        let result = WdfCall!(@shim $other_is_error, $name($($args),*));
 
-                // SAFETY: `T` impls Sync too
-                unsafe impl<T: Sync> Sync for [<_WDF_ $context_type _STATIC_WRAPPER>]<T> {}
-
-                // Unsure if C mutates this data, but it's in an unsafecell just in case
-                #[allow(non_upper_case_globals)]
-                static [<_WDF_ $context_type _TYPE_INFO>]: [<_WDF_ $context_type _STATIC_WRAPPER>]<$context_type> =
-                    [<_WDF_ $context_type _STATIC_WRAPPER>] {
-                        cell: ::std::cell::UnsafeCell::new(
-                            $crate::wdf_umdf_sys::_WDF_OBJECT_CONTEXT_TYPE_INFO {
-                                Size: ::std::mem::size_of::<$crate::wdf_umdf_sys::_WDF_OBJECT_CONTEXT_TYPE_INFO>() as u32,
-                                ContextName: concat!(stringify!($context_type), "\0")
-                                    .as_ptr().cast::<::std::ffi::c_char>(),
-                                ContextSize: ::std::mem::size_of::<[<WdfObject $context_type>]>(),
-                                // SAFETY:
-                                // StaticWrapper and UnsafeCell are both repr(transparent), so cast to underlying _WDF_OBJECT_CONTEXT_TYPE_INFO is ok
-                                UniqueType: &[<_WDF_ $context_type _TYPE_INFO>] as *const _ as *const _,
-                                EvtDriverGetUniqueContextType: ::std::option::Option::None,
-                            }
-                        ),
-
-                        _phantom: ::std::marker::PhantomData
-                    };
-
-                /// Allows us to keep ONE main Arc allocation while handing out weak pointers to the rest of the clones.
-                /// In this way, we can drop the allocation by dropping 1 arc, while letting others still access it
-                enum ArcPointer<T> {
-                    Strong(::std::sync::Arc<T>),
-                    Weak(::std::sync::Weak<T>)
-                }
+        result
+    }};
 
-                #[repr(transparent)]
-                struct [<WdfObject $context_type>](ArcPointer<::std::sync::RwLock<$context_type>>);
-
-                impl $context_type {
-                    /// Initialize and place context into internal WdfObject
-                    ///
-                    /// SAFETY:
-                    /// - handle must be a fresh unused object with no data in its context already
-                    /// - context type must already have been set up for handle
-                    /// - Must be set only once regardless of the object. For all other objects, use clone_into()
-                    $sv unsafe fn init(
-                        self,
-                        handle: $crate::wdf_umdf_sys::WDFOBJECT,
-                    ) -> ::std::result::Result<(), $crate::WdfError> {
-                        let context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(handle, [<_WDF_ $context_type _TYPE_INFO>].cell.get())?
-                        } as *mut ::std::mem::MaybeUninit<[<WdfObject $context_type>]>;
-
-                        let context = &mut *context;
-
-                        // Write to the memory location, making the data in it init
-                        context.write(
-                            [<WdfObject $context_type>](
-                                ArcPointer::Strong(::std::sync::Arc::new(::std::sync::RwLock::new(self)))
-                            )
-                        );
-
-                        Ok(())
-                    }
-
-                    /// Initialize handle's context and clone a Weak pointer to self context into it.
-                    /// Internally, these are Arc's, so they will always point to the same data.
-                    /// When the main Arc drops, none of these may access memory any longer
-                    ///
-                    /// SAFETY:
-                    /// - handle must be a fresh unused object with no data in its context already
-                    /// - to_handle must have set context_type for this type via WDF_OBJECT_ATTRIBUTES when it was created
-                    /// - to_handle must be a valid T
-                    $sv unsafe fn clone_into(
-                        &self,
-                        handle: $crate::wdf_umdf_sys::WDFOBJECT
-                    ) -> ::std::result::Result<(), $crate::WdfError> {
-                        let context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(handle, [<_WDF_ $context_type _TYPE_INFO>].cell.get())?
-                        } as *mut ::std::mem::MaybeUninit<[<WdfObject $context_type>]>;
-
-                        let context = &mut *context;
-
-                        let from_context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(self.device as *mut _, [<_WDF_ $context_type _TYPE_INFO>].cell.get())?
-                        } as *mut [<WdfObject $context_type>];
-
-                        let from_context = match &(*from_context).0 {
-                            ArcPointer::Strong(a) => a.clone(),
-                            ArcPointer::Weak(a) => a.upgrade().ok_or($crate::WdfError::UpgradeFailed)?.clone(),
-                        };
-
-                        // Write to the memory location, making the data in it init
-                        // clones the arc into new handle
-                        context.write(
-                            [<WdfObject $context_type>](ArcPointer::Weak(::std::sync::Arc::downgrade(&from_context)))
-                        );
-
-                        Ok(())
-                    }
-
-                    /// NOTE: Dropping memory that was created via `clone_into` will never drop the main allocation.
-                    ///       To drop the main allocation, you need to drop the instance made via `init`.
-                    ///       That instance can be obtained through the original handle you created it through
-                    ///
-                    /// SAFETY:
-                    /// - Data in context is assumed to already be init and a valid T
-                    /// - Therefore, init for the context must already have been done on this handle
-                    /// - No other mutable/non-mutable refs can exist to data when this is called, or it will alias
-                    ///
-                    /// This may overwrite data in the handle's context memory, it is UB to read it after drop (e.g. get*)
-                    $sv unsafe fn drop(
-                        handle: $crate::wdf_umdf_sys::WDFOBJECT,
-                    ) -> ::std::result::Result<(), $crate::WdfError> {
-                        let context = $crate::WdfObjectGetTypedContextWorker(
-                            handle,
-                            [<_WDF_ $context_type _TYPE_INFO>].cell.get(),
-                        )? as *mut [<WdfObject $context_type>];
-
-                        // drop the memory
-                        ::std::ptr::drop_in_place(context);
-
-                        Ok(())
-                    }
-
-                    /// Borrow the context immutably
-                    /// Function returns with error and won't call cb if it failed to lock
-                    ///
-                    /// SAFETY:
-                    /// - Must have initialized WdfObject first
-                    /// - Data must not have been dropped
-                    /// - Object must not have been destroyed
-                    $sv unsafe fn get<F>(
-                        handle: *mut $crate::wdf_umdf_sys::WDFDEVICE__,
-                        cb: F
-                    ) -> ::std::result::Result<(), $crate::WdfError>
-                    where
-                        F: ::std::ops::FnOnce(&$context_type)
-                    {
-                        let context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(handle as *mut _,
-                                // SAFETY: Reading is always fine, since user cannot obtain mutable reference
-                                (&*[<_WDF_ $context_type _TYPE_INFO>].cell.get()).UniqueType
-                            )?
-                        } as *mut [<WdfObject $context_type>];
-
-                        let context = &*context;
-
-                        let context = match &context.0 {
-                            ArcPointer::Strong(a) => a.clone(),
-                            ArcPointer::Weak(a) => a.upgrade().ok_or($crate::WdfError::UpgradeFailed)?.clone(),
-                        };
-
-                        let guard = context.read().map_err(|_| $crate::WdfError::LockFailed)?;
-
-                        cb(&*guard);
-
-                        Ok(())
-                    }
-
-                    /// Borrow the context mutably
-                    /// Function returns with error and won't call cb if it failed to lock
-                    ///
-                    /// SAFETY:
-                    /// - Must have initialized WdfObject first
-                    /// - Data must not have been dropped
-                    /// - Object must not have been destroyed
-                    $sv unsafe fn get_mut<F>(
-                        handle: *mut $crate::wdf_umdf_sys::WDFDEVICE__,
-                        cb: F
-                    ) -> ::std::result::Result<(), $crate::WdfError>
-                    where
-                        F: ::std::ops::FnOnce(&mut $context_type)
-                    {
-                        let context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(handle as *mut _,
-                                // SAFETY: Reading is always fine, since user cannot obtain mutable reference
-                                (&*[<_WDF_ $context_type _TYPE_INFO>].cell.get()).UniqueType
-                            )?
-                        } as *mut [<WdfObject $context_type>];
-
-                        let context = &*context;
-
-                        let context = match &context.0 {
-                            ArcPointer::Strong(a) => a.clone(),
-                            ArcPointer::Weak(a) => a.upgrade().ok_or($crate::WdfError::UpgradeFailed)?.clone(),
-                        };
-
-                        let mut guard = context.write().map_err(|_| $crate::WdfError::LockFailed)?;
-
-                        cb(&mut *guard);
-
-                        Ok(())
-                    }
-
-                                        /// Borrow the context immutably
-                    /// Function returns with error and won't call cb if it failed to lock
-                    ///
-                    /// SAFETY:
-                    /// - Must have initialized WdfObject first
-                    /// - Data must not have been dropped
-                    /// - Object must not have been destroyed
-                    $sv unsafe fn try_get<F>(
-                        handle: *mut $crate::wdf_umdf_sys::WDFDEVICE__,
-                        cb: F
-                    ) -> ::std::result::Result<(), $crate::WdfError>
-                    where
-                        F: ::std::ops::FnOnce(&$context_type)
-                    {
-                        let context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(handle as *mut _,
-                                // SAFETY: Reading is always fine, since user cannot obtain mutable reference
-                                (&*[<_WDF_ $context_type _TYPE_INFO>].cell.get()).UniqueType
-                            )?
-                        } as *mut [<WdfObject $context_type>];
-
-                        let context = &*context;
-
-                        let context = match &context.0 {
-                            ArcPointer::Strong(a) => a.clone(),
-                            ArcPointer::Weak(a) => a.upgrade().ok_or($crate::WdfError::UpgradeFailed)?.clone(),
-                        };
-
-                        let guard = context.try_read().map_err(|_| $crate::WdfError::LockFailed)?;
-
-                        cb(&*guard);
-
-                        Ok(())
-                    }
-
-                    /// Try to borrow the context mutably. Immediately returns if it's locked
-                    /// Function returns with error and won't call cb if it failed to lock
-                    ///
-                    /// SAFETY:
-                    /// - Must have initialized WdfObject first
-                    /// - Data must not have been dropped
-                    /// - Object must not have been destroyed
-                    $sv unsafe fn try_get_mut<F>(
-                        handle: *mut $crate::wdf_umdf_sys::WDFDEVICE__,
-                        cb: F
-                    ) -> ::std::result::Result<(), $crate::WdfError>
-                    where
-                        F: ::std::ops::FnOnce(&mut $context_type)
-                    {
-                        let context = unsafe {
-                            $crate::WdfObjectGetTypedContextWorker(handle as *mut _,
-                                // SAFETY: Reading is always fine, since user cannot obtain mutable reference
-                                (&*[<_WDF_ $context_type _TYPE_INFO>].cell.get()).UniqueType
-                            )?
-                        } as *mut [<WdfObject $context_type>];
-
-                        let context = &*context;
-
-                        let context = match &context.0 {
-                            ArcPointer::Strong(a) => a.clone(),
-                            ArcPointer::Weak(a) => a.upgrade().ok_or($crate::WdfError::UpgradeFailed)?.clone(),
-                        };
-
-                        let mut guard = context.try_write().map_err(|_| $crate::WdfError::LockFailed)?;
-
-                        cb(&mut *guard);
-
-                        Ok(())
-                    }
-
-                    // SAFETY:
-                    // - No other mutable refs must exist to target type
-                    // - Underlying memory must remain immutable and unchanged until reference is dropped
-                    $sv unsafe fn get_type_info() -> &'static $crate::wdf_umdf_sys::_WDF_OBJECT_CONTEXT_TYPE_INFO {
-                        unsafe { &*[<_WDF_ $context_type _TYPE_INFO>].cell.get() }
-                    }
-                }
-            }
-        }
+    ($name:ident ( $($args:expr),* )) => {
+        WdfCall!(false, $name($($args),*))
     };
+
+    ($other_is_error:expr, $name:ident ( $($args:expr),* )) => {{
+        // logs the call, see `trace`
+        #[cfg(feature = "trace-calls")]
+        let result = $crate::trace::call(stringify!($name), stringify!($($args),*), || {
+            WdfCall!(@call $other_is_error, $name($($args),*))
+        });
+        #[cfg(not(feature = "trace-calls"))]
+        let result = WdfCall!(@call $other_is_error, $name($($args),*));
+
+        result
+    }};
 }
 
 /// # Safety
@@ -589,3 +330,474 @@ pub unsafe fn WdfMemoryGetBuffer(
         )
     }
 }
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfDeviceCreateDeviceInterface(
+    // in
+    Device: WDFDEVICE,
+    // in
+    InterfaceClassGUID: *const GUID,
+    // in, optional
+    ReferenceString: Option<PCUNICODE_STRING>,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfDeviceCreateDeviceInterface(
+            Device,
+            InterfaceClassGUID,
+            ReferenceString.unwrap_or(std::ptr::null())
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfDeviceSetDeviceInterfaceState(
+    // in
+    Device: WDFDEVICE,
+    // in
+    InterfaceClassGUID: *const GUID,
+    // in, optional
+    ReferenceString: Option<PCUNICODE_STRING>,
+    // in
+    IsInterfaceEnabled: BOOLEAN,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfDeviceSetDeviceInterfaceState(
+            Device,
+            InterfaceClassGUID,
+            ReferenceString.unwrap_or(std::ptr::null()),
+            IsInterfaceEnabled
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfSpinLockCreate(
+    // in, optional
+    SpinLockAttributes: Option<&mut WDF_OBJECT_ATTRIBUTES>,
+    // out
+    SpinLock: &mut WDFSPINLOCK,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfSpinLockCreate(
+            SpinLockAttributes.map_or(WDF_NO_OBJECT_ATTRIBUTES!(), |a| a as *mut _),
+            SpinLock
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfSpinLockAcquire(
+    // in
+    SpinLock: WDFSPINLOCK,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfSpinLockAcquire(
+            SpinLock
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfSpinLockRelease(
+    // in
+    SpinLock: WDFSPINLOCK,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfSpinLockRelease(
+            SpinLock
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfWaitLockCreate(
+    // in, optional
+    LockAttributes: Option<&mut WDF_OBJECT_ATTRIBUTES>,
+    // out
+    Lock: &mut WDFWAITLOCK,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfWaitLockCreate(
+            LockAttributes.map_or(WDF_NO_OBJECT_ATTRIBUTES!(), |a| a as *mut _),
+            Lock
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfWaitLockAcquire(
+    // in
+    Lock: WDFWAITLOCK,
+    // in, optional
+    Timeout: Option<&mut i64>,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfWaitLockAcquire(
+            Lock,
+            Timeout.map_or(std::ptr::null_mut(), |t| t as *mut _)
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfWaitLockRelease(
+    // in
+    Lock: WDFWAITLOCK,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfWaitLockRelease(
+            Lock
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfTimerCreate(
+    // in
+    Config: &mut WDF_TIMER_CONFIG,
+    // in
+    Attributes: &mut WDF_OBJECT_ATTRIBUTES,
+    // out
+    Timer: &mut WDFTIMER,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfTimerCreate(
+            Config,
+            Attributes,
+            Timer
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfTimerStart(
+    // in
+    Timer: WDFTIMER,
+    // in
+    DueTime: i64,
+) -> Result<BOOLEAN, WdfError> {
+    WdfCall! {
+        WdfTimerStart(
+            Timer,
+            DueTime
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfTimerStop(
+    // in
+    Timer: WDFTIMER,
+    // in
+    Wait: BOOLEAN,
+) -> Result<BOOLEAN, WdfError> {
+    WdfCall! {
+        WdfTimerStop(
+            Timer,
+            Wait
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfWorkItemCreate(
+    // in
+    Config: &mut WDF_WORKITEM_CONFIG,
+    // in
+    Attributes: &mut WDF_OBJECT_ATTRIBUTES,
+    // out
+    WorkItem: &mut WDFWORKITEM,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfWorkItemCreate(
+            Config,
+            Attributes,
+            WorkItem
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfWorkItemEnqueue(
+    // in
+    WorkItem: WDFWORKITEM,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfWorkItemEnqueue(
+            WorkItem
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfWorkItemFlush(
+    // in
+    WorkItem: WDFWORKITEM,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfWorkItemFlush(
+            WorkItem
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfGetDriver() -> Result<WDFDRIVER, WdfError> {
+    WdfCall! {
+        WdfGetDriver()
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfDriverOpenParametersRegistryKey(
+    // in
+    Driver: WDFDRIVER,
+    // in
+    DesiredAccess: ACCESS_MASK,
+    // in, optional
+    KeyAttributes: Option<&mut WDF_OBJECT_ATTRIBUTES>,
+    // out
+    Key: &mut WDFKEY,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfDriverOpenParametersRegistryKey(
+            Driver,
+            DesiredAccess,
+            KeyAttributes.map_or(WDF_NO_OBJECT_ATTRIBUTES!(), |a| a as *mut _),
+            Key
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfDeviceOpenRegistryKey(
+    // in
+    Device: WDFDEVICE,
+    // in
+    DeviceInstanceKeyType: u32,
+    // in
+    DesiredAccess: ACCESS_MASK,
+    // in, optional
+    KeyAttributes: Option<&mut WDF_OBJECT_ATTRIBUTES>,
+    // out
+    Key: &mut WDFKEY,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfDeviceOpenRegistryKey(
+            Device,
+            DeviceInstanceKeyType,
+            DesiredAccess,
+            KeyAttributes.map_or(WDF_NO_OBJECT_ATTRIBUTES!(), |a| a as *mut _),
+            Key
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRegistryQueryValue(
+    // in
+    Key: WDFKEY,
+    // in
+    ValueName: PCUNICODE_STRING,
+    // in
+    ValueLength: u32,
+    // out, optional
+    Value: *mut c_void,
+    // out, optional
+    ValueLengthQueried: Option<&mut u32>,
+    // out, optional
+    ValueType: Option<&mut u32>,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfRegistryQueryValue(
+            Key,
+            ValueName,
+            ValueLength,
+            Value,
+            ValueLengthQueried.map_or(std::ptr::null_mut(), |l| l as *mut _),
+            ValueType.map_or(std::ptr::null_mut(), |t| t as *mut _)
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRegistryAssignValue(
+    // in
+    Key: WDFKEY,
+    // in
+    ValueName: PCUNICODE_STRING,
+    // in
+    ValueType: u32,
+    // in
+    ValueLength: u32,
+    // in
+    Value: *mut c_void,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfRegistryAssignValue(
+            Key,
+            ValueName,
+            ValueType,
+            ValueLength,
+            Value
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRegistryClose(
+    // in
+    Key: WDFKEY,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfRegistryClose(
+            Key
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfIoQueueCreate(
+    // in
+    Device: WDFDEVICE,
+    // in
+    Config: &mut WDF_IO_QUEUE_CONFIG,
+    // in, optional
+    QueueAttributes: Option<&mut WDF_OBJECT_ATTRIBUTES>,
+    // out, optional
+    Queue: Option<&mut WDFQUEUE>,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfIoQueueCreate(
+            Device,
+            Config,
+            QueueAttributes.map_or(WDF_NO_OBJECT_ATTRIBUTES!(), |a| a as *mut _),
+            Queue.map_or(std::ptr::null_mut(), |q| q as *mut _)
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRequestRetrieveInputBuffer(
+    // in
+    Request: WDFREQUEST,
+    // in
+    MinimumRequiredLength: usize,
+    // out
+    Buffer: &mut *mut c_void,
+    // out, optional
+    Length: Option<&mut usize>,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfRequestRetrieveInputBuffer(
+            Request,
+            MinimumRequiredLength,
+            Buffer,
+            Length.map_or(std::ptr::null_mut(), |l| l as *mut _)
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRequestRetrieveOutputBuffer(
+    // in
+    Request: WDFREQUEST,
+    // in
+    MinimumRequiredSize: usize,
+    // out
+    Buffer: &mut *mut c_void,
+    // out, optional
+    Length: Option<&mut usize>,
+) -> Result<NTSTATUS, WdfError> {
+    WdfCall! {
+        WdfRequestRetrieveOutputBuffer(
+            Request,
+            MinimumRequiredSize,
+            Buffer,
+            Length.map_or(std::ptr::null_mut(), |l| l as *mut _)
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRequestComplete(
+    // in
+    Request: WDFREQUEST,
+    // in
+    Status: NTSTATUS,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfRequestComplete(
+            Request,
+            Status
+        )
+    }
+}
+
+/// # Safety
+///
+/// None. User is responsible for safety.
+pub unsafe fn WdfRequestCompleteWithInformation(
+    // in
+    Request: WDFREQUEST,
+    // in
+    Status: NTSTATUS,
+    // in
+    Information: u64,
+) -> Result<(), WdfError> {
+    WdfCall! {
+        WdfRequestCompleteWithInformation(
+            Request,
+            Status,
+            Information
+        )
+    }
+}